@@ -4,7 +4,8 @@
 
 fn main() -> anyhow::Result<()> {
     tonic_prost_build::configure()
-        .build_server(false)
+        // The server is only needed by the mock coordinator
+        .build_server(std::env::var_os("CARGO_FEATURE_TEST_SUPPORT").is_some())
         .build_client(true)
         .compile_protos(&["proto/labgrid-coordinator.proto"], &["proto/"])?;
     Ok(())
@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Lists the places of a coordinator and the reservations matching them.
+//!
+//! Run with `cargo run -p labgrid-ui-core --example list_places -- <address>`.
+
+use labgrid_ui_core::prelude::*;
+
+#[tokio::main]
+async fn main() -> Result<(), GrpcClientError> {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "localhost:20408".to_string());
+    let mut client = LabgridGrpcClient::builder()
+        .retry_policy(RetryPolicy::default())
+        .connect(&address)
+        .await?;
+
+    let reservations = client.get_reservations().await?;
+    for place in client.get_places().await? {
+        let owner = place.acquired.as_deref().unwrap_or("-");
+        println!("{} (acquired: {owner})", place.name);
+        for reservation in reservations
+            .iter()
+            .filter(|r| r.filters.values().any(|f| f.matches(&place.tags)))
+        {
+            println!(
+                "  reserved by {} ({:?})",
+                reservation.owner,
+                reservation.reservation_state()
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Decodes arbitrary bytes as the coordinator stream messages and converts them into the domain types.
+//! Conversions are allowed to fail, but must never panic.
+
+#![no_main]
+
+use labgrid_ui_core::{proto, types};
+use libfuzzer_sys::fuzz_target;
+use prost::Message;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(msg) = proto::ClientOutMessage::decode(data) {
+        let _ = types::ClientOutMsg::try_from(msg);
+    }
+    if let Ok(msg) = proto::ExporterOutMessage::decode(data) {
+        let _ = types::ExporterOutMessage::try_from(msg);
+    }
+    if let Ok(msg) = proto::GetPlacesResponse::decode(data) {
+        for place in msg.places {
+            let _ = types::Place::try_from(place);
+        }
+    }
+    if let Ok(msg) = proto::GetReservationsResponse::decode(data) {
+        for reservation in msg.reservations {
+            let _ = types::Reservation::try_from(reservation);
+        }
+    }
+});
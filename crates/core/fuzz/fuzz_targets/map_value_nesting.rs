@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Builds deeply nested `MapValue` arrays and checks that they survive a round-trip through the conversions.
+
+#![no_main]
+
+use labgrid_ui_core::{proto, types};
+use libfuzzer_sys::fuzz_target;
+use prost::Message;
+
+fuzz_target!(|data: &[u8]| {
+    // Every input byte adds one nesting level, the leaf is taken from the first byte.
+    let leaf = types::MapValue::Int(data.first().copied().unwrap_or_default().into());
+    let value = data
+        .iter()
+        .fold(leaf, |inner, _| types::MapValue::Array(vec![inner]));
+
+    let encoded = proto::MapValue::try_from(value.clone())
+        .expect("Convert to protobuf")
+        .encode_to_vec();
+    // Decoding fails beyond prost's recursion limit, which is fine as long as nothing panics.
+    if let Ok(decoded) = proto::MapValue::decode(encoded.as_slice()) {
+        let converted = types::MapValue::try_from(decoded).expect("Convert from protobuf");
+        assert_eq!(converted, value);
+    }
+});
@@ -0,0 +1,437 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! A small expression language for reservation filters.
+//!
+//! Expressions compare place tags with values and can be combined with `AND`, `OR`, `NOT` and parentheses,
+//! e.g. `board=foo AND (rev>=3 OR lab=muc)`.
+//! Supported comparison operators are `=`, `!=`, `<`, `<=`, `>` and `>=`.
+//! Values that contain whitespace or special characters can be quoted: `lab="Munich 2"`.
+//!
+//! The coordinator only supports filters that require tags to be equal to values,
+//! so only conjunctions of `=` comparisons can be converted with [FilterExpr::to_filter].
+//! All expressions can however be evaluated client-side with [FilterExpr::matches], for example to preview
+//! which places would be matched.
+
+use crate::types::Filter;
+use core::fmt::Display;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// The filter name that is used when converting expressions into coordinator filters.
+///
+/// Same as used by `labgrid-client reserve`.
+pub const MAIN_FILTER_NAME: &str = "main";
+
+/// The maximum nesting depth of parentheses and `NOT` in an expression.
+pub const MAX_FILTER_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FilterExprError {
+    #[error("Unexpected character '{ch}' at position {pos}")]
+    UnexpectedChar { ch: char, pos: usize },
+    #[error("Unterminated string starting at position {pos}")]
+    UnterminatedString { pos: usize },
+    #[error("Unexpected '{token}' at position {pos}, expected {expected}")]
+    UnexpectedToken {
+        token: String,
+        pos: usize,
+        expected: &'static str,
+    },
+    #[error("Unexpected end of expression, expected {expected}")]
+    UnexpectedEnd { expected: &'static str },
+    #[error("Expression nested too deeply at position {pos}, at most {MAX_FILTER_DEPTH} levels are allowed")]
+    TooDeep { pos: usize },
+    #[error("Expression is not supported by the coordinator: {0}")]
+    NotExpressible(String),
+}
+
+/// Comparison operators between a tag and a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Display for CmpOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Eq => write!(f, "="),
+            Self::Ne => write!(f, "!="),
+            Self::Lt => write!(f, "<"),
+            Self::Le => write!(f, "<="),
+            Self::Gt => write!(f, ">"),
+            Self::Ge => write!(f, ">="),
+        }
+    }
+}
+
+/// A parsed filter expression.
+///
+/// Chains of `AND` and `OR` are stored flat, so that only parentheses and `NOT` nest the expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterExpr {
+    Cmp {
+        key: String,
+        op: CmpOp,
+        value: String,
+    },
+    And(Vec<FilterExpr>),
+    Or(Vec<FilterExpr>),
+    Not(Box<FilterExpr>),
+}
+
+impl FilterExpr {
+    /// Evaluates the expression against the supplied tags.
+    ///
+    /// Comparisons with tags that are not present always evaluate to `false`.
+    /// Ordering comparisons order numbers contained in the values numerically (e.g. `rev9 < rev10`).
+    pub fn matches(&self, tags: &HashMap<String, String>) -> bool {
+        match self {
+            Self::Cmp { key, op, value } => {
+                let Some(tag) = tags.get(key) else {
+                    return false;
+                };
+                let ord = numeric_sort::cmp(tag, value);
+                match op {
+                    CmpOp::Eq => tag == value,
+                    CmpOp::Ne => tag != value,
+                    CmpOp::Lt => ord == Ordering::Less,
+                    CmpOp::Le => ord != Ordering::Greater,
+                    CmpOp::Gt => ord == Ordering::Greater,
+                    CmpOp::Ge => ord != Ordering::Less,
+                }
+            }
+            Self::And(exprs) => exprs.iter().all(|expr| expr.matches(tags)),
+            Self::Or(exprs) => exprs.iter().any(|expr| expr.matches(tags)),
+            Self::Not(expr) => !expr.matches(tags),
+        }
+    }
+
+    /// Converts the expression into a coordinator filter.
+    ///
+    /// Fails if the expression is anything other than `=` comparisons joined by `AND`.
+    pub fn to_filter(&self) -> Result<Filter, FilterExprError> {
+        let mut filter = HashMap::new();
+        self.collect_filter(&mut filter)?;
+        Ok(Filter(filter))
+    }
+
+    /// Converts the expression into the coordinator filters used when creating reservations.
+    pub fn to_filters(&self) -> Result<HashMap<String, Filter>, FilterExprError> {
+        Ok(HashMap::from([(
+            MAIN_FILTER_NAME.to_string(),
+            self.to_filter()?,
+        )]))
+    }
+
+    fn collect_filter(&self, filter: &mut HashMap<String, String>) -> Result<(), FilterExprError> {
+        match self {
+            Self::Cmp {
+                key,
+                op: CmpOp::Eq,
+                value,
+            } => match filter.get(key) {
+                Some(existing) if existing != value => Err(FilterExprError::NotExpressible(
+                    format!("tag '{key}' is compared with different values"),
+                )),
+                _ => {
+                    filter.insert(key.clone(), value.clone());
+                    Ok(())
+                }
+            },
+            Self::Cmp { op, .. } => Err(FilterExprError::NotExpressible(format!(
+                "operator '{op}' can only be evaluated client-side"
+            ))),
+            Self::And(exprs) => exprs
+                .iter()
+                .try_for_each(|expr| expr.collect_filter(filter)),
+            Self::Or(..) => Err(FilterExprError::NotExpressible(
+                "'OR' can only be evaluated client-side".to_string(),
+            )),
+            Self::Not(..) => Err(FilterExprError::NotExpressible(
+                "'NOT' can only be evaluated client-side".to_string(),
+            )),
+        }
+    }
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '_' | '-' | '.' | ':' | '/' | '*' | '@')
+}
+
+fn is_keyword(word: &str) -> bool {
+    ["and", "or", "not"].contains(&word.to_lowercase().as_str())
+}
+
+fn fmt_word(word: &str, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    if word.is_empty() || is_keyword(word) || !word.chars().all(is_word_char) {
+        write!(f, "\"{}\"", word.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        write!(f, "{word}")
+    }
+}
+
+fn fmt_chain(
+    exprs: &[FilterExpr],
+    separator: &str,
+    parenthesize: impl Fn(&FilterExpr) -> bool,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    for (i, expr) in exprs.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{separator}")?;
+        }
+        if parenthesize(expr) {
+            write!(f, "({expr})")?;
+        } else {
+            write!(f, "{expr}")?;
+        }
+    }
+    Ok(())
+}
+
+impl Display for FilterExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cmp { key, op, value } => {
+                fmt_word(key, f)?;
+                write!(f, "{op}")?;
+                fmt_word(value, f)
+            }
+            // Nested chains are parenthesized, so that they are parsed into the same expression again
+            Self::And(exprs) => fmt_chain(
+                exprs,
+                " AND ",
+                |expr| matches!(expr, Self::And(..) | Self::Or(..)),
+                f,
+            ),
+            Self::Or(exprs) => fmt_chain(exprs, " OR ", |expr| matches!(expr, Self::Or(..)), f),
+            Self::Not(expr) => {
+                if matches!(expr.as_ref(), Self::And(..) | Self::Or(..)) {
+                    write!(f, "NOT ({expr})")
+                } else {
+                    write!(f, "NOT {expr}")
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for FilterExpr {
+    type Err = FilterExprError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            next: 0,
+            depth: 0,
+        };
+        let expr = parser.or()?;
+        match parser.peek() {
+            Some((token, pos)) => Err(FilterExprError::UnexpectedToken {
+                token: token.to_string(),
+                pos: *pos,
+                expected: "'AND', 'OR' or end of expression",
+            }),
+            None => Ok(expr),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Op(CmpOp),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Word(word) => write!(f, "{word}"),
+            Self::Op(op) => write!(f, "{op}"),
+            Self::And => write!(f, "AND"),
+            Self::Or => write!(f, "OR"),
+            Self::Not => write!(f, "NOT"),
+            Self::LParen => write!(f, "("),
+            Self::RParen => write!(f, ")"),
+        }
+    }
+}
+
+/// Splits the input into tokens, each paired with its character position.
+fn tokenize(s: &str) -> Result<Vec<(Token, usize)>, FilterExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().enumerate().peekable();
+
+    while let Some((pos, ch)) = chars.next() {
+        let token = match ch {
+            ch if ch.is_whitespace() => continue,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '=' => Token::Op(CmpOp::Eq),
+            '!' if chars.next_if(|(_, ch)| *ch == '=').is_some() => Token::Op(CmpOp::Ne),
+            '<' if chars.next_if(|(_, ch)| *ch == '=').is_some() => Token::Op(CmpOp::Le),
+            '<' => Token::Op(CmpOp::Lt),
+            '>' if chars.next_if(|(_, ch)| *ch == '=').is_some() => Token::Op(CmpOp::Ge),
+            '>' => Token::Op(CmpOp::Gt),
+            '"' => {
+                let mut word = String::new();
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, ch)) => word.push(ch),
+                            None => return Err(FilterExprError::UnterminatedString { pos }),
+                        },
+                        Some((_, ch)) => word.push(ch),
+                        None => return Err(FilterExprError::UnterminatedString { pos }),
+                    }
+                }
+                // Quoted words are never keywords
+                tokens.push((Token::Word(word), pos));
+                continue;
+            }
+            ch if is_word_char(ch) => {
+                let mut word = String::from(ch);
+                while let Some((_, ch)) = chars.next_if(|(_, ch)| is_word_char(*ch)) {
+                    word.push(ch);
+                }
+                match word.to_lowercase().as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => Token::Word(word),
+                }
+            }
+            ch => return Err(FilterExprError::UnexpectedChar { ch, pos }),
+        };
+        tokens.push((token, pos));
+    }
+    Ok(tokens)
+}
+
+/// Recursive descent parser, `NOT` binds tighter than `AND`, which binds tighter than `OR`.
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    next: usize,
+    /// The current nesting depth, bounded to not overflow the stack.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.next)
+    }
+
+    fn advance(&mut self, expected: &'static str) -> Result<(Token, usize), FilterExprError> {
+        let token = self
+            .tokens
+            .get(self.next)
+            .cloned()
+            .ok_or(FilterExprError::UnexpectedEnd { expected })?;
+        self.next += 1;
+        Ok(token)
+    }
+
+    fn or(&mut self) -> Result<FilterExpr, FilterExprError> {
+        let mut exprs = vec![self.and()?];
+        while matches!(self.peek(), Some((Token::Or, _))) {
+            self.next += 1;
+            exprs.push(self.and()?);
+        }
+        Ok(Self::chain(exprs, FilterExpr::Or))
+    }
+
+    fn and(&mut self) -> Result<FilterExpr, FilterExprError> {
+        let mut exprs = vec![self.unary()?];
+        while matches!(self.peek(), Some((Token::And, _))) {
+            self.next += 1;
+            exprs.push(self.unary()?);
+        }
+        Ok(Self::chain(exprs, FilterExpr::And))
+    }
+
+    /// A single expression is not wrapped into the chain.
+    fn chain(
+        mut exprs: Vec<FilterExpr>,
+        chain: impl FnOnce(Vec<FilterExpr>) -> FilterExpr,
+    ) -> FilterExpr {
+        if exprs.len() == 1 {
+            exprs.remove(0)
+        } else {
+            chain(exprs)
+        }
+    }
+
+    fn nested<T>(
+        &mut self,
+        pos: usize,
+        parse: impl FnOnce(&mut Self) -> Result<T, FilterExprError>,
+    ) -> Result<T, FilterExprError> {
+        if self.depth >= MAX_FILTER_DEPTH {
+            return Err(FilterExprError::TooDeep { pos });
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn unary(&mut self) -> Result<FilterExpr, FilterExprError> {
+        const EXPECTED: &str = "comparison, 'NOT' or '('";
+        match self.advance(EXPECTED)? {
+            (Token::Not, pos) => Ok(FilterExpr::Not(Box::new(self.nested(pos, Self::unary)?))),
+            (Token::LParen, pos) => {
+                let expr = self.nested(pos, Self::or)?;
+                match self.advance("')'")? {
+                    (Token::RParen, _) => Ok(expr),
+                    (token, pos) => Err(FilterExprError::UnexpectedToken {
+                        token: token.to_string(),
+                        pos,
+                        expected: "')'",
+                    }),
+                }
+            }
+            (Token::Word(key), _) => {
+                let op = match self.advance("comparison operator")? {
+                    (Token::Op(op), _) => op,
+                    (token, pos) => {
+                        return Err(FilterExprError::UnexpectedToken {
+                            token: token.to_string(),
+                            pos,
+                            expected: "comparison operator",
+                        })
+                    }
+                };
+                let value = match self.advance("value")? {
+                    (Token::Word(value), _) => value,
+                    (token, pos) => {
+                        return Err(FilterExprError::UnexpectedToken {
+                            token: token.to_string(),
+                            pos,
+                            expected: "value",
+                        })
+                    }
+                };
+                Ok(FilterExpr::Cmp { key, op, value })
+            }
+            (token, pos) => Err(FilterExprError::UnexpectedToken {
+                token: token.to_string(),
+                pos,
+                expected: EXPECTED,
+            }),
+        }
+    }
+}
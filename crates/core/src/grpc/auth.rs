@@ -0,0 +1,220 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use super::error::GrpcClientError;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::watch;
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
+use tonic::{Request, Status};
+use tracing::{debug, warn};
+
+/// How often a [TokenSource::File] is read again.
+const FILE_REFRESH: Duration = Duration::from_secs(1);
+/// The longest time after which a failed [TokenSource::Command] is run again.
+const COMMAND_RETRY: Duration = Duration::from_secs(5);
+
+/// Where the bearer token sent with every call is taken from.
+#[derive(Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TokenSource {
+    Static(String),
+    /// A file containing the token, read again every second so that rotated tokens are picked up.
+    File(PathBuf),
+    /// A command printing the token to stdout, split at whitespace into the program and its arguments.
+    ///
+    /// It is run again once the token is older than `refresh`, or after a few seconds when it failed.
+    Command {
+        command: String,
+        refresh: Duration,
+    },
+}
+
+impl std::fmt::Debug for TokenSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Static(_) => f.write_str("Static(..)"),
+            Self::File(path) => f.debug_tuple("File").field(path).finish(),
+            Self::Command { command, refresh } => f
+                .debug_struct("Command")
+                .field("command", command)
+                .field("refresh", refresh)
+                .finish(),
+        }
+    }
+}
+
+impl TokenSource {
+    /// Reads or requests the token, surrounding whitespace is removed.
+    async fn fetch(&self) -> Result<String, String> {
+        let token = match self {
+            Self::Static(token) => token.clone(),
+            Self::File(path) => tokio::fs::read_to_string(path)
+                .await
+                .map_err(|e| format!("Read token file '{}': {e}", path.display()))?,
+            Self::Command { command, .. } => {
+                let mut args = command.split_whitespace();
+                let program = args.next().ok_or("Empty token command")?;
+                let output = tokio::process::Command::new(program)
+                    .args(args)
+                    .kill_on_drop(true)
+                    .output()
+                    .await
+                    .map_err(|e| format!("Run token command '{program}': {e}"))?;
+                if !output.status.success() {
+                    return Err(format!(
+                        "Token command '{program}' failed with {}: {}",
+                        output.status,
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    ));
+                }
+                String::from_utf8(output.stdout)
+                    .map_err(|_| format!("Token command '{program}' printed invalid UTF-8"))?
+            }
+        };
+        let token = token.trim();
+        if token.is_empty() {
+            return Err("Empty token".to_string());
+        }
+        Ok(token.to_string())
+    }
+
+    /// The time after which the token is fetched again, `None` if it never changes.
+    fn refresh_after(&self, fetch_failed: bool) -> Option<Duration> {
+        match self {
+            Self::Static(_) => None,
+            Self::File(_) => Some(FILE_REFRESH),
+            Self::Command { refresh, .. } if fetch_failed => Some((*refresh).min(COMMAND_RETRY)),
+            Self::Command { refresh, .. } => Some(*refresh),
+        }
+    }
+}
+
+/// The value of the `authorization` metadata for the token.
+fn authorization_value(token: &str) -> Result<AsciiMetadataValue, String> {
+    let mut value = AsciiMetadataValue::try_from(format!("Bearer {token}"))
+        .map_err(|_| "Token contains invalid characters".to_string())?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Credentials sent as metadata with every call, for coordinators behind an authenticating proxy.
+#[derive(Debug, Clone, Default)]
+pub struct CallCredentials {
+    token: Option<TokenSource>,
+    metadata: MetadataMap,
+}
+
+impl CallCredentials {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends the token as `authorization: Bearer <token>`.
+    pub fn token(mut self, token: TokenSource) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Sends the metadata entry with every call, e.g. an API key expected by the proxy.
+    ///
+    /// Fails if the key is not a valid ASCII metadata key or the value contains invalid characters.
+    pub fn metadata(mut self, key: &str, value: &str) -> Result<Self, GrpcClientError> {
+        let invalid = || GrpcClientError::InvalidMetadata(key.to_string());
+        let key = AsciiMetadataKey::from_bytes(key.as_bytes()).map_err(|_| invalid())?;
+        let mut value = AsciiMetadataValue::try_from(value).map_err(|_| invalid())?;
+        // Hides the value from debug output, e.g. the tracing spans of the client builder.
+        value.set_sensitive(true);
+        self.metadata.insert(key, value);
+        Ok(self)
+    }
+
+    /// Whether no metadata is sent.
+    pub fn is_empty(&self) -> bool {
+        self.token.is_none() && self.metadata.is_empty()
+    }
+}
+
+/// The `authorization` metadata of the last fetched token, `None` before the first fetch finished.
+type FetchedToken = Option<Result<AsciiMetadataValue, String>>;
+
+/// Adds the [CallCredentials] to the requests of a client.
+///
+/// The token is fetched by a background task, so that reading a file or running a command never blocks a call.
+/// The task ends once the interceptor and all of its clones are dropped.
+#[derive(Debug, Clone, Default)]
+pub struct AuthInterceptor {
+    metadata: MetadataMap,
+    /// `None` without a token.
+    token: Option<watch::Receiver<FetchedToken>>,
+}
+
+impl AuthInterceptor {
+    /// Creates the interceptor, spawning the task fetching the token.
+    ///
+    /// Must be called within a tokio runtime.
+    pub fn new(credentials: CallCredentials) -> Self {
+        let token = credentials.token.map(|source| {
+            let (sender, receiver) = watch::channel(None);
+            tokio::spawn(fetch_token(source, sender));
+            receiver
+        });
+        Self {
+            metadata: credentials.metadata,
+            token,
+        }
+    }
+
+    /// Waits until the token was fetched for the first time, so that the first call is not rejected.
+    pub(crate) async fn token_fetched(&self) {
+        if let Some(token) = &self.token {
+            // Only fails once the task ended, which means there is nothing left to wait for.
+            let _ = token.clone().wait_for(Option::is_some).await;
+        }
+    }
+}
+
+/// Fetches the token from the source again and again, until all receivers are dropped.
+async fn fetch_token(source: TokenSource, sender: watch::Sender<FetchedToken>) {
+    loop {
+        let fetched = tokio::select! {
+            token = source.fetch() => token.and_then(|token| authorization_value(&token)),
+            () = sender.closed() => return,
+        };
+        match &fetched {
+            Ok(_) => debug!(?source, "Fetched token"),
+            Err(error) => warn!(?source, %error, "Fetch token"),
+        }
+        let refresh = source.refresh_after(fetched.is_err());
+        sender.send_replace(Some(fetched));
+        let Some(refresh) = refresh else {
+            return;
+        };
+        tokio::select! {
+            () = tokio::time::sleep(refresh) => {}
+            () = sender.closed() => return,
+        }
+    }
+}
+
+impl tonic::service::Interceptor for AuthInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let metadata = request.metadata_mut();
+        for key_and_value in self.metadata.iter() {
+            if let tonic::metadata::KeyAndValueRef::Ascii(key, value) = key_and_value {
+                metadata.insert(key.clone(), value.clone());
+            }
+        }
+        if let Some(token) = &self.token {
+            match &*token.borrow() {
+                Some(Ok(authorization)) => {
+                    metadata.insert("authorization", authorization.clone());
+                }
+                Some(Err(error)) => return Err(Status::unauthenticated(error.clone())),
+                None => return Err(Status::unauthenticated("Token not fetched yet")),
+            }
+        }
+        Ok(request)
+    }
+}
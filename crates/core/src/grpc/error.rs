@@ -5,11 +5,116 @@
 use crate::types;
 
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum GrpcClientError {
     #[error("tonic reported transport error")]
     TonicTransport(#[from] tonic::transport::Error),
     #[error("tonic reported error status")]
     TonicStatus(#[from] tonic::Status),
+    #[error("Unsupported coordinator URI '{0}'")]
+    UnsupportedUri(String),
     #[error("Message could not be converted")]
     MsgConversion(#[from] types::ConversionError),
+    #[error("Invalid call metadata '{0}'")]
+    InvalidMetadata(String),
+}
+
+/// The category of a [GrpcClientError], shared by clients to handle errors consistently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The coordinator can't be reached or the connection was lost.
+    Unavailable,
+    /// The coordinator did not respond in time.
+    Timeout,
+    Unauthenticated,
+    PermissionDenied,
+    InvalidArgument,
+    NotFound,
+    AlreadyExists,
+    /// The coordinator's state does not allow the operation, e.g. the place is acquired by someone else.
+    FailedPrecondition,
+    Cancelled,
+    /// The coordinator does not support the call.
+    Unimplemented,
+    /// The address of the coordinator is not supported.
+    InvalidUri,
+    /// A message could not be converted from/to its protobuf representation.
+    Conversion,
+    /// Internal coordinator failures and unclassified status codes.
+    Other,
+}
+
+impl ErrorKind {
+    /// Whether the connection to the coordinator can't be used anymore and should be closed.
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Self::Unavailable | Self::Timeout | Self::InvalidUri)
+    }
+
+    /// Whether repeating the same operation later might succeed without changing it.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Unavailable | Self::Timeout | Self::FailedPrecondition | Self::Cancelled
+        )
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::Unavailable => "coordinator unavailable",
+            Self::Timeout => "timeout",
+            Self::Unauthenticated => "unauthenticated",
+            Self::PermissionDenied => "permission denied",
+            Self::InvalidArgument => "invalid argument",
+            Self::NotFound => "not found",
+            Self::AlreadyExists => "already exists",
+            Self::FailedPrecondition => "failed precondition",
+            Self::Cancelled => "cancelled",
+            Self::Unimplemented => "unimplemented",
+            Self::InvalidUri => "invalid coordinator URI",
+            Self::Conversion => "message conversion",
+            Self::Other => "other",
+        };
+        write!(f, "{description}")
+    }
+}
+
+impl From<tonic::Code> for ErrorKind {
+    fn from(code: tonic::Code) -> Self {
+        match code {
+            tonic::Code::Unavailable => Self::Unavailable,
+            tonic::Code::DeadlineExceeded => Self::Timeout,
+            tonic::Code::Unauthenticated => Self::Unauthenticated,
+            tonic::Code::PermissionDenied => Self::PermissionDenied,
+            tonic::Code::InvalidArgument | tonic::Code::OutOfRange => Self::InvalidArgument,
+            tonic::Code::NotFound => Self::NotFound,
+            tonic::Code::AlreadyExists => Self::AlreadyExists,
+            tonic::Code::FailedPrecondition | tonic::Code::Aborted => Self::FailedPrecondition,
+            tonic::Code::Cancelled => Self::Cancelled,
+            tonic::Code::Unimplemented => Self::Unimplemented,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl GrpcClientError {
+    /// The category of the error.
+    ///
+    /// Transport failures are [ErrorKind::Unavailable].
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TonicTransport(_) => ErrorKind::Unavailable,
+            Self::TonicStatus(status) => ErrorKind::from(status.code()),
+            Self::UnsupportedUri(_) => ErrorKind::InvalidUri,
+            Self::MsgConversion(_) => ErrorKind::Conversion,
+            Self::InvalidMetadata(_) => ErrorKind::InvalidArgument,
+        }
+    }
+
+    /// Shortcut for `self.kind().is_fatal()`.
+    pub fn is_fatal(&self) -> bool {
+        self.kind().is_fatal()
+    }
 }
@@ -2,8 +2,12 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+pub mod auth;
 pub mod error;
+pub mod retry;
+pub mod trace;
 pub mod types;
+pub mod version;
 
 pub mod proto {
     #![allow(clippy::enum_variant_names)]
@@ -11,26 +15,200 @@ pub mod proto {
     tonic::include_proto!("labgrid");
 }
 
+use auth::{AuthInterceptor, CallCredentials};
 use error::GrpcClientError;
+use retry::RetryPolicy;
 use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
 use tokio_stream::StreamExt;
+use tonic::codegen::http::uri::PathAndQuery;
 use tonic::Request;
-use tracing::{error, instrument};
-use types::{ClientInMsg, ExporterInMessage, Filter, Place, Reservation};
+use trace::{Direction, MessageTrace, TracingCodec};
+use tracing::{error, instrument, warn};
+use types::{ClientInMsg, ExporterInMessage, ExporterOutMessage, Filter, Place, Reservation};
+
+/// Timeouts applied by the client, `None` disables the timeout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientTimeouts {
+    /// Establishing the connection to the coordinator.
+    pub connect: Option<Duration>,
+    /// Each unary call, streams are not affected.
+    pub rpc: Option<Duration>,
+}
+
+type CoordinatorService =
+    tonic::service::interceptor::InterceptedService<tonic::transport::Channel, AuthInterceptor>;
+type CoordinatorClient = proto::coordinator_client::CoordinatorClient<CoordinatorService>;
 
 #[derive(Debug)]
 pub struct LabgridGrpcClient {
-    client: proto::coordinator_client::CoordinatorClient<tonic::transport::Channel>,
+    client: CoordinatorClient,
+    /// Used for the streams instead of `client`, so that their messages can be traced.
+    grpc: tonic::client::Grpc<CoordinatorService>,
+    trace: MessageTrace,
+    /// Shares the fetched token with the interceptor of `client` and `grpc`.
+    auth: AuthInterceptor,
+    rpc_timeout: Option<Duration>,
+    /// Overrides `rpc_timeout` for the next unary call.
+    next_rpc_timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+}
+
+/// Builder for a [LabgridGrpcClient] with timeouts or retries.
+#[derive(Debug, Clone, Default)]
+pub struct LabgridGrpcClientBuilder {
+    timeouts: ClientTimeouts,
+    retry_policy: Option<RetryPolicy>,
+    credentials: CallCredentials,
+    trace: MessageTrace,
+}
+
+impl LabgridGrpcClientBuilder {
+    pub fn timeouts(mut self, timeouts: ClientTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Retry unary calls failing with a transient error status according to the policy.
+    ///
+    /// Without a policy, calls are not retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sends the credentials with every call and stream, e.g. for a coordinator behind an authenticating proxy.
+    pub fn credentials(mut self, credentials: CallCredentials) -> Self {
+        self.credentials = credentials;
+        self
+    }
+
+    /// Records the messages of all calls and streams in the trace while it is enabled.
+    pub fn message_trace(mut self, trace: MessageTrace) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    /// Connects to the coordinator at `address`.
+    ///
+    /// The address is either `host:port`, which is connected to through plain HTTP/2,
+    /// or a full URI with one of the schemes `http://`, `https://` or `unix://` (e.g. `unix:///run/labgrid.sock`).
+    /// `https://` requires tonic to be built with TLS support.
+    #[instrument]
+    pub async fn connect(self, address: &str) -> Result<LabgridGrpcClient, GrpcClientError> {
+        let channel = match address.split_once("://") {
+            None => {
+                self.endpoint(format!("http://{address}"))?
+                    .connect()
+                    .await?
+            }
+            Some(("http" | "https", _)) => self.endpoint(address.to_string())?.connect().await?,
+            #[cfg(unix)]
+            Some(("unix", path)) => {
+                let path = path.to_string();
+                // The URI is required by tonic, but is not used by the connector.
+                self.endpoint("http://[::]:50051".to_string())?
+                    .connect_with_connector(tower::service_fn(move |_: tonic::transport::Uri| {
+                        let path = path.clone();
+                        async move {
+                            let stream = tokio::net::UnixStream::connect(path).await?;
+                            Ok::<_, std::io::Error>(hyper_util::rt::TokioIo::new(stream))
+                        }
+                    }))
+                    .await?
+            }
+            Some(_) => return Err(GrpcClientError::UnsupportedUri(address.to_string())),
+        };
+        Ok(self.build(channel))
+    }
+
+    /// Builds the client on top of an already established channel.
+    ///
+    /// The connect timeout is not applied. Must be called within a tokio runtime.
+    pub fn build(self, channel: tonic::transport::Channel) -> LabgridGrpcClient {
+        let auth = AuthInterceptor::new(self.credentials);
+        let service = CoordinatorService::new(channel, auth.clone());
+        LabgridGrpcClient {
+            client: CoordinatorClient::new(service.clone()),
+            grpc: tonic::client::Grpc::new(service),
+            trace: self.trace,
+            auth,
+            rpc_timeout: self.timeouts.rpc,
+            next_rpc_timeout: None,
+            retry_policy: self.retry_policy,
+        }
+    }
+
+    fn endpoint(&self, uri: String) -> Result<tonic::transport::Endpoint, GrpcClientError> {
+        let mut endpoint = tonic::transport::Endpoint::from_shared(uri)?;
+        if let Some(timeout) = self.timeouts.connect {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        Ok(endpoint)
+    }
 }
 
 impl LabgridGrpcClient {
+    /// Connects to the coordinator at `address`, see [LabgridGrpcClientBuilder::connect] for the accepted formats.
     #[instrument]
     pub async fn new(address: &str) -> Result<Self, GrpcClientError> {
-        let client =
-            proto::coordinator_client::CoordinatorClient::connect(format!("http://{address}"))
-                .await
-                .map_err(GrpcClientError::from)?;
-        Ok(Self { client })
+        Self::builder().connect(address).await
+    }
+
+    /// Creates the client on top of an already established channel.
+    pub fn from_channel(channel: tonic::transport::Channel) -> Self {
+        Self::builder().build(channel)
+    }
+
+    pub fn builder() -> LabgridGrpcClientBuilder {
+        LabgridGrpcClientBuilder::default()
+    }
+
+    /// Overrides the timeout of the next unary call, e.g. `client.timeout(duration).get_places().await`.
+    ///
+    /// The timeout is sent to the coordinator as deadline of the request and applies to each attempt of a retried call.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.next_rpc_timeout = Some(timeout);
+        self
+    }
+
+    /// Executes a unary call, retrying it according to the retry policy.
+    async fn call<T, R, F, Fut>(
+        &mut self,
+        message: T,
+        mut call: F,
+    ) -> Result<tonic::Response<R>, tonic::Status>
+    where
+        T: Clone + std::fmt::Debug,
+        R: std::fmt::Debug,
+        F: FnMut(CoordinatorClient, Request<T>) -> Fut,
+        Fut: Future<Output = Result<tonic::Response<R>, tonic::Status>>,
+    {
+        let timeout = self.next_rpc_timeout.take().or(self.rpc_timeout);
+        self.auth.token_fetched().await;
+        let mut attempt = 1;
+        loop {
+            let mut request = Request::new(message.clone());
+            if let Some(timeout) = timeout {
+                request.set_timeout(timeout);
+            }
+            self.trace.record(Direction::Sent, &message);
+            let result = call(self.client.clone(), request).await;
+            match &result {
+                Ok(response) => self.trace.record(Direction::Received, response.get_ref()),
+                Err(status) => self.trace.record(Direction::Received, status),
+            }
+            match (&result, &self.retry_policy) {
+                (Err(status), Some(policy)) if policy.should_retry(status, attempt) => {
+                    let backoff = policy.backoff(attempt);
+                    warn!(?status, attempt, ?backoff, "Retrying failed call");
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                _ => return result,
+            }
+        }
     }
 
     #[instrument(skip(in_stream))]
@@ -48,7 +226,9 @@ impl LabgridGrpcClient {
                 None
             }
         });
-        Ok(self.client.client_stream(in_stream).await?.into_inner())
+        Ok(self
+            .streaming("/labgrid.Coordinator/ClientStream", in_stream)
+            .await?)
     }
 
     #[instrument(skip(in_stream))]
@@ -63,15 +243,92 @@ impl LabgridGrpcClient {
                 None
             }
         });
-        Ok(self.client.exporter_stream(in_stream).await?.into_inner())
+        Ok(self
+            .streaming("/labgrid.Coordinator/ExporterStream", in_stream)
+            .await?)
+    }
+
+    /// Starts the bidirectional stream of the rpc `path`, tracing its messages.
+    async fn streaming<I, O>(
+        &mut self,
+        path: &'static str,
+        in_stream: impl tokio_stream::Stream<Item = I> + Send + 'static,
+    ) -> Result<tonic::Streaming<O>, tonic::Status>
+    where
+        I: prost::Message + std::fmt::Debug + Send + 'static,
+        O: prost::Message + std::fmt::Debug + Default + Send + 'static,
+    {
+        self.auth.token_fetched().await;
+        self.grpc
+            .ready()
+            .await
+            .map_err(|e| tonic::Status::unknown(format!("Service was not ready: {e}")))?;
+        let mut request = Request::new(in_stream);
+        if let Some((service, method)) = path.trim_start_matches('/').split_once('/') {
+            request
+                .extensions_mut()
+                .insert(tonic::GrpcMethod::new(service, method));
+        }
+        let codec = TracingCodec::new(
+            tonic_prost::ProstCodec::<I, O>::default(),
+            self.trace.clone(),
+        );
+        let response = self
+            .grpc
+            .streaming(request, PathAndQuery::from_static(path), codec)
+            .await?;
+        Ok(response.into_inner())
+    }
+
+    /// The trace recording the messages of the client, see [LabgridGrpcClientBuilder::message_trace].
+    pub fn message_trace(&self) -> &MessageTrace {
+        &self.trace
+    }
+
+    /// The labgrid version of the coordinator.
+    ///
+    /// The coordinator only reports it to exporters, so it is read from the hello message of an exporter stream
+    /// that is closed again before registering an exporter. The coordinator sees (and usually logs) each call
+    /// as a short exporter session without resources, so only call it when the version is actually needed.
+    /// Fails with `Unimplemented` for coordinators not reporting it.
+    ///
+    /// Waits at most [version::HELLO_TIMEOUT] for the hello message, instead of the timeout of unary calls,
+    /// unless overridden with [LabgridGrpcClient::timeout].
+    #[instrument]
+    pub async fn coordinator_version(&mut self) -> Result<String, GrpcClientError> {
+        let timeout = self
+            .next_rpc_timeout
+            .take()
+            .unwrap_or(version::HELLO_TIMEOUT);
+        let hello = async {
+            let mut out_stream = self.exporter_stream(tokio_stream::empty()).await?;
+            match out_stream.message().await? {
+                Some(msg) => Ok::<_, GrpcClientError>(ExporterOutMessage::try_from(msg)?),
+                None => Err(tonic::Status::unimplemented(
+                    "Exporter stream ended without a hello message",
+                )
+                .into()),
+            }
+        };
+        let hello = tokio::time::timeout(timeout, hello)
+            .await
+            .map_err(|_| tonic::Status::deadline_exceeded("Waiting for the hello message"))??;
+        match hello {
+            ExporterOutMessage::Hello { version } => Ok(version),
+            _ => Err(tonic::Status::unimplemented(
+                "Exporter stream started without a hello message",
+            )
+            .into()),
+        }
     }
 
     #[instrument]
     pub async fn add_place(&mut self, name: String) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::AddPlaceRequest { name });
         let _response = self
-            .client
-            .add_place(request)
+            .call(
+                proto::AddPlaceRequest { name },
+                |mut client, request| async move { client.add_place(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -79,10 +336,11 @@ impl LabgridGrpcClient {
 
     #[instrument]
     pub async fn delete_place(&mut self, name: String) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::DeletePlaceRequest { name });
         let _response = self
-            .client
-            .delete_place(request)
+            .call(
+                proto::DeletePlaceRequest { name },
+                |mut client, request| async move { client.delete_place(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -90,10 +348,11 @@ impl LabgridGrpcClient {
 
     #[instrument]
     pub async fn get_places(&mut self) -> Result<Vec<Place>, GrpcClientError> {
-        let request = Request::new(proto::GetPlacesRequest {});
         let response = self
-            .client
-            .get_places(request)
+            .call(
+                proto::GetPlacesRequest {},
+                |mut client, request| async move { client.get_places(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         response
@@ -110,13 +369,14 @@ impl LabgridGrpcClient {
         place_name: String,
         alias: String,
     ) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::AddPlaceAliasRequest {
-            placename: place_name,
-            alias,
-        });
         let _response = self
-            .client
-            .add_place_alias(request)
+            .call(
+                proto::AddPlaceAliasRequest {
+                    placename: place_name,
+                    alias,
+                },
+                |mut client, request| async move { client.add_place_alias(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -128,13 +388,14 @@ impl LabgridGrpcClient {
         place_name: String,
         alias: String,
     ) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::DeletePlaceAliasRequest {
-            placename: place_name,
-            alias,
-        });
         let _response = self
-            .client
-            .delete_place_alias(request)
+            .call(
+                proto::DeletePlaceAliasRequest {
+                    placename: place_name,
+                    alias,
+                },
+                |mut client, request| async move { client.delete_place_alias(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -146,13 +407,33 @@ impl LabgridGrpcClient {
         place_name: String,
         tags: HashMap<String, String>,
     ) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::SetPlaceTagsRequest {
-            placename: place_name,
-            tags,
-        });
         let _response = self
-            .client
-            .set_place_tags(request)
+            .call(
+                proto::SetPlaceTagsRequest {
+                    placename: place_name,
+                    tags,
+                },
+                |mut client, request| async move { client.set_place_tags(request).await },
+            )
+            .await
+            .map_err(GrpcClientError::from)?;
+        Ok(())
+    }
+
+    #[instrument]
+    pub async fn set_place_comment(
+        &mut self,
+        place_name: String,
+        comment: String,
+    ) -> Result<(), GrpcClientError> {
+        let _response = self
+            .call(
+                proto::SetPlaceCommentRequest {
+                    placename: place_name,
+                    comment,
+                },
+                |mut client, request| async move { client.set_place_comment(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -165,14 +446,15 @@ impl LabgridGrpcClient {
         pattern: String,
         rename: Option<String>,
     ) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::AddPlaceMatchRequest {
-            placename: place_name,
-            pattern,
-            rename,
-        });
         let _response = self
-            .client
-            .add_place_match(request)
+            .call(
+                proto::AddPlaceMatchRequest {
+                    placename: place_name,
+                    pattern,
+                    rename,
+                },
+                |mut client, request| async move { client.add_place_match(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -185,14 +467,15 @@ impl LabgridGrpcClient {
         pattern: String,
         rename: Option<String>,
     ) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::DeletePlaceMatchRequest {
-            placename: place_name,
-            pattern,
-            rename,
-        });
         let _response = self
-            .client
-            .delete_place_match(request)
+            .call(
+                proto::DeletePlaceMatchRequest {
+                    placename: place_name,
+                    pattern,
+                    rename,
+                },
+                |mut client, request| async move { client.delete_place_match(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -200,12 +483,13 @@ impl LabgridGrpcClient {
 
     #[instrument]
     pub async fn acquire_place(&mut self, place_name: String) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::AcquirePlaceRequest {
-            placename: place_name,
-        });
         let _response = self
-            .client
-            .acquire_place(request)
+            .call(
+                proto::AcquirePlaceRequest {
+                    placename: place_name,
+                },
+                |mut client, request| async move { client.acquire_place(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -217,13 +501,14 @@ impl LabgridGrpcClient {
         place_name: String,
         from_user: Option<String>,
     ) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::ReleasePlaceRequest {
-            placename: place_name,
-            fromuser: from_user,
-        });
         let _response = self
-            .client
-            .release_place(request)
+            .call(
+                proto::ReleasePlaceRequest {
+                    placename: place_name,
+                    fromuser: from_user,
+                },
+                |mut client, request| async move { client.release_place(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -235,13 +520,14 @@ impl LabgridGrpcClient {
         place_name: String,
         user: String,
     ) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::AllowPlaceRequest {
-            placename: place_name,
-            user,
-        });
         let _response = self
-            .client
-            .allow_place(request)
+            .call(
+                proto::AllowPlaceRequest {
+                    placename: place_name,
+                    user,
+                },
+                |mut client, request| async move { client.allow_place(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -253,19 +539,24 @@ impl LabgridGrpcClient {
         filters: HashMap<String, Filter>,
         prio: f64,
     ) -> Result<Reservation, GrpcClientError> {
-        let request = Request::new(proto::CreateReservationRequest {
-            filters: filters
-                .into_iter()
-                .map(|f| Ok((f.0, f.1.try_into()?)))
-                .collect::<Result<HashMap<String, proto::reservation::Filter>, GrpcClientError>>(
-                )?,
-            prio,
-        });
-        let response = self
-            .client
-            .create_reservation(request)
-            .await
-            .map_err(GrpcClientError::from)?;
+        let response =
+            self
+                .call(
+                    proto::CreateReservationRequest {
+                        filters:
+                            filters
+                                .into_iter()
+                                .map(|f| Ok((f.0, f.1.try_into()?)))
+                                .collect::<Result<
+                                    HashMap<String, proto::reservation::Filter>,
+                                    GrpcClientError,
+                                >>()?,
+                        prio,
+                    },
+                    |mut client, request| async move { client.create_reservation(request).await },
+                )
+                .await
+                .map_err(GrpcClientError::from)?;
         Reservation::try_from(response.into_inner().reservation.ok_or_else(|| {
             GrpcClientError::MsgConversion(types::ConversionError::new(
                 "Response not holding a reservation",
@@ -276,10 +567,11 @@ impl LabgridGrpcClient {
 
     #[instrument]
     pub async fn cancel_reservation(&mut self, token: String) -> Result<(), GrpcClientError> {
-        let request = Request::new(proto::CancelReservationRequest { token });
         let _response = self
-            .client
-            .cancel_reservation(request)
+            .call(
+                proto::CancelReservationRequest { token },
+                |mut client, request| async move { client.cancel_reservation(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Ok(())
@@ -290,10 +582,11 @@ impl LabgridGrpcClient {
         &mut self,
         token: String,
     ) -> Result<Reservation, GrpcClientError> {
-        let request = Request::new(proto::PollReservationRequest { token });
         let response = self
-            .client
-            .poll_reservation(request)
+            .call(
+                proto::PollReservationRequest { token },
+                |mut client, request| async move { client.poll_reservation(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         Reservation::try_from(
@@ -307,10 +600,11 @@ impl LabgridGrpcClient {
 
     #[instrument]
     pub async fn get_reservations(&mut self) -> Result<Vec<Reservation>, GrpcClientError> {
-        let request = Request::new(proto::GetReservationsRequest {});
         let response = self
-            .client
-            .get_reservations(request)
+            .call(
+                proto::GetReservationsRequest {},
+                |mut client, request| async move { client.get_reservations(request).await },
+            )
             .await
             .map_err(GrpcClientError::from)?;
         response
@@ -0,0 +1,48 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::time::Duration;
+
+/// Policy for retrying unary calls that failed with a transient error status.
+///
+/// The backoff between attempts grows exponentially, starting at `initial_backoff` and capped at `max_backoff`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Factor by which the backoff grows after each retry.
+    pub backoff_multiplier: u32,
+    /// Status codes for which a call is retried.
+    pub retryable_codes: Vec<tonic::Code>,
+}
+
+impl Default for RetryPolicy {
+    /// Retries calls up to two times when the coordinator is temporarily unavailable.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(2),
+            backoff_multiplier: 2,
+            retryable_codes: vec![tonic::Code::Unavailable],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether a call that failed with `status` on attempt number `attempt` (starting at 1) should be retried.
+    pub fn should_retry(&self, status: &tonic::Status, attempt: u32) -> bool {
+        attempt < self.max_attempts && self.retryable_codes.contains(&status.code())
+    }
+
+    /// The time to wait before retrying a call that failed on attempt number `attempt` (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.backoff_multiplier
+            .checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.initial_backoff.checked_mul(factor))
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
@@ -0,0 +1,234 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::collections::VecDeque;
+use std::fmt::{Debug, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::SystemTime;
+use tonic::codec::{BufferSettings, Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::Status;
+
+/// Whether a traced message was sent to or received from the coordinator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sent => write!(f, "->"),
+            Self::Received => write!(f, "<-"),
+        }
+    }
+}
+
+/// A protobuf message exchanged with the coordinator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TracedMessage {
+    pub time: SystemTime,
+    pub direction: Direction,
+    /// The name of the protobuf message, e.g. `GetPlacesRequest`, or `Status` for the error status of a call.
+    pub message_type: &'static str,
+    /// The debug representation of the message.
+    pub message: String,
+}
+
+/// Records the protobuf messages exchanged with the coordinator in a ring buffer,
+/// for diagnosing incompatibilities with coordinator versions.
+///
+/// Recording is disabled until it is enabled with [MessageTrace::set_enabled], it can be toggled while connected.
+/// Clones share the buffer, so a trace passed to [LabgridGrpcClientBuilder::message_trace](crate::LabgridGrpcClientBuilder::message_trace)
+/// can be inspected by the application.
+#[derive(Debug, Clone)]
+pub struct MessageTrace {
+    inner: Arc<TraceInner>,
+}
+
+#[derive(Debug)]
+struct TraceInner {
+    enabled: AtomicBool,
+    capacity: usize,
+    messages: Mutex<VecDeque<TracedMessage>>,
+}
+
+impl Default for MessageTrace {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl MessageTrace {
+    pub const DEFAULT_CAPACITY: usize = 1000;
+
+    /// A disabled trace keeping the last `capacity` messages.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(TraceInner {
+                enabled: AtomicBool::new(false),
+                capacity: capacity.max(1),
+                messages: Mutex::new(VecDeque::new()),
+            }),
+        }
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.inner.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.inner.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Records the message if the trace is enabled, dropping the oldest one when the buffer is full.
+    ///
+    /// The message type is the name of the type of `message` without its module path.
+    pub fn record<M: Debug>(&self, direction: Direction, message: &M) {
+        if !self.is_enabled() {
+            return;
+        }
+        let message_type = std::any::type_name::<M>();
+        let message_type = message_type.rsplit("::").next().unwrap_or(message_type);
+        let traced = TracedMessage {
+            time: SystemTime::now(),
+            direction,
+            message_type,
+            message: format!("{message:?}"),
+        };
+        let mut messages = self.lock();
+        if messages.len() >= self.inner.capacity {
+            messages.pop_front();
+        }
+        messages.push_back(traced);
+    }
+
+    /// The recorded messages, oldest first.
+    pub fn messages(&self) -> Vec<TracedMessage> {
+        self.lock().iter().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// The recorded messages as text, one line per message with the seconds since the unix epoch.
+    pub fn dump(&self) -> String {
+        let mut dump = String::new();
+        for traced in self.lock().iter() {
+            let secs = traced
+                .time
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs_f64();
+            let _ = writeln!(
+                dump,
+                "{secs:.6} {} {}: {}",
+                traced.direction, traced.message_type, traced.message
+            );
+        }
+        dump
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<TracedMessage>> {
+        self.inner
+            .messages
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Wraps the codec of a stream, recording the encoded and decoded messages.
+#[derive(Debug)]
+pub(crate) struct TracingCodec<C> {
+    inner: C,
+    trace: MessageTrace,
+}
+
+impl<C> TracingCodec<C> {
+    pub(crate) fn new(inner: C, trace: MessageTrace) -> Self {
+        Self { inner, trace }
+    }
+}
+
+impl<C> Codec for TracingCodec<C>
+where
+    C: Codec,
+    C::Encode: Debug,
+    C::Decode: Debug,
+{
+    type Encode = C::Encode;
+    type Decode = C::Decode;
+    type Encoder = TracingCoder<C::Encoder>;
+    type Decoder = TracingCoder<C::Decoder>;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        TracingCoder {
+            inner: self.inner.encoder(),
+            trace: self.trace.clone(),
+        }
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        TracingCoder {
+            inner: self.inner.decoder(),
+            trace: self.trace.clone(),
+        }
+    }
+}
+
+/// The encoder or decoder of a [TracingCodec].
+#[derive(Debug)]
+pub(crate) struct TracingCoder<C> {
+    inner: C,
+    trace: MessageTrace,
+}
+
+impl<E> Encoder for TracingCoder<E>
+where
+    E: Encoder<Error = Status>,
+    E::Item: Debug,
+{
+    type Item = E::Item;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        self.trace.record(Direction::Sent, &item);
+        self.inner.encode(item, dst)
+    }
+
+    fn buffer_settings(&self) -> BufferSettings {
+        self.inner.buffer_settings()
+    }
+}
+
+impl<D> Decoder for TracingCoder<D>
+where
+    D: Decoder<Error = Status>,
+    D::Item: Debug,
+{
+    type Item = D::Item;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        let item = self.inner.decode(src)?;
+        if let Some(item) = &item {
+            self.trace.record(Direction::Received, item);
+        }
+        Ok(item)
+    }
+
+    fn buffer_settings(&self) -> BufferSettings {
+        self.inner.buffer_settings()
+    }
+}
@@ -13,14 +13,15 @@ pub struct ConversionError {
 }
 
 impl ConversionError {
-    pub fn new(msg: impl Into<String>) -> Self {
+    pub(crate) fn new(msg: impl Into<String>) -> Self {
         Self { msg: msg.into() }
     }
 }
 
 // Stream messages
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ClientInMsg {
     Sync(Sync),
     StartupDone(StartupDone),
@@ -60,7 +61,7 @@ impl TryFrom<ClientInMsg> for proto::ClientInMessage {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ClientOutMsg {
     pub sync: Option<Sync>,
     pub updates: Vec<UpdateResponse>,
@@ -80,7 +81,22 @@ impl TryFrom<proto::ClientOutMessage> for ClientOutMsg {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<ClientOutMsg> for proto::ClientOutMessage {
+    type Error = ConversionError;
+
+    fn try_from(value: ClientOutMsg) -> Result<Self, Self::Error> {
+        let sync = value.sync.map(proto::Sync::try_from).transpose()?;
+        let updates = value
+            .updates
+            .into_iter()
+            .map(|v| v.try_into())
+            .collect::<Result<Vec<proto::UpdateResponse>, ConversionError>>()?;
+        Ok(Self { sync, updates })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ExporterInMessage {
     Resource(Resource),
     StartupDone(StartupDone),
@@ -124,7 +140,8 @@ impl TryFrom<ExporterInMessage> for proto::ExporterInMessage {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ExporterOutMessage {
     Hello {
         version: String,
@@ -159,7 +176,31 @@ impl TryFrom<proto::ExporterOutMessage> for ExporterOutMessage {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<ExporterOutMessage> for proto::ExporterOutMessage {
+    type Error = ConversionError;
+
+    fn try_from(value: ExporterOutMessage) -> Result<Self, Self::Error> {
+        let kind = match value {
+            ExporterOutMessage::Hello { version } => {
+                proto::exporter_out_message::Kind::Hello(proto::Hello { version })
+            }
+            ExporterOutMessage::ExporterSetAcquiredRequest {
+                group_name,
+                resource_name,
+                place_name,
+            } => proto::exporter_out_message::Kind::SetAcquiredRequest(
+                proto::ExporterSetAcquiredRequest {
+                    group_name,
+                    resource_name,
+                    place_name,
+                },
+            ),
+        };
+        Ok(Self { kind: Some(kind) })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Subscribe {
     pub is_unsubscribe: Option<bool>,
     pub kind: SubscribeKind,
@@ -194,7 +235,8 @@ impl TryFrom<Subscribe> for proto::Subscribe {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum SubscribeKind {
     AllPlaces(bool),
     AllResources(bool),
@@ -224,7 +266,7 @@ impl TryFrom<SubscribeKind> for proto::subscribe::Kind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ExporterResponse {
     pub success: bool,
 }
@@ -249,7 +291,7 @@ impl TryFrom<ExporterResponse> for proto::ExporterResponse {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StartupDone {
     pub version: String,
     pub name: String,
@@ -277,7 +319,7 @@ impl TryFrom<StartupDone> for proto::StartupDone {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Sync {
     pub id: u64,
 }
@@ -298,7 +340,8 @@ impl TryFrom<Sync> for proto::Sync {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum UpdateResponse {
     Resource(Resource),
     DeleteResource(Path),
@@ -323,7 +366,25 @@ impl TryFrom<proto::UpdateResponse> for UpdateResponse {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<UpdateResponse> for proto::UpdateResponse {
+    type Error = ConversionError;
+
+    fn try_from(value: UpdateResponse) -> Result<Self, Self::Error> {
+        let kind = match value {
+            UpdateResponse::Resource(val) => {
+                proto::update_response::Kind::Resource(val.try_into()?)
+            }
+            UpdateResponse::DeleteResource(val) => {
+                proto::update_response::Kind::DelResource(val.try_into()?)
+            }
+            UpdateResponse::Place(val) => proto::update_response::Kind::Place(val.try_into()?),
+            UpdateResponse::DeletePlace(val) => proto::update_response::Kind::DelPlace(val),
+        };
+        Ok(Self { kind: Some(kind) })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Resource {
     pub path: Path,
     pub cls: String,
@@ -446,7 +507,7 @@ impl Path {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum MapValue {
     Bool(bool),
     Int(i64),
@@ -503,8 +564,17 @@ impl TryFrom<MapValue> for proto::MapValue {
 
 // Other
 
-#[derive(Debug, Clone)]
-pub struct Filter(HashMap<String, String>);
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter(pub HashMap<String, String>);
+
+impl Filter {
+    /// Whether a place with the supplied tags is matched, which is the case when it has all tags of the filter.
+    pub fn matches(&self, tags: &HashMap<String, String>) -> bool {
+        self.0
+            .iter()
+            .all(|(key, value)| tags.get(key) == Some(value))
+    }
+}
 
 impl TryFrom<proto::reservation::Filter> for Filter {
     type Error = ConversionError;
@@ -522,7 +592,47 @@ impl TryFrom<Filter> for proto::reservation::Filter {
     }
 }
 
-#[derive(Debug, Clone)]
+/// The state of a reservation, reported as integer in [Reservation::state].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReservationState {
+    Waiting,
+    Allocated,
+    Acquired,
+    Expired,
+    Invalid,
+}
+
+impl TryFrom<i32> for ReservationState {
+    type Error = ConversionError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Waiting),
+            1 => Ok(Self::Allocated),
+            2 => Ok(Self::Acquired),
+            3 => Ok(Self::Expired),
+            4 => Ok(Self::Invalid),
+            _ => Err(ConversionError::new(format!(
+                "Invalid reservation state '{value}'"
+            ))),
+        }
+    }
+}
+
+impl From<ReservationState> for i32 {
+    fn from(value: ReservationState) -> Self {
+        match value {
+            ReservationState::Waiting => 0,
+            ReservationState::Allocated => 1,
+            ReservationState::Acquired => 2,
+            ReservationState::Expired => 3,
+            ReservationState::Invalid => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Reservation {
     pub owner: String,
     pub token: String,
@@ -534,6 +644,21 @@ pub struct Reservation {
     pub timeout: f64,
 }
 
+impl Reservation {
+    /// The state of the reservation, `None` if the coordinator reported an unknown state.
+    pub fn reservation_state(&self) -> Option<ReservationState> {
+        ReservationState::try_from(self.state).ok()
+    }
+
+    /// Whether the reservation is expired or invalid and can only be cancelled to clean it up.
+    pub fn is_stale(&self) -> bool {
+        matches!(
+            self.reservation_state(),
+            Some(ReservationState::Expired | ReservationState::Invalid)
+        )
+    }
+}
+
 impl TryFrom<proto::Reservation> for Reservation {
     type Error = ConversionError;
 
@@ -555,7 +680,29 @@ impl TryFrom<proto::Reservation> for Reservation {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<Reservation> for proto::Reservation {
+    type Error = ConversionError;
+
+    fn try_from(value: Reservation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            owner: value.owner,
+            token: value.token,
+            state: value.state,
+            prio: value.prio,
+            filters: value
+                .filters
+                .into_iter()
+                .map(|f| Ok((f.0, f.1.try_into()?)))
+                .collect::<Result<HashMap<String, proto::reservation::Filter>, ConversionError>>(
+                )?,
+            allocations: value.allocations,
+            created: value.created,
+            timeout: value.timeout,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Place {
     pub name: String,
     pub aliases: Vec<String>,
@@ -594,7 +741,31 @@ impl TryFrom<proto::Place> for Place {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<Place> for proto::Place {
+    type Error = ConversionError;
+
+    fn try_from(value: Place) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name,
+            aliases: value.aliases,
+            comment: value.comment,
+            tags: value.tags,
+            matches: value
+                .matches
+                .into_iter()
+                .map(proto::ResourceMatch::try_from)
+                .collect::<Result<Vec<proto::ResourceMatch>, ConversionError>>()?,
+            acquired: value.acquired,
+            acquired_resources: value.acquired_resources,
+            allowed: value.allowed,
+            created: value.created,
+            changed: value.changed,
+            reservation: value.reservation,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ResourceMatch {
     pub exporter: String,
     pub group: String,
@@ -617,6 +788,20 @@ impl TryFrom<proto::ResourceMatch> for ResourceMatch {
     }
 }
 
+impl TryFrom<ResourceMatch> for proto::ResourceMatch {
+    type Error = ConversionError;
+
+    fn try_from(value: ResourceMatch) -> Result<Self, Self::Error> {
+        Ok(Self {
+            exporter: value.exporter,
+            group: value.group,
+            cls: value.cls,
+            name: value.name,
+            rename: value.rename,
+        })
+    }
+}
+
 impl ResourceMatch {
     pub fn numeric_cmp(&self, other: &Self) -> Ordering {
         let exporter_name_ord = numeric_sort::cmp(&self.exporter, &other.exporter);
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+/// The labgrid release the protocol definition of this client was taken from.
+///
+/// It is sent to the coordinator in the startup message of the client stream, like labgrid clients send their version.
+pub const PROTOCOL_VERSION: &str = "24.0";
+
+/// Time the coordinator has to send the hello message with its version, see [crate::LabgridGrpcClient::coordinator_version].
+///
+/// Short, since coordinators answer immediately and connecting should not wait for coordinators not reporting it.
+pub const HELLO_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The first labgrid major release with the gRPC coordinator, earlier releases communicate through crossbar.
+pub const MIN_GRPC_MAJOR: u32 = 24;
+
+/// The compatibility of the client with labgrid major releases, releases not listed are [Compatibility::Untested].
+pub const COMPATIBILITY_MATRIX: &[(RangeInclusive<u32>, Compatibility)] = &[
+    (0..=(MIN_GRPC_MAJOR - 1), Compatibility::Incompatible),
+    (MIN_GRPC_MAJOR..=25, Compatibility::Compatible),
+];
+
+/// Whether a labgrid release speaks the same protocol as the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compatibility {
+    Compatible,
+    /// Newer releases or versions that can't be parsed, they might have changed the protocol.
+    Untested,
+    /// Releases before the gRPC coordinator.
+    Incompatible,
+}
+
+/// A labgrid release version, e.g. `24.0.1` or `25.1.dev12+g4a8e2c1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LabgridVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl LabgridVersion {
+    /// Parses the major and minor version, ignoring the patch and pre-release or local parts.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts
+            .next()
+            .map(|minor| {
+                let digits = minor
+                    .find(|c: char| !c.is_ascii_digit())
+                    .map_or(minor, |end| &minor[..end]);
+                digits.parse().ok()
+            })
+            .unwrap_or(Some(0))?;
+        Some(Self { major, minor })
+    }
+
+    /// Looks up the release in the [COMPATIBILITY_MATRIX].
+    pub fn compatibility(&self) -> Compatibility {
+        COMPATIBILITY_MATRIX
+            .iter()
+            .find(|(majors, _)| majors.contains(&self.major))
+            .map_or(Compatibility::Untested, |(_, compatibility)| *compatibility)
+    }
+}
+
+impl std::fmt::Display for LabgridVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// The compatibility of the labgrid release with the `version` string, [Compatibility::Untested] if it can't be parsed.
+pub fn compatibility(version: &str) -> Compatibility {
+    LabgridVersion::parse(version)
+        .map_or(Compatibility::Untested, |version| version.compatibility())
+}
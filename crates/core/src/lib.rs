@@ -2,16 +2,86 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+//! gRPC client library for the [labgrid](https://github.com/labgrid-project/labgrid) coordinator.
+//!
+//! The [LabgridGrpcClient] wraps the coordinator's unary calls and streams,
+//! converting the protobuf messages into the types in [types].
+//! Most applications only need the items in the [prelude].
+//!
+//! ```no_run
+//! use labgrid_ui_core::prelude::*;
+//! use std::time::Duration;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), GrpcClientError> {
+//!     let mut client = LabgridGrpcClient::builder()
+//!         .timeouts(ClientTimeouts {
+//!             connect: Some(Duration::from_secs(5)),
+//!             rpc: Some(Duration::from_secs(10)),
+//!         })
+//!         .retry_policy(RetryPolicy::default())
+//!         .connect("localhost:20408")
+//!         .await?;
+//!     for place in client.get_places().await? {
+//!         println!("{}: acquired by {:?}", place.name, place.acquired);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Enums mirroring protocol messages and the error types are `#[non_exhaustive]`,
+//! so that messages added to the protocol can be supported without a breaking release.
+
 // Modules
+/// Reservation filter expression language.
+pub mod filter;
 pub(crate) mod grpc;
+/// Operational metrics of a client and their export.
+#[cfg(feature = "metrics")]
+pub mod metrics;
+/// In-process mock coordinator for integration testing clients.
+#[cfg(feature = "test-support")]
+pub mod mock;
+/// Mirroring place and resource state to an MQTT broker.
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+/// Textual resource paths and place match patterns.
+pub mod pattern;
+/// Webhook notifications on coordinator events.
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 
 // Re-Exports
+/// Credentials sent with the calls to the coordinator.
+pub use grpc::auth;
 /// Grpc client error types.
 pub use grpc::error;
 /// protobuf auto-generated code.
 pub use grpc::proto;
+/// Retry policy of unary calls.
+pub use grpc::retry::RetryPolicy;
+/// Recording the protobuf messages exchanged with the coordinator.
+pub use grpc::trace;
 /// Grpc rpc types that convert from/to protobuf auto-generated types.
 pub use grpc::types;
+/// Protocol versions and the compatibility with labgrid releases.
+pub use grpc::version;
+/// Timeouts of the client connection and calls.
+pub use grpc::ClientTimeouts;
 /// Labgrid gRPC client implementation.
 pub use grpc::LabgridGrpcClient;
+/// Builder for a client with timeouts or retries.
+pub use grpc::LabgridGrpcClientBuilder;
 pub use tonic;
+
+/// The commonly used client, error and coordinator types, for glob importing.
+pub mod prelude {
+    pub use crate::auth::{CallCredentials, TokenSource};
+    pub use crate::error::{ErrorKind, GrpcClientError};
+    pub use crate::filter::FilterExpr;
+    pub use crate::pattern::MatchPattern;
+    pub use crate::types::{
+        MapValue, Path, Place, Reservation, ReservationState, Resource, ResourceMatch,
+    };
+    pub use crate::{ClientTimeouts, LabgridGrpcClient, LabgridGrpcClientBuilder, RetryPolicy};
+}
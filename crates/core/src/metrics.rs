@@ -0,0 +1,239 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Operational metrics of a client, for monitoring unattended deployments.
+//!
+//! The [Metrics] are recorded by the application from what it observes of the coordinator,
+//! and rendered in the [Prometheus text format](https://prometheus.io/docs/instrumenting/exposition_formats/).
+//! They are exported through an HTTP endpoint or periodically written to a file,
+//! for example for the textfile collector of the node exporter, see [MetricsExport].
+
+use crate::types::Place;
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::{TokioIo, TokioTimer};
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::io;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tracing::debug;
+
+/// Prefix of all metric names.
+const PREFIX: &str = "labgrid_ui";
+/// The content type of the Prometheus text format.
+const CONTENT_TYPE_TEXT: &str = "text/plain; version=0.0.4; charset=utf-8";
+/// Time a client has to send the headers of its request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Counters and gauges of a client, shared between the recording application and the exports.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    connected: AtomicBool,
+    /// Whether the client was connected before, to count reconnects.
+    was_connected: AtomicBool,
+    places: AtomicU64,
+    resources: AtomicU64,
+    place_acquires: AtomicU64,
+    place_releases: AtomicU64,
+    grpc_errors: AtomicU64,
+    reconnects: AtomicU64,
+}
+
+impl Metrics {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Records that the client (re)connected to the coordinator.
+    pub fn record_connected(&self) {
+        self.connected.store(true, Ordering::Relaxed);
+        if self.was_connected.swap(true, Ordering::Relaxed) {
+            self.reconnects.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Records that the connection to the coordinator was lost or closed.
+    pub fn record_disconnected(&self) {
+        self.connected.store(false, Ordering::Relaxed);
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    pub fn set_places(&self, count: usize) {
+        self.places.store(count as u64, Ordering::Relaxed);
+    }
+
+    pub fn set_resources(&self, count: usize) {
+        self.resources.store(count as u64, Ordering::Relaxed);
+    }
+
+    /// Records the acquisition or release of a place that changed from `prev` to `place`.
+    ///
+    /// Places that were not known before are not counted, so that the initial sync doesn't count as acquisitions.
+    /// A place that was acquired by another user in the meantime counts as both.
+    pub fn record_place_update(&self, prev: Option<&Place>, place: &Place) {
+        let Some(prev) = prev else {
+            return;
+        };
+        if prev.acquired == place.acquired {
+            return;
+        }
+        if prev.acquired.is_some() {
+            self.place_releases.fetch_add(1, Ordering::Relaxed);
+        }
+        if place.acquired.is_some() {
+            self.place_acquires.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Records a failed call or a lost stream.
+    pub fn record_grpc_error(&self) {
+        self.grpc_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Renders the metrics in the Prometheus text format.
+    pub fn render(&self) -> String {
+        let metrics: [(&str, &str, &str, u64); 7] = [
+            (
+                "connected",
+                "gauge",
+                "Whether the client is connected to the coordinator.",
+                self.connected.load(Ordering::Relaxed).into(),
+            ),
+            (
+                "places",
+                "gauge",
+                "Number of places known to the client.",
+                self.places.load(Ordering::Relaxed),
+            ),
+            (
+                "resources",
+                "gauge",
+                "Number of resources known to the client.",
+                self.resources.load(Ordering::Relaxed),
+            ),
+            (
+                "place_acquires_total",
+                "counter",
+                "Observed acquisitions of places.",
+                self.place_acquires.load(Ordering::Relaxed),
+            ),
+            (
+                "place_releases_total",
+                "counter",
+                "Observed releases of places.",
+                self.place_releases.load(Ordering::Relaxed),
+            ),
+            (
+                "grpc_errors_total",
+                "counter",
+                "Failed calls and lost streams.",
+                self.grpc_errors.load(Ordering::Relaxed),
+            ),
+            (
+                "reconnects_total",
+                "counter",
+                "Reconnects to the coordinator after the first connect.",
+                self.reconnects.load(Ordering::Relaxed),
+            ),
+        ];
+        let mut out = String::new();
+        for (name, kind, help, value) in metrics {
+            // Writing to a string can't fail
+            let _ = write!(
+                out,
+                "# HELP {PREFIX}_{name} {help}\n# TYPE {PREFIX}_{name} {kind}\n{PREFIX}_{name} {value}\n"
+            );
+        }
+        out
+    }
+}
+
+/// Where metrics are exported to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetricsExport {
+    /// An HTTP endpoint serving the metrics at `/metrics`.
+    Http(SocketAddr),
+    /// A file that is rewritten every `interval`.
+    ///
+    /// The file is replaced atomically, so readers never see a partially written file.
+    File { path: PathBuf, interval: Duration },
+}
+
+impl MetricsExport {
+    /// Exports the metrics until an error occurs.
+    ///
+    /// Errors of single HTTP requests are only logged.
+    pub async fn run(self, metrics: Arc<Metrics>) -> io::Result<()> {
+        match self {
+            Self::Http(addr) => serve(metrics, TcpListener::bind(addr).await?).await,
+            Self::File { path, interval } => {
+                let mut interval = tokio::time::interval(interval);
+                loop {
+                    interval.tick().await;
+                    write_file(&metrics, &path).await?;
+                }
+            }
+        }
+    }
+}
+
+/// Serves the metrics at `/metrics` to HTTP clients connecting to `listener`.
+///
+/// Runs until accepting a connection fails.
+pub async fn serve(metrics: Arc<Metrics>, listener: TcpListener) -> io::Result<()> {
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            let service = service_fn(move |request: Request<hyper::body::Incoming>| {
+                let response = respond(&metrics, &request);
+                async move { Ok::<_, Infallible>(response) }
+            });
+            if let Err(error) = http1::Builder::new()
+                .timer(TokioTimer::new())
+                .header_read_timeout(REQUEST_TIMEOUT)
+                .keep_alive(false)
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!(?error, %peer, "Serve metrics connection");
+            }
+        });
+    }
+}
+
+/// Writes the rendered metrics to `path`, replacing it atomically through a temporary file next to it.
+pub async fn write_file(metrics: &Metrics, path: &std::path::Path) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    tokio::fs::write(&tmp, metrics.render()).await?;
+    tokio::fs::rename(&tmp, path).await
+}
+
+fn respond<B>(metrics: &Metrics, request: &Request<B>) -> Response<Full<Bytes>> {
+    let (status, body) = match (request.method(), request.uri().path()) {
+        (&Method::GET, "/metrics") => (StatusCode::OK, metrics.render()),
+        (&Method::GET, _) => (StatusCode::NOT_FOUND, String::new()),
+        _ => (StatusCode::METHOD_NOT_ALLOWED, String::new()),
+    };
+    let mut response = Response::new(Full::new(Bytes::from(body)));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static(CONTENT_TYPE_TEXT),
+    );
+    response
+}
@@ -0,0 +1,703 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::grpc::proto::coordinator_server::{Coordinator, CoordinatorServer};
+use crate::pattern::MatchPattern;
+use crate::proto;
+use crate::types::{
+    ClientInMsg, ConversionError, Filter, Place, Reservation, ReservationState, Resource,
+    ResourceMatch, SubscribeKind, UpdateResponse,
+};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::net::{Ipv4Addr, SocketAddr};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::{TcpListenerStream, UnboundedReceiverStream};
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
+use tracing::error;
+
+/// The owner of places acquired and reservations created before a client sent its startup message.
+const UNKNOWN_IDENTITY: &str = "unknown/unknown";
+/// The seconds until reservations created through the mock expire.
+const RESERVATION_TIMEOUT_SECS: f64 = 60.;
+
+type ClientOutSender = mpsc::UnboundedSender<Result<proto::ClientOutMessage, Status>>;
+
+#[derive(Debug, Default)]
+struct MockState {
+    places: BTreeMap<String, Place>,
+    resources: Vec<Resource>,
+    reservations: Vec<Reservation>,
+    /// Errors returned by the next calls of the rpc methods, keyed by method name.
+    failures: HashMap<&'static str, VecDeque<Status>>,
+    /// The senders of the open client streams, keyed by stream id.
+    streams: BTreeMap<u64, ClientOutSender>,
+    next_stream_id: u64,
+    next_reservation_token: u64,
+    /// The name sent in the last startup message, attributed as owner of acquired places.
+    identity: Option<String>,
+    /// The labgrid version reported to exporters, the exporter stream is unimplemented without it.
+    version: Option<String>,
+    received: Vec<ClientInMsg>,
+    calls: Vec<&'static str>,
+    /// Metadata entries that calls must carry, like an authenticating proxy in front of the coordinator.
+    required_metadata: Vec<(String, String)>,
+}
+
+impl MockState {
+    fn identity(&self) -> String {
+        self.identity
+            .clone()
+            .unwrap_or_else(|| UNKNOWN_IDENTITY.to_string())
+    }
+
+    /// Sends the updates to all open client streams.
+    fn broadcast(&mut self, updates: Vec<UpdateResponse>) {
+        let Ok(msg) = client_out_msg(None, updates) else {
+            return;
+        };
+        self.streams
+            .retain(|_, sender| sender.send(Ok(msg.clone())).is_ok());
+    }
+
+    fn place_mut(&mut self, name: &str) -> Result<&mut Place, Status> {
+        self.places
+            .get_mut(name)
+            .ok_or_else(|| Status::not_found(format!("Place '{name}' does not exist")))
+    }
+
+    /// Applies the change to the place and sends the changed place to the client streams.
+    fn change_place(
+        &mut self,
+        name: &str,
+        change: impl FnOnce(&mut Place) -> Result<(), Status>,
+    ) -> Result<(), Status> {
+        let place = self.place_mut(name)?;
+        change(place)?;
+        place.changed = now();
+        let update = UpdateResponse::Place(place.clone());
+        self.broadcast(vec![update]);
+        Ok(())
+    }
+
+    /// Handles a message received through a client stream, returning the response.
+    fn handle_client_in(&mut self, msg: ClientInMsg) -> Option<proto::ClientOutMessage> {
+        self.received.push(msg.clone());
+        let updates = match msg {
+            ClientInMsg::Sync(sync) => return client_out_msg(Some(sync.id), vec![]).ok(),
+            ClientInMsg::StartupDone(startup) => {
+                self.identity = Some(startup.name);
+                return None;
+            }
+            ClientInMsg::Subscribe(subscribe) if subscribe.is_unsubscribe == Some(true) => {
+                return None;
+            }
+            ClientInMsg::Subscribe(subscribe) => match subscribe.kind {
+                SubscribeKind::AllPlaces(true) => self
+                    .places
+                    .values()
+                    .cloned()
+                    .map(UpdateResponse::Place)
+                    .collect(),
+                SubscribeKind::AllResources(true) => self
+                    .resources
+                    .iter()
+                    .cloned()
+                    .map(UpdateResponse::Resource)
+                    .collect(),
+                _ => return None,
+            },
+        };
+        client_out_msg(None, updates).ok()
+    }
+}
+
+/// An in-process coordinator for testing clients, serving scriptable places, resources and reservations.
+///
+/// Clones share the state, so a clone can be kept to script and inspect the coordinator while it is served.
+/// Changes made through the unary calls and the `set_*`/`delete_*` methods are sent to the open client streams,
+/// like the labgrid coordinator does.
+#[derive(Debug, Clone, Default)]
+pub struct MockCoordinator {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockCoordinator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_place(self, place: Place) -> Self {
+        self.state().places.insert(place.name.clone(), place);
+        self
+    }
+
+    pub fn with_resource(self, resource: Resource) -> Self {
+        self.state().resources.push(resource);
+        self
+    }
+
+    pub fn with_reservation(self, reservation: Reservation) -> Self {
+        self.state().reservations.push(reservation);
+        self
+    }
+
+    /// Reports the labgrid `version` in the hello message of exporter streams.
+    pub fn with_version(self, version: &str) -> Self {
+        self.state().version = Some(version.to_string());
+        self
+    }
+
+    /// Rejects calls without the metadata entry as unauthenticated, e.g. `("authorization", "Bearer token")`.
+    pub fn with_required_metadata(self, key: &str, value: &str) -> Self {
+        self.state()
+            .required_metadata
+            .push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds or replaces the place.
+    pub fn set_place(&self, place: Place) {
+        let mut state = self.state();
+        state.places.insert(place.name.clone(), place.clone());
+        state.broadcast(vec![UpdateResponse::Place(place)]);
+    }
+
+    pub fn delete_place(&self, name: &str) {
+        let mut state = self.state();
+        if state.places.remove(name).is_some() {
+            state.broadcast(vec![UpdateResponse::DeletePlace(name.to_string())]);
+        }
+    }
+
+    /// Adds or replaces the resource with the same path.
+    pub fn set_resource(&self, resource: Resource) {
+        let mut state = self.state();
+        state.resources.retain(|r| r.path != resource.path);
+        state.resources.push(resource.clone());
+        state.broadcast(vec![UpdateResponse::Resource(resource)]);
+    }
+
+    pub fn delete_resource(&self, path: &crate::types::Path) {
+        let mut state = self.state();
+        state.resources.retain(|r| r.path != *path);
+        state.broadcast(vec![UpdateResponse::DeleteResource(path.clone())]);
+    }
+
+    /// Sends the updates to the open client streams without changing the state.
+    pub fn push_updates(&self, updates: Vec<UpdateResponse>) {
+        self.state().broadcast(updates);
+    }
+
+    /// Ends all open client streams.
+    pub fn close_streams(&self) {
+        self.state().streams.clear();
+    }
+
+    /// Fails the next call of the rpc `method` (e.g. `"AcquirePlace"`) with the status.
+    ///
+    /// Calling it multiple times fails the following calls in order.
+    pub fn fail_next(&self, method: &'static str, status: Status) {
+        self.state()
+            .failures
+            .entry(method)
+            .or_default()
+            .push_back(status);
+    }
+
+    pub fn places(&self) -> Vec<Place> {
+        self.state().places.values().cloned().collect()
+    }
+
+    pub fn reservations(&self) -> Vec<Reservation> {
+        self.state().reservations.clone()
+    }
+
+    /// The messages received through client streams.
+    pub fn received_messages(&self) -> Vec<ClientInMsg> {
+        self.state().received.clone()
+    }
+
+    /// The names of the received calls, in order, including the failed ones.
+    pub fn calls(&self) -> Vec<&'static str> {
+        self.state().calls.clone()
+    }
+
+    /// Serves the coordinator on a free local port until the returned server is dropped.
+    pub async fn serve(&self) -> std::io::Result<MockServer> {
+        let listener = tokio::net::TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let address = listener.local_addr()?;
+        let mock = self.clone();
+        let service = CoordinatorServer::with_interceptor(self.clone(), move |request| {
+            mock.check_metadata(request)
+        });
+        let task = tokio::spawn(async move {
+            if let Err(error) = tonic::transport::Server::builder()
+                .add_service(service)
+                .serve_with_incoming(TcpListenerStream::new(listener))
+                .await
+            {
+                error!(?error, "Serving mock coordinator");
+            }
+        });
+        Ok(MockServer { address, task })
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn check_metadata(&self, request: Request<()>) -> Result<Request<()>, Status> {
+        let state = self.state();
+        for (key, value) in &state.required_metadata {
+            if request.metadata().get(key.as_str()).map(|v| v.as_bytes()) != Some(value.as_bytes())
+            {
+                return Err(Status::unauthenticated(format!("Missing metadata '{key}'")));
+            }
+        }
+        Ok(request)
+    }
+
+    /// Records the call and returns the state, or the error scripted for the call.
+    fn begin(&self, method: &'static str) -> Result<MutexGuard<'_, MockState>, Status> {
+        let mut state = self.state();
+        state.calls.push(method);
+        match state.failures.get_mut(method).and_then(VecDeque::pop_front) {
+            Some(status) => Err(status),
+            None => Ok(state),
+        }
+    }
+}
+
+/// A served [MockCoordinator], stops serving when dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    address: SocketAddr,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl MockServer {
+    /// The address to connect to, in the form `host:port`.
+    pub fn address(&self) -> String {
+        self.address.to_string()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[tonic::async_trait]
+impl Coordinator for MockCoordinator {
+    type ClientStreamStream =
+        Pin<Box<dyn Stream<Item = Result<proto::ClientOutMessage, Status>> + Send + 'static>>;
+    type ExporterStreamStream =
+        Pin<Box<dyn Stream<Item = Result<proto::ExporterOutMessage, Status>> + Send + 'static>>;
+
+    async fn client_stream(
+        &self,
+        request: Request<Streaming<proto::ClientInMessage>>,
+    ) -> Result<Response<Self::ClientStreamStream>, Status> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let id = {
+            let mut state = self.begin("ClientStream")?;
+            let id = state.next_stream_id;
+            state.next_stream_id += 1;
+            state.streams.insert(id, sender);
+            id
+        };
+        let mut in_stream = request.into_inner();
+        let mock = self.clone();
+        tokio::spawn(async move {
+            while let Some(Ok(msg)) = in_stream.next().await {
+                let Ok(msg) = ClientInMsg::try_from(msg) else {
+                    continue;
+                };
+                let mut state = mock.state();
+                let response = state.handle_client_in(msg);
+                // The stream was closed through the mock
+                let Some(sender) = state.streams.get(&id) else {
+                    break;
+                };
+                if let Some(response) = response {
+                    if sender.send(Ok(response)).is_err() {
+                        break;
+                    }
+                }
+            }
+            // Like the coordinator, end the out stream once the client closed its in stream
+            mock.state().streams.remove(&id);
+        });
+        Ok(Response::new(Box::pin(UnboundedReceiverStream::new(
+            receiver,
+        ))))
+    }
+
+    async fn exporter_stream(
+        &self,
+        _request: Request<Streaming<proto::ExporterInMessage>>,
+    ) -> Result<Response<Self::ExporterStreamStream>, Status> {
+        let Some(version) = self.begin("ExporterStream")?.version.clone() else {
+            return Err(Status::unimplemented(
+                "The mock coordinator does not support exporters",
+            ));
+        };
+        // Like the coordinator, greet with the version before anything was received
+        let hello = proto::ExporterOutMessage {
+            kind: Some(proto::exporter_out_message::Kind::Hello(proto::Hello {
+                version,
+            })),
+        };
+        Ok(Response::new(Box::pin(tokio_stream::iter([
+            Ok(hello),
+            Err(Status::unimplemented(
+                "The mock coordinator does not support exporters",
+            )),
+        ]))))
+    }
+
+    async fn add_place(
+        &self,
+        request: Request<proto::AddPlaceRequest>,
+    ) -> Result<Response<proto::AddPlaceResponse>, Status> {
+        let mut state = self.begin("AddPlace")?;
+        let name = request.into_inner().name;
+        if name.is_empty() {
+            return Err(Status::invalid_argument("Place name must not be empty"));
+        }
+        if state.places.contains_key(&name) {
+            return Err(Status::already_exists(format!(
+                "Place '{name}' already exists"
+            )));
+        }
+        let now = now();
+        let place = Place {
+            name: name.clone(),
+            aliases: vec![],
+            comment: String::new(),
+            tags: HashMap::new(),
+            matches: vec![],
+            acquired: None,
+            acquired_resources: vec![],
+            allowed: vec![],
+            created: now,
+            changed: now,
+            reservation: None,
+        };
+        state.places.insert(name, place.clone());
+        state.broadcast(vec![UpdateResponse::Place(place)]);
+        Ok(Response::new(proto::AddPlaceResponse {}))
+    }
+
+    async fn delete_place(
+        &self,
+        request: Request<proto::DeletePlaceRequest>,
+    ) -> Result<Response<proto::DeletePlaceResponse>, Status> {
+        let mut state = self.begin("DeletePlace")?;
+        let name = request.into_inner().name;
+        state.place_mut(&name)?;
+        state.places.remove(&name);
+        state.broadcast(vec![UpdateResponse::DeletePlace(name)]);
+        Ok(Response::new(proto::DeletePlaceResponse {}))
+    }
+
+    async fn get_places(
+        &self,
+        _request: Request<proto::GetPlacesRequest>,
+    ) -> Result<Response<proto::GetPlacesResponse>, Status> {
+        let state = self.begin("GetPlaces")?;
+        let places = state
+            .places
+            .values()
+            .cloned()
+            .map(to_proto)
+            .collect::<Result<_, _>>()?;
+        Ok(Response::new(proto::GetPlacesResponse { places }))
+    }
+
+    async fn add_place_alias(
+        &self,
+        request: Request<proto::AddPlaceAliasRequest>,
+    ) -> Result<Response<proto::AddPlaceAliasResponse>, Status> {
+        let mut state = self.begin("AddPlaceAlias")?;
+        let request = request.into_inner();
+        state.change_place(&request.placename, |place| {
+            if place.aliases.contains(&request.alias) {
+                return Err(Status::failed_precondition("Alias already exists"));
+            }
+            place.aliases.push(request.alias);
+            Ok(())
+        })?;
+        Ok(Response::new(proto::AddPlaceAliasResponse {}))
+    }
+
+    async fn delete_place_alias(
+        &self,
+        request: Request<proto::DeletePlaceAliasRequest>,
+    ) -> Result<Response<proto::DeletePlaceAliasResponse>, Status> {
+        let mut state = self.begin("DeletePlaceAlias")?;
+        let request = request.into_inner();
+        state.change_place(&request.placename, |place| {
+            let len = place.aliases.len();
+            place.aliases.retain(|alias| *alias != request.alias);
+            if place.aliases.len() == len {
+                return Err(Status::failed_precondition("Alias does not exist"));
+            }
+            Ok(())
+        })?;
+        Ok(Response::new(proto::DeletePlaceAliasResponse {}))
+    }
+
+    async fn set_place_tags(
+        &self,
+        request: Request<proto::SetPlaceTagsRequest>,
+    ) -> Result<Response<proto::SetPlaceTagsResponse>, Status> {
+        let mut state = self.begin("SetPlaceTags")?;
+        let request = request.into_inner();
+        state.change_place(&request.placename, |place| {
+            // Like the coordinator, tags with empty values are removed
+            for (key, value) in request.tags {
+                if value.is_empty() {
+                    place.tags.remove(&key);
+                } else {
+                    place.tags.insert(key, value);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(Response::new(proto::SetPlaceTagsResponse {}))
+    }
+
+    async fn set_place_comment(
+        &self,
+        request: Request<proto::SetPlaceCommentRequest>,
+    ) -> Result<Response<proto::SetPlaceCommentResponse>, Status> {
+        let mut state = self.begin("SetPlaceComment")?;
+        let request = request.into_inner();
+        state.change_place(&request.placename, |place| {
+            place.comment = request.comment;
+            Ok(())
+        })?;
+        Ok(Response::new(proto::SetPlaceCommentResponse {}))
+    }
+
+    async fn add_place_match(
+        &self,
+        request: Request<proto::AddPlaceMatchRequest>,
+    ) -> Result<Response<proto::AddPlaceMatchResponse>, Status> {
+        let mut state = self.begin("AddPlaceMatch")?;
+        let request = request.into_inner();
+        let resource_match = parse_match(&request.pattern, request.rename)?;
+        state.change_place(&request.placename, |place| {
+            if place.matches.contains(&resource_match) {
+                return Err(Status::failed_precondition("Match already exists"));
+            }
+            place.matches.push(resource_match);
+            Ok(())
+        })?;
+        Ok(Response::new(proto::AddPlaceMatchResponse {}))
+    }
+
+    async fn delete_place_match(
+        &self,
+        request: Request<proto::DeletePlaceMatchRequest>,
+    ) -> Result<Response<proto::DeletePlaceMatchResponse>, Status> {
+        let mut state = self.begin("DeletePlaceMatch")?;
+        let request = request.into_inner();
+        let resource_match = parse_match(&request.pattern, request.rename)?;
+        state.change_place(&request.placename, |place| {
+            let len = place.matches.len();
+            place.matches.retain(|m| *m != resource_match);
+            if place.matches.len() == len {
+                return Err(Status::failed_precondition("Match does not exist"));
+            }
+            Ok(())
+        })?;
+        Ok(Response::new(proto::DeletePlaceMatchResponse {}))
+    }
+
+    async fn acquire_place(
+        &self,
+        request: Request<proto::AcquirePlaceRequest>,
+    ) -> Result<Response<proto::AcquirePlaceResponse>, Status> {
+        let mut state = self.begin("AcquirePlace")?;
+        let identity = state.identity();
+        state.change_place(&request.into_inner().placename, |place| {
+            if let Some(owner) = &place.acquired {
+                return Err(Status::failed_precondition(format!(
+                    "Place is already acquired by '{owner}'"
+                )));
+            }
+            place.acquired = Some(identity);
+            Ok(())
+        })?;
+        Ok(Response::new(proto::AcquirePlaceResponse {}))
+    }
+
+    async fn release_place(
+        &self,
+        request: Request<proto::ReleasePlaceRequest>,
+    ) -> Result<Response<proto::ReleasePlaceResponse>, Status> {
+        let mut state = self.begin("ReleasePlace")?;
+        let request = request.into_inner();
+        state.change_place(&request.placename, |place| {
+            match (&place.acquired, &request.fromuser) {
+                (None, _) => return Err(Status::failed_precondition("Place is not acquired")),
+                (Some(owner), Some(from_user)) if owner != from_user => {
+                    return Err(Status::failed_precondition(format!(
+                        "Place is acquired by '{owner}', not by '{from_user}'"
+                    )));
+                }
+                _ => {}
+            }
+            place.acquired = None;
+            place.allowed.clear();
+            Ok(())
+        })?;
+        Ok(Response::new(proto::ReleasePlaceResponse {}))
+    }
+
+    async fn allow_place(
+        &self,
+        request: Request<proto::AllowPlaceRequest>,
+    ) -> Result<Response<proto::AllowPlaceResponse>, Status> {
+        let mut state = self.begin("AllowPlace")?;
+        let request = request.into_inner();
+        state.change_place(&request.placename, |place| {
+            if place.acquired.is_none() {
+                return Err(Status::failed_precondition("Place is not acquired"));
+            }
+            if !place.allowed.contains(&request.user) {
+                place.allowed.push(request.user);
+            }
+            Ok(())
+        })?;
+        Ok(Response::new(proto::AllowPlaceResponse {}))
+    }
+
+    async fn create_reservation(
+        &self,
+        request: Request<proto::CreateReservationRequest>,
+    ) -> Result<Response<proto::CreateReservationResponse>, Status> {
+        let mut state = self.begin("CreateReservation")?;
+        let request = request.into_inner();
+        if request.filters.is_empty() {
+            return Err(Status::invalid_argument("Reservation has no filters"));
+        }
+        let filters = request
+            .filters
+            .into_iter()
+            .map(|(name, filter)| Ok((name, Filter::try_from(filter)?)))
+            .collect::<Result<HashMap<String, Filter>, ConversionError>>()
+            .map_err(conversion_status)?;
+        state.next_reservation_token += 1;
+        let created = now();
+        let reservation = Reservation {
+            owner: state.identity(),
+            token: format!("MOCK{}", state.next_reservation_token),
+            state: ReservationState::Waiting.into(),
+            prio: request.prio,
+            filters,
+            allocations: HashMap::new(),
+            created,
+            timeout: created + RESERVATION_TIMEOUT_SECS,
+        };
+        state.reservations.push(reservation.clone());
+        Ok(Response::new(proto::CreateReservationResponse {
+            reservation: Some(to_proto(reservation)?),
+        }))
+    }
+
+    async fn cancel_reservation(
+        &self,
+        request: Request<proto::CancelReservationRequest>,
+    ) -> Result<Response<proto::CancelReservationResponse>, Status> {
+        let mut state = self.begin("CancelReservation")?;
+        let token = request.into_inner().token;
+        let len = state.reservations.len();
+        state.reservations.retain(|r| r.token != token);
+        if state.reservations.len() == len {
+            return Err(Status::failed_precondition(format!(
+                "Reservation '{token}' does not exist"
+            )));
+        }
+        Ok(Response::new(proto::CancelReservationResponse {}))
+    }
+
+    async fn poll_reservation(
+        &self,
+        request: Request<proto::PollReservationRequest>,
+    ) -> Result<Response<proto::PollReservationResponse>, Status> {
+        let state = self.begin("PollReservation")?;
+        let token = request.into_inner().token;
+        let reservation = state
+            .reservations
+            .iter()
+            .find(|r| r.token == token)
+            .cloned()
+            .ok_or_else(|| {
+                Status::failed_precondition(format!("Reservation '{token}' does not exist"))
+            })?;
+        Ok(Response::new(proto::PollReservationResponse {
+            reservation: Some(to_proto(reservation)?),
+        }))
+    }
+
+    async fn get_reservations(
+        &self,
+        _request: Request<proto::GetReservationsRequest>,
+    ) -> Result<Response<proto::GetReservationsResponse>, Status> {
+        let state = self.begin("GetReservations")?;
+        let reservations = state
+            .reservations
+            .iter()
+            .cloned()
+            .map(to_proto)
+            .collect::<Result<_, _>>()?;
+        Ok(Response::new(proto::GetReservationsResponse {
+            reservations,
+        }))
+    }
+}
+
+fn now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
+
+fn conversion_status(error: ConversionError) -> Status {
+    Status::internal(error.to_string())
+}
+
+fn to_proto<T, P: TryFrom<T, Error = ConversionError>>(value: T) -> Result<P, Status> {
+    P::try_from(value).map_err(conversion_status)
+}
+
+fn client_out_msg(
+    sync_id: Option<u64>,
+    updates: Vec<UpdateResponse>,
+) -> Result<proto::ClientOutMessage, Status> {
+    Ok(proto::ClientOutMessage {
+        sync: sync_id.map(|id| proto::Sync { id }),
+        updates: updates
+            .into_iter()
+            .map(to_proto)
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Parses a match pattern in the form `exporter/group/cls[/name]`.
+fn parse_match(pattern: &str, rename: Option<String>) -> Result<ResourceMatch, Status> {
+    let pattern = pattern
+        .parse::<MatchPattern>()
+        .map_err(|e| Status::invalid_argument(e.to_string()))?;
+    Ok(pattern.into_resource_match(rename.filter(|rename| !rename.is_empty())))
+}
@@ -0,0 +1,584 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Mirroring the state of places and resources to an MQTT broker.
+//!
+//! A [MqttStateMirror] derives retained [MqttMessage]s from the updates of the client stream,
+//! only for states that actually changed. They are handed to a [MqttPublisher], whose [MqttConnection]
+//! keeps the connection to the broker and publishes them with QoS 0 (MQTT 3.1.1):
+//!
+//! - `<prefix>/status`: `online` or `offline`, the latter is also the last will of the connection
+//! - `<prefix>/places/<name>/state`: `free` or `acquired`
+//! - `<prefix>/places/<name>/acquired_by`: the `host/user` that acquired the place, empty when free
+//! - `<prefix>/resources/<exporter>/<group>/<resource>/state`: `available`, `unavailable` or `acquired`
+//!
+//! Topics of removed places and resources are cleared by publishing empty retained messages.
+//! The latest retained messages are published again after reconnecting to the broker.
+
+use crate::types::{Path, Place, Resource, UpdateResponse};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, watch};
+use tracing::{debug, warn};
+
+/// Time the broker has to accept the connection.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// The largest packet accepted from the broker.
+///
+/// Only acknowledgements and ping responses are expected, since nothing is subscribed.
+const MAX_INCOMING_PACKET_LEN: usize = 1024;
+/// The largest remaining length of a packet allowed by MQTT 3.1.1.
+const MAX_REMAINING_LEN: usize = 268_435_455;
+
+/// The options of the connection to the broker.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MqttOptions {
+    /// The broker host and port, e.g. `localhost:1883`.
+    pub broker: String,
+    /// Must be unique among the clients of the broker.
+    pub client_id: String,
+    pub username: Option<String>,
+    /// Requires the username, MQTT 3.1.1 doesn't allow a password without it.
+    pub password: Option<String>,
+    /// The first level of all topics, e.g. `labgrid`.
+    pub topic_prefix: String,
+    /// The interval of pings, the broker closes the connection if it misses them.
+    pub keep_alive: Duration,
+    /// Time waited before reconnecting after the connection to the broker was lost.
+    pub reconnect_delay: Duration,
+}
+
+// Passwords must not end up in the logs.
+impl std::fmt::Debug for MqttOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MqttOptions")
+            .field("broker", &self.broker)
+            .field("client_id", &self.client_id)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| ".."))
+            .field("topic_prefix", &self.topic_prefix)
+            .field("keep_alive", &self.keep_alive)
+            .field("reconnect_delay", &self.reconnect_delay)
+            .finish()
+    }
+}
+
+impl MqttOptions {
+    pub fn new(broker: impl Into<String>, client_id: impl Into<String>) -> Self {
+        Self {
+            broker: broker.into(),
+            client_id: client_id.into(),
+            username: None,
+            password: None,
+            topic_prefix: "labgrid".to_string(),
+            keep_alive: Duration::from_secs(30),
+            reconnect_delay: Duration::from_secs(10),
+        }
+    }
+
+    fn status_topic(&self) -> String {
+        format!("{}/status", self.topic_prefix)
+    }
+}
+
+/// A message published to the broker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttMessage {
+    pub topic: String,
+    pub payload: String,
+    /// Whether the broker keeps the message for clients subscribing later.
+    pub retain: bool,
+}
+
+impl MqttMessage {
+    fn retained(topic: String, payload: impl Into<String>) -> Self {
+        Self {
+            topic,
+            payload: payload.into(),
+            retain: true,
+        }
+    }
+}
+
+/// Derives the messages mirroring the state of places and resources.
+///
+/// After the connection to the coordinator was lost, [MqttStateMirror::resync] and [MqttStateMirror::synced]
+/// clear the topics of places and resources that were removed in the meantime.
+#[derive(Debug, Clone)]
+pub struct MqttStateMirror {
+    topic_prefix: String,
+    /// The last published state and owner of the places.
+    places: BTreeMap<String, (&'static str, String)>,
+    /// The last published state of the resources.
+    resources: BTreeMap<Path, &'static str>,
+    /// The places and resources seen since [MqttStateMirror::resync], `None` when not resyncing.
+    seen: Option<(BTreeSet<String>, BTreeSet<Path>)>,
+}
+
+impl MqttStateMirror {
+    pub fn new(topic_prefix: impl Into<String>) -> Self {
+        Self {
+            topic_prefix: topic_prefix.into(),
+            places: BTreeMap::new(),
+            resources: BTreeMap::new(),
+            seen: None,
+        }
+    }
+
+    /// Records an update of the client stream, returns the messages of the changed states.
+    pub fn update(&mut self, update: &UpdateResponse) -> Vec<MqttMessage> {
+        match update {
+            UpdateResponse::Place(place) => self.place(place),
+            UpdateResponse::DeletePlace(name) => self.delete_place(name),
+            UpdateResponse::Resource(resource) => self.resource(resource),
+            UpdateResponse::DeleteResource(path) => self.delete_resource(path),
+        }
+    }
+
+    pub fn place(&mut self, place: &Place) -> Vec<MqttMessage> {
+        if let Some((places, _)) = &mut self.seen {
+            places.insert(place.name.clone());
+        }
+        let (state, owner) = match &place.acquired {
+            Some(owner) => ("acquired", owner.clone()),
+            None => ("free", String::new()),
+        };
+        let prev = self
+            .places
+            .insert(place.name.clone(), (state, owner.clone()));
+        let mut messages = Vec::new();
+        if prev.as_ref().map(|(state, _)| *state) != Some(state) {
+            messages.push(MqttMessage::retained(
+                self.place_topic(&place.name, "state"),
+                state,
+            ));
+        }
+        if prev.map(|(_, owner)| owner).as_ref() != Some(&owner) {
+            messages.push(MqttMessage::retained(
+                self.place_topic(&place.name, "acquired_by"),
+                owner,
+            ));
+        }
+        messages
+    }
+
+    pub fn delete_place(&mut self, name: &str) -> Vec<MqttMessage> {
+        if self.places.remove(name).is_none() {
+            return Vec::new();
+        }
+        ["state", "acquired_by"]
+            .into_iter()
+            .map(|leaf| MqttMessage::retained(self.place_topic(name, leaf), ""))
+            .collect()
+    }
+
+    pub fn resource(&mut self, resource: &Resource) -> Vec<MqttMessage> {
+        if let Some((_, resources)) = &mut self.seen {
+            resources.insert(resource.path.clone());
+        }
+        let state = if !resource.acquired.is_empty() {
+            "acquired"
+        } else if resource.available {
+            "available"
+        } else {
+            "unavailable"
+        };
+        if self.resources.insert(resource.path.clone(), state) == Some(state) {
+            return Vec::new();
+        }
+        vec![MqttMessage::retained(
+            self.resource_topic(&resource.path),
+            state,
+        )]
+    }
+
+    pub fn delete_resource(&mut self, path: &Path) -> Vec<MqttMessage> {
+        if self.resources.remove(path).is_none() {
+            return Vec::new();
+        }
+        vec![MqttMessage::retained(self.resource_topic(path), "")]
+    }
+
+    /// Starts tracking which places and resources are present again, when the coordinator sends them anew.
+    pub fn resync(&mut self) {
+        self.seen = Some((BTreeSet::new(), BTreeSet::new()));
+    }
+
+    /// Finishes a [MqttStateMirror::resync], returns the messages clearing the topics of what is no longer present.
+    pub fn synced(&mut self) -> Vec<MqttMessage> {
+        let Some((places, resources)) = self.seen.take() else {
+            return Vec::new();
+        };
+        let removed_places = self
+            .places
+            .keys()
+            .filter(|name| !places.contains(*name))
+            .cloned()
+            .collect::<Vec<_>>();
+        let removed_resources = self
+            .resources
+            .keys()
+            .filter(|path| !resources.contains(*path))
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut messages = Vec::new();
+        for name in removed_places {
+            messages.extend(self.delete_place(&name));
+        }
+        for path in removed_resources {
+            messages.extend(self.delete_resource(&path));
+        }
+        messages
+    }
+
+    fn place_topic(&self, name: &str, leaf: &str) -> String {
+        format!("{}/places/{}/{leaf}", self.topic_prefix, topic_level(name))
+    }
+
+    fn resource_topic(&self, path: &Path) -> String {
+        format!(
+            "{}/resources/{}/{}/{}/state",
+            self.topic_prefix,
+            topic_level(path.exporter_name.as_deref().unwrap_or("_")),
+            topic_level(&path.group_name),
+            topic_level(&path.resource_name)
+        )
+    }
+}
+
+/// Replaces the characters that can't be part of a topic level.
+fn topic_level(name: &str) -> String {
+    name.replace(['/', '+', '#'], "_")
+}
+
+/// The state of the connection to the broker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MqttStatus {
+    Connecting,
+    Connected,
+    /// The connection failed or was lost, it is retried after the reconnect delay.
+    Disconnected {
+        error: String,
+    },
+}
+
+/// Hands messages to the [MqttConnection], cheap to clone.
+#[derive(Debug, Clone)]
+pub struct MqttPublisher {
+    sender: mpsc::UnboundedSender<MqttMessage>,
+}
+
+impl MqttPublisher {
+    /// Creates a publisher and the connection publishing its messages to the broker.
+    ///
+    /// The connection must be run for messages to be published.
+    pub fn new(options: MqttOptions) -> (Self, MqttConnection) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let (status, _) = watch::channel(MqttStatus::Connecting);
+        (
+            Self { sender },
+            MqttConnection {
+                options,
+                receiver,
+                retained: BTreeMap::new(),
+                status,
+            },
+        )
+    }
+
+    pub fn publish(&self, messages: impl IntoIterator<Item = MqttMessage>) {
+        for message in messages {
+            // The connection only stops when all publishers are dropped
+            let _ = self.sender.send(message);
+        }
+    }
+}
+
+/// The connection to the broker, publishing the messages of the [MqttPublisher].
+#[derive(Debug)]
+pub struct MqttConnection {
+    options: MqttOptions,
+    receiver: mpsc::UnboundedReceiver<MqttMessage>,
+    /// The latest retained message of each topic, published again after reconnecting.
+    retained: BTreeMap<String, MqttMessage>,
+    status: watch::Sender<MqttStatus>,
+}
+
+/// How a session with the broker ended.
+enum SessionEnd {
+    /// All publishers were dropped.
+    Closed,
+    Lost(io::Error),
+}
+
+impl MqttConnection {
+    /// The status of the connection, updated while it runs.
+    pub fn status(&self) -> watch::Receiver<MqttStatus> {
+        self.status.subscribe()
+    }
+
+    /// Keeps the connection to the broker until all publishers are dropped.
+    ///
+    /// Reconnects after the reconnect delay when the connection fails or is lost.
+    /// Retained messages published in the meantime are kept and published once connected again.
+    pub async fn run(mut self) {
+        loop {
+            self.status.send_replace(MqttStatus::Connecting);
+            let error = match self.session().await {
+                SessionEnd::Closed => return,
+                SessionEnd::Lost(error) => error,
+            };
+            debug!(?error, broker = self.options.broker, "MQTT connection lost");
+            self.status.send_replace(MqttStatus::Disconnected {
+                error: error.to_string(),
+            });
+            let reconnect = tokio::time::sleep(self.options.reconnect_delay);
+            tokio::pin!(reconnect);
+            loop {
+                tokio::select! {
+                    _ = &mut reconnect => break,
+                    message = self.receiver.recv() => match message {
+                        Some(message) => self.retain(&message),
+                        None => return,
+                    },
+                }
+            }
+        }
+    }
+
+    fn retain(&mut self, message: &MqttMessage) {
+        if message.retain {
+            self.retained.insert(message.topic.clone(), message.clone());
+        }
+    }
+
+    async fn session(&mut self) -> SessionEnd {
+        let stream = match tokio::time::timeout(CONNECT_TIMEOUT, self.connect()).await {
+            Ok(Ok(stream)) => stream,
+            Ok(Err(error)) => return SessionEnd::Lost(error),
+            Err(_) => {
+                return SessionEnd::Lost(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "Broker didn't accept the connection in time",
+                ))
+            }
+        };
+        self.status.send_replace(MqttStatus::Connected);
+        let (reader, mut writer) = tokio::io::split(stream);
+        match self.publish_session(reader, &mut writer).await {
+            Ok(()) => {
+                // Not the last will, since the connection is closed gracefully
+                let offline = MqttMessage::retained(self.options.status_topic(), "offline");
+                let _ = write_publish(&mut writer, &offline).await;
+                let _ = writer.write_all(&[DISCONNECT, 0]).await;
+                SessionEnd::Closed
+            }
+            Err(error) => SessionEnd::Lost(error),
+        }
+    }
+
+    /// Connects and waits for the broker to accept the connection.
+    async fn connect(&self) -> io::Result<TcpStream> {
+        if self.options.password.is_some() && self.options.username.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "A password can't be sent without a username",
+            ));
+        }
+        let connect_packet = connect_packet(&self.options)?;
+        let mut stream = TcpStream::connect(&self.options.broker).await?;
+        stream.write_all(&connect_packet).await?;
+        let mut reader = BufReader::new(&mut stream);
+        let (packet_type, body) = read_packet(&mut reader).await?;
+        match (packet_type, body.as_slice()) {
+            (CONNACK, [_, 0]) => Ok(stream),
+            (CONNACK, [_, code]) => Err(io::Error::new(
+                io::ErrorKind::ConnectionRefused,
+                format!("Broker refused the connection with return code {code}"),
+            )),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Expected CONNACK from the broker",
+            )),
+        }
+    }
+
+    /// Publishes messages until all publishers are dropped, which returns `Ok(())`.
+    async fn publish_session(
+        &mut self,
+        reader: impl AsyncRead + Unpin,
+        writer: &mut (impl AsyncWrite + Unpin),
+    ) -> io::Result<()> {
+        let online = MqttMessage::retained(self.options.status_topic(), "online");
+        write_publish(writer, &online).await?;
+        for message in self.retained.values() {
+            write_publish(writer, message).await?;
+        }
+
+        let (pong_sender, mut pongs) = mpsc::unbounded_channel();
+        let read_packets = read_packets(reader, pong_sender);
+        tokio::pin!(read_packets);
+        let mut pings = tokio::time::interval(self.options.keep_alive.max(Duration::from_secs(1)));
+        pings.tick().await;
+        let mut awaiting_pong = false;
+        loop {
+            tokio::select! {
+                message = self.receiver.recv() => {
+                    let Some(message) = message else {
+                        return Ok(());
+                    };
+                    self.retain(&message);
+                    write_publish(writer, &message).await?;
+                }
+                _ = pings.tick() => {
+                    if awaiting_pong {
+                        return Err(io::Error::new(io::ErrorKind::TimedOut, "Broker didn't answer the ping"));
+                    }
+                    writer.write_all(&[PINGREQ, 0]).await?;
+                    awaiting_pong = true;
+                }
+                Some(()) = pongs.recv() => awaiting_pong = false,
+                res = &mut read_packets => return Err(res),
+            }
+        }
+    }
+}
+
+const CONNECT: u8 = 0x10;
+const CONNACK: u8 = 0x20;
+const PUBLISH: u8 = 0x30;
+const PINGREQ: u8 = 0xc0;
+const PINGRESP: u8 = 0xd0;
+const DISCONNECT: u8 = 0xe0;
+
+/// Reads the packets of the broker, reporting ping responses, until reading fails.
+async fn read_packets(
+    reader: impl AsyncRead + Unpin,
+    pongs: mpsc::UnboundedSender<()>,
+) -> io::Error {
+    let mut reader = BufReader::new(reader);
+    loop {
+        match read_packet(&mut reader).await {
+            Ok((PINGRESP, _)) => {
+                let _ = pongs.send(());
+            }
+            Ok((packet_type, _)) => debug!(packet_type, "Ignoring MQTT packet"),
+            Err(error) => return error,
+        }
+    }
+}
+
+/// Reads a packet, returns its type (the upper bits of the first byte) and its body.
+///
+/// Packets larger than [MAX_INCOMING_PACKET_LEN] are rejected before allocating their body.
+async fn read_packet(reader: &mut (impl AsyncRead + Unpin)) -> io::Result<(u8, Vec<u8>)> {
+    let header = reader.read_u8().await?;
+    let mut len = 0usize;
+    for shift in (0..28).step_by(7) {
+        let byte = reader.read_u8().await?;
+        len |= usize::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            if len > MAX_INCOMING_PACKET_LEN {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Packet of {len} bytes from the broker is too large"),
+                ));
+            }
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body).await?;
+            return Ok((header & 0xf0, body));
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "Malformed remaining length",
+    ))
+}
+
+/// Publishes the message, messages that can't be encoded are skipped, so that they don't end the connection.
+async fn write_publish(
+    writer: &mut (impl AsyncWrite + Unpin),
+    message: &MqttMessage,
+) -> io::Result<()> {
+    match publish_packet(message) {
+        Ok(packet) => writer.write_all(&packet).await,
+        Err(error) => {
+            warn!(?error, topic = message.topic, "Skipping MQTT message");
+            Ok(())
+        }
+    }
+}
+
+fn publish_packet(message: &MqttMessage) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    put_str(&mut body, &message.topic)?;
+    body.extend_from_slice(message.payload.as_bytes());
+    packet(PUBLISH | u8::from(message.retain), &body)
+}
+
+fn connect_packet(options: &MqttOptions) -> io::Result<Vec<u8>> {
+    // Clean session, last will with retain
+    let mut flags = 0x02 | 0x04 | 0x20;
+    if options.username.is_some() {
+        flags |= 0x80;
+    }
+    if options.password.is_some() {
+        flags |= 0x40;
+    }
+    let mut body = Vec::new();
+    put_str(&mut body, "MQTT")?;
+    body.push(4);
+    body.push(flags);
+    let keep_alive = u16::try_from(options.keep_alive.as_secs()).unwrap_or(u16::MAX);
+    body.extend_from_slice(&keep_alive.to_be_bytes());
+    put_str(&mut body, &options.client_id)?;
+    put_str(&mut body, &options.status_topic())?;
+    put_str(&mut body, "offline")?;
+    for field in [&options.username, &options.password].into_iter().flatten() {
+        put_str(&mut body, field)?;
+    }
+    packet(CONNECT, &body)
+}
+
+/// Puts a string with its length prefix, fails for strings longer than the maximum length.
+fn put_str(buf: &mut Vec<u8>, s: &str) -> io::Result<()> {
+    let len = u16::try_from(s.len()).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("String of {} bytes is too long for MQTT", s.len()),
+        )
+    })?;
+    buf.extend_from_slice(&len.to_be_bytes());
+    buf.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+/// A packet with the fixed header and the variable length encoded remaining length.
+fn packet(header: u8, body: &[u8]) -> io::Result<Vec<u8>> {
+    if body.len() > MAX_REMAINING_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Packet of {} bytes is too large for MQTT", body.len()),
+        ));
+    }
+    let mut packet = vec![header];
+    let mut len = body.len();
+    loop {
+        let mut byte = (len % 128) as u8;
+        len /= 128;
+        if len > 0 {
+            byte |= 0x80;
+        }
+        packet.push(byte);
+        if len == 0 {
+            break;
+        }
+    }
+    packet.extend_from_slice(body);
+    Ok(packet)
+}
@@ -0,0 +1,249 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Textual forms of resource paths and place match patterns.
+//!
+//! Resource paths are written as `exporter/group/name`, or `group/name` when the exporter is unknown.
+//! Match patterns are written as `exporter/group/cls[/name]`, the same as accepted by `labgrid-client add-match`.
+//! Every component of a match pattern may contain the shell-style wildcards `*`, `?` and `[seq]`/`[!seq]`,
+//! which are matched like Python's `fnmatch.fnmatchcase` that is used by the coordinator.
+
+use crate::types::{Path, ResourceMatch};
+use core::fmt::Display;
+use std::str::FromStr;
+
+const WILDCARD_CHARS: [char; 3] = ['*', '?', '['];
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PatternError {
+    #[error("'{input}' has {found} components, expected {expected}")]
+    ComponentCount {
+        input: String,
+        found: usize,
+        expected: &'static str,
+    },
+    #[error("'{input}' has an empty component")]
+    EmptyComponent { input: String },
+    #[error("Resource path '{input}' can't contain wildcards")]
+    Wildcard { input: String },
+}
+
+impl Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(exporter_name) = &self.exporter_name {
+            write!(f, "{exporter_name}/")?;
+        }
+        write!(f, "{}/{}", self.group_name, self.resource_name)
+    }
+}
+
+impl FromStr for Path {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = split_components(s, 2..=3, "2 or 3")?;
+        if components.iter().any(|c| c.contains(WILDCARD_CHARS)) {
+            return Err(PatternError::Wildcard {
+                input: s.to_string(),
+            });
+        }
+        let (exporter_name, group_name, resource_name) = match components[..] {
+            [group, name] => (None, group, name),
+            [exporter, group, name] => (Some(exporter.to_string()), group, name),
+            _ => unreachable!("component count is checked"),
+        };
+        Ok(Self {
+            exporter_name,
+            group_name: group_name.to_string(),
+            resource_name: resource_name.to_string(),
+        })
+    }
+}
+
+/// A place match pattern `exporter/group/cls[/name]`, without the rename of a [ResourceMatch].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MatchPattern {
+    pub exporter: String,
+    pub group: String,
+    pub cls: String,
+    pub name: Option<String>,
+}
+
+impl Display for MatchPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.exporter, self.group, self.cls)?;
+        if let Some(name) = &self.name {
+            write!(f, "/{name}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MatchPattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = split_components(s, 3..=4, "3 or 4")?;
+        let (exporter, group, cls, name) = match components[..] {
+            [exporter, group, cls] => (exporter, group, cls, None),
+            [exporter, group, cls, name] => (exporter, group, cls, Some(name.to_string())),
+            _ => unreachable!("component count is checked"),
+        };
+        Ok(Self {
+            exporter: exporter.to_string(),
+            group: group.to_string(),
+            cls: cls.to_string(),
+            name,
+        })
+    }
+}
+
+impl From<ResourceMatch> for MatchPattern {
+    fn from(value: ResourceMatch) -> Self {
+        Self {
+            exporter: value.exporter,
+            group: value.group,
+            cls: value.cls,
+            name: value.name,
+        }
+    }
+}
+
+impl MatchPattern {
+    /// The pattern that exactly matches the resource at `path` with class `cls`.
+    pub fn for_resource(path: &Path, cls: &str) -> Self {
+        Self {
+            exporter: path.exporter_name.clone().unwrap_or_default(),
+            group: path.group_name.clone(),
+            cls: cls.to_string(),
+            name: Some(path.resource_name.clone()),
+        }
+    }
+
+    /// Whether the pattern matches the resource at `path` with class `cls`.
+    ///
+    /// Patterns without a name match all resources of the group with the class.
+    pub fn matches(&self, path: &Path, cls: &str) -> bool {
+        wildcard_match(
+            &self.exporter,
+            path.exporter_name.as_deref().unwrap_or_default(),
+        ) && wildcard_match(&self.group, &path.group_name)
+            && wildcard_match(&self.cls, cls)
+            && self
+                .name
+                .as_ref()
+                .map_or(true, |name| wildcard_match(name, &path.resource_name))
+    }
+
+    /// Converts into a [ResourceMatch] with the optional rename.
+    pub fn into_resource_match(self, rename: Option<String>) -> ResourceMatch {
+        ResourceMatch {
+            exporter: self.exporter,
+            group: self.group,
+            cls: self.cls,
+            name: self.name,
+            rename,
+        }
+    }
+}
+
+impl ResourceMatch {
+    /// The match pattern, without the rename.
+    pub fn pattern(&self) -> MatchPattern {
+        MatchPattern {
+            exporter: self.exporter.clone(),
+            group: self.group.clone(),
+            cls: self.cls.clone(),
+            name: self.name.clone(),
+        }
+    }
+}
+
+fn split_components<'a>(
+    s: &'a str,
+    count: std::ops::RangeInclusive<usize>,
+    expected: &'static str,
+) -> Result<Vec<&'a str>, PatternError> {
+    let components = s.trim().split('/').collect::<Vec<&str>>();
+    if !count.contains(&components.len()) {
+        return Err(PatternError::ComponentCount {
+            input: s.to_string(),
+            found: components.len(),
+            expected,
+        });
+    }
+    if components.iter().any(|c| c.is_empty()) {
+        return Err(PatternError::EmptyComponent {
+            input: s.to_string(),
+        });
+    }
+    Ok(components)
+}
+
+/// Matches `text` against the shell-style wildcard `pattern`, case-sensitively.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<char>>();
+    let text = text.chars().collect::<Vec<char>>();
+    // Position after the last `*` in the pattern and the text position it was tried at, for backtracking
+    let mut backtrack: Option<(usize, usize)> = None;
+    let (mut p, mut t) = (0, 0);
+
+    while t < text.len() {
+        let step = match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p + 1, t));
+                p += 1;
+                continue;
+            }
+            Some('?') => Some(p + 1),
+            Some('[') => match_set(&pattern[p..], text[t]).map(|len| p + len),
+            Some(ch) if *ch == text[t] => Some(p + 1),
+            _ => None,
+        };
+        match (step, backtrack) {
+            (Some(next), _) => {
+                p = next;
+                t += 1;
+            }
+            (None, Some((star_p, star_t))) => {
+                backtrack = Some((star_p, star_t + 1));
+                p = star_p;
+                t = star_t + 1;
+            }
+            (None, None) => return false,
+        }
+    }
+    pattern[p..].iter().all(|ch| *ch == '*')
+}
+
+/// Matches `ch` against the set `[seq]`/`[!seq]` at the start of `pattern`.
+///
+/// Returns the length of the set in the pattern if it matches.
+/// A `[` without a closing `]` is matched literally, like `fnmatch` does.
+fn match_set(pattern: &[char], ch: char) -> Option<usize> {
+    let negated = pattern.get(1) == Some(&'!');
+    let start = if negated { 2 } else { 1 };
+    // A `]` directly after the opening bracket is part of the set
+    let Some(end) = pattern
+        .iter()
+        .skip(start + 1)
+        .position(|c| *c == ']')
+        .map(|pos| pos + start + 1)
+    else {
+        return (ch == '[').then_some(1);
+    };
+    let set = &pattern[start..end];
+    let mut found = false;
+    let mut i = 0;
+    while i < set.len() {
+        if i + 2 < set.len() && set[i + 1] == '-' {
+            found |= (set[i]..=set[i + 2]).contains(&ch);
+            i += 3;
+        } else {
+            found |= set[i] == ch;
+            i += 1;
+        }
+    }
+    (found != negated).then_some(end + 1)
+}
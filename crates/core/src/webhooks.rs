@@ -0,0 +1,377 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Webhook notifications on coordinator events.
+//!
+//! A [WebhookTracker] derives [WebhookEvent]s from the updates of the client stream and the polled reservations.
+//! The events are handed to a [WebhookNotifier], whose [WebhookDelivery] POSTs them as JSON to the configured URLs:
+//!
+//! ```json
+//! {"event":"place_acquired","place":"board-1","owner":"host/user","source":"labgrid-ui","time":1740830400.0}
+//! ```
+
+use crate::types::{Path, Place, Reservation, ReservationState, Resource, UpdateResponse};
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::header::{CONTENT_TYPE, HOST, USER_AGENT};
+use hyper::{Method, Request, Uri};
+use hyper_util::rt::TokioIo;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Time a webhook has to accept a notification.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An event observed on the coordinator, the payload of a notification.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum WebhookEvent {
+    PlaceAcquired {
+        place: String,
+        owner: String,
+    },
+    PlaceReleased {
+        place: String,
+        owner: String,
+    },
+    ResourceAdded {
+        path: String,
+        cls: String,
+    },
+    ResourceRemoved {
+        path: String,
+    },
+    ReservationAdded {
+        token: String,
+        owner: String,
+        state: String,
+    },
+    /// The state of the reservation changed.
+    ReservationChanged {
+        token: String,
+        owner: String,
+        state: String,
+    },
+    ReservationRemoved {
+        token: String,
+        owner: String,
+    },
+}
+
+/// Derives the events from what is observed of the coordinator.
+///
+/// Everything observed before the initial sync of the client stream only establishes the known state,
+/// so that connecting doesn't notify about every place and resource. The same applies to the first reservations.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookTracker {
+    synced: bool,
+    /// The owners of the known places.
+    places: HashMap<String, Option<String>>,
+    resources: BTreeSet<Path>,
+    /// The owners and states of the known reservations by their token, `None` before they were first observed.
+    reservations: Option<HashMap<String, (String, i32)>>,
+}
+
+impl WebhookTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that the initial sync of the client stream completed.
+    pub fn synced(&mut self) {
+        self.synced = true;
+    }
+
+    /// Forgets the known state, must be called when the client stream was lost.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Records an update of the client stream, returns the events it caused.
+    pub fn update(&mut self, update: &UpdateResponse) -> Vec<WebhookEvent> {
+        match update {
+            UpdateResponse::Place(place) => self.place(place),
+            UpdateResponse::DeletePlace(name) => {
+                self.delete_place(name);
+                Vec::new()
+            }
+            UpdateResponse::Resource(resource) => self.resource(resource).into_iter().collect(),
+            UpdateResponse::DeleteResource(path) => {
+                self.delete_resource(path).into_iter().collect()
+            }
+        }
+    }
+
+    /// Records a place update, returns the events it caused.
+    ///
+    /// A place that was acquired by another user in the meantime causes both a release and an acquisition.
+    pub fn place(&mut self, place: &Place) -> Vec<WebhookEvent> {
+        let prev = self
+            .places
+            .insert(place.name.clone(), place.acquired.clone())
+            .flatten();
+        let mut events = Vec::new();
+        if self.synced && prev != place.acquired {
+            if let Some(owner) = prev {
+                events.push(WebhookEvent::PlaceReleased {
+                    place: place.name.clone(),
+                    owner,
+                });
+            }
+            if let Some(owner) = &place.acquired {
+                events.push(WebhookEvent::PlaceAcquired {
+                    place: place.name.clone(),
+                    owner: owner.clone(),
+                });
+            }
+        }
+        events
+    }
+
+    pub fn delete_place(&mut self, name: &str) {
+        self.places.remove(name);
+    }
+
+    /// Records a resource update, returns the event if the resource appeared.
+    pub fn resource(&mut self, resource: &Resource) -> Option<WebhookEvent> {
+        (self.resources.insert(resource.path.clone()) && self.synced).then(|| {
+            WebhookEvent::ResourceAdded {
+                path: resource.path.to_string(),
+                cls: resource.cls.clone(),
+            }
+        })
+    }
+
+    /// Records a resource deletion, returns the event if the resource was known.
+    pub fn delete_resource(&mut self, path: &Path) -> Option<WebhookEvent> {
+        (self.resources.remove(path) && self.synced).then(|| WebhookEvent::ResourceRemoved {
+            path: path.to_string(),
+        })
+    }
+
+    /// Records the current reservations, returns the events of the changes since they were last observed.
+    pub fn reservations(&mut self, reservations: &[Reservation]) -> Vec<WebhookEvent> {
+        let current = reservations
+            .iter()
+            .map(|r| (r.token.clone(), (r.owner.clone(), r.state)))
+            .collect::<HashMap<_, _>>();
+        let Some(prev) = self.reservations.replace(current.clone()) else {
+            return Vec::new();
+        };
+        let mut events = Vec::new();
+        for reservation in reservations {
+            let state = state_name(reservation.state);
+            match prev.get(&reservation.token) {
+                None => events.push(WebhookEvent::ReservationAdded {
+                    token: reservation.token.clone(),
+                    owner: reservation.owner.clone(),
+                    state,
+                }),
+                Some((_, prev_state)) if *prev_state != reservation.state => {
+                    events.push(WebhookEvent::ReservationChanged {
+                        token: reservation.token.clone(),
+                        owner: reservation.owner.clone(),
+                        state,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        let mut removed = prev
+            .into_iter()
+            .filter(|(token, _)| !current.contains_key(token))
+            .collect::<Vec<_>>();
+        removed.sort();
+        events.extend(
+            removed
+                .into_iter()
+                .map(|(token, (owner, _))| WebhookEvent::ReservationRemoved { token, owner }),
+        );
+        events
+    }
+}
+
+/// The name of a reservation state in notifications.
+fn state_name(state: i32) -> String {
+    match ReservationState::try_from(state) {
+        Ok(ReservationState::Waiting) => "waiting".to_string(),
+        Ok(ReservationState::Allocated) => "allocated".to_string(),
+        Ok(ReservationState::Acquired) => "acquired".to_string(),
+        Ok(ReservationState::Expired) => "expired".to_string(),
+        Ok(ReservationState::Invalid) => "invalid".to_string(),
+        _ => state.to_string(),
+    }
+}
+
+/// The URL of a webhook, only `http` is supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookUrl(Uri);
+
+/// Error parsing a [WebhookUrl].
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum WebhookUrlError {
+    #[error("Invalid URL: {0}")]
+    Invalid(#[from] hyper::http::uri::InvalidUri),
+    #[error("Unsupported scheme, only 'http' is supported")]
+    UnsupportedScheme,
+    #[error("URL has no host")]
+    MissingHost,
+}
+
+impl FromStr for WebhookUrl {
+    type Err = WebhookUrlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let uri = s.parse::<Uri>()?;
+        if uri.scheme_str() != Some("http") {
+            return Err(WebhookUrlError::UnsupportedScheme);
+        }
+        if uri.host().is_none() {
+            return Err(WebhookUrlError::MissingHost);
+        }
+        Ok(Self(uri))
+    }
+}
+
+impl Display for WebhookUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Hands events to the [WebhookDelivery], cheap to clone.
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    sender: mpsc::UnboundedSender<WebhookEvent>,
+}
+
+impl WebhookNotifier {
+    /// Creates a notifier and the delivery POSTing its events to `urls`, identifying the client as `source`.
+    ///
+    /// The delivery must be run for notifications to be sent.
+    pub fn new(urls: Vec<WebhookUrl>, source: String) -> (Self, WebhookDelivery) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        (
+            Self { sender },
+            WebhookDelivery {
+                urls,
+                source,
+                receiver,
+            },
+        )
+    }
+
+    pub fn notify(&self, events: impl IntoIterator<Item = WebhookEvent>) {
+        for event in events {
+            // The delivery only stops when all notifiers are dropped
+            let _ = self.sender.send(event);
+        }
+    }
+}
+
+/// POSTs the notified events to the webhooks, in the order they were notified.
+#[derive(Debug)]
+pub struct WebhookDelivery {
+    urls: Vec<WebhookUrl>,
+    source: String,
+    receiver: mpsc::UnboundedReceiver<WebhookEvent>,
+}
+
+#[derive(serde::Serialize)]
+struct Payload<'a> {
+    #[serde(flatten)]
+    event: &'a WebhookEvent,
+    source: &'a str,
+    /// Seconds since the unix epoch.
+    time: f64,
+}
+
+impl WebhookDelivery {
+    /// Delivers the events until all notifiers are dropped.
+    ///
+    /// Failed deliveries are only logged and not retried.
+    pub async fn run(mut self) {
+        while let Some(event) = self.receiver.recv().await {
+            let payload = Payload {
+                event: &event,
+                source: &self.source,
+                time: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs_f64(),
+            };
+            let body = match serde_json::to_vec(&payload) {
+                Ok(body) => Bytes::from(body),
+                Err(error) => {
+                    warn!(?error, "Serialize webhook payload");
+                    continue;
+                }
+            };
+            for url in &self.urls {
+                match tokio::time::timeout(DELIVERY_TIMEOUT, post(url, body.clone())).await {
+                    Ok(Ok(())) => debug!(%url, ?event, "Delivered webhook notification"),
+                    Ok(Err(error)) => warn!(%url, %error, "Deliver webhook notification"),
+                    Err(_) => warn!(%url, "Delivering webhook notification timed out"),
+                }
+            }
+        }
+    }
+}
+
+/// Error delivering a notification to a webhook.
+#[derive(Debug, thiserror::Error)]
+enum DeliveryError {
+    #[error("Connect: {0}")]
+    Connect(#[from] std::io::Error),
+    #[error("HTTP: {0}")]
+    Http(#[from] hyper::Error),
+    #[error("Request: {0}")]
+    Request(#[from] hyper::http::Error),
+    #[error("Webhook responded with status {0}")]
+    Status(hyper::StatusCode),
+}
+
+async fn post(url: &WebhookUrl, body: Bytes) -> Result<(), DeliveryError> {
+    let host = url.0.host().unwrap_or_default();
+    let port = url.0.port_u16().unwrap_or(80);
+    // Without the user info of the authority, credentials must not be sent in plain text
+    let host_header = match url.0.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    };
+    let stream = TcpStream::connect((host.trim_matches(['[', ']']), port)).await?;
+    let (mut sender, connection) =
+        hyper::client::conn::http1::handshake(TokioIo::new(stream)).await?;
+    tokio::spawn(async move {
+        if let Err(error) = connection.await {
+            debug!(?error, "Webhook connection");
+        }
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(
+            url.0
+                .path_and_query()
+                .map_or("/", |path_and_query| path_and_query.as_str()),
+        )
+        .header(HOST, host_header)
+        .header(CONTENT_TYPE, "application/json")
+        .header(
+            USER_AGENT,
+            concat!("labgrid-ui/", env!("CARGO_PKG_VERSION")),
+        )
+        .body(Full::new(body))?;
+    let response = sender.send_request(request).await?;
+    if !response.status().is_success() {
+        return Err(DeliveryError::Status(response.status()));
+    }
+    Ok(())
+}
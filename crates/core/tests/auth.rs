@@ -0,0 +1,112 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::auth::{CallCredentials, TokenSource};
+use labgrid_ui_core::error::{ErrorKind, GrpcClientError};
+use labgrid_ui_core::mock::MockCoordinator;
+use labgrid_ui_core::LabgridGrpcClient;
+use std::time::Duration;
+
+#[test]
+fn invalid_metadata() {
+    assert!(matches!(
+        CallCredentials::new().metadata("x api key", "secret"),
+        Err(GrpcClientError::InvalidMetadata(key)) if key == "x api key"
+    ));
+    assert!(CallCredentials::new()
+        .metadata("x-api-key", "line\nbreak")
+        .is_err());
+    let credentials = CallCredentials::new()
+        .metadata("x-api-key", "secret")
+        .unwrap();
+    assert!(!credentials.is_empty());
+    // Values are hidden from the debug output
+    assert!(!format!("{credentials:?}").contains("secret"));
+    assert!(!format!("{:?}", TokenSource::Static("secret".to_string())).contains("secret"));
+}
+
+#[tokio::test]
+async fn static_token_and_metadata() {
+    let mock = MockCoordinator::new()
+        .with_required_metadata("authorization", "Bearer secret")
+        .with_required_metadata("x-api-key", "key");
+    let server = mock.serve().await.unwrap();
+
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+    let error = client.get_places().await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Unauthenticated);
+
+    let mut client = LabgridGrpcClient::builder()
+        .credentials(
+            CallCredentials::new()
+                .token(TokenSource::Static("secret".to_string()))
+                .metadata("x-api-key", "key")
+                .unwrap(),
+        )
+        .connect(&server.address())
+        .await
+        .unwrap();
+    assert!(client.get_places().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn token_file() {
+    let mock = MockCoordinator::new().with_required_metadata("authorization", "Bearer rotated");
+    let server = mock.serve().await.unwrap();
+    let path = std::env::temp_dir().join(format!("labgrid-ui-token-{}", std::process::id()));
+
+    let mut client = LabgridGrpcClient::builder()
+        .credentials(CallCredentials::new().token(TokenSource::File(path.clone())))
+        .connect(&server.address())
+        .await
+        .unwrap();
+    // A missing file fails the call before it is sent
+    let error = client.get_places().await.unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::Unauthenticated);
+    assert!(mock.calls().is_empty());
+
+    // The file is read again in the background, calls succeed once the token was picked up
+    std::fs::write(&path, "rotated\n").unwrap();
+    let mut result = client.get_places().await;
+    for _ in 0..50 {
+        if result.is_ok() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        result = client.get_places().await;
+    }
+    std::fs::remove_file(&path).unwrap();
+    assert!(result.is_ok());
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn token_command() {
+    let mock = MockCoordinator::new().with_required_metadata("authorization", "Bearer fetched");
+    let server = mock.serve().await.unwrap();
+
+    let mut client = LabgridGrpcClient::builder()
+        .credentials(CallCredentials::new().token(TokenSource::Command {
+            command: "echo fetched".to_string(),
+            refresh: Duration::from_secs(300),
+        }))
+        .connect(&server.address())
+        .await
+        .unwrap();
+    assert!(client.get_places().await.is_ok());
+    assert!(client.get_places().await.is_ok());
+
+    let mut client = LabgridGrpcClient::builder()
+        .credentials(CallCredentials::new().token(TokenSource::Command {
+            command: "false".to_string(),
+            refresh: Duration::from_secs(300),
+        }))
+        .connect(&server.address())
+        .await
+        .unwrap();
+    assert_eq!(
+        client.get_places().await.unwrap_err().kind(),
+        ErrorKind::Unauthenticated
+    );
+}
@@ -0,0 +1,64 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Places, resources and reservations shared by the integration tests,
+//! adjusted with the struct update syntax.
+
+// Not every test uses every fixture
+#![allow(dead_code)]
+
+use labgrid_ui_core::types::{Path, Place, Reservation, Resource};
+use std::collections::HashMap;
+
+/// A free place without aliases, tags or matches.
+pub fn place(name: &str) -> Place {
+    Place {
+        name: name.to_string(),
+        aliases: Vec::new(),
+        comment: String::new(),
+        tags: HashMap::new(),
+        matches: Vec::new(),
+        acquired: None,
+        acquired_resources: Vec::new(),
+        allowed: Vec::new(),
+        created: 1.,
+        changed: 1.,
+        reservation: None,
+    }
+}
+
+/// The path of a resource of `exporter-1` in the group `board-1`.
+pub fn path(resource_name: &str) -> Path {
+    Path {
+        exporter_name: Some("exporter-1".to_string()),
+        group_name: "board-1".to_string(),
+        resource_name: resource_name.to_string(),
+    }
+}
+
+/// An available, unacquired serial port of `exporter-1` in the group `board-1`.
+pub fn resource(resource_name: &str) -> Resource {
+    Resource {
+        path: path(resource_name),
+        cls: "NetworkSerialPort".to_string(),
+        params: HashMap::new(),
+        extra: HashMap::new(),
+        acquired: String::new(),
+        available: true,
+    }
+}
+
+/// A reservation of `host/user` without filters.
+pub fn reservation(token: &str, state: i32) -> Reservation {
+    Reservation {
+        owner: "host/user".to_string(),
+        token: token.to_string(),
+        state,
+        prio: 0.,
+        filters: HashMap::new(),
+        allocations: HashMap::new(),
+        created: 1.,
+        timeout: 2.,
+    }
+}
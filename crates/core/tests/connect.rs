@@ -0,0 +1,25 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::error::GrpcClientError;
+use labgrid_ui_core::LabgridGrpcClient;
+
+#[tokio::test]
+async fn connect_unsupported_uri() {
+    assert!(matches!(
+        LabgridGrpcClient::new("ftp://localhost:20408").await,
+        Err(GrpcClientError::UnsupportedUri(uri)) if uri == "ftp://localhost:20408"
+    ));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn connect_unix_socket_missing() {
+    let dir = std::env::temp_dir().join(format!("labgrid-ui-connect-{}", std::process::id()));
+    let uri = format!("unix://{}", dir.join("coordinator.sock").display());
+    assert!(matches!(
+        LabgridGrpcClient::new(&uri).await,
+        Err(GrpcClientError::TonicTransport(_))
+    ));
+}
@@ -0,0 +1,429 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Round-trip (domain type -> protobuf -> domain type) property tests for all conversions in
+//! [labgrid_ui_core::types], plus robustness tests against malformed and deeply nested messages.
+
+use labgrid_ui_core::proto;
+use labgrid_ui_core::types::{
+    ClientInMsg, ClientOutMsg, ExporterInMessage, ExporterOutMessage, ExporterResponse, Filter,
+    MapValue, Path, Place, Reservation, Resource, ResourceMatch, StartupDone, Subscribe,
+    SubscribeKind, Sync, UpdateResponse,
+};
+use proptest::prelude::*;
+use prost::Message;
+use std::collections::HashMap;
+
+// Strategies
+
+/// Floats that survive an equality comparison (NaN != NaN).
+fn arb_f64() -> impl Strategy<Value = f64> {
+    any::<f64>().prop_filter("NaN is never equal to itself", |f| !f.is_nan())
+}
+
+fn arb_string() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_./-]{0,16}"
+}
+
+fn arb_non_empty_string() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_./-]{1,16}"
+}
+
+fn arb_string_map() -> impl Strategy<Value = HashMap<String, String>> {
+    prop::collection::hash_map(arb_string(), arb_string(), 0..4)
+}
+
+fn arb_map_value() -> impl Strategy<Value = MapValue> {
+    let leaf = prop_oneof![
+        any::<bool>().prop_map(MapValue::Bool),
+        any::<i64>().prop_map(MapValue::Int),
+        any::<u64>().prop_map(MapValue::UInt),
+        arb_f64().prop_map(MapValue::Float),
+        arb_string().prop_map(MapValue::String),
+    ];
+    leaf.prop_recursive(8, 64, 8, |inner| {
+        prop::collection::vec(inner, 0..8).prop_map(MapValue::Array)
+    })
+}
+
+fn arb_map_values() -> impl Strategy<Value = HashMap<String, MapValue>> {
+    prop::collection::hash_map(arb_string(), arb_map_value(), 0..6)
+}
+
+fn arb_sync() -> impl Strategy<Value = Sync> {
+    any::<u64>().prop_map(|id| Sync { id })
+}
+
+fn arb_startup_done() -> impl Strategy<Value = StartupDone> {
+    (arb_string(), arb_string()).prop_map(|(version, name)| StartupDone { version, name })
+}
+
+fn arb_subscribe() -> impl Strategy<Value = Subscribe> {
+    (
+        any::<Option<bool>>(),
+        prop_oneof![
+            any::<bool>().prop_map(SubscribeKind::AllPlaces),
+            any::<bool>().prop_map(SubscribeKind::AllResources),
+        ],
+    )
+        .prop_map(|(is_unsubscribe, kind)| Subscribe {
+            is_unsubscribe,
+            kind,
+        })
+}
+
+fn arb_client_in_msg() -> impl Strategy<Value = ClientInMsg> {
+    prop_oneof![
+        arb_sync().prop_map(ClientInMsg::Sync),
+        arb_startup_done().prop_map(ClientInMsg::StartupDone),
+        arb_subscribe().prop_map(ClientInMsg::Subscribe),
+    ]
+}
+
+fn arb_path() -> impl Strategy<Value = Path> {
+    (prop::option::of(arb_string()), arb_string(), arb_string()).prop_map(
+        |(exporter_name, group_name, resource_name)| Path {
+            exporter_name,
+            group_name,
+            resource_name,
+        },
+    )
+}
+
+fn arb_resource() -> impl Strategy<Value = Resource> {
+    (
+        arb_path(),
+        arb_string(),
+        arb_map_values(),
+        arb_map_values(),
+        arb_string(),
+        any::<bool>(),
+    )
+        .prop_map(|(path, cls, params, extra, acquired, available)| Resource {
+            path,
+            cls,
+            params,
+            extra,
+            acquired,
+            available,
+        })
+}
+
+fn arb_exporter_in_message() -> impl Strategy<Value = ExporterInMessage> {
+    prop_oneof![
+        arb_resource().prop_map(ExporterInMessage::Resource),
+        arb_startup_done().prop_map(ExporterInMessage::StartupDone),
+        any::<bool>()
+            .prop_map(|success| ExporterInMessage::ExporterResponse(ExporterResponse { success })),
+    ]
+}
+
+fn arb_exporter_out_message() -> impl Strategy<Value = ExporterOutMessage> {
+    prop_oneof![
+        arb_string().prop_map(|version| ExporterOutMessage::Hello { version }),
+        (arb_string(), arb_string(), prop::option::of(arb_string())).prop_map(
+            |(group_name, resource_name, place_name)| {
+                ExporterOutMessage::ExporterSetAcquiredRequest {
+                    group_name,
+                    resource_name,
+                    place_name,
+                }
+            }
+        ),
+    ]
+}
+
+fn arb_resource_match() -> impl Strategy<Value = ResourceMatch> {
+    (
+        arb_string(),
+        arb_string(),
+        arb_string(),
+        prop::option::of(arb_string()),
+        prop::option::of(arb_string()),
+    )
+        .prop_map(|(exporter, group, cls, name, rename)| ResourceMatch {
+            exporter,
+            group,
+            cls,
+            name,
+            rename,
+        })
+}
+
+fn arb_place() -> impl Strategy<Value = Place> {
+    (
+        (
+            arb_string(),
+            prop::collection::vec(arb_string(), 0..3),
+            arb_string(),
+            arb_string_map(),
+            prop::collection::vec(arb_resource_match(), 0..4),
+        ),
+        (
+            // Empty acquired strings are normalized to `None`, see `empty_acquired_is_none`.
+            prop::option::of(arb_non_empty_string()),
+            prop::collection::vec(arb_string(), 0..3),
+            prop::collection::vec(arb_string(), 0..3),
+            arb_f64(),
+            arb_f64(),
+            prop::option::of(arb_string()),
+        ),
+    )
+        .prop_map(
+            |(
+                (name, aliases, comment, tags, matches),
+                (acquired, acquired_resources, allowed, created, changed, reservation),
+            )| Place {
+                name,
+                aliases,
+                comment,
+                tags,
+                matches,
+                acquired,
+                acquired_resources,
+                allowed,
+                created,
+                changed,
+                reservation,
+            },
+        )
+}
+
+fn arb_filter() -> impl Strategy<Value = Filter> {
+    arb_string_map().prop_map(Filter)
+}
+
+fn arb_reservation() -> impl Strategy<Value = Reservation> {
+    (
+        arb_string(),
+        arb_string(),
+        any::<i32>(),
+        arb_f64(),
+        prop::collection::hash_map(arb_string(), arb_filter(), 0..3),
+        arb_string_map(),
+        arb_f64(),
+        arb_f64(),
+    )
+        .prop_map(
+            |(owner, token, state, prio, filters, allocations, created, timeout)| Reservation {
+                owner,
+                token,
+                state,
+                prio,
+                filters,
+                allocations,
+                created,
+                timeout,
+            },
+        )
+}
+
+fn arb_update_response() -> impl Strategy<Value = UpdateResponse> {
+    prop_oneof![
+        arb_resource().prop_map(UpdateResponse::Resource),
+        arb_path().prop_map(UpdateResponse::DeleteResource),
+        arb_place().prop_map(UpdateResponse::Place),
+        arb_string().prop_map(UpdateResponse::DeletePlace),
+    ]
+}
+
+fn arb_client_out_msg() -> impl Strategy<Value = ClientOutMsg> {
+    (
+        prop::option::of(arb_sync()),
+        prop::collection::vec(arb_update_response(), 0..4),
+    )
+        .prop_map(|(sync, updates)| ClientOutMsg { sync, updates })
+}
+
+/// Converts the domain value to protobuf and back, additionally passing it through the protobuf wire format.
+fn round_trip<T, P>(value: T) -> T
+where
+    T: TryFrom<P> + core::fmt::Debug,
+    P: TryFrom<T> + Message + Default,
+    <T as TryFrom<P>>::Error: core::fmt::Debug,
+    <P as TryFrom<T>>::Error: core::fmt::Debug,
+{
+    let proto = P::try_from(value).expect("Convert to protobuf");
+    let decoded = P::decode(proto.encode_to_vec().as_slice()).expect("Decode protobuf");
+    T::try_from(decoded).expect("Convert from protobuf")
+}
+
+// Round-trip tests
+
+proptest! {
+    #[test]
+    fn round_trip_client_in_msg(value in arb_client_in_msg()) {
+        prop_assert_eq!(round_trip::<_, proto::ClientInMessage>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_client_out_msg(value in arb_client_out_msg()) {
+        prop_assert_eq!(round_trip::<_, proto::ClientOutMessage>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_exporter_in_message(value in arb_exporter_in_message()) {
+        prop_assert_eq!(round_trip::<_, proto::ExporterInMessage>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_exporter_out_message(value in arb_exporter_out_message()) {
+        prop_assert_eq!(round_trip::<_, proto::ExporterOutMessage>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_subscribe(value in arb_subscribe()) {
+        prop_assert_eq!(round_trip::<_, proto::Subscribe>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_startup_done(value in arb_startup_done()) {
+        prop_assert_eq!(round_trip::<_, proto::StartupDone>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_sync(value in arb_sync()) {
+        prop_assert_eq!(round_trip::<_, proto::Sync>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_update_response(value in arb_update_response()) {
+        prop_assert_eq!(round_trip::<_, proto::UpdateResponse>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_resource(value in arb_resource()) {
+        prop_assert_eq!(round_trip::<_, proto::Resource>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_path(value in arb_path()) {
+        prop_assert_eq!(round_trip::<_, proto::resource::Path>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_map_value(value in arb_map_value()) {
+        prop_assert_eq!(round_trip::<_, proto::MapValue>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_filter(value in arb_filter()) {
+        prop_assert_eq!(round_trip::<_, proto::reservation::Filter>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_reservation(value in arb_reservation()) {
+        prop_assert_eq!(round_trip::<_, proto::Reservation>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_place(value in arb_place()) {
+        prop_assert_eq!(round_trip::<_, proto::Place>(value.clone()), value);
+    }
+
+    #[test]
+    fn round_trip_resource_match(value in arb_resource_match()) {
+        prop_assert_eq!(round_trip::<_, proto::ResourceMatch>(value.clone()), value);
+    }
+}
+
+// Malformed messages
+
+proptest! {
+    /// Arbitrary bytes that happen to decode must never make the conversion panic.
+    #[test]
+    fn malformed_client_out_message_does_not_panic(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        if let Ok(msg) = proto::ClientOutMessage::decode(bytes.as_slice()) {
+            let _ = ClientOutMsg::try_from(msg);
+        }
+    }
+
+    #[test]
+    fn malformed_exporter_out_message_does_not_panic(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        if let Ok(msg) = proto::ExporterOutMessage::decode(bytes.as_slice()) {
+            let _ = ExporterOutMessage::try_from(msg);
+        }
+    }
+
+    #[test]
+    fn malformed_map_value_does_not_panic(bytes in prop::collection::vec(any::<u8>(), 0..512)) {
+        if let Ok(msg) = proto::MapValue::decode(bytes.as_slice()) {
+            let _ = MapValue::try_from(msg);
+        }
+    }
+}
+
+#[test]
+fn missing_kind_is_error() {
+    assert!(ClientInMsg::try_from(proto::ClientInMessage { kind: None }).is_err());
+    assert!(ExporterInMessage::try_from(proto::ExporterInMessage { kind: None }).is_err());
+    assert!(ExporterOutMessage::try_from(proto::ExporterOutMessage { kind: None }).is_err());
+    assert!(UpdateResponse::try_from(proto::UpdateResponse { kind: None }).is_err());
+    assert!(MapValue::try_from(proto::MapValue { kind: None }).is_err());
+    assert!(Subscribe::try_from(proto::Subscribe {
+        is_unsubscribe: None,
+        kind: None
+    })
+    .is_err());
+}
+
+#[test]
+fn resource_without_path_is_error() {
+    assert!(Resource::try_from(proto::Resource::default()).is_err());
+}
+
+#[test]
+fn resource_params_without_kind_are_skipped() {
+    let resource = proto::Resource {
+        path: Some(proto::resource::Path::default()),
+        params: HashMap::from([
+            ("empty".to_string(), proto::MapValue { kind: None }),
+            (
+                "host".to_string(),
+                proto::MapValue {
+                    kind: Some(proto::map_value::Kind::StringValue("lab".to_string())),
+                },
+            ),
+        ]),
+        ..Default::default()
+    };
+    let resource = Resource::try_from(resource).unwrap();
+    assert_eq!(
+        resource.params,
+        HashMap::from([("host".to_string(), MapValue::String("lab".to_string()))])
+    );
+}
+
+#[test]
+fn empty_acquired_is_none() {
+    let place = proto::Place {
+        acquired: Some(String::new()),
+        ..Default::default()
+    };
+    assert_eq!(Place::try_from(place).unwrap().acquired, None);
+}
+
+#[test]
+fn nested_map_value_without_kind_is_error() {
+    let value = proto::MapValue {
+        kind: Some(proto::map_value::Kind::ArrayValue(proto::MapValueArray {
+            values: vec![proto::MapValue {
+                kind: Some(proto::map_value::Kind::ArrayValue(proto::MapValueArray {
+                    values: vec![proto::MapValue { kind: None }],
+                })),
+            }],
+        })),
+    };
+    assert!(MapValue::try_from(value).is_err());
+}
+
+#[test]
+fn deeply_nested_map_value_round_trips() {
+    // Every level consists of a `MapValue` and a `MapValueArray` message,
+    // so this stays below prost's default recursion limit of 100 when decoding.
+    let value = (0..45).fold(MapValue::Bool(true), |inner, _| {
+        MapValue::Array(vec![inner])
+    });
+    assert_eq!(round_trip::<_, proto::MapValue>(value.clone()), value);
+}
@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::error::{ErrorKind, GrpcClientError};
+use labgrid_ui_core::tonic::{Code, Status};
+use labgrid_ui_core::LabgridGrpcClient;
+
+fn status_error(code: Code) -> GrpcClientError {
+    GrpcClientError::TonicStatus(Status::new(code, "test"))
+}
+
+#[test]
+fn status_kinds() {
+    assert_eq!(
+        status_error(Code::Unavailable).kind(),
+        ErrorKind::Unavailable
+    );
+    assert_eq!(
+        status_error(Code::DeadlineExceeded).kind(),
+        ErrorKind::Timeout
+    );
+    assert_eq!(
+        status_error(Code::Aborted).kind(),
+        ErrorKind::FailedPrecondition
+    );
+    assert_eq!(
+        status_error(Code::OutOfRange).kind(),
+        ErrorKind::InvalidArgument
+    );
+    assert_eq!(status_error(Code::DataLoss).kind(), ErrorKind::Other);
+}
+
+#[test]
+fn fatal_and_retryable() {
+    assert!(status_error(Code::Unavailable).is_fatal());
+    assert!(status_error(Code::DeadlineExceeded).is_fatal());
+    assert!(!status_error(Code::PermissionDenied).is_fatal());
+    assert!(!status_error(Code::InvalidArgument).is_fatal());
+
+    assert!(ErrorKind::FailedPrecondition.is_retryable());
+    assert!(!ErrorKind::PermissionDenied.is_retryable());
+    assert!(!ErrorKind::NotFound.is_retryable());
+}
+
+#[tokio::test]
+async fn client_error_kinds() {
+    let Err(error) = LabgridGrpcClient::new("ftp://localhost").await else {
+        panic!("Expected unsupported URI error");
+    };
+    assert_eq!(error.kind(), ErrorKind::InvalidUri);
+    assert!(error.is_fatal());
+
+    let address = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap().to_string()
+    };
+    let Err(error) = LabgridGrpcClient::new(&address).await else {
+        panic!("Expected connection to be refused");
+    };
+    assert_eq!(error.kind(), ErrorKind::Unavailable);
+}
@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::filter::{FilterExpr, FilterExprError, MAIN_FILTER_NAME, MAX_FILTER_DEPTH};
+use std::collections::HashMap;
+
+fn tags(tags: &[(&str, &str)]) -> HashMap<String, String> {
+    tags.iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn parse_display_roundtrip() {
+    let expr = "board=foo and (rev>=3 OR lab = \"muc 2\")"
+        .parse::<FilterExpr>()
+        .unwrap();
+    let canonical = expr.to_string();
+    assert_eq!(canonical.parse::<FilterExpr>().unwrap(), expr);
+}
+
+#[test]
+fn parse_errors() {
+    assert!("board=".parse::<FilterExpr>().is_err());
+    assert!("board=foo AND".parse::<FilterExpr>().is_err());
+    assert!("(board=foo".parse::<FilterExpr>().is_err());
+    assert!("board=\"foo".parse::<FilterExpr>().is_err());
+    assert!("board=foo lab=muc".parse::<FilterExpr>().is_err());
+
+    let nested = |depth| format!("{}board=foo{}", "(".repeat(depth), ")".repeat(depth));
+    assert!(nested(MAX_FILTER_DEPTH).parse::<FilterExpr>().is_ok());
+    assert_eq!(
+        nested(MAX_FILTER_DEPTH + 1).parse::<FilterExpr>(),
+        Err(FilterExprError::TooDeep {
+            pos: MAX_FILTER_DEPTH
+        })
+    );
+    assert!(matches!(
+        "NOT ".repeat(100_000).parse::<FilterExpr>(),
+        Err(FilterExprError::TooDeep { .. })
+    ));
+}
+
+#[test]
+fn long_chains() {
+    let chain = |op| vec!["board=foo"; 50_000].join(op);
+    let expr = chain(" AND ").parse::<FilterExpr>().unwrap();
+    assert!(matches!(&expr, FilterExpr::And(exprs) if exprs.len() == 50_000));
+    assert!(expr.matches(&tags(&[("board", "foo")])));
+    assert!(expr.to_filters().is_ok());
+    assert_eq!(expr.to_string().parse::<FilterExpr>().unwrap(), expr);
+
+    let expr = chain(" OR ").parse::<FilterExpr>().unwrap();
+    assert!(!expr.matches(&tags(&[("board", "bar")])));
+    assert_eq!(expr.to_string().parse::<FilterExpr>().unwrap(), expr);
+}
+
+#[test]
+fn matches() {
+    let expr = "board=foo AND (rev>=3 OR lab=muc)"
+        .parse::<FilterExpr>()
+        .unwrap();
+    assert!(expr.matches(&tags(&[("board", "foo"), ("rev", "10")])));
+    assert!(expr.matches(&tags(&[("board", "foo"), ("rev", "1"), ("lab", "muc")])));
+    assert!(!expr.matches(&tags(&[("board", "foo"), ("rev", "2")])));
+    assert!(!expr.matches(&tags(&[("board", "bar"), ("rev", "3")])));
+    assert!(!expr.matches(&tags(&[("board", "foo")])));
+
+    let expr = "NOT board=foo".parse::<FilterExpr>().unwrap();
+    assert!(expr.matches(&tags(&[("board", "bar")])));
+}
+
+#[test]
+fn to_filters() {
+    let filters = "board=foo AND lab=muc"
+        .parse::<FilterExpr>()
+        .unwrap()
+        .to_filters()
+        .unwrap();
+    assert_eq!(
+        filters[MAIN_FILTER_NAME].0,
+        tags(&[("board", "foo"), ("lab", "muc")])
+    );
+
+    for expr in [
+        "board=foo OR lab=muc",
+        "rev>=3",
+        "NOT board=foo",
+        "board=foo AND board=bar",
+    ] {
+        assert!(matches!(
+            expr.parse::<FilterExpr>().unwrap().to_filters(),
+            Err(FilterExprError::NotExpressible(_))
+        ));
+    }
+}
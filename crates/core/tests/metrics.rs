@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod common;
+
+use labgrid_ui_core::metrics::{self, Metrics};
+use labgrid_ui_core::types::Place;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+fn place(acquired: Option<&str>) -> Place {
+    Place {
+        acquired: acquired.map(str::to_string),
+        ..common::place("board-1")
+    }
+}
+
+fn value(rendered: &str, name: &str) -> Option<u64> {
+    rendered
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| line.strip_prefix(&format!("labgrid_ui_{name} ")))
+        .and_then(|value| value.parse().ok())
+}
+
+async fn request(addr: std::net::SocketAddr, request_line: &str) -> String {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream
+        .write_all(format!("{request_line}\r\nHost: localhost\r\n\r\n").as_bytes())
+        .await
+        .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+#[test]
+fn metrics_record() {
+    let metrics = Metrics::new();
+    metrics.record_connected();
+    metrics.set_places(2);
+
+    // Places seen for the first time are not counted
+    metrics.record_place_update(None, &place(Some("host/user")));
+    metrics.record_place_update(Some(&place(None)), &place(Some("host/user")));
+    metrics.record_place_update(Some(&place(Some("host/user"))), &place(Some("host/user")));
+    metrics.record_place_update(Some(&place(Some("host/user"))), &place(Some("host/other")));
+    metrics.record_place_update(Some(&place(Some("host/other"))), &place(None));
+
+    metrics.record_grpc_error();
+    metrics.record_disconnected();
+    assert!(!metrics.is_connected());
+    metrics.record_connected();
+
+    let rendered = metrics.render();
+    assert!(rendered.contains("# TYPE labgrid_ui_reconnects_total counter\n"));
+    assert_eq!(value(&rendered, "connected"), Some(1));
+    assert_eq!(value(&rendered, "places"), Some(2));
+    assert_eq!(value(&rendered, "resources"), Some(0));
+    assert_eq!(value(&rendered, "place_acquires_total"), Some(2));
+    assert_eq!(value(&rendered, "place_releases_total"), Some(2));
+    assert_eq!(value(&rendered, "grpc_errors_total"), Some(1));
+    assert_eq!(value(&rendered, "reconnects_total"), Some(1));
+}
+
+#[tokio::test]
+async fn metrics_serve() {
+    let metrics = Metrics::new();
+    metrics.set_resources(5);
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(metrics::serve(Arc::clone(&metrics), listener));
+
+    let response = request(addr, "GET /metrics HTTP/1.1").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    assert_eq!(body, metrics.render());
+    assert_eq!(value(body, "resources"), Some(5));
+
+    assert!(request(addr, "GET / HTTP/1.1")
+        .await
+        .starts_with("HTTP/1.1 404 Not Found\r\n"));
+    assert!(request(addr, "POST /metrics HTTP/1.1")
+        .await
+        .starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+}
+
+#[tokio::test]
+async fn metrics_write_file() {
+    let dir = std::env::temp_dir().join(format!("labgrid-ui-metrics-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("labgrid_ui.prom");
+    let metrics = Metrics::new();
+    metrics.set_places(3);
+
+    metrics::write_file(&metrics, &path).await.unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(written, metrics.render());
+    assert_eq!(value(&written, "places"), Some(3));
+}
@@ -0,0 +1,211 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod common;
+
+use labgrid_ui_core::error::GrpcClientError;
+use labgrid_ui_core::mock::MockCoordinator;
+use labgrid_ui_core::proto;
+use labgrid_ui_core::tonic::{Code, Status, Streaming};
+use labgrid_ui_core::types::{
+    ClientInMsg, ClientOutMsg, Filter, Place, ReservationState, StartupDone, Subscribe,
+    SubscribeKind, Sync, UpdateResponse,
+};
+use labgrid_ui_core::{LabgridGrpcClient, RetryPolicy};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio_stream::StreamExt;
+
+fn place(name: &str) -> Place {
+    Place {
+        tags: HashMap::from([("board".to_string(), "imx8".to_string())]),
+        ..common::place(name)
+    }
+}
+
+async fn next(stream: &mut Streaming<proto::ClientOutMessage>) -> ClientOutMsg {
+    ClientOutMsg::try_from(stream.next().await.unwrap().unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn places() {
+    let mock = MockCoordinator::new().with_place(place("board-1"));
+    let server = mock.serve().await.unwrap();
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+
+    client.add_place("board-2".to_string()).await.unwrap();
+    assert!(matches!(
+        client.add_place("board-2".to_string()).await,
+        Err(GrpcClientError::TonicStatus(status)) if status.code() == Code::AlreadyExists
+    ));
+    client
+        .set_place_comment("board-2".to_string(), "Rack 3".to_string())
+        .await
+        .unwrap();
+    client
+        .add_place_match(
+            "board-2".to_string(),
+            "exporter-1/board-2/*".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    client
+        .set_place_tags(
+            "board-1".to_string(),
+            HashMap::from([("board".to_string(), String::new())]),
+        )
+        .await
+        .unwrap();
+
+    let places = client.get_places().await.unwrap();
+    assert_eq!(
+        places.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+        vec!["board-1", "board-2"]
+    );
+    assert!(places[0].tags.is_empty());
+    assert_eq!(places[1].comment, "Rack 3");
+    assert_eq!(places[1].matches[0].cls, "*");
+
+    client.delete_place("board-1".to_string()).await.unwrap();
+    assert!(matches!(
+        client.delete_place("board-1".to_string()).await,
+        Err(GrpcClientError::TonicStatus(status)) if status.code() == Code::NotFound
+    ));
+    assert_eq!(mock.places().len(), 1);
+}
+
+#[tokio::test]
+async fn acquire_release() {
+    let mock = MockCoordinator::new().with_place(place("board-1"));
+    let server = mock.serve().await.unwrap();
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+
+    client.acquire_place("board-1".to_string()).await.unwrap();
+    assert!(mock.places()[0].acquired.is_some());
+    assert!(matches!(
+        client.acquire_place("board-1".to_string()).await,
+        Err(GrpcClientError::TonicStatus(status)) if status.code() == Code::FailedPrecondition
+    ));
+    assert!(client
+        .release_place("board-1".to_string(), Some("host/other".to_string()))
+        .await
+        .is_err());
+    client
+        .release_place("board-1".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(mock.places()[0].acquired, None);
+}
+
+#[tokio::test]
+async fn reservations() {
+    let mock = MockCoordinator::new();
+    let server = mock.serve().await.unwrap();
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+
+    let filters = HashMap::from([(
+        "main".to_string(),
+        Filter(HashMap::from([("board".to_string(), "imx8".to_string())])),
+    )]);
+    let reservation = client.create_reservation(filters, 1.).await.unwrap();
+    assert_eq!(
+        reservation.reservation_state(),
+        Some(ReservationState::Waiting)
+    );
+    assert_eq!(
+        client
+            .poll_reservation(reservation.token.clone())
+            .await
+            .unwrap(),
+        reservation
+    );
+    assert_eq!(
+        client.get_reservations().await.unwrap(),
+        vec![reservation.clone()]
+    );
+    client
+        .cancel_reservation(reservation.token.clone())
+        .await
+        .unwrap();
+    assert!(client.poll_reservation(reservation.token).await.is_err());
+    assert!(mock.reservations().is_empty());
+}
+
+#[tokio::test]
+async fn scripted_failures() {
+    let mock = MockCoordinator::new().with_place(place("board-1"));
+    let server = mock.serve().await.unwrap();
+
+    // Without a retry policy the failure is reported
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+    mock.fail_next("GetPlaces", Status::unavailable("restarting"));
+    assert!(matches!(
+        client.get_places().await,
+        Err(GrpcClientError::TonicStatus(status)) if status.code() == Code::Unavailable
+    ));
+
+    // With the default retry policy the call succeeds on the third attempt
+    let mut client = LabgridGrpcClient::builder()
+        .retry_policy(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        })
+        .connect(&server.address())
+        .await
+        .unwrap();
+    mock.fail_next("GetPlaces", Status::unavailable("restarting"));
+    mock.fail_next("GetPlaces", Status::unavailable("restarting"));
+    assert_eq!(client.get_places().await.unwrap().len(), 1);
+    assert_eq!(mock.calls(), vec!["GetPlaces"; 4]);
+
+    // Non-transient errors are not retried
+    mock.fail_next("AcquirePlace", Status::permission_denied("not allowed"));
+    assert!(client.acquire_place("board-1".to_string()).await.is_err());
+    assert_eq!(mock.calls().len(), 5);
+}
+
+#[tokio::test]
+async fn client_stream() {
+    let mock = MockCoordinator::new().with_place(place("board-1"));
+    let server = mock.serve().await.unwrap();
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+
+    let in_msgs = vec![
+        ClientInMsg::StartupDone(StartupDone {
+            version: "1".to_string(),
+            name: "host/me".to_string(),
+        }),
+        ClientInMsg::Subscribe(Subscribe {
+            is_unsubscribe: None,
+            kind: SubscribeKind::AllPlaces(true),
+        }),
+        ClientInMsg::Sync(Sync { id: 7 }),
+    ];
+    // Keep the stream open after sending the messages
+    let in_stream = tokio_stream::iter(in_msgs.clone()).chain(tokio_stream::pending());
+    let mut out_stream = client.client_stream(in_stream).await.unwrap();
+
+    assert_eq!(
+        next(&mut out_stream).await.updates,
+        vec![UpdateResponse::Place(place("board-1"))]
+    );
+    assert_eq!(next(&mut out_stream).await.sync, Some(Sync { id: 7 }));
+    assert_eq!(mock.received_messages(), in_msgs);
+
+    // Changes are sent to the stream, places are acquired by the identity of the startup message
+    client.acquire_place("board-1".to_string()).await.unwrap();
+    let UpdateResponse::Place(acquired) = next(&mut out_stream).await.updates.remove(0) else {
+        panic!("Expected place update");
+    };
+    assert_eq!(acquired.acquired.as_deref(), Some("host/me"));
+    mock.delete_place("board-1");
+    assert_eq!(
+        next(&mut out_stream).await.updates,
+        vec![UpdateResponse::DeletePlace("board-1".to_string())]
+    );
+
+    mock.close_streams();
+    assert!(out_stream.next().await.is_none());
+}
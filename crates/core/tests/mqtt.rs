@@ -0,0 +1,251 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod common;
+
+use common::path;
+use labgrid_ui_core::mqtt::{MqttMessage, MqttOptions, MqttPublisher, MqttStateMirror, MqttStatus};
+use labgrid_ui_core::types::{Place, Resource, UpdateResponse};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+fn place(acquired: Option<&str>) -> UpdateResponse {
+    UpdateResponse::Place(Place {
+        acquired: acquired.map(str::to_string),
+        ..common::place("board-1")
+    })
+}
+
+fn resource(resource_name: &str, available: bool) -> UpdateResponse {
+    UpdateResponse::Resource(Resource {
+        available,
+        ..common::resource(resource_name)
+    })
+}
+
+fn message(topic: &str, payload: &str) -> MqttMessage {
+    MqttMessage {
+        topic: topic.to_string(),
+        payload: payload.to_string(),
+        retain: true,
+    }
+}
+
+#[test]
+fn mqtt_state_mirror() {
+    let mut mirror = MqttStateMirror::new("lab");
+    assert_eq!(
+        mirror.update(&place(None)),
+        vec![
+            message("lab/places/board-1/state", "free"),
+            message("lab/places/board-1/acquired_by", ""),
+        ]
+    );
+    assert!(mirror.update(&place(None)).is_empty());
+    assert_eq!(
+        mirror.update(&place(Some("host/user"))),
+        vec![
+            message("lab/places/board-1/state", "acquired"),
+            message("lab/places/board-1/acquired_by", "host/user"),
+        ]
+    );
+    // Only the owner changed
+    assert_eq!(
+        mirror.update(&place(Some("host/other"))),
+        vec![message("lab/places/board-1/acquired_by", "host/other")]
+    );
+
+    assert_eq!(
+        mirror.update(&resource("serial", true)),
+        vec![message(
+            "lab/resources/exporter-1/board-1/serial/state",
+            "available"
+        )]
+    );
+    assert!(mirror.update(&resource("serial", true)).is_empty());
+    assert_eq!(
+        mirror.update(&resource("serial", false)),
+        vec![message(
+            "lab/resources/exporter-1/board-1/serial/state",
+            "unavailable"
+        )]
+    );
+    assert_eq!(
+        mirror.update(&UpdateResponse::DeleteResource(path("serial"))),
+        vec![message("lab/resources/exporter-1/board-1/serial/state", "")]
+    );
+    assert!(mirror
+        .update(&UpdateResponse::DeleteResource(path("serial")))
+        .is_empty());
+
+    // Only what is not sent again after reconnecting is cleared
+    mirror.update(&resource("power", true));
+    mirror.resync();
+    assert!(mirror.update(&place(Some("host/other"))).is_empty());
+    assert_eq!(
+        mirror.synced(),
+        vec![message("lab/resources/exporter-1/board-1/power/state", "")]
+    );
+    assert!(mirror.synced().is_empty());
+    assert_eq!(
+        mirror.update(&UpdateResponse::DeletePlace("board-1".to_string())),
+        vec![
+            message("lab/places/board-1/state", ""),
+            message("lab/places/board-1/acquired_by", ""),
+        ]
+    );
+}
+
+/// Reads a packet, returns the first byte of the fixed header and the body.
+async fn read_packet(reader: &mut (impl AsyncRead + Unpin)) -> (u8, Vec<u8>) {
+    let header = reader.read_u8().await.unwrap();
+    let (mut len, mut shift) = (0, 0);
+    loop {
+        let byte = reader.read_u8().await.unwrap();
+        len |= usize::from(byte & 0x7f) << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body).await.unwrap();
+    (header, body)
+}
+
+/// Splits a length prefixed string off the start of `body`.
+fn take_str(body: &mut &[u8]) -> String {
+    let len = usize::from(u16::from_be_bytes([body[0], body[1]]));
+    let s = String::from_utf8(body[2..2 + len].to_vec()).unwrap();
+    *body = &body[2 + len..];
+    s
+}
+
+async fn read_publish(reader: &mut (impl AsyncRead + Unpin)) -> MqttMessage {
+    let (header, body) = read_packet(reader).await;
+    assert_eq!(header & 0xf0, 0x30);
+    let mut body = body.as_slice();
+    let topic = take_str(&mut body);
+    MqttMessage {
+        topic,
+        payload: String::from_utf8(body.to_vec()).unwrap(),
+        retain: header & 0x01 != 0,
+    }
+}
+
+#[tokio::test]
+async fn mqtt_publish() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut options = MqttOptions::new(
+        listener.local_addr().unwrap().to_string(),
+        "labgrid-ui-test",
+    );
+    options.username = Some("user".to_string());
+    options.password = Some("secret".to_string());
+    let (publisher, connection) = MqttPublisher::new(options);
+    let mut status = connection.status();
+    let connection = tokio::spawn(connection.run());
+    publisher.publish([
+        // Too long to be encoded, skipped instead of being truncated
+        message(&"ä".repeat(40_000), "free"),
+        message("labgrid/places/board-1/state", "free"),
+    ]);
+
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let (header, body) = read_packet(&mut stream).await;
+    assert_eq!(header, 0x10);
+    let mut body = body.as_slice();
+    assert_eq!(take_str(&mut body), "MQTT");
+    // Level 4, username, password, will retain, will and clean session
+    assert_eq!(&body[..2], &[4, 0xe6]);
+    body = &body[4..];
+    assert_eq!(take_str(&mut body), "labgrid-ui-test");
+    assert_eq!(take_str(&mut body), "labgrid/status");
+    assert_eq!(take_str(&mut body), "offline");
+    assert_eq!(take_str(&mut body), "user");
+    assert_eq!(take_str(&mut body), "secret");
+    stream.write_all(&[0x20, 2, 0, 0]).await.unwrap();
+
+    status
+        .wait_for(|status| *status == MqttStatus::Connected)
+        .await
+        .unwrap();
+    assert_eq!(
+        read_publish(&mut stream).await,
+        message("labgrid/status", "online")
+    );
+    assert_eq!(
+        read_publish(&mut stream).await,
+        message("labgrid/places/board-1/state", "free")
+    );
+
+    // Dropping the publisher disconnects gracefully
+    drop(publisher);
+    assert_eq!(
+        read_publish(&mut stream).await,
+        message("labgrid/status", "offline")
+    );
+    assert_eq!(read_packet(&mut stream).await, (0xe0, Vec::new()));
+    connection.await.unwrap();
+}
+
+#[tokio::test]
+async fn mqtt_password_without_username() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let mut options = MqttOptions::new(
+        listener.local_addr().unwrap().to_string(),
+        "labgrid-ui-test",
+    );
+    options.password = Some("secret".to_string());
+    let (publisher, connection) = MqttPublisher::new(options);
+    let mut status = connection.status();
+    let connection = tokio::spawn(connection.run());
+
+    status
+        .wait_for(|status| matches!(status, MqttStatus::Disconnected { .. }))
+        .await
+        .unwrap();
+    drop(publisher);
+    connection.await.unwrap();
+}
+
+#[tokio::test]
+async fn mqtt_oversized_packet() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let options = MqttOptions::new(
+        listener.local_addr().unwrap().to_string(),
+        "labgrid-ui-test",
+    );
+    let (publisher, connection) = MqttPublisher::new(options);
+    let mut status = connection.status();
+    let connection = tokio::spawn(connection.run());
+
+    let (mut stream, _) = listener.accept().await.unwrap();
+    read_packet(&mut stream).await;
+    // CONNACK claiming the largest remaining length
+    stream
+        .write_all(&[0x20, 0xff, 0xff, 0xff, 0x7f])
+        .await
+        .unwrap();
+    let status = status
+        .wait_for(|status| matches!(status, MqttStatus::Disconnected { .. }))
+        .await
+        .unwrap()
+        .clone();
+    assert!(
+        matches!(&status, MqttStatus::Disconnected { error } if error.contains("too large")),
+        "{status:?}"
+    );
+    drop(publisher);
+    connection.await.unwrap();
+}
+
+#[test]
+fn mqtt_options_debug() {
+    let mut options = MqttOptions::new("localhost:1883", "labgrid-ui");
+    options.username = Some("user".to_string());
+    options.password = Some("secret".to_string());
+    let debug = format!("{options:?}");
+    assert!(debug.contains("user") && !debug.contains("secret"));
+}
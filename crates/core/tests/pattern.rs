@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::pattern::{MatchPattern, PatternError};
+use labgrid_ui_core::types::{Path, ResourceMatch};
+use proptest::prelude::*;
+
+fn path(exporter: Option<&str>, group: &str, name: &str) -> Path {
+    Path {
+        exporter_name: exporter.map(str::to_string),
+        group_name: group.to_string(),
+        resource_name: name.to_string(),
+    }
+}
+
+fn arb_component() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_.-]{1,12}"
+}
+
+fn arb_pattern_component() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9_.*?-]{1,12}"
+}
+
+proptest! {
+    #[test]
+    fn path_roundtrip(
+        exporter_name in proptest::option::of(arb_component()),
+        group_name in arb_component(),
+        resource_name in arb_component(),
+    ) {
+        let path = Path { exporter_name, group_name, resource_name };
+        prop_assert_eq!(path.to_string().parse::<Path>().unwrap(), path);
+    }
+
+    #[test]
+    fn match_pattern_roundtrip(
+        exporter in arb_pattern_component(),
+        group in arb_pattern_component(),
+        cls in arb_pattern_component(),
+        name in proptest::option::of(arb_pattern_component()),
+    ) {
+        let pattern = MatchPattern { exporter, group, cls, name };
+        prop_assert_eq!(pattern.to_string().parse::<MatchPattern>().unwrap(), pattern);
+    }
+
+    #[test]
+    fn resource_pattern_matches_itself(
+        exporter_name in arb_component(),
+        group_name in arb_component(),
+        resource_name in arb_component(),
+        cls in arb_component(),
+    ) {
+        let path = Path { exporter_name: Some(exporter_name), group_name, resource_name };
+        prop_assert!(MatchPattern::for_resource(&path, &cls).matches(&path, &cls));
+    }
+}
+
+#[test]
+fn path_parse() {
+    assert_eq!(
+        "exporter-1/board-1/serial".parse::<Path>(),
+        Ok(path(Some("exporter-1"), "board-1", "serial"))
+    );
+    assert_eq!(
+        "board-1/serial".parse::<Path>(),
+        Ok(path(None, "board-1", "serial"))
+    );
+    assert!(matches!(
+        "serial".parse::<Path>(),
+        Err(PatternError::ComponentCount { found: 1, .. })
+    ));
+    assert!(matches!(
+        "exporter-1//serial".parse::<Path>(),
+        Err(PatternError::EmptyComponent { .. })
+    ));
+    assert!(matches!(
+        "exporter-1/*/serial".parse::<Path>(),
+        Err(PatternError::Wildcard { .. })
+    ));
+}
+
+#[test]
+fn match_pattern_parse() {
+    let pattern = "exporter-1/board-1/NetworkSerialPort"
+        .parse::<MatchPattern>()
+        .unwrap();
+    assert_eq!(pattern.name, None);
+    assert_eq!(
+        pattern.into_resource_match(Some("console".to_string())),
+        ResourceMatch {
+            exporter: "exporter-1".to_string(),
+            group: "board-1".to_string(),
+            cls: "NetworkSerialPort".to_string(),
+            name: None,
+            rename: Some("console".to_string()),
+        }
+    );
+    assert!("exporter-1/board-1".parse::<MatchPattern>().is_err());
+    assert!("a/b/c/d/e".parse::<MatchPattern>().is_err());
+    assert!("a/b//d".parse::<MatchPattern>().is_err());
+}
+
+#[test]
+fn match_pattern_wildcards() {
+    let serial = path(Some("exporter-1"), "board-1", "serial");
+    let matches = |pattern: &str, cls: &str| {
+        pattern
+            .parse::<MatchPattern>()
+            .unwrap()
+            .matches(&serial, cls)
+    };
+
+    assert!(matches(
+        "exporter-1/board-1/NetworkSerialPort",
+        "NetworkSerialPort"
+    ));
+    assert!(matches("*/*/*", "NetworkSerialPort"));
+    assert!(matches(
+        "exporter-?/board-[0-9]/*SerialPort/ser*",
+        "USBSerialPort"
+    ));
+    assert!(matches("exporter-[!2]/board-1/*/serial", "USBSerialPort"));
+    assert!(!matches("exporter-[!1]/board-1/*/serial", "USBSerialPort"));
+    assert!(!matches("exporter-1/board-1/*/power", "USBSerialPort"));
+    assert!(!matches(
+        "exporter-1/board-1/usbserialport",
+        "USBSerialPort"
+    ));
+    // Unterminated sets are matched literally
+    assert!(!matches("exporter-1/board-[1/*", "USBSerialPort"));
+}
@@ -0,0 +1,33 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::tonic::{Code, Status};
+use labgrid_ui_core::RetryPolicy;
+use std::time::Duration;
+
+#[test]
+fn should_retry() {
+    let policy = RetryPolicy::default();
+    let unavailable = Status::new(Code::Unavailable, "connection reset");
+    assert!(policy.should_retry(&unavailable, 1));
+    assert!(policy.should_retry(&unavailable, 2));
+    assert!(!policy.should_retry(&unavailable, 3));
+    assert!(!policy.should_retry(&Status::new(Code::NotFound, "no such place"), 1));
+}
+
+#[test]
+fn backoff() {
+    let policy = RetryPolicy {
+        max_attempts: 10,
+        initial_backoff: Duration::from_millis(100),
+        max_backoff: Duration::from_secs(1),
+        backoff_multiplier: 3,
+        ..RetryPolicy::default()
+    };
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(300));
+    assert_eq!(policy.backoff(3), Duration::from_millis(900));
+    assert_eq!(policy.backoff(4), Duration::from_secs(1));
+    assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(1));
+}
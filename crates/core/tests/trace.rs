@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::mock::MockCoordinator;
+use labgrid_ui_core::tonic::Status;
+use labgrid_ui_core::trace::{Direction, MessageTrace};
+use labgrid_ui_core::types::{ClientInMsg, StartupDone};
+use labgrid_ui_core::LabgridGrpcClient;
+use tokio_stream::StreamExt;
+
+fn types(trace: &MessageTrace) -> Vec<(Direction, &'static str)> {
+    trace
+        .messages()
+        .iter()
+        .map(|traced| (traced.direction, traced.message_type))
+        .collect()
+}
+
+#[test]
+fn trace_ring_buffer() {
+    let trace = MessageTrace::new(2);
+    trace.record(Direction::Sent, &"ignored".to_string());
+    assert!(trace.is_empty());
+
+    trace.set_enabled(true);
+    for message in ["first", "second", "third"] {
+        trace.record(Direction::Sent, &message.to_string());
+    }
+    let messages = trace.messages();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].message, "\"second\"");
+    assert_eq!(messages[1].message_type, "String");
+    assert!(trace
+        .dump()
+        .lines()
+        .all(|line| line.contains(" -> String: ")));
+
+    trace.clear();
+    assert!(trace.is_empty());
+}
+
+#[tokio::test]
+async fn trace_calls() {
+    let mock = MockCoordinator::new();
+    let server = mock.serve().await.unwrap();
+    let trace = MessageTrace::default();
+    let mut client = LabgridGrpcClient::builder()
+        .message_trace(trace.clone())
+        .connect(&server.address())
+        .await
+        .unwrap();
+
+    client.get_places().await.unwrap();
+    assert!(trace.is_empty());
+
+    trace.set_enabled(true);
+    client.get_places().await.unwrap();
+    mock.fail_next("GetPlaces", Status::unavailable("restarting"));
+    assert!(client.get_places().await.is_err());
+    assert_eq!(
+        types(&trace),
+        [
+            (Direction::Sent, "GetPlacesRequest"),
+            (Direction::Received, "GetPlacesResponse"),
+            (Direction::Sent, "GetPlacesRequest"),
+            (Direction::Received, "Status"),
+        ]
+    );
+    assert!(trace.messages()[3].message.contains("restarting"));
+}
+
+#[tokio::test]
+async fn trace_client_stream() {
+    let mock = MockCoordinator::new();
+    let server = mock.serve().await.unwrap();
+    let trace = MessageTrace::default();
+    trace.set_enabled(true);
+    let mut client = LabgridGrpcClient::builder()
+        .message_trace(trace.clone())
+        .connect(&server.address())
+        .await
+        .unwrap();
+
+    let in_stream = tokio_stream::iter([ClientInMsg::StartupDone(StartupDone {
+        version: "1".to_string(),
+        name: "host/me".to_string(),
+    })])
+    .chain(tokio_stream::pending());
+    let mut out_stream = client.client_stream(in_stream).await.unwrap();
+    client.add_place("board-1".to_string()).await.unwrap();
+    out_stream.next().await.unwrap().unwrap();
+
+    let types = types(&trace);
+    assert!(types.contains(&(Direction::Sent, "ClientInMessage")));
+    assert!(types.contains(&(Direction::Received, "ClientOutMessage")));
+    assert!(trace.dump().contains("host/me"));
+}
@@ -0,0 +1,74 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::error::GrpcClientError;
+use labgrid_ui_core::mock::MockCoordinator;
+use labgrid_ui_core::tonic::Code;
+use labgrid_ui_core::version::{self, Compatibility, LabgridVersion};
+use labgrid_ui_core::LabgridGrpcClient;
+
+#[test]
+fn parse() {
+    assert_eq!(
+        LabgridVersion::parse("24.0.1"),
+        Some(LabgridVersion {
+            major: 24,
+            minor: 0
+        })
+    );
+    assert_eq!(
+        LabgridVersion::parse("25.1.dev12+g4a8e2c1"),
+        Some(LabgridVersion {
+            major: 25,
+            minor: 1
+        })
+    );
+    assert_eq!(
+        LabgridVersion::parse(" 26 "),
+        Some(LabgridVersion {
+            major: 26,
+            minor: 0
+        })
+    );
+    assert_eq!(
+        LabgridVersion::parse("25.0rc1").map(|v| v.to_string()),
+        Some("25.0".to_string())
+    );
+    assert_eq!(LabgridVersion::parse("v24.0"), None);
+    assert_eq!(LabgridVersion::parse(""), None);
+}
+
+#[test]
+fn compatibility() {
+    assert_eq!(
+        version::compatibility("23.0.6"),
+        Compatibility::Incompatible
+    );
+    assert_eq!(version::compatibility("24.0"), Compatibility::Compatible);
+    assert_eq!(version::compatibility("25.0.1"), Compatibility::Compatible);
+    assert_eq!(version::compatibility("26.0"), Compatibility::Untested);
+    assert_eq!(version::compatibility("unknown"), Compatibility::Untested);
+    assert_eq!(
+        version::compatibility(version::PROTOCOL_VERSION),
+        Compatibility::Compatible
+    );
+}
+
+#[tokio::test]
+async fn coordinator_version() {
+    let server = MockCoordinator::new()
+        .with_version("25.0.1")
+        .serve()
+        .await
+        .unwrap();
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+    assert_eq!(client.coordinator_version().await.unwrap(), "25.0.1");
+
+    let server = MockCoordinator::new().serve().await.unwrap();
+    let mut client = LabgridGrpcClient::new(&server.address()).await.unwrap();
+    assert!(matches!(
+        client.coordinator_version().await,
+        Err(GrpcClientError::TonicStatus(status)) if status.code() == Code::Unimplemented
+    ));
+}
@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+mod common;
+
+use common::{path, reservation};
+use labgrid_ui_core::types::{Place, UpdateResponse};
+use labgrid_ui_core::webhooks::{WebhookEvent, WebhookNotifier, WebhookTracker, WebhookUrl};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+fn place(acquired: Option<&str>) -> UpdateResponse {
+    UpdateResponse::Place(Place {
+        acquired: acquired.map(str::to_string),
+        ..common::place("board-1")
+    })
+}
+
+fn resource(resource_name: &str) -> UpdateResponse {
+    UpdateResponse::Resource(common::resource(resource_name))
+}
+
+#[test]
+fn webhook_tracker() {
+    let mut tracker = WebhookTracker::new();
+    // The initial state doesn't cause events
+    assert!(tracker.update(&place(Some("host/user"))).is_empty());
+    assert!(tracker.update(&resource("serial")).is_empty());
+    tracker.synced();
+
+    assert_eq!(
+        tracker.update(&place(Some("host/other"))),
+        vec![
+            WebhookEvent::PlaceReleased {
+                place: "board-1".to_string(),
+                owner: "host/user".to_string()
+            },
+            WebhookEvent::PlaceAcquired {
+                place: "board-1".to_string(),
+                owner: "host/other".to_string()
+            },
+        ]
+    );
+    assert!(tracker.update(&place(Some("host/other"))).is_empty());
+    // Resource updates of known resources only change their state
+    assert!(tracker.update(&resource("serial")).is_empty());
+    assert_eq!(
+        tracker.update(&resource("power")),
+        vec![WebhookEvent::ResourceAdded {
+            path: "exporter-1/board-1/power".to_string(),
+            cls: "NetworkSerialPort".to_string()
+        }]
+    );
+    assert_eq!(
+        tracker.update(&UpdateResponse::DeleteResource(path("serial"))),
+        vec![WebhookEvent::ResourceRemoved {
+            path: "exporter-1/board-1/serial".to_string()
+        }]
+    );
+
+    assert!(tracker
+        .reservations(&[reservation("AAAA", 0), reservation("BBBB", 0)])
+        .is_empty());
+    assert_eq!(
+        tracker.reservations(&[reservation("AAAA", 1), reservation("CCCC", 0)]),
+        vec![
+            WebhookEvent::ReservationChanged {
+                token: "AAAA".to_string(),
+                owner: "host/user".to_string(),
+                state: "allocated".to_string()
+            },
+            WebhookEvent::ReservationAdded {
+                token: "CCCC".to_string(),
+                owner: "host/user".to_string(),
+                state: "waiting".to_string()
+            },
+            WebhookEvent::ReservationRemoved {
+                token: "BBBB".to_string(),
+                owner: "host/user".to_string()
+            },
+        ]
+    );
+
+    tracker.reset();
+    assert!(tracker.update(&resource("power")).is_empty());
+}
+
+#[test]
+fn webhook_url() {
+    assert!("http://localhost:8080/hooks/labgrid"
+        .parse::<WebhookUrl>()
+        .is_ok());
+    assert!("https://localhost/hooks".parse::<WebhookUrl>().is_err());
+    assert!("/hooks".parse::<WebhookUrl>().is_err());
+}
+
+#[tokio::test]
+async fn webhook_delivery() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let url = format!("http://user:secret@{addr}/hooks/labgrid")
+        .parse::<WebhookUrl>()
+        .unwrap();
+    let (notifier, delivery) = WebhookNotifier::new(vec![url], "test".to_string());
+    tokio::spawn(delivery.run());
+    notifier.notify([WebhookEvent::ResourceRemoved {
+        path: "exporter-1/board-1/serial".to_string(),
+    }]);
+
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).await.unwrap();
+    assert_eq!(request_line, "POST /hooks/labgrid HTTP/1.1\r\n");
+    let mut content_length = 0;
+    let mut host = None;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).await.unwrap();
+        if header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+            if name.eq_ignore_ascii_case("host") {
+                host = Some(value.trim().to_string());
+            }
+        }
+    }
+    // The user info is not sent
+    assert_eq!(host, Some(addr.to_string()));
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await.unwrap();
+    reader
+        .get_mut()
+        .write_all(b"HTTP/1.1 204 No Content\r\n\r\n")
+        .await
+        .unwrap();
+
+    let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(payload["event"], "resource_removed");
+    assert_eq!(payload["path"], "exporter-1/board-1/serial");
+    assert_eq!(payload["source"], "test");
+    assert!(payload["time"].as_f64().unwrap() > 0.);
+}
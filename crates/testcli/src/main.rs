@@ -2,19 +2,58 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+// Modules
+mod monitor;
+mod table;
+mod watch;
+
 use anyhow::Context;
-use clap::Parser;
-use labgrid_ui_core::LabgridGrpcClient;
-use std::collections::HashMap;
+use clap::{CommandFactory, Parser};
+use clap_complete::env::{self, EnvCompleter};
+use clap_complete::{ArgValueCandidates, CompleteEnv, CompletionCandidate};
+use labgrid_ui_core::auth::{CallCredentials, TokenSource};
+use labgrid_ui_core::error::GrpcClientError;
+use labgrid_ui_core::filter::FilterExpr;
+use labgrid_ui_core::metrics::{Metrics, MetricsExport};
+use labgrid_ui_core::pattern::MatchPattern;
+use labgrid_ui_core::types::Reservation;
+use labgrid_ui_core::webhooks::{WebhookNotifier, WebhookUrl};
+use labgrid_ui_core::{ClientTimeouts, LabgridGrpcClient, RetryPolicy};
 use std::error::Error;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+use table::PlaceColumn;
+use tokio::task::JoinSet;
 use tokio_util::sync::CancellationToken;
 use tracing::debug;
+use watch::WatchTarget;
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
-    /// Coordinator host and port.
+    /// Coordinator host and port, or URI (`http://`, `https://`, `unix://`).
     #[arg(short = 'c', long, env = "LG_COORDINATOR")]
-    coordinator: String,
+    coordinator: Option<String>,
+    /// Maximum number of attempts of calls failing because the coordinator is temporarily unavailable.
+    #[arg(long, default_value_t = 1)]
+    max_attempts: u32,
+    /// Timeout in seconds for connecting and for each call, streams are not affected.
+    #[arg(long)]
+    timeout: Option<u64>,
+    /// Bearer token sent with every call, for coordinators behind an authenticating proxy.
+    #[arg(long, env = "LG_AUTH_TOKEN", group = "auth", hide_env_values = true)]
+    auth_token: Option<String>,
+    /// File containing the bearer token, read again every second.
+    #[arg(long, group = "auth")]
+    auth_token_file: Option<PathBuf>,
+    /// Command printing the bearer token, run again after five minutes.
+    #[arg(long, group = "auth")]
+    auth_token_command: Option<String>,
+    /// Metadata entry sent with every call as `key=value`, can be supplied multiple times.
+    #[arg(long = "grpc-metadata", value_name = "KEY=VALUE")]
+    grpc_metadata: Vec<String>,
     #[command(subcommand)]
     cmd: Command,
 }
@@ -32,63 +71,123 @@ pub enum Command {
         #[arg(short, long)]
         name: String,
     },
-    GetPlaces,
+    /// Print all places as a table.
+    GetPlaces {
+        /// The displayed columns, separated by commas.
+        #[arg(long, value_enum, value_delimiter = ',', default_values_t = PlaceColumn::ALL)]
+        columns: Vec<PlaceColumn>,
+        /// The column by which the places get sorted.
+        #[arg(long, value_enum, default_value_t = PlaceColumn::Name)]
+        sort_by: PlaceColumn,
+    },
+    /// Continuously print a table of all places or resources, re-rendered when updates arrive.{n}
+    /// Terminates on Ctrl-C.
+    Watch {
+        #[arg(value_enum)]
+        target: WatchTarget,
+        /// The displayed columns of the places table, separated by commas.
+        #[arg(long, value_enum, value_delimiter = ',', default_values_t = PlaceColumn::ALL)]
+        columns: Vec<PlaceColumn>,
+        /// The column by which the places get sorted.
+        #[arg(long, value_enum, default_value_t = PlaceColumn::Name)]
+        sort_by: PlaceColumn,
+    },
+    /// Record operational metrics of the places and resources observed through the client stream
+    /// and export them, without a UI.{n}
+    /// Initiates the client stream again when it is lost. Terminates on Ctrl-C.
+    #[command(group(clap::ArgGroup::new("export").required(true).multiple(true).args(["listen", "file"])))]
+    Metrics {
+        /// Serve the metrics in the Prometheus text format at `http://<ADDR>/metrics`, e.g. `127.0.0.1:9464`.
+        #[arg(long)]
+        listen: Option<SocketAddr>,
+        /// Periodically write the metrics in the Prometheus text format to this file.
+        #[arg(long)]
+        file: Option<PathBuf>,
+        /// Seconds between writes of the metrics file.
+        #[arg(long, default_value_t = 15)]
+        interval: u64,
+    },
+    /// POST JSON notifications to webhooks when places are acquired or released,
+    /// resources appear or disappear or reservations change, without a UI.{n}
+    /// Initiates the client stream again when it is lost. Terminates on Ctrl-C.
+    Webhooks {
+        /// The URL of a webhook, can be supplied multiple times. Only `http` URLs are supported.
+        #[arg(long = "url", required = true)]
+        urls: Vec<WebhookUrl>,
+        /// Seconds between polls of the reservations.
+        #[arg(long, default_value_t = 10)]
+        reservations_interval: u64,
+    },
     AddPlaceAlias {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
         #[arg(short, long)]
         alias: String,
     },
     DeletePlaceAlias {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
         #[arg(short, long)]
         alias: String,
     },
     SetPlaceTags {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
         /// Specify the place tags. Allows repeated argument invokations.{n}
         /// e.g. `set-place-tags -t "board=foo" -t "category=bar" ..`.
         #[arg(short = 't', long = "tag", value_parser = parse_key_val::<String, String>)]
         tags: Vec<(String, String)>,
     },
+    SetPlaceComment {
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
+        place_name: String,
+        #[arg(short = 'm', long)]
+        comment: String,
+    },
     AddPlaceMatch {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
-        #[arg(short, long)]
-        pattern: String,
+        /// Match pattern `exporter/group/cls[/name]`, components may contain wildcards.
+        #[arg(short = 'm', long)]
+        pattern: MatchPattern,
         #[arg(short, long)]
         rename: Option<String>,
     },
     DeletePlaceMatch {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
-        #[arg(short, long)]
-        pattern: String,
+        /// Match pattern `exporter/group/cls[/name]`, components may contain wildcards.
+        #[arg(short = 'm', long)]
+        pattern: MatchPattern,
         #[arg(short, long)]
         rename: Option<String>,
     },
     AcquirePlace {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
     },
     ReleasePlace {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
         #[arg(short, long)]
         from_user: Option<String>,
     },
     AllowPlace {
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(place_candidates))]
         place_name: String,
         #[arg(short, long)]
         user: String,
     },
     CreateReservation {
-        // TODO: filters parsing
         #[arg(short, long)]
         prio: f64,
+        /// Filter expression selecting the places to reserve, e.g. `board=foo AND lab=muc`.{n}
+        /// Expressions containing `OR`, `NOT` or comparisons other than `=` can only be previewed with `--dry-run`.
+        #[arg(short = 'f', long)]
+        filter_expr: Option<FilterExpr>,
+        /// Only print the places matching the filter expression instead of creating the reservation.
+        #[arg(long, requires = "filter_expr")]
+        dry_run: bool,
     },
     CancelReservation {
         #[arg(short, long)]
@@ -99,6 +198,94 @@ pub enum Command {
         token: String,
     },
     GetReservations,
+    /// Cancel all selected reservations and print a summary.{n}
+    /// Selects the expired and invalid reservations with `--stale`, the own reservations with `--mine`,
+    /// or the reservations matching both.
+    #[command(group(clap::ArgGroup::new("selection").required(true).multiple(true).args(["mine", "stale"])))]
+    CancelAll {
+        /// Select the reservations owned by `LG_HOSTNAME/LG_USERNAME`,
+        /// defaulting to the system host and user name.
+        #[arg(long)]
+        mine: bool,
+        /// Select expired and invalid reservations.
+        #[arg(long)]
+        stale: bool,
+        /// Only print the selected reservations instead of cancelling them.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Print the shell completion script to stdout.{n}
+    /// e.g. `source <(labgrid-ui-testcli completions bash)` in `~/.bashrc`.{n}
+    /// Place names are completed with the places of the coordinator in `LG_COORDINATOR`.
+    Completions {
+        #[arg(value_enum)]
+        shell: CompletionShell,
+    },
+}
+
+/// Shells for which completions can be generated.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl CompletionShell {
+    fn completer(self) -> &'static dyn EnvCompleter {
+        match self {
+            CompletionShell::Bash => &env::Bash,
+            CompletionShell::Zsh => &env::Zsh,
+            CompletionShell::Fish => &env::Fish,
+        }
+    }
+}
+
+/// Name of the environment variable selecting the shell when the completion script calls the CLI.
+const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Seconds to wait for the coordinator when completing place names.
+const COMPLETE_PLACES_TIMEOUT: u64 = 2;
+
+/// Generates the completion script for the supplied shell.
+///
+/// The script calls the CLI with `COMPLETE` set, which completes the arguments through [`CompleteEnv`].
+fn completions(shell: CompletionShell) -> anyhow::Result<String> {
+    let cmd = Cli::command();
+    let bin = cmd.get_name();
+    let mut buf = Vec::new();
+    shell
+        .completer()
+        .write_registration(COMPLETE_VAR, bin, bin, bin, &mut buf)?;
+    Ok(String::from_utf8_lossy(&buf).to_string())
+}
+
+/// The names of the places of the coordinator in `LG_COORDINATOR`, offered when completing place names.
+fn place_candidates() -> Vec<CompletionCandidate> {
+    let Ok(addr) = std::env::var("LG_COORDINATOR") else {
+        return Vec::new();
+    };
+    let Ok(runtime) = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    else {
+        return Vec::new();
+    };
+    let places = runtime.block_on(async {
+        let timeout = Duration::from_secs(COMPLETE_PLACES_TIMEOUT);
+        tokio::time::timeout(timeout, async {
+            let mut grpc_client = LabgridGrpcClient::new(&addr).await.ok()?;
+            grpc_client.get_places().await.ok()
+        })
+        .await
+        .ok()
+        .flatten()
+    });
+    places
+        .unwrap_or_default()
+        .into_iter()
+        .map(|place| CompletionCandidate::new(place.name))
+        .collect()
 }
 
 fn parse_key_val<T, U>(s: &str) -> Result<(T, U), Box<dyn Error + Send + Sync + 'static>>
@@ -114,12 +301,96 @@ where
     Ok((s[..pos].parse()?, s[pos + 1..].parse()?))
 }
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
+/// Exit code when the coordinator could not be reached or the connection was lost.
+const EXIT_CODE_FATAL: u8 = 2;
+
+fn main() -> ExitCode {
+    // Completes the arguments instead when called by the completion scripts,
+    // before starting the runtime because the candidates are fetched blocking
+    CompleteEnv::with_factory(Cli::command)
+        .var(COMPLETE_VAR)
+        .complete();
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            eprintln!("Error: {error:?}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match runtime.block_on(run()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {error:?}");
+            exit_code(&error)
+        }
+    }
+}
+
+/// The exit code for the error, distinguishing fatal client errors from failed operations.
+fn exit_code(error: &anyhow::Error) -> ExitCode {
+    let kind = error
+        .chain()
+        .find_map(|e| e.downcast_ref::<GrpcClientError>())
+        .map(GrpcClientError::kind);
+    match kind {
+        Some(kind) if kind.is_fatal() => {
+            eprintln!("Hint: {kind}, check the coordinator address and that it is running");
+            ExitCode::from(EXIT_CODE_FATAL)
+        }
+        Some(kind) if kind.is_retryable() => {
+            eprintln!("Hint: {kind}, retrying later might succeed");
+            ExitCode::FAILURE
+        }
+        _ => ExitCode::FAILURE,
+    }
+}
+
+/// The credentials of the authentication arguments.
+fn credentials(cli: &Cli) -> anyhow::Result<CallCredentials> {
+    let mut credentials = CallCredentials::new();
+    if let Some(token) = &cli.auth_token {
+        credentials = credentials.token(TokenSource::Static(token.clone()));
+    } else if let Some(path) = &cli.auth_token_file {
+        credentials = credentials.token(TokenSource::File(path.clone()));
+    } else if let Some(command) = &cli.auth_token_command {
+        credentials = credentials.token(TokenSource::Command {
+            command: command.clone(),
+            refresh: Duration::from_secs(300),
+        });
+    }
+    for entry in &cli.grpc_metadata {
+        let (key, value) = entry
+            .split_once('=')
+            .with_context(|| format!("Metadata '{entry}' is not 'KEY=VALUE'"))?;
+        credentials = credentials.metadata(key, value)?;
+    }
+    Ok(credentials)
+}
+
+async fn run() -> anyhow::Result<()> {
     setup_tracing_subscriber()?;
     let cli = Cli::parse();
-    let addr = cli.coordinator;
-    let mut grpc_client = LabgridGrpcClient::new(&addr).await?;
+    if let Command::Completions { shell } = cli.cmd {
+        print!("{}", completions(shell)?);
+        return Ok(());
+    }
+    let credentials = credentials(&cli)?;
+    let addr = cli
+        .coordinator
+        .context("Coordinator must be supplied through '--coordinator' or 'LG_COORDINATOR'")?;
+    let timeout = cli.timeout.map(Duration::from_secs);
+    let mut grpc_client = LabgridGrpcClient::builder()
+        .timeouts(ClientTimeouts {
+            connect: timeout,
+            rpc: timeout,
+        })
+        .retry_policy(RetryPolicy {
+            max_attempts: cli.max_attempts,
+            ..RetryPolicy::default()
+        })
+        .credentials(credentials)
+        .connect(&addr)
+        .await?;
     let quit_token = CancellationToken::new();
 
     let quit_token_c = quit_token.clone();
@@ -160,19 +431,61 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
         }
-        Command::GetPlaces => {
-            println!("Get Places");
+        Command::GetPlaces { columns, sort_by } => {
             tokio::select! {
                 places = grpc_client.get_places() => {
-                    println!("Got places:");
-                    for place in places? {
-                        println!("  - {place:#?}");
-                    }
+                    print!("{}", table::places_table(places?, &columns, sort_by));
                 },
                 _ = quit_token.cancelled() => {
                 }
             }
         }
+        Command::Watch {
+            target,
+            columns,
+            sort_by,
+        } => {
+            watch::watch(&mut grpc_client, target, &columns, sort_by, quit_token).await?;
+        }
+        Command::Metrics {
+            listen,
+            file,
+            interval,
+        } => {
+            let metrics = Metrics::new();
+            let mut exports = JoinSet::new();
+            let file = file.map(|path| MetricsExport::File {
+                path,
+                interval: Duration::from_secs(interval),
+            });
+            for export in listen.map(MetricsExport::Http).into_iter().chain(file) {
+                exports.spawn(export.run(Arc::clone(&metrics)));
+            }
+            tokio::select! {
+                res = monitor::monitor(&mut grpc_client, &metrics, quit_token) => res?,
+                Some(res) = exports.join_next() => {
+                    res.context("Join metrics export")?.context("Export metrics")?;
+                }
+            }
+        }
+        Command::Webhooks {
+            urls,
+            reservations_interval,
+        } => {
+            let (notifier, delivery) =
+                WebhookNotifier::new(urls, format!("labgrid-ui-testcli/{}", std::process::id()));
+            let delivery = tokio::spawn(delivery.run());
+            monitor::notify_webhooks(
+                &mut grpc_client,
+                &notifier,
+                Duration::from_secs(reservations_interval.max(1)),
+                quit_token,
+            )
+            .await?;
+            // Let the delivery finish the pending notifications
+            drop(notifier);
+            delivery.await.context("Join webhook delivery")?;
+        }
         Command::AddPlaceAlias { place_name, alias } => {
             println!("Add place alias");
             tokio::select! {
@@ -204,6 +517,20 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
         }
+        Command::SetPlaceComment {
+            place_name,
+            comment,
+        } => {
+            println!("Set place comment");
+
+            tokio::select! {
+                res = grpc_client.set_place_comment(place_name, comment) => {
+                    res.context("Set place comment result")?;
+                },
+                _ = quit_token.cancelled() => {
+                }
+            }
+        }
         Command::AddPlaceMatch {
             place_name,
             pattern,
@@ -212,7 +539,7 @@ async fn main() -> anyhow::Result<()> {
             println!("Add place match");
 
             tokio::select! {
-                res = grpc_client.add_place_match(place_name, pattern, rename) => {
+                res = grpc_client.add_place_match(place_name, pattern.to_string(), rename) => {
                     res.context("Add place match result")?;
                 },
                 _ = quit_token.cancelled() => {
@@ -227,7 +554,7 @@ async fn main() -> anyhow::Result<()> {
             println!("Delete place match");
 
             tokio::select! {
-                res = grpc_client.delete_place_match(place_name, pattern, rename) => {
+                res = grpc_client.delete_place_match(place_name, pattern.to_string(), rename) => {
                     res.context("Delete place match result")?;
                 },
                 _ = quit_token.cancelled() => {
@@ -270,9 +597,33 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
         }
-        Command::CreateReservation { prio } => {
+        Command::CreateReservation {
+            prio,
+            filter_expr: Some(filter_expr),
+            dry_run: true,
+        } => {
+            tokio::select! {
+                places = grpc_client.get_places() => {
+                    let places = places?
+                        .into_iter()
+                        .filter(|p| filter_expr.matches(&p.tags))
+                        .collect();
+                    println!("Places matching '{filter_expr}' (prio {prio}):");
+                    print!("{}", table::places_table(places, &PlaceColumn::ALL, PlaceColumn::Name));
+                },
+                _ = quit_token.cancelled() => {
+                }
+            }
+        }
+        Command::CreateReservation {
+            prio, filter_expr, ..
+        } => {
             println!("Create reservation");
-            let filters = HashMap::default();
+            let filters = filter_expr
+                .map(|e| e.to_filters())
+                .transpose()
+                .context("Convert filter expression")?
+                .unwrap_or_default();
 
             tokio::select! {
                 res = grpc_client.create_reservation(filters, prio) => {
@@ -319,6 +670,80 @@ async fn main() -> anyhow::Result<()> {
                 }
             }
         }
+        Command::CancelAll {
+            mine,
+            stale,
+            dry_run,
+        } => {
+            let identity = lg_identity();
+            tokio::select! {
+                res = cancel_all(&mut grpc_client, |r| (!mine || r.owner == identity) && (!stale || r.is_stale()), dry_run) => {
+                    res?;
+                },
+                _ = quit_token.cancelled() => {
+                }
+            }
+        }
+        Command::Completions { .. } => {
+            unreachable!("Completions are generated before connecting to the coordinator")
+        }
+    }
+    Ok(())
+}
+
+/// The `host/user` name the coordinator reports as owner of reservations created by this client.
+///
+/// Read from `LG_HOSTNAME` and `LG_USERNAME`, defaulting to the system host and user name like labgrid does.
+fn lg_identity() -> String {
+    let hostname =
+        std::env::var("LG_HOSTNAME").unwrap_or_else(|_| whoami::hostname().unwrap_or_default());
+    let username =
+        std::env::var("LG_USERNAME").unwrap_or_else(|_| whoami::username().unwrap_or_default());
+    format!("{hostname}/{username}")
+}
+
+/// Cancels all reservations selected by `select` and prints a summary.
+///
+/// Fails if cancelling any of the reservations failed, the remaining ones are still attempted.
+async fn cancel_all(
+    grpc_client: &mut LabgridGrpcClient,
+    select: impl Fn(&Reservation) -> bool,
+    dry_run: bool,
+) -> anyhow::Result<()> {
+    let reservations = grpc_client
+        .get_reservations()
+        .await
+        .context("Get reservations result")?
+        .into_iter()
+        .filter(|r| select(r))
+        .collect::<Vec<_>>();
+    if dry_run {
+        println!("Selected {} reservations:", reservations.len());
+        for reservation in &reservations {
+            println!("  - {} ({})", reservation.token, reservation.owner);
+        }
+        return Ok(());
+    }
+    let total = reservations.len();
+    let mut failed = 0;
+    for reservation in reservations {
+        match grpc_client
+            .cancel_reservation(reservation.token.clone())
+            .await
+        {
+            Ok(()) => println!("Cancelled {} ({})", reservation.token, reservation.owner),
+            Err(error) if error.is_fatal() => {
+                return Err(error).context("Cancel reservation result");
+            }
+            Err(error) => {
+                eprintln!("Cancelling {} failed: {error}", reservation.token);
+                failed += 1;
+            }
+        }
+    }
+    println!("Cancelled {} of {total} reservations", total - failed);
+    if failed > 0 {
+        anyhow::bail!("Cancelling {failed} reservations failed");
     }
     Ok(())
 }
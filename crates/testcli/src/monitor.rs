@@ -0,0 +1,190 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Context;
+use labgrid_ui_core::metrics::Metrics;
+use labgrid_ui_core::proto;
+use labgrid_ui_core::types::{
+    self, ClientInMsg, ClientOutMsg, Path, Place, StartupDone, Subscribe, SubscribeKind,
+    UpdateResponse,
+};
+use labgrid_ui_core::version;
+use labgrid_ui_core::webhooks::{WebhookNotifier, WebhookTracker};
+use labgrid_ui_core::LabgridGrpcClient;
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+use tokio_stream::{Stream, StreamExt};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, warn};
+
+/// Time waited before the client stream is initiated again after it was lost.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Records metrics of the places and resources observed through the client stream, without a UI.
+///
+/// The client stream is initiated again when it is lost.
+/// Runs until the quit token is cancelled.
+pub async fn monitor(
+    client: &mut LabgridGrpcClient,
+    metrics: &Metrics,
+    quit_token: CancellationToken,
+) -> anyhow::Result<()> {
+    loop {
+        let res = tokio::select! {
+            res = observe(client, metrics) => res,
+            _ = quit_token.cancelled() => return Ok(()),
+        };
+        match res {
+            Ok(()) => debug!("Client stream closed by coordinator"),
+            Err(error) => {
+                warn!(?error, "Client stream lost");
+                metrics.record_grpc_error();
+            }
+        }
+        metrics.record_disconnected();
+        metrics.set_places(0);
+        metrics.set_resources(0);
+        tokio::select! {
+            _ = tokio::time::sleep(RECONNECT_DELAY) => {},
+            _ = quit_token.cancelled() => return Ok(()),
+        }
+    }
+}
+
+/// Initiates a client stream subscribed to all places and resources, followed by an initial sync.
+async fn subscribed_client_stream(
+    client: &mut LabgridGrpcClient,
+) -> anyhow::Result<
+    impl Stream<
+            Item = Result<proto::ClientOutMessage, impl std::error::Error + Send + Sync + 'static>,
+        > + Unpin,
+> {
+    let in_msgs = vec![
+        ClientInMsg::StartupDone(StartupDone {
+            version: version::PROTOCOL_VERSION.to_string(),
+            name: format!("labgrid-ui-testcli/{}", std::process::id()),
+        }),
+        ClientInMsg::Subscribe(Subscribe {
+            is_unsubscribe: None,
+            kind: SubscribeKind::AllPlaces(true),
+        }),
+        ClientInMsg::Subscribe(Subscribe {
+            is_unsubscribe: None,
+            kind: SubscribeKind::AllResources(true),
+        }),
+        ClientInMsg::Sync(types::Sync { id: 1 }),
+    ];
+    // The in stream must stay open, otherwise the coordinator ends the session.
+    let in_stream = tokio_stream::iter(in_msgs).chain(tokio_stream::pending());
+    client
+        .client_stream(in_stream)
+        .await
+        .context("Initiate client stream")
+}
+
+/// Observes a single client stream session until the stream ends.
+async fn observe(client: &mut LabgridGrpcClient, metrics: &Metrics) -> anyhow::Result<()> {
+    let mut out_stream = subscribed_client_stream(client).await?;
+    let mut places = HashMap::<String, Place>::new();
+    let mut resources = BTreeSet::<Path>::new();
+    while let Some(msg) = out_stream.next().await {
+        let msg = match ClientOutMsg::try_from(msg.context("Receive client out message")?) {
+            Ok(msg) => msg,
+            Err(error) => {
+                error!(?error, "Convert client out message");
+                continue;
+            }
+        };
+        if msg.sync.is_some() {
+            metrics.record_connected();
+        }
+        for update in msg.updates {
+            match update {
+                UpdateResponse::Place(p) => {
+                    metrics.record_place_update(places.get(&p.name), &p);
+                    places.insert(p.name.clone(), p);
+                }
+                UpdateResponse::DeletePlace(n) => {
+                    places.remove(&n);
+                }
+                UpdateResponse::Resource(r) => {
+                    resources.insert(r.path);
+                }
+                UpdateResponse::DeleteResource(p) => {
+                    resources.remove(&p);
+                }
+                update => warn!(?update, "Unhandled update response"),
+            }
+        }
+        metrics.set_places(places.len());
+        metrics.set_resources(resources.len());
+    }
+    Ok(())
+}
+
+/// Notifies webhooks about the changes observed through the client stream and the polled reservations.
+///
+/// The client stream is initiated again when it is lost.
+/// Runs until the quit token is cancelled.
+pub async fn notify_webhooks(
+    client: &mut LabgridGrpcClient,
+    notifier: &WebhookNotifier,
+    reservations_interval: Duration,
+    quit_token: CancellationToken,
+) -> anyhow::Result<()> {
+    let mut tracker = WebhookTracker::new();
+    loop {
+        let res = tokio::select! {
+            res = track(client, &mut tracker, notifier, reservations_interval) => res,
+            _ = quit_token.cancelled() => return Ok(()),
+        };
+        match res {
+            Ok(()) => debug!("Client stream closed by coordinator"),
+            Err(error) => warn!(?error, "Client stream lost"),
+        }
+        tracker.reset();
+        tokio::select! {
+            _ = tokio::time::sleep(RECONNECT_DELAY) => {},
+            _ = quit_token.cancelled() => return Ok(()),
+        }
+    }
+}
+
+/// Tracks a single client stream session until the stream ends.
+async fn track(
+    client: &mut LabgridGrpcClient,
+    tracker: &mut WebhookTracker,
+    notifier: &WebhookNotifier,
+    reservations_interval: Duration,
+) -> anyhow::Result<()> {
+    let mut out_stream = subscribed_client_stream(client).await?;
+    let mut reservations_ticks = tokio::time::interval(reservations_interval);
+    loop {
+        tokio::select! {
+            msg = out_stream.next() => {
+                let Some(msg) = msg else {
+                    return Ok(());
+                };
+                let msg = match ClientOutMsg::try_from(msg.context("Receive client out message")?) {
+                    Ok(msg) => msg,
+                    Err(error) => {
+                        error!(?error, "Convert client out message");
+                        continue;
+                    }
+                };
+                for update in &msg.updates {
+                    notifier.notify(tracker.update(update));
+                }
+                // Only the initial sync is sent, its echo follows the initial places and resources
+                if msg.sync.is_some() {
+                    tracker.synced();
+                }
+            }
+            _ = reservations_ticks.tick() => {
+                let reservations = client.get_reservations().await.context("Get reservations")?;
+                notifier.notify(tracker.reservations(&reservations));
+            }
+        }
+    }
+}
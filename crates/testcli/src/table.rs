@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::types::{Place, Resource};
+use std::cmp::Ordering;
+
+/// Columns of the places table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PlaceColumn {
+    Name,
+    AcquiredBy,
+    Tags,
+    Matches,
+    Reservation,
+}
+
+impl PlaceColumn {
+    /// All columns in their default order.
+    pub const ALL: [Self; 5] = [
+        Self::Name,
+        Self::AcquiredBy,
+        Self::Tags,
+        Self::Matches,
+        Self::Reservation,
+    ];
+
+    fn header(&self) -> &'static str {
+        match self {
+            Self::Name => "NAME",
+            Self::AcquiredBy => "ACQUIRED-BY",
+            Self::Tags => "TAGS",
+            Self::Matches => "MATCHES",
+            Self::Reservation => "RESERVATION",
+        }
+    }
+
+    fn cell(&self, place: &Place) -> String {
+        match self {
+            Self::Name => place.name.clone(),
+            Self::AcquiredBy => place.acquired.clone().unwrap_or_else(|| "-".to_string()),
+            Self::Tags => {
+                let mut tags = place
+                    .tags
+                    .iter()
+                    .map(|(k, v)| format!("{k}={v}"))
+                    .collect::<Vec<String>>();
+                tags.sort();
+                if tags.is_empty() {
+                    "-".to_string()
+                } else {
+                    tags.join(",")
+                }
+            }
+            Self::Matches => place.matches.len().to_string(),
+            Self::Reservation => place.reservation.clone().unwrap_or_else(|| "-".to_string()),
+        }
+    }
+
+    fn cmp(&self, first: &Place, second: &Place) -> Ordering {
+        match self {
+            Self::Matches => first.matches.len().cmp(&second.matches.len()),
+            _ => numeric_sort::cmp(&self.cell(first), &self.cell(second)),
+        }
+    }
+}
+
+/// Renders the places as a table with the supplied columns, sorted by column `sort_by`.
+///
+/// Places with equal values in the sorted column are ordered by their name.
+pub fn places_table(
+    mut places: Vec<Place>,
+    columns: &[PlaceColumn],
+    sort_by: PlaceColumn,
+) -> String {
+    places.sort_by(|first, second| {
+        sort_by
+            .cmp(first, second)
+            .then_with(|| PlaceColumn::Name.cmp(first, second))
+    });
+    let rows = std::iter::once(
+        columns
+            .iter()
+            .map(|c| c.header().to_string())
+            .collect::<Vec<String>>(),
+    )
+    .chain(
+        places
+            .iter()
+            .map(|place| columns.iter().map(|c| c.cell(place)).collect()),
+    )
+    .collect::<Vec<Vec<String>>>();
+    render_rows(rows)
+}
+
+/// Renders the resources as a table, sorted by their path.
+pub fn resources_table(mut resources: Vec<Resource>) -> String {
+    resources.sort_by(|first, second| first.path.numeric_cmp(&second.path));
+    let header = [
+        "EXPORTER",
+        "GROUP",
+        "NAME",
+        "CLASS",
+        "ACQUIRED",
+        "AVAILABLE",
+    ]
+    .map(str::to_string)
+    .to_vec();
+    let rows = std::iter::once(header)
+        .chain(resources.into_iter().map(|r| {
+            vec![
+                r.path.exporter_name.unwrap_or_else(|| "-".to_string()),
+                r.path.group_name,
+                r.path.resource_name,
+                r.cls,
+                if r.acquired.is_empty() {
+                    "-".to_string()
+                } else {
+                    r.acquired
+                },
+                r.available.to_string(),
+            ]
+        }))
+        .collect::<Vec<Vec<String>>>();
+    render_rows(rows)
+}
+
+/// Renders the rows with left aligned, space separated columns.
+///
+/// All rows must have the same number of cells.
+fn render_rows(rows: Vec<Vec<String>>) -> String {
+    let widths = (0..rows.first().map(Vec::len).unwrap_or(0))
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<usize>>();
+
+    let mut table = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<String>>()
+            .join("  ");
+        table += line.trim_end();
+        table += "\n";
+    }
+    table
+}
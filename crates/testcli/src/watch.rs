@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::table::{self, PlaceColumn};
+use anyhow::Context;
+use labgrid_ui_core::types::{
+    self, ClientInMsg, ClientOutMsg, Path, Place, Resource, StartupDone, Subscribe, SubscribeKind,
+    UpdateResponse,
+};
+use labgrid_ui_core::version;
+use labgrid_ui_core::LabgridGrpcClient;
+use std::collections::{BTreeMap, HashMap};
+use tokio_stream::StreamExt;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, warn};
+
+/// Clears the terminal and moves the cursor to the top left.
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// What is watched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum WatchTarget {
+    Places,
+    Resources,
+}
+
+/// Watches the places or resources through the client stream and re-renders their table on every update.
+///
+/// Runs until the stream is closed by the coordinator or the quit token is cancelled.
+pub async fn watch(
+    client: &mut LabgridGrpcClient,
+    target: WatchTarget,
+    columns: &[PlaceColumn],
+    sort_by: PlaceColumn,
+    quit_token: CancellationToken,
+) -> anyhow::Result<()> {
+    let in_msgs = vec![
+        ClientInMsg::StartupDone(StartupDone {
+            version: version::PROTOCOL_VERSION.to_string(),
+            name: format!("labgrid-ui-testcli/{}", std::process::id()),
+        }),
+        ClientInMsg::Subscribe(Subscribe {
+            is_unsubscribe: None,
+            kind: match target {
+                WatchTarget::Places => SubscribeKind::AllPlaces(true),
+                WatchTarget::Resources => SubscribeKind::AllResources(true),
+            },
+        }),
+        ClientInMsg::Sync(types::Sync { id: 1 }),
+    ];
+    // The in stream must stay open, otherwise the coordinator ends the session.
+    let in_stream = tokio_stream::iter(in_msgs).chain(tokio_stream::pending());
+    let mut out_stream = client
+        .client_stream(in_stream)
+        .await
+        .context("Initiate client stream")?;
+
+    let mut places = HashMap::<String, Place>::new();
+    let mut resources = BTreeMap::<Path, Resource>::new();
+    // The initial state is sent before the sync response, so the first table gets rendered after it.
+    let mut synced = false;
+    loop {
+        let msg = tokio::select! {
+            msg = out_stream.next() => msg,
+            _ = quit_token.cancelled() => return Ok(()),
+        };
+        let Some(msg) = msg else {
+            debug!("Client stream closed by coordinator");
+            return Ok(());
+        };
+        let msg = match ClientOutMsg::try_from(msg.context("Receive client out message")?) {
+            Ok(msg) => msg,
+            Err(error) => {
+                error!(?error, "Convert client out message");
+                continue;
+            }
+        };
+        synced |= msg.sync.is_some();
+        for update in msg.updates {
+            match update {
+                UpdateResponse::Place(p) => {
+                    places.insert(p.name.clone(), p);
+                }
+                UpdateResponse::DeletePlace(n) => {
+                    places.remove(&n);
+                }
+                UpdateResponse::Resource(r) => {
+                    resources.insert(r.path.clone(), r);
+                }
+                UpdateResponse::DeleteResource(p) => {
+                    resources.remove(&p);
+                }
+                update => warn!(?update, "Unhandled update response"),
+            }
+        }
+        if !synced {
+            continue;
+        }
+        let table = match target {
+            WatchTarget::Places => {
+                table::places_table(places.values().cloned().collect(), columns, sort_by)
+            }
+            WatchTarget::Resources => table::resources_table(resources.values().cloned().collect()),
+        };
+        print!("{CLEAR_SCREEN}{table}");
+    }
+}
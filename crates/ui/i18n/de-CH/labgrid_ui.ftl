@@ -1 +1,3 @@
 app-title = Labgrid Benutzeroberfläche
+time-format = %d.%m.%Y %H:%M:%S
+decimal-separator = .
@@ -4,42 +4,315 @@ app-version-label = Version
 app-quit-label = Beenden
 connect-button = Verbinden
 refresh-ui-tooltip = UI Zustand aktualisieren
+announcement-dismiss-tooltip = Ankündigung ausblenden
+availability-notice-dismiss-tooltip = Hinweis ausblenden
+resource-available-notice = Die beobachtete Ressource '{$path}' ist seit {$time} verfügbar
+resource-unavailable-notice = Die beobachtete Ressource '{$path}' ist seit {$time} nicht mehr verfügbar
+protocol-warning-dismiss-tooltip = Warnung ausblenden
+protocol-version-unknown = unbekannt
+protocol-untested-msg = Der Coordinator verwendet labgrid { $version }, das mit diesem Client nicht getestet wurde (Protokoll von labgrid { $client_version }). Plätze oder Ressourcen könnten fehlen, falls sich das Protokoll geändert hat.
+protocol-incompatible-msg = Der Coordinator verwendet labgrid { $version }, das mit diesem Client nicht kompatibel ist. Mindestens labgrid { $min } wird benötigt.
+protocol-mismatch-msg = Nachrichten des Coordinators (labgrid { $version }) konnten nicht gelesen werden, er verwendet wahrscheinlich ein anderes Protokoll als dieser Client (labgrid { $client_version }). Plätze oder Ressourcen könnten fehlen: { $error }
 disconnect-button = Trennen
 clipboard-copy-tooltip = In Zwischenablage kopieren
 clipboard-paste-tooltip = Von Zwischenablage einfügen
 text-input-clear-tooltip = Text löschen
+time-format = %d.%m.%Y %H:%M:%S
+decimal-separator = ,
+time-ago-just-now = gerade eben
+time-ago-minutes = vor {$minutes} min
+time-ago-hours = vor {$hours} h
+time-ago-days = {$days ->
+    [one] vor {$days} Tag
+   *[other] vor {$days} Tagen
+}
 connecting-msg = Verbinde zu '{$address}' ..
 connected-to-coordinator-label = Verbunden mit Koordinator '{$address}'
+connection-latency-label = {$ms} ms
+connection-latency-tooltip = Antwortzeit des letzten Keepalives
 show-details-button = Zeige Details
 hide-details-button = Verstecke Details
 confirmation-modal-confirm-button = Bestätigen
 confirmation-modal-cancel-button = Abbrechen
-coordinator-address-placeholder = Coordinator Adresse und Port
+coordinator-address-placeholder = Coordinator Adresse und Port, oder URI
+venv-dir-reset-tooltip = Venv-Verzeichnis auf Standard zurücksetzen
 error-critical = Kritischer Error
+error-retry-button = Wiederholen
+error-console-tooltip = Alle Fehler dieser Sitzung anzeigen
+error-console-header = Fehler
+error-console-empty = Es wurden keine Fehler gemeldet.
+error-console-filter-all = Alle
+error-console-copy-button = Alle kopieren
+error-console-clear-button = Alle löschen
 error-noncritical = Nicht-kritischer Error
 connection-msg-invalid-input = Unfähig Verbindungsaktion auszuführen, invalide Eingabe
+connection-msg-bulk-place-op-failed = Operation für {$count} Plätze fehlgeschlagen
+connection-msg-cancel-reservations-failed = Abbrechen von {$count} Reservierungen fehlgeschlagen
+connection-msg-restore-places-failed = Wiederherstellen von {$count} Änderungen des Platz-Snapshots fehlgeschlagen
 error-invalid-path = Gegebener Pfad ist invalid
 error-app-config-load = Laden der App Konfiguration gescheitert
 error-app-config-save = Sichern der App Konfiguration gescheitert
+error-utilization-record = Aufzeichnen der Auslastung der Plätze fehlgeschlagen
+error-app-config-reload = Neuladen der geänderten App Konfiguration gescheitert
+error-app-config-open = Öffnen der App Konfigurationsdatei gescheitert
+error-terminal-launch = Starten von {$program} im Terminalemulator gescheitert
+error-metrics-export = Exportieren der Metriken gescheitert
+error-rest-gateway = Bereitstellen des REST-Gateways gescheitert
+error-clipboard-copy = Kopieren in die Zwischenablage gescheitert
+error-clipboard-paste = Einfügen aus der Zwischenablage gescheitert
+error-connecting-failed = Verbindung zum Coordinator gescheitert
+error-connecting-timeout = Zeitüberschreitung beim Verbinden zum Coordinator
+error-keepalive-timeout = Der Coordinator hat nicht auf den Keepalive geantwortet, die Verbindung gilt als verloren
+error-transport-failure = Die Verbindung zum Coordinator ist abgebrochen
+error-msg-conversion = Eine Nachricht des Coordinators konnte nicht konvertiert werden
+error-grpc-status-unavailable = Der Coordinator ist nicht erreichbar ({$code})
+error-grpc-status = Der Coordinator hat einen Fehler gemeldet ({$code})
+
+file-dialog-filter-python-scripts-label = Python-Skripte
+file-browser-pick-dir-header = Verzeichnis auswählen
+file-browser-pick-file-header = Datei auswählen
+file-browser-listing-msg = Verzeichnis wird gelesen ..
+file-browser-list-failed-msg = Lesen des Verzeichnisses fehlgeschlagen: {$error}
+file-browser-empty-msg = Das Verzeichnis ist leer
+file-browser-up-tooltip = Übergeordnetes Verzeichnis
+file-browser-pick-button = Auswählen
 
 settings-button = Einstellungen
+profiles-button = Profile
+profiles-header = Verbindungsprofile
+profiles-empty = Noch keine Profile
+profile-default-name = Profil
+profile-select-placeholder = Profil
+profile-clear-tooltip = Ohne Profil verbinden
+profile-add-button = Profil hinzufügen
+profile-delete-button = Profil löschen
+profile-name-label = Name
+profile-address-label = Koordinator-Adresse
+profile-tls-label = Über TLS verbinden
+profile-scope-label = Abonnements
+profile-places-only-mine-label = Nur meine Plätze anzeigen
+profile-resources-only-available-label = Nur verfügbare Ressourcen anzeigen
+subscription-scope-places-and-resources = Plätze und Ressourcen
+subscription-scope-places-only = Nur Plätze
 settings-header = Einstellungen
 settings-language-pick-label = Wähle die Sprache
 settings-optimize-touch-label = Optimiere UI für Touchscreens
+settings-ui-scale-label = Skalierung der Oberfläche
+settings-ui-scale-value = {$percent} %
+settings-high-contrast-label = Farben mit hohem Kontrast verwenden
+settings-resource-grace-period-label = Ressourcen von Offline-Exportern behalten für
+settings-resource-grace-period-value = {$secs} s
+settings-offline-exporter-resources-label = Ressourcen von Exportern die offline sind
+settings-offline-exporter-resources-show = Anzeigen
+settings-offline-exporter-resources-gray = Ausgegraut anzeigen
+settings-offline-exporter-resources-hide = Ausblenden
+settings-bulk-ops-per-sec-label = Ratenbegrenzung von Massenaktionen auf Plätzen
+settings-bulk-ops-per-sec-value = {$ops} Ops/s
+settings-autosave-label = Einstellungen regelmäßig speichern
+settings-autosave-value = {$secs ->
+    [0] Aus
+   *[other] Alle {$secs} s
+}
+settings-last-save-label = Zuletzt gespeichert um {$time}
+settings-last-save-never = Noch nicht gespeichert
+settings-save-button = Jetzt speichern
+settings-saved-confirmation = Einstellungen gespeichert
+settings-timeout-preset-label = Verbindungs-Timeouts
+settings-timeout-preset-lan = Lokales Netzwerk
+settings-timeout-preset-vpn = VPN / entferntes Labor
+settings-timeout-preset-custom = Benutzerdefiniert
+settings-timeout-connect-label = Timeout für den Verbindungsaufbau zum Coordinator
+settings-timeout-stream-label = Timeout für den Start des Client-Streams
+settings-timeout-rpc-label = Timeout von Coordinator-Aufrufen
+settings-keepalive-label = Intervall der Keepalives (0 deaktiviert sie)
+settings-reservations-poll-label = Intervall der Aktualisierung der Reservierungen
+settings-timeout-secs-value = {$secs} s
+settings-retry-transient-errors-label = Aufrufe wiederholen, wenn der Coordinator vorübergehend nicht erreichbar ist
+settings-auth-token-label = Authentifizierungstoken
+settings-auth-token-none = Keines
+settings-auth-token-static = Festes Token
+settings-auth-token-file = Token-Datei
+settings-auth-token-command = Token-Befehl
+settings-auth-token-static-label = Token
+settings-auth-token-file-label = Pfad der Token-Datei
+settings-auth-token-command-label = Befehl, der das Token ausgibt
+settings-auth-token-refresh-label = Token-Befehl erneut ausführen nach
+settings-auth-metadata-label = Aufruf-Metadaten
+settings-auth-metadata-tooltip = Kommagetrennte key=value-Einträge, die mit jedem Aufruf gesendet werden, wirksam beim Verbinden
+settings-check-script-imports-label = Beim Prüfen von Skripten die labgrid-Importe prüfen
+settings-scripts-max-depth-label = Tiefe der durchsuchten Skript-Unterverzeichnisse
+settings-scripts-max-depth-value = {$depth ->
+    [0] Nur das Skriptverzeichnis
+   *[other] {$depth} Ebenen
+}
+settings-script-timeout-label = Skripte beenden, die länger laufen als
+settings-script-timeout-value = {$mins ->
+    [0] Kein Timeout
+   *[other] {$mins} min
+}
+settings-mqtt-label = Zustand von Plätzen und Ressourcen an einen MQTT-Broker spiegeln
+settings-mqtt-broker-label = MQTT-Broker
+settings-mqtt-topic-prefix-label = MQTT-Topic-Präfix
+settings-mqtt-username-label = MQTT-Benutzername
+settings-mqtt-password-label = MQTT-Passwort
+settings-mqtt-apply-button = Übernehmen
+settings-mqtt-apply-tooltip = MQTT-Brücke mit den geänderten Einstellungen neu starten
+settings-mqtt-status-connecting = Verbinde…
+settings-mqtt-status-connected = Verbunden
+settings-mqtt-status-disconnected = Getrennt: {$error}
+settings-persist-acquire-history-label = Ressourcen Erwerbsverlauf speichern
+settings-record-utilization-label = Auslastung der Plätze für die Übersicht aufzeichnen
+settings-card-color-free-label = Kartenfarbe freier Plätze
+settings-card-color-mine-label = Kartenfarbe von mir belegter Plätze
+settings-card-color-others-label = Kartenfarbe von anderen belegter Plätze
+settings-card-color-reserved-label = Kartenfarbe reservierter Plätze
+settings-card-color-maintenance-label = Kartenfarbe von Plätzen in Wartung
+settings-card-color-reset-tooltip = Auf Standardfarbe zurücksetzen
+settings-identity-colors-label = Belegungen nach Benutzer einfärben
+settings-identity-colors-tooltip = Von anderen belegte Plätze und belegte Ressourcen werden mit einer aus dem Host-/Benutzernamen des Besitzers abgeleiteten Farbe eingefärbt
+settings-notification-place-released-label = Benachrichtigen wenn ein beobachteter Platz freigegeben wird
+settings-notification-reservation-allocated-label = Benachrichtigen wenn meine Reservierung zugeteilt wird
+settings-notification-script-finished-label = Benachrichtigen wenn ein Skript beendet ist
+settings-notification-auto-release-warning-label = Benachrichtigen bevor ein ungenutzter Platz automatisch freigegeben wird
+settings-notification-resource-availability-label = Benachrichtigen wenn eine beobachtete Ressource verfügbar oder nicht mehr verfügbar wird
+settings-quick-action-acquire-label = Belegen/Freigeben-Schaltfläche auf Platzkarten anzeigen
+settings-quick-action-power-cycle-label = Neustart-Schaltfläche auf Platzkarten anzeigen
+settings-quick-action-console-label = Konsolen-Schaltfläche auf Platzkarten anzeigen
+settings-quick-action-favorite-script-label = Lieblingsskript-Schaltfläche auf Platzkarten anzeigen
+settings-quick-action-favorite-script-pick-label = Lieblingsskript
+settings-quick-action-favorite-script-placeholder = Skript auswählen
+settings-identity-label = Labgrid Identität
+settings-identity-hostname-label = Hostname (überschreibt LG_HOSTNAME)
+settings-identity-username-label = Benutzername (überschreibt LG_USERNAME)
+settings-terminal-command-label = Terminalemulator (z.B. 'x-terminal-emulator -e')
+settings-auto-release-label = Über die Oberfläche belegte, ungenutzte Plätze freigeben
+settings-auto-release-idle-label = Freigeben nach Inaktivität von
+settings-auto-release-warn-label = Warnen vor der Freigabe
+settings-release-on-exit-label = Über die UI belegte Plätze beim Beenden freigeben
+settings-auto-release-minutes-value = {$minutes} min
+settings-identity-reconnect-button = Neu verbinden
+settings-identity-reconnect-tooltip = Aktuell verbunden als '{ $identity }', neu verbinden um die geänderte Identität anzuwenden
+notification-place-released-summary = Platz freigegeben
+notification-place-released-body = Der beobachtete Platz '{$place}' wurde freigegeben
+notification-resource-available-summary = Ressource verfügbar
+notification-resource-available-body = Die beobachtete Ressource '{$path}' ist verfügbar geworden
+notification-resource-unavailable-summary = Ressource nicht verfügbar
+notification-resource-unavailable-body = Die beobachtete Ressource '{$path}' ist nicht mehr verfügbar
+notification-reservation-allocated-summary = Reservierung zugeteilt
+notification-reservation-allocated-body = Ihre Reservierung '{$token}' wurde zugeteilt
+notification-script-finished-summary = Skript beendet
+notification-script-finished-body = Skript '{$script}' wurde mit Exit-Code {$code} beendet
+notification-script-timed-out-body = Skript '{$script}' hat das Timeout von {$secs} s überschritten und wurde beendet
+notification-auto-release-warning-summary = Platz wird bald freigegeben
+notification-auto-release-warning-body = Der ungenutzte Platz '{$place}' wird in {$minutes} min freigegeben
+settings-venv-dir-label = Verzeichnis der virtuellen Umgebung für Skripte ändern
+settings-venv-dir-pick-tooltip = Neues Venv-Verzeichnis wählen
+settings-venv-create-tooltip = Eine virtuelle Umgebung mit labgrid erstellen
+settings-venv-versions-label = Erkannte Versionen
+settings-venv-versions-detecting = Wird erkannt…
+settings-venv-versions-value = Python { $python }, labgrid { $labgrid }
+settings-venv-versions-no-labgrid = Python { $python }, labgrid ist nicht installiert
+settings-venv-versions-failed = Erkennung fehlgeschlagen
+settings-venv-versions-error = Der Python-Interpreter der virtuellen Umgebung konnte nicht ausgeführt werden: { $error }
+settings-venv-labgrid-missing-msg = labgrid ist in der virtuellen Umgebung nicht installiert, Skripte die es verwenden schlagen fehl.
+settings-venv-incompatible-msg = labgrid { $labgrid } kann nicht mit dem gRPC-Koordinator kommunizieren, Skripte benötigen mindestens labgrid { $min }.
+settings-log-viewer-label = Diagnoseprotokoll
+settings-log-viewer-button = Protokoll anzeigen
+settings-config-file-label = Konfigurationsdatei
+settings-config-file-open-tooltip = Mit der Standardanwendung öffnen, Änderungen auf der Festplatte werden sofort übernommen
+log-viewer-header = Diagnoseprotokoll
+log-viewer-level-label = Stufe
+log-viewer-msg = Zeigt die letzten Ereignisse der Anwendung. Ereignisse werden ab der gewählten Stufe aufgezeichnet, unabhängig von RUST_LOG.
+log-viewer-empty = Keine Ereignisse aufgezeichnet.
+log-viewer-clear-button = Leeren
+log-viewer-copy-button = In die Zwischenablage kopieren
+protocol-inspector-header = Protokoll-Inspektor
+protocol-inspector-record-label = Aufzeichnen
+protocol-inspector-msg = Zeigt die während der Aufzeichnung mit dem Coordinator ausgetauschten Protobuf-Nachrichten. { $recorded } Nachrichten sind aufgezeichnet, die neuesten { $shown } zum Filter passenden werden angezeigt.
+protocol-inspector-filter-placeholder = Nach Nachrichtentyp oder Inhalt filtern
+protocol-inspector-empty = Keine Nachrichten aufgezeichnet.
+protocol-inspector-clear-button = Leeren
+protocol-inspector-copy-button = In die Zwischenablage kopieren
+protocol-inspector-copy-tooltip = Kopiert alle aufgezeichneten Nachrichten, auch die nicht angezeigten
+venv-wizard-header = Virtuelle Umgebung erstellen
+venv-wizard-msg = Erstellt eine virtuelle Python-Umgebung mit installiertem labgrid, in der die Skripte ausgeführt werden. Benötigt python3 mit dem venv-Modul und Zugriff auf den Python Package Index.
+venv-wizard-dir-label = Verzeichnis
+venv-wizard-labgrid-version-label = labgrid-Version
+venv-wizard-labgrid-version-placeholder = Neueste
+venv-wizard-invalid-dir-msg = Das Verzeichnis muss ein absoluter Pfad sein
+venv-wizard-dir-not-empty-msg = Das Verzeichnis existiert bereits und ist nicht leer
+venv-wizard-invalid-version-msg = Die Version ist keine gültige Versionsnummer, z.B. 24.0.1
+venv-wizard-step-create = Virtuelle Umgebung erstellen
+venv-wizard-step-install = labgrid installieren
+venv-wizard-step-validate = Virtuelle Umgebung prüfen
+venv-wizard-done-msg = Die virtuelle Umgebung wurde mit labgrid {$version} erstellt
+venv-wizard-create-button = Erstellen
+venv-wizard-use-button = Für die Skripte verwenden
 
 lang-de-ch = "Schweizerdeutsch"
 lang-de-de = "Deutsch"
 lang-en-us = "Amerikanisches Englisch"
 lang-es-es = "Spanisch"
+lang-fr-fr = "Französisch"
+lang-it-it = "Italienisch"
+lang-zh-cn = "Vereinfachtes Chinesisch"
 
 labgrid-places-label = Plätze
+labgrid-places-show-resources-panel-button = Zeige Ressourcen
+labgrid-places-group-by-none = Keine Gruppierung
+labgrid-places-group-by-tag = Nach Tag gruppieren: {$key}
+labgrid-places-group-untagged = Ohne Tag '{$key}'
+labgrid-places-select-mode-button = Auswählen
+labgrid-places-exit-select-mode-button = Auswahl beenden
+labgrid-places-selected-label = {$count} ausgewählt
+labgrid-places-select-all-button = Alle auswählen
+labgrid-places-clear-selection-button = Auswahl aufheben
+labgrid-places-bulk-set-tag-button = Tag setzen
+labgrid-places-bulk-progress-label = {$done} / {$total} Plätze
+labgrid-places-bulk-pause-button = Pausieren
+labgrid-places-bulk-resume-button = Fortsetzen
+labgrid-places-bulk-cancel-button = Abbrechen
+labgrid-places-bulk-delete-confirmation-msg = Sind Sie sicher dass {$count} Plätze gelöscht werden sollen?
+labgrid-place-select-checkbox = Ausgewählt
+labgrid-place-mine-badge = Von mir belegt
+labgrid-place-mine-tooltip = Der Platz ist von der Identität dieses Clients belegt
+labgrid-place-session-label = Sitzung
+labgrid-place-session-remaining = Freigabe in {$remaining}
+labgrid-place-session-extend-tooltip = Sitzung verlängern, die Inaktivitätszeit beginnt neu
+labgrid-place-session-keep-tooltip = Platz behalten, er wird nicht automatisch freigegeben
+labgrid-place-resources-label = Ressourcen
+labgrid-place-resources-available = {$available}/{$total} verfügbar
+labgrid-place-resources-tooltip = Verfügbare Ressourcen der Matches des Platzes, Matches ohne Ressource zählen als fehlend
+labgrid-places-only-mine-checkbox = Nur meine Plätze
+labgrid-place-power-cycle-tooltip = Platz neu starten (muss von mir belegt sein)
+labgrid-place-power-cycle-failed-msg = Neustart von Platz '{ $place }' fehlgeschlagen
+labgrid-place-console-tooltip = Befehl zum Verbinden mit der Konsole kopieren
+labgrid-place-favorite-script-tooltip = Lieblingsskript auf diesem Platz ausführen
+labgrid-place-watch-tooltip = Beobachten, benachrichtigen wenn der Platz freigegeben wird
+labgrid-place-unwatch-tooltip = Nicht mehr beobachten
+labgrid-places-hide-resources-panel-button = Verstecke Ressourcen
+labgrid-places-resources-panel-hint = Ressourcen auf Plätze ziehen, um Matches für sie hinzuzufügen
+labgrid-places-resources-panel-dragging-msg = '{$pattern}' auf einem Platz ablegen
+labgrid-place-drop-resource-confirmation-msg = Match '{$pattern}' zu Platz '{$place}' hinzufügen?
 labgrid-place-name-label = Name
 labgrid-place-comment-label = Kommentar
+labgrid-place-comment-placeholder = Kommentar
+labgrid-place-comment-edit-tooltip = Kommentar bearbeiten
+labgrid-place-comment-save-button = Speichern
+labgrid-place-comment-cancel-button = Abbrechen
 labgrid-place-acquire-button = Erhalten
+labgrid-place-acquire-with-reason-tooltip = Mit einem Grund belegen, der anderen Benutzern angezeigt wird
+labgrid-place-acquire-with-reason-msg = Warum wird der Platz '{$place}' belegt? Der Grund wird anderen Benutzern angezeigt, bis der Platz freigegeben wird.
+labgrid-place-acquire-reason-placeholder = z.B. Bisecting eines Boot-Problems
+labgrid-place-acquire-reason-label = Grund
 labgrid-place-not-acquired-label = Verfügbar
 labgrid-place-acquired-by-label = Gehalten von
 labgrid-place-release-label = Kicken
+labgrid-place-force-release-button = Freigabe erzwingen
+labgrid-place-force-release-confirmation-msg = Platz '{ $place }' ist von '{ $owner }' belegt. Den Namen des Platzes eingeben um ihn für diesen Benutzer freizugeben.
 labgrid-place-tags-label = Tags
+labgrid-place-created-label = Erstellt
+labgrid-place-changed-label = Geändert
 labgrid-place-add-placeholder = Platzname
 labgrid-place-add-button = Hinzufügen
 labgrid-place-delete-button = Löschen
@@ -49,41 +322,295 @@ labgrid-place-add-tag-value-placeholder = Tag Wert
 labgrid-place-add-tag-tooltip = Tag hinzufügen
 labgrid-place-close-add-tag-tooltip = Verbergen
 labgrid-place-details-header = Platz '{$place}' Details
+labgrid-place-clone-button = Klonen
+labgrid-place-clone-tooltip = Einen neuen Platz mit den Tags, Aliasen und Ressourcen-Matches dieses Platzes erstellen
+labgrid-place-clone-msg = Einen neuen Platz wie '{$place}' erstellen. Dessen Name wird in den Aliasen und Ressourcen-Matches durch den neuen Namen ersetzt.
+labgrid-place-clone-aliases-label = Aliase
+labgrid-place-clone-none-label = Keine
+labgrid-place-clone-empty-name-msg = Der Name darf nicht leer sein
+labgrid-place-clone-name-taken-msg = Ein Platz mit diesem Namen existiert bereits
+labgrid-place-clone-alias-taken-msg = Alias '{$alias}' wird bereits verwendet
+labgrid-place-clone-invalid-pattern-msg = '{$pattern}' ist kein gültiges Ressourcen-Match-Muster
+place-activity-header = Aktivität
+place-activity-export-button = Exportieren
+place-activity-export-failed = Exportieren der Platz-Aktivität fehlgeschlagen
+export-button = Exportieren…
+export-tooltip = Die angezeigten Einträge in eine CSV- oder JSON-Datei exportieren
+export-failed = Exportieren fehlgeschlagen
+place-snapshot-export-button = Snapshot…
+place-snapshot-export-tooltip = Namen, Aliase, Kommentare, Tags und Ressourcen-Matches aller Plätze in einer JSON-Datei speichern
+place-snapshot-restore-button = Wiederherstellen…
+place-snapshot-restore-tooltip = Einen gespeicherten Snapshot der Plätze auf den Koordinator anwenden, mit Vorschau der Änderungen
+place-snapshot-import-failed = Lesen des Platz-Snapshots fehlgeschlagen
+place-snapshot-restore-msg = Das Wiederherstellen von '{$file}' erstellt die fehlenden Plätze und fügt die fehlenden Aliase, Tags und Ressourcen-Matches hinzu. Es wird nichts gelöscht.
+place-snapshot-taken-msg = Snapshot von {$coordinator}, erstellt {$created}
+place-snapshot-up-to-date-msg = Der Koordinator enthält bereits alles aus dem Snapshot
+place-snapshot-apply-button = {$count} Änderungen anwenden
+place-snapshot-step-add-place = Platz '{$place}' erstellen
+place-snapshot-step-add-alias = Alias '{$alias}' hinzufügen
+place-snapshot-step-set-comment = Kommentar auf '{$comment}' setzen
+place-snapshot-step-add-tag = Tag {$key}={$value} hinzufügen
+place-snapshot-step-change-tag = Tag {$key} von '{$current}' auf '{$value}' ändern
+place-snapshot-step-add-match = Ressourcen-Match {$pattern} hinzufügen
+place-snapshot-compare-button = Vergleichen…
+place-snapshot-compare-tooltip = Die Plätze mit einem gespeicherten Snapshot vergleichen und einzelne Unterschiede anwenden
+place-snapshot-compare-msg = Unterschiede der Plätze zu '{$file}'
+place-snapshot-no-differences-msg = Die Plätze stimmen mit dem Snapshot überein
+place-snapshot-close-button = Schließen
+place-snapshot-restore-all-button = Alles wiederherstellen…
+place-snapshot-diff-apply-button = Anwenden
+place-snapshot-diff-remove-button = Entfernen
+place-snapshot-diff-missing-place = Platz fehlt auf dem Koordinator
+place-snapshot-diff-extra-place = Platz nicht im Snapshot
+place-snapshot-diff-missing-alias = Alias '{$alias}' fehlt
+place-snapshot-diff-extra-alias = Alias '{$alias}' nicht im Snapshot
+place-snapshot-diff-comment = Kommentar ist '{$current}' statt '{$snapshot}'
+place-snapshot-diff-missing-tag = Tag {$key}={$value} fehlt
+place-snapshot-diff-changed-tag = Tag {$key} ist '{$current}' statt '{$snapshot}'
+place-snapshot-diff-extra-tag = Tag {$key}={$value} nicht im Snapshot
+place-snapshot-diff-missing-match = Ressourcen-Match {$pattern} fehlt
+place-snapshot-diff-extra-match = Ressourcen-Match {$pattern} nicht im Snapshot
+place-activity-acquired = Belegt von {$user}
+place-activity-released = Freigegeben, war belegt von {$user}
+place-activity-match-added = Match '{$pattern}' hinzugefügt
+place-activity-match-removed = Match '{$pattern}' entfernt
+labgrid-place-details-acquired-notice = Dieser Platz wurde soeben von {$user} belegt
+labgrid-place-details-released-notice = Dieser Platz wurde soeben von einem anderen Client freigegeben
+labgrid-place-details-modified-notice = Dieser Platz wurde soeben von einem anderen Client verändert
 labgrid-place-delete-tag-confirmation-msg = Sind Sie sicher dass Platz Tag '{$tag}' gelöscht werden soll?
 labgrid-place-resource-matches-header = Ressourcen Matches
+labgrid-place-aliases-header = Aliase
+labgrid-place-alias-add-placeholder-text = Alias
+labgrid-place-alias-add-button = Hinzufügen
+labgrid-place-alias-delete-button = Löschen
 labgrid-place-resource-acquired-header = Gehaltene Ressourcen
-labgrid-place-resource-match-add-placeholder-text = Ressource Match Muster
+labgrid-place-acquired-resource-origin = {$exporter} / {$group} / {$cls}
+labgrid-place-acquired-resource-acquired-tooltip = Gerade belegt
+labgrid-place-acquired-resource-released-tooltip = Gerade freigegeben
+labgrid-place-resource-match-rename-placeholder-text = Umbenennen
+labgrid-place-resource-match-rename-tooltip = Optionaler Name, den die gematchten Ressourcen im Platz erhalten, z.B. um Treiber in der Umgebungskonfiguration an sie zu binden
+labgrid-place-resource-match-renamed-tooltip = Gematchte Ressourcen werden in diesen Namen umbenannt
+labgrid-place-resource-match-builder-exporter = Exporter
+labgrid-place-resource-match-builder-group = Gruppe
+labgrid-place-resource-match-builder-cls = Klasse
+labgrid-place-resource-match-builder-name = Name (optional)
+labgrid-place-resource-match-builder-matching = {$count ->
+    [0] Passt auf keine bekannte Ressource
+    [one] Passt auf eine bekannte Ressource
+   *[other] Passt auf {$count} bekannte Ressourcen
+}
 labgrid-place-resource-match-add-button = Hinzufügen
 labgrid-place-resource-match-delete-button = Löschen
 labgrid-resources-label = Ressourcen
 labgrid-resources-only-show-available-checkbox = Zeige nur verfügbare
+labgrid-resources-exporter-filter-label = Exporter: {$exporter}
+labgrid-resources-exporter-filter-clear-tooltip = Ressourcen aller Exporter anzeigen
+labgrid-resources-exporter-offline-label = Offline seit {$time}
+labgrid-resources-exporter-offline-tooltip = Der Koordinator hat alle Ressourcen dieses Exporters entfernt, vermutlich wurde die Verbindung getrennt
+labgrid-exporters-label = Exporter
+labgrid-exporters-empty = Keine Exporter bekannt, Exporter werden aus den vom Koordinator gemeldeten Ressourcen abgeleitet.
+labgrid-exporter-resources-label = Ressourcen
+labgrid-exporter-available-label = Verfügbar
+labgrid-exporter-available-value = {$available} von {$total}
+labgrid-exporter-acquired-label = Belegt
+labgrid-exporter-stale-label = Entfernt
+labgrid-exporter-last-seen-label = Zuletzt gesehen
+labgrid-exporter-last-seen-unknown = Nicht seit dem Verbinden
+labgrid-exporter-offline-label = Offline seit
+labgrid-exporter-show-resources-button = Ressourcen anzeigen
+labgrid-exporter-usb-topology-button = USB-Topologie
+labgrid-resources-class-filter-placeholder = Alle Klassen
+labgrid-resources-search-placeholder = Exporter, Gruppe, Name oder Parameter suchen
+labgrid-resources-class-filter-clear-tooltip = Ressourcen aller Klassen anzeigen
+labgrid-classes-label = Ressourcenklassen
+labgrid-classes-empty = Keine Ressourcen bekannt.
+labgrid-class-resources-value = {$resources} Ressourcen
+labgrid-class-available-value = {$available} von {$total} verfügbar ({$percent} %)
+labgrid-class-acquired-value = {$acquired} belegt
+labgrid-class-show-resources-button = Ressourcen anzeigen
+
+dashboard-label = Übersicht
+dashboard-places-label = Plätze
+dashboard-acquired-label = Belegt
+dashboard-free-label = Frei
+dashboard-resources-available-label = Verfügbare Ressourcen
+dashboard-count-value = {$count} ({$percent} %)
+dashboard-place-states-label = Plätze nach Zustand
+dashboard-places-empty = Keine Plätze bekannt.
+dashboard-state-free = Frei
+dashboard-state-mine = Von mir belegt
+dashboard-state-others = Von anderen belegt
+dashboard-state-reserved = Reserviert
+dashboard-state-maintenance = In Wartung
+dashboard-acquisitions-label = Belegte Plätze pro Benutzer
+dashboard-acquisitions-empty = Keine Plätze sind belegt.
+dashboard-class-availability-label = Verfügbarkeit der Ressourcen pro Klasse
+dashboard-percent-value = {$percent} %
+dashboard-occupancy-label = Auslastung pro Platz
+dashboard-occupancy-disabled = Die Auslastung der Plätze wird nicht aufgezeichnet, die Aufzeichnung kann in den Einstellungen aktiviert werden.
+utilization-range-day = Letzter Tag
+utilization-range-week = Letzte Woche
 labgrid-resources-no-exporter-name = Ohne Exporter-Name
 labgrid-resource-acquired-label = Erworben
 labgrid-resource-availability-tooltip = Verfügbarkeit
 labgrid-resource-params-label = Parameter
 labgrid-resource-extra-label = Extra
+labgrid-camera-preview-label = Vorschau
+labgrid-camera-preview-refresh-button = Aktualisieren
+labgrid-camera-preview-loading = Lade das Standbild ..
+labgrid-camera-preview-failed = Abrufen des Standbilds fehlgeschlagen: {$error}
+labgrid-camera-preview-no-url = Die Kamera hat keine HTTP-URL, von der ein Standbild abgerufen werden kann.
+labgrid-resource-stale-label = Offline
+labgrid-resource-stale-tooltip = Der Exporter dieser Ressource ist offline, sie wird nach Ablauf der Schonfrist entfernt
+labgrid-resource-acquire-history-label = Zuletzt erworben von
+labgrid-resource-acquire-record = {$acquired_by} um {$time}
+labgrid-resource-assign-tooltip = Platz zuweisen…
+labgrid-resource-assign-header = Ressource einem Platz zuweisen
+labgrid-resource-assign-pattern-label = Match Muster
+labgrid-resource-assign-button = Zuweisen
+labgrid-resource-class-tooltip = Ressourcenklasse {$cls}
+labgrid-resource-class-unknown-tooltip = Die Ressourcenklasse {$cls} ist unbekannt, klicken um Symbol und Schnellaktion zu konfigurieren
+labgrid-resource-copy-param-tooltip = Wert des Parameters '{$param}' in die Zwischenablage kopieren
+labgrid-resource-copy-command-tooltip = {$program} Befehl in die Zwischenablage kopieren
+labgrid-resource-launch-command-tooltip = {$program} im Terminalemulator ausführen
+labgrid-resource-watch-tooltip = Beobachten, benachrichtigen wenn sich die Verfügbarkeit der Ressource ändert
+labgrid-resource-unwatch-tooltip = Nicht mehr beobachten
+resource-class-header = Ressourcenklasse {$cls}
+resource-class-known-msg = Diese Ressourcenklasse ist bekannt, ihr Symbol kann überschrieben werden.
+resource-class-unknown-msg = Diese Ressourcenklasse ist unbekannt, die folgenden Parameter wurden bei ihren Ressourcen beobachtet. Ordne ihr ein Symbol und einen Parameter zu, der von der Schnellaktion kopiert wird.
+resource-class-params-label = Beobachtete Parameter
+resource-class-no-params-msg = Es wurden keine Parameter beobachtet.
+resource-class-param-observed = {$kinds}, bei {$count} von {$total} Ressourcen
+resource-class-icon-label = Symbol
+resource-class-icon-placeholder = Kein Symbol
+resource-class-copy-param-label = Von der Schnellaktion kopierter Parameter
+resource-class-copy-param-placeholder = Keine Schnellaktion
+resource-class-reset-button = Zurücksetzen
+usb-topology-header = USB-Topologie von {$exporter}
+usb-topology-empty = Der Exporter hat keine USB-Geräte mit bekanntem Bus gemeldet.
+usb-topology-bus-label = Bus {$bus}
+usb-topology-port-label = Port {$port}
+usb-topology-unknown-port-label = Port unbekannt
+usb-topology-device-value = Gerät {$devnum}
+usb-topology-acquired-value = Belegt von {$place}
+quick-switcher-placeholder = Plätze und Skripte durchsuchen
+quick-switcher-hint = ↑↓ auswählen · Enter Platzdetails öffnen oder Skript ausführen · Umschalt+Enter Platz belegen · Esc schließen
+quick-switcher-place-label = Platz
+quick-switcher-script-label = Skript
+quick-switcher-no-matches-msg = Keine passenden Plätze oder Skripte
+quick-switcher-command-label = Befehl
+command-palette-placeholder = Befehle, Plätze und Skripte durchsuchen
+command-palette-hint = ↑↓ auswählen · Enter Befehl ausführen, Platzdetails öffnen oder Skript ausführen · Umschalt+Enter Platz belegen · Esc schließen
+command-refresh = Aktualisieren
+command-show-tab = { $tab } anzeigen
+command-create-reservation = Reservierung erstellen
+command-open-settings = Einstellungen öffnen
+command-disconnect = Trennen
+resource-class-icon-board = Board
+resource-class-icon-power = Stromversorgung
+resource-class-icon-serial = Serielle Konsole
+resource-class-icon-network = Netzwerk
+resource-class-icon-usb = USB
+resource-class-icon-video = Video
+resource-class-icon-storage = Speicher
+resource-class-icon-display = Bildschirm
+resource-class-icon-relay = Relais / GPIO
+resource-class-icon-sensor = Sensor
 labgrid-reservations-label = Reservierungen
 labgrid-reservation-owner-label = Besitzer
 labgrid-reservation-token-label = Token
 labgrid-reservation-prio-label = Priorität
 labgrid-reservation-filters-label = Filter
+labgrid-reservation-created-label = Erstellt
+labgrid-reservation-expires-label = Läuft ab
+labgrid-reservation-expires-in = in {$remaining}
+labgrid-reservation-timed-out = Abgelaufen
+labgrid-reservation-time-unset = -
+labgrid-reservation-allocations-label = Zugeteilt
+labgrid-reservation-goto-place-button = Zum Platz
+labgrid-reservation-queue-label = Warteschlange
+labgrid-reservation-queue-position = Position {$position}
+labgrid-reservation-queue-free-places = {$free} von {$matching} passenden Plätzen frei
+labgrid-reservation-queue-expected-wait = Erwartete Wartezeit ~{$minutes} min
+labgrid-reservation-queue-expected-wait-unknown = Erwartete Wartezeit unbekannt
+labgrid-reservation-cancel-label = Abbrechen
+labgrid-reservation-cancel-foreign-button = Reservierung abbrechen
+labgrid-reservation-cancel-foreign-confirmation-msg = Reservierung '{ $token }' gehört '{ $owner }'. Den Namen des Besitzers eingeben um sie abzubrechen.
+labgrid-reservation-mine-badge = Meine Reservierung
+labgrid-reservations-show-foreign-checkbox = Reservierungen anderer anzeigen
+labgrid-reservation-create-button = Reservierung erstellen
+labgrid-reservation-create-header = Reservierung erstellen
+labgrid-reservation-state-waiting = Wartend
+labgrid-reservation-state-allocated = Zugeteilt
+labgrid-reservation-state-acquired = Belegt
+labgrid-reservation-state-expired = Abgelaufen
+labgrid-reservation-state-invalid = Ungültig
+labgrid-reservation-state-unknown = Unbekannt
+labgrid-reservations-cleanup-button = Aufräumen
+labgrid-reservations-cleanup-tooltip = Abgelaufene, ungültige oder eigene Reservierungen auf einmal abbrechen
+labgrid-reservations-cleanup-header = Reservierungen aufräumen
+labgrid-reservations-cleanup-select-label = Auswahl
+labgrid-reservations-cleanup-stale = Abgelaufene und ungültige Reservierungen
+labgrid-reservations-cleanup-mine = Alle meine Reservierungen
+labgrid-reservations-cleanup-summary = {$count ->
+    [0] Keine Reservierungen ausgewählt
+    [one] Eine Reservierung wird abgebrochen
+   *[other] {$count} Reservierungen werden abgebrochen
+}
+labgrid-reservations-cleanup-confirm-button = Reservierungen abbrechen
+labgrid-reservation-filter-placeholder = Filter-Ausdruck, z.B. board=foo AND lab=muc
+labgrid-reservation-filter-empty-msg = Filter-Ausdruck eingeben, um die zu reservierenden Plätze auszuwählen
+labgrid-reservation-filter-invalid-msg = Ungültiger Filter-Ausdruck: {$error}
+labgrid-reservation-filter-client-side-msg = Nur Vorschau, der Ausdruck kann nicht für Reservierungen verwendet werden: {$reason}
+labgrid-reservation-filter-matches-label = {$count ->
+    [one] Ein passender Platz
+   *[other] {$count} passende Plätze
+}
 
 scripts-label = Skripte
 scripts-none-found-msg = Keine Skripte gefunden
 scripts-dir-reset-tooltip = Skript-Pfad auf Standard zurücksetzen
 scripts-dir-pick-tooltip = Neuen Skript-Pfad auswählen
-scripts-dir-rescan-tooltip = Skript-Pfad neu scannen
+scripts-dir-rescan-tooltip = Skript-Pfad neu scannen, Änderungen werden normalerweise automatisch erkannt
 scripts-dir-rescan-failed-error = Skript-Pfad Scan fehlgeschlagen
+scripts-favorites-label = Favoriten
+script-favorite-tooltip = Das Skript oben anheften
+script-move-up-tooltip = Nach oben verschieben
+script-move-down-tooltip = Nach unten verschieben
+script-display-name-tooltip = Den angezeigten Namen ändern
+script-display-name-msg = Angezeigter Name des Skripts "{$script}", der Dateiname wird angezeigt wenn er leer ist
+script-display-name-placeholder = Angezeigter Name
+script-display-name-button = Speichern
 scripts-env-label = Umgebung
-script-label = Skript
 script-output-label = Ausgabe
 script-execute-button = Ausführen
 script-abort-button = Abbrechen
 script-status-label = Status
 script-status-none = In Ruhe
 script-status-running = Läuft
+script-status-cleaning-up = Aufräumen
 script-status-finished = Abgeschlossen mit Status-Code '{$code}'
+script-status-timed-out = Timeout nach {$secs} s
 script-failed-msg = Gescheitert
+script-cleanup-failed-msg = Skript-Aufräumen gescheitert
+script-validate-button = Prüfen
+script-validate-tooltip = Das Skript auf Syntaxfehler und fehlschlagende labgrid-Importe prüfen, ohne es auszuführen
+script-validation-label = Prüfung
+script-validation-running = Prüfe
+script-validation-valid = Gültig
+script-validation-invalid = Fehler, siehe Ausgabe
+script-validation-failed-msg = Skript-Prüfung gescheitert
+script-not-found-msg = Skript '{ $script }' nicht im Skriptverzeichnis gefunden
 script-output-show-label = Zeigen
 script-output-hide-label = Verbergen
 script-output-clear-tooltip = Skript-Ausgabe leeren
+reservation-run-label = Auf nächstem freien Board ausführen
+reservation-run-filter-placeholder = Filter-Ausdruck, z.B. board=imx8
+reservation-run-script-placeholder = Skript
+reservation-run-button = Ausführen
+reservation-run-cancel-button = Abbrechen
+reservation-run-status-reserving = Reserviere
+reservation-run-status-waiting = Warte auf einen Platz
+reservation-run-status-acquiring = Belege '{$place}'
+reservation-run-status-running = Läuft auf '{$place}'
+reservation-run-lost-msg = Die Reservierung des Laufs ist abgelaufen oder wurde abgebrochen
@@ -4,47 +4,315 @@ app-version-label = Version
 app-quit-label = Quit
 connect-button = Connect
 refresh-ui-tooltip = Refresh UI State
+announcement-dismiss-tooltip = Dismiss announcement
+availability-notice-dismiss-tooltip = Dismiss the notice
+resource-available-notice = The watched resource '{$path}' became available at {$time}
+resource-unavailable-notice = The watched resource '{$path}' became unavailable at {$time}
+protocol-warning-dismiss-tooltip = Dismiss the warning
+protocol-version-unknown = unknown
+protocol-untested-msg = The coordinator runs labgrid { $version }, which was not tested with this client (protocol of labgrid { $client_version }). Places or resources might be missing if the protocol changed.
+protocol-incompatible-msg = The coordinator runs labgrid { $version }, which is incompatible with this client. At least labgrid { $min } is required.
+protocol-mismatch-msg = Messages from the coordinator (labgrid { $version }) could not be read, it likely uses a different protocol than this client (labgrid { $client_version }). Places or resources might be missing: { $error }
 disconnect-button = Disconnect
 clipboard-copy-tooltip = Copy to Clipboard
 clipboard-paste-tooltip = Paste from Clipboard
 text-input-clear-tooltip = Clear text
+time-format = %Y-%m-%d %H:%M:%S
+decimal-separator = .
+time-ago-just-now = just now
+time-ago-minutes = {$minutes} min ago
+time-ago-hours = {$hours} h ago
+time-ago-days = {$days ->
+    [one] {$days} day ago
+   *[other] {$days} days ago
+}
 connecting-msg = Connecting to '{$address}' ..
 connected-to-coordinator-label = Connected to Coordinator '{$address}'
+connection-latency-label = {$ms} ms
+connection-latency-tooltip = Round-trip time of the last keepalive
 show-details-button = Show Details
 hide-details-button = Hide Details
 confirmation-modal-confirm-button = Confirm
 confirmation-modal-cancel-button = Cancel
-coordinator-address-placeholder = Coordinator Address and Port
+coordinator-address-placeholder = Coordinator Address and Port, or URI
 venv-dir-reset-tooltip = Reset the Venv Directory to Default
 error-critical = Critical Error
+error-retry-button = Retry
+error-console-tooltip = Show all errors of this session
+error-console-header = Errors
+error-console-empty = No errors were reported.
+error-console-filter-all = All
+error-console-copy-button = Copy all
+error-console-clear-button = Clear all
 error-noncritical = Non-Critical Error
 connection-msg-invalid-input = Unable to perform connection action, invalid input
+connection-msg-bulk-place-op-failed = Operation failed for {$count} places
+connection-msg-cancel-reservations-failed = Cancelling failed for {$count} reservations
+connection-msg-restore-places-failed = Restoring failed for {$count} changes of the place snapshot
 error-invalid-path = Supplied Path is invalid
 error-app-config-load = Loading app configuration from file failed
 error-app-config-save = Saving app configuration to file failed
+error-utilization-record = Recording the utilization of places failed
+error-app-config-reload = Reloading the changed app configuration failed
+error-app-config-open = Opening the app configuration file failed
+error-terminal-launch = Launching {$program} in the terminal emulator failed
+error-metrics-export = Exporting the metrics failed
+error-rest-gateway = Serving the REST gateway failed
+error-clipboard-copy = Copying to the clipboard failed
+error-clipboard-paste = Pasting from the clipboard failed
+error-connecting-failed = Connecting to the coordinator failed
+error-connecting-timeout = Timeout reached while connecting to the coordinator
+error-keepalive-timeout = The coordinator did not answer the keepalive, the connection is considered lost
+error-transport-failure = The connection to the coordinator failed
+error-msg-conversion = A message from the coordinator could not be converted
+error-grpc-status-unavailable = The coordinator is unavailable ({$code})
+error-grpc-status = The coordinator reported an error ({$code})
 
 file-dialog-filter-python-scripts-label = Python Scripts
+file-browser-pick-dir-header = Choose Directory
+file-browser-pick-file-header = Choose File
+file-browser-listing-msg = Listing directory ..
+file-browser-list-failed-msg = Listing the directory failed: {$error}
+file-browser-empty-msg = The directory is empty
+file-browser-up-tooltip = Parent directory
+file-browser-pick-button = Choose
 
 settings-button = Settings
+profiles-button = Profiles
+profiles-header = Connection Profiles
+profiles-empty = No profiles yet
+profile-default-name = Profile
+profile-select-placeholder = Profile
+profile-clear-tooltip = Connect without a profile
+profile-add-button = Add profile
+profile-delete-button = Delete profile
+profile-name-label = Name
+profile-address-label = Coordinator address
+profile-tls-label = Connect through TLS
+profile-scope-label = Subscriptions
+profile-places-only-mine-label = Only show my places
+profile-resources-only-available-label = Only show available resources
+subscription-scope-places-and-resources = Places and resources
+subscription-scope-places-only = Places only
 settings-header = Settings
 settings-language-pick-label = Pick the Language
 settings-optimize-touch-label = Optimize UI for Touchscreens
+settings-ui-scale-label = Scale of the UI
+settings-ui-scale-value = {$percent} %
+settings-high-contrast-label = Use high contrast colors
+settings-resource-grace-period-label = Keep resources of offline exporters for
+settings-resource-grace-period-value = {$secs} s
+settings-offline-exporter-resources-label = Resources of offline exporters
+settings-offline-exporter-resources-show = Show
+settings-offline-exporter-resources-gray = Gray out
+settings-offline-exporter-resources-hide = Hide
+settings-bulk-ops-per-sec-label = Rate limit of bulk place actions
+settings-bulk-ops-per-sec-value = {$ops} ops/s
+settings-autosave-label = Save settings periodically
+settings-autosave-value = {$secs ->
+    [0] Off
+   *[other] Every {$secs} s
+}
+settings-last-save-label = Last saved at {$time}
+settings-last-save-never = Not saved yet
+settings-save-button = Save Now
+settings-saved-confirmation = Settings saved
+settings-timeout-preset-label = Connection timeouts
+settings-timeout-preset-lan = Local network
+settings-timeout-preset-vpn = VPN / remote lab
+settings-timeout-preset-custom = Custom
+settings-timeout-connect-label = Timeout for connecting to the coordinator
+settings-timeout-stream-label = Timeout for starting the client stream
+settings-timeout-rpc-label = Timeout of coordinator calls
+settings-keepalive-label = Interval of keepalives (0 disables them)
+settings-reservations-poll-label = Interval of refreshing the reservations
+settings-timeout-secs-value = {$secs} s
+settings-retry-transient-errors-label = Retry calls when the coordinator is temporarily unavailable
+settings-auth-token-label = Authentication token
+settings-auth-token-none = None
+settings-auth-token-static = Static token
+settings-auth-token-file = Token file
+settings-auth-token-command = Token command
+settings-auth-token-static-label = Token
+settings-auth-token-file-label = Path of the token file
+settings-auth-token-command-label = Command printing the token
+settings-auth-token-refresh-label = Run the token command again after
+settings-auth-metadata-label = Call metadata
+settings-auth-metadata-tooltip = Comma separated key=value entries sent with every call, applied when connecting
+settings-check-script-imports-label = Check the labgrid imports when validating scripts
+settings-scripts-max-depth-label = Depth of the scanned script subdirectories
+settings-scripts-max-depth-value = {$depth ->
+    [0] Only the scripts directory
+   *[other] {$depth} levels
+}
+settings-script-timeout-label = Kill scripts running longer than
+settings-script-timeout-value = {$mins ->
+    [0] No timeout
+   *[other] {$mins} min
+}
+settings-mqtt-label = Mirror place and resource state to an MQTT broker
+settings-mqtt-broker-label = MQTT broker
+settings-mqtt-topic-prefix-label = MQTT topic prefix
+settings-mqtt-username-label = MQTT username
+settings-mqtt-password-label = MQTT password
+settings-mqtt-apply-button = Apply
+settings-mqtt-apply-tooltip = Restart the MQTT bridge with the changed settings
+settings-mqtt-status-connecting = Connecting…
+settings-mqtt-status-connected = Connected
+settings-mqtt-status-disconnected = Disconnected: {$error}
+settings-persist-acquire-history-label = Persist resource acquire history
+settings-record-utilization-label = Record the utilization of places for the dashboard
+settings-card-color-free-label = Card color of free places
+settings-card-color-mine-label = Card color of places acquired by me
+settings-card-color-others-label = Card color of places acquired by others
+settings-card-color-reserved-label = Card color of reserved places
+settings-card-color-maintenance-label = Card color of places in maintenance
+settings-card-color-reset-tooltip = Reset to default color
+settings-identity-colors-label = Color acquisitions by user
+settings-identity-colors-tooltip = Places acquired by others and acquired resources are colored with a color derived from the owner's host/user name
+settings-notification-place-released-label = Notify when a watched place is released
+settings-notification-reservation-allocated-label = Notify when my reservation is allocated
+settings-notification-script-finished-label = Notify when a script finished
+settings-notification-auto-release-warning-label = Notify before an idle place is released automatically
+settings-notification-resource-availability-label = Notify when a watched resource becomes available or unavailable
+settings-quick-action-acquire-label = Show acquire/release button on place cards
+settings-quick-action-power-cycle-label = Show power cycle button on place cards
+settings-quick-action-console-label = Show console button on place cards
+settings-quick-action-favorite-script-label = Show favorite script button on place cards
+settings-quick-action-favorite-script-pick-label = Favorite script
+settings-quick-action-favorite-script-placeholder = Pick a script
+settings-identity-label = Labgrid identity
+settings-identity-hostname-label = Hostname (overrides LG_HOSTNAME)
+settings-identity-username-label = Username (overrides LG_USERNAME)
+settings-terminal-command-label = Terminal emulator (e.g. 'x-terminal-emulator -e')
+settings-auto-release-label = Release idle places acquired through the UI
+settings-auto-release-idle-label = Release after being idle for
+settings-auto-release-warn-label = Warn before the release
+settings-release-on-exit-label = Release places acquired through the UI on exit
+settings-auto-release-minutes-value = {$minutes} min
+settings-identity-reconnect-button = Reconnect
+settings-identity-reconnect-tooltip = Currently connected as '{ $identity }', reconnect to apply the changed identity
+notification-place-released-summary = Place released
+notification-place-released-body = The watched place '{$place}' was released
+notification-resource-available-summary = Resource available
+notification-resource-available-body = The watched resource '{$path}' became available
+notification-resource-unavailable-summary = Resource unavailable
+notification-resource-unavailable-body = The watched resource '{$path}' became unavailable
+notification-reservation-allocated-summary = Reservation allocated
+notification-reservation-allocated-body = Your reservation '{$token}' was allocated
+notification-script-finished-summary = Script finished
+notification-script-finished-body = Script '{$script}' finished with exit code {$code}
+notification-script-timed-out-body = Script '{$script}' timed out after {$secs} s and was killed
+notification-auto-release-warning-summary = Place about to be released
+notification-auto-release-warning-body = The idle place '{$place}' will be released in {$minutes} min
 settings-venv-dir-label = Change the virtual environment directory for scripts
 settings-venv-dir-pick-tooltip = Pick a new Venv Directory
+settings-venv-create-tooltip = Create a virtual environment with labgrid
+settings-venv-versions-label = Detected versions
+settings-venv-versions-detecting = Detecting…
+settings-venv-versions-value = Python { $python }, labgrid { $labgrid }
+settings-venv-versions-no-labgrid = Python { $python }, labgrid is not installed
+settings-venv-versions-failed = Detection failed
+settings-venv-versions-error = The python interpreter of the virtual environment could not be run: { $error }
+settings-venv-labgrid-missing-msg = labgrid is not installed in the virtual environment, scripts using it will fail.
+settings-venv-incompatible-msg = labgrid { $labgrid } can't communicate with the gRPC coordinator, scripts need at least labgrid { $min }.
+settings-log-viewer-label = Diagnostics log
+settings-log-viewer-button = Show log
+settings-config-file-label = Configuration file
+settings-config-file-open-tooltip = Open with the default application, changes on disk are applied right away
+log-viewer-header = Diagnostics log
+log-viewer-level-label = Level
+log-viewer-msg = Shows the recent events of the application. Events are recorded from the selected level on, independent of RUST_LOG.
+log-viewer-empty = No events recorded.
+log-viewer-clear-button = Clear
+log-viewer-copy-button = Copy to clipboard
+protocol-inspector-header = Protocol inspector
+protocol-inspector-record-label = Record
+protocol-inspector-msg = Shows the protobuf messages exchanged with the coordinator while recording. { $recorded } messages are recorded, the newest { $shown } matching the filter are shown.
+protocol-inspector-filter-placeholder = Filter by message type or content
+protocol-inspector-empty = No messages recorded.
+protocol-inspector-clear-button = Clear
+protocol-inspector-copy-button = Copy to clipboard
+protocol-inspector-copy-tooltip = Copies all recorded messages, including those not shown
+venv-wizard-header = Create virtual environment
+venv-wizard-msg = Creates a python virtual environment with labgrid installed, in which the scripts are run. Requires python3 with the venv module and access to the python package index.
+venv-wizard-dir-label = Directory
+venv-wizard-labgrid-version-label = labgrid version
+venv-wizard-labgrid-version-placeholder = Latest
+venv-wizard-invalid-dir-msg = The directory must be an absolute path
+venv-wizard-dir-not-empty-msg = The directory already exists and is not empty
+venv-wizard-invalid-version-msg = The version is not a valid version number, e.g. 24.0.1
+venv-wizard-step-create = Create the virtual environment
+venv-wizard-step-install = Install labgrid
+venv-wizard-step-validate = Validate the virtual environment
+venv-wizard-done-msg = The virtual environment was created with labgrid {$version}
+venv-wizard-create-button = Create
+venv-wizard-use-button = Use for the scripts
 
 lang-de-ch = "Swiss German"
 lang-de-de = "German"
 lang-en-us = "American English"
 lang-es-es = "Spanish"
+lang-fr-fr = "French"
+lang-it-it = "Italian"
+lang-zh-cn = "Simplified Chinese"
 
 labgrid-places-label = Places
+labgrid-places-show-resources-panel-button = Show Resources
+labgrid-places-group-by-none = No grouping
+labgrid-places-group-by-tag = Group by tag: {$key}
+labgrid-places-group-untagged = Without tag '{$key}'
+labgrid-places-select-mode-button = Select
+labgrid-places-exit-select-mode-button = Done Selecting
+labgrid-places-selected-label = {$count} selected
+labgrid-places-select-all-button = Select All
+labgrid-places-clear-selection-button = Clear Selection
+labgrid-places-bulk-set-tag-button = Set Tag
+labgrid-places-bulk-progress-label = {$done} / {$total} places
+labgrid-places-bulk-pause-button = Pause
+labgrid-places-bulk-resume-button = Resume
+labgrid-places-bulk-cancel-button = Cancel
+labgrid-places-bulk-delete-confirmation-msg = Are you sure you want to delete {$count} places?
+labgrid-place-select-checkbox = Selected
+labgrid-place-mine-badge = Acquired by me
+labgrid-place-mine-tooltip = The place is acquired by this client's identity
+labgrid-place-session-label = Session
+labgrid-place-session-remaining = Released in {$remaining}
+labgrid-place-session-extend-tooltip = Extend the session, restarting the idle time
+labgrid-place-session-keep-tooltip = Keep the place, it will not be released automatically
+labgrid-place-resources-label = Resources
+labgrid-place-resources-available = {$available}/{$total} available
+labgrid-place-resources-tooltip = Available resources matched by the place, matches without any resource count as missing
+labgrid-places-only-mine-checkbox = Only my places
+labgrid-place-power-cycle-tooltip = Power cycle the place (must be acquired by me)
+labgrid-place-power-cycle-failed-msg = Power cycling place '{ $place }' failed
+labgrid-place-console-tooltip = Copy the command to connect to the console
+labgrid-place-favorite-script-tooltip = Execute the favorite script on this place
+labgrid-place-watch-tooltip = Watch, notify when the place is released
+labgrid-place-unwatch-tooltip = Stop watching
+labgrid-places-hide-resources-panel-button = Hide Resources
+labgrid-places-resources-panel-hint = Drag resources onto places to add matches for them
+labgrid-places-resources-panel-dragging-msg = Drop '{$pattern}' onto a place
+labgrid-place-drop-resource-confirmation-msg = Add match '{$pattern}' to place '{$place}'?
 labgrid-place-name-label = Name
 labgrid-place-comment-label = Comment
+labgrid-place-comment-placeholder = Comment
+labgrid-place-comment-edit-tooltip = Edit comment
+labgrid-place-comment-save-button = Save
+labgrid-place-comment-cancel-button = Cancel
 labgrid-place-acquire-button = Acquire
+labgrid-place-acquire-with-reason-tooltip = Acquire with a reason that is shown to other users
+labgrid-place-acquire-with-reason-msg = Why are you acquiring place '{$place}'? The reason is shown to other users until the place is released.
+labgrid-place-acquire-reason-placeholder = e.g. bisecting boot issue
+labgrid-place-acquire-reason-label = Reason
 labgrid-place-not-acquired-label = Available
 labgrid-place-acquired-by-label = Acquired by
 labgrid-place-release-label = Kick
+labgrid-place-force-release-button = Force release
+labgrid-place-force-release-confirmation-msg = Place '{ $place }' is acquired by '{ $owner }'. Type the name of the place to release it from this user.
 labgrid-place-tags-label = Tags
+labgrid-place-created-label = Created
+labgrid-place-changed-label = Changed
 labgrid-place-add-placeholder = Place Name
 labgrid-place-add-button = Add
 labgrid-place-delete-button = Delete
@@ -54,42 +322,295 @@ labgrid-place-add-tag-value-placeholder = Tag Value
 labgrid-place-add-tag-tooltip = Add Tag
 labgrid-place-close-add-tag-tooltip = Close
 labgrid-place-details-header = Place '{$place}' Details
+labgrid-place-clone-button = Clone
+labgrid-place-clone-tooltip = Create a new place with the tags, aliases and resource matches of this place
+labgrid-place-clone-msg = Create a new place set up like '{$place}'. Its name is replaced with the new name in the aliases and resource matches.
+labgrid-place-clone-aliases-label = Aliases
+labgrid-place-clone-none-label = None
+labgrid-place-clone-empty-name-msg = The name must not be empty
+labgrid-place-clone-name-taken-msg = A place with this name already exists
+labgrid-place-clone-alias-taken-msg = Alias '{$alias}' is already used
+labgrid-place-clone-invalid-pattern-msg = '{$pattern}' is not a valid resource match pattern
+place-activity-header = Activity
+place-activity-export-button = Export
+place-activity-export-failed = Exporting the place activity failed
+export-button = Export…
+export-tooltip = Export the displayed entries to a CSV or JSON file
+export-failed = Exporting failed
+place-snapshot-export-button = Snapshot…
+place-snapshot-export-tooltip = Save the names, aliases, comments, tags and resource matches of all places to a JSON file
+place-snapshot-restore-button = Restore…
+place-snapshot-restore-tooltip = Apply a saved snapshot of the places to the coordinator, with a preview of the changes
+place-snapshot-import-failed = Reading the place snapshot failed
+place-snapshot-restore-msg = Restoring '{$file}' creates the missing places and adds the missing aliases, tags and resource matches. Nothing is deleted.
+place-snapshot-taken-msg = Snapshot of {$coordinator} taken {$created}
+place-snapshot-up-to-date-msg = The coordinator already contains everything of the snapshot
+place-snapshot-apply-button = Apply {$count} changes
+place-snapshot-step-add-place = Create place '{$place}'
+place-snapshot-step-add-alias = Add alias '{$alias}'
+place-snapshot-step-set-comment = Set comment to '{$comment}'
+place-snapshot-step-add-tag = Add tag {$key}={$value}
+place-snapshot-step-change-tag = Change tag {$key} from '{$current}' to '{$value}'
+place-snapshot-step-add-match = Add resource match {$pattern}
+place-snapshot-compare-button = Compare…
+place-snapshot-compare-tooltip = Compare the places with a saved snapshot and apply single differences
+place-snapshot-compare-msg = Differences of the places with '{$file}'
+place-snapshot-no-differences-msg = The places match the snapshot
+place-snapshot-close-button = Close
+place-snapshot-restore-all-button = Restore all…
+place-snapshot-diff-apply-button = Apply
+place-snapshot-diff-remove-button = Remove
+place-snapshot-diff-missing-place = Place missing on the coordinator
+place-snapshot-diff-extra-place = Place not in the snapshot
+place-snapshot-diff-missing-alias = Alias '{$alias}' missing
+place-snapshot-diff-extra-alias = Alias '{$alias}' not in the snapshot
+place-snapshot-diff-comment = Comment is '{$current}' instead of '{$snapshot}'
+place-snapshot-diff-missing-tag = Tag {$key}={$value} missing
+place-snapshot-diff-changed-tag = Tag {$key} is '{$current}' instead of '{$snapshot}'
+place-snapshot-diff-extra-tag = Tag {$key}={$value} not in the snapshot
+place-snapshot-diff-missing-match = Resource match {$pattern} missing
+place-snapshot-diff-extra-match = Resource match {$pattern} not in the snapshot
+place-activity-acquired = Acquired by {$user}
+place-activity-released = Released, was acquired by {$user}
+place-activity-match-added = Match '{$pattern}' added
+place-activity-match-removed = Match '{$pattern}' removed
+labgrid-place-details-acquired-notice = This place was just acquired by {$user}
+labgrid-place-details-released-notice = This place was just released by another client
+labgrid-place-details-modified-notice = This place was just modified by another client
 labgrid-place-delete-tag-confirmation-msg = Are you sure you want to delete place tag '{$tag}'?
 labgrid-place-resource-matches-header = Resource Matches
+labgrid-place-aliases-header = Aliases
+labgrid-place-alias-add-placeholder-text = Alias
+labgrid-place-alias-add-button = Add
+labgrid-place-alias-delete-button = Delete
 labgrid-place-resource-acquired-header = Acquired Resources
-labgrid-place-resource-match-add-placeholder-text = Resource Match Pattern
+labgrid-place-acquired-resource-origin = {$exporter} / {$group} / {$cls}
+labgrid-place-acquired-resource-acquired-tooltip = Acquired just now
+labgrid-place-acquired-resource-released-tooltip = Released just now
+labgrid-place-resource-match-rename-placeholder-text = Rename
+labgrid-place-resource-match-rename-tooltip = Optional name the matched resources get in the place, e.g. to bind drivers to them in the environment configuration
+labgrid-place-resource-match-renamed-tooltip = Matched resources are renamed to this name
+labgrid-place-resource-match-builder-exporter = Exporter
+labgrid-place-resource-match-builder-group = Group
+labgrid-place-resource-match-builder-cls = Class
+labgrid-place-resource-match-builder-name = Name (optional)
+labgrid-place-resource-match-builder-matching = {$count ->
+    [0] Matches no known resources
+    [one] Matches one known resource
+   *[other] Matches {$count} known resources
+}
 labgrid-place-resource-match-add-button = Add
 labgrid-place-resource-match-delete-button = Delete
 labgrid-resources-label = Resources
 labgrid-resources-only-show-available-checkbox = Only Show Available
+labgrid-resources-exporter-filter-label = Exporter: {$exporter}
+labgrid-resources-exporter-filter-clear-tooltip = Show resources of all exporters
+labgrid-resources-exporter-offline-label = Offline since {$time}
+labgrid-resources-exporter-offline-tooltip = The coordinator removed all resources of this exporter, it probably disconnected
+labgrid-exporters-label = Exporters
+labgrid-exporters-empty = No exporters known, exporters are derived from the resources announced by the coordinator.
+labgrid-exporter-resources-label = Resources
+labgrid-exporter-available-label = Available
+labgrid-exporter-available-value = {$available} of {$total}
+labgrid-exporter-acquired-label = Acquired
+labgrid-exporter-stale-label = Removed
+labgrid-exporter-last-seen-label = Last seen
+labgrid-exporter-last-seen-unknown = Not since connecting
+labgrid-exporter-offline-label = Offline since
+labgrid-exporter-show-resources-button = Show Resources
+labgrid-exporter-usb-topology-button = USB Topology
+labgrid-resources-class-filter-placeholder = All classes
+labgrid-resources-search-placeholder = Search exporter, group, name or params
+labgrid-resources-class-filter-clear-tooltip = Show resources of all classes
+labgrid-classes-label = Resource Classes
+labgrid-classes-empty = No resources known.
+labgrid-class-resources-value = {$resources} Resources
+labgrid-class-available-value = {$available} of {$total} available ({$percent} %)
+labgrid-class-acquired-value = {$acquired} acquired
+labgrid-class-show-resources-button = Show Resources
+
+dashboard-label = Dashboard
+dashboard-places-label = Places
+dashboard-acquired-label = Acquired
+dashboard-free-label = Free
+dashboard-resources-available-label = Available Resources
+dashboard-count-value = {$count} ({$percent} %)
+dashboard-place-states-label = Places by State
+dashboard-places-empty = No places known.
+dashboard-state-free = Free
+dashboard-state-mine = Acquired by me
+dashboard-state-others = Acquired by others
+dashboard-state-reserved = Reserved
+dashboard-state-maintenance = In maintenance
+dashboard-acquisitions-label = Acquired Places per User
+dashboard-acquisitions-empty = No places are acquired.
+dashboard-class-availability-label = Resource Availability per Class
+dashboard-percent-value = {$percent} %
+dashboard-occupancy-label = Occupancy per Place
+dashboard-occupancy-disabled = The utilization of the places is not recorded, recording can be enabled in the settings.
+utilization-range-day = Last day
+utilization-range-week = Last week
 labgrid-resources-no-exporter-name = Without Exporter Name
 labgrid-resource-acquired-label = Acquired
 labgrid-resource-availability-tooltip = Availability
 labgrid-resource-params-label = Parameter
 labgrid-resource-extra-label = Extra
+labgrid-camera-preview-label = Preview
+labgrid-camera-preview-refresh-button = Refresh
+labgrid-camera-preview-loading = Loading the snapshot ..
+labgrid-camera-preview-failed = Fetching the snapshot failed: {$error}
+labgrid-camera-preview-no-url = The camera has no HTTP URL to fetch a snapshot from.
+labgrid-resource-stale-label = Offline
+labgrid-resource-stale-tooltip = The exporter of this resource went offline, it will be removed after the grace period
+labgrid-resource-acquire-history-label = Recently acquired by
+labgrid-resource-acquire-record = {$acquired_by} at {$time}
+labgrid-resource-assign-tooltip = Assign to place…
+labgrid-resource-assign-header = Assign Resource to Place
+labgrid-resource-assign-pattern-label = Match Pattern
+labgrid-resource-assign-button = Assign
+labgrid-resource-class-tooltip = Resource class {$cls}
+labgrid-resource-class-unknown-tooltip = The resource class {$cls} is unknown, click to configure its icon and quick action
+labgrid-resource-copy-param-tooltip = Copy the value of the parameter '{$param}' to the clipboard
+labgrid-resource-copy-command-tooltip = Copy the {$program} command to the clipboard
+labgrid-resource-launch-command-tooltip = Run {$program} in the terminal emulator
+labgrid-resource-watch-tooltip = Watch, notify when the resource becomes available or unavailable
+labgrid-resource-unwatch-tooltip = Stop watching
+resource-class-header = Resource Class {$cls}
+resource-class-known-msg = This resource class is known, its icon can be overridden.
+resource-class-unknown-msg = This resource class is unknown, the parameters below were observed on its resources. Map it to an icon and a parameter that is copied by the quick action button.
+resource-class-params-label = Observed Parameters
+resource-class-no-params-msg = No parameters were observed.
+resource-class-param-observed = {$kinds}, on {$count} of {$total} resources
+resource-class-icon-label = Icon
+resource-class-icon-placeholder = No icon
+resource-class-copy-param-label = Parameter copied by the quick action button
+resource-class-copy-param-placeholder = No quick action
+resource-class-reset-button = Reset
+usb-topology-header = USB Topology of {$exporter}
+usb-topology-empty = No USB devices with a known bus were announced by the exporter.
+usb-topology-bus-label = Bus {$bus}
+usb-topology-port-label = Port {$port}
+usb-topology-unknown-port-label = Port unknown
+usb-topology-device-value = Device {$devnum}
+usb-topology-acquired-value = Acquired by {$place}
+quick-switcher-placeholder = Search places and scripts
+quick-switcher-hint = ↑↓ select · Enter open place details or run script · Shift+Enter acquire place · Esc close
+quick-switcher-place-label = Place
+quick-switcher-script-label = Script
+quick-switcher-no-matches-msg = No matching places or scripts
+quick-switcher-command-label = Command
+command-palette-placeholder = Search commands, places and scripts
+command-palette-hint = ↑↓ select · Enter run command, open place details or run script · Shift+Enter acquire place · Esc close
+command-refresh = Refresh
+command-show-tab = Show { $tab }
+command-create-reservation = Create reservation
+command-open-settings = Open settings
+command-disconnect = Disconnect
+resource-class-icon-board = Board
+resource-class-icon-power = Power
+resource-class-icon-serial = Serial console
+resource-class-icon-network = Network
+resource-class-icon-usb = USB
+resource-class-icon-video = Video
+resource-class-icon-storage = Storage
+resource-class-icon-display = Display
+resource-class-icon-relay = Relay / GPIO
+resource-class-icon-sensor = Sensor
 labgrid-reservations-label = Reservations
 labgrid-reservation-owner-label = Owner
 labgrid-reservation-token-label = Token
 labgrid-reservation-prio-label = Priority
 labgrid-reservation-filters-label = Filters
+labgrid-reservation-created-label = Created
+labgrid-reservation-expires-label = Expires
+labgrid-reservation-expires-in = in {$remaining}
+labgrid-reservation-timed-out = Timed out
+labgrid-reservation-time-unset = -
+labgrid-reservation-allocations-label = Allocated
+labgrid-reservation-goto-place-button = Go to place
+labgrid-reservation-queue-label = Queue
+labgrid-reservation-queue-position = Position {$position}
+labgrid-reservation-queue-free-places = {$free} of {$matching} matching places free
+labgrid-reservation-queue-expected-wait = Expected wait ~{$minutes} min
+labgrid-reservation-queue-expected-wait-unknown = Expected wait unknown
 labgrid-reservation-cancel-label = Cancel
+labgrid-reservation-cancel-foreign-button = Cancel reservation
+labgrid-reservation-cancel-foreign-confirmation-msg = Reservation '{ $token }' belongs to '{ $owner }'. Type the name of the owner to cancel it.
+labgrid-reservation-mine-badge = My reservation
+labgrid-reservations-show-foreign-checkbox = Show reservations of others
+labgrid-reservation-create-button = Create Reservation
+labgrid-reservation-create-header = Create Reservation
+labgrid-reservation-state-waiting = Waiting
+labgrid-reservation-state-allocated = Allocated
+labgrid-reservation-state-acquired = Acquired
+labgrid-reservation-state-expired = Expired
+labgrid-reservation-state-invalid = Invalid
+labgrid-reservation-state-unknown = Unknown
+labgrid-reservations-cleanup-button = Clean Up
+labgrid-reservations-cleanup-tooltip = Cancel expired, invalid or own reservations at once
+labgrid-reservations-cleanup-header = Clean Up Reservations
+labgrid-reservations-cleanup-select-label = Select
+labgrid-reservations-cleanup-stale = Expired and invalid reservations
+labgrid-reservations-cleanup-mine = All my reservations
+labgrid-reservations-cleanup-summary = {$count ->
+    [0] No reservations are selected
+    [one] One reservation will be cancelled
+   *[other] {$count} reservations will be cancelled
+}
+labgrid-reservations-cleanup-confirm-button = Cancel Reservations
+labgrid-reservation-filter-placeholder = Filter Expression, e.g. board=foo AND lab=muc
+labgrid-reservation-filter-empty-msg = Enter a filter expression to select the places to reserve
+labgrid-reservation-filter-invalid-msg = Invalid filter expression: {$error}
+labgrid-reservation-filter-client-side-msg = Preview only, the expression can't be used for reservations: {$reason}
+labgrid-reservation-filter-matches-label = {$count ->
+    [one] One matching place
+   *[other] {$count} matching places
+}
 
 scripts-label = Scripts
 scripts-none-found-msg = No Scripts Found
 scripts-dir-reset-tooltip = Reset Scripts Directory to Default
 scripts-dir-pick-tooltip = Pick a new Scripts Directory
-scripts-dir-rescan-tooltip = Rescan the Scripts Directory
+scripts-dir-rescan-tooltip = Rescan the Scripts Directory, changes are usually picked up automatically
 scripts-dir-rescan-failed-error = Scripts Directory Rescan Failed
+scripts-favorites-label = Favorites
+script-favorite-tooltip = Pin the script at the top
+script-move-up-tooltip = Move up
+script-move-down-tooltip = Move down
+script-display-name-tooltip = Change the displayed name
+script-display-name-msg = Displayed name of the script "{$script}", the file name is displayed when it is empty
+script-display-name-placeholder = Displayed name
+script-display-name-button = Save
 scripts-env-label = Environment
-script-label = Script
 script-output-label = Output
 script-execute-button = Execute
 script-abort-button = Abort
 script-status-label = Status
 script-status-none = Idle
 script-status-running = Running
+script-status-cleaning-up = Cleaning up
 script-status-finished = Finished with Exit-Code '{$code}'
+script-status-timed-out = Timed out after {$secs} s
 script-failed-msg = Script failed
+script-cleanup-failed-msg = Script cleanup failed
+script-validate-button = Validate
+script-validate-tooltip = Check the script for syntax errors and failing labgrid imports, without executing it
+script-validation-label = Validation
+script-validation-running = Validating
+script-validation-valid = Valid
+script-validation-invalid = Errors, see the output
+script-validation-failed-msg = Script validation failed
+script-not-found-msg = Script '{ $script }' not found in the scripts directory
 script-output-show-label = Show
 script-output-hide-label = Hide
 script-output-clear-tooltip = Clear script output
+reservation-run-label = Run on next available board
+reservation-run-filter-placeholder = Filter Expression, e.g. board=imx8
+reservation-run-script-placeholder = Script
+reservation-run-button = Run
+reservation-run-cancel-button = Cancel
+reservation-run-status-reserving = Reserving
+reservation-run-status-waiting = Waiting for a place
+reservation-run-status-acquiring = Acquiring '{$place}'
+reservation-run-status-running = Running on '{$place}'
+reservation-run-lost-msg = The reservation of the run expired or was cancelled
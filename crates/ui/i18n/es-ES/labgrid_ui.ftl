@@ -1 +1,3 @@
 app-title = Labgrid interfaz de usuario
+time-format = %d/%m/%Y %H:%M:%S
+decimal-separator = ,
@@ -0,0 +1,620 @@
+app-title = Interface utilisateur Labgrid
+app-authors-label = Auteurs
+app-version-label = Version
+app-quit-label = Quitter
+connect-button = Connecter
+refresh-ui-tooltip = Actualiser l'état de l'interface
+announcement-dismiss-tooltip = Masquer l'annonce
+availability-notice-dismiss-tooltip = Masquer l'avis
+resource-available-notice = La ressource surveillée '{$path}' est devenue disponible à {$time}
+resource-unavailable-notice = La ressource surveillée '{$path}' est devenue indisponible à {$time}
+protocol-warning-dismiss-tooltip = Masquer l'avertissement
+protocol-version-unknown = inconnue
+protocol-untested-msg = Le coordinateur exécute labgrid { $version }, qui n'a pas été testé avec ce client (protocole de labgrid { $client_version }). Des places ou des ressources peuvent manquer si le protocole a changé.
+protocol-incompatible-msg = Le coordinateur exécute labgrid { $version }, qui est incompatible avec ce client. Au moins labgrid { $min } est requis.
+protocol-mismatch-msg = Les messages du coordinateur (labgrid { $version }) n'ont pas pu être lus, il utilise probablement un autre protocole que ce client (labgrid { $client_version }). Des places ou des ressources peuvent manquer : { $error }
+disconnect-button = Déconnecter
+clipboard-copy-tooltip = Copier dans le presse-papiers
+clipboard-paste-tooltip = Coller depuis le presse-papiers
+text-input-clear-tooltip = Effacer le texte
+time-format = %d/%m/%Y %H:%M:%S
+decimal-separator = ,
+time-ago-just-now = à l'instant
+time-ago-minutes = il y a {$minutes} min
+time-ago-hours = il y a {$hours} h
+time-ago-days = {$days ->
+    [one] il y a {$days} jour
+   *[other] il y a {$days} jours
+}
+connecting-msg = Connexion à '{$address}' ..
+connected-to-coordinator-label = Connecté au coordinateur '{$address}'
+connection-latency-label = {$ms} ms
+connection-latency-tooltip = Temps aller-retour du dernier keepalive
+show-details-button = Afficher les détails
+hide-details-button = Masquer les détails
+confirmation-modal-confirm-button = Confirmer
+confirmation-modal-cancel-button = Annuler
+coordinator-address-placeholder = Adresse et port du coordinateur, ou URI
+venv-dir-reset-tooltip = Réinitialiser le répertoire venv par défaut
+error-critical = Erreur critique
+error-retry-button = Réessayer
+error-console-tooltip = Afficher toutes les erreurs de cette session
+error-console-header = Erreurs
+error-console-empty = Aucune erreur n'a été signalée.
+error-console-filter-all = Toutes
+error-console-copy-button = Tout copier
+error-console-clear-button = Tout effacer
+error-noncritical = Erreur non critique
+connection-msg-invalid-input = Impossible d'exécuter l'action de connexion, saisie invalide
+connection-msg-bulk-place-op-failed = L'opération a échoué pour {$count} places
+connection-msg-cancel-reservations-failed = L'annulation a échoué pour {$count} réservations
+connection-msg-restore-places-failed = La restauration a échoué pour {$count} modifications de l'instantané des places
+error-invalid-path = Le chemin indiqué est invalide
+error-app-config-load = Le chargement de la configuration de l'application a échoué
+error-app-config-save = L'enregistrement de la configuration de l'application a échoué
+error-utilization-record = L'enregistrement de l'utilisation des places a échoué
+error-app-config-reload = Le rechargement de la configuration modifiée de l'application a échoué
+error-app-config-open = L'ouverture du fichier de configuration de l'application a échoué
+error-terminal-launch = Le lancement de {$program} dans l'émulateur de terminal a échoué
+error-metrics-export = L'export des métriques a échoué
+error-rest-gateway = Le service de la passerelle REST a échoué
+error-clipboard-copy = La copie dans le presse-papiers a échoué
+error-clipboard-paste = Le collage depuis le presse-papiers a échoué
+error-connecting-failed = La connexion au coordinateur a échoué
+error-connecting-timeout = Délai dépassé lors de la connexion au coordinateur
+error-keepalive-timeout = Le coordinateur n'a pas répondu au keepalive, la connexion est considérée comme perdue
+error-transport-failure = La connexion au coordinateur a échoué
+error-msg-conversion = Un message du coordinateur n'a pas pu être converti
+error-grpc-status-unavailable = Le coordinateur n'est pas disponible ({$code})
+error-grpc-status = Le coordinateur a signalé une erreur ({$code})
+
+file-dialog-filter-python-scripts-label = Scripts Python
+file-browser-pick-dir-header = Choisir un répertoire
+file-browser-pick-file-header = Choisir un fichier
+file-browser-listing-msg = Lecture du répertoire ..
+file-browser-list-failed-msg = La lecture du répertoire a échoué : {$error}
+file-browser-empty-msg = Le répertoire est vide
+file-browser-up-tooltip = Répertoire parent
+file-browser-pick-button = Choisir
+
+settings-button = Paramètres
+profiles-button = Profils
+profiles-header = Profils de connexion
+profiles-empty = Aucun profil
+profile-default-name = Profil
+profile-select-placeholder = Profil
+profile-clear-tooltip = Se connecter sans profil
+profile-add-button = Ajouter un profil
+profile-delete-button = Supprimer le profil
+profile-name-label = Nom
+profile-address-label = Adresse du coordinateur
+profile-tls-label = Se connecter via TLS
+profile-scope-label = Abonnements
+profile-places-only-mine-label = N'afficher que mes places
+profile-resources-only-available-label = N'afficher que les ressources disponibles
+subscription-scope-places-and-resources = Places et ressources
+subscription-scope-places-only = Places uniquement
+settings-header = Paramètres
+settings-language-pick-label = Choisir la langue
+settings-optimize-touch-label = Optimiser l'interface pour les écrans tactiles
+settings-ui-scale-label = Échelle de l'interface
+settings-ui-scale-value = {$percent} %
+settings-high-contrast-label = Utiliser des couleurs à contraste élevé
+settings-resource-grace-period-label = Conserver les ressources des exportateurs hors ligne pendant
+settings-resource-grace-period-value = {$secs} s
+settings-offline-exporter-resources-label = Ressources des exportateurs hors ligne
+settings-offline-exporter-resources-show = Afficher
+settings-offline-exporter-resources-gray = Griser
+settings-offline-exporter-resources-hide = Masquer
+settings-bulk-ops-per-sec-label = Limite de débit des actions groupées sur les places
+settings-bulk-ops-per-sec-value = {$ops} op/s
+settings-autosave-label = Enregistrer les paramètres périodiquement
+settings-autosave-value = {$secs ->
+    [0] Désactivé
+   *[other] Toutes les {$secs} s
+}
+settings-last-save-label = Dernier enregistrement à {$time}
+settings-last-save-never = Pas encore enregistré
+settings-save-button = Enregistrer maintenant
+settings-saved-confirmation = Paramètres enregistrés
+settings-timeout-preset-label = Délais de connexion
+settings-timeout-preset-lan = Réseau local
+settings-timeout-preset-vpn = VPN / laboratoire distant
+settings-timeout-preset-custom = Personnalisé
+settings-timeout-connect-label = Délai de connexion au coordinateur
+settings-timeout-stream-label = Délai de démarrage du flux client
+settings-timeout-rpc-label = Délai des appels au coordinateur
+settings-keepalive-label = Intervalle des keepalives (0 les désactive)
+settings-reservations-poll-label = Intervalle d'actualisation des réservations
+settings-timeout-secs-value = {$secs} s
+settings-retry-transient-errors-label = Réessayer les appels lorsque le coordinateur est temporairement indisponible
+settings-auth-token-label = Jeton d'authentification
+settings-auth-token-none = Aucun
+settings-auth-token-static = Jeton fixe
+settings-auth-token-file = Fichier de jeton
+settings-auth-token-command = Commande de jeton
+settings-auth-token-static-label = Jeton
+settings-auth-token-file-label = Chemin du fichier de jeton
+settings-auth-token-command-label = Commande affichant le jeton
+settings-auth-token-refresh-label = Réexécuter la commande de jeton après
+settings-auth-metadata-label = Métadonnées des appels
+settings-auth-metadata-tooltip = Entrées clé=valeur séparées par des virgules, envoyées avec chaque appel, appliquées à la connexion
+settings-check-script-imports-label = Vérifier les imports labgrid lors de la validation des scripts
+settings-scripts-max-depth-label = Profondeur des sous-répertoires de scripts analysés
+settings-scripts-max-depth-value = {$depth ->
+    [0] Uniquement le répertoire des scripts
+    [one] {$depth} niveau
+   *[other] {$depth} niveaux
+}
+settings-script-timeout-label = Arrêter les scripts qui s'exécutent plus de
+settings-script-timeout-value = {$mins ->
+    [0] Pas de délai
+   *[other] {$mins} min
+}
+settings-mqtt-label = Refléter l'état des places et des ressources vers un broker MQTT
+settings-mqtt-broker-label = Broker MQTT
+settings-mqtt-topic-prefix-label = Préfixe des topics MQTT
+settings-mqtt-username-label = Nom d'utilisateur MQTT
+settings-mqtt-password-label = Mot de passe MQTT
+settings-mqtt-apply-button = Appliquer
+settings-mqtt-apply-tooltip = Redémarrer le pont MQTT avec les paramètres modifiés
+settings-mqtt-status-connecting = Connexion…
+settings-mqtt-status-connected = Connecté
+settings-mqtt-status-disconnected = Déconnecté : {$error}
+settings-persist-acquire-history-label = Conserver l'historique d'acquisition des ressources
+settings-record-utilization-label = Enregistrer l'utilisation des places pour le tableau de bord
+settings-card-color-free-label = Couleur des cartes des places libres
+settings-card-color-mine-label = Couleur des cartes des places acquises par moi
+settings-card-color-others-label = Couleur des cartes des places acquises par d'autres
+settings-card-color-reserved-label = Couleur des cartes des places réservées
+settings-card-color-maintenance-label = Couleur des cartes des places en maintenance
+settings-card-color-reset-tooltip = Rétablir la couleur par défaut
+settings-identity-colors-label = Colorer les acquisitions par utilisateur
+settings-identity-colors-tooltip = Les places acquises par d'autres et les ressources acquises sont colorées avec une couleur dérivée du nom host/user du propriétaire
+settings-notification-place-released-label = Notifier lorsqu'une place surveillée est libérée
+settings-notification-reservation-allocated-label = Notifier lorsque ma réservation est attribuée
+settings-notification-script-finished-label = Notifier lorsqu'un script est terminé
+settings-notification-auto-release-warning-label = Notifier avant la libération automatique d'une place inactive
+settings-notification-resource-availability-label = Notifier lorsqu'une ressource surveillée devient disponible ou indisponible
+settings-quick-action-acquire-label = Afficher le bouton acquérir/libérer sur les cartes des places
+settings-quick-action-power-cycle-label = Afficher le bouton de redémarrage électrique sur les cartes des places
+settings-quick-action-console-label = Afficher le bouton console sur les cartes des places
+settings-quick-action-favorite-script-label = Afficher le bouton du script favori sur les cartes des places
+settings-quick-action-favorite-script-pick-label = Script favori
+settings-quick-action-favorite-script-placeholder = Choisir un script
+settings-identity-label = Identité labgrid
+settings-identity-hostname-label = Nom d'hôte (remplace LG_HOSTNAME)
+settings-identity-username-label = Nom d'utilisateur (remplace LG_USERNAME)
+settings-terminal-command-label = Émulateur de terminal (p. ex. 'x-terminal-emulator -e')
+settings-auto-release-label = Libérer les places inactives acquises via l'interface
+settings-auto-release-idle-label = Libérer après une inactivité de
+settings-auto-release-warn-label = Avertir avant la libération
+settings-release-on-exit-label = Libérer les places acquises via l'interface à la fermeture
+settings-auto-release-minutes-value = {$minutes} min
+settings-identity-reconnect-button = Reconnecter
+settings-identity-reconnect-tooltip = Actuellement connecté en tant que '{ $identity }', reconnectez-vous pour appliquer l'identité modifiée
+notification-place-released-summary = Place libérée
+notification-place-released-body = La place surveillée '{$place}' a été libérée
+notification-resource-available-summary = Ressource disponible
+notification-resource-available-body = La ressource surveillée '{$path}' est devenue disponible
+notification-resource-unavailable-summary = Ressource indisponible
+notification-resource-unavailable-body = La ressource surveillée '{$path}' est devenue indisponible
+notification-reservation-allocated-summary = Réservation attribuée
+notification-reservation-allocated-body = Votre réservation '{$token}' a été attribuée
+notification-script-finished-summary = Script terminé
+notification-script-finished-body = Le script '{$script}' s'est terminé avec le code de sortie {$code}
+notification-script-timed-out-body = Le script '{$script}' a dépassé le délai de {$secs} s et a été arrêté
+notification-auto-release-warning-summary = Place bientôt libérée
+notification-auto-release-warning-body = La place inactive '{$place}' sera libérée dans {$minutes} min
+settings-venv-dir-label = Changer le répertoire de l'environnement virtuel des scripts
+settings-venv-dir-pick-tooltip = Choisir un nouveau répertoire venv
+settings-venv-create-tooltip = Créer un environnement virtuel avec labgrid
+settings-venv-versions-label = Versions détectées
+settings-venv-versions-detecting = Détection…
+settings-venv-versions-value = Python { $python }, labgrid { $labgrid }
+settings-venv-versions-no-labgrid = Python { $python }, labgrid n'est pas installé
+settings-venv-versions-failed = La détection a échoué
+settings-venv-versions-error = L'interpréteur python de l'environnement virtuel n'a pas pu être exécuté : { $error }
+settings-venv-labgrid-missing-msg = labgrid n'est pas installé dans l'environnement virtuel, les scripts qui l'utilisent échoueront.
+settings-venv-incompatible-msg = labgrid { $labgrid } ne peut pas communiquer avec le coordinateur gRPC, les scripts nécessitent au moins labgrid { $min }.
+settings-log-viewer-label = Journal de diagnostic
+settings-log-viewer-button = Afficher le journal
+settings-config-file-label = Fichier de configuration
+settings-config-file-open-tooltip = Ouvrir avec l'application par défaut, les modifications sur le disque sont appliquées immédiatement
+log-viewer-header = Journal de diagnostic
+log-viewer-level-label = Niveau
+log-viewer-msg = Affiche les événements récents de l'application. Les événements sont enregistrés à partir du niveau sélectionné, indépendamment de RUST_LOG.
+log-viewer-empty = Aucun événement enregistré.
+log-viewer-clear-button = Effacer
+log-viewer-copy-button = Copier dans le presse-papiers
+protocol-inspector-header = Inspecteur de protocole
+protocol-inspector-record-label = Enregistrer
+protocol-inspector-msg = Affiche les messages protobuf échangés avec le coordinateur pendant l'enregistrement. { $recorded } messages sont enregistrés, les { $shown } plus récents correspondant au filtre sont affichés.
+protocol-inspector-filter-placeholder = Filtrer par type ou contenu de message
+protocol-inspector-empty = Aucun message enregistré.
+protocol-inspector-clear-button = Vider
+protocol-inspector-copy-button = Copier dans le presse-papiers
+protocol-inspector-copy-tooltip = Copie tous les messages enregistrés, y compris ceux qui ne sont pas affichés
+venv-wizard-header = Créer un environnement virtuel
+venv-wizard-msg = Crée un environnement virtuel python avec labgrid installé, dans lequel les scripts sont exécutés. Nécessite python3 avec le module venv et un accès à l'index des paquets python.
+venv-wizard-dir-label = Répertoire
+venv-wizard-labgrid-version-label = Version de labgrid
+venv-wizard-labgrid-version-placeholder = La plus récente
+venv-wizard-invalid-dir-msg = Le répertoire doit être un chemin absolu
+venv-wizard-dir-not-empty-msg = Le répertoire existe déjà et n'est pas vide
+venv-wizard-invalid-version-msg = La version n'est pas un numéro de version valide, p. ex. 24.0.1
+venv-wizard-step-create = Créer l'environnement virtuel
+venv-wizard-step-install = Installer labgrid
+venv-wizard-step-validate = Valider l'environnement virtuel
+venv-wizard-done-msg = L'environnement virtuel a été créé avec labgrid {$version}
+venv-wizard-create-button = Créer
+venv-wizard-use-button = Utiliser pour les scripts
+
+lang-de-ch = "Suisse allemand"
+lang-de-de = "Allemand"
+lang-en-us = "Anglais américain"
+lang-es-es = "Espagnol"
+lang-fr-fr = "Français"
+lang-it-it = "Italien"
+lang-zh-cn = "Chinois simplifié"
+
+labgrid-places-label = Places
+labgrid-places-show-resources-panel-button = Afficher les ressources
+labgrid-places-group-by-none = Sans regroupement
+labgrid-places-group-by-tag = Regrouper par tag : {$key}
+labgrid-places-group-untagged = Sans tag '{$key}'
+labgrid-places-select-mode-button = Sélectionner
+labgrid-places-exit-select-mode-button = Terminer la sélection
+labgrid-places-selected-label = {$count ->
+    [one] {$count} sélectionnée
+   *[other] {$count} sélectionnées
+}
+labgrid-places-select-all-button = Tout sélectionner
+labgrid-places-clear-selection-button = Effacer la sélection
+labgrid-places-bulk-set-tag-button = Définir un tag
+labgrid-places-bulk-progress-label = {$done} / {$total} places
+labgrid-places-bulk-pause-button = Pause
+labgrid-places-bulk-resume-button = Reprendre
+labgrid-places-bulk-cancel-button = Annuler
+labgrid-places-bulk-delete-confirmation-msg = Voulez-vous vraiment supprimer {$count} places ?
+labgrid-place-select-checkbox = Sélectionnée
+labgrid-place-mine-badge = Acquise par moi
+labgrid-place-mine-tooltip = La place est acquise par l'identité de ce client
+labgrid-place-session-label = Session
+labgrid-place-session-remaining = Libérée dans {$remaining}
+labgrid-place-session-extend-tooltip = Prolonger la session en redémarrant le temps d'inactivité
+labgrid-place-session-keep-tooltip = Garder la place, elle ne sera pas libérée automatiquement
+labgrid-place-resources-label = Ressources
+labgrid-place-resources-available = {$available}/{$total} disponibles
+labgrid-place-resources-tooltip = Ressources disponibles correspondant à la place, les correspondances sans ressource comptent comme manquantes
+labgrid-places-only-mine-checkbox = Uniquement mes places
+labgrid-place-power-cycle-tooltip = Redémarrer électriquement la place (doit être acquise par moi)
+labgrid-place-power-cycle-failed-msg = Le redémarrage électrique de la place '{ $place }' a échoué
+labgrid-place-console-tooltip = Copier la commande de connexion à la console
+labgrid-place-favorite-script-tooltip = Exécuter le script favori sur cette place
+labgrid-place-watch-tooltip = Surveiller, notifier lorsque la place est libérée
+labgrid-place-unwatch-tooltip = Arrêter la surveillance
+labgrid-places-hide-resources-panel-button = Masquer les ressources
+labgrid-places-resources-panel-hint = Faites glisser des ressources sur des places pour leur ajouter des correspondances
+labgrid-places-resources-panel-dragging-msg = Déposez '{$pattern}' sur une place
+labgrid-place-drop-resource-confirmation-msg = Ajouter la correspondance '{$pattern}' à la place '{$place}' ?
+labgrid-place-name-label = Nom
+labgrid-place-comment-label = Commentaire
+labgrid-place-comment-placeholder = Commentaire
+labgrid-place-comment-edit-tooltip = Modifier le commentaire
+labgrid-place-comment-save-button = Enregistrer
+labgrid-place-comment-cancel-button = Annuler
+labgrid-place-acquire-button = Acquérir
+labgrid-place-acquire-with-reason-tooltip = Acquérir avec une raison affichée aux autres utilisateurs
+labgrid-place-acquire-with-reason-msg = Pourquoi acquérez-vous la place '{$place}' ? La raison est affichée aux autres utilisateurs jusqu'à la libération de la place.
+labgrid-place-acquire-reason-placeholder = p. ex. bissection d'un problème de démarrage
+labgrid-place-acquire-reason-label = Raison
+labgrid-place-not-acquired-label = Disponible
+labgrid-place-acquired-by-label = Acquise par
+labgrid-place-release-label = Expulser
+labgrid-place-force-release-button = Forcer la libération
+labgrid-place-force-release-confirmation-msg = La place '{ $place }' est acquise par '{ $owner }'. Saisissez le nom de la place pour la libérer de cet utilisateur.
+labgrid-place-tags-label = Tags
+labgrid-place-created-label = Créée
+labgrid-place-changed-label = Modifiée
+labgrid-place-add-placeholder = Nom de la place
+labgrid-place-add-button = Ajouter
+labgrid-place-delete-button = Supprimer
+labgrid-place-delete-confirmation-msg = Voulez-vous vraiment supprimer la place '{$place}' ?
+labgrid-place-add-tag-placeholder = Nom du tag
+labgrid-place-add-tag-value-placeholder = Valeur du tag
+labgrid-place-add-tag-tooltip = Ajouter un tag
+labgrid-place-close-add-tag-tooltip = Fermer
+labgrid-place-details-header = Détails de la place '{$place}'
+labgrid-place-clone-button = Cloner
+labgrid-place-clone-tooltip = Créer une nouvelle place avec les tags, alias et correspondances de ressources de cette place
+labgrid-place-clone-msg = Créer une nouvelle place configurée comme '{$place}'. Son nom est remplacé par le nouveau nom dans les alias et les correspondances de ressources.
+labgrid-place-clone-aliases-label = Alias
+labgrid-place-clone-none-label = Aucun
+labgrid-place-clone-empty-name-msg = Le nom ne doit pas être vide
+labgrid-place-clone-name-taken-msg = Une place portant ce nom existe déjà
+labgrid-place-clone-alias-taken-msg = L'alias '{$alias}' est déjà utilisé
+labgrid-place-clone-invalid-pattern-msg = '{$pattern}' n'est pas un motif de correspondance de ressource valide
+place-activity-header = Activité
+place-activity-export-button = Exporter
+place-activity-export-failed = L'export de l'activité de la place a échoué
+export-button = Exporter…
+export-tooltip = Exporter les entrées affichées dans un fichier CSV ou JSON
+export-failed = L'export a échoué
+place-snapshot-export-button = Instantané…
+place-snapshot-export-tooltip = Enregistrer les noms, alias, commentaires, tags et correspondances de ressources de toutes les places dans un fichier JSON
+place-snapshot-restore-button = Restaurer…
+place-snapshot-restore-tooltip = Appliquer un instantané enregistré des places au coordinateur, avec un aperçu des modifications
+place-snapshot-import-failed = La lecture de l'instantané des places a échoué
+place-snapshot-restore-msg = La restauration de '{$file}' crée les places manquantes et ajoute les alias, tags et correspondances de ressources manquants. Rien n'est supprimé.
+place-snapshot-taken-msg = Instantané de {$coordinator} pris le {$created}
+place-snapshot-up-to-date-msg = Le coordinateur contient déjà tout le contenu de l'instantané
+place-snapshot-apply-button = Appliquer {$count} modifications
+place-snapshot-step-add-place = Créer la place '{$place}'
+place-snapshot-step-add-alias = Ajouter l'alias '{$alias}'
+place-snapshot-step-set-comment = Définir le commentaire à '{$comment}'
+place-snapshot-step-add-tag = Ajouter le tag {$key}={$value}
+place-snapshot-step-change-tag = Changer le tag {$key} de '{$current}' à '{$value}'
+place-snapshot-step-add-match = Ajouter la correspondance de ressources {$pattern}
+place-snapshot-compare-button = Comparer…
+place-snapshot-compare-tooltip = Comparer les places avec un instantané enregistré et appliquer des différences individuelles
+place-snapshot-compare-msg = Différences des places avec '{$file}'
+place-snapshot-no-differences-msg = Les places correspondent à l'instantané
+place-snapshot-close-button = Fermer
+place-snapshot-restore-all-button = Tout restaurer…
+place-snapshot-diff-apply-button = Appliquer
+place-snapshot-diff-remove-button = Supprimer
+place-snapshot-diff-missing-place = Place absente du coordinateur
+place-snapshot-diff-extra-place = Place absente de l'instantané
+place-snapshot-diff-missing-alias = Alias '{$alias}' manquant
+place-snapshot-diff-extra-alias = Alias '{$alias}' absent de l'instantané
+place-snapshot-diff-comment = Le commentaire est '{$current}' au lieu de '{$snapshot}'
+place-snapshot-diff-missing-tag = Tag {$key}={$value} manquant
+place-snapshot-diff-changed-tag = Le tag {$key} est '{$current}' au lieu de '{$snapshot}'
+place-snapshot-diff-extra-tag = Tag {$key}={$value} absent de l'instantané
+place-snapshot-diff-missing-match = Correspondance de ressources {$pattern} manquante
+place-snapshot-diff-extra-match = Correspondance de ressources {$pattern} absente de l'instantané
+place-activity-acquired = Acquise par {$user}
+place-activity-released = Libérée, était acquise par {$user}
+place-activity-match-added = Correspondance '{$pattern}' ajoutée
+place-activity-match-removed = Correspondance '{$pattern}' supprimée
+labgrid-place-details-acquired-notice = Cette place vient d'être acquise par {$user}
+labgrid-place-details-released-notice = Cette place vient d'être libérée par un autre client
+labgrid-place-details-modified-notice = Cette place vient d'être modifiée par un autre client
+labgrid-place-delete-tag-confirmation-msg = Voulez-vous vraiment supprimer le tag '{$tag}' de la place ?
+labgrid-place-resource-matches-header = Correspondances de ressources
+labgrid-place-aliases-header = Alias
+labgrid-place-alias-add-placeholder-text = Alias
+labgrid-place-alias-add-button = Ajouter
+labgrid-place-alias-delete-button = Supprimer
+labgrid-place-resource-acquired-header = Ressources acquises
+labgrid-place-acquired-resource-origin = {$exporter} / {$group} / {$cls}
+labgrid-place-acquired-resource-acquired-tooltip = Acquise à l'instant
+labgrid-place-acquired-resource-released-tooltip = Libérée à l'instant
+labgrid-place-resource-match-rename-placeholder-text = Renommer
+labgrid-place-resource-match-rename-tooltip = Nom facultatif que reçoivent les ressources correspondantes dans la place, p. ex. pour leur lier des drivers dans la configuration d'environnement
+labgrid-place-resource-match-renamed-tooltip = Les ressources correspondantes sont renommées avec ce nom
+labgrid-place-resource-match-builder-exporter = Exportateur
+labgrid-place-resource-match-builder-group = Groupe
+labgrid-place-resource-match-builder-cls = Classe
+labgrid-place-resource-match-builder-name = Nom (facultatif)
+labgrid-place-resource-match-builder-matching = {$count ->
+    [0] Ne correspond à aucune ressource connue
+    [one] Correspond à une ressource connue
+   *[other] Correspond à {$count} ressources connues
+}
+labgrid-place-resource-match-add-button = Ajouter
+labgrid-place-resource-match-delete-button = Supprimer
+labgrid-resources-label = Ressources
+labgrid-resources-only-show-available-checkbox = N'afficher que les disponibles
+labgrid-resources-exporter-filter-label = Exportateur : {$exporter}
+labgrid-resources-exporter-filter-clear-tooltip = Afficher les ressources de tous les exportateurs
+labgrid-resources-exporter-offline-label = Hors ligne depuis {$time}
+labgrid-resources-exporter-offline-tooltip = Le coordinateur a supprimé toutes les ressources de cet exportateur, il s'est probablement déconnecté
+labgrid-exporters-label = Exportateurs
+labgrid-exporters-empty = Aucun exportateur connu, les exportateurs sont déduits des ressources annoncées par le coordinateur.
+labgrid-exporter-resources-label = Ressources
+labgrid-exporter-available-label = Disponibles
+labgrid-exporter-available-value = {$available} sur {$total}
+labgrid-exporter-acquired-label = Acquises
+labgrid-exporter-stale-label = Supprimées
+labgrid-exporter-last-seen-label = Vu pour la dernière fois
+labgrid-exporter-last-seen-unknown = Pas depuis la connexion
+labgrid-exporter-offline-label = Hors ligne depuis
+labgrid-exporter-show-resources-button = Afficher les ressources
+labgrid-exporter-usb-topology-button = Topologie USB
+labgrid-resources-class-filter-placeholder = Toutes les classes
+labgrid-resources-search-placeholder = Rechercher exportateur, groupe, nom ou paramètres
+labgrid-resources-class-filter-clear-tooltip = Afficher les ressources de toutes les classes
+labgrid-classes-label = Classes de ressources
+labgrid-classes-empty = Aucune ressource connue.
+labgrid-class-resources-value = {$resources} ressources
+labgrid-class-available-value = {$available} sur {$total} disponibles ({$percent} %)
+labgrid-class-acquired-value = {$acquired} acquises
+labgrid-class-show-resources-button = Afficher les ressources
+
+dashboard-label = Tableau de bord
+dashboard-places-label = Places
+dashboard-acquired-label = Acquises
+dashboard-free-label = Libres
+dashboard-resources-available-label = Ressources disponibles
+dashboard-count-value = {$count} ({$percent} %)
+dashboard-place-states-label = Places par état
+dashboard-places-empty = Aucune place connue.
+dashboard-state-free = Libre
+dashboard-state-mine = Acquise par moi
+dashboard-state-others = Acquise par d'autres
+dashboard-state-reserved = Réservée
+dashboard-state-maintenance = En maintenance
+dashboard-acquisitions-label = Places acquises par utilisateur
+dashboard-acquisitions-empty = Aucune place n'est acquise.
+dashboard-class-availability-label = Disponibilité des ressources par classe
+dashboard-percent-value = {$percent} %
+dashboard-occupancy-label = Occupation par place
+dashboard-occupancy-disabled = L'utilisation des places n'est pas enregistrée, l'enregistrement peut être activé dans les paramètres.
+utilization-range-day = Dernier jour
+utilization-range-week = Dernière semaine
+labgrid-resources-no-exporter-name = Sans nom d'exportateur
+labgrid-resource-acquired-label = Acquise
+labgrid-resource-availability-tooltip = Disponibilité
+labgrid-resource-params-label = Paramètres
+labgrid-resource-extra-label = Extra
+labgrid-camera-preview-label = Aperçu
+labgrid-camera-preview-refresh-button = Actualiser
+labgrid-camera-preview-loading = Chargement de l'instantané ..
+labgrid-camera-preview-failed = La récupération de l'instantané a échoué : {$error}
+labgrid-camera-preview-no-url = La caméra n'a pas d'URL HTTP pour récupérer un instantané.
+labgrid-resource-stale-label = Hors ligne
+labgrid-resource-stale-tooltip = L'exportateur de cette ressource est hors ligne, elle sera supprimée après le délai de grâce
+labgrid-resource-acquire-history-label = Récemment acquise par
+labgrid-resource-acquire-record = {$acquired_by} à {$time}
+labgrid-resource-assign-tooltip = Attribuer à une place…
+labgrid-resource-assign-header = Attribuer la ressource à une place
+labgrid-resource-assign-pattern-label = Motif de correspondance
+labgrid-resource-assign-button = Attribuer
+labgrid-resource-class-tooltip = Classe de ressource {$cls}
+labgrid-resource-class-unknown-tooltip = La classe de ressource {$cls} est inconnue, cliquez pour configurer son icône et son action rapide
+labgrid-resource-copy-param-tooltip = Copier la valeur du paramètre '{$param}' dans le presse-papiers
+labgrid-resource-copy-command-tooltip = Copier la commande {$program} dans le presse-papiers
+labgrid-resource-launch-command-tooltip = Exécuter {$program} dans l'émulateur de terminal
+labgrid-resource-watch-tooltip = Surveiller, notifier lorsque la ressource devient disponible ou indisponible
+labgrid-resource-unwatch-tooltip = Arrêter la surveillance
+resource-class-header = Classe de ressource {$cls}
+resource-class-known-msg = Cette classe de ressource est connue, son icône peut être remplacée.
+resource-class-unknown-msg = Cette classe de ressource est inconnue, les paramètres ci-dessous ont été observés sur ses ressources. Associez-la à une icône et à un paramètre copié par le bouton d'action rapide.
+resource-class-params-label = Paramètres observés
+resource-class-no-params-msg = Aucun paramètre n'a été observé.
+resource-class-param-observed = {$kinds}, sur {$count} des {$total} ressources
+resource-class-icon-label = Icône
+resource-class-icon-placeholder = Pas d'icône
+resource-class-copy-param-label = Paramètre copié par le bouton d'action rapide
+resource-class-copy-param-placeholder = Pas d'action rapide
+resource-class-reset-button = Réinitialiser
+usb-topology-header = Topologie USB de {$exporter}
+usb-topology-empty = L'exportateur n'a annoncé aucun périphérique USB avec un bus connu.
+usb-topology-bus-label = Bus {$bus}
+usb-topology-port-label = Port {$port}
+usb-topology-unknown-port-label = Port inconnu
+usb-topology-device-value = Périphérique {$devnum}
+usb-topology-acquired-value = Acquise par {$place}
+quick-switcher-placeholder = Rechercher des places et des scripts
+quick-switcher-hint = ↑↓ sélectionner · Entrée ouvrir les détails de la place ou exécuter le script · Maj+Entrée acquérir la place · Échap fermer
+quick-switcher-place-label = Place
+quick-switcher-script-label = Script
+quick-switcher-no-matches-msg = Aucune place ni aucun script correspondant
+quick-switcher-command-label = Commande
+command-palette-placeholder = Rechercher des commandes, des places et des scripts
+command-palette-hint = ↑↓ sélectionner · Entrée exécuter la commande, ouvrir les détails de la place ou exécuter le script · Maj+Entrée acquérir la place · Échap fermer
+command-refresh = Actualiser
+command-show-tab = Afficher { $tab }
+command-create-reservation = Créer une réservation
+command-open-settings = Ouvrir les paramètres
+command-disconnect = Déconnecter
+resource-class-icon-board = Carte
+resource-class-icon-power = Alimentation
+resource-class-icon-serial = Console série
+resource-class-icon-network = Réseau
+resource-class-icon-usb = USB
+resource-class-icon-video = Vidéo
+resource-class-icon-storage = Stockage
+resource-class-icon-display = Écran
+resource-class-icon-relay = Relais / GPIO
+resource-class-icon-sensor = Capteur
+labgrid-reservations-label = Réservations
+labgrid-reservation-owner-label = Propriétaire
+labgrid-reservation-token-label = Jeton
+labgrid-reservation-prio-label = Priorité
+labgrid-reservation-filters-label = Filtres
+labgrid-reservation-created-label = Créée
+labgrid-reservation-expires-label = Expire
+labgrid-reservation-expires-in = dans {$remaining}
+labgrid-reservation-timed-out = Expirée
+labgrid-reservation-time-unset = -
+labgrid-reservation-allocations-label = Attribuée
+labgrid-reservation-goto-place-button = Aller à la place
+labgrid-reservation-queue-label = File d'attente
+labgrid-reservation-queue-position = Position {$position}
+labgrid-reservation-queue-free-places = {$free} des {$matching} places correspondantes libres
+labgrid-reservation-queue-expected-wait = Attente prévue ~{$minutes} min
+labgrid-reservation-queue-expected-wait-unknown = Attente prévue inconnue
+labgrid-reservation-cancel-label = Annuler
+labgrid-reservation-cancel-foreign-button = Annuler la réservation
+labgrid-reservation-cancel-foreign-confirmation-msg = La réservation '{ $token }' appartient à '{ $owner }'. Saisissez le nom du propriétaire pour l'annuler.
+labgrid-reservation-mine-badge = Ma réservation
+labgrid-reservations-show-foreign-checkbox = Afficher les réservations des autres
+labgrid-reservation-create-button = Créer une réservation
+labgrid-reservation-create-header = Créer une réservation
+labgrid-reservation-state-waiting = En attente
+labgrid-reservation-state-allocated = Attribuée
+labgrid-reservation-state-acquired = Acquise
+labgrid-reservation-state-expired = Expirée
+labgrid-reservation-state-invalid = Invalide
+labgrid-reservation-state-unknown = Inconnu
+labgrid-reservations-cleanup-button = Nettoyer
+labgrid-reservations-cleanup-tooltip = Annuler d'un coup les réservations expirées, invalides ou personnelles
+labgrid-reservations-cleanup-header = Nettoyer les réservations
+labgrid-reservations-cleanup-select-label = Sélectionner
+labgrid-reservations-cleanup-stale = Réservations expirées et invalides
+labgrid-reservations-cleanup-mine = Toutes mes réservations
+labgrid-reservations-cleanup-summary = {$count ->
+    [0] Aucune réservation n'est sélectionnée
+    [one] Une réservation sera annulée
+   *[other] {$count} réservations seront annulées
+}
+labgrid-reservations-cleanup-confirm-button = Annuler les réservations
+labgrid-reservation-filter-placeholder = Expression de filtre, p. ex. board=foo AND lab=muc
+labgrid-reservation-filter-empty-msg = Saisissez une expression de filtre pour sélectionner les places à réserver
+labgrid-reservation-filter-invalid-msg = Expression de filtre invalide : {$error}
+labgrid-reservation-filter-client-side-msg = Aperçu uniquement, l'expression ne peut pas être utilisée pour les réservations : {$reason}
+labgrid-reservation-filter-matches-label = {$count ->
+    [one] Une place correspondante
+   *[other] {$count} places correspondantes
+}
+
+scripts-label = Scripts
+scripts-none-found-msg = Aucun script trouvé
+scripts-dir-reset-tooltip = Réinitialiser le répertoire des scripts par défaut
+scripts-dir-pick-tooltip = Choisir un nouveau répertoire des scripts
+scripts-dir-rescan-tooltip = Analyser à nouveau le répertoire des scripts, les modifications sont généralement prises en compte automatiquement
+scripts-dir-rescan-failed-error = L'analyse du répertoire des scripts a échoué
+scripts-favorites-label = Favoris
+script-favorite-tooltip = Épingler le script en haut
+script-move-up-tooltip = Monter
+script-move-down-tooltip = Descendre
+script-display-name-tooltip = Changer le nom affiché
+script-display-name-msg = Nom affiché du script "{$script}", le nom du fichier est affiché s'il est vide
+script-display-name-placeholder = Nom affiché
+script-display-name-button = Enregistrer
+scripts-env-label = Environnement
+script-output-label = Sortie
+script-execute-button = Exécuter
+script-abort-button = Interrompre
+script-status-label = État
+script-status-none = Inactif
+script-status-running = En cours d'exécution
+script-status-cleaning-up = Nettoyage
+script-status-finished = Terminé avec le code de sortie '{$code}'
+script-status-timed-out = Délai dépassé après {$secs} s
+script-failed-msg = Le script a échoué
+script-cleanup-failed-msg = Le nettoyage du script a échoué
+script-validate-button = Valider
+script-validate-tooltip = Vérifier le script pour des erreurs de syntaxe et des imports labgrid défaillants, sans l'exécuter
+script-validation-label = Validation
+script-validation-running = Validation en cours
+script-validation-valid = Valide
+script-validation-invalid = Erreurs, voir la sortie
+script-validation-failed-msg = La validation du script a échoué
+script-not-found-msg = Le script '{ $script }' est introuvable dans le répertoire des scripts
+script-output-show-label = Afficher
+script-output-hide-label = Masquer
+script-output-clear-tooltip = Effacer la sortie du script
+reservation-run-label = Exécuter sur la prochaine carte disponible
+reservation-run-filter-placeholder = Expression de filtre, p. ex. board=imx8
+reservation-run-script-placeholder = Script
+reservation-run-button = Exécuter
+reservation-run-cancel-button = Annuler
+reservation-run-status-reserving = Réservation
+reservation-run-status-waiting = En attente d'une place
+reservation-run-status-acquiring = Acquisition de '{$place}'
+reservation-run-status-running = Exécution sur '{$place}'
+reservation-run-lost-msg = La réservation de l'exécution a expiré ou a été annulée
@@ -0,0 +1,620 @@
+app-title = Interfaccia utente Labgrid
+app-authors-label = Autori
+app-version-label = Versione
+app-quit-label = Esci
+connect-button = Connetti
+refresh-ui-tooltip = Aggiorna lo stato dell'interfaccia
+announcement-dismiss-tooltip = Nascondi l'annuncio
+availability-notice-dismiss-tooltip = Nascondi l'avviso
+resource-available-notice = La risorsa osservata '{$path}' è diventata disponibile alle {$time}
+resource-unavailable-notice = La risorsa osservata '{$path}' è diventata non disponibile alle {$time}
+protocol-warning-dismiss-tooltip = Nascondi l'avviso
+protocol-version-unknown = sconosciuta
+protocol-untested-msg = Il coordinatore esegue labgrid { $version }, che non è stato testato con questo client (protocollo di labgrid { $client_version }). Postazioni o risorse potrebbero mancare se il protocollo è cambiato.
+protocol-incompatible-msg = Il coordinatore esegue labgrid { $version }, che non è compatibile con questo client. È richiesto almeno labgrid { $min }.
+protocol-mismatch-msg = Non è stato possibile leggere i messaggi del coordinatore (labgrid { $version }), probabilmente usa un protocollo diverso da questo client (labgrid { $client_version }). Postazioni o risorse potrebbero mancare: { $error }
+disconnect-button = Disconnetti
+clipboard-copy-tooltip = Copia negli appunti
+clipboard-paste-tooltip = Incolla dagli appunti
+text-input-clear-tooltip = Cancella il testo
+time-format = %d/%m/%Y %H:%M:%S
+decimal-separator = ,
+time-ago-just-now = proprio ora
+time-ago-minutes = {$minutes} min fa
+time-ago-hours = {$hours} h fa
+time-ago-days = {$days ->
+    [one] {$days} giorno fa
+   *[other] {$days} giorni fa
+}
+connecting-msg = Connessione a '{$address}' ..
+connected-to-coordinator-label = Connesso al coordinatore '{$address}'
+connection-latency-label = {$ms} ms
+connection-latency-tooltip = Tempo di andata e ritorno dell'ultimo keepalive
+show-details-button = Mostra dettagli
+hide-details-button = Nascondi dettagli
+confirmation-modal-confirm-button = Conferma
+confirmation-modal-cancel-button = Annulla
+coordinator-address-placeholder = Indirizzo e porta del coordinatore, o URI
+venv-dir-reset-tooltip = Ripristina la directory venv predefinita
+error-critical = Errore critico
+error-retry-button = Riprova
+error-console-tooltip = Mostra tutti gli errori di questa sessione
+error-console-header = Errori
+error-console-empty = Non è stato segnalato alcun errore.
+error-console-filter-all = Tutti
+error-console-copy-button = Copia tutto
+error-console-clear-button = Cancella tutto
+error-noncritical = Errore non critico
+connection-msg-invalid-input = Impossibile eseguire l'azione di connessione, input non valido
+connection-msg-bulk-place-op-failed = Operazione non riuscita per {$count} postazioni
+connection-msg-cancel-reservations-failed = Annullamento non riuscito per {$count} prenotazioni
+connection-msg-restore-places-failed = Ripristino non riuscito per {$count} modifiche dello snapshot delle postazioni
+error-invalid-path = Il percorso indicato non è valido
+error-app-config-load = Caricamento della configurazione dell'applicazione non riuscito
+error-app-config-save = Salvataggio della configurazione dell'applicazione non riuscito
+error-utilization-record = Registrazione dell'utilizzo delle postazioni non riuscita
+error-app-config-reload = Ricaricamento della configurazione modificata dell'applicazione non riuscito
+error-app-config-open = Apertura del file di configurazione dell'applicazione non riuscita
+error-terminal-launch = Avvio di {$program} nell'emulatore di terminale non riuscito
+error-metrics-export = Esportazione delle metriche non riuscita
+error-rest-gateway = Servizio del gateway REST non riuscito
+error-clipboard-copy = Copia negli appunti non riuscita
+error-clipboard-paste = Incolla dagli appunti non riuscito
+error-connecting-failed = Connessione al coordinatore non riuscita
+error-connecting-timeout = Timeout raggiunto durante la connessione al coordinatore
+error-keepalive-timeout = Il coordinatore non ha risposto al keepalive, la connessione è considerata persa
+error-transport-failure = La connessione al coordinatore non è riuscita
+error-msg-conversion = Non è stato possibile convertire un messaggio del coordinatore
+error-grpc-status-unavailable = Il coordinatore non è disponibile ({$code})
+error-grpc-status = Il coordinatore ha segnalato un errore ({$code})
+
+file-dialog-filter-python-scripts-label = Script Python
+file-browser-pick-dir-header = Scegli directory
+file-browser-pick-file-header = Scegli file
+file-browser-listing-msg = Lettura della directory ..
+file-browser-list-failed-msg = Lettura della directory non riuscita: {$error}
+file-browser-empty-msg = La directory è vuota
+file-browser-up-tooltip = Directory superiore
+file-browser-pick-button = Scegli
+
+settings-button = Impostazioni
+profiles-button = Profili
+profiles-header = Profili di connessione
+profiles-empty = Ancora nessun profilo
+profile-default-name = Profilo
+profile-select-placeholder = Profilo
+profile-clear-tooltip = Connetti senza profilo
+profile-add-button = Aggiungi profilo
+profile-delete-button = Elimina profilo
+profile-name-label = Nome
+profile-address-label = Indirizzo del coordinatore
+profile-tls-label = Connetti tramite TLS
+profile-scope-label = Sottoscrizioni
+profile-places-only-mine-label = Mostra solo le mie postazioni
+profile-resources-only-available-label = Mostra solo le risorse disponibili
+subscription-scope-places-and-resources = Postazioni e risorse
+subscription-scope-places-only = Solo postazioni
+settings-header = Impostazioni
+settings-language-pick-label = Scegli la lingua
+settings-optimize-touch-label = Ottimizza l'interfaccia per touchscreen
+settings-ui-scale-label = Scala dell'interfaccia
+settings-ui-scale-value = {$percent} %
+settings-high-contrast-label = Usa colori ad alto contrasto
+settings-resource-grace-period-label = Mantieni le risorse degli exporter offline per
+settings-resource-grace-period-value = {$secs} s
+settings-offline-exporter-resources-label = Risorse degli exporter offline
+settings-offline-exporter-resources-show = Mostra
+settings-offline-exporter-resources-gray = Mostra in grigio
+settings-offline-exporter-resources-hide = Nascondi
+settings-bulk-ops-per-sec-label = Limite di frequenza delle azioni multiple sulle postazioni
+settings-bulk-ops-per-sec-value = {$ops} op/s
+settings-autosave-label = Salva le impostazioni periodicamente
+settings-autosave-value = {$secs ->
+    [0] Disattivato
+   *[other] Ogni {$secs} s
+}
+settings-last-save-label = Ultimo salvataggio alle {$time}
+settings-last-save-never = Non ancora salvato
+settings-save-button = Salva ora
+settings-saved-confirmation = Impostazioni salvate
+settings-timeout-preset-label = Timeout di connessione
+settings-timeout-preset-lan = Rete locale
+settings-timeout-preset-vpn = VPN / laboratorio remoto
+settings-timeout-preset-custom = Personalizzato
+settings-timeout-connect-label = Timeout per la connessione al coordinatore
+settings-timeout-stream-label = Timeout per l'avvio dello stream del client
+settings-timeout-rpc-label = Timeout delle chiamate al coordinatore
+settings-keepalive-label = Intervallo dei keepalive (0 li disattiva)
+settings-reservations-poll-label = Intervallo di aggiornamento delle prenotazioni
+settings-timeout-secs-value = {$secs} s
+settings-retry-transient-errors-label = Riprova le chiamate quando il coordinatore è temporaneamente non disponibile
+settings-auth-token-label = Token di autenticazione
+settings-auth-token-none = Nessuno
+settings-auth-token-static = Token fisso
+settings-auth-token-file = File del token
+settings-auth-token-command = Comando del token
+settings-auth-token-static-label = Token
+settings-auth-token-file-label = Percorso del file del token
+settings-auth-token-command-label = Comando che stampa il token
+settings-auth-token-refresh-label = Riesegui il comando del token dopo
+settings-auth-metadata-label = Metadati delle chiamate
+settings-auth-metadata-tooltip = Voci chiave=valore separate da virgole, inviate con ogni chiamata, applicate alla connessione
+settings-check-script-imports-label = Controlla gli import di labgrid durante la validazione degli script
+settings-scripts-max-depth-label = Profondità delle sottodirectory degli script analizzate
+settings-scripts-max-depth-value = {$depth ->
+    [0] Solo la directory degli script
+    [one] {$depth} livello
+   *[other] {$depth} livelli
+}
+settings-script-timeout-label = Termina gli script in esecuzione da più di
+settings-script-timeout-value = {$mins ->
+    [0] Nessun timeout
+   *[other] {$mins} min
+}
+settings-mqtt-label = Rispecchia lo stato di postazioni e risorse su un broker MQTT
+settings-mqtt-broker-label = Broker MQTT
+settings-mqtt-topic-prefix-label = Prefisso dei topic MQTT
+settings-mqtt-username-label = Nome utente MQTT
+settings-mqtt-password-label = Password MQTT
+settings-mqtt-apply-button = Applica
+settings-mqtt-apply-tooltip = Riavvia il bridge MQTT con le impostazioni modificate
+settings-mqtt-status-connecting = Connessione…
+settings-mqtt-status-connected = Connesso
+settings-mqtt-status-disconnected = Disconnesso: {$error}
+settings-persist-acquire-history-label = Conserva la cronologia di acquisizione delle risorse
+settings-record-utilization-label = Registra l'utilizzo delle postazioni per il cruscotto
+settings-card-color-free-label = Colore delle schede delle postazioni libere
+settings-card-color-mine-label = Colore delle schede delle postazioni acquisite da me
+settings-card-color-others-label = Colore delle schede delle postazioni acquisite da altri
+settings-card-color-reserved-label = Colore delle schede delle postazioni prenotate
+settings-card-color-maintenance-label = Colore delle schede delle postazioni in manutenzione
+settings-card-color-reset-tooltip = Ripristina il colore predefinito
+settings-identity-colors-label = Colora le acquisizioni per utente
+settings-identity-colors-tooltip = Le postazioni acquisite da altri e le risorse acquisite sono colorate con un colore derivato dal nome host/user del proprietario
+settings-notification-place-released-label = Notifica quando una postazione osservata viene rilasciata
+settings-notification-reservation-allocated-label = Notifica quando la mia prenotazione viene assegnata
+settings-notification-script-finished-label = Notifica quando uno script è terminato
+settings-notification-auto-release-warning-label = Notifica prima che una postazione inattiva venga rilasciata automaticamente
+settings-notification-resource-availability-label = Notifica quando una risorsa osservata diventa disponibile o non disponibile
+settings-quick-action-acquire-label = Mostra il pulsante acquisisci/rilascia sulle schede delle postazioni
+settings-quick-action-power-cycle-label = Mostra il pulsante di riavvio dell'alimentazione sulle schede delle postazioni
+settings-quick-action-console-label = Mostra il pulsante console sulle schede delle postazioni
+settings-quick-action-favorite-script-label = Mostra il pulsante dello script preferito sulle schede delle postazioni
+settings-quick-action-favorite-script-pick-label = Script preferito
+settings-quick-action-favorite-script-placeholder = Scegli uno script
+settings-identity-label = Identità labgrid
+settings-identity-hostname-label = Nome host (sostituisce LG_HOSTNAME)
+settings-identity-username-label = Nome utente (sostituisce LG_USERNAME)
+settings-terminal-command-label = Emulatore di terminale (ad es. 'x-terminal-emulator -e')
+settings-auto-release-label = Rilascia le postazioni inattive acquisite tramite l'interfaccia
+settings-auto-release-idle-label = Rilascia dopo un'inattività di
+settings-auto-release-warn-label = Avvisa prima del rilascio
+settings-release-on-exit-label = Rilascia all'uscita le postazioni acquisite tramite l'interfaccia
+settings-auto-release-minutes-value = {$minutes} min
+settings-identity-reconnect-button = Riconnetti
+settings-identity-reconnect-tooltip = Attualmente connesso come '{ $identity }', riconnettiti per applicare l'identità modificata
+notification-place-released-summary = Postazione rilasciata
+notification-place-released-body = La postazione osservata '{$place}' è stata rilasciata
+notification-resource-available-summary = Risorsa disponibile
+notification-resource-available-body = La risorsa osservata '{$path}' è diventata disponibile
+notification-resource-unavailable-summary = Risorsa non disponibile
+notification-resource-unavailable-body = La risorsa osservata '{$path}' è diventata non disponibile
+notification-reservation-allocated-summary = Prenotazione assegnata
+notification-reservation-allocated-body = La tua prenotazione '{$token}' è stata assegnata
+notification-script-finished-summary = Script terminato
+notification-script-finished-body = Lo script '{$script}' è terminato con il codice di uscita {$code}
+notification-script-timed-out-body = Lo script '{$script}' ha superato il timeout dopo {$secs} s ed è stato terminato
+notification-auto-release-warning-summary = Postazione in procinto di essere rilasciata
+notification-auto-release-warning-body = La postazione inattiva '{$place}' sarà rilasciata tra {$minutes} min
+settings-venv-dir-label = Cambia la directory dell'ambiente virtuale per gli script
+settings-venv-dir-pick-tooltip = Scegli una nuova directory venv
+settings-venv-create-tooltip = Crea un ambiente virtuale con labgrid
+settings-venv-versions-label = Versioni rilevate
+settings-venv-versions-detecting = Rilevamento…
+settings-venv-versions-value = Python { $python }, labgrid { $labgrid }
+settings-venv-versions-no-labgrid = Python { $python }, labgrid non è installato
+settings-venv-versions-failed = Rilevamento non riuscito
+settings-venv-versions-error = Non è stato possibile eseguire l'interprete python dell'ambiente virtuale: { $error }
+settings-venv-labgrid-missing-msg = labgrid non è installato nell'ambiente virtuale, gli script che lo usano non riusciranno.
+settings-venv-incompatible-msg = labgrid { $labgrid } non può comunicare con il coordinatore gRPC, gli script richiedono almeno labgrid { $min }.
+settings-log-viewer-label = Log diagnostico
+settings-log-viewer-button = Mostra log
+settings-config-file-label = File di configurazione
+settings-config-file-open-tooltip = Apri con l'applicazione predefinita, le modifiche su disco vengono applicate subito
+log-viewer-header = Log diagnostico
+log-viewer-level-label = Livello
+log-viewer-msg = Mostra gli eventi recenti dell'applicazione. Gli eventi vengono registrati a partire dal livello selezionato, indipendentemente da RUST_LOG.
+log-viewer-empty = Nessun evento registrato.
+log-viewer-clear-button = Cancella
+log-viewer-copy-button = Copia negli appunti
+protocol-inspector-header = Ispettore del protocollo
+protocol-inspector-record-label = Registra
+protocol-inspector-msg = Mostra i messaggi protobuf scambiati con il coordinatore durante la registrazione. { $recorded } messaggi sono registrati, i { $shown } più recenti corrispondenti al filtro vengono mostrati.
+protocol-inspector-filter-placeholder = Filtra per tipo o contenuto del messaggio
+protocol-inspector-empty = Nessun messaggio registrato.
+protocol-inspector-clear-button = Svuota
+protocol-inspector-copy-button = Copia negli appunti
+protocol-inspector-copy-tooltip = Copia tutti i messaggi registrati, anche quelli non mostrati
+venv-wizard-header = Crea ambiente virtuale
+venv-wizard-msg = Crea un ambiente virtuale python con labgrid installato, nel quale vengono eseguiti gli script. Richiede python3 con il modulo venv e l'accesso all'indice dei pacchetti python.
+venv-wizard-dir-label = Directory
+venv-wizard-labgrid-version-label = Versione di labgrid
+venv-wizard-labgrid-version-placeholder = Più recente
+venv-wizard-invalid-dir-msg = La directory deve essere un percorso assoluto
+venv-wizard-dir-not-empty-msg = La directory esiste già e non è vuota
+venv-wizard-invalid-version-msg = La versione non è un numero di versione valido, ad es. 24.0.1
+venv-wizard-step-create = Crea l'ambiente virtuale
+venv-wizard-step-install = Installa labgrid
+venv-wizard-step-validate = Valida l'ambiente virtuale
+venv-wizard-done-msg = L'ambiente virtuale è stato creato con labgrid {$version}
+venv-wizard-create-button = Crea
+venv-wizard-use-button = Usa per gli script
+
+lang-de-ch = "Tedesco svizzero"
+lang-de-de = "Tedesco"
+lang-en-us = "Inglese americano"
+lang-es-es = "Spagnolo"
+lang-fr-fr = "Francese"
+lang-it-it = "Italiano"
+lang-zh-cn = "Cinese semplificato"
+
+labgrid-places-label = Postazioni
+labgrid-places-show-resources-panel-button = Mostra risorse
+labgrid-places-group-by-none = Nessun raggruppamento
+labgrid-places-group-by-tag = Raggruppa per tag: {$key}
+labgrid-places-group-untagged = Senza tag '{$key}'
+labgrid-places-select-mode-button = Seleziona
+labgrid-places-exit-select-mode-button = Fine selezione
+labgrid-places-selected-label = {$count ->
+    [one] {$count} selezionata
+   *[other] {$count} selezionate
+}
+labgrid-places-select-all-button = Seleziona tutto
+labgrid-places-clear-selection-button = Annulla selezione
+labgrid-places-bulk-set-tag-button = Imposta tag
+labgrid-places-bulk-progress-label = {$done} / {$total} postazioni
+labgrid-places-bulk-pause-button = Pausa
+labgrid-places-bulk-resume-button = Riprendi
+labgrid-places-bulk-cancel-button = Annulla
+labgrid-places-bulk-delete-confirmation-msg = Eliminare davvero {$count} postazioni?
+labgrid-place-select-checkbox = Selezionata
+labgrid-place-mine-badge = Acquisita da me
+labgrid-place-mine-tooltip = La postazione è acquisita dall'identità di questo client
+labgrid-place-session-label = Sessione
+labgrid-place-session-remaining = Rilasciata tra {$remaining}
+labgrid-place-session-extend-tooltip = Prolunga la sessione, riavviando il tempo di inattività
+labgrid-place-session-keep-tooltip = Mantieni la postazione, non verrà rilasciata automaticamente
+labgrid-place-resources-label = Risorse
+labgrid-place-resources-available = {$available}/{$total} disponibili
+labgrid-place-resources-tooltip = Risorse disponibili corrispondenti alla postazione, le corrispondenze senza risorsa contano come mancanti
+labgrid-places-only-mine-checkbox = Solo le mie postazioni
+labgrid-place-power-cycle-tooltip = Riavvia l'alimentazione della postazione (deve essere acquisita da me)
+labgrid-place-power-cycle-failed-msg = Riavvio dell'alimentazione della postazione '{ $place }' non riuscito
+labgrid-place-console-tooltip = Copia il comando per connettersi alla console
+labgrid-place-favorite-script-tooltip = Esegui lo script preferito su questa postazione
+labgrid-place-watch-tooltip = Osserva, notifica quando la postazione viene rilasciata
+labgrid-place-unwatch-tooltip = Smetti di osservare
+labgrid-places-hide-resources-panel-button = Nascondi risorse
+labgrid-places-resources-panel-hint = Trascina le risorse sulle postazioni per aggiungere corrispondenze
+labgrid-places-resources-panel-dragging-msg = Rilascia '{$pattern}' su una postazione
+labgrid-place-drop-resource-confirmation-msg = Aggiungere la corrispondenza '{$pattern}' alla postazione '{$place}'?
+labgrid-place-name-label = Nome
+labgrid-place-comment-label = Commento
+labgrid-place-comment-placeholder = Commento
+labgrid-place-comment-edit-tooltip = Modifica commento
+labgrid-place-comment-save-button = Salva
+labgrid-place-comment-cancel-button = Annulla
+labgrid-place-acquire-button = Acquisisci
+labgrid-place-acquire-with-reason-tooltip = Acquisisci con un motivo mostrato agli altri utenti
+labgrid-place-acquire-with-reason-msg = Perché acquisisci la postazione '{$place}'? Il motivo viene mostrato agli altri utenti fino al rilascio della postazione.
+labgrid-place-acquire-reason-placeholder = ad es. bisezione di un problema di avvio
+labgrid-place-acquire-reason-label = Motivo
+labgrid-place-not-acquired-label = Disponibile
+labgrid-place-acquired-by-label = Acquisita da
+labgrid-place-release-label = Espelli
+labgrid-place-force-release-button = Forza rilascio
+labgrid-place-force-release-confirmation-msg = La postazione '{ $place }' è acquisita da '{ $owner }'. Digita il nome della postazione per rilasciarla da questo utente.
+labgrid-place-tags-label = Tag
+labgrid-place-created-label = Creata
+labgrid-place-changed-label = Modificata
+labgrid-place-add-placeholder = Nome della postazione
+labgrid-place-add-button = Aggiungi
+labgrid-place-delete-button = Elimina
+labgrid-place-delete-confirmation-msg = Eliminare davvero la postazione '{$place}'?
+labgrid-place-add-tag-placeholder = Nome del tag
+labgrid-place-add-tag-value-placeholder = Valore del tag
+labgrid-place-add-tag-tooltip = Aggiungi tag
+labgrid-place-close-add-tag-tooltip = Chiudi
+labgrid-place-details-header = Dettagli della postazione '{$place}'
+labgrid-place-clone-button = Clona
+labgrid-place-clone-tooltip = Crea una nuova postazione con i tag, gli alias e le corrispondenze di risorse di questa postazione
+labgrid-place-clone-msg = Crea una nuova postazione configurata come '{$place}'. Il suo nome viene sostituito con il nuovo nome negli alias e nelle corrispondenze di risorse.
+labgrid-place-clone-aliases-label = Alias
+labgrid-place-clone-none-label = Nessuno
+labgrid-place-clone-empty-name-msg = Il nome non deve essere vuoto
+labgrid-place-clone-name-taken-msg = Esiste già una postazione con questo nome
+labgrid-place-clone-alias-taken-msg = L'alias '{$alias}' è già in uso
+labgrid-place-clone-invalid-pattern-msg = '{$pattern}' non è un pattern di corrispondenza di risorse valido
+place-activity-header = Attività
+place-activity-export-button = Esporta
+place-activity-export-failed = Esportazione dell'attività della postazione non riuscita
+export-button = Esporta…
+export-tooltip = Esporta le voci visualizzate in un file CSV o JSON
+export-failed = Esportazione non riuscita
+place-snapshot-export-button = Snapshot…
+place-snapshot-export-tooltip = Salva nomi, alias, commenti, tag e corrispondenze delle risorse di tutte le postazioni in un file JSON
+place-snapshot-restore-button = Ripristina…
+place-snapshot-restore-tooltip = Applica al coordinatore uno snapshot salvato delle postazioni, con un'anteprima delle modifiche
+place-snapshot-import-failed = Lettura dello snapshot delle postazioni non riuscita
+place-snapshot-restore-msg = Il ripristino di '{$file}' crea le postazioni mancanti e aggiunge gli alias, i tag e le corrispondenze delle risorse mancanti. Non viene eliminato nulla.
+place-snapshot-taken-msg = Snapshot di {$coordinator} creato il {$created}
+place-snapshot-up-to-date-msg = Il coordinatore contiene già tutto il contenuto dello snapshot
+place-snapshot-apply-button = Applica {$count} modifiche
+place-snapshot-step-add-place = Crea la postazione '{$place}'
+place-snapshot-step-add-alias = Aggiungi l'alias '{$alias}'
+place-snapshot-step-set-comment = Imposta il commento a '{$comment}'
+place-snapshot-step-add-tag = Aggiungi il tag {$key}={$value}
+place-snapshot-step-change-tag = Cambia il tag {$key} da '{$current}' a '{$value}'
+place-snapshot-step-add-match = Aggiungi la corrispondenza delle risorse {$pattern}
+place-snapshot-compare-button = Confronta…
+place-snapshot-compare-tooltip = Confronta le postazioni con uno snapshot salvato e applica singole differenze
+place-snapshot-compare-msg = Differenze delle postazioni con '{$file}'
+place-snapshot-no-differences-msg = Le postazioni corrispondono allo snapshot
+place-snapshot-close-button = Chiudi
+place-snapshot-restore-all-button = Ripristina tutto…
+place-snapshot-diff-apply-button = Applica
+place-snapshot-diff-remove-button = Rimuovi
+place-snapshot-diff-missing-place = Postazione mancante sul coordinatore
+place-snapshot-diff-extra-place = Postazione non presente nello snapshot
+place-snapshot-diff-missing-alias = Alias '{$alias}' mancante
+place-snapshot-diff-extra-alias = Alias '{$alias}' non presente nello snapshot
+place-snapshot-diff-comment = Il commento è '{$current}' invece di '{$snapshot}'
+place-snapshot-diff-missing-tag = Tag {$key}={$value} mancante
+place-snapshot-diff-changed-tag = Il tag {$key} è '{$current}' invece di '{$snapshot}'
+place-snapshot-diff-extra-tag = Tag {$key}={$value} non presente nello snapshot
+place-snapshot-diff-missing-match = Corrispondenza delle risorse {$pattern} mancante
+place-snapshot-diff-extra-match = Corrispondenza delle risorse {$pattern} non presente nello snapshot
+place-activity-acquired = Acquisita da {$user}
+place-activity-released = Rilasciata, era acquisita da {$user}
+place-activity-match-added = Corrispondenza '{$pattern}' aggiunta
+place-activity-match-removed = Corrispondenza '{$pattern}' rimossa
+labgrid-place-details-acquired-notice = Questa postazione è appena stata acquisita da {$user}
+labgrid-place-details-released-notice = Questa postazione è appena stata rilasciata da un altro client
+labgrid-place-details-modified-notice = Questa postazione è appena stata modificata da un altro client
+labgrid-place-delete-tag-confirmation-msg = Eliminare davvero il tag '{$tag}' della postazione?
+labgrid-place-resource-matches-header = Corrispondenze di risorse
+labgrid-place-aliases-header = Alias
+labgrid-place-alias-add-placeholder-text = Alias
+labgrid-place-alias-add-button = Aggiungi
+labgrid-place-alias-delete-button = Elimina
+labgrid-place-resource-acquired-header = Risorse acquisite
+labgrid-place-acquired-resource-origin = {$exporter} / {$group} / {$cls}
+labgrid-place-acquired-resource-acquired-tooltip = Appena acquisita
+labgrid-place-acquired-resource-released-tooltip = Appena rilasciata
+labgrid-place-resource-match-rename-placeholder-text = Rinomina
+labgrid-place-resource-match-rename-tooltip = Nome facoltativo che le risorse corrispondenti ricevono nella postazione, ad es. per associarvi dei driver nella configurazione dell'ambiente
+labgrid-place-resource-match-renamed-tooltip = Le risorse corrispondenti vengono rinominate con questo nome
+labgrid-place-resource-match-builder-exporter = Exporter
+labgrid-place-resource-match-builder-group = Gruppo
+labgrid-place-resource-match-builder-cls = Classe
+labgrid-place-resource-match-builder-name = Nome (facoltativo)
+labgrid-place-resource-match-builder-matching = {$count ->
+    [0] Non corrisponde ad alcuna risorsa nota
+    [one] Corrisponde a una risorsa nota
+   *[other] Corrisponde a {$count} risorse note
+}
+labgrid-place-resource-match-add-button = Aggiungi
+labgrid-place-resource-match-delete-button = Elimina
+labgrid-resources-label = Risorse
+labgrid-resources-only-show-available-checkbox = Mostra solo disponibili
+labgrid-resources-exporter-filter-label = Exporter: {$exporter}
+labgrid-resources-exporter-filter-clear-tooltip = Mostra le risorse di tutti gli exporter
+labgrid-resources-exporter-offline-label = Offline dalle {$time}
+labgrid-resources-exporter-offline-tooltip = Il coordinatore ha rimosso tutte le risorse di questo exporter, probabilmente si è disconnesso
+labgrid-exporters-label = Exporter
+labgrid-exporters-empty = Nessun exporter noto, gli exporter vengono dedotti dalle risorse annunciate dal coordinatore.
+labgrid-exporter-resources-label = Risorse
+labgrid-exporter-available-label = Disponibili
+labgrid-exporter-available-value = {$available} di {$total}
+labgrid-exporter-acquired-label = Acquisite
+labgrid-exporter-stale-label = Rimosse
+labgrid-exporter-last-seen-label = Ultima volta visto
+labgrid-exporter-last-seen-unknown = Non dalla connessione
+labgrid-exporter-offline-label = Offline dalle
+labgrid-exporter-show-resources-button = Mostra risorse
+labgrid-exporter-usb-topology-button = Topologia USB
+labgrid-resources-class-filter-placeholder = Tutte le classi
+labgrid-resources-search-placeholder = Cerca exporter, gruppo, nome o parametri
+labgrid-resources-class-filter-clear-tooltip = Mostra le risorse di tutte le classi
+labgrid-classes-label = Classi di risorse
+labgrid-classes-empty = Nessuna risorsa nota.
+labgrid-class-resources-value = {$resources} risorse
+labgrid-class-available-value = {$available} di {$total} disponibili ({$percent} %)
+labgrid-class-acquired-value = {$acquired} acquisite
+labgrid-class-show-resources-button = Mostra risorse
+
+dashboard-label = Cruscotto
+dashboard-places-label = Postazioni
+dashboard-acquired-label = Acquisite
+dashboard-free-label = Libere
+dashboard-resources-available-label = Risorse disponibili
+dashboard-count-value = {$count} ({$percent} %)
+dashboard-place-states-label = Postazioni per stato
+dashboard-places-empty = Nessuna postazione conosciuta.
+dashboard-state-free = Libera
+dashboard-state-mine = Acquisita da me
+dashboard-state-others = Acquisita da altri
+dashboard-state-reserved = Prenotata
+dashboard-state-maintenance = In manutenzione
+dashboard-acquisitions-label = Postazioni acquisite per utente
+dashboard-acquisitions-empty = Nessuna postazione è acquisita.
+dashboard-class-availability-label = Disponibilità delle risorse per classe
+dashboard-percent-value = {$percent} %
+dashboard-occupancy-label = Occupazione per postazione
+dashboard-occupancy-disabled = L'utilizzo delle postazioni non viene registrato, la registrazione può essere attivata nelle impostazioni.
+utilization-range-day = Ultimo giorno
+utilization-range-week = Ultima settimana
+labgrid-resources-no-exporter-name = Senza nome dell'exporter
+labgrid-resource-acquired-label = Acquisita
+labgrid-resource-availability-tooltip = Disponibilità
+labgrid-resource-params-label = Parametri
+labgrid-resource-extra-label = Extra
+labgrid-camera-preview-label = Anteprima
+labgrid-camera-preview-refresh-button = Aggiorna
+labgrid-camera-preview-loading = Caricamento dell'istantanea ..
+labgrid-camera-preview-failed = Recupero dell'istantanea non riuscito: {$error}
+labgrid-camera-preview-no-url = La telecamera non ha un URL HTTP da cui recuperare un'istantanea.
+labgrid-resource-stale-label = Offline
+labgrid-resource-stale-tooltip = L'exporter di questa risorsa è andato offline, verrà rimossa dopo il periodo di tolleranza
+labgrid-resource-acquire-history-label = Acquisita di recente da
+labgrid-resource-acquire-record = {$acquired_by} alle {$time}
+labgrid-resource-assign-tooltip = Assegna a una postazione…
+labgrid-resource-assign-header = Assegna la risorsa a una postazione
+labgrid-resource-assign-pattern-label = Pattern di corrispondenza
+labgrid-resource-assign-button = Assegna
+labgrid-resource-class-tooltip = Classe di risorsa {$cls}
+labgrid-resource-class-unknown-tooltip = La classe di risorsa {$cls} è sconosciuta, clicca per configurarne l'icona e l'azione rapida
+labgrid-resource-copy-param-tooltip = Copia negli appunti il valore del parametro '{$param}'
+labgrid-resource-copy-command-tooltip = Copia negli appunti il comando {$program}
+labgrid-resource-launch-command-tooltip = Esegui {$program} nell'emulatore di terminale
+labgrid-resource-watch-tooltip = Osserva, notifica quando la risorsa diventa disponibile o non disponibile
+labgrid-resource-unwatch-tooltip = Smetti di osservare
+resource-class-header = Classe di risorsa {$cls}
+resource-class-known-msg = Questa classe di risorsa è nota, la sua icona può essere sostituita.
+resource-class-unknown-msg = Questa classe di risorsa è sconosciuta, i parametri seguenti sono stati osservati sulle sue risorse. Associala a un'icona e a un parametro copiato dal pulsante di azione rapida.
+resource-class-params-label = Parametri osservati
+resource-class-no-params-msg = Nessun parametro è stato osservato.
+resource-class-param-observed = {$kinds}, su {$count} di {$total} risorse
+resource-class-icon-label = Icona
+resource-class-icon-placeholder = Nessuna icona
+resource-class-copy-param-label = Parametro copiato dal pulsante di azione rapida
+resource-class-copy-param-placeholder = Nessuna azione rapida
+resource-class-reset-button = Ripristina
+usb-topology-header = Topologia USB di {$exporter}
+usb-topology-empty = L'exporter non ha annunciato dispositivi USB con un bus noto.
+usb-topology-bus-label = Bus {$bus}
+usb-topology-port-label = Porta {$port}
+usb-topology-unknown-port-label = Porta sconosciuta
+usb-topology-device-value = Dispositivo {$devnum}
+usb-topology-acquired-value = Acquisita da {$place}
+quick-switcher-placeholder = Cerca postazioni e script
+quick-switcher-hint = ↑↓ seleziona · Invio apri i dettagli della postazione o esegui lo script · Maiusc+Invio acquisisci la postazione · Esc chiudi
+quick-switcher-place-label = Postazione
+quick-switcher-script-label = Script
+quick-switcher-no-matches-msg = Nessuna postazione o script corrispondente
+quick-switcher-command-label = Comando
+command-palette-placeholder = Cerca comandi, postazioni e script
+command-palette-hint = ↑↓ seleziona · Invio esegui il comando, apri i dettagli della postazione o esegui lo script · Maiusc+Invio acquisisci la postazione · Esc chiudi
+command-refresh = Aggiorna
+command-show-tab = Mostra { $tab }
+command-create-reservation = Crea prenotazione
+command-open-settings = Apri impostazioni
+command-disconnect = Disconnetti
+resource-class-icon-board = Scheda
+resource-class-icon-power = Alimentazione
+resource-class-icon-serial = Console seriale
+resource-class-icon-network = Rete
+resource-class-icon-usb = USB
+resource-class-icon-video = Video
+resource-class-icon-storage = Archiviazione
+resource-class-icon-display = Display
+resource-class-icon-relay = Relè / GPIO
+resource-class-icon-sensor = Sensore
+labgrid-reservations-label = Prenotazioni
+labgrid-reservation-owner-label = Proprietario
+labgrid-reservation-token-label = Token
+labgrid-reservation-prio-label = Priorità
+labgrid-reservation-filters-label = Filtri
+labgrid-reservation-created-label = Creata
+labgrid-reservation-expires-label = Scade
+labgrid-reservation-expires-in = tra {$remaining}
+labgrid-reservation-timed-out = Scaduta
+labgrid-reservation-time-unset = -
+labgrid-reservation-allocations-label = Assegnata
+labgrid-reservation-goto-place-button = Vai alla postazione
+labgrid-reservation-queue-label = Coda
+labgrid-reservation-queue-position = Posizione {$position}
+labgrid-reservation-queue-free-places = {$free} di {$matching} postazioni corrispondenti libere
+labgrid-reservation-queue-expected-wait = Attesa prevista ~{$minutes} min
+labgrid-reservation-queue-expected-wait-unknown = Attesa prevista sconosciuta
+labgrid-reservation-cancel-label = Annulla
+labgrid-reservation-cancel-foreign-button = Annulla prenotazione
+labgrid-reservation-cancel-foreign-confirmation-msg = La prenotazione '{ $token }' appartiene a '{ $owner }'. Digita il nome del proprietario per annullarla.
+labgrid-reservation-mine-badge = La mia prenotazione
+labgrid-reservations-show-foreign-checkbox = Mostra le prenotazioni degli altri
+labgrid-reservation-create-button = Crea prenotazione
+labgrid-reservation-create-header = Crea prenotazione
+labgrid-reservation-state-waiting = In attesa
+labgrid-reservation-state-allocated = Assegnata
+labgrid-reservation-state-acquired = Acquisita
+labgrid-reservation-state-expired = Scaduta
+labgrid-reservation-state-invalid = Non valida
+labgrid-reservation-state-unknown = Sconosciuto
+labgrid-reservations-cleanup-button = Pulisci
+labgrid-reservations-cleanup-tooltip = Annulla in una volta le prenotazioni scadute, non valide o proprie
+labgrid-reservations-cleanup-header = Pulisci prenotazioni
+labgrid-reservations-cleanup-select-label = Seleziona
+labgrid-reservations-cleanup-stale = Prenotazioni scadute e non valide
+labgrid-reservations-cleanup-mine = Tutte le mie prenotazioni
+labgrid-reservations-cleanup-summary = {$count ->
+    [0] Nessuna prenotazione selezionata
+    [one] Una prenotazione verrà annullata
+   *[other] {$count} prenotazioni verranno annullate
+}
+labgrid-reservations-cleanup-confirm-button = Annulla prenotazioni
+labgrid-reservation-filter-placeholder = Espressione di filtro, ad es. board=foo AND lab=muc
+labgrid-reservation-filter-empty-msg = Inserisci un'espressione di filtro per selezionare le postazioni da prenotare
+labgrid-reservation-filter-invalid-msg = Espressione di filtro non valida: {$error}
+labgrid-reservation-filter-client-side-msg = Solo anteprima, l'espressione non può essere usata per le prenotazioni: {$reason}
+labgrid-reservation-filter-matches-label = {$count ->
+    [one] Una postazione corrispondente
+   *[other] {$count} postazioni corrispondenti
+}
+
+scripts-label = Script
+scripts-none-found-msg = Nessuno script trovato
+scripts-dir-reset-tooltip = Ripristina la directory degli script predefinita
+scripts-dir-pick-tooltip = Scegli una nuova directory degli script
+scripts-dir-rescan-tooltip = Analizza di nuovo la directory degli script, di solito le modifiche vengono rilevate automaticamente
+scripts-dir-rescan-failed-error = Analisi della directory degli script non riuscita
+scripts-favorites-label = Preferiti
+script-favorite-tooltip = Fissa lo script in alto
+script-move-up-tooltip = Sposta su
+script-move-down-tooltip = Sposta giù
+script-display-name-tooltip = Cambia il nome visualizzato
+script-display-name-msg = Nome visualizzato dello script "{$script}", se è vuoto viene visualizzato il nome del file
+script-display-name-placeholder = Nome visualizzato
+script-display-name-button = Salva
+scripts-env-label = Ambiente
+script-output-label = Output
+script-execute-button = Esegui
+script-abort-button = Interrompi
+script-status-label = Stato
+script-status-none = Inattivo
+script-status-running = In esecuzione
+script-status-cleaning-up = Pulizia in corso
+script-status-finished = Terminato con codice di uscita '{$code}'
+script-status-timed-out = Timeout dopo {$secs} s
+script-failed-msg = Script non riuscito
+script-cleanup-failed-msg = Pulizia dello script non riuscita
+script-validate-button = Valida
+script-validate-tooltip = Controlla lo script per errori di sintassi e import di labgrid non riusciti, senza eseguirlo
+script-validation-label = Validazione
+script-validation-running = Validazione in corso
+script-validation-valid = Valido
+script-validation-invalid = Errori, vedi l'output
+script-validation-failed-msg = Validazione dello script non riuscita
+script-not-found-msg = Script '{ $script }' non trovato nella directory degli script
+script-output-show-label = Mostra
+script-output-hide-label = Nascondi
+script-output-clear-tooltip = Cancella l'output dello script
+reservation-run-label = Esegui sulla prossima scheda disponibile
+reservation-run-filter-placeholder = Espressione di filtro, ad es. board=imx8
+reservation-run-script-placeholder = Script
+reservation-run-button = Esegui
+reservation-run-cancel-button = Annulla
+reservation-run-status-reserving = Prenotazione in corso
+reservation-run-status-waiting = In attesa di una postazione
+reservation-run-status-acquiring = Acquisizione di '{$place}'
+reservation-run-status-running = In esecuzione su '{$place}'
+reservation-run-lost-msg = La prenotazione dell'esecuzione è scaduta o è stata annullata
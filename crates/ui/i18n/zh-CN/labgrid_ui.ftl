@@ -0,0 +1,608 @@
+app-title = Labgrid 用户界面
+app-authors-label = 作者
+app-version-label = 版本
+app-quit-label = 退出
+connect-button = 连接
+refresh-ui-tooltip = 刷新界面状态
+announcement-dismiss-tooltip = 关闭公告
+availability-notice-dismiss-tooltip = 关闭提示
+resource-available-notice = 关注的资源 '{$path}' 已于 {$time} 变为可用
+resource-unavailable-notice = 关注的资源 '{$path}' 已于 {$time} 变为不可用
+protocol-warning-dismiss-tooltip = 关闭警告
+protocol-version-unknown = 未知
+protocol-untested-msg = 协调器运行的是 labgrid { $version }，未与此客户端（labgrid { $client_version } 的协议）一起测试过。如果协议发生了变化，可能会缺少位置或资源。
+protocol-incompatible-msg = 协调器运行的是 labgrid { $version }，与此客户端不兼容。至少需要 labgrid { $min }。
+protocol-mismatch-msg = 无法读取协调器（labgrid { $version }）的消息，它可能使用了与此客户端（labgrid { $client_version }）不同的协议。可能会缺少位置或资源：{ $error }
+disconnect-button = 断开连接
+clipboard-copy-tooltip = 复制到剪贴板
+clipboard-paste-tooltip = 从剪贴板粘贴
+text-input-clear-tooltip = 清除文本
+time-format = %Y-%m-%d %H:%M:%S
+decimal-separator = .
+time-ago-just-now = 刚刚
+time-ago-minutes = {$minutes} 分钟前
+time-ago-hours = {$hours} 小时前
+time-ago-days = {$days} 天前
+connecting-msg = 正在连接到 '{$address}' ..
+connected-to-coordinator-label = 已连接到协调器 '{$address}'
+connection-latency-label = {$ms} 毫秒
+connection-latency-tooltip = 上一次 keepalive 的往返时间
+show-details-button = 显示详情
+hide-details-button = 隐藏详情
+confirmation-modal-confirm-button = 确认
+confirmation-modal-cancel-button = 取消
+coordinator-address-placeholder = 协调器地址和端口，或 URI
+venv-dir-reset-tooltip = 将 venv 目录重置为默认值
+error-critical = 严重错误
+error-retry-button = 重试
+error-console-tooltip = 显示本次会话的所有错误
+error-console-header = 错误
+error-console-empty = 没有报告任何错误。
+error-console-filter-all = 全部
+error-console-copy-button = 全部复制
+error-console-clear-button = 全部清除
+error-noncritical = 非严重错误
+connection-msg-invalid-input = 无法执行连接操作，输入无效
+connection-msg-bulk-place-op-failed = {$count} 个位置的操作失败
+connection-msg-cancel-reservations-failed = {$count} 个预约取消失败
+connection-msg-restore-places-failed = 位置快照中 {$count} 项更改恢复失败
+error-invalid-path = 提供的路径无效
+error-app-config-load = 从文件加载应用配置失败
+error-app-config-save = 将应用配置保存到文件失败
+error-utilization-record = 记录位置的使用情况失败
+error-app-config-reload = 重新加载已更改的应用配置失败
+error-app-config-open = 打开应用配置文件失败
+error-terminal-launch = 在终端模拟器中启动 {$program} 失败
+error-metrics-export = 导出指标失败
+error-rest-gateway = 提供 REST 网关服务失败
+error-clipboard-copy = 复制到剪贴板失败
+error-clipboard-paste = 从剪贴板粘贴失败
+error-connecting-failed = 连接协调器失败
+error-connecting-timeout = 连接协调器超时
+error-keepalive-timeout = 协调器未响应 keepalive，连接被视为已断开
+error-transport-failure = 与协调器的连接失败
+error-msg-conversion = 无法转换来自协调器的消息
+error-grpc-status-unavailable = 协调器不可用（{$code}）
+error-grpc-status = 协调器报告了一个错误（{$code}）
+
+file-dialog-filter-python-scripts-label = Python 脚本
+file-browser-pick-dir-header = 选择目录
+file-browser-pick-file-header = 选择文件
+file-browser-listing-msg = 正在读取目录 ..
+file-browser-list-failed-msg = 读取目录失败：{$error}
+file-browser-empty-msg = 目录为空
+file-browser-up-tooltip = 上级目录
+file-browser-pick-button = 选择
+
+settings-button = 设置
+profiles-button = 配置档案
+profiles-header = 连接配置档案
+profiles-empty = 还没有配置档案
+profile-default-name = 配置档案
+profile-select-placeholder = 配置档案
+profile-clear-tooltip = 不使用配置档案连接
+profile-add-button = 添加配置档案
+profile-delete-button = 删除配置档案
+profile-name-label = 名称
+profile-address-label = 协调器地址
+profile-tls-label = 通过 TLS 连接
+profile-scope-label = 订阅
+profile-places-only-mine-label = 只显示我的位置
+profile-resources-only-available-label = 只显示可用的资源
+subscription-scope-places-and-resources = 位置和资源
+subscription-scope-places-only = 仅位置
+settings-header = 设置
+settings-language-pick-label = 选择语言
+settings-optimize-touch-label = 针对触摸屏优化界面
+settings-ui-scale-label = 界面缩放
+settings-ui-scale-value = {$percent}%
+settings-high-contrast-label = 使用高对比度颜色
+settings-resource-grace-period-label = 保留离线导出器的资源
+settings-resource-grace-period-value = {$secs} 秒
+settings-offline-exporter-resources-label = 离线导出器的资源
+settings-offline-exporter-resources-show = 显示
+settings-offline-exporter-resources-gray = 灰显
+settings-offline-exporter-resources-hide = 隐藏
+settings-bulk-ops-per-sec-label = 批量位置操作的速率限制
+settings-bulk-ops-per-sec-value = 每秒 {$ops} 次操作
+settings-autosave-label = 定期保存设置
+settings-autosave-value = {$secs ->
+    [0] 关闭
+   *[other] 每 {$secs} 秒
+}
+settings-last-save-label = 上次保存于 {$time}
+settings-last-save-never = 尚未保存
+settings-save-button = 立即保存
+settings-saved-confirmation = 设置已保存
+settings-timeout-preset-label = 连接超时
+settings-timeout-preset-lan = 本地网络
+settings-timeout-preset-vpn = VPN / 远程实验室
+settings-timeout-preset-custom = 自定义
+settings-timeout-connect-label = 连接协调器的超时
+settings-timeout-stream-label = 启动客户端流的超时
+settings-timeout-rpc-label = 协调器调用的超时
+settings-keepalive-label = keepalive 间隔（0 表示禁用）
+settings-reservations-poll-label = 刷新预约的间隔
+settings-timeout-secs-value = {$secs} 秒
+settings-retry-transient-errors-label = 协调器暂时不可用时重试调用
+settings-auth-token-label = 认证令牌
+settings-auth-token-none = 无
+settings-auth-token-static = 固定令牌
+settings-auth-token-file = 令牌文件
+settings-auth-token-command = 令牌命令
+settings-auth-token-static-label = 令牌
+settings-auth-token-file-label = 令牌文件路径
+settings-auth-token-command-label = 输出令牌的命令
+settings-auth-token-refresh-label = 重新运行令牌命令的间隔
+settings-auth-metadata-label = 调用元数据
+settings-auth-metadata-tooltip = 以逗号分隔的 key=value 条目，随每次调用发送，连接时生效
+settings-check-script-imports-label = 验证脚本时检查 labgrid 导入
+settings-scripts-max-depth-label = 扫描的脚本子目录深度
+settings-scripts-max-depth-value = {$depth ->
+    [0] 仅脚本目录
+   *[other] {$depth} 层
+}
+settings-script-timeout-label = 终止运行时间超过以下时长的脚本
+settings-script-timeout-value = {$mins ->
+    [0] 无超时
+   *[other] {$mins} 分钟
+}
+settings-mqtt-label = 将位置和资源状态镜像到 MQTT 代理
+settings-mqtt-broker-label = MQTT 代理
+settings-mqtt-topic-prefix-label = MQTT 主题前缀
+settings-mqtt-username-label = MQTT 用户名
+settings-mqtt-password-label = MQTT 密码
+settings-mqtt-apply-button = 应用
+settings-mqtt-apply-tooltip = 使用更改后的设置重启 MQTT 桥接
+settings-mqtt-status-connecting = 正在连接…
+settings-mqtt-status-connected = 已连接
+settings-mqtt-status-disconnected = 已断开：{$error}
+settings-persist-acquire-history-label = 保存资源获取历史
+settings-record-utilization-label = 为仪表板记录位置的使用情况
+settings-card-color-free-label = 空闲位置的卡片颜色
+settings-card-color-mine-label = 我获取的位置的卡片颜色
+settings-card-color-others-label = 他人获取的位置的卡片颜色
+settings-card-color-reserved-label = 已预约位置的卡片颜色
+settings-card-color-maintenance-label = 维护中位置的卡片颜色
+settings-card-color-reset-tooltip = 恢复默认颜色
+settings-identity-colors-label = 按用户为获取着色
+settings-identity-colors-tooltip = 他人获取的位置和已获取的资源使用由所有者的 host/user 名称派生的颜色着色
+settings-notification-place-released-label = 关注的位置被释放时通知
+settings-notification-reservation-allocated-label = 我的预约被分配时通知
+settings-notification-script-finished-label = 脚本完成时通知
+settings-notification-auto-release-warning-label = 空闲位置被自动释放前通知
+settings-notification-resource-availability-label = 关注的资源变为可用或不可用时通知
+settings-quick-action-acquire-label = 在位置卡片上显示获取/释放按钮
+settings-quick-action-power-cycle-label = 在位置卡片上显示电源重启按钮
+settings-quick-action-console-label = 在位置卡片上显示控制台按钮
+settings-quick-action-favorite-script-label = 在位置卡片上显示收藏脚本按钮
+settings-quick-action-favorite-script-pick-label = 收藏的脚本
+settings-quick-action-favorite-script-placeholder = 选择脚本
+settings-identity-label = Labgrid 身份
+settings-identity-hostname-label = 主机名（覆盖 LG_HOSTNAME）
+settings-identity-username-label = 用户名（覆盖 LG_USERNAME）
+settings-terminal-command-label = 终端模拟器（例如 'x-terminal-emulator -e'）
+settings-auto-release-label = 释放通过界面获取的空闲位置
+settings-auto-release-idle-label = 空闲多长时间后释放
+settings-auto-release-warn-label = 释放前警告
+settings-release-on-exit-label = 退出时释放通过界面获取的位置
+settings-auto-release-minutes-value = {$minutes} 分钟
+settings-identity-reconnect-button = 重新连接
+settings-identity-reconnect-tooltip = 当前以 '{ $identity }' 身份连接，重新连接以应用更改后的身份
+notification-place-released-summary = 位置已释放
+notification-place-released-body = 关注的位置 '{$place}' 已被释放
+notification-resource-available-summary = 资源可用
+notification-resource-available-body = 关注的资源 '{$path}' 已变为可用
+notification-resource-unavailable-summary = 资源不可用
+notification-resource-unavailable-body = 关注的资源 '{$path}' 已变为不可用
+notification-reservation-allocated-summary = 预约已分配
+notification-reservation-allocated-body = 您的预约 '{$token}' 已被分配
+notification-script-finished-summary = 脚本已完成
+notification-script-finished-body = 脚本 '{$script}' 已完成，退出码为 {$code}
+notification-script-timed-out-body = 脚本 '{$script}' 在 {$secs} 秒后超时并已被终止
+notification-auto-release-warning-summary = 位置即将被释放
+notification-auto-release-warning-body = 空闲位置 '{$place}' 将在 {$minutes} 分钟后被释放
+settings-venv-dir-label = 更改脚本的虚拟环境目录
+settings-venv-dir-pick-tooltip = 选择新的 venv 目录
+settings-venv-create-tooltip = 创建包含 labgrid 的虚拟环境
+settings-venv-versions-label = 检测到的版本
+settings-venv-versions-detecting = 正在检测…
+settings-venv-versions-value = Python { $python }，labgrid { $labgrid }
+settings-venv-versions-no-labgrid = Python { $python }，未安装 labgrid
+settings-venv-versions-failed = 检测失败
+settings-venv-versions-error = 无法运行虚拟环境的 python 解释器：{ $error }
+settings-venv-labgrid-missing-msg = 虚拟环境中未安装 labgrid，使用它的脚本将会失败。
+settings-venv-incompatible-msg = labgrid { $labgrid } 无法与 gRPC 协调器通信，脚本至少需要 labgrid { $min }。
+settings-log-viewer-label = 诊断日志
+settings-log-viewer-button = 显示日志
+settings-config-file-label = 配置文件
+settings-config-file-open-tooltip = 使用默认应用打开，磁盘上的更改会立即生效
+log-viewer-header = 诊断日志
+log-viewer-level-label = 级别
+log-viewer-msg = 显示应用的最近事件。从所选级别起记录事件，与 RUST_LOG 无关。
+log-viewer-empty = 没有记录的事件。
+log-viewer-clear-button = 清除
+log-viewer-copy-button = 复制到剪贴板
+protocol-inspector-header = 协议检查器
+protocol-inspector-record-label = 记录
+protocol-inspector-msg = 显示记录期间与协调器交换的 protobuf 消息。已记录 { $recorded } 条消息，显示与过滤条件匹配的最新 { $shown } 条。
+protocol-inspector-filter-placeholder = 按消息类型或内容过滤
+protocol-inspector-empty = 未记录任何消息。
+protocol-inspector-clear-button = 清空
+protocol-inspector-copy-button = 复制到剪贴板
+protocol-inspector-copy-tooltip = 复制所有已记录的消息，包括未显示的消息
+venv-wizard-header = 创建虚拟环境
+venv-wizard-msg = 创建一个安装了 labgrid 的 python 虚拟环境，脚本将在其中运行。需要带有 venv 模块的 python3 以及对 python 软件包索引的访问。
+venv-wizard-dir-label = 目录
+venv-wizard-labgrid-version-label = labgrid 版本
+venv-wizard-labgrid-version-placeholder = 最新
+venv-wizard-invalid-dir-msg = 目录必须是绝对路径
+venv-wizard-dir-not-empty-msg = 目录已存在且不为空
+venv-wizard-invalid-version-msg = 版本不是有效的版本号，例如 24.0.1
+venv-wizard-step-create = 创建虚拟环境
+venv-wizard-step-install = 安装 labgrid
+venv-wizard-step-validate = 验证虚拟环境
+venv-wizard-done-msg = 已使用 labgrid {$version} 创建虚拟环境
+venv-wizard-create-button = 创建
+venv-wizard-use-button = 用于脚本
+
+lang-de-ch = "瑞士德语"
+lang-de-de = "德语"
+lang-en-us = "美式英语"
+lang-es-es = "西班牙语"
+lang-fr-fr = "法语"
+lang-it-it = "意大利语"
+lang-zh-cn = "简体中文"
+
+labgrid-places-label = 位置
+labgrid-places-show-resources-panel-button = 显示资源
+labgrid-places-group-by-none = 不分组
+labgrid-places-group-by-tag = 按标签分组：{$key}
+labgrid-places-group-untagged = 没有标签 '{$key}'
+labgrid-places-select-mode-button = 选择
+labgrid-places-exit-select-mode-button = 完成选择
+labgrid-places-selected-label = 已选择 {$count} 个
+labgrid-places-select-all-button = 全选
+labgrid-places-clear-selection-button = 清除选择
+labgrid-places-bulk-set-tag-button = 设置标签
+labgrid-places-bulk-progress-label = {$done} / {$total} 个位置
+labgrid-places-bulk-pause-button = 暂停
+labgrid-places-bulk-resume-button = 继续
+labgrid-places-bulk-cancel-button = 取消
+labgrid-places-bulk-delete-confirmation-msg = 确定要删除 {$count} 个位置吗？
+labgrid-place-select-checkbox = 已选择
+labgrid-place-mine-badge = 由我获取
+labgrid-place-mine-tooltip = 该位置由此客户端的身份获取
+labgrid-place-session-label = 会话
+labgrid-place-session-remaining = {$remaining} 后释放
+labgrid-place-session-extend-tooltip = 延长会话，重新开始计算空闲时间
+labgrid-place-session-keep-tooltip = 保留该位置，它不会被自动释放
+labgrid-place-resources-label = 资源
+labgrid-place-resources-available = {$available}/{$total} 可用
+labgrid-place-resources-tooltip = 该位置匹配的可用资源，没有任何资源的匹配计为缺失
+labgrid-places-only-mine-checkbox = 只看我的位置
+labgrid-place-power-cycle-tooltip = 对该位置进行电源重启（必须由我获取）
+labgrid-place-power-cycle-failed-msg = 位置 '{ $place }' 电源重启失败
+labgrid-place-console-tooltip = 复制连接控制台的命令
+labgrid-place-favorite-script-tooltip = 在此位置上执行收藏的脚本
+labgrid-place-watch-tooltip = 关注，位置被释放时通知
+labgrid-place-unwatch-tooltip = 取消关注
+labgrid-places-hide-resources-panel-button = 隐藏资源
+labgrid-places-resources-panel-hint = 将资源拖到位置上以为其添加匹配
+labgrid-places-resources-panel-dragging-msg = 将 '{$pattern}' 放到一个位置上
+labgrid-place-drop-resource-confirmation-msg = 将匹配 '{$pattern}' 添加到位置 '{$place}'？
+labgrid-place-name-label = 名称
+labgrid-place-comment-label = 备注
+labgrid-place-comment-placeholder = 备注
+labgrid-place-comment-edit-tooltip = 编辑备注
+labgrid-place-comment-save-button = 保存
+labgrid-place-comment-cancel-button = 取消
+labgrid-place-acquire-button = 获取
+labgrid-place-acquire-with-reason-tooltip = 获取并附上向其他用户显示的原因
+labgrid-place-acquire-with-reason-msg = 您为什么要获取位置 '{$place}'？在位置被释放之前，该原因会向其他用户显示。
+labgrid-place-acquire-reason-placeholder = 例如：二分排查启动问题
+labgrid-place-acquire-reason-label = 原因
+labgrid-place-not-acquired-label = 可用
+labgrid-place-acquired-by-label = 获取者
+labgrid-place-release-label = 踢出
+labgrid-place-force-release-button = 强制释放
+labgrid-place-force-release-confirmation-msg = 位置 '{ $place }' 已被 '{ $owner }' 获取。输入位置名称以从该用户处释放它。
+labgrid-place-tags-label = 标签
+labgrid-place-created-label = 创建于
+labgrid-place-changed-label = 修改于
+labgrid-place-add-placeholder = 位置名称
+labgrid-place-add-button = 添加
+labgrid-place-delete-button = 删除
+labgrid-place-delete-confirmation-msg = 确定要删除位置 '{$place}' 吗？
+labgrid-place-add-tag-placeholder = 标签名称
+labgrid-place-add-tag-value-placeholder = 标签值
+labgrid-place-add-tag-tooltip = 添加标签
+labgrid-place-close-add-tag-tooltip = 关闭
+labgrid-place-details-header = 位置 '{$place}' 详情
+labgrid-place-clone-button = 克隆
+labgrid-place-clone-tooltip = 使用此位置的标签、别名和资源匹配创建一个新位置
+labgrid-place-clone-msg = 创建一个与 '{$place}' 配置相同的新位置。别名和资源匹配中的名称会被替换为新名称。
+labgrid-place-clone-aliases-label = 别名
+labgrid-place-clone-none-label = 无
+labgrid-place-clone-empty-name-msg = 名称不能为空
+labgrid-place-clone-name-taken-msg = 已存在同名的位置
+labgrid-place-clone-alias-taken-msg = 别名 '{$alias}' 已被使用
+labgrid-place-clone-invalid-pattern-msg = '{$pattern}' 不是有效的资源匹配模式
+place-activity-header = 活动
+place-activity-export-button = 导出
+place-activity-export-failed = 导出位置活动失败
+export-button = 导出…
+export-tooltip = 将显示的条目导出为 CSV 或 JSON 文件
+export-failed = 导出失败
+place-snapshot-export-button = 快照…
+place-snapshot-export-tooltip = 将所有位置的名称、别名、备注、标签和资源匹配保存到 JSON 文件
+place-snapshot-restore-button = 恢复…
+place-snapshot-restore-tooltip = 将已保存的位置快照应用到协调器，并预览更改
+place-snapshot-import-failed = 读取位置快照失败
+place-snapshot-restore-msg = 恢复 '{$file}' 会创建缺失的位置，并添加缺失的别名、标签和资源匹配。不会删除任何内容。
+place-snapshot-taken-msg = {$coordinator} 的快照，创建于 {$created}
+place-snapshot-up-to-date-msg = 协调器已包含快照中的全部内容
+place-snapshot-apply-button = 应用 {$count} 项更改
+place-snapshot-step-add-place = 创建位置 '{$place}'
+place-snapshot-step-add-alias = 添加别名 '{$alias}'
+place-snapshot-step-set-comment = 将备注设为 '{$comment}'
+place-snapshot-step-add-tag = 添加标签 {$key}={$value}
+place-snapshot-step-change-tag = 将标签 {$key} 从 '{$current}' 改为 '{$value}'
+place-snapshot-step-add-match = 添加资源匹配 {$pattern}
+place-snapshot-compare-button = 比较…
+place-snapshot-compare-tooltip = 将位置与已保存的快照进行比较，并逐项应用差异
+place-snapshot-compare-msg = 位置与 '{$file}' 的差异
+place-snapshot-no-differences-msg = 位置与快照一致
+place-snapshot-close-button = 关闭
+place-snapshot-restore-all-button = 全部恢复…
+place-snapshot-diff-apply-button = 应用
+place-snapshot-diff-remove-button = 移除
+place-snapshot-diff-missing-place = 协调器上缺少该位置
+place-snapshot-diff-extra-place = 快照中没有该位置
+place-snapshot-diff-missing-alias = 缺少别名 '{$alias}'
+place-snapshot-diff-extra-alias = 快照中没有别名 '{$alias}'
+place-snapshot-diff-comment = 备注为 '{$current}'，而不是 '{$snapshot}'
+place-snapshot-diff-missing-tag = 缺少标签 {$key}={$value}
+place-snapshot-diff-changed-tag = 标签 {$key} 为 '{$current}'，而不是 '{$snapshot}'
+place-snapshot-diff-extra-tag = 快照中没有标签 {$key}={$value}
+place-snapshot-diff-missing-match = 缺少资源匹配 {$pattern}
+place-snapshot-diff-extra-match = 快照中没有资源匹配 {$pattern}
+place-activity-acquired = 由 {$user} 获取
+place-activity-released = 已释放，之前由 {$user} 获取
+place-activity-match-added = 已添加匹配 '{$pattern}'
+place-activity-match-removed = 已移除匹配 '{$pattern}'
+labgrid-place-details-acquired-notice = 此位置刚刚被 {$user} 获取
+labgrid-place-details-released-notice = 此位置刚刚被另一个客户端释放
+labgrid-place-details-modified-notice = 此位置刚刚被另一个客户端修改
+labgrid-place-delete-tag-confirmation-msg = 确定要删除位置标签 '{$tag}' 吗？
+labgrid-place-resource-matches-header = 资源匹配
+labgrid-place-aliases-header = 别名
+labgrid-place-alias-add-placeholder-text = 别名
+labgrid-place-alias-add-button = 添加
+labgrid-place-alias-delete-button = 删除
+labgrid-place-resource-acquired-header = 已获取的资源
+labgrid-place-acquired-resource-origin = {$exporter} / {$group} / {$cls}
+labgrid-place-acquired-resource-acquired-tooltip = 刚刚获取
+labgrid-place-acquired-resource-released-tooltip = 刚刚释放
+labgrid-place-resource-match-rename-placeholder-text = 重命名
+labgrid-place-resource-match-rename-tooltip = 匹配的资源在该位置中使用的可选名称，例如用于在环境配置中为其绑定驱动
+labgrid-place-resource-match-renamed-tooltip = 匹配的资源会被重命名为此名称
+labgrid-place-resource-match-builder-exporter = 导出器
+labgrid-place-resource-match-builder-group = 组
+labgrid-place-resource-match-builder-cls = 类
+labgrid-place-resource-match-builder-name = 名称（可选）
+labgrid-place-resource-match-builder-matching = {$count ->
+    [0] 不匹配任何已知资源
+   *[other] 匹配 {$count} 个已知资源
+}
+labgrid-place-resource-match-add-button = 添加
+labgrid-place-resource-match-delete-button = 删除
+labgrid-resources-label = 资源
+labgrid-resources-only-show-available-checkbox = 只显示可用的
+labgrid-resources-exporter-filter-label = 导出器：{$exporter}
+labgrid-resources-exporter-filter-clear-tooltip = 显示所有导出器的资源
+labgrid-resources-exporter-offline-label = 自 {$time} 起离线
+labgrid-resources-exporter-offline-tooltip = 协调器已移除此导出器的所有资源，它可能已断开连接
+labgrid-exporters-label = 导出器
+labgrid-exporters-empty = 没有已知的导出器，导出器是根据协调器公布的资源推断出来的。
+labgrid-exporter-resources-label = 资源
+labgrid-exporter-available-label = 可用
+labgrid-exporter-available-value = {$total} 个中的 {$available} 个
+labgrid-exporter-acquired-label = 已获取
+labgrid-exporter-stale-label = 已移除
+labgrid-exporter-last-seen-label = 最后出现
+labgrid-exporter-last-seen-unknown = 连接以来未出现
+labgrid-exporter-offline-label = 离线时间
+labgrid-exporter-show-resources-button = 显示资源
+labgrid-exporter-usb-topology-button = USB 拓扑
+labgrid-resources-class-filter-placeholder = 所有类
+labgrid-resources-search-placeholder = 搜索导出器、组、名称或参数
+labgrid-resources-class-filter-clear-tooltip = 显示所有类的资源
+labgrid-classes-label = 资源类
+labgrid-classes-empty = 没有已知的资源。
+labgrid-class-resources-value = {$resources} 个资源
+labgrid-class-available-value = {$total} 个中的 {$available} 个可用（{$percent} %）
+labgrid-class-acquired-value = {$acquired} 个已获取
+labgrid-class-show-resources-button = 显示资源
+
+dashboard-label = 仪表板
+dashboard-places-label = 位置
+dashboard-acquired-label = 已获取
+dashboard-free-label = 空闲
+dashboard-resources-available-label = 可用资源
+dashboard-count-value = {$count} ({$percent} %)
+dashboard-place-states-label = 按状态划分的位置
+dashboard-places-empty = 没有已知的位置。
+dashboard-state-free = 空闲
+dashboard-state-mine = 由我获取
+dashboard-state-others = 由他人获取
+dashboard-state-reserved = 已预留
+dashboard-state-maintenance = 维护中
+dashboard-acquisitions-label = 每个用户获取的位置
+dashboard-acquisitions-empty = 没有已获取的位置。
+dashboard-class-availability-label = 按类别划分的资源可用性
+dashboard-percent-value = {$percent} %
+dashboard-occupancy-label = 每个位置的占用率
+dashboard-occupancy-disabled = 未记录位置的使用情况，可以在设置中启用记录。
+utilization-range-day = 最近一天
+utilization-range-week = 最近一周
+labgrid-resources-no-exporter-name = 没有导出器名称
+labgrid-resource-acquired-label = 已获取
+labgrid-resource-availability-tooltip = 可用性
+labgrid-resource-params-label = 参数
+labgrid-resource-extra-label = 额外信息
+labgrid-camera-preview-label = 预览
+labgrid-camera-preview-refresh-button = 刷新
+labgrid-camera-preview-loading = 正在加载快照 ..
+labgrid-camera-preview-failed = 获取快照失败：{$error}
+labgrid-camera-preview-no-url = 该摄像头没有可获取快照的 HTTP URL。
+labgrid-resource-stale-label = 离线
+labgrid-resource-stale-tooltip = 此资源的导出器已离线，宽限期结束后将被移除
+labgrid-resource-acquire-history-label = 最近的获取者
+labgrid-resource-acquire-record = {$acquired_by} 于 {$time}
+labgrid-resource-assign-tooltip = 分配到位置…
+labgrid-resource-assign-header = 将资源分配到位置
+labgrid-resource-assign-pattern-label = 匹配模式
+labgrid-resource-assign-button = 分配
+labgrid-resource-class-tooltip = 资源类 {$cls}
+labgrid-resource-class-unknown-tooltip = 资源类 {$cls} 未知，点击配置其图标和快捷操作
+labgrid-resource-copy-param-tooltip = 将参数 '{$param}' 的值复制到剪贴板
+labgrid-resource-copy-command-tooltip = 将 {$program} 命令复制到剪贴板
+labgrid-resource-launch-command-tooltip = 在终端模拟器中运行 {$program}
+labgrid-resource-watch-tooltip = 关注，资源变为可用或不可用时通知
+labgrid-resource-unwatch-tooltip = 取消关注
+resource-class-header = 资源类 {$cls}
+resource-class-known-msg = 此资源类是已知的，可以覆盖其图标。
+resource-class-unknown-msg = 此资源类未知，在其资源上观察到了以下参数。为其映射一个图标以及一个由快捷操作按钮复制的参数。
+resource-class-params-label = 观察到的参数
+resource-class-no-params-msg = 没有观察到任何参数。
+resource-class-param-observed = {$kinds}，出现在 {$total} 个资源中的 {$count} 个上
+resource-class-icon-label = 图标
+resource-class-icon-placeholder = 无图标
+resource-class-copy-param-label = 由快捷操作按钮复制的参数
+resource-class-copy-param-placeholder = 无快捷操作
+resource-class-reset-button = 重置
+usb-topology-header = {$exporter} 的 USB 拓扑
+usb-topology-empty = 导出器没有公布总线已知的 USB 设备。
+usb-topology-bus-label = 总线 {$bus}
+usb-topology-port-label = 端口 {$port}
+usb-topology-unknown-port-label = 端口未知
+usb-topology-device-value = 设备 {$devnum}
+usb-topology-acquired-value = 被 {$place} 获取
+quick-switcher-placeholder = 搜索位置和脚本
+quick-switcher-hint = ↑↓ 选择 · Enter 打开位置详情或运行脚本 · Shift+Enter 获取位置 · Esc 关闭
+quick-switcher-place-label = 位置
+quick-switcher-script-label = 脚本
+quick-switcher-no-matches-msg = 没有匹配的位置或脚本
+quick-switcher-command-label = 命令
+command-palette-placeholder = 搜索命令、位置和脚本
+command-palette-hint = ↑↓ 选择 · Enter 运行命令、打开位置详情或运行脚本 · Shift+Enter 获取位置 · Esc 关闭
+command-refresh = 刷新
+command-show-tab = 显示{ $tab }
+command-create-reservation = 创建预约
+command-open-settings = 打开设置
+command-disconnect = 断开连接
+resource-class-icon-board = 开发板
+resource-class-icon-power = 电源
+resource-class-icon-serial = 串口控制台
+resource-class-icon-network = 网络
+resource-class-icon-usb = USB
+resource-class-icon-video = 视频
+resource-class-icon-storage = 存储
+resource-class-icon-display = 显示器
+resource-class-icon-relay = 继电器 / GPIO
+resource-class-icon-sensor = 传感器
+labgrid-reservations-label = 预约
+labgrid-reservation-owner-label = 所有者
+labgrid-reservation-token-label = 令牌
+labgrid-reservation-prio-label = 优先级
+labgrid-reservation-filters-label = 过滤器
+labgrid-reservation-created-label = 创建于
+labgrid-reservation-expires-label = 过期于
+labgrid-reservation-expires-in = 剩余 {$remaining}
+labgrid-reservation-timed-out = 已超时
+labgrid-reservation-time-unset = -
+labgrid-reservation-allocations-label = 已分配
+labgrid-reservation-goto-place-button = 前往位置
+labgrid-reservation-queue-label = 队列
+labgrid-reservation-queue-position = 第 {$position} 位
+labgrid-reservation-queue-free-places = {$matching} 个匹配位置中有 {$free} 个空闲
+labgrid-reservation-queue-expected-wait = 预计等待约 {$minutes} 分钟
+labgrid-reservation-queue-expected-wait-unknown = 预计等待时间未知
+labgrid-reservation-cancel-label = 取消
+labgrid-reservation-cancel-foreign-button = 取消预约
+labgrid-reservation-cancel-foreign-confirmation-msg = 预约 '{ $token }' 属于 '{ $owner }'。输入所有者的名称以取消它。
+labgrid-reservation-mine-badge = 我的预约
+labgrid-reservations-show-foreign-checkbox = 显示他人的预约
+labgrid-reservation-create-button = 创建预约
+labgrid-reservation-create-header = 创建预约
+labgrid-reservation-state-waiting = 等待中
+labgrid-reservation-state-allocated = 已分配
+labgrid-reservation-state-acquired = 已获取
+labgrid-reservation-state-expired = 已过期
+labgrid-reservation-state-invalid = 无效
+labgrid-reservation-state-unknown = 未知
+labgrid-reservations-cleanup-button = 清理
+labgrid-reservations-cleanup-tooltip = 一次性取消已过期、无效或自己的预约
+labgrid-reservations-cleanup-header = 清理预约
+labgrid-reservations-cleanup-select-label = 选择
+labgrid-reservations-cleanup-stale = 已过期和无效的预约
+labgrid-reservations-cleanup-mine = 我的所有预约
+labgrid-reservations-cleanup-summary = {$count ->
+    [0] 未选择任何预约
+   *[other] 将取消 {$count} 个预约
+}
+labgrid-reservations-cleanup-confirm-button = 取消预约
+labgrid-reservation-filter-placeholder = 过滤表达式，例如 board=foo AND lab=muc
+labgrid-reservation-filter-empty-msg = 输入过滤表达式以选择要预约的位置
+labgrid-reservation-filter-invalid-msg = 无效的过滤表达式：{$error}
+labgrid-reservation-filter-client-side-msg = 仅供预览，该表达式不能用于预约：{$reason}
+labgrid-reservation-filter-matches-label = {$count} 个匹配的位置
+
+scripts-label = 脚本
+scripts-none-found-msg = 未找到脚本
+scripts-dir-reset-tooltip = 将脚本目录重置为默认值
+scripts-dir-pick-tooltip = 选择新的脚本目录
+scripts-dir-rescan-tooltip = 重新扫描脚本目录，通常会自动检测到更改
+scripts-dir-rescan-failed-error = 重新扫描脚本目录失败
+scripts-favorites-label = 收藏
+script-favorite-tooltip = 将脚本置顶
+script-move-up-tooltip = 上移
+script-move-down-tooltip = 下移
+script-display-name-tooltip = 更改显示名称
+script-display-name-msg = 脚本 "{$script}" 的显示名称，为空时显示文件名
+script-display-name-placeholder = 显示名称
+script-display-name-button = 保存
+scripts-env-label = 环境
+script-output-label = 输出
+script-execute-button = 执行
+script-abort-button = 中止
+script-status-label = 状态
+script-status-none = 空闲
+script-status-running = 运行中
+script-status-cleaning-up = 正在清理
+script-status-finished = 已完成，退出码 '{$code}'
+script-status-timed-out = {$secs} 秒后超时
+script-failed-msg = 脚本失败
+script-cleanup-failed-msg = 脚本清理失败
+script-validate-button = 验证
+script-validate-tooltip = 检查脚本的语法错误和失败的 labgrid 导入，而不执行它
+script-validation-label = 验证
+script-validation-running = 正在验证
+script-validation-valid = 有效
+script-validation-invalid = 有错误，请查看输出
+script-validation-failed-msg = 脚本验证失败
+script-not-found-msg = 在脚本目录中未找到脚本 '{ $script }'
+script-output-show-label = 显示
+script-output-hide-label = 隐藏
+script-output-clear-tooltip = 清除脚本输出
+reservation-run-label = 在下一个可用的开发板上运行
+reservation-run-filter-placeholder = 过滤表达式，例如 board=imx8
+reservation-run-script-placeholder = 脚本
+reservation-run-button = 运行
+reservation-run-cancel-button = 取消
+reservation-run-status-reserving = 正在预约
+reservation-run-status-waiting = 正在等待位置
+reservation-run-status-acquiring = 正在获取 '{$place}'
+reservation-run-status-running = 正在 '{$place}' 上运行
+reservation-run-lost-msg = 运行的预约已过期或已被取消
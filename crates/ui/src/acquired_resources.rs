@@ -0,0 +1,150 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::AppMsg;
+use core::time::Duration;
+use iced::futures;
+use labgrid_ui_core::pattern::MatchPattern;
+use std::time::Instant;
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use tokio_stream::StreamExt;
+
+/// How long a resource that was acquired or released by a place is highlighted.
+pub(crate) const CHANGE_HIGHLIGHT: Duration = Duration::from_secs(3);
+
+/// A resource that was acquired or released by a place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AcquiredResourceChange {
+    pub(crate) resource: String,
+    /// Whether the resource was acquired, otherwise it was released.
+    pub(crate) acquired: bool,
+    pub(crate) at: Instant,
+}
+
+impl AcquiredResourceChange {
+    /// The progress of the highlight, from `0.0` when the change happened to `1.0` when it has faded out.
+    pub(crate) fn progress(&self, now: Instant) -> f32 {
+        (now.saturating_duration_since(self.at).as_secs_f32() / CHANGE_HIGHLIGHT.as_secs_f32())
+            .min(1.0)
+    }
+}
+
+/// The recent changes of the acquired resources of a place, highlighted in the place details.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AcquiredResourceChanges(Vec<AcquiredResourceChange>);
+
+impl AcquiredResourceChanges {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Records the resources that are in `current` but not in `prev` as acquired, and the other way around as released.
+    ///
+    /// A new change of a resource replaces its previous one.
+    pub(crate) fn record(&mut self, prev: &[String], current: &[String], now: Instant) {
+        let released = prev
+            .iter()
+            .filter(|r| !current.contains(r))
+            .map(|r| (r, false));
+        let acquired = current
+            .iter()
+            .filter(|r| !prev.contains(r))
+            .map(|r| (r, true));
+        for (resource, acquired) in released.chain(acquired) {
+            self.0.retain(|c| &c.resource != resource);
+            self.0.push(AcquiredResourceChange {
+                resource: resource.clone(),
+                acquired,
+                at: now,
+            });
+        }
+    }
+
+    /// Removes the changes whose highlight has faded out.
+    pub(crate) fn prune(&mut self, now: Instant) {
+        self.0.retain(|c| c.progress(now) < 1.0);
+    }
+
+    /// The change of `resource`, if it was changed recently.
+    pub(crate) fn get(&self, resource: &str) -> Option<&AcquiredResourceChange> {
+        self.0.iter().find(|c| c.resource == resource)
+    }
+
+    /// The released resources that are still highlighted.
+    pub(crate) fn released(&self) -> impl Iterator<Item = &AcquiredResourceChange> {
+        self.0.iter().filter(|c| !c.acquired)
+    }
+}
+
+/// The exporter, group, class and name of an acquired resource as reported by the client out stream.
+///
+/// Returns `None` if the resource is not in the `exporter/group/cls/name` form.
+pub(crate) fn parse_acquired_resource(resource: &str) -> Option<MatchPattern> {
+    resource
+        .parse::<MatchPattern>()
+        .ok()
+        .filter(|pattern| pattern.name.is_some())
+}
+
+/// An iced subscription that triggers `AppMsg::AcquiredResourcesAnimationTick` messages while changes are highlighted.
+pub(crate) fn animation_tick_subscription() -> impl futures::Stream<Item = AppMsg> {
+    const TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+    IntervalStream::new(time::interval(TICK_INTERVAL))
+        .map(|_| AppMsg::AcquiredResourcesAnimationTick)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acquired_resource_changes() {
+        let start = Instant::now();
+        let resources = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        let mut changes = AcquiredResourceChanges::default();
+
+        changes.record(
+            &resources(&["e/g/Power/power"]),
+            &resources(&["e/g/Power/power", "e/g/USBSerialPort/serial"]),
+            start,
+        );
+        assert!(changes.get("e/g/Power/power").is_none());
+        assert!(changes.get("e/g/USBSerialPort/serial").unwrap().acquired);
+
+        // Releasing a resource replaces the previous change
+        let released = start + Duration::from_secs(1);
+        changes.record(
+            &resources(&["e/g/Power/power", "e/g/USBSerialPort/serial"]),
+            &resources(&["e/g/Power/power"]),
+            released,
+        );
+        assert_eq!(
+            changes.released().collect::<Vec<_>>(),
+            vec![&AcquiredResourceChange {
+                resource: "e/g/USBSerialPort/serial".to_string(),
+                acquired: false,
+                at: released,
+            }]
+        );
+
+        changes.prune(start + CHANGE_HIGHLIGHT);
+        assert!(!changes.is_empty());
+        changes.prune(released + CHANGE_HIGHLIGHT);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn acquired_resource_parse() {
+        let pattern = parse_acquired_resource("exporter-1/board-1/USBSerialPort/serial").unwrap();
+        assert_eq!(pattern.exporter, "exporter-1");
+        assert_eq!(pattern.name.as_deref(), Some("serial"));
+        assert_eq!(parse_acquired_resource("board-1/serial"), None);
+        assert_eq!(
+            parse_acquired_resource("exporter-1/board-1/USBSerialPort"),
+            None
+        );
+    }
+}
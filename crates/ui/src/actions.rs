@@ -0,0 +1,322 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::connection::{self, ConnectionEvent, ConnectionMsg};
+use crate::headless;
+use crate::scripts::{self, Env, EnvEntry, Script, ScriptExit};
+use crate::Args;
+use anyhow::Context;
+use core::time::Duration;
+use iced::futures::StreamExt;
+use labgrid_ui_core::types::Place;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::time;
+
+/// Time an acquire or release action may take, including connecting to the coordinator.
+const PLACE_ACTION_TIMEOUT: Duration = Duration::from_secs(30);
+/// Exit code when the script timed out, the same as the one of coreutils `timeout`.
+const SCRIPT_TIMED_OUT_EXIT_CODE: i32 = 124;
+
+/// A one-shot action requested on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CliAction {
+    Acquire(String),
+    Release(String),
+    RunScript {
+        /// The script file, relative to the scripts directory or absolute.
+        script: PathBuf,
+        /// Passed to the script as `LG_PLACE`.
+        place: Option<String>,
+        /// Passed to the script as `LG_ENV`.
+        lg_env: Option<String>,
+    },
+}
+
+impl CliAction {
+    /// The action requested by the arguments, `None` if the app should be started as usual.
+    pub(crate) fn from_args(args: &Args) -> Option<Self> {
+        if let Some(place) = &args.acquire {
+            Some(Self::Acquire(place.clone()))
+        } else if let Some(place) = &args.release {
+            Some(Self::Release(place.clone()))
+        } else {
+            args.run_script.as_ref().map(|script| Self::RunScript {
+                script: script.clone(),
+                place: args.place.clone(),
+                lg_env: args.lg_env.clone(),
+            })
+        }
+    }
+}
+
+/// How a [PlaceAction] progressed in response to a connection event.
+#[derive(Debug, Clone)]
+enum PlaceActionStep {
+    Continue,
+    Send(ConnectionMsg),
+    Done(String),
+    Failed(String),
+}
+
+/// Acquiring or releasing a place, driven by the events of the connection.
+#[derive(Debug)]
+struct PlaceAction {
+    acquire: bool,
+    place: String,
+    identity: String,
+    places: HashMap<String, Place>,
+    /// Whether the acquire or release message was sent.
+    sent: bool,
+}
+
+impl PlaceAction {
+    fn new(acquire: bool, place: String) -> Self {
+        Self {
+            acquire,
+            place,
+            identity: String::new(),
+            places: HashMap::new(),
+            sent: false,
+        }
+    }
+
+    fn handle_event(&mut self, event: ConnectionEvent) -> PlaceActionStep {
+        match event {
+            ConnectionEvent::Connected { identity, .. } => self.identity = identity,
+            ConnectionEvent::Disconnected { error } => {
+                return PlaceActionStep::Failed(match error {
+                    Some(error) => format!("Disconnected: {}\n{}", error.short, error.detailed),
+                    None => "Disconnected".to_string(),
+                });
+            }
+            ConnectionEvent::NonCriticalError { error } if self.sent => {
+                return PlaceActionStep::Failed(format!("{}\n{}", error.short, error.detailed));
+            }
+            ConnectionEvent::Places(places) => {
+                self.places
+                    .extend(places.into_iter().map(|p| (p.name.clone(), p)));
+            }
+            ConnectionEvent::Place(place) => {
+                let name = place.name.clone();
+                self.places.insert(name.clone(), place);
+                if self.sent && name == self.place {
+                    return self.check_done().unwrap_or(PlaceActionStep::Continue);
+                }
+            }
+            ConnectionEvent::DeletePlace(name) => {
+                self.places.remove(&name);
+            }
+            ConnectionEvent::Synced if !self.sent => {
+                let Some(place) = self.places.get(&self.place) else {
+                    return PlaceActionStep::Failed(format!("Place '{}' not found", self.place));
+                };
+                if let Some(done) = self.check_done() {
+                    return done;
+                }
+                // Our own places are released, acquiring them is done already
+                if let Some(owner) = place
+                    .acquired
+                    .as_ref()
+                    .filter(|owner| **owner != self.identity)
+                {
+                    return PlaceActionStep::Failed(format!(
+                        "Place '{}' is acquired by '{owner}'",
+                        self.place
+                    ));
+                }
+                self.sent = true;
+                let name = self.place.clone();
+                return PlaceActionStep::Send(if self.acquire {
+                    ConnectionMsg::AcquirePlace { name }
+                } else {
+                    ConnectionMsg::ReleasePlace { name }
+                });
+            }
+            _ => {}
+        }
+        PlaceActionStep::Continue
+    }
+
+    /// `Some` once the place is in the requested state.
+    fn check_done(&self) -> Option<PlaceActionStep> {
+        let acquired = self.places.get(&self.place)?.acquired.as_deref();
+        match (self.acquire, acquired) {
+            (true, Some(owner)) if owner == self.identity => Some(PlaceActionStep::Done(format!(
+                "Acquired place '{}'",
+                self.place
+            ))),
+            (false, None) => Some(PlaceActionStep::Done(format!(
+                "Released place '{}'",
+                self.place
+            ))),
+            _ => None,
+        }
+    }
+}
+
+/// Runs the action with the configuration of the app, without bringing up the UI.
+///
+/// Returns the exit code of the process.
+pub(crate) fn run(args: &Args, action: CliAction) -> anyhow::Result<i32> {
+    tokio::runtime::Runtime::new()
+        .context("Create async runtime")?
+        .block_on(run_async(args, action))
+}
+
+async fn run_async(args: &Args, action: CliAction) -> anyhow::Result<i32> {
+    let config = headless::load_config(args)?;
+    let place_action = match action {
+        CliAction::Acquire(place) => PlaceAction::new(true, place),
+        CliAction::Release(place) => PlaceAction::new(false, place),
+        CliAction::RunScript {
+            script,
+            place,
+            lg_env,
+        } => {
+            let script = Script::from_path(config.scripts_dir.join(&script))
+                .with_context(|| format!("Script '{}'", script.display()))?;
+            let mut env = Env::default();
+            if let Some(place) = place {
+                env.insert(EnvEntry::LgPlace, place);
+            }
+            if let Some(lg_env) = lg_env {
+                env.insert(EnvEntry::LgEnv, lg_env);
+            }
+            let timeout = script.timeout(scripts::default_timeout(config.script_timeout_mins));
+            let (exit, stdout, stderr) = script
+                .execute_quiet(&config.venv_dir, &env, timeout)
+                .await
+                .context("Run script")?;
+            print!("{stdout}");
+            eprint!("{stderr}");
+            return Ok(match exit {
+                ScriptExit::Exited(exit_code) => exit_code,
+                ScriptExit::TimedOut(timeout) => {
+                    eprintln!("Script timed out after {} s", timeout.as_secs());
+                    SCRIPT_TIMED_OUT_EXIT_CODE
+                }
+            });
+        }
+    };
+    let connect = headless::connect_msg(args, &config)?;
+    time::timeout(
+        PLACE_ACTION_TIMEOUT,
+        run_place_action(place_action, connect),
+    )
+    .await
+    .context("Timed out")??;
+    Ok(0)
+}
+
+async fn run_place_action(mut action: PlaceAction, connect: ConnectionMsg) -> anyhow::Result<()> {
+    let mut events = Box::pin(connection::kickoff());
+    let mut sender = None;
+    while let Some(event) = events.next().await {
+        if let ConnectionEvent::ReceiveReady(mut new_sender) = event {
+            new_sender.send(connect.clone());
+            sender = Some(new_sender);
+            continue;
+        }
+        match action.handle_event(event) {
+            PlaceActionStep::Continue => {}
+            PlaceActionStep::Send(msg) => {
+                if let Some(sender) = &mut sender {
+                    sender.send(msg);
+                }
+            }
+            PlaceActionStep::Done(msg) => {
+                println!("{msg}");
+                return Ok(());
+            }
+            PlaceActionStep::Failed(error) => anyhow::bail!(error),
+        }
+    }
+    anyhow::bail!("Connection ended")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use clap::Parser;
+
+    fn place(acquired: Option<&str>) -> Place {
+        Place {
+            acquired: acquired.map(str::to_string),
+            ..test_fixtures::place("board-1")
+        }
+    }
+
+    #[test]
+    fn cli_action_from_args() {
+        let action = |args: &[&str]| {
+            Args::try_parse_from([&["labgrid-ui"], args].concat())
+                .map(|args| CliAction::from_args(&args))
+        };
+        assert_eq!(action(&[]).unwrap(), None);
+        assert_eq!(
+            action(&["--acquire", "board-1"]).unwrap(),
+            Some(CliAction::Acquire("board-1".to_string()))
+        );
+        assert_eq!(
+            action(&["--run-script", "flash.py", "--place", "board-1"]).unwrap(),
+            Some(CliAction::RunScript {
+                script: PathBuf::from("flash.py"),
+                place: Some("board-1".to_string()),
+                lg_env: None
+            })
+        );
+        assert!(action(&["--acquire", "board-1", "--release", "board-1"]).is_err());
+        assert!(action(&["--acquire", "board-1", "--headless"]).is_err());
+        assert!(action(&["--place", "board-1"]).is_err());
+    }
+
+    #[test]
+    fn place_action() {
+        let connected = || ConnectionEvent::Connected {
+            address: "localhost:20408".to_string(),
+            identity: "host/me".to_string(),
+        };
+        let mut acquire = PlaceAction::new(true, "board-1".to_string());
+        acquire.handle_event(connected());
+        acquire.handle_event(ConnectionEvent::Places(vec![place(None)]));
+        assert!(matches!(
+            acquire.handle_event(ConnectionEvent::Synced),
+            PlaceActionStep::Send(ConnectionMsg::AcquirePlace { name }) if name == "board-1"
+        ));
+        assert!(matches!(
+            acquire.handle_event(ConnectionEvent::Place(place(Some("host/me")))),
+            PlaceActionStep::Done(msg) if msg == "Acquired place 'board-1'"
+        ));
+
+        let mut release = PlaceAction::new(false, "board-1".to_string());
+        release.handle_event(connected());
+        release.handle_event(ConnectionEvent::Places(vec![place(Some("host/me"))]));
+        assert!(matches!(
+            release.handle_event(ConnectionEvent::Synced),
+            PlaceActionStep::Send(ConnectionMsg::ReleasePlace { name }) if name == "board-1"
+        ));
+        assert!(matches!(
+            release.handle_event(ConnectionEvent::Place(place(None))),
+            PlaceActionStep::Done(msg) if msg == "Released place 'board-1'"
+        ));
+
+        // Places acquired by others aren't released
+        let mut release = PlaceAction::new(false, "board-1".to_string());
+        release.handle_event(connected());
+        release.handle_event(ConnectionEvent::Place(place(Some("host/other"))));
+        assert!(matches!(
+            release.handle_event(ConnectionEvent::Synced),
+            PlaceActionStep::Failed(_)
+        ));
+
+        let mut unknown = PlaceAction::new(false, "board-2".to_string());
+        unknown.handle_event(connected());
+        assert!(matches!(
+            unknown.handle_event(ConnectionEvent::Synced),
+            PlaceActionStep::Failed(error) if error == "Place 'board-2' not found"
+        ));
+    }
+}
@@ -2,19 +2,68 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::config::{self, Config};
-use crate::connection::{self, ConnectionEvent, ConnectionMsg, ConnectionSender};
+use crate::acquired_resources::{self, AcquiredResourceChanges};
+use crate::camera::{self, CameraPreview};
+use crate::clipboard::{self, ClipboardAccess};
+use crate::config::{self, Config, ConfigOverrides};
+use crate::connection::{
+    self, BulkPlaceOpProgress, ConnectionAuth, ConnectionEvent, ConnectionMsg, ConnectionSender,
+    ConnectionTimeouts, SubscriptionScope, SHUTDOWN_TIMEOUT,
+};
+use crate::error_log::{ErrorFilter, ErrorLog};
+use crate::export::{ExportFormat, ExportKind, Table};
+use crate::exporters::{ExporterLastSeen, OfflineExporterResources};
+use crate::file_browser::{self, FileBrowser, FileBrowserEntry, FileBrowserTarget};
+use crate::file_dialogs::{FileDialogKind, FileDialogRequest, FileDialogs, NativeFileDialogs};
+use crate::headless::HeadlessJob;
+use crate::history::{AcquireHistory, PlaceActivityLog};
 use crate::i18n::{self, fl, AppLanguage};
-use crate::scripts::{EnvEntry, Script, ScriptStatus, Scripts};
+use crate::log_viewer::{self, LogEntry, LogLevel};
+use crate::match_builder::{MatchBuilder, MatchComponent};
+use crate::metrics;
+use crate::mqtt::{self, MqttBridge, MqttSettings};
+use crate::notifications::{self, NotificationEvent, NotificationSettings};
+use crate::palette::{self, CardPalette, PlaceCardState};
+use crate::place_clone::PlaceClone;
+use crate::place_snapshot::{PlaceSnapshot, SnapshotFile};
+use crate::profiles::{self, ConnectionProfile};
+use crate::protocol_inspector;
+use crate::quick_actions::{QuickAction, QuickActionSettings};
+use crate::quick_connect::QuickConnect;
+use crate::quick_switcher::{self, QuickSwitcher, QuickSwitcherItem};
+use crate::reservation_runner::{self, ReservationRun, RunStep};
+use crate::resource_classes::{ClassMapping, ResourceClassRegistry};
+use crate::resource_filter::ResourceFilter;
+use crate::resource_watch::{AvailabilityNotice, ResourceWatch};
+#[cfg(feature = "rest-gateway")]
+use crate::rest_gateway::RestGateway;
+use crate::script_meta::ScriptMetaSettings;
+use crate::scripts::{EnvEntry, Script, ScriptExit, ScriptStatus, ScriptValidation, Scripts};
+use crate::session_timer::{self, AutoReleaseSettings, SessionTimer, SessionTimerAction};
+use crate::shortcuts;
+use crate::util::IdentityOverride;
+use crate::utilization::{UtilizationLog, UtilizationRange};
+use crate::venv::{VenvProgress, VenvStep, VenvVersions, VenvWizard};
+use crate::view_state::{ViewState, WindowGeometry};
 use crate::views::{self};
-use crate::{scripts, util, Args};
-use anyhow::Context;
-use arboard::Clipboard;
-use iced::{window, Font, Size, Subscription, Task};
+use crate::webhooks::{self, Webhooks};
+use crate::{queue, scripts, util, Args};
+use chrono::{DateTime, Local};
+use iced::advanced::widget;
+use iced::widget::image;
+use iced::{event, keyboard, theme, window, Event, Font, Point, Size, Subscription, Task, Theme};
 use iced_fonts::BOOTSTRAP_FONT_BYTES;
-use labgrid_ui_core::types::{self, Place, Reservation, Resource};
+use labgrid_ui_core::filter::FilterExpr;
+use labgrid_ui_core::metrics::Metrics;
+use labgrid_ui_core::mqtt::MqttStatus;
+use labgrid_ui_core::trace::TracedMessage;
+use labgrid_ui_core::types::{self, Place, Reservation, ReservationState, Resource};
+use labgrid_ui_core::version::{self, Compatibility};
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
-use tracing::{debug, error, warn};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
 
 #[allow(unused)]
 pub(crate) const FONT_CANTARELL: Font = Font::with_name("Cantarell");
@@ -23,14 +72,119 @@ pub(crate) const FONT_NOTO_EMOJI: Font = Font::with_name("Noto Emoji");
 #[allow(unused)]
 pub(crate) const FONT_INCONSOLATA: Font = Font::with_name("Inconsolata");
 
+/// The color theme of the UI, the system color scheme is followed when none is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum AppTheme {
+    Light,
+    Dark,
+}
+
+/// The name of the place whose comment carries a lab-wide announcement to all UI users.
+pub(crate) const ANNOUNCEMENT_PLACE_NAME: &str = "labgrid-ui-announcement";
+/// The place tag holding the reason why the place was acquired, shown to other users on the place card.
+///
+/// Cleared by the UI once the place is released.
+pub(crate) const ACQUIRE_REASON_TAG: &str = "acquire_reason";
+/// How long the confirmation of a settings save is shown in the settings.
+const SETTINGS_SAVED_CONFIRMATION: Duration = Duration::from_secs(2);
+
+/// The reason the place was acquired for, if it is acquired and a reason was given.
+pub(crate) fn acquire_reason(place: &Place) -> Option<&str> {
+    place.acquired.as_ref()?;
+    place
+        .tags
+        .get(ACQUIRE_REASON_TAG)
+        .map(String::as_str)
+        .filter(|reason| !reason.is_empty())
+}
+
 /// Identifier for the current selected tab page.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Default, serde::Serialize, serde::Deserialize,
+)]
 pub(crate) enum TabId {
     #[default]
     Places,
     Reservations,
     Resources,
+    Exporters,
+    Classes,
     Scripts,
+    Dashboard,
+}
+
+impl TabId {
+    /// All tabs in the order they are displayed.
+    pub(crate) const ALL: [Self; 7] = [
+        Self::Places,
+        Self::Reservations,
+        Self::Resources,
+        Self::Exporters,
+        Self::Classes,
+        Self::Scripts,
+        Self::Dashboard,
+    ];
+
+    pub(crate) fn label(&self) -> String {
+        match self {
+            Self::Places => fl!("labgrid-places-label"),
+            Self::Reservations => fl!("labgrid-reservations-label"),
+            Self::Resources => fl!("labgrid-resources-label"),
+            Self::Exporters => fl!("labgrid-exporters-label"),
+            Self::Classes => fl!("labgrid-classes-label"),
+            Self::Scripts => fl!("scripts-label"),
+            Self::Dashboard => fl!("dashboard-label"),
+        }
+    }
+}
+
+/// How the places in the places tab are grouped.
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) enum PlaceGrouping {
+    #[default]
+    None,
+    /// Group by the values of the tag with the contained key.
+    Tag(String),
+}
+
+impl std::fmt::Display for PlaceGrouping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "{}", fl!("labgrid-places-group-by-none")),
+            Self::Tag(key) => write!(f, "{}", fl!("labgrid-places-group-by-tag", key = key)),
+        }
+    }
+}
+
+/// Which reservations are selected for cancellation when cleaning up the reservations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ReservationCleanup {
+    /// Expired and invalid reservations.
+    #[default]
+    Stale,
+    /// All reservations owned by this client.
+    Mine,
+}
+
+impl ReservationCleanup {
+    pub(crate) const ALL: [Self; 2] = [Self::Stale, Self::Mine];
+
+    /// Whether the reservation is selected for the [AppConnected::identity].
+    pub(crate) fn selects(&self, reservation: &Reservation, identity: &str) -> bool {
+        match self {
+            Self::Stale => reservation.is_stale(),
+            Self::Mine => reservation.owner == identity,
+        }
+    }
+}
+
+impl std::fmt::Display for ReservationCleanup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stale => write!(f, "{}", fl!("labgrid-reservations-cleanup-stale")),
+            Self::Mine => write!(f, "{}", fl!("labgrid-reservations-cleanup-mine")),
+        }
+    }
 }
 
 /// Top-level app messages.
@@ -41,16 +195,174 @@ pub(crate) enum AppMsg {
     None,
     ChangeLanguage(AppLanguage),
     OptimizeTouch(bool),
+    ChangeUiScale(u32),
+    WindowResized(Size),
+    WindowMoved(Point),
+    HighContrast(bool),
+    /// The color scheme of the system changed.
+    SystemThemeChanged(theme::Mode),
+    PersistAcquireHistory(bool),
+    RecordUtilization(bool),
+    ChangeResourceGracePeriod(u32),
+    ChangeOfflineExporterResources(OfflineExporterResources),
+    ChangeCardColor {
+        state: PlaceCardState,
+        hex: String,
+    },
+    ChangeIdentityColors(bool),
+    ChangeNotification {
+        event: NotificationEvent,
+        enabled: bool,
+    },
+    ToggleWatchPlace {
+        place_name: String,
+    },
+    ToggleWatchResource(types::Path),
+    ChangeIdentityHostname(String),
+    ChangeIdentityUsername(String),
+    ChangeTerminalCommand(String),
+    /// Reconnects to the coordinator, to apply a changed identity.
+    Reconnect,
+    /// Exporting the metrics failed with the contained error.
+    MetricsExportFailed(String),
+    /// Serving the REST gateway failed with the contained error.
+    #[cfg(feature = "rest-gateway")]
+    RestGatewayFailed(String),
+    ChangeQuickAction {
+        action: QuickAction,
+        enabled: bool,
+    },
+    ChangeFavoriteScript(String),
+    ChangeAutoRelease(AutoReleaseSettings),
+    ChangeBulkOpsPerSec(u32),
+    /// Changes the interval of the periodic configuration save, `0` disables it.
+    ChangeAutosave(u32),
+    ChangeConnectionTimeouts(ConnectionTimeouts),
+    RetryTransientErrors(bool),
+    ChangeConnectionAuth(ConnectionAuth),
+    /// Changes whether validating scripts also checks their labgrid imports.
+    CheckScriptImports(bool),
+    /// Changes the depth of the subdirectories scanned for scripts and rescans them.
+    ChangeScriptsMaxDepth(u32),
+    /// Changes the timeout in minutes of scripts that don't declare one, `0` disables it.
+    ChangeScriptTimeout(u32),
+    /// Pins the script with the name at the top of the scripts tab or unpins it.
+    ToggleScriptFavorite(String),
+    /// Sets the displayed name of the script, an empty name resets it to the file name.
+    SetScriptDisplayName {
+        name: String,
+        display_name: String,
+    },
+    /// Moves the script one position up or down among the scripts it is displayed with.
+    MoveScript {
+        name: String,
+        up: bool,
+    },
+    /// Changes the MQTT settings, which are applied right away only when enabling or disabling the bridge.
+    ChangeMqttSettings(MqttSettings),
+    /// Restarts the MQTT bridge with the current settings.
+    ApplyMqttSettings,
+    /// The MQTT bridge was started, state changes are published through it.
+    MqttBridgeReady(MqttBridge),
+    MqttStatus(MqttStatus),
+    /// Sets the user mapping of the resource class, a default mapping removes it.
+    ChangeResourceClassMapping {
+        cls: String,
+        mapping: ClassMapping,
+    },
+    /// Selects the connection profile used for connecting, fills in its address.
+    SelectProfile(Option<String>),
+    /// Adds a profile with the current address and opens it in the profiles manager.
+    AddProfile,
+    /// Replaces the profile at the index, keeping it selected when renamed.
+    ChangeProfile {
+        index: usize,
+        profile: ConnectionProfile,
+    },
+    /// Removes the profile at the index and returns to the profile list.
+    DeleteProfile(usize),
+    PruneStaleResources,
+    /// Advances the session timers of places acquired through the UI.
+    SessionTimerTick,
+    /// Fades the highlights of acquired resource changes and removes faded ones.
+    AcquiredResourcesAnimationTick,
+    /// Redraws the timeout countdowns of the reservations.
+    ReservationsCountdownTick,
+    ExportPlaceActivity {
+        place_name: String,
+    },
+    ExportPlaceActivityFailed {
+        err: String,
+    },
+    /// Exports the entries currently displayed in the tab to a file picked in a save-file dialog.
+    ExportTab {
+        kind: ExportKind,
+    },
+    ExportTabFailed {
+        err: String,
+    },
+    /// Saves a snapshot of the configuration of all places to a file picked in a save-file dialog.
+    ExportPlaceSnapshot,
+    /// Reads a place snapshot from a file picked in an open-file dialog and previews restoring it,
+    /// or compares it with the places if `compare` is set.
+    ImportPlaceSnapshot {
+        compare: bool,
+    },
+    PlaceSnapshotImported {
+        file: SnapshotFile,
+        compare: bool,
+    },
+    ImportPlaceSnapshotFailed {
+        err: String,
+    },
     ClipboardCopy(String),
+    /// Runs the quick connect command in the configured terminal emulator.
+    LaunchQuickConnect(QuickConnect),
     SaveConfig,
+    /// Opens the configuration file with the default application of the desktop.
+    OpenConfigFile,
+    /// The configuration file was changed on disk, it is reloaded if it differs from the current settings.
+    ConfigFileChanged,
+    /// Saves the configuration and confirms it in the settings, sent after settings changes.
+    SaveSettings,
+    /// Hides the confirmation of the settings save at the contained time, unless saved again since.
+    HideSettingsSavedConfirmation(Instant),
     CloseLatestWindow,
+    /// Shuts down the connection before closing the window, when connected.
     CloseWindow(window::Id),
+    /// The connection did not report the shutdown in time, the window is closed regardless.
+    ShutdownTimedOut,
     ShowModal(Box<Modal>),
     HideModal,
     WithHideModal(Box<Self>),
     DismissError,
-    ChangeVenvDir { dir: PathBuf },
-    ChangeScriptsDir { dir: PathBuf },
+    ChangeErrorFilter(ErrorFilter),
+    /// Copies the errors matching the filter of the error console.
+    CopyErrors,
+    /// Removes all errors from the error console and the stack.
+    ClearErrors,
+    /// Dismisses the latest error and retries the operation that failed.
+    RetryError,
+    ChangeVenvDir {
+        dir: PathBuf,
+    },
+    ChangeScriptsDir {
+        dir: PathBuf,
+    },
+    /// Picks a path through a native dialog, or the built-in file browser if they are unavailable.
+    PickPath {
+        target: FileBrowserTarget,
+        dir: PathBuf,
+    },
+    FileBrowser(FileBrowserMsg),
+    VenvWizard(VenvWizardMsg),
+    LogViewer(LogViewerMsg),
+    ProtocolInspector(ProtocolInspectorMsg),
+    /// The versions detected in the venv at `dir`, stale results are ignored.
+    VenvVersionsDetected {
+        dir: PathBuf,
+        versions: Result<VenvVersions, String>,
+    },
     ConnectionMsg(ConnectionMsg),
     ConnectionEvent(ConnectionEvent),
     NotConnected(NotConnectedMsg),
@@ -64,6 +376,113 @@ impl AppMsg {
     pub(crate) fn hide_modal(self) -> Self {
         Self::WithHideModal(Box::new(self))
     }
+
+    /// Whether the message changes a setting, after which the configuration is saved immediately.
+    pub(crate) fn changes_settings(&self) -> bool {
+        matches!(
+            self,
+            Self::ChangeLanguage(_)
+                | Self::OptimizeTouch(_)
+                | Self::ChangeUiScale(_)
+                | Self::HighContrast(_)
+                | Self::PersistAcquireHistory(_)
+                | Self::RecordUtilization(_)
+                | Self::ChangeResourceGracePeriod(_)
+                | Self::ChangeOfflineExporterResources(_)
+                | Self::ChangeCardColor { .. }
+                | Self::ChangeIdentityColors(_)
+                | Self::ChangeNotification { .. }
+                | Self::ToggleWatchPlace { .. }
+                | Self::ToggleWatchResource(_)
+                | Self::ChangeIdentityHostname(_)
+                | Self::ChangeIdentityUsername(_)
+                | Self::ChangeTerminalCommand(_)
+                | Self::ChangeQuickAction { .. }
+                | Self::ChangeFavoriteScript(_)
+                | Self::ChangeAutoRelease(_)
+                | Self::ChangeBulkOpsPerSec(_)
+                | Self::ChangeAutosave(_)
+                | Self::ChangeConnectionTimeouts(_)
+                | Self::RetryTransientErrors(_)
+                | Self::ChangeConnectionAuth(_)
+                | Self::CheckScriptImports(_)
+                | Self::ChangeScriptsMaxDepth(_)
+                | Self::ChangeScriptTimeout(_)
+                | Self::ToggleScriptFavorite(_)
+                | Self::SetScriptDisplayName { .. }
+                | Self::MoveScript { .. }
+                | Self::ChangeMqttSettings(_)
+                | Self::ChangeResourceClassMapping { .. }
+                | Self::SelectProfile(_)
+                | Self::AddProfile
+                | Self::ChangeProfile { .. }
+                | Self::DeleteProfile(_)
+                | Self::ChangeVenvDir { .. }
+                | Self::ChangeScriptsDir { .. }
+        )
+    }
+}
+
+/// Message of the built-in file browser.
+#[derive(Debug, Clone)]
+pub(crate) enum FileBrowserMsg {
+    /// The native dialog failed to open, the file browser is used instead from now on.
+    NativeDialogFailed {
+        target: FileBrowserTarget,
+        dir: PathBuf,
+    },
+    /// The directory navigated to as `requested` was listed, `dir` is the directory that was actually listed.
+    Listed {
+        requested: PathBuf,
+        dir: PathBuf,
+        entries: Result<Vec<FileBrowserEntry>, String>,
+    },
+    Navigate(PathBuf),
+    PathInput(String),
+    /// Navigates to the path of the input.
+    SubmitPathInput,
+    /// Selects the file with the name in the displayed directory.
+    Select(String),
+    /// Applies the picked path and closes the file browser.
+    Pick,
+}
+
+/// Message of the wizard creating a virtual environment.
+#[derive(Debug, Clone)]
+pub(crate) enum VenvWizardMsg {
+    UpdateDir(String),
+    UpdateLabgridVersion(String),
+    Start,
+    StepFinished {
+        step: VenvStep,
+        result: Result<String, String>,
+    },
+    /// Uses the created virtual environment for the scripts and returns to the settings.
+    Use,
+}
+
+/// Message of the log viewer.
+#[derive(Debug, Clone)]
+pub(crate) enum LogViewerMsg {
+    /// Takes the recent events from the buffer, periodically sent while the log viewer is shown.
+    Refresh,
+    ChangeLevel(LogLevel),
+    Clear,
+    /// Copies the shown events.
+    Copy,
+}
+
+/// Message of the protocol inspector.
+#[derive(Debug, Clone)]
+pub(crate) enum ProtocolInspectorMsg {
+    /// Takes the recorded messages from the trace, periodically sent while the inspector is shown.
+    Refresh,
+    /// Starts or stops recording the messages.
+    Record(bool),
+    UpdateFilter(String),
+    Clear,
+    /// Copies all recorded messages.
+    Copy,
 }
 
 /// Message when the app is in "not connected" state.
@@ -83,9 +502,74 @@ pub(crate) enum ConnectedMsg {
     ClipboardPasteAddPlaceName,
     ShowResourceDetails(types::Path),
     ResourcesOnlyShowAvailable(bool),
+    /// Switches to the resources tab, only showing the resources of the exporter.
+    ShowExporterResources(String),
+    ClearResourcesExporterFilter,
+    /// Switches to the resources tab, only showing the resources of the class.
+    ShowClassResources(String),
+    UtilizationRangeSelected(UtilizationRange),
+    ClearResourcesClassFilter,
+    /// Only shows the resources of the class picked in the resources tab.
+    ResourcesClassSelected(String),
+    ResourcesSearchInput(String),
     HideResourceDetails(types::Path),
+    /// Fetches a new snapshot of the camera resource.
+    RefreshCameraPreview(types::Path),
+    #[cfg_attr(not(feature = "camera-preview"), allow(dead_code))]
+    CameraPreviewFetched {
+        path: types::Path,
+        result: Result<Vec<u8>, String>,
+    },
+    /// Fills the components of the added match from the pattern.
     UpdateAddPlaceMatchPattern(String),
+    UpdateAddPlaceMatchComponent {
+        component: MatchComponent,
+        value: String,
+    },
+    UpdateAddPlaceMatchRename(String),
+    UpdateAddPlaceAlias(String),
     ClipboardPasteAddPlaceMatchPattern,
+    ToggleResourcesPanel,
+    StartResourceDrag {
+        pattern: String,
+    },
+    EndResourceDrag,
+    DismissPlaceDetailsNotice,
+    DismissAnnouncement,
+    DismissAvailabilityNotice(types::Path),
+    DismissProtocolWarning,
+    ChangePlaceGrouping(PlaceGrouping),
+    /// Collapses or expands the group of places with the tag value, `None` for the untagged places.
+    TogglePlaceGroupCollapsed(Option<String>),
+    /// Collapses or expands the scripts of the subdirectory with the relative path.
+    ToggleScriptDirCollapsed(String),
+    PlacesOnlyMine(bool),
+    /// Shows or hides the reservations of other users in the reservations tab.
+    ReservationsShowForeign(bool),
+    TogglePlacesSelectMode,
+    TogglePlaceSelected {
+        place_name: String,
+    },
+    SelectAllPlaces,
+    ClearPlaceSelection,
+    UpdateBulkPlaceTagText(String),
+    UpdateBulkPlaceTagValueText(String),
+    UpdateForceReleaseConfirmText(String),
+    UpdateCancelReservationConfirmText(String),
+    UpdateAcquireReasonText(String),
+    UpdateScriptDisplayNameText(String),
+    UpdatePlaceCloneName(String),
+    UpdatePlaceCloneAlias {
+        index: usize,
+        alias: String,
+    },
+    UpdatePlaceCloneMatch {
+        index: usize,
+        pattern: String,
+    },
+    UpdateCreateReservationFilter(String),
+    ChangeReservationCleanup(ReservationCleanup),
+    UpdateCreateReservationPrio(String),
     ShowAddPlaceTag {
         place_name: String,
     },
@@ -103,27 +587,91 @@ pub(crate) enum ConnectedMsg {
     ClearAddPlaceTagText {
         place_name: String,
     },
-    OpenChangeScriptsDirDialog {
-        initial_dir: PathBuf,
+    EditPlaceComment {
+        place_name: String,
+    },
+    UpdateEditPlaceCommentText {
+        place_name: String,
+        text: String,
+    },
+    CancelEditPlaceComment {
+        place_name: String,
     },
-    OpenChangeVenvDirFileDialog {
-        initial_dir: PathBuf,
+    SavePlaceComment {
+        place_name: String,
     },
     RescanScriptsDir,
     ExecuteScript {
         script: Script,
     },
+    /// Executes the script with the supplied file name with `LG_PLACE` set to the place.
+    ExecuteScriptOnPlace {
+        script_name: String,
+        place_name: String,
+    },
+    PowerCyclePlace {
+        place_name: String,
+    },
+    /// Restarts the idle duration of the session timer of the place.
+    ExtendSession {
+        place_name: String,
+    },
+    /// Keeps the place acquired, it will not be released automatically.
+    KeepPlace {
+        place_name: String,
+    },
+    PauseBulkPlaceOp(bool),
+    CancelBulkPlaceOp,
+    PowerCyclePlaceFinished {
+        place_name: String,
+        exit_code: i32,
+        stderr: String,
+    },
+    PowerCyclePlaceFailed {
+        place_name: String,
+        err: String,
+    },
     AbortScript,
+    ScriptCleanupFinished {
+        script: Script,
+        exit_code: i32,
+        stdout: String,
+        stderr: String,
+    },
+    ScriptCleanupFailed {
+        script: Script,
+        err: String,
+    },
     ScriptFinished {
         script: Script,
         exit_code: i32,
         stdout: String,
         stderr: String,
     },
+    /// The script was killed after exceeding the timeout, with the output until then.
+    ScriptTimedOut {
+        script: Script,
+        timeout: Duration,
+        stdout: String,
+        stderr: String,
+    },
     ScriptExecutionFailed {
         script: Script,
         err: String,
     },
+    /// Validates the python script without executing it.
+    ValidateScript {
+        script: Script,
+    },
+    /// The script was validated, `errors` is `None` if it is valid.
+    ScriptValidated {
+        script: Script,
+        errors: Option<String>,
+    },
+    ScriptValidationFailed {
+        script: Script,
+        err: String,
+    },
     ScriptsEnvUpdate {
         entry: EnvEntry,
         value: String,
@@ -131,12 +679,32 @@ pub(crate) enum ConnectedMsg {
     ScriptsEnvClear {
         entry: EnvEntry,
     },
-    ScriptsEnvOpenLgEnvFileDialog {
-        initial_file: PathBuf,
-    },
     ScriptOutShow,
     ScriptOutHide,
     ScriptOutClear,
+    /// Updates the filter expression of the reservation template of "Run on next available board".
+    UpdateReservationRunFilter(String),
+    /// Selects the script, by file name, run on the next available board.
+    SelectReservationRunScript(String),
+    /// Reserves a place matching the template and runs the selected script on it once allocated.
+    StartReservationRun,
+    /// Ends the run, aborting the script and releasing the place if it is already running.
+    CancelReservationRun,
+    /// Polls the reservation of the run, so that it does not expire.
+    PollReservationRun,
+    QuickSwitcherInput(String),
+    QuickSwitcherMove {
+        down: bool,
+    },
+    /// Activates the selected entry of the quick switcher.
+    QuickSwitcherSubmit {
+        acquire: bool,
+    },
+    /// Opens the details of a place or acquires it, or runs a script with the current environment.
+    QuickSwitcherActivate {
+        item: QuickSwitcherItem,
+        acquire: bool,
+    },
 }
 
 /// Starts the entire application.
@@ -149,12 +717,23 @@ pub(crate) fn run(args: Args) -> iced::Result {
             args.optimize_touch,
             args.internal_clipboard,
         );
+        app.config_overrides = args.config_overrides();
+        app.theme = args.theme;
+        let exports = metrics::exports(args.metrics_listen, args.metrics_file.clone());
+        let task = if exports.is_empty() {
+            Task::none()
+        } else {
+            let app_metrics = Metrics::new();
+            app.metrics = Some(Arc::clone(&app_metrics));
+            metrics::export_task(&app_metrics, exports)
+        };
 
-        match Config::load_from_path(util::config_path()) {
-            Ok(Some(config)) => app.load_config(config),
+        let config = match Config::load_from_path(util::config_path()) {
+            Ok(Some(config)) => config,
             Ok(None) => {
                 // Save initially
                 app.save_config_to_path();
+                app.extract_config()
             }
             Err(error) => {
                 error!(?error, "Loading configuration from file");
@@ -162,12 +741,46 @@ pub(crate) fn run(args: Args) -> iced::Result {
                     criticality: ErrorCriticality::NonCritical,
                     short: fl!("error-app-config-load"),
                     detailed: format!("{error:?}"),
-                })
+                    retry: None,
+                });
+                app.extract_config()
             }
-        }
+        };
+        // Also applies the overrides of the command line
+        app.load_config(config);
+        // Created after loading the configuration, so that notifications carry the configured identity
+        let task = match Webhooks::new(args.webhook_urls.clone(), app.lg_identity()) {
+            Some((app_webhooks, delivery)) => {
+                app.webhooks = Some(app_webhooks);
+                Task::batch([task, webhooks::delivery_task(delivery)])
+            }
+            None => task,
+        };
+        #[cfg(feature = "rest-gateway")]
+        let task = match args.rest_listen {
+            Some(addr) => {
+                let gateway = RestGateway::new();
+                let serve = gateway.serve_task(addr);
+                app.rest_gateway = Some(gateway);
+                Task::batch([task, serve])
+            }
+            None => task,
+        };
+        let task = Task::batch([task, iced::system::theme().map(AppMsg::SystemThemeChanged)]);
+
+        (app, task)
+    };
 
-        (app, Task::none())
+    let mut window_settings = window::Settings {
+        min_size: Some(Size::new(600., 400.)),
+        ..Default::default()
     };
+    // The window is created before the app is initialized with the configuration
+    if let Ok(Some(config)) = Config::load_from_path(util::config_path()) {
+        if let Some(geometry) = config.view_state.window {
+            geometry.apply(&mut window_settings);
+        }
+    }
 
     iced::application(initialize, App::update, views::view_app)
         .title(App::title)
@@ -175,10 +788,7 @@ pub(crate) fn run(args: Args) -> iced::Result {
             default_font: iced::Font::with_name("Cantarell"),
             ..Default::default()
         })
-        .window(window::Settings {
-            min_size: Some(Size::new(600., 400.)),
-            ..Default::default()
-        })
+        .window(window_settings)
         .subscription(App::subscription)
         // Font loading must come *after* initializing settings
         .font(include_bytes!("../data/fonts/Cantarell-Bold.ttf").as_slice())
@@ -188,7 +798,8 @@ pub(crate) fn run(args: Args) -> iced::Result {
         .font(include_bytes!("../data/fonts/Inconsolata-VariableFont_wdth_wght.ttf").as_slice())
         .font(include_bytes!("../data/fonts/NotoEmoji-VariableFont_wght.ttf").as_slice())
         .font(BOOTSTRAP_FONT_BYTES)
-        //.theme(|_| Theme::Light)
+        .theme(App::theme)
+        .scale_factor(App::scale_factor)
         .antialiasing(true)
         .exit_on_close_request(false)
         .run()
@@ -209,7 +820,7 @@ pub(crate) enum AppState {
 
 /// Different modals that can be displayed in the UI.
 ///
-/// Because this is an enum, only a single modal can be displayed at once.
+/// Only a single modal can be displayed at once, except for confirmations (see [ModalStack]).
 /// This avoids usability challenges/issues that arise when using nested modals.
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Default)]
@@ -217,6 +828,8 @@ pub(crate) enum Modal {
     #[default]
     None,
     Settings,
+    /// Lists all errors reported during the session.
+    ErrorConsole,
     PlaceDetails {
         place_name: String,
     },
@@ -224,6 +837,129 @@ pub(crate) enum Modal {
         msg: String,
         confirm: AppMsg,
     },
+    /// Confirmation for force releasing a place acquired by `owner`, requires typing the place name.
+    ForceRelease {
+        place_name: String,
+        owner: String,
+    },
+    /// Confirmation for cancelling a reservation of another user, requires typing the owner.
+    CancelForeignReservation {
+        token: String,
+        owner: String,
+    },
+    /// Acquires a place with a reason that is displayed to other users.
+    AcquireWithReason {
+        place_name: String,
+    },
+    /// Preview of cloning the place, its state is kept in [AppConnected::place_clone].
+    ClonePlace {
+        place_name: String,
+    },
+    /// Dry run of restoring the snapshot kept in [AppConnected::place_snapshot].
+    RestorePlaces,
+    /// The differences of the places with the snapshot kept in [AppConnected::place_snapshot].
+    ComparePlaces,
+    CreateReservation,
+    /// Summary of the reservations selected for cancellation by the clean up.
+    CleanupReservations,
+    AssignResource {
+        path: types::Path,
+        cls: String,
+    },
+    /// The observed parameters of a resource class and its user mapping.
+    ResourceClass {
+        cls: String,
+    },
+    /// The quick switcher, listing commands as well when opened as command palette.
+    QuickSwitcher {
+        commands: bool,
+    },
+    /// The profiles manager, with the index of the edited profile.
+    Profiles {
+        editing: Option<usize>,
+    },
+    /// The built-in file browser, its state is kept in [App::file_browser].
+    FileBrowser,
+    /// Creates a virtual environment with labgrid, its state is kept in [App::venv_wizard].
+    CreateVenv,
+    /// The recent tracing events, kept in [App::log_entries].
+    LogViewer,
+    /// The messages exchanged with the coordinator, kept in [App::protocol_messages].
+    ///
+    /// Not offered anywhere in the UI, opened with `Ctrl+Shift+I`.
+    ProtocolInspector,
+    /// Changes the displayed name of the script, edited in [AppConnected::script_display_name_text].
+    ScriptDisplayName {
+        script_name: String,
+    },
+    /// The USB devices of the exporter, arranged by the hub ports they are plugged into.
+    UsbTopology {
+        exporter: String,
+    },
+}
+
+impl Modal {
+    pub(crate) fn is_confirmation(&self) -> bool {
+        matches!(
+            self,
+            Self::Confirmation { .. }
+                | Self::ForceRelease { .. }
+                | Self::CancelForeignReservation { .. }
+        )
+    }
+
+    /// Whether the modal is shown on top of the displayed modal.
+    fn is_overlay(&self) -> bool {
+        self.is_confirmation() || matches!(self, Self::FileBrowser)
+    }
+}
+
+/// The displayed modals.
+///
+/// A confirmation or the file browser can be shown on top of another modal, for example to confirm deleting a tag
+/// from within the place details. Deeper nesting is not possible.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModalStack {
+    base: Modal,
+    overlay: Option<Modal>,
+}
+
+impl ModalStack {
+    /// The modal at the bottom of the stack, [Modal::None] when no modal is displayed.
+    pub(crate) fn base(&self) -> &Modal {
+        &self.base
+    }
+
+    /// The confirmation displayed on top of the base modal.
+    pub(crate) fn overlay(&self) -> Option<&Modal> {
+        self.overlay.as_ref()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        matches!(self.base, Modal::None)
+    }
+
+    /// Shows the modal.
+    ///
+    /// Confirmations and the file browser are shown on top of the displayed modal,
+    /// other modals replace all displayed modals.
+    pub(crate) fn show(&mut self, modal: Modal) {
+        let on_top =
+            modal.is_overlay() && !matches!(self.base, Modal::None) && !self.base.is_overlay();
+        if on_top {
+            self.overlay = Some(modal);
+        } else {
+            self.base = modal;
+            self.overlay = None;
+        }
+    }
+
+    /// Hides the topmost modal.
+    pub(crate) fn hide(&mut self) {
+        if self.overlay.take().is_none() {
+            self.base = Modal::None;
+        }
+    }
 }
 
 /// The criticality of of an [ErrorReport].
@@ -251,27 +987,30 @@ pub(crate) struct ErrorReport {
     ///
     /// Often is just the string representation of a emitted error implementing [std::error::Error]
     pub(crate) detailed: String,
+    /// Message that retries the failed operation, rendered as retry button when present.
+    pub(crate) retry: Option<Box<AppMsg>>,
 }
 
 /// Holds the entire app state
 pub(crate) struct App {
     /// The state that is dependent on the status of the connection.
     pub(crate) state: AppState,
-    /// The current displayed modal ([Modal] has variant [Modal::None] when no modal should be displayed).
-    pub(crate) modal: Modal,
+    /// The currently displayed modals.
+    pub(crate) modal: ModalStack,
     /// Optimize the UI for touch input.
     pub(crate) optimize_touch: bool,
+    /// Scale factor of the UI in percent.
+    pub(crate) ui_scale_percent: u32,
+    /// Use the high contrast variant of the theme.
+    pub(crate) high_contrast: bool,
+    /// The color scheme of the system, followed by the high contrast theme when no theme is set.
+    pub(crate) system_theme_mode: theme::Mode,
     /// App clipboard. Needs to be held for the entire duration of the process.
-    pub(crate) clipboard: Option<Clipboard>,
-    /// Determines if a internal clipboard implementation should be used instead of delegating copy/pasting
-    /// to the system clipboard.
-    ///
-    /// Useful when the host does not implement a clipboard (e.g. when running on a kiosk wayland compositor like cage).
-    pub(crate) internal_clipboard: bool,
-    /// The data of the internal clipboard.
     ///
-    /// Only used when `internal_clipboard` is set to `true`.
-    pub(crate) internal_clipboard_buf: String,
+    /// Either the system clipboard or an internal implementation, see [clipboard::new_clipboard].
+    pub(crate) clipboard: Box<dyn ClipboardAccess>,
+    /// Opens the file dialogs, the native dialogs outside of tests.
+    pub(crate) file_dialogs: Arc<dyn FileDialogs>,
     /// The current app language.
     ///
     /// Whenever the language is changed, the [i18n::change_language] routine is called.
@@ -279,7 +1018,7 @@ pub(crate) struct App {
     /// The sender that sends messages to the connection subscription.
     pub(crate) connection_sender: Option<ConnectionSender>,
     /// All current reported errors.
-    pub(crate) errors: Vec<ErrorReport>,
+    pub(crate) errors: ErrorLog,
     /// The current set python virtual environment directory.
     ///
     /// Used when executing scripts in the UI scripts tab.
@@ -288,22 +1027,179 @@ pub(crate) struct App {
     ///
     /// Used for listing scripts in the UI scripts tab.
     pub(crate) scripts_dir: PathBuf,
+    /// Depth of the subdirectories of the scripts directory that are scanned for scripts.
+    pub(crate) scripts_max_depth: u32,
+    /// Minutes after which scripts that don't declare a timeout are killed, `0` disables it.
+    pub(crate) script_timeout_mins: u32,
+    /// Favorites, display names and the manual order of the scripts.
+    pub(crate) script_meta: ScriptMetaSettings,
+    /// Whether validating scripts also checks that their labgrid imports resolve.
+    pub(crate) check_script_imports: bool,
+    /// The observed acquisitions of resources.
+    ///
+    /// Kept across reconnects and persisted in the configuration if `persist_acquire_history` is set.
+    pub(crate) acquire_history: AcquireHistory,
+    pub(crate) persist_acquire_history: bool,
+    /// The observed state transitions of places, kept across reconnects.
+    pub(crate) place_activity: PlaceActivityLog,
+    /// The observed acquisitions and releases of places, stored on disk if `record_utilization` is set.
+    pub(crate) utilization: UtilizationLog,
+    pub(crate) record_utilization: bool,
+    /// Seconds resources that were deleted by the coordinator are kept and marked as stale,
+    /// so that brief exporter restarts don't make them vanish.
+    pub(crate) resource_grace_period_secs: u32,
+    pub(crate) offline_exporter_resources: OfflineExporterResources,
+    /// The colors of the place cards, depending on the place's state.
+    pub(crate) card_palette: CardPalette,
+    /// For which events desktop notifications are sent.
+    pub(crate) notification_settings: NotificationSettings,
+    /// Names of the places for which a notification is sent when they are released.
+    pub(crate) watched_places: BTreeSet<String>,
+    /// Paths of the resources whose availability changes are shown in a banner and notified,
+    /// see [ResourceWatch::key].
+    pub(crate) watched_resources: BTreeSet<String>,
+    /// The buttons shown directly on the place cards.
+    pub(crate) quick_actions: QuickActionSettings,
+    /// Applied on the next (re)connect.
+    pub(crate) identity_override: IdentityOverride,
+    /// The terminal emulator quick connect commands are launched in, empty if none is configured.
+    pub(crate) terminal_command: String,
+    pub(crate) auto_release: AutoReleaseSettings,
+    pub(crate) bulk_ops_per_sec: u32,
+    /// Seconds between periodic saves of the configuration, `0` disables them.
+    pub(crate) autosave_secs: u32,
+    /// When the configuration was last saved successfully.
+    pub(crate) last_config_save: Option<DateTime<Local>>,
+    /// When the settings were saved after a change, while the confirmation is shown.
+    pub(crate) settings_saved_confirmation: Option<Instant>,
+    pub(crate) connection_timeouts: ConnectionTimeouts,
+    pub(crate) retry_transient_errors: bool,
+    pub(crate) connection_auth: ConnectionAuth,
+    pub(crate) mqtt_settings: MqttSettings,
+    /// The settings and labgrid identity the MQTT bridge runs with, `None` when it is disabled.
+    pub(crate) mqtt_applied: Option<(MqttSettings, String)>,
+    /// Publishes the state changes, present once the bridge started.
+    pub(crate) mqtt: Option<MqttBridge>,
+    pub(crate) mqtt_status: Option<MqttStatus>,
+    pub(crate) resource_classes: ResourceClassRegistry,
+    pub(crate) profiles: Vec<ConnectionProfile>,
+    /// The name of the profile used for connecting.
+    pub(crate) selected_profile: Option<String>,
+    /// The window that is closed once the connection is shut down.
+    pub(crate) closing_window: Option<window::Id>,
+    /// The recorded operational metrics, only present if they are exported.
+    pub(crate) metrics: Option<Arc<Metrics>>,
+    /// Notifies webhooks about coordinator events, only present if webhook URLs were supplied.
+    pub(crate) webhooks: Option<Webhooks>,
+    /// Serves the coordinator state as JSON, only present if it was requested on the command line.
+    #[cfg(feature = "rest-gateway")]
+    pub(crate) rest_gateway: Option<RestGateway>,
+    /// Only used by the headless mode, kept to preserve them when saving the configuration.
+    pub(crate) headless_jobs: Vec<HeadlessJob>,
+    /// The state of the built-in file browser, while it is shown.
+    pub(crate) file_browser: Option<FileBrowser>,
+    pub(crate) venv_wizard: VenvWizard,
+    /// The versions detected in [App::venv_dir], `None` while detecting or before the settings were shown.
+    pub(crate) venv_versions: Option<Result<VenvVersions, String>>,
+    /// The events of the log viewer, taken from [log_viewer::LOG_BUFFER] while it is shown.
+    pub(crate) log_entries: Vec<LogEntry>,
+    /// The most verbose level of the events that are recorded and shown in the log viewer.
+    pub(crate) log_level: LogLevel,
+    /// The messages of the protocol inspector, taken from [protocol_inspector::PROTOCOL_TRACE] while it is shown.
+    pub(crate) protocol_messages: Vec<TracedMessage>,
+    pub(crate) protocol_filter: String,
+    /// Paths are picked through the built-in file browser instead of native dialogs.
+    pub(crate) native_dialogs_unavailable: bool,
+    /// The settings given on the command line, applied on top of the loaded configuration.
+    pub(crate) config_overrides: ConfigOverrides,
+    /// The values of the configuration file replaced by [App::config_overrides], which are saved instead of them.
+    pub(crate) overridden_config: ConfigOverrides,
+    pub(crate) theme: Option<AppTheme>,
+    /// The state of the views restored when connecting, kept up to date while connected when it is saved.
+    pub(crate) view_state: ViewState,
 }
 
 impl std::fmt::Debug for App {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("App")
+        let mut debug = f.debug_struct("App");
+        debug
             .field("state", &self.state)
             .field("modal", &self.modal)
             .field("optimize_touch", &self.optimize_touch)
+            .field("ui_scale_percent", &self.ui_scale_percent)
+            .field("high_contrast", &self.high_contrast)
+            .field("system_theme_mode", &self.system_theme_mode)
             .field("clipboard", &".. no debug impl ..")
-            .field("internal_clipboard", &self.internal_clipboard)
-            .field("internal_clipboard_buf", &self.internal_clipboard_buf)
+            .field("file_dialogs", &self.file_dialogs)
             .field("language", &self.language)
             .field("connection_sender", &self.connection_sender)
             .field("errors", &self.errors)
             .field("venv_dir", &self.venv_dir)
             .field("scripts_dir", &self.scripts_dir)
+            .field("scripts_max_depth", &self.scripts_max_depth)
+            .field("script_timeout_mins", &self.script_timeout_mins)
+            .field("script_meta", &self.script_meta)
+            .field("check_script_imports", &self.check_script_imports)
+            .field("acquire_history", &self.acquire_history)
+            .field("persist_acquire_history", &self.persist_acquire_history)
+            .field("place_activity", &self.place_activity)
+            .field("utilization", &self.utilization)
+            .field("record_utilization", &self.record_utilization)
+            .field(
+                "resource_grace_period_secs",
+                &self.resource_grace_period_secs,
+            )
+            .field(
+                "offline_exporter_resources",
+                &self.offline_exporter_resources,
+            )
+            .field("card_palette", &self.card_palette)
+            .field("notification_settings", &self.notification_settings)
+            .field("watched_places", &self.watched_places)
+            .field("watched_resources", &self.watched_resources)
+            .field("quick_actions", &self.quick_actions)
+            .field("auto_release", &self.auto_release)
+            .field("bulk_ops_per_sec", &self.bulk_ops_per_sec)
+            .field("autosave_secs", &self.autosave_secs)
+            .field("last_config_save", &self.last_config_save)
+            .field(
+                "settings_saved_confirmation",
+                &self.settings_saved_confirmation,
+            )
+            .field("connection_timeouts", &self.connection_timeouts)
+            .field("retry_transient_errors", &self.retry_transient_errors)
+            .field("connection_auth", &self.connection_auth)
+            .field("mqtt_settings", &self.mqtt_settings)
+            .field("mqtt_applied", &self.mqtt_applied)
+            .field("mqtt", &self.mqtt)
+            .field("mqtt_status", &self.mqtt_status)
+            .field("resource_classes", &self.resource_classes)
+            .field("profiles", &self.profiles)
+            .field("selected_profile", &self.selected_profile)
+            .field("identity_override", &self.identity_override)
+            .field("terminal_command", &self.terminal_command)
+            .field("closing_window", &self.closing_window)
+            .field("metrics", &self.metrics)
+            .field("webhooks", &self.webhooks);
+        #[cfg(feature = "rest-gateway")]
+        debug.field("rest_gateway", &self.rest_gateway);
+        debug
+            .field("headless_jobs", &self.headless_jobs)
+            .field("file_browser", &self.file_browser)
+            .field("venv_wizard", &self.venv_wizard)
+            .field("venv_versions", &self.venv_versions)
+            .field("log_entries", &self.log_entries.len())
+            .field("protocol_messages", &self.protocol_messages.len())
+            .field("protocol_filter", &self.protocol_filter)
+            .field("log_level", &self.log_level)
+            .field(
+                "native_dialogs_unavailable",
+                &self.native_dialogs_unavailable,
+            )
+            .field("config_overrides", &self.config_overrides)
+            .field("overridden_config", &self.overridden_config)
+            .field("theme", &self.theme)
+            .field("view_state", &self.view_state)
             .finish()
     }
 }
@@ -324,27 +1220,96 @@ impl App {
         if let Err(err) = util::ensure_app_default_dirs() {
             error!(?err, "Ensure existance of app default dirs");
         };
-        let clipboard = if internal_clipboard {
-            None
-        } else {
-            Clipboard::new().ok()
-        };
+        let mut app = Self::with_side_effects(
+            coordinator_address,
+            optimize_touch,
+            clipboard::new_clipboard(internal_clipboard),
+            Arc::new(NativeFileDialogs),
+        );
+        app.native_dialogs_unavailable = !file_browser::native_dialog_available();
+        app
+    }
 
+    /// Create a new application with the supplied clipboard and file dialogs.
+    ///
+    /// Unlike [App::new], the app directories are not created and the availability of native dialogs is not probed.
+    /// Messages are handled by [App::update] without side effects other than through the clipboard,
+    /// the connection sender and the returned tasks, which open file dialogs only through `file_dialogs`.
+    /// Exceptions are messages that explicitly work with files or the language, like [AppMsg::SaveConfig].
+    pub(crate) fn with_side_effects(
+        coordinator_address: Option<String>,
+        optimize_touch: bool,
+        clipboard: Box<dyn ClipboardAccess>,
+        file_dialogs: Arc<dyn FileDialogs>,
+    ) -> Self {
         Self {
             state: AppState::NotConnected(AppNotConnected {
                 input_address: coordinator_address.unwrap_or_default(),
             }),
-            language: AppLanguage::try_from(i18n::current_language())
-                .expect("Loaded language is not a variant of 'AppLanguage'"),
-            modal: Modal::None,
+            // Languages of the user locale directory might not be a variant
+            language: AppLanguage::try_from(i18n::current_language()).unwrap_or_default(),
+            modal: ModalStack::default(),
             optimize_touch,
+            ui_scale_percent: 100,
+            high_contrast: false,
+            system_theme_mode: theme::Mode::None,
             clipboard,
-            internal_clipboard,
-            internal_clipboard_buf: String::default(),
+            file_dialogs,
             connection_sender: None,
-            errors: Vec::default(),
+            errors: ErrorLog::default(),
             venv_dir: util::default_venv_dir(),
             scripts_dir: util::default_scripts_dir(),
+            scripts_max_depth: scripts::DEFAULT_MAX_DEPTH,
+            script_timeout_mins: 0,
+            script_meta: ScriptMetaSettings::default(),
+            check_script_imports: true,
+            acquire_history: AcquireHistory::default(),
+            persist_acquire_history: false,
+            place_activity: PlaceActivityLog::default(),
+            utilization: UtilizationLog::default(),
+            record_utilization: false,
+            resource_grace_period_secs: config::DEFAULT_RESOURCE_GRACE_PERIOD_SECS,
+            offline_exporter_resources: OfflineExporterResources::default(),
+            card_palette: CardPalette::default(),
+            notification_settings: NotificationSettings::default(),
+            watched_places: BTreeSet::default(),
+            watched_resources: BTreeSet::default(),
+            quick_actions: QuickActionSettings::default(),
+            identity_override: IdentityOverride::default(),
+            terminal_command: String::new(),
+            auto_release: AutoReleaseSettings::default(),
+            bulk_ops_per_sec: config::DEFAULT_BULK_OPS_PER_SEC,
+            autosave_secs: config::DEFAULT_AUTOSAVE_SECS,
+            last_config_save: None,
+            settings_saved_confirmation: None,
+            connection_timeouts: ConnectionTimeouts::default(),
+            retry_transient_errors: true,
+            connection_auth: ConnectionAuth::default(),
+            mqtt_settings: MqttSettings::default(),
+            mqtt_applied: None,
+            mqtt: None,
+            mqtt_status: None,
+            resource_classes: ResourceClassRegistry::default(),
+            profiles: Vec::default(),
+            selected_profile: None,
+            closing_window: None,
+            metrics: None,
+            webhooks: None,
+            #[cfg(feature = "rest-gateway")]
+            rest_gateway: None,
+            headless_jobs: Vec::default(),
+            file_browser: None,
+            venv_wizard: VenvWizard::default(),
+            venv_versions: None,
+            log_entries: Vec::new(),
+            protocol_messages: Vec::new(),
+            protocol_filter: String::new(),
+            log_level: log_viewer::LOG_BUFFER.level(),
+            native_dialogs_unavailable: false,
+            config_overrides: ConfigOverrides::default(),
+            overridden_config: ConfigOverrides::default(),
+            theme: None,
+            view_state: ViewState::default(),
         }
     }
 
@@ -353,12 +1318,133 @@ impl App {
         fl!("app-title")
     }
 
+    /// Returns the theme of the command line, `None` follows the system color scheme.
+    ///
+    /// The high contrast variant is dark when the system color scheme is unknown.
+    fn theme(&self) -> Option<Theme> {
+        if self.high_contrast {
+            let dark = match self.theme {
+                Some(theme) => theme == AppTheme::Dark,
+                None => self.system_theme_mode != theme::Mode::Light,
+            };
+            return Some(palette::high_contrast_theme(dark));
+        }
+        self.theme.map(|theme| match theme {
+            AppTheme::Light => Theme::Light,
+            AppTheme::Dark => Theme::Dark,
+        })
+    }
+
+    /// Returns the scale factor of the UI.
+    fn scale_factor(&self) -> f32 {
+        self.ui_scale_percent as f32 / 100.
+    }
+
     /// Returns all joined subscription.
     fn subscription(&self) -> Subscription<AppMsg> {
         let subscriptions = [
             Subscription::run(connection::kickoff).map(AppMsg::ConnectionEvent),
-            Subscription::run(config::periodic_save_subscription),
+            if self.autosave_secs > 0 {
+                Subscription::run_with(self.autosave_secs, config::periodic_save_subscription)
+            } else {
+                Subscription::none()
+            },
+            Subscription::run(config::periodic_prune_stale_resources_subscription),
+            Subscription::run(config::watch_subscription),
+            event::listen_with(|event, _status, _id| match event {
+                Event::Window(window::Event::Resized(size)) => Some(AppMsg::WindowResized(size)),
+                Event::Window(window::Event::Moved(position)) => {
+                    Some(AppMsg::WindowMoved(position))
+                }
+                _ => None,
+            }),
+            if self.high_contrast && self.theme.is_none() {
+                iced::system::theme_changes().map(AppMsg::SystemThemeChanged)
+            } else {
+                Subscription::none()
+            },
+            match &self.mqtt_applied {
+                Some(target) => Subscription::run_with(target.clone(), mqtt::bridge_subscription),
+                None => Subscription::none(),
+            },
+            match &self.state {
+                AppState::Connected(AppConnected {
+                    reservation_run: Some(run),
+                    ..
+                }) if run.token().is_some() => {
+                    Subscription::run(reservation_runner::poll_subscription)
+                }
+                _ => Subscription::none(),
+            },
+            match &self.state {
+                AppState::Connected(connected) => Subscription::run_with(
+                    (connected.scripts.dir(), connected.scripts.max_depth),
+                    scripts::watch_subscription,
+                ),
+                _ => Subscription::none(),
+            },
+            match &self.state {
+                AppState::Connected(connected) if !connected.session_timers.is_empty() => {
+                    Subscription::run(session_timer::periodic_tick_subscription)
+                }
+                _ => Subscription::none(),
+            },
+            match &self.state {
+                AppState::Connected(connected)
+                    if connected
+                        .places
+                        .iter()
+                        .any(|(_, ui)| !ui.acquired_resource_changes.is_empty()) =>
+                {
+                    Subscription::run(acquired_resources::animation_tick_subscription)
+                }
+                _ => Subscription::none(),
+            },
+            match &self.state {
+                AppState::Connected(connected)
+                    if connected.active_tab == TabId::Reservations
+                        && connected.reservations.iter().any(|r| !r.is_stale()) =>
+                {
+                    Subscription::run(queue::countdown_tick_subscription)
+                }
+                _ => Subscription::none(),
+            },
             window::close_requests().map(AppMsg::CloseWindow),
+            match &self.state {
+                AppState::Connected(_) => keyboard::listen().filter_map(shortcuts::open_shortcuts),
+                _ => Subscription::none(),
+            },
+            match &self.state {
+                AppState::Connected(_) if self.modal.is_empty() => {
+                    keyboard::listen().filter_map(shortcuts::connected_shortcuts)
+                }
+                _ => Subscription::none(),
+            },
+            match self.modal.base() {
+                Modal::LogViewer => Subscription::run(log_viewer::refresh_subscription),
+                Modal::ProtocolInspector => {
+                    Subscription::run(protocol_inspector::refresh_subscription)
+                }
+                _ => Subscription::none(),
+            },
+            keyboard::listen().filter_map(shortcuts::protocol_inspector_shortcut),
+            match self.modal.base() {
+                Modal::QuickSwitcher { .. } if self.modal.overlay().is_none() => {
+                    keyboard::listen().filter_map(quick_switcher::navigation_keys)
+                }
+                _ => Subscription::none(),
+            },
+            if self.modal.is_empty() {
+                Subscription::none()
+            } else {
+                keyboard::listen().filter_map(|event| match event {
+                    keyboard::Event::KeyPressed {
+                        key: keyboard::Key::Named(keyboard::key::Named::Escape),
+                        ..
+                    } => Some(AppMsg::HideModal),
+                    _ => None,
+                })
+            },
         ];
         Subscription::batch(subscriptions)
     }
@@ -366,89 +1452,765 @@ impl App {
     /// Handle received app messages through iced's message passing.
     fn update(&mut self, msg: AppMsg) -> Task<AppMsg> {
         debug!(?msg, "App UI update");
+        let changes_settings = msg.changes_settings();
+        if let (Some(metrics), AppMsg::ConnectionEvent(event)) = (&self.metrics, &msg) {
+            metrics::record_event(metrics, &self.state, event);
+        }
+        if let (Some(webhooks), AppMsg::ConnectionEvent(event)) = (&mut self.webhooks, &msg) {
+            webhooks.record_event(event);
+        }
+        if let (Some(mqtt), AppMsg::ConnectionEvent(event)) = (&mut self.mqtt, &msg) {
+            mqtt.record_event(event);
+        }
+        #[cfg(feature = "rest-gateway")]
+        if let (Some(gateway), AppMsg::ConnectionEvent(event)) = (&self.rest_gateway, &msg) {
+            gateway.record_event(event);
+        }
+        // Advanced once the event updated the connected state
+        let reservation_run_event = match (&self.state, &msg) {
+            (
+                AppState::Connected(AppConnected {
+                    reservation_run: Some(_),
+                    ..
+                }),
+                AppMsg::ConnectionEvent(
+                    event @ (ConnectionEvent::ReservationCreated(_)
+                    | ConnectionEvent::Reservations(_)
+                    | ConnectionEvent::Place(_)),
+                ),
+            ) => Some(event.clone()),
+            _ => None,
+        };
 
         let (new_state, task): (Option<AppState>, Task<AppMsg>) = match msg {
             AppMsg::None => (None, Task::none()),
             AppMsg::ChangeLanguage(language) => {
-                if self.language != language {
-                    match i18n::change_language(language.into()) {
-                        Ok(_) => {
-                            self.language = language;
-                        }
-                        Err(error) => error!(?error, ?language, "Change language"),
-                    }
-                }
+                self.set_language(language);
                 (None, Task::none())
             }
             AppMsg::OptimizeTouch(optimize_touch) => {
                 self.optimize_touch = optimize_touch;
                 (None, Task::none())
             }
-            AppMsg::ClipboardCopy(content) => {
-                if let Err(e) = set_clipboard_text(
-                    &mut self.clipboard,
-                    self.internal_clipboard,
-                    &mut self.internal_clipboard_buf,
-                    content,
-                ) {
-                    error!("Set clipboard content, Err: {e:?}");
-                    self.errors.push(ErrorReport {
-                        criticality: ErrorCriticality::NonCritical,
-                        short: "Set clipboard content".to_string(),
-                        detailed: format!("{e:?}"),
-                    });
-                }
+            AppMsg::ChangeUiScale(percent) => {
+                self.ui_scale_percent = percent;
                 (None, Task::none())
             }
-            AppMsg::SaveConfig => {
-                self.save_config_to_path();
+            AppMsg::HighContrast(high_contrast) => {
+                self.high_contrast = high_contrast;
                 (None, Task::none())
             }
-            AppMsg::CloseLatestWindow => {
-                self.save_config_to_path();
-                (None, window::latest().and_then(window::close))
+            AppMsg::SystemThemeChanged(mode) => {
+                self.system_theme_mode = mode;
+                (None, Task::none())
             }
-            AppMsg::CloseWindow(id) => {
-                self.save_config_to_path();
-                (None, window::close(id))
+            AppMsg::WindowResized(size) => {
+                let geometry = self.view_state.window.get_or_insert(WindowGeometry {
+                    width: size.width,
+                    height: size.height,
+                    position: None,
+                });
+                geometry.width = size.width;
+                geometry.height = size.height;
+                (None, Task::none())
             }
-            AppMsg::ShowModal(modal) => {
-                self.modal = *modal;
+            AppMsg::WindowMoved(position) => {
+                if let Some(geometry) = &mut self.view_state.window {
+                    geometry.position = Some((position.x, position.y));
+                }
                 (None, Task::none())
             }
-            AppMsg::HideModal => {
-                self.modal = Modal::None;
+            AppMsg::PersistAcquireHistory(persist) => {
+                self.persist_acquire_history = persist;
                 (None, Task::none())
             }
-            AppMsg::WithHideModal(msg) => {
-                // Recursing like that is not the most awesome pattern, but eh it works
-                self.modal = Modal::None;
-                (None, self.update(*msg))
+            AppMsg::RecordUtilization(record) => {
+                self.set_record_utilization(record);
+                (None, Task::none())
             }
-            AppMsg::DismissError => {
-                self.errors.pop();
+            AppMsg::ChangeResourceGracePeriod(secs) => {
+                self.resource_grace_period_secs = secs;
                 (None, Task::none())
             }
-            AppMsg::ChangeVenvDir { dir } => {
-                match scripts::validate_venv_dir(&dir) {
-                    Ok(()) => self.venv_dir = dir,
-                    Err(err) => {
-                        error!(
-                            ?err,
-                            "Validation while attempting to change labgrid venv dir failed"
-                        );
-                        self.errors.push(ErrorReport {
-                            criticality: ErrorCriticality::NonCritical,
-                            short: fl!("error-invalid-path"),
-                            detailed: format!("Invalid labgrid venv path: '{}'", dir.display()),
-                        });
-                    }
-                }
+            AppMsg::ChangeOfflineExporterResources(display) => {
+                self.offline_exporter_resources = display;
                 (None, Task::none())
             }
+            AppMsg::ChangeCardColor { state, hex } => {
+                self.card_palette.set_hex(state, hex);
+                (None, Task::none())
+            }
+            AppMsg::ChangeIdentityColors(enabled) => {
+                self.card_palette.identity_colors = enabled;
+                (None, Task::none())
+            }
+            AppMsg::ChangeNotification { event, enabled } => {
+                self.notification_settings.set_enabled(event, enabled);
+                (None, Task::none())
+            }
+            AppMsg::ToggleWatchPlace { place_name } => {
+                if !self.watched_places.remove(&place_name) {
+                    self.watched_places.insert(place_name);
+                }
+                (None, Task::none())
+            }
+            AppMsg::ToggleWatchResource(path) => {
+                let key = ResourceWatch::key(&path);
+                if !self.watched_resources.remove(&key) {
+                    self.watched_resources.insert(key);
+                }
+                (None, Task::none())
+            }
+            AppMsg::ChangeIdentityHostname(hostname) => {
+                self.identity_override.hostname = hostname;
+                (None, Task::none())
+            }
+            AppMsg::ChangeIdentityUsername(username) => {
+                self.identity_override.username = username;
+                (None, Task::none())
+            }
+            AppMsg::ChangeTerminalCommand(command) => {
+                self.terminal_command = command;
+                (None, Task::none())
+            }
+            #[cfg(feature = "rest-gateway")]
+            AppMsg::RestGatewayFailed(detailed) => {
+                error!(detailed, "Serving the REST gateway");
+                self.errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("error-rest-gateway"),
+                    detailed,
+                    retry: None,
+                });
+                (None, Task::none())
+            }
+            AppMsg::MetricsExportFailed(detailed) => {
+                error!(detailed, "Exporting metrics");
+                self.errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("error-metrics-export"),
+                    detailed,
+                    retry: None,
+                });
+                (None, Task::none())
+            }
+            AppMsg::Reconnect => {
+                if let AppState::Connected(connected) = &self.state {
+                    let address = connected.address.clone();
+                    let msg = connect_msg(
+                        address.clone(),
+                        &self.identity_override,
+                        self.selected_profile(),
+                        self.connection_timeouts,
+                        self.retry_transient_errors,
+                        &self.connection_auth,
+                    );
+                    send_connection_msg(&mut self.connection_sender, msg);
+                    (Some(AppState::Connecting { address }), Task::none())
+                } else {
+                    (None, Task::none())
+                }
+            }
+            AppMsg::ChangeQuickAction { action, enabled } => {
+                self.quick_actions.set_enabled(action, enabled);
+                (None, Task::none())
+            }
+            AppMsg::ChangeFavoriteScript(script_name) => {
+                self.quick_actions.favorite_script_name = Some(script_name);
+                (None, Task::none())
+            }
+            AppMsg::ChangeAutoRelease(settings) => {
+                self.auto_release = settings;
+                (None, Task::none())
+            }
+            AppMsg::ChangeBulkOpsPerSec(ops_per_sec) => {
+                self.bulk_ops_per_sec = ops_per_sec;
+                if let Some(sender) = &self.connection_sender {
+                    sender.bulk_op_control().set_ops_per_sec(ops_per_sec);
+                }
+                (None, Task::none())
+            }
+            AppMsg::ChangeAutosave(secs) => {
+                self.autosave_secs = secs;
+                (None, Task::none())
+            }
+            AppMsg::ChangeConnectionTimeouts(timeouts) => {
+                self.connection_timeouts = timeouts;
+                (None, Task::none())
+            }
+            AppMsg::RetryTransientErrors(retry) => {
+                self.retry_transient_errors = retry;
+                (None, Task::none())
+            }
+            AppMsg::ChangeConnectionAuth(auth) => {
+                self.connection_auth = auth;
+                (None, Task::none())
+            }
+            AppMsg::CheckScriptImports(check) => {
+                self.check_script_imports = check;
+                (None, Task::none())
+            }
+            AppMsg::ChangeScriptsMaxDepth(max_depth) => {
+                self.scripts_max_depth = max_depth;
+                let task = if let AppState::Connected(connected) = &mut self.state {
+                    connected.scripts.max_depth = max_depth;
+                    Task::done(AppMsg::Connected(ConnectedMsg::RescanScriptsDir))
+                } else {
+                    Task::none()
+                };
+                (None, task)
+            }
+            AppMsg::ChangeScriptTimeout(mins) => {
+                self.script_timeout_mins = mins;
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.scripts.default_timeout = scripts::default_timeout(mins);
+                }
+                (None, Task::none())
+            }
+            AppMsg::ToggleScriptFavorite(name) => {
+                self.script_meta.toggle_favorite(&name);
+                (None, Task::none())
+            }
+            AppMsg::SetScriptDisplayName { name, display_name } => {
+                self.script_meta.set_display_name(&name, &display_name);
+                (None, Task::none())
+            }
+            AppMsg::MoveScript { name, up } => {
+                if let AppState::Connected(connected) = &self.state {
+                    self.script_meta
+                        .move_script(&connected.scripts.scripts, &name, up);
+                }
+                (None, Task::none())
+            }
+            AppMsg::ChangeMqttSettings(settings) => {
+                let toggled = settings.enabled != self.mqtt_settings.enabled;
+                self.mqtt_settings = settings;
+                if toggled {
+                    self.apply_mqtt_settings();
+                }
+                (None, Task::none())
+            }
+            AppMsg::ApplyMqttSettings => {
+                self.apply_mqtt_settings();
+                (None, Task::none())
+            }
+            AppMsg::MqttBridgeReady(mut bridge) => {
+                if let AppState::Connected(connected) = &self.state {
+                    bridge.publish_state(
+                        connected.places.iter().map(|(place, _)| place),
+                        connected
+                            .resources
+                            .iter()
+                            .filter(|(_, ui)| ui.stale_since.is_none())
+                            .map(|(resource, _)| resource),
+                    );
+                }
+                self.mqtt = Some(bridge);
+                (None, Task::none())
+            }
+            AppMsg::MqttStatus(status) => {
+                self.mqtt_status = Some(status);
+                (None, Task::none())
+            }
+            AppMsg::ChangeResourceClassMapping { cls, mapping } => {
+                self.resource_classes.set(cls, mapping);
+                (None, Task::none())
+            }
+            AppMsg::SelectProfile(name) => {
+                self.selected_profile = name;
+                let address = self.selected_profile().map(|p| p.address.clone());
+                if let (AppState::NotConnected(not_connected), Some(address)) =
+                    (&mut self.state, address)
+                {
+                    not_connected.input_address = address;
+                }
+                (None, Task::none())
+            }
+            AppMsg::AddProfile => {
+                let address = match &self.state {
+                    AppState::NotConnected(not_connected) => not_connected.input_address.clone(),
+                    AppState::Connecting { address } => address.clone(),
+                    AppState::Connected(connected) => connected.address.clone(),
+                };
+                self.profiles.push(ConnectionProfile {
+                    name: profiles::unique_name(&self.profiles, &fl!("profile-default-name")),
+                    address,
+                    ..ConnectionProfile::default()
+                });
+                self.modal.show(Modal::Profiles {
+                    editing: Some(self.profiles.len() - 1),
+                });
+                (None, Task::none())
+            }
+            AppMsg::ChangeProfile { index, profile } => {
+                if let Some(old) = self.profiles.get_mut(index) {
+                    if self.selected_profile.as_ref() == Some(&old.name) {
+                        self.selected_profile = Some(profile.name.clone());
+                    }
+                    *old = profile;
+                }
+                (None, Task::none())
+            }
+            AppMsg::DeleteProfile(index) => {
+                if index < self.profiles.len() {
+                    let removed = self.profiles.remove(index);
+                    if self.selected_profile.as_ref() == Some(&removed.name) {
+                        self.selected_profile = None;
+                    }
+                }
+                self.modal.show(Modal::Profiles { editing: None });
+                (None, Task::none())
+            }
+            AppMsg::AcquiredResourcesAnimationTick => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    let now = Instant::now();
+                    for (_, ui) in &mut connected.places {
+                        ui.acquired_resource_changes.prune(now);
+                    }
+                }
+                (None, Task::none())
+            }
+            // Nothing to update, the countdowns are derived from the current time when viewed
+            AppMsg::ReservationsCountdownTick => (None, Task::none()),
+            AppMsg::SessionTimerTick => {
+                let mut tasks = Vec::new();
+                if let AppState::Connected(connected) = &mut self.state {
+                    let now = Instant::now();
+                    for (place_name, action) in
+                        session_timer::tick(&mut connected.session_timers, &self.auto_release, now)
+                    {
+                        match action {
+                            SessionTimerAction::Warn { remaining } => {
+                                tasks.push(notifications::notify(
+                                    &self.notification_settings,
+                                    NotificationEvent::AutoReleaseWarning,
+                                    fl!("notification-auto-release-warning-summary"),
+                                    fl!(
+                                        "notification-auto-release-warning-body",
+                                        place = place_name.as_str(),
+                                        minutes = remaining.as_secs().div_ceil(60)
+                                    ),
+                                ));
+                            }
+                            SessionTimerAction::Release => {
+                                info!(place_name, "Releasing idle place");
+                                let msg = ConnectionMsg::ReleasePlace { name: place_name };
+                                connected.last_own_place_action = Some((msg.clone(), now));
+                                send_connection_msg(&mut self.connection_sender, msg);
+                            }
+                        }
+                    }
+                }
+                (None, Task::batch(tasks))
+            }
+            AppMsg::ExportPlaceActivity { place_name } => {
+                let activity = self.place_activity.export(&place_name);
+                let dialog = self.file_dialogs.open(
+                    FileDialogKind::SaveFile,
+                    FileDialogRequest::new().file_name(format!("{place_name}-activity.txt")),
+                );
+                let task = Task::perform(
+                    async move {
+                        let Some(path) = dialog.await else {
+                            return Ok(());
+                        };
+                        tokio::fs::write(path, activity)
+                            .await
+                            .map_err(|e| format!("{e:?}"))
+                    },
+                    |res| match res {
+                        Ok(()) => AppMsg::None,
+                        Err(err) => AppMsg::ExportPlaceActivityFailed { err },
+                    },
+                );
+                (None, task)
+            }
+            AppMsg::ExportPlaceActivityFailed { err } => {
+                error!(?err, "Export place activity");
+                self.errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("place-activity-export-failed"),
+                    detailed: err,
+                    retry: None,
+                });
+                (None, Task::none())
+            }
+            AppMsg::ExportTab { kind } => {
+                if let AppState::Connected(connected) = &self.state {
+                    let table = connected.export_table(kind);
+                    let dialog = self.file_dialogs.open(
+                        FileDialogKind::SaveFile,
+                        FileDialogRequest::new()
+                            .file_name(format!("{}.csv", kind.file_stem()))
+                            .filter("CSV", &["csv"])
+                            .filter("JSON", &["json"]),
+                    );
+                    let task = Task::perform(
+                        async move {
+                            let Some(path) = dialog.await else {
+                                return Ok(());
+                            };
+                            let content = table.serialize(ExportFormat::from_path(&path));
+                            tokio::fs::write(path, content)
+                                .await
+                                .map_err(|e| format!("{e:?}"))
+                        },
+                        |res| match res {
+                            Ok(()) => AppMsg::None,
+                            Err(err) => AppMsg::ExportTabFailed { err },
+                        },
+                    );
+                    (None, task)
+                } else {
+                    (None, Task::none())
+                }
+            }
+            AppMsg::ExportTabFailed { err } => {
+                error!(?err, "Export tab");
+                self.errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("export-failed"),
+                    detailed: err,
+                    retry: None,
+                });
+                (None, Task::none())
+            }
+            AppMsg::ExportPlaceSnapshot => {
+                if let AppState::Connected(connected) = &self.state {
+                    let snapshot = PlaceSnapshot::new(
+                        connected.address.clone(),
+                        connected.places.iter().map(|(place, _)| place),
+                        Local::now(),
+                    );
+                    let dialog = self.file_dialogs.open(
+                        FileDialogKind::SaveFile,
+                        FileDialogRequest::new()
+                            .file_name("places-snapshot.json")
+                            .filter("JSON", &["json"]),
+                    );
+                    let task = Task::perform(
+                        async move {
+                            let Some(path) = dialog.await else {
+                                return Ok(());
+                            };
+                            tokio::fs::write(path, snapshot.to_json())
+                                .await
+                                .map_err(|e| format!("{e:?}"))
+                        },
+                        |res| match res {
+                            Ok(()) => AppMsg::None,
+                            Err(err) => AppMsg::ExportTabFailed { err },
+                        },
+                    );
+                    (None, task)
+                } else {
+                    (None, Task::none())
+                }
+            }
+            AppMsg::ImportPlaceSnapshot { compare } => {
+                let dialog = self.file_dialogs.open(
+                    FileDialogKind::PickFile,
+                    FileDialogRequest::new().filter("JSON", &["json"]),
+                );
+                let task = Task::perform(
+                    async move {
+                        let Some(path) = dialog.await else {
+                            return Ok(None);
+                        };
+                        let json = tokio::fs::read_to_string(&path)
+                            .await
+                            .map_err(|e| format!("{e:?}"))?;
+                        let snapshot = PlaceSnapshot::from_json(&json)?;
+                        Ok(Some(SnapshotFile { path, snapshot }))
+                    },
+                    move |res| match res {
+                        Ok(Some(file)) => AppMsg::PlaceSnapshotImported { file, compare },
+                        Ok(None) => AppMsg::None,
+                        Err(err) => AppMsg::ImportPlaceSnapshotFailed { err },
+                    },
+                );
+                (None, task)
+            }
+            AppMsg::PlaceSnapshotImported { file, compare } => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.place_snapshot = Some(file);
+                    let modal = if compare {
+                        Modal::ComparePlaces
+                    } else {
+                        Modal::RestorePlaces
+                    };
+                    (None, self.update(AppMsg::ShowModal(Box::new(modal))))
+                } else {
+                    (None, Task::none())
+                }
+            }
+            AppMsg::ImportPlaceSnapshotFailed { err } => {
+                error!(?err, "Import place snapshot");
+                self.errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("place-snapshot-import-failed"),
+                    detailed: err,
+                    retry: None,
+                });
+                (None, Task::none())
+            }
+            AppMsg::PruneStaleResources => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.prune_stale_resources(Duration::from_secs(
+                        self.resource_grace_period_secs.into(),
+                    ));
+                }
+                (None, Task::none())
+            }
+            AppMsg::ClipboardCopy(content) => {
+                if let Err(e) = self.clipboard.set_text(content) {
+                    error!("Set clipboard content, Err: {e:?}");
+                    self.errors.push(ErrorReport {
+                        criticality: ErrorCriticality::NonCritical,
+                        short: fl!("error-clipboard-copy"),
+                        detailed: format!("{e:?}"),
+                        retry: None,
+                    });
+                }
+                (None, Task::none())
+            }
+            AppMsg::LaunchQuickConnect(command) => {
+                if let Err(error) = command.launch(&self.terminal_command) {
+                    error!(?error, "Launching quick connect command in terminal");
+                    self.errors.push(ErrorReport {
+                        criticality: ErrorCriticality::NonCritical,
+                        short: fl!("error-terminal-launch", program = command.program),
+                        detailed: format!("{error:?}"),
+                        retry: None,
+                    });
+                }
+                (None, Task::none())
+            }
+            AppMsg::SaveConfig => {
+                self.save_config_to_path();
+                (None, Task::none())
+            }
+            AppMsg::OpenConfigFile => {
+                if let Err(error) = util::open_path(&util::config_path()) {
+                    error!(?error, "Opening configuration file");
+                    self.errors.push(ErrorReport {
+                        criticality: ErrorCriticality::NonCritical,
+                        short: fl!("error-app-config-open"),
+                        detailed: format!("{error:?}"),
+                        retry: None,
+                    });
+                }
+                (None, Task::none())
+            }
+            AppMsg::ConfigFileChanged => {
+                self.reload_config_from_path(&util::config_path());
+                (None, Task::none())
+            }
+            AppMsg::SaveSettings => {
+                if self.save_config_to_path() {
+                    let saved = Instant::now();
+                    self.settings_saved_confirmation = Some(saved);
+                    (
+                        None,
+                        Task::perform(tokio::time::sleep(SETTINGS_SAVED_CONFIRMATION), move |_| {
+                            AppMsg::HideSettingsSavedConfirmation(saved)
+                        }),
+                    )
+                } else {
+                    (None, Task::none())
+                }
+            }
+            AppMsg::HideSettingsSavedConfirmation(saved) => {
+                if self.settings_saved_confirmation == Some(saved) {
+                    self.settings_saved_confirmation = None;
+                }
+                (None, Task::none())
+            }
+            AppMsg::CloseLatestWindow => (
+                None,
+                window::latest().and_then(|id| Task::done(AppMsg::CloseWindow(id))),
+            ),
+            AppMsg::CloseWindow(id) => {
+                self.save_config_to_path();
+                match (&self.state, &mut self.connection_sender) {
+                    // A repeated close request while shutting down closes immediately
+                    _ if self.closing_window.is_some() => (None, window::close(id)),
+                    (AppState::Connected(connected), Some(sender)) => {
+                        sender.bulk_op_control().cancel();
+                        let release = if self.auto_release.release_on_exit {
+                            connected.places_to_release_on_exit()
+                        } else {
+                            Vec::new()
+                        };
+                        sender.send(ConnectionMsg::Shutdown { release });
+                        self.closing_window = Some(id);
+                        (
+                            None,
+                            Task::perform(
+                                async { tokio::time::sleep(SHUTDOWN_TIMEOUT).await },
+                                |_| AppMsg::ShutdownTimedOut,
+                            ),
+                        )
+                    }
+                    _ => (None, window::close(id)),
+                }
+            }
+            AppMsg::ShutdownTimedOut => match self.closing_window.take() {
+                Some(id) => {
+                    warn!("Connection shutdown timed out, closing window");
+                    (None, window::close(id))
+                }
+                None => (None, Task::none()),
+            },
+            AppMsg::ConnectionEvent(ConnectionEvent::Latency(latency)) => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.latency = Some(latency);
+                }
+                (None, Task::none())
+            }
+            AppMsg::ConnectionEvent(ConnectionEvent::CoordinatorVersion(version)) => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.coordinator_version = version;
+                }
+                (None, Task::none())
+            }
+            AppMsg::ConnectionEvent(ConnectionEvent::ProtocolMismatch(error)) => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.protocol_mismatch = Some(error);
+                    connected.protocol_warning_dismissed = false;
+                }
+                (None, Task::none())
+            }
+            AppMsg::ConnectionEvent(ConnectionEvent::Synced) => (None, Task::none()),
+            // Only relevant for the reservation run
+            AppMsg::ConnectionEvent(ConnectionEvent::ReservationCreated(_)) => (None, Task::none()),
+            AppMsg::ConnectionEvent(ConnectionEvent::ShutdownComplete) => {
+                match self.closing_window.take() {
+                    Some(id) => (None, window::close(id)),
+                    None => (None, Task::none()),
+                }
+            }
+            AppMsg::ShowModal(modal) => {
+                self.modal.show(*modal);
+                if matches!(self.modal.base(), Modal::CreateVenv) && !self.venv_wizard.is_running()
+                {
+                    self.venv_wizard = VenvWizard::default();
+                }
+                match self.modal.base() {
+                    Modal::ErrorConsole => self.errors.mark_seen(),
+                    Modal::LogViewer => self.log_entries = log_viewer::LOG_BUFFER.entries(),
+                    Modal::ProtocolInspector => {
+                        self.protocol_messages = protocol_inspector::PROTOCOL_TRACE.messages();
+                    }
+                    _ => {}
+                }
+                let detect_task = if matches!(self.modal.base(), Modal::Settings)
+                    && self.modal.overlay().is_none()
+                {
+                    self.detect_venv_versions()
+                } else {
+                    Task::none()
+                };
+                // Keyboard input must not reach the modal below the confirmation
+                let task = if self.modal.overlay().is_some() {
+                    widget::operate(widget::operation::focusable::unfocus())
+                } else {
+                    match (&mut self.state, self.modal.base()) {
+                        (AppState::Connected(connected), Modal::QuickSwitcher { commands }) => {
+                            connected.quick_switcher = QuickSwitcher {
+                                commands: *commands,
+                                ..QuickSwitcher::default()
+                            };
+                            iced::widget::operation::focus(quick_switcher::QUICK_SWITCHER_INPUT_ID)
+                        }
+                        (AppState::Connected(connected), Modal::ClonePlace { place_name }) => {
+                            connected.place_clone =
+                                connected.place_by_name(place_name).map(|(place, _)| {
+                                    PlaceClone::new(place, connected.places.iter().map(|(p, _)| p))
+                                });
+                            Task::none()
+                        }
+                        (
+                            AppState::Connected(connected),
+                            Modal::ScriptDisplayName { script_name },
+                        ) => {
+                            connected.script_display_name_text = self
+                                .script_meta
+                                .scripts
+                                .get(script_name)
+                                .and_then(|meta| meta.display_name.clone())
+                                .unwrap_or_default();
+                            Task::none()
+                        }
+                        (AppState::Connected(connected), _) => {
+                            connected.place_details_notice = None;
+                            Task::none()
+                        }
+                        _ => Task::none(),
+                    }
+                };
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.force_release_confirm_text.clear();
+                    connected.cancel_reservation_confirm_text.clear();
+                    connected.acquire_reason_text.clear();
+                }
+                (None, Task::batch([task, detect_task]))
+            }
+            AppMsg::HideModal => {
+                self.modal.hide();
+                (None, Task::none())
+            }
+            AppMsg::WithHideModal(msg) => {
+                // Recursing like that is not the most awesome pattern, but eh it works
+                self.modal.hide();
+                (None, self.update(*msg))
+            }
+            AppMsg::DismissError => {
+                self.errors.pop();
+                (None, Task::none())
+            }
+            AppMsg::ChangeErrorFilter(filter) => {
+                self.errors.filter = filter;
+                (None, Task::none())
+            }
+            AppMsg::CopyErrors => (
+                None,
+                self.update(AppMsg::ClipboardCopy(self.errors.to_text())),
+            ),
+            AppMsg::ClearErrors => {
+                self.errors.clear();
+                (None, Task::none())
+            }
+            AppMsg::RetryError => match self.errors.pop() {
+                Some(ErrorReport {
+                    retry: Some(retry), ..
+                }) => (None, self.update(*retry)),
+                _ => (None, Task::none()),
+            },
+            AppMsg::ChangeVenvDir { dir } => match scripts::validate_venv_dir(&dir) {
+                Ok(()) => {
+                    self.venv_dir = dir;
+                    (None, self.detect_venv_versions())
+                }
+                Err(err) => {
+                    error!(
+                        ?err,
+                        "Validation while attempting to change labgrid venv dir failed"
+                    );
+                    self.errors.push(ErrorReport {
+                        criticality: ErrorCriticality::NonCritical,
+                        short: fl!("error-invalid-path"),
+                        detailed: format!("Invalid labgrid venv path: '{}'", dir.display()),
+                        retry: None,
+                    });
+                    (None, Task::none())
+                }
+            },
             AppMsg::ChangeScriptsDir { dir } => {
-                match Scripts::from_dir(dir.clone()) {
-                    Ok(scripts) => {
+                match Scripts::from_dir(dir.clone(), self.scripts_max_depth) {
+                    Ok(mut scripts) => {
+                        scripts.default_timeout =
+                            scripts::default_timeout(self.script_timeout_mins);
                         self.scripts_dir = scripts.dir();
                         if let AppState::Connected(connected) = &mut self.state {
                             connected.scripts = scripts;
@@ -463,18 +2225,39 @@ impl App {
                             criticality: ErrorCriticality::NonCritical,
                             short: fl!("error-invalid-path"),
                             detailed: format!("Invalid scripts directory : '{}'", dir.display()),
+                            retry: None,
                         });
                     }
                 }
                 (None, Task::none())
             }
+            AppMsg::PickPath { target, dir } => (None, self.pick_path(target, dir)),
+            AppMsg::FileBrowser(msg) => (None, self.update_file_browser(msg)),
+            AppMsg::VenvWizard(msg) => (None, self.update_venv_wizard(msg)),
+            AppMsg::LogViewer(msg) => (None, self.update_log_viewer(msg)),
+            AppMsg::ProtocolInspector(msg) => (None, self.update_protocol_inspector(msg)),
+            AppMsg::VenvVersionsDetected { dir, versions } => {
+                if dir == self.venv_dir {
+                    if let Err(error) = &versions {
+                        warn!(error, "Detecting the versions of the venv failed");
+                    }
+                    self.venv_versions = Some(versions);
+                }
+                (None, Task::none())
+            }
             AppMsg::ConnectionMsg(msg) => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    connected.last_own_place_action = Some((msg.clone(), Instant::now()));
+                }
                 if let Some(sender) = &mut self.connection_sender {
                     sender.send(msg);
                 }
                 (None, Task::none())
             }
             AppMsg::ConnectionEvent(ConnectionEvent::ReceiveReady(sender)) => {
+                sender
+                    .bulk_op_control()
+                    .set_ops_per_sec(self.bulk_ops_per_sec);
                 self.connection_sender = Some(sender);
                 (None, Task::none())
             }
@@ -495,122 +2278,697 @@ impl App {
             }
             AppMsg::ConnectionEvent(ConnectionEvent::Place(place)) => {
                 debug!(?place, "Refreshing place data");
+                let mut task = Task::none();
+                let result = self.utilization.observe(&place, Local::now());
+                self.report_utilization_error(result);
                 if let AppState::Connected(connected) = &mut self.state {
+                    if matches!(self.modal.base(), Modal::PlaceDetails { place_name } if *place_name == place.name)
+                    {
+                        connected.update_place_details_notice(&place, Instant::now());
+                    }
+                    if let Some((prev, _)) = connected.place_by_name(&place.name) {
+                        self.place_activity.record(prev, &place, Local::now());
+                        if prev.acquired.is_some()
+                            && place.acquired.is_none()
+                            && self.watched_places.contains(&place.name)
+                        {
+                            task = notifications::notify(
+                                &self.notification_settings,
+                                NotificationEvent::PlaceReleased,
+                                fl!("notification-place-released-summary"),
+                                fl!(
+                                    "notification-place-released-body",
+                                    place = place.name.as_str()
+                                ),
+                            );
+                        }
+                    }
+                    if let Some(msg) = connected.clear_acquire_reason(&place) {
+                        send_connection_msg(&mut self.connection_sender, msg);
+                    }
+                    connected.update_session_timer(&place, Instant::now());
                     connected.place_add_replace(place);
                 }
-                (None, Task::none())
+                (None, task)
             }
             AppMsg::ConnectionEvent(ConnectionEvent::DeletePlace(name)) => {
                 debug!("Deleting place");
+                let result = self.utilization.observe_deleted(&name, Local::now());
+                self.report_utilization_error(result);
                 if let AppState::Connected(connected) = &mut self.state {
+                    connected.session_timers.remove(&name);
                     connected.delete_place(name);
                 }
                 (None, Task::none())
             }
+            AppMsg::ConnectionEvent(ConnectionEvent::BulkPlaceOpProgress(progress)) => {
+                if let AppState::Connected(connected) = &mut self.state {
+                    if progress.is_none() {
+                        connected.bulk_op_paused = false;
+                    }
+                    connected.bulk_op_progress = progress;
+                }
+                (None, Task::none())
+            }
             AppMsg::ConnectionEvent(ConnectionEvent::Places(places)) => {
                 debug!("Refreshing places");
+                let now = Local::now();
+                let result = places
+                    .iter()
+                    .try_for_each(|place| self.utilization.observe(place, now));
+                self.report_utilization_error(result);
                 if let AppState::Connected(connected) = &mut self.state {
                     connected.places = places
                         .into_iter()
                         .map(|p| (p, PlaceUi::default()))
                         .collect();
+                    let mine = connected
+                        .places
+                        .iter()
+                        .filter(|(p, _)| connected.acquired_by_me(p))
+                        .map(|(p, _)| p.name.clone())
+                        .collect::<BTreeSet<String>>();
+                    connected
+                        .session_timers
+                        .retain(|place_name, _| mine.contains(place_name));
                     connected.sort_places();
                 }
                 (None, Task::none())
             }
             AppMsg::ConnectionEvent(ConnectionEvent::Reservations(reservations)) => {
                 debug!("Refreshing reservations");
+                let mut tasks = Vec::new();
                 if let AppState::Connected(connected) = &mut self.state {
+                    for reservation in connected.newly_allocated_reservations(&reservations) {
+                        tasks.push(notifications::notify(
+                            &self.notification_settings,
+                            NotificationEvent::ReservationAllocated,
+                            fl!("notification-reservation-allocated-summary"),
+                            fl!(
+                                "notification-reservation-allocated-body",
+                                token = reservation.token.as_str()
+                            ),
+                        ));
+                    }
                     connected.reservations = reservations;
                     connected.sort_reservations();
                 }
-                (None, Task::none())
+                (None, Task::batch(tasks))
             }
             AppMsg::ConnectionEvent(ConnectionEvent::Resource(resource)) => {
                 debug!("Add/refreshing resource");
+                let mut task = Task::none();
                 if let AppState::Connected(connected) = &mut self.state {
+                    let prev = connected
+                        .resources
+                        .iter()
+                        .find(|(r, _)| r.path == resource.path);
+                    let prev_acquired = prev.map(|(r, _)| r.acquired.as_str());
+                    let prev_available =
+                        prev.map(|(r, ui)| r.available && ui.stale_since.is_none());
+                    let now = Local::now();
+                    self.acquire_history.record(prev_acquired, &resource, now);
+                    if let Some(notice) = connected.resource_watch.observe(
+                        &self.watched_resources,
+                        prev_available,
+                        &resource,
+                        now,
+                    ) {
+                        task = notify_availability_change(&self.notification_settings, &notice);
+                    }
+                    connected.exporter_last_seen.record(&resource.path, now);
                     connected.resource_add_replace(resource);
                 }
-                (None, Task::none())
+                (None, task)
             }
             AppMsg::ConnectionEvent(ConnectionEvent::DeleteResource(path)) => {
                 debug!("Deleting resource");
+                let mut task = Task::none();
                 if let AppState::Connected(connected) = &mut self.state {
-                    connected.remove_resource(path);
+                    let prev_available = connected
+                        .resources
+                        .iter()
+                        .find(|(r, _)| r.path == path)
+                        .map(|(r, ui)| r.available && ui.stale_since.is_none());
+                    if let Some(notice) = connected.resource_watch.observe_deleted(
+                        &self.watched_resources,
+                        prev_available,
+                        &path,
+                        Local::now(),
+                    ) {
+                        task = notify_availability_change(&self.notification_settings, &notice);
+                    }
+                    if self.resource_grace_period_secs == 0 {
+                        connected.remove_resource(path.clone());
+                    } else {
+                        connected.resource_mark_stale(path.clone());
+                    }
+                    connected.exporter_last_seen.record_deleted(
+                        &path,
+                        &connected.resources,
+                        Local::now(),
+                    );
                 }
-                (None, Task::none())
+                (None, task)
             }
-            AppMsg::ConnectionEvent(ConnectionEvent::Connected { address }) => {
-                let new_state =
-                    AppState::Connected(AppConnected::new(address, self.scripts_dir.clone()));
+            AppMsg::ConnectionEvent(ConnectionEvent::Connected { address, identity }) => {
+                let mut connected = AppConnected::new(
+                    address,
+                    identity,
+                    self.scripts_dir.clone(),
+                    self.scripts_max_depth,
+                );
+                connected.scripts.default_timeout =
+                    scripts::default_timeout(self.script_timeout_mins);
+                self.view_state.restore(&mut connected);
+                // The filters of the profile take precedence
+                if let Some(profile) = self.selected_profile() {
+                    connected.places_only_mine = profile.filters.places_only_mine;
+                    connected.resources_only_show_available =
+                        profile.filters.resources_only_available;
+                }
+                let new_state = AppState::Connected(connected);
                 // For some reason reservations are not part of the client syncing..
                 send_connection_msg(&mut self.connection_sender, ConnectionMsg::GetReservations);
                 (Some(new_state), Task::none())
             }
             AppMsg::NotConnected(msg) => {
                 if let AppState::NotConnected(not_connected) = &mut self.state {
-                    not_connected.update(msg, &mut self.connection_sender)
+                    let profile = self
+                        .selected_profile
+                        .as_ref()
+                        .and_then(|name| self.profiles.iter().find(|p| p.name == *name));
+                    not_connected.update(
+                        msg,
+                        &mut self.connection_sender,
+                        &self.identity_override,
+                        profile,
+                        self.connection_timeouts,
+                        self.retry_transient_errors,
+                        &self.connection_auth,
+                    )
                 } else {
                     (None, Task::none())
                 }
             }
             AppMsg::Connected(msg) => {
-                if let AppState::Connected(connected) = &mut self.state {
+                let notify_task = match &msg {
+                    ConnectedMsg::ScriptFinished {
+                        script, exit_code, ..
+                    } => notifications::notify(
+                        &self.notification_settings,
+                        NotificationEvent::ScriptFinished,
+                        fl!("notification-script-finished-summary"),
+                        fl!(
+                            "notification-script-finished-body",
+                            script = script.name.as_str(),
+                            code = exit_code
+                        ),
+                    ),
+                    ConnectedMsg::ScriptTimedOut {
+                        script, timeout, ..
+                    } => notifications::notify(
+                        &self.notification_settings,
+                        NotificationEvent::ScriptFinished,
+                        fl!("notification-script-finished-summary"),
+                        fl!(
+                            "notification-script-timed-out-body",
+                            script = script.name.as_str(),
+                            secs = timeout.as_secs()
+                        ),
+                    ),
+                    _ => Task::none(),
+                };
+                let (new_state, task) = if let AppState::Connected(connected) = &mut self.state {
                     connected.update(
                         msg,
                         &mut self.connection_sender,
-                        &mut self.clipboard,
-                        self.internal_clipboard,
-                        &mut self.internal_clipboard_buf,
+                        self.clipboard.as_mut(),
                         &mut self.errors,
                         &self.venv_dir,
+                        self.check_script_imports,
                     )
                 } else {
                     (None, Task::none())
-                }
+                };
+                (new_state, Task::batch([task, notify_task]))
             }
         };
         if let Some(new_state) = new_state {
+            if let AppState::Connected(connected) = &self.state {
+                self.view_state.capture(connected);
+            }
             self.state = new_state;
         }
+        let task = match (reservation_run_event, &mut self.state) {
+            (Some(event), AppState::Connected(connected)) => Task::batch([
+                task,
+                connected.advance_reservation_run(
+                    &event,
+                    &mut self.connection_sender,
+                    &mut self.errors,
+                    &self.venv_dir,
+                ),
+            ]),
+            _ => task,
+        };
+        if let Some(metrics) = &self.metrics {
+            metrics::record_state(metrics, &self.state);
+        }
 
-        task
+        // Saved through a task, so that handling the message doesn't touch the file system
+        if changes_settings {
+            Task::batch([task, Task::done(AppMsg::SaveSettings)])
+        } else {
+            task
+        }
+    }
+
+    /// Picks the path through a native dialog, or the built-in file browser if they are unavailable.
+    ///
+    /// Dialogs failing to open switch to the file browser for the rest of the session.
+    fn pick_path(&mut self, target: FileBrowserTarget, dir: PathBuf) -> Task<AppMsg> {
+        let dir = if dir.as_os_str().is_empty() {
+            self.scripts_dir.clone()
+        } else {
+            dir
+        };
+        if self.native_dialogs_unavailable {
+            return self.open_file_browser(target, dir);
+        }
+        let mut request = FileDialogRequest::new().directory(dir.clone());
+        match target {
+            FileBrowserTarget::VenvDir | FileBrowserTarget::CreatedVenvDir => {}
+            FileBrowserTarget::ScriptsDir => {
+                request = request.filter(
+                    fl!("file-dialog-filter-python-scripts-label"),
+                    target.extensions(),
+                );
+            }
+            FileBrowserTarget::LgEnvFile => {
+                request = request.filter("YAML", target.extensions());
+            }
+        }
+        let kind = if target.picks_dir() {
+            FileDialogKind::PickFolder
+        } else {
+            FileDialogKind::PickFile
+        };
+        let dialog = self.file_dialogs.open(kind, request);
+        Task::perform(
+            async move {
+                let opened = Instant::now();
+                let res = dialog.await;
+                (res, opened.elapsed())
+            },
+            move |(res, elapsed)| match res {
+                Some(path) => target.picked_msg(path),
+                None if elapsed < file_browser::NATIVE_DIALOG_FAILURE_THRESHOLD => {
+                    AppMsg::FileBrowser(FileBrowserMsg::NativeDialogFailed { target, dir })
+                }
+                None => AppMsg::None,
+            },
+        )
+    }
+
+    fn update_log_viewer(&mut self, msg: LogViewerMsg) -> Task<AppMsg> {
+        match msg {
+            LogViewerMsg::Refresh => self.log_entries = log_viewer::LOG_BUFFER.entries(),
+            LogViewerMsg::ChangeLevel(level) => {
+                self.log_level = level;
+                log_viewer::LOG_BUFFER.set_level(level);
+            }
+            LogViewerMsg::Clear => {
+                log_viewer::LOG_BUFFER.clear();
+                self.log_entries.clear();
+            }
+            LogViewerMsg::Copy => {
+                let text = self
+                    .log_entries
+                    .iter()
+                    .filter(|entry| self.log_level.includes(&entry.level))
+                    .map(|entry| entry.to_string() + "\n")
+                    .collect();
+                return self.update(AppMsg::ClipboardCopy(text));
+            }
+        }
+        Task::none()
+    }
+
+    fn update_protocol_inspector(&mut self, msg: ProtocolInspectorMsg) -> Task<AppMsg> {
+        let trace = &protocol_inspector::PROTOCOL_TRACE;
+        match msg {
+            ProtocolInspectorMsg::Refresh => self.protocol_messages = trace.messages(),
+            ProtocolInspectorMsg::Record(record) => trace.set_enabled(record),
+            ProtocolInspectorMsg::UpdateFilter(filter) => self.protocol_filter = filter,
+            ProtocolInspectorMsg::Clear => {
+                trace.clear();
+                self.protocol_messages.clear();
+            }
+            ProtocolInspectorMsg::Copy => return self.update(AppMsg::ClipboardCopy(trace.dump())),
+        }
+        Task::none()
+    }
+
+    /// Detects the python and labgrid versions of the venv in the background.
+    fn detect_venv_versions(&mut self) -> Task<AppMsg> {
+        self.venv_versions = None;
+        let dir = self.venv_dir.clone();
+        Task::perform(VenvVersions::detect(dir.clone()), move |versions| {
+            AppMsg::VenvVersionsDetected {
+                dir: dir.clone(),
+                versions: versions.map_err(|err| format!("{err:#}")),
+            }
+        })
+    }
+
+    fn update_venv_wizard(&mut self, msg: VenvWizardMsg) -> Task<AppMsg> {
+        let wizard = &mut self.venv_wizard;
+        let step = match msg {
+            VenvWizardMsg::UpdateDir(dir) if !wizard.is_running() => {
+                wizard.dir = dir;
+                None
+            }
+            VenvWizardMsg::UpdateLabgridVersion(version) if !wizard.is_running() => {
+                wizard.labgrid_version = version;
+                None
+            }
+            VenvWizardMsg::UpdateDir(_) | VenvWizardMsg::UpdateLabgridVersion(_) => None,
+            VenvWizardMsg::Start => wizard.start(),
+            VenvWizardMsg::StepFinished { step, result } => {
+                if let Err(error) = &result {
+                    warn!(?step, error, "Creating virtual environment failed");
+                }
+                wizard.step_finished(step, result)
+            }
+            VenvWizardMsg::Use => {
+                if !matches!(wizard.progress, VenvProgress::Done(_)) {
+                    return Task::none();
+                }
+                let dir = wizard.dir();
+                self.modal.show(Modal::Settings);
+                return Task::done(AppMsg::ChangeVenvDir { dir });
+            }
+        };
+        let Some(step) = step else {
+            return Task::none();
+        };
+        let (dir, requirement) = (wizard.dir(), wizard.requirement());
+        Task::perform(step.run(dir, requirement), move |result| {
+            AppMsg::VenvWizard(VenvWizardMsg::StepFinished {
+                step,
+                result: result.map_err(|err| format!("{err:#}")),
+            })
+        })
+    }
+
+    fn open_file_browser(&mut self, target: FileBrowserTarget, dir: PathBuf) -> Task<AppMsg> {
+        self.file_browser = Some(FileBrowser::new(target, dir.clone()));
+        self.modal.show(Modal::FileBrowser);
+        list_dir_task(dir, target)
+    }
+
+    fn update_file_browser(&mut self, msg: FileBrowserMsg) -> Task<AppMsg> {
+        if let FileBrowserMsg::NativeDialogFailed { target, dir } = msg {
+            warn!("Native file dialog failed to open, using the built-in file browser");
+            self.native_dialogs_unavailable = true;
+            return self.open_file_browser(target, dir);
+        }
+        let Some(browser) = &mut self.file_browser else {
+            return Task::none();
+        };
+        match msg {
+            FileBrowserMsg::Listed {
+                requested,
+                dir,
+                entries,
+            } => {
+                if requested == browser.dir {
+                    browser.path_input = dir.display().to_string();
+                    browser.dir = dir;
+                    match entries {
+                        Ok(entries) => browser.entries = Some(entries),
+                        Err(error) => {
+                            browser.entries = Some(Vec::new());
+                            browser.error = Some(error);
+                        }
+                    }
+                }
+                Task::none()
+            }
+            FileBrowserMsg::Navigate(dir) => {
+                browser.navigate(dir.clone());
+                list_dir_task(dir, browser.target)
+            }
+            FileBrowserMsg::PathInput(input) => {
+                browser.path_input = input;
+                Task::none()
+            }
+            FileBrowserMsg::SubmitPathInput => {
+                let dir = PathBuf::from(browser.path_input.trim());
+                browser.navigate(dir.clone());
+                list_dir_task(dir, browser.target)
+            }
+            FileBrowserMsg::Select(name) => {
+                browser.selected = Some(name);
+                Task::none()
+            }
+            FileBrowserMsg::Pick => {
+                let Some(path) = browser.picked() else {
+                    return Task::none();
+                };
+                let msg = browser.target.picked_msg(path);
+                self.file_browser = None;
+                self.modal.hide();
+                Task::done(msg)
+            }
+            FileBrowserMsg::NativeDialogFailed { .. } => Task::none(),
+        }
     }
 
-    pub(crate) fn load_config(&mut self, config: Config) {
-        self.language = config.language;
+    /// Applies the configuration with the [App::config_overrides] on top.
+    pub(crate) fn load_config(&mut self, mut config: Config) {
+        self.overridden_config = self.config_overrides.apply(&mut config);
+        self.set_language(config.language);
         self.optimize_touch = config.optimize_touch;
+        self.ui_scale_percent = config.ui_scale_percent.clamp(
+            *config::UI_SCALE_PERCENT_RANGE.start(),
+            *config::UI_SCALE_PERCENT_RANGE.end(),
+        );
+        self.high_contrast = config.high_contrast;
+        self.view_state = config.view_state;
         self.venv_dir = config.venv_dir;
         self.scripts_dir = config.scripts_dir;
+        self.scripts_max_depth = config.scripts_max_depth;
+        self.script_timeout_mins = config.script_timeout_mins;
+        self.script_meta = config.script_meta;
+        self.persist_acquire_history = config.persist_acquire_history;
+        self.acquire_history = config.acquire_history;
+        self.set_record_utilization(config.record_utilization);
+        self.resource_grace_period_secs = config.resource_grace_period_secs;
+        self.offline_exporter_resources = config.offline_exporter_resources;
+        self.card_palette = config.card_palette;
+        self.notification_settings = config.notifications;
+        self.watched_places = config.watched_places;
+        self.watched_resources = config.watched_resources;
+        self.quick_actions = config.quick_actions;
+        self.identity_override = config.identity_override;
+        self.terminal_command = config.terminal_command;
+        self.auto_release = config.auto_release;
+        self.bulk_ops_per_sec = config.bulk_ops_per_sec;
+        self.autosave_secs = config.autosave_secs;
+        self.connection_timeouts = config.connection_timeouts;
+        self.retry_transient_errors = config.retry_transient_errors;
+        self.connection_auth = config.connection_auth;
+        self.check_script_imports = config.check_script_imports;
+        self.resource_classes = config.resource_classes;
+        self.profiles = config.profiles;
+        self.selected_profile = config.selected_profile;
+        self.headless_jobs = config.headless_jobs;
+        self.mqtt_settings = config.mqtt;
+        self.apply_mqtt_settings();
+    }
+
+    /// Reloads the configuration after it was changed on disk, unless it matches the current settings.
+    ///
+    /// The acquire history is kept when it isn't persisted, because the file doesn't contain it then.
+    fn reload_config_from_path(&mut self, path: &Path) {
+        let config = match Config::load_from_path(path) {
+            Ok(Some(config)) => config,
+            // Removed, it is written again on the next save
+            Ok(None) => return,
+            Err(error) => {
+                error!(?error, "Reloading configuration from file");
+                self.errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("error-app-config-reload"),
+                    detailed: format!("{error:?}"),
+                    retry: None,
+                });
+                return;
+            }
+        };
+        let current = self.extract_config();
+        if serde_json::to_value(&config).ok() == serde_json::to_value(&current).ok() {
+            return;
+        }
+        info!("Reloading configuration changed on disk");
+        let acquire_history =
+            (!config.persist_acquire_history).then(|| std::mem::take(&mut self.acquire_history));
+        self.load_config(config);
+        if let Some(acquire_history) = acquire_history {
+            self.acquire_history = acquire_history;
+        }
+    }
+
+    /// Changes the language of the translations, the language is kept if loading it fails.
+    fn set_language(&mut self, language: AppLanguage) {
+        if self.language != language {
+            match i18n::change_language(language.into()) {
+                Ok(_) => {
+                    self.language = language;
+                }
+                Err(error) => error!(?error, ?language, "Change language"),
+            }
+        }
+    }
+
+    /// Runs the MQTT bridge with the current settings and identity, restarting it if they changed.
+    fn apply_mqtt_settings(&mut self) {
+        let applied = self
+            .mqtt_settings
+            .enabled
+            .then(|| (self.mqtt_settings.clone(), self.lg_identity()));
+        if applied != self.mqtt_applied {
+            self.mqtt_applied = applied;
+            self.mqtt = None;
+            self.mqtt_status = None;
+        }
+    }
+
+    /// The profile used for connecting.
+    pub(crate) fn selected_profile(&self) -> Option<&ConnectionProfile> {
+        let name = self.selected_profile.as_ref()?;
+        self.profiles.iter().find(|p| p.name == *name)
+    }
+
+    /// The `host/user` name used when connecting, taking the overrides of the selected profile into account.
+    pub(crate) fn lg_identity(&self) -> String {
+        match self.selected_profile() {
+            Some(profile) => {
+                util::get_lg_identity(&profile.identity_override(&self.identity_override))
+            }
+            None => util::get_lg_identity(&self.identity_override),
+        }
     }
 
+    /// The configuration of the current settings, with the file values of the unchanged overridden settings.
     pub(crate) fn extract_config(&self) -> Config {
         let coordinator_address = if let AppState::Connected(connected) = &self.state {
             connected.address.clone()
         } else {
             String::default()
         };
-        Config {
+        let mut config = Config {
+            version: config::CONFIG_VERSION,
             coordinator_address,
             language: self.language,
             optimize_touch: self.optimize_touch,
+            ui_scale_percent: self.ui_scale_percent,
+            high_contrast: self.high_contrast,
             venv_dir: self.venv_dir.clone(),
             scripts_dir: self.scripts_dir.clone(),
+            scripts_max_depth: self.scripts_max_depth,
+            script_timeout_mins: self.script_timeout_mins,
+            script_meta: self.script_meta.clone(),
+            resource_grace_period_secs: self.resource_grace_period_secs,
+            offline_exporter_resources: self.offline_exporter_resources,
+            persist_acquire_history: self.persist_acquire_history,
+            record_utilization: self.record_utilization,
+            acquire_history: if self.persist_acquire_history {
+                self.acquire_history.clone()
+            } else {
+                AcquireHistory::default()
+            },
+            card_palette: self.card_palette.clone(),
+            notifications: self.notification_settings,
+            watched_places: self.watched_places.clone(),
+            watched_resources: self.watched_resources.clone(),
+            quick_actions: self.quick_actions.clone(),
+            identity_override: self.identity_override.clone(),
+            auto_release: self.auto_release,
+            bulk_ops_per_sec: self.bulk_ops_per_sec,
+            autosave_secs: self.autosave_secs,
+            connection_timeouts: self.connection_timeouts,
+            retry_transient_errors: self.retry_transient_errors,
+            connection_auth: self.connection_auth.clone(),
+            check_script_imports: self.check_script_imports,
+            resource_classes: self.resource_classes.clone(),
+            profiles: self.profiles.clone(),
+            selected_profile: self.selected_profile.clone(),
+            headless_jobs: self.headless_jobs.clone(),
+            mqtt: self.mqtt_settings.clone(),
+            terminal_command: self.terminal_command.clone(),
+            view_state: self.view_state.clone(),
+        };
+        if let AppState::Connected(connected) = &self.state {
+            config.view_state.capture(connected);
+        }
+        self.config_overrides
+            .restore(&mut config, &self.overridden_config);
+        config
+    }
+
+    /// Starts or stops storing the observed acquisitions and releases of places on disk.
+    ///
+    /// When started, the events recorded in previous runs are loaded.
+    pub(crate) fn set_record_utilization(&mut self, record: bool) {
+        self.record_utilization = record;
+        if !record {
+            self.utilization.close();
+        } else if !self.utilization.is_open() {
+            match UtilizationLog::open(util::utilization_path(), Local::now()) {
+                Ok(utilization) => self.utilization = utilization,
+                Err(error) => self.report_utilization_error(Err(error)),
+            }
         }
     }
 
-    /// Saves the current application configuration to the FS.
+    /// Reports a failure to store the utilization, after which it is only kept in memory.
+    fn report_utilization_error(&mut self, result: anyhow::Result<()>) {
+        let Err(error) = result else {
+            return;
+        };
+        error!(?error, "Recording place utilization");
+        self.utilization.close();
+        self.errors.push(ErrorReport {
+            criticality: ErrorCriticality::NonCritical,
+            short: fl!("error-utilization-record"),
+            detailed: format!("{error:?}"),
+            retry: None,
+        });
+    }
+
+    /// Saves the current application configuration to the FS, returns whether it succeeded.
     ///
     /// If it fails, an error is reported in the UI and as event.
-    pub(crate) fn save_config_to_path(&mut self) {
+    pub(crate) fn save_config_to_path(&mut self) -> bool {
         let config = self.extract_config();
-        if let Err(error) = config.save_to_path(util::config_path()) {
-            error!(?error, "Saving configuration to file");
-            self.errors.push(ErrorReport {
-                criticality: ErrorCriticality::Critical,
-                short: fl!("error-app-config-save"),
-                detailed: format!("{error:?}"),
-            });
+        match config.save_to_path(util::config_path()) {
+            Ok(()) => {
+                self.last_config_save = Some(Local::now());
+                true
+            }
+            Err(error) => {
+                error!(?error, "Saving configuration to file");
+                self.errors.push(ErrorReport {
+                    criticality: ErrorCriticality::Critical,
+                    short: fl!("error-app-config-save"),
+                    detailed: format!("{error:?}"),
+                    retry: Some(Box::new(AppMsg::SaveConfig)),
+                });
+                false
+            }
         }
     }
 
@@ -627,47 +2985,6 @@ impl App {
     }
 }
 
-/// Get the clipboard text.
-///
-/// Retrieves from the system clipboard if `internal_clipboard` is set false,
-/// or from `clipboard` if set true.
-fn clipboard_text(
-    clipboard: &mut Option<Clipboard>,
-    internal_clipboard: bool,
-    internal_clipboard_buf: &str,
-) -> anyhow::Result<String> {
-    debug!("Get clipboard text");
-
-    if let Some(clipboard) = clipboard {
-        clipboard.get_text().context("Get clipboard text")
-    } else if internal_clipboard {
-        Ok(internal_clipboard_buf.to_owned())
-    } else {
-        Ok(String::default())
-    }
-}
-
-/// Set the clipboard.
-///
-/// Set the system clipboard text if `internal_clipboard` is set to false,
-/// or `clipboard` if set true.
-fn set_clipboard_text(
-    clipboard: &mut Option<Clipboard>,
-    internal_clipboard: bool,
-    internal_clipboard_buf: &mut String,
-    text: String,
-) -> anyhow::Result<()> {
-    debug!("Set clipboard text");
-    if let Some(clipboard) = clipboard {
-        clipboard.set_text(text).context("Set clipboard text")
-    } else if internal_clipboard {
-        *internal_clipboard_buf = text;
-        Ok(())
-    } else {
-        Ok(())
-    }
-}
-
 /// Holds app state when in not-connected state.
 #[derive(Debug)]
 pub(crate) struct AppNotConnected {
@@ -695,10 +3012,16 @@ impl AppNotConnected {
     ///
     /// When `<new-app-state>` is [Option::Some], the app will transition into the new state
     /// by the top-level app message handler.
+    #[allow(clippy::too_many_arguments)]
     fn update(
         &mut self,
         msg: NotConnectedMsg,
         connection_sender: &mut Option<ConnectionSender>,
+        identity_override: &IdentityOverride,
+        profile: Option<&ConnectionProfile>,
+        timeouts: ConnectionTimeouts,
+        retry: bool,
+        auth: &ConnectionAuth,
     ) -> (Option<AppState>, Task<AppMsg>) {
         match msg {
             NotConnectedMsg::Connect => {
@@ -710,12 +3033,19 @@ impl AppNotConnected {
                     address = self.input_address,
                     "Attempting to connect to gRPC server"
                 );
-                sender.send(ConnectionMsg::Connect {
-                    address: self.input_address.clone(),
-                });
-                let new_state = AppState::Connecting {
-                    address: self.input_address.clone(),
+                let address = match profile {
+                    Some(profile) => profile.uri(&self.input_address),
+                    None => self.input_address.clone(),
                 };
+                sender.send(connect_msg(
+                    address.clone(),
+                    identity_override,
+                    profile,
+                    timeouts,
+                    retry,
+                    auth,
+                ));
+                let new_state = AppState::Connecting { address };
                 (Some(new_state), Task::none())
             }
             NotConnectedMsg::UpdateInputAddress(input_address) => {
@@ -730,6 +3060,10 @@ impl AppNotConnected {
 #[derive(Debug, Clone)]
 pub(crate) struct ResourceUi {
     pub(crate) show_details: bool,
+    /// Set when the resource was deleted by the coordinator, but is still kept during the grace period.
+    pub(crate) stale_since: Option<Instant>,
+    /// The snapshot of a camera resource, fetched when its details are shown.
+    pub(crate) camera_preview: Option<CameraPreview>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -737,6 +3071,8 @@ impl Default for ResourceUi {
     fn default() -> Self {
         Self {
             show_details: false,
+            stale_since: None,
+            camera_preview: None,
         }
     }
 }
@@ -745,18 +3081,44 @@ impl Default for ResourceUi {
 #[derive(Debug, Clone)]
 pub(crate) struct PlaceUi {
     pub(crate) add_tag_text: Option<(String, String)>,
+    /// The edited comment text, set while the comment is edited.
+    pub(crate) edit_comment_text: Option<String>,
+    /// Recently acquired or released resources, kept when the place is updated.
+    pub(crate) acquired_resource_changes: AcquiredResourceChanges,
 }
 
 #[allow(clippy::derivable_impls)]
 impl Default for PlaceUi {
     fn default() -> Self {
-        Self { add_tag_text: None }
+        Self {
+            add_tag_text: None,
+            edit_comment_text: None,
+            acquired_resource_changes: AcquiredResourceChanges::default(),
+        }
     }
 }
 
+/// A detected or likely mismatch between the protocol of the coordinator and this client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ProtocolWarning {
+    /// The coordinator reported a labgrid version the client was not tested with.
+    Untested { version: String },
+    /// The coordinator reported a labgrid version with a different protocol.
+    Incompatible { version: String },
+    /// A message from the coordinator could not be converted.
+    Mismatch {
+        version: Option<String>,
+        error: String,
+    },
+}
+
 #[derive(Debug)]
 pub(crate) struct AppConnected {
     pub(crate) address: String,
+    /// The `host/user` name this client identifies with towards the coordinator.
+    pub(crate) identity: String,
+    /// The round-trip time of the last keepalive over the client stream.
+    pub(crate) latency: Option<Duration>,
     pub(crate) active_tab: TabId,
     pub(crate) places: Vec<(Place, PlaceUi)>,
     // TODO: more efficient/better fitting data structure than a Vec, possibly HashMap?
@@ -764,32 +3126,152 @@ pub(crate) struct AppConnected {
     // TODO: more efficient/better fitting data structure than a Vec, possibly HashMap?
     pub(crate) resources: Vec<(Resource, ResourceUi)>,
     pub(crate) resources_only_show_available: bool,
+    /// Only show the resources of the exporter with this name in the resources tab.
+    pub(crate) resources_exporter_filter: Option<String>,
+    /// Only show the resources of the class with this name in the resources tab.
+    pub(crate) resources_class_filter: Option<String>,
+    /// Terms searched in the resources tab, see [ResourceFilter::matches_search].
+    pub(crate) resources_search_text: String,
+    /// When resources of the exporters were last seen in the client stream.
+    pub(crate) exporter_last_seen: ExporterLastSeen,
     pub(crate) add_place_text: String,
-    pub(crate) add_place_match_text: String,
+    pub(crate) add_place_match: MatchBuilder,
+    /// The name resources matched by the added match are renamed to, not renamed if empty.
+    pub(crate) add_place_match_rename_text: String,
+    pub(crate) add_place_alias_text: String,
+    /// Show a panel with resources that can be dragged onto places in the places tab.
+    pub(crate) places_show_resources_panel: bool,
+    /// The match pattern of the resource that is currently dragged.
+    pub(crate) dragged_resource_pattern: Option<String>,
+    /// The last dismissed announcement, it is shown again once it changes.
+    pub(crate) dismissed_announcement: Option<String>,
+    /// The availability changes of watched resources.
+    pub(crate) resource_watch: ResourceWatch,
+    /// The labgrid version reported by the coordinator.
+    pub(crate) coordinator_version: Option<String>,
+    /// The error converting the first message from the coordinator that failed.
+    pub(crate) protocol_mismatch: Option<String>,
+    pub(crate) protocol_warning_dismissed: bool,
+    /// Notice about a change of the place in the details modal by another client.
+    pub(crate) place_details_notice: Option<String>,
+    /// The last connection message sent by the UI and when it was sent.
+    ///
+    /// Used to distinguish own changes of places from changes by other clients.
+    pub(crate) last_own_place_action: Option<(ConnectionMsg, Instant)>,
+    /// Only show places acquired by this client in the places tab.
+    pub(crate) places_only_mine: bool,
+    /// Timers of the places acquired through the UI, keyed by place name.
+    pub(crate) session_timers: HashMap<String, SessionTimer>,
+    pub(crate) place_grouping: PlaceGrouping,
+    /// The values of the groups that are collapsed in the places tab, `None` for the untagged places.
+    pub(crate) collapsed_place_groups: BTreeSet<Option<String>>,
+    /// The relative paths of the subdirectories that are collapsed in the scripts tab.
+    pub(crate) collapsed_script_dirs: BTreeSet<String>,
+    /// Show checkboxes on the place cards and the bulk actions for the selected places.
+    pub(crate) places_select_mode: bool,
+    /// The names of the places selected for bulk actions.
+    pub(crate) selected_places: BTreeSet<String>,
+    pub(crate) bulk_place_tag_text: (String, String),
+    /// The progress of the running bulk place operation.
+    pub(crate) bulk_op_progress: Option<BulkPlaceOpProgress>,
+    pub(crate) bulk_op_paused: bool,
+    /// The place name typed to confirm force releasing a place.
+    pub(crate) force_release_confirm_text: String,
+    /// The owner typed to confirm cancelling a reservation of another user.
+    pub(crate) cancel_reservation_confirm_text: String,
+    pub(crate) acquire_reason_text: String,
+    pub(crate) script_display_name_text: String,
+    /// The place previewed in the clone place modal.
+    pub(crate) place_clone: Option<PlaceClone>,
+    /// The snapshot previewed in the restore places modal or compared in the compare places modal.
+    pub(crate) place_snapshot: Option<SnapshotFile>,
+    pub(crate) create_reservation_filter_text: String,
+    pub(crate) reservation_cleanup: ReservationCleanup,
+    /// Show the reservations of other users in the reservations tab, not only the own ones.
+    pub(crate) reservations_show_foreign: bool,
+    /// The time range the occupancy of the places is displayed for in the dashboard tab.
+    pub(crate) utilization_range: UtilizationRange,
+    pub(crate) create_reservation_prio_text: String,
     pub(crate) scripts: Scripts,
     pub(crate) script_out: String,
     pub(crate) script_status: scripts::ScriptStatus,
     pub(crate) script_show_output: bool,
+    /// The results of validating python scripts, by script path.
+    pub(crate) script_validations: HashMap<PathBuf, ScriptValidation>,
+    /// The filter expression of the reservation template of "Run on next available board".
+    pub(crate) reservation_run_filter_text: String,
+    /// The file name of the script run on the next available board.
+    pub(crate) reservation_run_script: Option<String>,
+    pub(crate) reservation_run: Option<ReservationRun>,
+    pub(crate) quick_switcher: QuickSwitcher,
 }
 
 impl AppConnected {
     /// Create a new connected app state.
-    fn new(address: String, scripts_dir: PathBuf) -> Self {
+    pub(crate) fn new(
+        address: String,
+        identity: String,
+        scripts_dir: PathBuf,
+        scripts_max_depth: u32,
+    ) -> Self {
         Self {
             address,
+            identity,
+            latency: None,
             active_tab: TabId::default(),
             places: Vec::default(),
             reservations: Vec::default(),
             resources: Vec::default(),
             resources_only_show_available: true,
+            resources_exporter_filter: None,
+            resources_class_filter: None,
+            resources_search_text: String::default(),
+            exporter_last_seen: ExporterLastSeen::default(),
             add_place_text: String::default(),
-            add_place_match_text: String::default(),
+            add_place_match: MatchBuilder::default(),
+            add_place_match_rename_text: String::default(),
+            add_place_alias_text: String::default(),
+            places_show_resources_panel: false,
+            dragged_resource_pattern: None,
+            dismissed_announcement: None,
+            resource_watch: ResourceWatch::default(),
+            coordinator_version: None,
+            protocol_mismatch: None,
+            protocol_warning_dismissed: false,
+            place_details_notice: None,
+            last_own_place_action: None,
+            places_only_mine: false,
+            session_timers: HashMap::default(),
+            place_grouping: PlaceGrouping::default(),
+            collapsed_place_groups: BTreeSet::default(),
+            collapsed_script_dirs: BTreeSet::default(),
+            places_select_mode: false,
+            selected_places: BTreeSet::default(),
+            bulk_place_tag_text: (String::default(), String::default()),
+            bulk_op_progress: None,
+            bulk_op_paused: false,
+            force_release_confirm_text: String::default(),
+            cancel_reservation_confirm_text: String::default(),
+            acquire_reason_text: String::default(),
+            script_display_name_text: String::default(),
+            place_clone: None,
+            place_snapshot: None,
+            create_reservation_filter_text: String::default(),
+            reservation_cleanup: ReservationCleanup::default(),
+            reservations_show_foreign: true,
+            utilization_range: UtilizationRange::default(),
+            create_reservation_prio_text: "0.0".to_string(),
             // First attempt to discover scripts in default dir,
             // if it fails fall back to default (no scripts enumerated)
-            scripts: Scripts::from_dir(scripts_dir).unwrap_or_default(),
+            scripts: Scripts::from_dir(scripts_dir, scripts_max_depth).unwrap_or_default(),
             script_status: scripts::ScriptStatus::None,
             script_out: String::default(),
             script_show_output: false,
+            script_validations: HashMap::default(),
+            reservation_run_filter_text: String::default(),
+            reservation_run_script: None,
+            reservation_run: None,
+            quick_switcher: QuickSwitcher::default(),
         }
     }
 
@@ -799,16 +3281,14 @@ impl AppConnected {
     ///
     /// When `<new-app-state>` is [Option::Some], the app will transition into the hew state
     /// by the top-level app message handler.
-    #[allow(clippy::too_many_arguments)]
     fn update(
         &mut self,
         msg: ConnectedMsg,
         connection_sender: &mut Option<ConnectionSender>,
-        clipboard: &mut Option<Clipboard>,
-        internal_clipboard: bool,
-        internal_clipboard_buf: &mut str,
-        errors: &mut Vec<ErrorReport>,
+        clipboard: &mut dyn ClipboardAccess,
+        errors: &mut ErrorLog,
         venv_dir: &Path,
+        check_script_imports: bool,
     ) -> (Option<AppState>, Task<AppMsg>) {
         match msg {
             ConnectedMsg::Disconnect => {
@@ -826,55 +3306,255 @@ impl AppConnected {
                 self.active_tab = tab;
                 (None, Task::none())
             }
+            ConnectedMsg::UtilizationRangeSelected(range) => {
+                self.utilization_range = range;
+                (None, Task::none())
+            }
             ConnectedMsg::UpdateAddPlaceName(text) => {
                 self.add_place_text = text;
                 (None, Task::none())
             }
             ConnectedMsg::ClipboardPasteAddPlaceName => {
-                match clipboard_text(clipboard, internal_clipboard, internal_clipboard_buf) {
+                match clipboard.text() {
                     Ok(text) => self.add_place_text = text,
                     Err(e) => {
                         error!("Paste clipboard into add place text field, Err: {e:?}");
                         errors.push(ErrorReport {
                             criticality: ErrorCriticality::NonCritical,
-                            short: "Paste clipboard into add place text field".to_string(),
+                            short: fl!("error-clipboard-paste"),
                             detailed: format!("{e:?}"),
+                            retry: None,
                         });
                     }
                 }
                 (None, Task::none())
             }
             ConnectedMsg::ShowResourceDetails(path) => {
-                self.resource_set_show_details(path, true);
-                self.add_place_match_text.clear();
-                (None, Task::none())
+                self.resource_set_show_details(path.clone(), true);
+                self.add_place_match = MatchBuilder::default();
+                self.add_place_match_rename_text.clear();
+                (None, self.refresh_camera_preview(path))
             }
             ConnectedMsg::ResourcesOnlyShowAvailable(show) => {
                 self.resources_only_show_available = show;
                 (None, Task::none())
             }
+            ConnectedMsg::ShowExporterResources(exporter) => {
+                self.resources_exporter_filter = Some(exporter);
+                self.active_tab = TabId::Resources;
+                (None, Task::none())
+            }
+            ConnectedMsg::ClearResourcesExporterFilter => {
+                self.resources_exporter_filter = None;
+                (None, Task::none())
+            }
+            ConnectedMsg::ShowClassResources(cls) => {
+                self.resources_class_filter = Some(cls);
+                self.active_tab = TabId::Resources;
+                (None, Task::none())
+            }
+            ConnectedMsg::ClearResourcesClassFilter => {
+                self.resources_class_filter = None;
+                (None, Task::none())
+            }
+            ConnectedMsg::ResourcesClassSelected(cls) => {
+                self.resources_class_filter = Some(cls);
+                (None, Task::none())
+            }
+            ConnectedMsg::ResourcesSearchInput(text) => {
+                self.resources_search_text = text;
+                (None, Task::none())
+            }
             ConnectedMsg::HideResourceDetails(path) => {
-                self.resource_set_show_details(path, false);
+                self.resource_set_show_details(path.clone(), false);
+                if let Some((_, ui)) = self.resources.iter_mut().find(|(r, _)| r.path == path) {
+                    ui.camera_preview = None;
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::RefreshCameraPreview(path) => (None, self.refresh_camera_preview(path)),
+            ConnectedMsg::CameraPreviewFetched { path, result } => {
+                // Dropped when the details were hidden in the meantime
+                if let Some(preview) = self
+                    .resources
+                    .iter_mut()
+                    .find(|(r, _)| r.path == path)
+                    .and_then(|(_, ui)| ui.camera_preview.as_mut())
+                {
+                    *preview = match result {
+                        Ok(bytes) => CameraPreview::Loaded(image::Handle::from_bytes(bytes)),
+                        Err(error) => CameraPreview::Failed(error),
+                    };
+                }
                 (None, Task::none())
             }
             ConnectedMsg::UpdateAddPlaceMatchPattern(text) => {
-                self.add_place_match_text = text;
+                self.add_place_match.set_pattern(&text);
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateAddPlaceMatchComponent { component, value } => {
+                self.add_place_match.set(component, value);
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateAddPlaceMatchRename(text) => {
+                self.add_place_match_rename_text = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateAddPlaceAlias(text) => {
+                self.add_place_alias_text = text;
                 (None, Task::none())
             }
             ConnectedMsg::ClipboardPasteAddPlaceMatchPattern => {
-                match clipboard_text(clipboard, internal_clipboard, internal_clipboard_buf) {
-                    Ok(text) => self.add_place_match_text = text,
+                match clipboard.text() {
+                    Ok(text) => self.add_place_match.set_pattern(&text),
                     Err(e) => {
                         error!("Paste clipboard into add place match text field, Err: {e:?}");
                         errors.push(ErrorReport {
                             criticality: ErrorCriticality::NonCritical,
-                            short: "Paste clipboard into add place match text field".to_string(),
+                            short: fl!("error-clipboard-paste"),
                             detailed: format!("{e:?}"),
+                            retry: None,
                         });
                     }
                 }
                 (None, Task::none())
             }
+            ConnectedMsg::ToggleResourcesPanel => {
+                self.places_show_resources_panel = !self.places_show_resources_panel;
+                (None, Task::none())
+            }
+            ConnectedMsg::StartResourceDrag { pattern } => {
+                self.dragged_resource_pattern = Some(pattern);
+                (None, Task::none())
+            }
+            ConnectedMsg::EndResourceDrag => {
+                self.dragged_resource_pattern = None;
+                (None, Task::none())
+            }
+            ConnectedMsg::DismissAnnouncement => {
+                self.dismissed_announcement = self.announcement().map(ToOwned::to_owned);
+                (None, Task::none())
+            }
+            ConnectedMsg::DismissAvailabilityNotice(path) => {
+                self.resource_watch.dismiss(&path);
+                (None, Task::none())
+            }
+            ConnectedMsg::DismissProtocolWarning => {
+                self.protocol_warning_dismissed = true;
+                (None, Task::none())
+            }
+            ConnectedMsg::DismissPlaceDetailsNotice => {
+                self.place_details_notice = None;
+                (None, Task::none())
+            }
+            ConnectedMsg::ChangePlaceGrouping(grouping) => {
+                if self.place_grouping != grouping {
+                    self.place_grouping = grouping;
+                    self.collapsed_place_groups.clear();
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::ToggleScriptDirCollapsed(dir) => {
+                if !self.collapsed_script_dirs.remove(&dir) {
+                    self.collapsed_script_dirs.insert(dir);
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::TogglePlaceGroupCollapsed(group) => {
+                if !self.collapsed_place_groups.remove(&group) {
+                    self.collapsed_place_groups.insert(group);
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::PlacesOnlyMine(only_mine) => {
+                self.places_only_mine = only_mine;
+                (None, Task::none())
+            }
+            ConnectedMsg::ReservationsShowForeign(show) => {
+                self.reservations_show_foreign = show;
+                (None, Task::none())
+            }
+            ConnectedMsg::TogglePlacesSelectMode => {
+                self.places_select_mode = !self.places_select_mode;
+                self.selected_places.clear();
+                (None, Task::none())
+            }
+            ConnectedMsg::TogglePlaceSelected { place_name } => {
+                if !self.selected_places.remove(&place_name) {
+                    self.selected_places.insert(place_name);
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::SelectAllPlaces => {
+                self.selected_places = self.places.iter().map(|(p, _)| p.name.clone()).collect();
+                (None, Task::none())
+            }
+            ConnectedMsg::ClearPlaceSelection => {
+                self.selected_places.clear();
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateBulkPlaceTagText(text) => {
+                self.bulk_place_tag_text.0 = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateBulkPlaceTagValueText(text) => {
+                self.bulk_place_tag_text.1 = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateScriptDisplayNameText(text) => {
+                self.script_display_name_text = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateAcquireReasonText(text) => {
+                self.acquire_reason_text = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdatePlaceCloneName(name) => {
+                if let Some(place_clone) = &mut self.place_clone {
+                    place_clone.set_name(name);
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdatePlaceCloneAlias { index, alias } => {
+                if let Some(entry) = self
+                    .place_clone
+                    .as_mut()
+                    .and_then(|c| c.aliases.get_mut(index))
+                {
+                    *entry = alias;
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdatePlaceCloneMatch { index, pattern } => {
+                if let Some(entry) = self
+                    .place_clone
+                    .as_mut()
+                    .and_then(|c| c.matches.get_mut(index))
+                {
+                    entry.pattern = pattern;
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateForceReleaseConfirmText(text) => {
+                self.force_release_confirm_text = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateCancelReservationConfirmText(text) => {
+                self.cancel_reservation_confirm_text = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::ChangeReservationCleanup(cleanup) => {
+                self.reservation_cleanup = cleanup;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateCreateReservationFilter(text) => {
+                self.create_reservation_filter_text = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateCreateReservationPrio(text) => {
+                self.create_reservation_prio_text = text;
+                (None, Task::none())
+            }
             ConnectedMsg::ShowAddPlaceTag { place_name } => {
                 if let Some((_, ui)) = self.place_by_name_mut(&place_name) {
                     ui.add_tag_text = Some((String::default(), String::default()));
@@ -905,50 +3585,44 @@ impl AppConnected {
                 }
                 (None, Task::none())
             }
-            ConnectedMsg::ClearAddPlaceTagText { place_name } => {
+            ConnectedMsg::EditPlaceComment { place_name } => {
+                if let Some((place, ui)) = self.place_by_name_mut(&place_name) {
+                    ui.edit_comment_text = Some(place.comment.clone());
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::UpdateEditPlaceCommentText { place_name, text } => {
                 if let Some((_, ui)) = self.place_by_name_mut(&place_name) {
-                    ui.add_tag_text = Some((String::default(), String::default()));
+                    ui.edit_comment_text = Some(text);
                 }
                 (None, Task::none())
             }
-            ConnectedMsg::OpenChangeScriptsDirDialog { initial_dir } => {
-                let task = Task::perform(
-                    async move {
-                        let res = rfd::AsyncFileDialog::new()
-                            .add_filter(fl!("file-dialog-filter-python-scripts-label"), &["py"])
-                            .set_directory(initial_dir)
-                            .pick_folder()
-                            .await;
-                        res.map(|f| f.path().to_owned())
-                    },
-                    |res| {
-                        if let Some(dir) = res {
-                            AppMsg::ChangeScriptsDir { dir }
-                        } else {
-                            AppMsg::None
-                        }
-                    },
-                );
-                (None, task)
+            ConnectedMsg::CancelEditPlaceComment { place_name } => {
+                if let Some((_, ui)) = self.place_by_name_mut(&place_name) {
+                    ui.edit_comment_text = None;
+                }
+                (None, Task::none())
             }
-            ConnectedMsg::OpenChangeVenvDirFileDialog { initial_dir } => {
-                let task = Task::perform(
-                    async move {
-                        let res = rfd::AsyncFileDialog::new()
-                            .set_directory(initial_dir)
-                            .pick_folder()
-                            .await;
-                        res.map(|f| f.path().to_owned())
-                    },
-                    |res| {
-                        if let Some(dir) = res {
-                            AppMsg::ChangeVenvDir { dir }
-                        } else {
-                            AppMsg::None
-                        }
-                    },
-                );
-                (None, task)
+            ConnectedMsg::SavePlaceComment { place_name } => {
+                if let Some(comment) = self
+                    .place_by_name_mut(&place_name)
+                    .and_then(|(_, ui)| ui.edit_comment_text.take())
+                {
+                    send_connection_msg(
+                        connection_sender,
+                        ConnectionMsg::SetPlaceComment {
+                            place_name,
+                            comment,
+                        },
+                    );
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::ClearAddPlaceTagText { place_name } => {
+                if let Some((_, ui)) = self.place_by_name_mut(&place_name) {
+                    ui.add_tag_text = Some((String::default(), String::default()));
+                }
+                (None, Task::none())
             }
             ConnectedMsg::RescanScriptsDir => {
                 if let Err(err) = self.scripts.rescan() {
@@ -960,45 +3634,174 @@ impl AppConnected {
                             "Scripts dir : {}, Err : {err:?}",
                             self.scripts.dir().display()
                         ),
+                        retry: None,
                     })
                 }
 
                 (None, Task::none())
             }
             ConnectedMsg::ExecuteScript { script } => {
+                let env = self.scripts.env.clone();
+                (None, self.execute_script(script, env, venv_dir))
+            }
+            ConnectedMsg::ExecuteScriptOnPlace {
+                script_name,
+                place_name,
+            } => {
+                if matches!(
+                    self.script_status,
+                    ScriptStatus::Running { .. } | ScriptStatus::CleaningUp { .. }
+                ) {
+                    return (None, Task::none());
+                }
+                let Some(script) = self.scripts.get(&script_name).cloned() else {
+                    errors.push(ErrorReport {
+                        criticality: ErrorCriticality::NonCritical,
+                        short: fl!("script-not-found-msg", script = script_name.clone()),
+                        detailed: format!(
+                            "Scripts dir : {}, Script : {script_name}",
+                            self.scripts.dir().display()
+                        ),
+                        retry: None,
+                    });
+                    return (None, Task::none());
+                };
+                self.touch_session_timer(&place_name);
+                let mut env = self.scripts.env.clone();
+                env.insert(EnvEntry::LgPlace, place_name);
+                (None, self.execute_script(script, env, venv_dir))
+            }
+            ConnectedMsg::ExtendSession { place_name } => {
+                self.touch_session_timer(&place_name);
+                (None, Task::none())
+            }
+            ConnectedMsg::PauseBulkPlaceOp(paused) => {
+                if let Some(sender) = connection_sender {
+                    sender.bulk_op_control().set_paused(paused);
+                    self.bulk_op_paused = paused;
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::CancelBulkPlaceOp => {
+                if let Some(sender) = connection_sender {
+                    sender.bulk_op_control().cancel();
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::KeepPlace { place_name } => {
+                if let Some(timer) = self.session_timers.get_mut(&place_name) {
+                    timer.keep();
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::PowerCyclePlace { place_name } => {
+                self.touch_session_timer(&place_name);
                 let venv_dir = venv_dir.to_owned();
+                let address = self.address.clone();
                 let env = self.scripts.env.clone();
-                let script_c = script.clone();
-                let script_c2 = script.clone();
-                self.script_out.clear();
-                self.script_out += &format!("### Executing script ###\nEnv:\n{env}");
-                let (task, handle) = Task::abortable(Task::perform(
-                    async move { script.execute(&venv_dir, &env).await },
+                let task = Task::perform(
+                    {
+                        let place_name = place_name.clone();
+                        async move {
+                            scripts::labgrid_client(
+                                &venv_dir,
+                                &address,
+                                &["-p", &place_name, "power", "cycle"],
+                                &env,
+                            )
+                            .await
+                        }
+                    },
                     move |out| match out {
-                        Ok((exit_code, stdout, stderr)) => {
-                            AppMsg::Connected(ConnectedMsg::ScriptFinished {
-                                script: script_c.clone(),
+                        Ok((exit_code, _, stderr)) => {
+                            AppMsg::Connected(ConnectedMsg::PowerCyclePlaceFinished {
+                                place_name: place_name.clone(),
                                 exit_code,
-                                stdout,
                                 stderr,
                             })
                         }
-                        Err(err) => AppMsg::Connected(ConnectedMsg::ScriptExecutionFailed {
-                            script: script_c.clone(),
+                        Err(err) => AppMsg::Connected(ConnectedMsg::PowerCyclePlaceFailed {
+                            place_name: place_name.clone(),
                             err: format!("{err:?}"),
                         }),
                     },
-                ));
-                self.script_status = ScriptStatus::Running {
-                    script: script_c2,
-                    handle: handle.abort_on_drop(),
-                };
+                );
                 (None, task)
             }
+            ConnectedMsg::PowerCyclePlaceFinished {
+                place_name,
+                exit_code,
+                stderr,
+            } => {
+                if exit_code != 0 {
+                    errors.push(ErrorReport {
+                        criticality: ErrorCriticality::NonCritical,
+                        short: fl!("labgrid-place-power-cycle-failed-msg", place = place_name),
+                        detailed: format!("Exit code: {exit_code}, Stderr: {stderr}"),
+                        retry: None,
+                    });
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::PowerCyclePlaceFailed { place_name, err } => {
+                errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("labgrid-place-power-cycle-failed-msg", place = place_name),
+                    detailed: err,
+                    retry: None,
+                });
+                (None, Task::none())
+            }
             ConnectedMsg::AbortScript => {
                 // Handle aborts script task on drop
-                self.script_status = ScriptStatus::None;
+                let status = std::mem::replace(&mut self.script_status, ScriptStatus::None);
                 self.script_out.clear();
+                match status {
+                    ScriptStatus::Running { script, .. }
+                        if script.front_matter.cleanup.is_some() =>
+                    {
+                        (None, self.cleanup_script(script, venv_dir, None))
+                    }
+                    ScriptStatus::Running { script, .. } => {
+                        self.end_reservation_run(&script, connection_sender);
+                        (None, Task::none())
+                    }
+                    _ => (None, Task::none()),
+                }
+            }
+            ConnectedMsg::ScriptCleanupFinished {
+                script,
+                exit_code,
+                stdout,
+                stderr,
+            } => {
+                self.end_cleanup(script.clone());
+                self.end_reservation_run(&script, connection_sender);
+                self.script_out += &format!(
+                    "### Cleanup finished with exit code {exit_code} ###\n### Cleanup Stdout ###\n{stdout}\n### Cleanup Stderr ###\n{stderr}"
+                );
+                if exit_code != 0 {
+                    errors.push(ErrorReport {
+                        criticality: ErrorCriticality::NonCritical,
+                        short: fl!("script-cleanup-failed-msg"),
+                        detailed: format!(
+                            "Script: '{}', Exit code: {exit_code}, Stderr: {stderr}",
+                            script.path().display()
+                        ),
+                        retry: None,
+                    });
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::ScriptCleanupFailed { script, err } => {
+                self.end_cleanup(script.clone());
+                self.end_reservation_run(&script, connection_sender);
+                errors.push(ErrorReport {
+                    criticality: ErrorCriticality::Critical,
+                    short: fl!("script-cleanup-failed-msg"),
+                    detailed: format!("Script: '{}', Err: {err}", script.path().display()),
+                    retry: None,
+                });
                 (None, Task::none())
             }
             ConnectedMsg::ScriptFinished {
@@ -1007,18 +3810,87 @@ impl AppConnected {
                 stdout,
                 stderr,
             } => {
-                self.script_status = ScriptStatus::Finished { script, exit_code };
                 self.script_out +=
                     &format!("### Script Stdout ###\n{stdout}\n### Script Stderr ###\n{stderr}");
+                self.end_reservation_run(&script, connection_sender);
+                self.script_status = ScriptStatus::Finished { script, exit_code };
+                (None, Task::none())
+            }
+            ConnectedMsg::ScriptTimedOut {
+                script,
+                timeout,
+                stdout,
+                stderr,
+            } => {
+                self.script_out += &format!(
+                    "### Script timed out after {} s and was killed ###\n### Script Stdout ###\n{stdout}\n### Script Stderr ###\n{stderr}\n",
+                    timeout.as_secs()
+                );
+                if script.front_matter.cleanup.is_some() {
+                    return (None, self.cleanup_script(script, venv_dir, Some(timeout)));
+                }
+                self.end_reservation_run(&script, connection_sender);
+                self.script_status = ScriptStatus::TimedOut { script, timeout };
                 (None, Task::none())
             }
             ConnectedMsg::ScriptExecutionFailed { script, err } => {
                 self.script_status = ScriptStatus::None;
                 self.script_out.clear();
+                self.end_reservation_run(&script, connection_sender);
                 errors.push(ErrorReport {
                     criticality: ErrorCriticality::Critical,
                     short: fl!("script-failed-msg"),
                     detailed: format!("Script: '{}', Err: {err}", script.path().display()),
+                    retry: None,
+                });
+                (None, Task::none())
+            }
+            ConnectedMsg::ValidateScript { script } => {
+                let venv_dir = venv_dir.to_owned();
+                self.script_validations
+                    .insert(script.path(), ScriptValidation::Running);
+                (
+                    None,
+                    Task::perform(
+                        {
+                            let script = script.clone();
+                            async move { script.validate(&venv_dir, check_script_imports).await }
+                        },
+                        move |out| match out {
+                            Ok(errors) => AppMsg::Connected(ConnectedMsg::ScriptValidated {
+                                script: script.clone(),
+                                errors,
+                            }),
+                            Err(err) => AppMsg::Connected(ConnectedMsg::ScriptValidationFailed {
+                                script: script.clone(),
+                                err: format!("{err:?}"),
+                            }),
+                        },
+                    ),
+                )
+            }
+            ConnectedMsg::ScriptValidated { script, errors } => {
+                let validation = match errors {
+                    Some(errors) => {
+                        self.script_out += &format!(
+                            "### Validation of '{}' failed ###\n{errors}\n",
+                            script.path().display()
+                        );
+                        self.script_show_output = true;
+                        ScriptValidation::Invalid
+                    }
+                    None => ScriptValidation::Valid,
+                };
+                self.script_validations.insert(script.path(), validation);
+                (None, Task::none())
+            }
+            ConnectedMsg::ScriptValidationFailed { script, err } => {
+                self.script_validations.remove(&script.path());
+                errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("script-validation-failed-msg"),
+                    detailed: format!("Script: '{}', Err: {err}", script.path().display()),
+                    retry: None,
                 });
                 (None, Task::none())
             }
@@ -1030,32 +3902,6 @@ impl AppConnected {
                 self.scripts.env.remove(&entry);
                 (None, Task::none())
             }
-            ConnectedMsg::ScriptsEnvOpenLgEnvFileDialog { initial_file } => {
-                let task = Task::perform(
-                    async move {
-                        let mut dialog = rfd::AsyncFileDialog::new();
-                        if let Some(parent_dir) = initial_file.parent() {
-                            dialog = dialog.set_directory(parent_dir);
-                        };
-                        let res = dialog
-                            .add_filter("YAML", &["yml", "yaml"])
-                            .pick_file()
-                            .await;
-                        res.map(|f| f.path().to_owned())
-                    },
-                    |res| {
-                        if let Some(file) = res {
-                            AppMsg::Connected(ConnectedMsg::ScriptsEnvUpdate {
-                                entry: EnvEntry::LgEnv,
-                                value: file.to_string_lossy().to_string(),
-                            })
-                        } else {
-                            AppMsg::None
-                        }
-                    },
-                );
-                (None, task)
-            }
             ConnectedMsg::ScriptOutShow => {
                 self.script_show_output = true;
                 (None, Task::none())
@@ -1068,7 +3914,313 @@ impl AppConnected {
                 self.script_out.clear();
                 (None, Task::none())
             }
+            ConnectedMsg::UpdateReservationRunFilter(text) => {
+                self.reservation_run_filter_text = text;
+                (None, Task::none())
+            }
+            ConnectedMsg::SelectReservationRunScript(script_name) => {
+                self.reservation_run_script = Some(script_name);
+                (None, Task::none())
+            }
+            ConnectedMsg::StartReservationRun => {
+                if self.reservation_run.is_some() {
+                    return (None, Task::none());
+                }
+                let Some(filters) = self
+                    .reservation_run_filter_text
+                    .parse::<FilterExpr>()
+                    .ok()
+                    .and_then(|expr| expr.to_filters().ok())
+                else {
+                    return (None, Task::none());
+                };
+                let Some(script) = self
+                    .reservation_run_script
+                    .as_ref()
+                    .and_then(|name| self.scripts.get(name).cloned())
+                else {
+                    return (None, Task::none());
+                };
+                let run = ReservationRun::new(script, filters);
+                send_connection_msg(connection_sender, run.create_msg());
+                self.script_out = format!(
+                    "### Waiting for a place matching '{}' ###\n",
+                    self.reservation_run_filter_text.trim()
+                );
+                self.script_show_output = true;
+                self.reservation_run = Some(run);
+                (None, Task::none())
+            }
+            ConnectedMsg::CancelReservationRun => {
+                let running = matches!(
+                    (&self.reservation_run, &self.script_status),
+                    (Some(run), ScriptStatus::Running { script, .. }) if run.place().is_some() && *script == run.script
+                );
+                if running {
+                    // Ends the run once the script is aborted and cleaned up
+                    return (
+                        None,
+                        Task::done(AppMsg::Connected(ConnectedMsg::AbortScript)),
+                    );
+                }
+                if let Some(run) = self.reservation_run.take() {
+                    for msg in run.end_msgs() {
+                        send_connection_msg(connection_sender, msg);
+                    }
+                    self.script_out += "### Cancelled ###\n";
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::PollReservationRun => {
+                if let Some(token) = self.reservation_run.as_ref().and_then(|run| run.token()) {
+                    send_connection_msg(
+                        connection_sender,
+                        ConnectionMsg::PollReservation {
+                            token: token.to_string(),
+                        },
+                    );
+                }
+                (None, Task::none())
+            }
+            ConnectedMsg::QuickSwitcherInput(query) => {
+                self.quick_switcher.query = query;
+                self.quick_switcher.selected = 0;
+                (None, Task::none())
+            }
+            ConnectedMsg::QuickSwitcherMove { down } => {
+                let count = self.quick_switcher_items().len();
+                self.quick_switcher.move_selection(down, count);
+                (None, Task::none())
+            }
+            ConnectedMsg::QuickSwitcherSubmit { acquire } => {
+                let Some(item) = self
+                    .quick_switcher_items()
+                    .into_iter()
+                    .nth(self.quick_switcher.selected)
+                else {
+                    return (None, Task::none());
+                };
+                (
+                    None,
+                    Task::done(AppMsg::Connected(ConnectedMsg::QuickSwitcherActivate {
+                        item,
+                        acquire,
+                    })),
+                )
+            }
+            ConnectedMsg::QuickSwitcherActivate { item, acquire } => {
+                let msg = match item {
+                    QuickSwitcherItem::Command(command) => command.msg(),
+                    QuickSwitcherItem::Place(place_name) if acquire => {
+                        AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlace { name: place_name })
+                            .hide_modal()
+                    }
+                    QuickSwitcherItem::Place(place_name) => {
+                        AppMsg::ShowModal(Box::new(Modal::PlaceDetails { place_name }))
+                    }
+                    QuickSwitcherItem::Script(script_name) => {
+                        let Some(script) = self.scripts.get(&script_name).cloned() else {
+                            return (None, Task::none());
+                        };
+                        AppMsg::Connected(ConnectedMsg::ExecuteScript { script }).hide_modal()
+                    }
+                };
+                (None, Task::done(msg))
+            }
+        }
+    }
+
+    /// The commands, places and scripts matching the query of the quick switcher.
+    ///
+    /// Commands are only listed when it is opened as command palette.
+    pub(crate) fn quick_switcher_items(&self) -> Vec<QuickSwitcherItem> {
+        let commands = self
+            .quick_switcher
+            .commands
+            .then(quick_switcher::Command::all)
+            .into_iter()
+            .flatten();
+        quick_switcher::matching_items(
+            &self.quick_switcher.query,
+            commands,
+            self.places.iter().map(|(place, _)| place.name.as_str()),
+            self.scripts.iter().map(|script| script.name.as_str()),
+        )
+    }
+
+    /// Advances the reservation run with the connection event, once it updated the state.
+    fn advance_reservation_run(
+        &mut self,
+        event: &ConnectionEvent,
+        connection_sender: &mut Option<ConnectionSender>,
+        errors: &mut ErrorLog,
+        venv_dir: &Path,
+    ) -> Task<AppMsg> {
+        let Some(run) = &mut self.reservation_run else {
+            return Task::none();
+        };
+        let step = match event {
+            ConnectionEvent::ReservationCreated(reservation) => {
+                run.reservation_created(reservation);
+                None
+            }
+            ConnectionEvent::Reservations(reservations) => run.reservations_changed(reservations),
+            ConnectionEvent::Place(place) => run.place_changed(place, &self.identity),
+            _ => None,
+        };
+        match step {
+            Some(RunStep::Acquire(place_name)) => {
+                self.script_out += &format!("### Allocated '{place_name}', acquiring ###\n");
+                send_connection_msg(
+                    connection_sender,
+                    ConnectionMsg::AcquirePlace { name: place_name },
+                );
+                Task::none()
+            }
+            Some(RunStep::Execute(place_name)) => {
+                let script = run.script.clone();
+                let mut env = self.scripts.env.clone();
+                env.insert(EnvEntry::LgPlace, place_name);
+                self.execute_script(script, env, venv_dir)
+            }
+            Some(RunStep::Lost) => {
+                let token = run.token().unwrap_or_default().to_string();
+                self.reservation_run = None;
+                errors.push(ErrorReport {
+                    criticality: ErrorCriticality::NonCritical,
+                    short: fl!("reservation-run-lost-msg"),
+                    detailed: format!("Reservation: '{token}'"),
+                    retry: None,
+                });
+                Task::none()
+            }
+            None => Task::none(),
+        }
+    }
+
+    /// Ends the reservation run if it runs the script, releasing the place and cancelling the reservation.
+    fn end_reservation_run(
+        &mut self,
+        script: &Script,
+        connection_sender: &mut Option<ConnectionSender>,
+    ) {
+        if !self
+            .reservation_run
+            .as_ref()
+            .is_some_and(|run| run.place().is_some() && run.script == *script)
+        {
+            return;
+        }
+        if let Some(run) = self.reservation_run.take() {
+            for msg in run.end_msgs() {
+                send_connection_msg(connection_sender, msg);
+            }
+        }
+    }
+
+    /// Executes the script with the supplied environment, replacing the output of the previous script.
+    fn execute_script(
+        &mut self,
+        script: Script,
+        env: scripts::Env,
+        venv_dir: &Path,
+    ) -> Task<AppMsg> {
+        let venv_dir = venv_dir.to_owned();
+        let script_c = script.clone();
+        let script_c2 = script.clone();
+        self.script_out.clear();
+        self.script_out += &format!("### Executing script ###\nEnv:\n{env}");
+        let timeout = script.timeout(self.scripts.default_timeout);
+        let (task, handle) = Task::abortable(Task::perform(
+            async move { script.execute(&venv_dir, &env, timeout).await },
+            move |out| match out {
+                Ok((ScriptExit::Exited(exit_code), stdout, stderr)) => {
+                    AppMsg::Connected(ConnectedMsg::ScriptFinished {
+                        script: script_c.clone(),
+                        exit_code,
+                        stdout,
+                        stderr,
+                    })
+                }
+                Ok((ScriptExit::TimedOut(timeout), stdout, stderr)) => {
+                    AppMsg::Connected(ConnectedMsg::ScriptTimedOut {
+                        script: script_c.clone(),
+                        timeout,
+                        stdout,
+                        stderr,
+                    })
+                }
+                Err(err) => AppMsg::Connected(ConnectedMsg::ScriptExecutionFailed {
+                    script: script_c.clone(),
+                    err: format!("{err:?}"),
+                }),
+            },
+        ));
+        self.script_status = ScriptStatus::Running {
+            script: script_c2,
+            handle: handle.abort_on_drop(),
+        };
+        task
+    }
+
+    /// Runs the cleanup command of the script's front-matter and sets the script status accordingly.
+    ///
+    /// `timed_out` is the exceeded timeout when the script was killed, otherwise it was aborted.
+    fn cleanup_script(
+        &mut self,
+        script: Script,
+        venv_dir: &Path,
+        timed_out: Option<Duration>,
+    ) -> Task<AppMsg> {
+        let venv_dir = venv_dir.to_owned();
+        let env = self.scripts.env.clone();
+        let script_c = script.clone();
+        if timed_out.is_none() {
+            self.script_out += "### Script aborted, running cleanup ###\n";
+        } else {
+            self.script_out += "### Running cleanup ###\n";
         }
+        let (task, handle) = Task::abortable(Task::perform(
+            async move { script_c.cleanup(&venv_dir, &env).await },
+            {
+                let script = script.clone();
+                move |out| match out {
+                    Ok(out) => {
+                        let (exit_code, stdout, stderr) = out.unwrap_or_default();
+                        AppMsg::Connected(ConnectedMsg::ScriptCleanupFinished {
+                            script: script.clone(),
+                            exit_code,
+                            stdout,
+                            stderr,
+                        })
+                    }
+                    Err(err) => AppMsg::Connected(ConnectedMsg::ScriptCleanupFailed {
+                        script: script.clone(),
+                        err: format!("{err:?}"),
+                    }),
+                }
+            },
+        ));
+        self.script_status = ScriptStatus::CleaningUp {
+            script,
+            handle: handle.abort_on_drop(),
+            timed_out,
+        };
+        task
+    }
+
+    /// Sets the status once the cleanup command of the script finished, keeping whether the script timed out.
+    fn end_cleanup(&mut self, script: Script) {
+        self.script_status = match &self.script_status {
+            ScriptStatus::CleaningUp {
+                timed_out: Some(timeout),
+                ..
+            } => ScriptStatus::TimedOut {
+                script,
+                timeout: *timeout,
+            },
+            _ => ScriptStatus::None,
+        };
     }
 
     /// Returns a immutable reference to the place whose name matches with the supplied name.
@@ -1116,12 +4268,13 @@ impl AppConnected {
     ///
     /// Sorts the resources after insertion/replacement.
     pub(crate) fn resource_add_replace(&mut self, resource: Resource) {
-        if let Some((found, _)) = self
+        if let Some((found, ui)) = self
             .resources
             .iter_mut()
             .find(|(r, _)| r.path == resource.path)
         {
             *found = resource;
+            ui.stale_since = None;
         } else {
             self.resources.push((resource, ResourceUi::default()));
         }
@@ -1141,6 +4294,40 @@ impl AppConnected {
         Some(self.resources.remove(i))
     }
 
+    /// Marks the resource with the supplied path as stale, if it isn't already.
+    ///
+    /// Stale resources are removed by [Self::prune_stale_resources] once their grace period has elapsed.
+    pub(crate) fn resource_mark_stale(&mut self, path: types::Path) {
+        if let Some((_, ui)) = self.resources.iter_mut().find(|(r, _)| r.path == path) {
+            ui.stale_since.get_or_insert_with(Instant::now);
+        } else {
+            warn!(?path, "Can't mark resource as stale, not found");
+        }
+    }
+
+    /// Removes all resources that have been stale for longer than the `grace_period`.
+    pub(crate) fn prune_stale_resources(&mut self, grace_period: Duration) {
+        self.resources.retain(|(_, ui)| {
+            ui.stale_since
+                .map_or(true, |since| since.elapsed() < grace_period)
+        });
+    }
+
+    /// Fetches a snapshot of the resource at `path`, if it is a camera with a snapshot URL.
+    pub(crate) fn refresh_camera_preview(&mut self, path: types::Path) -> Task<AppMsg> {
+        let Some((resource, ui)) = self.resources.iter_mut().find(|(r, _)| r.path == path) else {
+            return Task::none();
+        };
+        if !camera::is_camera(&resource.cls) {
+            return Task::none();
+        }
+        let Some(url) = camera::snapshot_url(resource) else {
+            return Task::none();
+        };
+        ui.camera_preview = Some(CameraPreview::Loading);
+        camera_preview_task(path, url.to_string())
+    }
+
     /// Toggles whether resource details should be shown in the UI.
     pub(crate) fn resource_set_show_details(&mut self, path: types::Path, show_details: bool) {
         if let Some((_, ui)) = self.resources.iter_mut().find(|(r, _)| r.path == path) {
@@ -1161,32 +4348,1943 @@ impl AppConnected {
     /// Sorts the places after insertion/replacement.
     pub(crate) fn place_add_replace(&mut self, place: Place) {
         if let Some(found) = self.places.iter_mut().find(|(p, _)| p.name == place.name) {
-            *found = (place, PlaceUi::default());
+            let mut acquired_resource_changes =
+                std::mem::take(&mut found.1.acquired_resource_changes);
+            acquired_resource_changes.record(
+                &found.0.acquired_resources,
+                &place.acquired_resources,
+                Instant::now(),
+            );
+            *found = (
+                place,
+                PlaceUi {
+                    acquired_resource_changes,
+                    ..PlaceUi::default()
+                },
+            );
         } else {
             self.places.push((place, PlaceUi::default()));
         }
         self.sort_places();
     }
 
-    /// Deletes a place with the supplied name.
+    /// The reservations owned by this client that are allocated in `reservations`, but were not before.
+    pub(crate) fn newly_allocated_reservations<'a>(
+        &'a self,
+        reservations: &'a [Reservation],
+    ) -> impl Iterator<Item = &'a Reservation> {
+        newly_allocated_reservations(&self.identity, &self.reservations, reservations)
+    }
+
+    /// Whether the reservation is owned by this client.
+    pub(crate) fn reservation_is_mine(&self, reservation: &Reservation) -> bool {
+        reservation.owner == self.identity
+    }
+
+    /// The reservations displayed in the reservations tab, the own ones first.
     ///
-    /// Returns [Option::Some} if the place was found and removed, [Option::None]
-    /// if it was not present (and therefore could not be removed).
-    pub(crate) fn delete_place(&mut self, name: String) -> Option<Place> {
-        let (i, _) = self
+    /// The reservations of other users are only included if [AppConnected::reservations_show_foreign] is set.
+    pub(crate) fn displayed_reservations(&self) -> Vec<&Reservation> {
+        let mut reservations = self
+            .reservations
+            .iter()
+            .filter(|r| self.reservations_show_foreign || self.reservation_is_mine(r))
+            .collect::<Vec<_>>();
+        // Stable, so the order of the coordinator is kept otherwise
+        reservations.sort_by_key(|r| !self.reservation_is_mine(r));
+        reservations
+    }
+
+    /// The reservations selected for cancellation by the current clean up.
+    pub(crate) fn reservations_to_clean_up(&self) -> Vec<&Reservation> {
+        self.reservations
+            .iter()
+            .filter(|r| self.reservation_cleanup.selects(r, &self.identity))
+            .collect()
+    }
+
+    /// Whether the place is acquired by this client.
+    pub(crate) fn acquired_by_me(&self, place: &Place) -> bool {
+        place.acquired.as_ref() == Some(&self.identity)
+    }
+
+    /// The places acquired through the UI that are still acquired by the user, sorted by name.
+    pub(crate) fn places_to_release_on_exit(&self) -> Vec<String> {
+        let mut names = self
             .places
             .iter()
-            .enumerate()
-            .find(|(_, (p, _))| p.name == name)?;
-        Some(self.places.remove(i)).map(|(p, _)| p)
+            .filter(|(p, _)| self.session_timers.contains_key(&p.name) && self.acquired_by_me(p))
+            .map(|(p, _)| p.name.clone())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
     }
-}
 
-/// Send a message to the connection subscription.
-fn send_connection_msg(connection_sender: &mut Option<ConnectionSender>, msg: ConnectionMsg) {
-    let Some(sender) = connection_sender else {
-        warn!("Connection not yet ready.");
+    /// The filters of the resources tab.
+    pub(crate) fn resource_filter(&self) -> ResourceFilter<'_> {
+        ResourceFilter {
+            only_available: self.resources_only_show_available,
+            exporter: self.resources_exporter_filter.as_deref(),
+            cls: self.resources_class_filter.as_deref(),
+            search: &self.resources_search_text,
+        }
+    }
+
+    /// The entries of the tab prepared for exporting, filtered like they are displayed.
+    pub(crate) fn export_table(&self, kind: ExportKind) -> Table {
+        match kind {
+            ExportKind::Places => Table::places(
+                self.places
+                    .iter()
+                    .map(|(p, _)| p)
+                    .filter(|p| !self.places_only_mine || self.acquired_by_me(p)),
+            ),
+            ExportKind::Resources => Table::resources(
+                self.resources
+                    .iter()
+                    .map(|(r, _)| r)
+                    .filter(|r| self.resource_filter().matches(r)),
+            ),
+            ExportKind::Reservations => Table::reservations(&self.reservations),
+        }
+    }
+
+    /// The message clearing the acquire reason of the updated place, when it was released by this client.
+    pub(crate) fn clear_acquire_reason(&self, place: &Place) -> Option<ConnectionMsg> {
+        let released_by_me = place.acquired.is_none()
+            && self
+                .place_by_name(&place.name)
+                .is_some_and(|(p, _)| self.acquired_by_me(p));
+        (released_by_me && place.tags.contains_key(ACQUIRE_REASON_TAG)).then(|| {
+            ConnectionMsg::DeletePlaceTag {
+                place_name: place.name.clone(),
+                tag: ACQUIRE_REASON_TAG.to_string(),
+            }
+        })
+    }
+
+    /// Starts the session timer when the updated place was just acquired through the UI,
+    /// stops it when the place is no longer acquired by this client.
+    pub(crate) fn update_session_timer(&mut self, place: &Place, now: Instant) {
+        if !self.acquired_by_me(place) {
+            self.session_timers.remove(&place.name);
+            return;
+        }
+        let was_mine = self
+            .place_by_name(&place.name)
+            .is_some_and(|(p, _)| self.acquired_by_me(p));
+        let own_acquire = match &self.last_own_place_action {
+            Some((
+                ConnectionMsg::AcquirePlace { name }
+                | ConnectionMsg::AcquirePlaceWithReason { name, .. },
+                _,
+            )) => *name == place.name,
+            Some((ConnectionMsg::AcquirePlaces { names }, _)) => names.contains(&place.name),
+            _ => false,
+        };
+        if !was_mine && own_acquire {
+            self.session_timers
+                .entry(place.name.clone())
+                .or_insert_with(|| SessionTimer::new(now));
+        }
+    }
+
+    /// Registers activity on the place through the UI, restarting the idle duration of its session timer.
+    fn touch_session_timer(&mut self, place_name: &str) {
+        if let Some(timer) = self.session_timers.get_mut(place_name) {
+            timer.touch(Instant::now());
+        }
+    }
+
+    /// The lab-wide announcement, if one is present and not dismissed.
+    ///
+    /// Announcements are carried by the comment of the place named [ANNOUNCEMENT_PLACE_NAME].
+    pub(crate) fn announcement(&self) -> Option<&str> {
+        let (place, _) = self.place_by_name(ANNOUNCEMENT_PLACE_NAME)?;
+        let announcement = place.comment.trim();
+        (!announcement.is_empty() && self.dismissed_announcement.as_deref() != Some(announcement))
+            .then_some(announcement)
+    }
+
+    /// The warning about the protocol of the coordinator, if it is not dismissed.
+    ///
+    /// Messages that failed to convert take precedence over the compatibility of the reported version.
+    pub(crate) fn protocol_warning(&self) -> Option<ProtocolWarning> {
+        if self.protocol_warning_dismissed {
+            return None;
+        }
+        if let Some(error) = &self.protocol_mismatch {
+            return Some(ProtocolWarning::Mismatch {
+                version: self.coordinator_version.clone(),
+                error: error.clone(),
+            });
+        }
+        let version = self.coordinator_version.clone()?;
+        match version::compatibility(&version) {
+            Compatibility::Compatible => None,
+            Compatibility::Untested => Some(ProtocolWarning::Untested { version }),
+            Compatibility::Incompatible => Some(ProtocolWarning::Incompatible { version }),
+        }
+    }
+
+    /// Sets the notice of the place details modal if the updated place was changed by another client.
+    ///
+    /// Changes shortly after an own action on the place are attributed to this client.
+    pub(crate) fn update_place_details_notice(&mut self, place: &Place, now: Instant) {
+        /// How long after an own action changes of the affected place are attributed to this client.
+        const OWN_ACTION_WINDOW: Duration = Duration::from_secs(5);
+
+        let Some((prev, _)) = self.places.iter().find(|(p, _)| p.name == place.name) else {
+            return;
+        };
+        if prev == place {
+            return;
+        }
+        let own_action = self
+            .last_own_place_action
+            .as_ref()
+            .is_some_and(|(msg, time)| {
+                msg.affects_place(&place.name) && now.duration_since(*time) < OWN_ACTION_WINDOW
+            });
+        let notice = if prev.acquired != place.acquired {
+            match &place.acquired {
+                Some(acquired) if *acquired != self.identity => Some(fl!(
+                    "labgrid-place-details-acquired-notice",
+                    user = acquired.as_str()
+                )),
+                Some(_) => None,
+                None if own_action => None,
+                None => Some(fl!("labgrid-place-details-released-notice")),
+            }
+        } else if own_action {
+            None
+        } else {
+            Some(fl!("labgrid-place-details-modified-notice"))
+        };
+        if notice.is_some() {
+            self.place_details_notice = notice;
+        }
+    }
+
+    /// Deletes a place with the supplied name.
+    ///
+    /// Returns [Option::Some} if the place was found and removed, [Option::None]
+    /// if it was not present (and therefore could not be removed).
+    pub(crate) fn delete_place(&mut self, name: String) -> Option<Place> {
+        let (i, _) = self
+            .places
+            .iter()
+            .enumerate()
+            .find(|(_, (p, _))| p.name == name)?;
+        self.selected_places.remove(&name);
+        Some(self.places.remove(i)).map(|(p, _)| p)
+    }
+}
+
+/// The reservations owned by `identity` that are allocated in `current`, but were not in `prev`.
+pub(crate) fn newly_allocated_reservations<'a>(
+    identity: &'a str,
+    prev: &'a [Reservation],
+    current: &'a [Reservation],
+) -> impl Iterator<Item = &'a Reservation> {
+    current.iter().filter(move |r| {
+        r.owner == identity
+            && r.reservation_state() == Some(ReservationState::Allocated)
+            && prev.iter().any(|p| {
+                p.token == r.token && p.reservation_state() != Some(ReservationState::Allocated)
+            })
+    })
+}
+
+/// The message for connecting to the coordinator at `address`.
+///
+/// The identity and subscription scope are taken from the profile, if one is used.
+pub(crate) fn connect_msg(
+    address: String,
+    identity_override: &IdentityOverride,
+    profile: Option<&ConnectionProfile>,
+    timeouts: ConnectionTimeouts,
+    retry: bool,
+    auth: &ConnectionAuth,
+) -> ConnectionMsg {
+    let (identity, scope) = match profile {
+        Some(profile) => (
+            util::get_lg_identity(&profile.identity_override(identity_override)),
+            profile.scope,
+        ),
+        None => (
+            util::get_lg_identity(identity_override),
+            SubscriptionScope::default(),
+        ),
+    };
+    ConnectionMsg::Connect {
+        address,
+        identity,
+        timeouts,
+        retry,
+        auth: auth.clone(),
+        scope,
+    }
+}
+
+/// A task listing the directory for the file browser.
+fn list_dir_task(dir: PathBuf, target: FileBrowserTarget) -> Task<AppMsg> {
+    Task::perform(
+        async move {
+            let (listed, entries) = file_browser::list_dir(dir.clone(), target.extensions()).await;
+            FileBrowserMsg::Listed {
+                requested: dir,
+                dir: listed,
+                entries,
+            }
+        },
+        AppMsg::FileBrowser,
+    )
+}
+
+/// A task fetching the snapshot of the camera resource at `path` from `url`.
+#[cfg(feature = "camera-preview")]
+fn camera_preview_task(path: types::Path, url: String) -> Task<AppMsg> {
+    Task::perform(camera::fetch_snapshot(url), move |result| {
+        AppMsg::Connected(ConnectedMsg::CameraPreviewFetched { path, result })
+    })
+}
+
+/// Without the `camera-preview` feature, no resources are previewed.
+#[cfg(not(feature = "camera-preview"))]
+fn camera_preview_task(_path: types::Path, _url: String) -> Task<AppMsg> {
+    Task::none()
+}
+
+/// A desktop notification about the availability change of a watched resource.
+fn notify_availability_change(
+    settings: &NotificationSettings,
+    notice: &AvailabilityNotice,
+) -> Task<AppMsg> {
+    let path = notice.path.to_string();
+    let (summary, body) = if notice.available {
+        (
+            fl!("notification-resource-available-summary"),
+            fl!("notification-resource-available-body", path = path.as_str()),
+        )
+    } else {
+        (
+            fl!("notification-resource-unavailable-summary"),
+            fl!(
+                "notification-resource-unavailable-body",
+                path = path.as_str()
+            ),
+        )
+    };
+    notifications::notify(
+        settings,
+        NotificationEvent::ResourceAvailabilityChanged,
+        summary,
+        body,
+    )
+}
+
+/// Send a message to the connection subscription.
+fn send_connection_msg(connection_sender: &mut Option<ConnectionSender>, msg: ConnectionMsg) {
+    let Some(sender) = connection_sender else {
+        warn!("Connection not yet ready.");
         return;
     };
     sender.send(msg);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard::InternalClipboard;
+    use crate::connection::TimeoutPreset;
+    use crate::test_fixtures;
+    use futures_util::StreamExt;
+    use iced::futures::channel::mpsc;
+    use std::collections::HashMap;
+
+    fn place() -> Place {
+        Place {
+            comment: "Rack 3".to_string(),
+            ..test_fixtures::place("board-1")
+        }
+    }
+
+    fn resource() -> Resource {
+        test_fixtures::resource("serial", "NetworkSerialPort")
+    }
+
+    #[test]
+    fn place_details_notice() {
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        connected.places = vec![(place(), PlaceUi::default())];
+        let now = Instant::now();
+
+        let mut acquired = place();
+        acquired.acquired = Some("host/alice".to_string());
+        connected.update_place_details_notice(&acquired, now);
+        assert_eq!(
+            connected.place_details_notice.as_deref(),
+            Some("This place was just acquired by \u{2068}host/alice\u{2069}")
+        );
+
+        // Own changes don't produce a notice
+        connected.place_details_notice = None;
+        let mut commented = place();
+        commented.comment = "Rack 4".to_string();
+        connected.last_own_place_action = Some((
+            ConnectionMsg::SetPlaceComment {
+                place_name: "board-1".to_string(),
+                comment: "Rack 4".to_string(),
+            },
+            now,
+        ));
+        connected.update_place_details_notice(&commented, now);
+        assert_eq!(connected.place_details_notice, None);
+        connected.update_place_details_notice(&commented, now + Duration::from_secs(10));
+        assert_eq!(
+            connected.place_details_notice.as_deref(),
+            Some("This place was just modified by another client")
+        );
+    }
+
+    #[test]
+    fn protocol_warning() {
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        assert_eq!(connected.protocol_warning(), None);
+        connected.coordinator_version = Some("25.0.1".to_string());
+        assert_eq!(connected.protocol_warning(), None);
+        connected.coordinator_version = Some("99.0".to_string());
+        assert_eq!(
+            connected.protocol_warning(),
+            Some(ProtocolWarning::Untested {
+                version: "99.0".to_string()
+            })
+        );
+        connected.coordinator_version = Some("23.0.6".to_string());
+        assert_eq!(
+            connected.protocol_warning(),
+            Some(ProtocolWarning::Incompatible {
+                version: "23.0.6".to_string()
+            })
+        );
+
+        connected.protocol_mismatch = Some("Conversion failed".to_string());
+        assert_eq!(
+            connected.protocol_warning(),
+            Some(ProtocolWarning::Mismatch {
+                version: Some("23.0.6".to_string()),
+                error: "Conversion failed".to_string()
+            })
+        );
+        let _ = connected.update(
+            ConnectedMsg::DismissProtocolWarning,
+            &mut None,
+            &mut InternalClipboard::default(),
+            &mut ErrorLog::default(),
+            Path::new(""),
+            true,
+        );
+        assert_eq!(connected.protocol_warning(), None);
+    }
+
+    #[test]
+    fn announcement_dismiss() {
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        let mut announcement = place();
+        announcement.name = ANNOUNCEMENT_PLACE_NAME.to_string();
+        announcement.comment = "Maintenance on friday".to_string();
+        connected.places = vec![(place(), PlaceUi::default())];
+        assert_eq!(connected.announcement(), None);
+
+        connected.place_add_replace(announcement.clone());
+        assert_eq!(connected.announcement(), Some("Maintenance on friday"));
+        let _ = connected.update(
+            ConnectedMsg::DismissAnnouncement,
+            &mut None,
+            &mut InternalClipboard::default(),
+            &mut ErrorLog::default(),
+            Path::new(""),
+            true,
+        );
+        assert_eq!(connected.announcement(), None);
+
+        // A changed announcement is shown again
+        announcement.comment = "Maintenance on monday".to_string();
+        connected.place_add_replace(announcement);
+        assert_eq!(connected.announcement(), Some("Maintenance on monday"));
+    }
+
+    #[test]
+    fn reservations_clean_up() {
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        let reservation = |owner: &str, token: &str, state: ReservationState| Reservation {
+            owner: owner.to_string(),
+            token: token.to_string(),
+            state: state.into(),
+            prio: 0.,
+            filters: HashMap::new(),
+            allocations: HashMap::new(),
+            created: 0.,
+            timeout: 0.,
+        };
+        connected.reservations = vec![
+            reservation("host/me", "AAAA", ReservationState::Waiting),
+            reservation("host/other", "BBBB", ReservationState::Expired),
+            reservation("host/me", "CCCC", ReservationState::Invalid),
+        ];
+        let tokens = |connected: &AppConnected| {
+            connected
+                .reservations_to_clean_up()
+                .into_iter()
+                .map(|r| r.token.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        assert_eq!(tokens(&connected), "BBBB,CCCC");
+        connected.reservation_cleanup = ReservationCleanup::Mine;
+        assert_eq!(tokens(&connected), "AAAA,CCCC");
+    }
+
+    #[test]
+    fn reservations_displayed() {
+        let (mut app, _receiver) = connected_app();
+        let reservation = |owner: &str, token: &str| Reservation {
+            owner: owner.to_string(),
+            token: token.to_string(),
+            state: ReservationState::Waiting.into(),
+            prio: 0.,
+            filters: HashMap::new(),
+            allocations: HashMap::new(),
+            created: 0.,
+            timeout: 0.,
+        };
+        connected(&mut app).reservations = vec![
+            reservation("host/other", "AAAA"),
+            reservation("host/me", "BBBB"),
+            reservation("host/third", "CCCC"),
+            reservation("host/me", "DDDD"),
+        ];
+        let tokens = |connected: &AppConnected| {
+            connected
+                .displayed_reservations()
+                .into_iter()
+                .map(|r| r.token.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        assert_eq!(tokens(connected(&mut app)), "BBBB,DDDD,AAAA,CCCC");
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ReservationsShowForeign(
+            false,
+        )));
+        assert_eq!(tokens(connected(&mut app)), "BBBB,DDDD");
+
+        // The confirmation of a foreign cancellation starts empty
+        let _ = app.update(AppMsg::Connected(
+            ConnectedMsg::UpdateCancelReservationConfirmText("host/other".to_string()),
+        ));
+        let _ = app.update(AppMsg::ShowModal(Box::new(
+            Modal::CancelForeignReservation {
+                token: "AAAA".to_string(),
+                owner: "host/other".to_string(),
+            },
+        )));
+        assert!(matches!(
+            app.modal.base(),
+            Modal::CancelForeignReservation { .. }
+        ));
+        assert!(connected(&mut app)
+            .cancel_reservation_confirm_text
+            .is_empty());
+    }
+
+    #[test]
+    fn place_acquire_reason() {
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        let mut place = place();
+        place.acquired = Some("host/me".to_string());
+        place.tags = HashMap::from([(
+            ACQUIRE_REASON_TAG.to_string(),
+            "bisecting boot issue".to_string(),
+        )]);
+        assert_eq!(acquire_reason(&place), Some("bisecting boot issue"));
+        assert!(connected.clear_acquire_reason(&place).is_none());
+        connected.place_add_replace(place.clone());
+
+        // The reason of a place released by this client is cleared
+        place.acquired = None;
+        assert_eq!(acquire_reason(&place), None);
+        assert!(matches!(
+            connected.clear_acquire_reason(&place),
+            Some(ConnectionMsg::DeletePlaceTag { place_name, tag })
+                if place_name == "board-1" && tag == ACQUIRE_REASON_TAG
+        ));
+
+        // But not when it was acquired by another client
+        connected.places[0].0.acquired = Some("host/other".to_string());
+        assert!(connected.clear_acquire_reason(&place).is_none());
+    }
+
+    #[test]
+    fn place_acquired_resource_changes() {
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        let serial = "exporter-1/board-1/USBSerialPort/serial".to_string();
+        let mut place = place();
+        connected.place_add_replace(place.clone());
+
+        place.acquired_resources = vec![serial.clone()];
+        connected.place_add_replace(place.clone());
+        assert!(
+            connected.places[0]
+                .1
+                .acquired_resource_changes
+                .get(&serial)
+                .unwrap()
+                .acquired
+        );
+
+        // Changes are kept until they fade out when the place is updated again
+        place.comment = "Rack 3".to_string();
+        connected.place_add_replace(place.clone());
+        assert!(connected.places[0]
+            .1
+            .acquired_resource_changes
+            .get(&serial)
+            .is_some());
+
+        place.acquired_resources.clear();
+        connected.place_add_replace(place);
+        assert_eq!(
+            connected.places[0]
+                .1
+                .acquired_resource_changes
+                .released()
+                .map(|c| c.resource.as_str())
+                .collect::<Vec<_>>(),
+            vec![serial.as_str()]
+        );
+    }
+
+    #[test]
+    fn newly_allocated_reservations() {
+        let reservation = |token: &str, owner: &str, state: ReservationState| Reservation {
+            owner: owner.to_string(),
+            token: token.to_string(),
+            state: state.into(),
+            prio: 0.,
+            filters: HashMap::new(),
+            allocations: HashMap::new(),
+            created: 0.,
+            timeout: 0.,
+        };
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        connected.reservations = vec![
+            reservation("MINE", "host/me", ReservationState::Waiting),
+            reservation("OTHER", "host/other", ReservationState::Waiting),
+        ];
+        let reservations = vec![
+            reservation("MINE", "host/me", ReservationState::Allocated),
+            reservation("OTHER", "host/other", ReservationState::Allocated),
+            reservation("NEW", "host/me", ReservationState::Allocated),
+        ];
+        assert_eq!(
+            connected
+                .newly_allocated_reservations(&reservations)
+                .map(|r| r.token.as_str())
+                .collect::<Vec<_>>(),
+            vec!["MINE"]
+        );
+    }
+
+    #[test]
+    fn modal_stack_confirmation_over_details() {
+        let details = || Modal::PlaceDetails {
+            place_name: "board-1".to_string(),
+        };
+        let confirmation = || Modal::Confirmation {
+            msg: "Delete tag?".to_string(),
+            confirm: AppMsg::None,
+        };
+        let mut modals = ModalStack::default();
+        modals.show(details());
+        modals.show(confirmation());
+        assert!(matches!(modals.base(), Modal::PlaceDetails { .. }));
+        assert!(matches!(modals.overlay(), Some(Modal::Confirmation { .. })));
+        // No deeper nesting, the overlay is replaced
+        modals.show(confirmation());
+        modals.hide();
+        assert!(matches!(modals.base(), Modal::PlaceDetails { .. }));
+        assert!(modals.overlay().is_none());
+        modals.hide();
+        assert!(modals.is_empty());
+
+        // Other modals replace all displayed modals
+        modals.show(details());
+        modals.show(confirmation());
+        modals.show(Modal::Settings);
+        assert!(matches!(modals.base(), Modal::Settings));
+        assert!(modals.overlay().is_none());
+    }
+
+    /// File dialogs resolving to a preset path, recording what was requested.
+    #[derive(Debug, Default)]
+    struct PresetFileDialogs {
+        /// `None` behaves like a cancelled dialog.
+        path: Option<PathBuf>,
+        requests: std::sync::Mutex<Vec<(FileDialogKind, FileDialogRequest)>>,
+    }
+
+    impl FileDialogs for PresetFileDialogs {
+        fn open(
+            &self,
+            kind: FileDialogKind,
+            request: FileDialogRequest,
+        ) -> futures_util::future::BoxFuture<'static, Option<PathBuf>> {
+            self.requests.lock().unwrap().push((kind, request));
+            Box::pin(std::future::ready(self.path.clone()))
+        }
+    }
+
+    /// Runs the task to completion, returns the messages it produced.
+    async fn task_messages(task: Task<AppMsg>) -> Vec<AppMsg> {
+        let Some(stream) = iced_runtime::task::into_stream(task) else {
+            return Vec::new();
+        };
+        stream
+            .filter_map(|action| async move {
+                match action {
+                    iced_runtime::Action::Output(msg) => Some(msg),
+                    _ => None,
+                }
+            })
+            .collect()
+            .await
+    }
+
+    /// An app that is side-effect free, for testing the message handling.
+    fn test_app() -> (App, mpsc::Receiver<ConnectionMsg>) {
+        let mut app = App::with_side_effects(
+            None,
+            false,
+            Box::new(InternalClipboard::default()),
+            Arc::new(PresetFileDialogs::default()),
+        );
+        let (sender, receiver) = ConnectionSender::channel(16);
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::ReceiveReady(
+            sender,
+        )));
+        (app, receiver)
+    }
+
+    fn connected_app() -> (App, mpsc::Receiver<ConnectionMsg>) {
+        let (mut app, mut receiver) = test_app();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Connected {
+            address: "localhost:20408".to_string(),
+            identity: "host/me".to_string(),
+        }));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::GetReservations))
+        ));
+        (app, receiver)
+    }
+
+    fn connected(app: &mut App) -> &mut AppConnected {
+        match &mut app.state {
+            AppState::Connected(connected) => connected,
+            state => panic!("App not connected, state: {state:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn update_export_tab_file_dialog() {
+        let path = std::env::temp_dir().join(format!(
+            "labgrid-ui-export-places-{}.json",
+            std::process::id()
+        ));
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(place())));
+
+        // A cancelled dialog doesn't write anything
+        let task = app.update(AppMsg::ExportTab {
+            kind: ExportKind::Places,
+        });
+        assert!(matches!(task_messages(task).await[..], [AppMsg::None]));
+        assert!(!path.exists());
+
+        let dialogs = Arc::new(PresetFileDialogs {
+            path: Some(path.clone()),
+            ..Default::default()
+        });
+        app.file_dialogs = dialogs.clone();
+        let task = app.update(AppMsg::ExportTab {
+            kind: ExportKind::Places,
+        });
+        assert!(matches!(task_messages(task).await[..], [AppMsg::None]));
+        let (kind, request) = dialogs.requests.lock().unwrap().remove(0);
+        assert_eq!(kind, FileDialogKind::SaveFile);
+        assert_eq!(request.file_name.as_deref(), Some("places.csv"));
+        assert_eq!(request.filters.len(), 2);
+        // The format follows the extension of the picked path
+        let exported = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(exported.trim_start().starts_with('['), "{exported}");
+        assert!(exported.contains("board-1"));
+    }
+
+    fn error(short: &str, retry: Option<AppMsg>) -> ErrorReport {
+        ErrorReport {
+            criticality: ErrorCriticality::NonCritical,
+            short: short.to_string(),
+            detailed: String::new(),
+            retry: retry.map(Box::new),
+        }
+    }
+
+    #[test]
+    fn update_connect_disconnect() {
+        let (mut app, mut receiver) = test_app();
+        let _ = app.update(AppMsg::NotConnected(NotConnectedMsg::UpdateInputAddress(
+            "localhost:20408".to_string(),
+        )));
+        let _ = app.update(AppMsg::NotConnected(NotConnectedMsg::Connect));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::Connect { address, .. })) if address == "localhost:20408"
+        ));
+        assert!(
+            matches!(&app.state, AppState::Connecting { address } if address == "localhost:20408")
+        );
+
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Connected {
+            address: "localhost:20408".to_string(),
+            identity: "host/me".to_string(),
+        }));
+        assert_eq!(connected(&mut app).identity, "host/me");
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::GetReservations))
+        ));
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::Disconnect));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::Disconnect))
+        ));
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Disconnected {
+            error: Some(error("Connection lost", None)),
+        }));
+        // The address is kept for reconnecting
+        assert!(
+            matches!(&app.state, AppState::NotConnected(n) if n.input_address == "localhost:20408")
+        );
+        assert_eq!(app.errors.stack().len(), 1);
+    }
+
+    #[test]
+    fn update_connect_without_sender() {
+        let mut app = App::with_side_effects(
+            None,
+            false,
+            Box::new(InternalClipboard::default()),
+            Arc::new(PresetFileDialogs::default()),
+        );
+        let _ = app.update(AppMsg::NotConnected(NotConnectedMsg::Connect));
+        assert!(matches!(app.state, AppState::NotConnected(_)));
+    }
+
+    #[test]
+    fn update_reconnect() {
+        let (mut app, mut receiver) = connected_app();
+        let _ = app.update(AppMsg::ChangeIdentityUsername("other".to_string()));
+        let _ = app.update(AppMsg::Reconnect);
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::Connect { identity, .. })) if identity.ends_with("/other")
+        ));
+        assert!(matches!(app.state, AppState::Connecting { .. }));
+
+        // Only reconnects when connected
+        let _ = app.update(AppMsg::Reconnect);
+        assert!(receiver.try_next().is_err());
+    }
+
+    #[test]
+    fn update_profiles() {
+        let (mut app, mut receiver) = test_app();
+        let _ = app.update(AppMsg::NotConnected(NotConnectedMsg::UpdateInputAddress(
+            "lab:20408".to_string(),
+        )));
+        let _ = app.update(AppMsg::AddProfile);
+        assert_eq!(app.profiles[0].address, "lab:20408");
+        assert!(matches!(
+            app.modal.base(),
+            Modal::Profiles { editing: Some(0) }
+        ));
+
+        let profile = ConnectionProfile {
+            name: "Lab".to_string(),
+            tls: true,
+            scope: SubscriptionScope::PlacesOnly,
+            ..app.profiles[0].clone()
+        };
+        let _ = app.update(AppMsg::SelectProfile(Some(app.profiles[0].name.clone())));
+        let _ = app.update(AppMsg::ChangeProfile { index: 0, profile });
+        // Renaming keeps the profile selected
+        assert_eq!(app.selected_profile.as_deref(), Some("Lab"));
+
+        let _ = app.update(AppMsg::NotConnected(NotConnectedMsg::UpdateInputAddress(
+            String::new(),
+        )));
+        let _ = app.update(AppMsg::SelectProfile(Some("Lab".to_string())));
+        let _ = app.update(AppMsg::NotConnected(NotConnectedMsg::Connect));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::Connect { address, scope, .. }))
+                if address == "https://lab:20408" && scope == SubscriptionScope::PlacesOnly
+        ));
+
+        let _ = app.update(AppMsg::DeleteProfile(0));
+        assert!(app.profiles.is_empty());
+        assert_eq!(app.selected_profile, None);
+    }
+
+    #[test]
+    fn update_places() {
+        let (mut app, _receiver) = connected_app();
+        let mut other = place();
+        other.name = "board-0".to_string();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Places(vec![
+            place(),
+            other,
+        ])));
+        assert_eq!(
+            connected(&mut app)
+                .places
+                .iter()
+                .map(|(p, _)| p.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["board-0", "board-1"]
+        );
+
+        let mut acquired = place();
+        acquired.acquired = Some("host/me".to_string());
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(acquired)));
+        assert_eq!(
+            connected(&mut app)
+                .place_by_name("board-1")
+                .and_then(|(p, _)| p.acquired.as_deref()),
+            Some("host/me")
+        );
+
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::DeletePlace(
+            "board-0".to_string(),
+        )));
+        assert!(connected(&mut app).place_by_name("board-0").is_none());
+        assert_eq!(connected(&mut app).places.len(), 1);
+    }
+
+    #[test]
+    fn update_place_snapshot_imported() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Places(vec![
+            place(),
+        ])));
+        let snapshot = PlaceSnapshot::new(
+            "localhost:20408".to_string(),
+            connected(&mut app).places.iter().map(|(p, _)| p),
+            Local::now(),
+        );
+        let file = SnapshotFile {
+            path: PathBuf::from("places-snapshot.json"),
+            snapshot: snapshot.clone(),
+        };
+        let _ = app.update(AppMsg::PlaceSnapshotImported {
+            file: file.clone(),
+            compare: false,
+        });
+        assert!(matches!(app.modal.base(), Modal::RestorePlaces));
+        assert_eq!(
+            connected(&mut app)
+                .place_snapshot
+                .as_ref()
+                .map(|file| &file.snapshot),
+            Some(&snapshot)
+        );
+
+        let _ = app.update(AppMsg::HideModal);
+        let _ = app.update(AppMsg::PlaceSnapshotImported {
+            file,
+            compare: true,
+        });
+        assert!(matches!(app.modal.base(), Modal::ComparePlaces));
+
+        let _ = app.update(AppMsg::ImportPlaceSnapshotFailed {
+            err: "Unsupported snapshot version 2".to_string(),
+        });
+        assert_eq!(app.errors.stack().len(), 1);
+    }
+
+    #[test]
+    fn update_quick_switcher() {
+        let (mut app, _receiver) = connected_app();
+        let mut other = place();
+        other.name = "imx8-2".to_string();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Places(vec![
+            place(),
+            other,
+        ])));
+        connected(&mut app).quick_switcher.query = "stale".to_string();
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::QuickSwitcher {
+            commands: false,
+        })));
+        assert!(matches!(app.modal.base(), Modal::QuickSwitcher { .. }));
+        assert!(connected(&mut app).quick_switcher.query.is_empty());
+        assert_eq!(connected(&mut app).quick_switcher_items().len(), 2);
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::QuickSwitcherMove {
+            down: true,
+        }));
+        assert_eq!(connected(&mut app).quick_switcher.selected, 1);
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::QuickSwitcherInput(
+            "imx".to_string(),
+        )));
+        assert_eq!(connected(&mut app).quick_switcher.selected, 0);
+        assert_eq!(
+            connected(&mut app).quick_switcher_items(),
+            vec![QuickSwitcherItem::Place("imx8-2".to_string())]
+        );
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::QuickSwitcherMove {
+            down: true,
+        }));
+        assert_eq!(connected(&mut app).quick_switcher.selected, 0);
+
+        // As command palette, commands are listed first
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::QuickSwitcher {
+            commands: true,
+        })));
+        let items = connected(&mut app).quick_switcher_items();
+        assert_eq!(
+            items[0],
+            QuickSwitcherItem::Command(quick_switcher::Command::Refresh)
+        );
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::QuickSwitcherInput(
+            "imx".to_string(),
+        )));
+        assert!(connected(&mut app)
+            .quick_switcher_items()
+            .contains(&QuickSwitcherItem::Place("imx8-2".to_string())));
+        let _ = app.update(quick_switcher::Command::ShowTab(TabId::Scripts).msg());
+        assert_eq!(connected(&mut app).active_tab, TabId::Scripts);
+        assert!(app.modal.is_empty());
+    }
+
+    #[test]
+    fn update_place_selection_and_filter() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Places(vec![
+            place(),
+        ])));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::TogglePlacesSelectMode));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::TogglePlaceSelected {
+            place_name: "board-1".to_string(),
+        }));
+        assert!(connected(&mut app).selected_places.contains("board-1"));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::TogglePlaceSelected {
+            place_name: "board-1".to_string(),
+        }));
+        assert!(connected(&mut app).selected_places.is_empty());
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::SelectAllPlaces));
+        assert_eq!(connected(&mut app).selected_places.len(), 1);
+        // Leaving the select mode clears the selection
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::TogglePlacesSelectMode));
+        assert!(connected(&mut app).selected_places.is_empty());
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::PlacesOnlyMine(true)));
+        assert!(connected(&mut app).places_only_mine);
+    }
+
+    #[test]
+    fn update_exporters() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Resource(
+            resource(),
+        )));
+        assert!(connected(&mut app)
+            .exporter_last_seen
+            .get("exporter-1")
+            .is_some());
+        // The exporter disconnects and comes back
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::DeleteResource(
+            resource().path,
+        )));
+        assert!(connected(&mut app)
+            .exporter_last_seen
+            .offline_since("exporter-1")
+            .is_some());
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Resource(
+            resource(),
+        )));
+        assert_eq!(
+            connected(&mut app)
+                .exporter_last_seen
+                .offline_since("exporter-1"),
+            None
+        );
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ShowExporterResources(
+            "exporter-1".to_string(),
+        )));
+        assert_eq!(connected(&mut app).active_tab, TabId::Resources);
+        assert_eq!(
+            connected(&mut app).resources_exporter_filter.as_deref(),
+            Some("exporter-1")
+        );
+        let _ = app.update(AppMsg::Connected(
+            ConnectedMsg::ClearResourcesExporterFilter,
+        ));
+        assert_eq!(connected(&mut app).resources_exporter_filter, None);
+    }
+
+    #[test]
+    fn update_class_filter() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ShowClassResources(
+            "NetworkSerialPort".to_string(),
+        )));
+        assert_eq!(connected(&mut app).active_tab, TabId::Resources);
+        assert_eq!(
+            connected(&mut app).resources_class_filter.as_deref(),
+            Some("NetworkSerialPort")
+        );
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ClearResourcesClassFilter));
+        assert_eq!(connected(&mut app).resources_class_filter, None);
+    }
+
+    #[test]
+    fn update_resources_class_and_search() {
+        let (mut app, _receiver) = connected_app();
+        connected(&mut app).resources = vec![
+            (resource(), ResourceUi::default()),
+            (
+                Resource {
+                    cls: "NetworkPowerPort".to_string(),
+                    path: types::Path {
+                        resource_name: "power".to_string(),
+                        ..resource().path
+                    },
+                    ..resource()
+                },
+                ResourceUi::default(),
+            ),
+        ];
+        let displayed = |app: &mut App| {
+            let connected = connected(app);
+            connected
+                .resources
+                .iter()
+                .filter(|(r, _)| connected.resource_filter().matches(r))
+                .map(|(r, _)| r.path.resource_name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(displayed(&mut app), ["serial", "power"]);
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ResourcesClassSelected(
+            "NetworkPowerPort".to_string(),
+        )));
+        assert_eq!(displayed(&mut app), ["power"]);
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ClearResourcesClassFilter));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ResourcesSearchInput(
+            "SERIAL".to_string(),
+        )));
+        assert_eq!(displayed(&mut app), ["serial"]);
+    }
+
+    #[test]
+    fn update_watched_resource_availability() {
+        let (mut app, _receiver) = connected_app();
+        let path = resource().path;
+        let _ = app.update(AppMsg::ToggleWatchResource(path.clone()));
+        assert!(app.watched_resources.contains("exporter-1/board-1/serial"));
+        assert!(AppMsg::ToggleWatchResource(path.clone()).changes_settings());
+
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Resource(
+            resource(),
+        )));
+        assert!(connected(&mut app).resource_watch.notices.is_empty());
+        // The exporter goes down and comes back
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::DeleteResource(
+            path.clone(),
+        )));
+        assert!(!connected(&mut app).resource_watch.notices[0].available);
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Resource(
+            resource(),
+        )));
+        let notices = &connected(&mut app).resource_watch.notices;
+        assert_eq!(notices.len(), 1);
+        assert!(notices[0].available);
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::DismissAvailabilityNotice(
+            path.clone(),
+        )));
+        assert!(connected(&mut app).resource_watch.notices.is_empty());
+        let _ = app.update(AppMsg::ToggleWatchResource(path));
+        assert!(app.watched_resources.is_empty());
+    }
+
+    #[test]
+    fn update_metrics() {
+        let metrics = Metrics::new();
+        let (mut app, _receiver) = test_app();
+        app.metrics = Some(Arc::clone(&metrics));
+        let connect = || {
+            AppMsg::ConnectionEvent(ConnectionEvent::Connected {
+                address: "localhost:20408".to_string(),
+                identity: "host/me".to_string(),
+            })
+        };
+        let _ = app.update(connect());
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Places(vec![
+            place(),
+        ])));
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Resource(
+            resource(),
+        )));
+        let mut acquired = place();
+        acquired.acquired = Some("host/other".to_string());
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(acquired)));
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Disconnected {
+            error: Some(error("Lost", None)),
+        }));
+        assert!(!metrics.is_connected());
+        let _ = app.update(connect());
+
+        let rendered = metrics.render();
+        for line in [
+            "labgrid_ui_connected 1",
+            // Reset by the reconnect
+            "labgrid_ui_places 0",
+            "labgrid_ui_resources 0",
+            "labgrid_ui_place_acquires_total 1",
+            "labgrid_ui_place_releases_total 0",
+            "labgrid_ui_grpc_errors_total 1",
+            "labgrid_ui_reconnects_total 1",
+        ] {
+            assert!(rendered.lines().any(|l| l == line), "missing '{line}'");
+        }
+    }
+
+    #[test]
+    fn update_accessibility() {
+        let (mut app, _receiver) = test_app();
+        assert!(AppMsg::ChangeUiScale(150).changes_settings());
+        assert!(!AppMsg::SystemThemeChanged(theme::Mode::Dark).changes_settings());
+        let _ = app.update(AppMsg::ChangeUiScale(150));
+        assert_eq!(app.scale_factor(), 1.5);
+        assert_eq!(app.theme(), None);
+
+        let _ = app.update(AppMsg::HighContrast(true));
+        assert_eq!(app.theme().unwrap().to_string(), "High Contrast Dark");
+        let _ = app.update(AppMsg::SystemThemeChanged(theme::Mode::Light));
+        assert_eq!(app.theme().unwrap().to_string(), "High Contrast Light");
+        app.theme = Some(AppTheme::Dark);
+        assert_eq!(app.theme().unwrap().to_string(), "High Contrast Dark");
+
+        let config = app.extract_config();
+        assert_eq!(config.ui_scale_percent, 150);
+        assert!(config.high_contrast);
+        app.load_config(Config {
+            ui_scale_percent: 0,
+            ..config
+        });
+        assert_eq!(app.ui_scale_percent, 50);
+    }
+
+    #[test]
+    fn update_view_state_restored() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::TabSelected(TabId::Scripts)));
+        connected(&mut app).resources_only_show_available = false;
+        let _ = app.update(AppMsg::WindowResized(Size::new(1280., 720.)));
+        let _ = app.update(AppMsg::WindowMoved(Point::new(10., 20.)));
+        let config = app.extract_config();
+        assert_eq!(config.view_state.active_tab, TabId::Scripts);
+        assert_eq!(
+            config.view_state.window,
+            Some(WindowGeometry {
+                width: 1280.,
+                height: 720.,
+                position: Some((10., 20.)),
+            })
+        );
+
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Disconnected {
+            error: None,
+        }));
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Connected {
+            address: "localhost:20408".to_string(),
+            identity: "host/me".to_string(),
+        }));
+        assert_eq!(connected(&mut app).active_tab, TabId::Scripts);
+        assert!(!connected(&mut app).resources_only_show_available);
+    }
+
+    #[test]
+    fn update_clipboard_copy_paste() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::ClipboardCopy("board-2".to_string()));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ClipboardPasteAddPlaceName));
+        assert_eq!(connected(&mut app).add_place_text, "board-2");
+        assert!(app.errors.stack().is_empty());
+    }
+
+    #[test]
+    fn update_launch_quick_connect_without_terminal() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::LaunchQuickConnect(QuickConnect {
+            program: "ssh",
+            args: vec!["root@192.168.1.10".to_string()],
+        }));
+        assert_eq!(app.errors.stack().len(), 1);
+        assert!(app.errors.stack()[0]
+            .detailed
+            .contains("No terminal emulator configured"));
+    }
+
+    #[test]
+    fn update_connection_msg_forwarded() {
+        let (mut app, mut receiver) = connected_app();
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::Refresh));
+        assert!(matches!(receiver.try_next(), Ok(Some(ConnectionMsg::Sync))));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::GetReservations))
+        ));
+        let _ = app.update(AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        }));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::AcquirePlace { name })) if name == "board-1"
+        ));
+        assert!(connected(&mut app).last_own_place_action.is_some());
+    }
+
+    #[test]
+    fn update_modals() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::Settings)));
+        assert!(matches!(app.modal.base(), Modal::Settings));
+        let _ = app.update(AppMsg::HideModal);
+        assert!(app.modal.is_empty());
+
+        // The wrapped message is handled after the modal is hidden
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::Settings)));
+        let _ = app.update(AppMsg::OptimizeTouch(true).hide_modal());
+        assert!(app.modal.is_empty());
+        assert!(app.optimize_touch);
+    }
+
+    #[test]
+    fn update_errors() {
+        let (mut app, _receiver) = test_app();
+        app.errors.push(error("first", None));
+        app.errors.push(error(
+            "second",
+            Some(AppMsg::NotConnected(NotConnectedMsg::UpdateInputAddress(
+                "localhost:20408".to_string(),
+            ))),
+        ));
+        let _ = app.update(AppMsg::RetryError);
+        assert_eq!(app.coordinator_address(), "localhost:20408");
+        assert_eq!(app.errors.stack().len(), 1);
+        // Without a retry the error is only dismissed
+        let _ = app.update(AppMsg::RetryError);
+        assert!(app.errors.stack().is_empty());
+
+        app.errors.push(error("third", None));
+        let _ = app.update(AppMsg::DismissError);
+        assert!(app.errors.stack().is_empty());
+        let _ = app.update(AppMsg::DismissError);
+
+        // Dismissed errors are kept for the error console
+        assert_eq!(app.errors.logged(), 3);
+        assert_eq!(app.errors.unseen(), 3);
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::ErrorConsole)));
+        assert_eq!(app.errors.unseen(), 0);
+        let _ = app.update(AppMsg::ChangeErrorFilter(ErrorFilter::Critical));
+        assert_eq!(app.errors.filtered().count(), 0);
+        let _ = app.update(AppMsg::ClearErrors);
+        assert_eq!(app.errors.logged(), 0);
+    }
+
+    #[test]
+    fn update_settings() {
+        let (mut app, _receiver) = test_app();
+        let _ = app.update(AppMsg::ToggleWatchPlace {
+            place_name: "board-1".to_string(),
+        });
+        assert!(app.watched_places.contains("board-1"));
+        let _ = app.update(AppMsg::ToggleWatchPlace {
+            place_name: "board-1".to_string(),
+        });
+        assert!(app.watched_places.is_empty());
+
+        let _ = app.update(AppMsg::ChangeQuickAction {
+            action: QuickAction::Console,
+            enabled: true,
+        });
+        assert!(app.quick_actions.enabled(QuickAction::Console));
+        let _ = app.update(AppMsg::ChangeResourceGracePeriod(5));
+        assert_eq!(app.resource_grace_period_secs, 5);
+        let _ = app.update(AppMsg::ChangeOfflineExporterResources(
+            OfflineExporterResources::Hide,
+        ));
+        assert_eq!(
+            app.offline_exporter_resources,
+            OfflineExporterResources::Hide
+        );
+
+        assert_eq!(app.connection_timeouts.preset(), Some(TimeoutPreset::Vpn));
+        let _ = app.update(AppMsg::ChangeConnectionTimeouts(
+            TimeoutPreset::Lan.timeouts(),
+        ));
+        assert_eq!(app.connection_timeouts.preset(), Some(TimeoutPreset::Lan));
+        let _ = app.update(AppMsg::ChangeConnectionTimeouts(ConnectionTimeouts {
+            rpc_secs: 15,
+            ..app.connection_timeouts
+        }));
+        assert_eq!(app.connection_timeouts.preset(), None);
+        assert_eq!(app.connection_timeouts.rpc(), Duration::from_secs(15));
+
+        let _ = app.update(AppMsg::ChangeVenvDir {
+            dir: PathBuf::from("/does/not/exist"),
+        });
+        assert_eq!(app.errors.stack().len(), 1);
+        assert_ne!(app.venv_dir, PathBuf::from("/does/not/exist"));
+    }
+
+    #[test]
+    fn update_venv_versions() {
+        let (mut app, _receiver) = test_app();
+        let versions = VenvVersions {
+            python: "3.11.2".to_string(),
+            labgrid: Some("24.0.1".to_string()),
+        };
+        // Results for a venv that isn't selected anymore are ignored
+        let _ = app.update(AppMsg::VenvVersionsDetected {
+            dir: PathBuf::from("/previous/venv"),
+            versions: Ok(versions.clone()),
+        });
+        assert_eq!(app.venv_versions, None);
+        let _ = app.update(AppMsg::VenvVersionsDetected {
+            dir: app.venv_dir.clone(),
+            versions: Ok(versions.clone()),
+        });
+        assert_eq!(app.venv_versions, Some(Ok(versions)));
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::Settings)));
+        assert_eq!(app.venv_versions, None);
+    }
+
+    #[test]
+    fn update_log_viewer() {
+        let (mut app, _receiver) = test_app();
+        let _ = app.update(AppMsg::LogViewer(LogViewerMsg::ChangeLevel(
+            LogLevel::Debug,
+        )));
+        assert_eq!(app.log_level, LogLevel::Debug);
+        assert_eq!(log_viewer::LOG_BUFFER.level(), LogLevel::Debug);
+        assert!(!AppMsg::LogViewer(LogViewerMsg::Refresh).changes_settings());
+
+        let _ = app.update(AppMsg::LogViewer(LogViewerMsg::Clear));
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::LogViewer)));
+        assert!(app.log_entries.is_empty());
+        assert!(matches!(app.modal.base(), Modal::LogViewer));
+        let _ = app.update(AppMsg::LogViewer(LogViewerMsg::ChangeLevel(LogLevel::Info)));
+    }
+
+    #[test]
+    fn update_protocol_inspector() {
+        let (mut app, _receiver) = test_app();
+        let trace = &protocol_inspector::PROTOCOL_TRACE;
+        let _ = app.update(AppMsg::ProtocolInspector(ProtocolInspectorMsg::Record(
+            true,
+        )));
+        assert!(trace.is_enabled());
+        assert!(!AppMsg::ProtocolInspector(ProtocolInspectorMsg::Record(true)).changes_settings());
+
+        let _ = app.update(AppMsg::ProtocolInspector(ProtocolInspectorMsg::Record(
+            false,
+        )));
+        let _ = app.update(AppMsg::ProtocolInspector(
+            ProtocolInspectorMsg::UpdateFilter("GetPlaces".to_string()),
+        ));
+        let _ = app.update(AppMsg::ProtocolInspector(ProtocolInspectorMsg::Clear));
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::ProtocolInspector)));
+        assert!(!trace.is_enabled());
+        assert!(app.protocol_messages.is_empty());
+        assert_eq!(app.protocol_filter, "GetPlaces");
+        assert!(matches!(app.modal.base(), Modal::ProtocolInspector));
+    }
+
+    #[test]
+    fn config_overrides() {
+        use clap::Parser;
+
+        let args = crate::Args::try_parse_from([
+            "labgrid-ui",
+            "--venv-dir",
+            "/srv/venv",
+            "--language",
+            "de-DE",
+            "--theme",
+            "dark",
+        ])
+        .unwrap();
+        assert_eq!(args.language, Some(AppLanguage::DeDe));
+        assert_eq!(args.theme, Some(AppTheme::Dark));
+        assert!(crate::Args::try_parse_from(["labgrid-ui", "--language", "pt-BR"]).is_err());
+
+        let (mut app, _receiver) = test_app();
+        app.config_overrides = ConfigOverrides {
+            venv_dir: args.venv_dir,
+            ..ConfigOverrides::default()
+        };
+        app.load_config(Config {
+            venv_dir: PathBuf::from("/home/me/venv"),
+            language: app.language,
+            ..Config::default()
+        });
+        assert_eq!(app.venv_dir, PathBuf::from("/srv/venv"));
+        // The overridden value is not saved
+        assert_eq!(
+            app.extract_config().venv_dir,
+            PathBuf::from("/home/me/venv")
+        );
+        app.venv_dir = PathBuf::from("/opt/venv");
+        assert_eq!(app.extract_config().venv_dir, PathBuf::from("/opt/venv"));
+    }
+
+    #[test]
+    fn reload_config() {
+        let (mut app, _receiver) = test_app();
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-reload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+
+        // Saves of the app itself don't change anything
+        app.extract_config().save_to_path(&path).unwrap();
+        app.reload_config_from_path(&path);
+        assert!(!app.optimize_touch);
+
+        let config = Config {
+            optimize_touch: true,
+            bulk_ops_per_sec: 3,
+            ..app.extract_config()
+        };
+        config.save_to_path(&path).unwrap();
+        app.reload_config_from_path(&path);
+        assert!(app.optimize_touch);
+        assert_eq!(app.bulk_ops_per_sec, 3);
+
+        // Invalid files are reported and the settings are kept
+        std::fs::write(&path, "{ \"optimize_touch\": 1 }").unwrap();
+        app.reload_config_from_path(&path);
+        assert!(app.optimize_touch);
+        assert_eq!(app.errors.stack().len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn update_autosave() {
+        let (mut app, _receiver) = test_app();
+        assert!(AppMsg::ChangeAutosave(60).changes_settings());
+        assert!(!AppMsg::SaveConfig.changes_settings());
+        let _ = app.update(AppMsg::ChangeAutosave(0));
+        assert_eq!(app.autosave_secs, 0);
+        assert_eq!(app.extract_config().autosave_secs, 0);
+
+        // Only the confirmation of the latest save is hidden
+        let first = Instant::now();
+        let latest = first + Duration::from_secs(1);
+        app.settings_saved_confirmation = Some(latest);
+        let _ = app.update(AppMsg::HideSettingsSavedConfirmation(first));
+        assert_eq!(app.settings_saved_confirmation, Some(latest));
+        let _ = app.update(AppMsg::HideSettingsSavedConfirmation(latest));
+        assert_eq!(app.settings_saved_confirmation, None);
+    }
+
+    #[test]
+    fn update_file_browser() {
+        let (mut app, _receiver) = test_app();
+        let _ = app.update(AppMsg::ShowModal(Box::new(Modal::Settings)));
+        let _ = app.update(AppMsg::FileBrowser(FileBrowserMsg::NativeDialogFailed {
+            target: FileBrowserTarget::LgEnvFile,
+            dir: PathBuf::from("/lab/envs/missing"),
+        }));
+        assert!(app.native_dialogs_unavailable);
+        // Shown on top of the settings
+        assert!(matches!(app.modal.base(), Modal::Settings));
+        assert!(matches!(app.modal.overlay(), Some(Modal::FileBrowser)));
+
+        // Listings of directories that are no longer displayed are ignored
+        let listed = |requested: &str| {
+            AppMsg::FileBrowser(FileBrowserMsg::Listed {
+                requested: PathBuf::from(requested),
+                dir: PathBuf::from("/lab/envs"),
+                entries: Ok(vec![FileBrowserEntry {
+                    name: "board-1.yaml".to_string(),
+                    is_dir: false,
+                }]),
+            })
+        };
+        let _ = app.update(listed("/lab"));
+        assert!(app.file_browser.as_ref().unwrap().entries.is_none());
+        let _ = app.update(listed("/lab/envs/missing"));
+        let browser = app.file_browser.as_ref().unwrap();
+        assert_eq!(browser.dir, PathBuf::from("/lab/envs"));
+        assert_eq!(browser.path_input, "/lab/envs");
+        assert_eq!(browser.picked(), None);
+
+        let _ = app.update(AppMsg::FileBrowser(FileBrowserMsg::Select(
+            "board-1.yaml".to_string(),
+        )));
+        assert_eq!(
+            app.file_browser.as_ref().unwrap().picked(),
+            Some(PathBuf::from("/lab/envs/board-1.yaml"))
+        );
+        let _ = app.update(AppMsg::FileBrowser(FileBrowserMsg::Pick));
+        assert!(app.file_browser.is_none());
+        assert!(matches!(app.modal.base(), Modal::Settings));
+        assert!(app.modal.overlay().is_none());
+
+        // The file browser is used right away from now on
+        let _ = app.update(AppMsg::PickPath {
+            target: FileBrowserTarget::VenvDir,
+            dir: PathBuf::from("/opt/labgrid/venv"),
+        });
+        let browser = app.file_browser.as_ref().unwrap();
+        assert_eq!(browser.picked(), Some(PathBuf::from("/opt/labgrid/venv")));
+    }
+
+    #[test]
+    fn update_script_validation() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::CheckScriptImports(false));
+        assert!(!app.extract_config().check_script_imports);
+
+        let script = Script {
+            path: PathBuf::from("/scripts/flash.py"),
+            name: "flash.py".to_string(),
+            _type: scripts::ScriptType::Python,
+            front_matter: scripts::FrontMatter::default(),
+        };
+        let validation =
+            |app: &mut App| connected(app).script_validations.get(&script.path).cloned();
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ValidateScript {
+            script: script.clone(),
+        }));
+        assert_eq!(validation(&mut app), Some(ScriptValidation::Running));
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ScriptValidated {
+            script: script.clone(),
+            errors: Some("SyntaxError: invalid syntax".to_string()),
+        }));
+        assert_eq!(validation(&mut app), Some(ScriptValidation::Invalid));
+        assert!(connected(&mut app).script_show_output);
+        assert!(connected(&mut app)
+            .script_out
+            .contains("SyntaxError: invalid syntax"));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ScriptValidated {
+            script: script.clone(),
+            errors: None,
+        }));
+        assert_eq!(validation(&mut app), Some(ScriptValidation::Valid));
+
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ScriptValidationFailed {
+            script: script.clone(),
+            err: "No such file or directory".to_string(),
+        }));
+        assert_eq!(validation(&mut app), None);
+        assert_eq!(app.errors.stack().len(), 1);
+    }
+
+    #[test]
+    fn update_script_timeout() {
+        let (mut app, _receiver) = connected_app();
+        let _ = app.update(AppMsg::ChangeScriptTimeout(30));
+        assert_eq!(
+            connected(&mut app).scripts.default_timeout,
+            Some(Duration::from_secs(30 * 60))
+        );
+        assert_eq!(app.extract_config().script_timeout_mins, 30);
+
+        let mut script = Script {
+            path: PathBuf::from("/scripts/pytest.sh"),
+            name: "pytest.sh".to_string(),
+            _type: scripts::ScriptType::Shell,
+            front_matter: scripts::FrontMatter::default(),
+        };
+        let timed_out = |script: &Script| {
+            AppMsg::Connected(ConnectedMsg::ScriptTimedOut {
+                script: script.clone(),
+                timeout: Duration::from_secs(1800),
+                stdout: "test_flash PASSED".to_string(),
+                stderr: String::new(),
+            })
+        };
+        let _ = app.update(timed_out(&script));
+        assert!(matches!(
+            connected(&mut app).script_status,
+            ScriptStatus::TimedOut { timeout, .. } if timeout == Duration::from_secs(1800)
+        ));
+        assert!(connected(&mut app).script_out.contains("test_flash PASSED"));
+
+        // Scripts with a cleanup command are cleaned up, and keep the timed out status afterwards
+        script.front_matter.cleanup = Some("true".to_string());
+        let _ = app.update(timed_out(&script));
+        assert!(matches!(
+            connected(&mut app).script_status,
+            ScriptStatus::CleaningUp {
+                timed_out: Some(_),
+                ..
+            }
+        ));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ScriptCleanupFinished {
+            script,
+            exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+        }));
+        assert!(matches!(
+            connected(&mut app).script_status,
+            ScriptStatus::TimedOut { .. }
+        ));
+    }
+
+    #[test]
+    fn update_reservation_run() {
+        let (mut app, mut receiver) = connected_app();
+        let script = Script {
+            path: PathBuf::from("/scripts/flash.py"),
+            name: "flash.py".to_string(),
+            _type: scripts::ScriptType::Python,
+            front_matter: scripts::FrontMatter::default(),
+        };
+        connected(&mut app).scripts.scripts.push(script.clone());
+        let filters = HashMap::from([(
+            "main".to_string(),
+            types::Filter(HashMap::from([("board".to_string(), "imx8".to_string())])),
+        )]);
+        let reservation = |state: ReservationState| Reservation {
+            owner: "host/me".to_string(),
+            token: "AAAA".to_string(),
+            state: state.into(),
+            prio: 0.,
+            filters: filters.clone(),
+            allocations: HashMap::from([("main".to_string(), "board-1".to_string())]),
+            created: 0.,
+            timeout: 0.,
+        };
+
+        // Not started without a script
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::UpdateReservationRunFilter(
+            "board=imx8".to_string(),
+        )));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::StartReservationRun));
+        assert!(receiver.try_next().is_err());
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::SelectReservationRunScript(
+            "flash.py".to_string(),
+        )));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::StartReservationRun));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::CreateReservation { filters: created, .. })) if created == filters
+        ));
+
+        let _ = app.update(AppMsg::ConnectionEvent(
+            ConnectionEvent::ReservationCreated(reservation(ReservationState::Waiting)),
+        ));
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::PollReservationRun));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::PollReservation { token })) if token == "AAAA"
+        ));
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Reservations(
+            vec![reservation(ReservationState::Allocated)],
+        )));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::AcquirePlace { name })) if name == "board-1"
+        ));
+        let mut acquired = place();
+        acquired.acquired = Some("host/me".to_string());
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(acquired)));
+        assert!(matches!(
+            connected(&mut app).script_status,
+            ScriptStatus::Running { .. }
+        ));
+
+        // Released and cancelled once the script finished
+        let _ = app.update(AppMsg::Connected(ConnectedMsg::ScriptFinished {
+            script,
+            exit_code: 0,
+            stdout: String::new(),
+            stderr: String::new(),
+        }));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::ReleasePlace { name })) if name == "board-1"
+        ));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::CancelReservation { token })) if token == "AAAA"
+        ));
+        assert!(connected(&mut app).reservation_run.is_none());
+    }
+
+    #[test]
+    fn update_mqtt_settings() {
+        let (mut app, _receiver) = test_app();
+        let enabled = MqttSettings {
+            enabled: true,
+            ..MqttSettings::default()
+        };
+        // Enabling applies right away
+        let _ = app.update(AppMsg::ChangeMqttSettings(enabled.clone()));
+        assert_eq!(
+            app.mqtt_applied.as_ref().map(|(settings, _)| settings),
+            Some(&enabled)
+        );
+        assert_eq!(app.extract_config().mqtt, enabled);
+
+        // Other changes only once applied
+        let changed = MqttSettings {
+            broker: "broker:1883".to_string(),
+            ..enabled.clone()
+        };
+        let _ = app.update(AppMsg::ChangeMqttSettings(changed.clone()));
+        let _ = app.update(AppMsg::MqttStatus(MqttStatus::Connected));
+        assert_eq!(
+            app.mqtt_applied.as_ref().map(|(settings, _)| settings),
+            Some(&enabled)
+        );
+        let _ = app.update(AppMsg::ApplyMqttSettings);
+        assert_eq!(
+            app.mqtt_applied.as_ref().map(|(settings, _)| settings),
+            Some(&changed)
+        );
+        assert_eq!(app.mqtt_status, None);
+
+        let _ = app.update(AppMsg::ChangeMqttSettings(MqttSettings::default()));
+        assert_eq!(app.mqtt_applied, None);
+    }
+
+    #[test]
+    fn update_session_timer() {
+        let (mut app, mut receiver) = connected_app();
+        let mut acquired = place();
+        acquired.acquired = Some("host/me".to_string());
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Places(vec![
+            place(),
+        ])));
+        // Acquired by the same identity, but not through the UI
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(
+            acquired.clone(),
+        )));
+        assert!(connected(&mut app).session_timers.is_empty());
+
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(place())));
+        let _ = app.update(AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        }));
+        let _ = receiver.try_next();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(acquired)));
+        assert!(connected(&mut app).session_timers.contains_key("board-1"));
+
+        // Not released while auto-release is disabled
+        let _ = app.update(AppMsg::SessionTimerTick);
+        assert!(receiver.try_next().is_err());
+        let _ = app.update(AppMsg::ChangeAutoRelease(AutoReleaseSettings {
+            enabled: true,
+            idle_mins: 0,
+            warn_mins: 0,
+            release_on_exit: false,
+        }));
+        let _ = app.update(AppMsg::SessionTimerTick);
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::ReleasePlace { name })) if name == "board-1"
+        ));
+        assert!(connected(&mut app).session_timers.is_empty());
+    }
+
+    #[test]
+    fn update_close_window_shutdown() {
+        let (mut app, mut receiver) = connected_app();
+        let mut acquired = place();
+        acquired.acquired = Some("host/me".to_string());
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Places(vec![
+            place(),
+        ])));
+        let _ = app.update(AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        }));
+        let _ = receiver.try_next();
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::Place(acquired)));
+        let _ = app.update(AppMsg::ChangeAutoRelease(AutoReleaseSettings {
+            release_on_exit: true,
+            ..AutoReleaseSettings::default()
+        }));
+
+        let id = window::Id::unique();
+        let _ = app.update(AppMsg::CloseWindow(id));
+        assert!(matches!(
+            receiver.try_next(),
+            Ok(Some(ConnectionMsg::Shutdown { release })) if release == ["board-1"]
+        ));
+        assert_eq!(app.closing_window, Some(id));
+
+        let _ = app.update(AppMsg::ConnectionEvent(ConnectionEvent::ShutdownComplete));
+        assert_eq!(app.closing_window, None);
+        // The timeout after the completed shutdown has no effect
+        let _ = app.update(AppMsg::ShutdownTimedOut);
+        assert_eq!(app.closing_window, None);
+    }
+}
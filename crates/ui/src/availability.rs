@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::ResourceUi;
+use labgrid_ui_core::pattern::MatchPattern;
+use labgrid_ui_core::types::{Place, Resource};
+
+/// How many of the resources matched by a place are available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ResourceAvailability {
+    pub(crate) available: usize,
+    /// The matched resources, plus one for every match that doesn't match any resource.
+    pub(crate) total: usize,
+}
+
+impl ResourceAvailability {
+    /// Whether acquiring the place gives a complete setup.
+    pub(crate) fn is_complete(&self) -> bool {
+        self.available == self.total
+    }
+}
+
+/// The availability of the resources matched by the place, `None` if it has no matches.
+///
+/// Stale resources are counted as unavailable.
+pub(crate) fn resource_availability(
+    place: &Place,
+    resources: &[(Resource, ResourceUi)],
+) -> Option<ResourceAvailability> {
+    if place.matches.is_empty() {
+        return None;
+    }
+    let patterns = place
+        .matches
+        .iter()
+        .map(|m| m.pattern())
+        .collect::<Vec<MatchPattern>>();
+    let matched = resources
+        .iter()
+        .filter(|(r, _)| patterns.iter().any(|p| p.matches(&r.path, &r.cls)))
+        .collect::<Vec<_>>();
+    let unmatched = patterns
+        .iter()
+        .filter(|p| !resources.iter().any(|(r, _)| p.matches(&r.path, &r.cls)))
+        .count();
+    Some(ResourceAvailability {
+        available: matched
+            .iter()
+            .filter(|(r, ui)| r.available && ui.stale_since.is_none())
+            .count(),
+        total: matched.len() + unmatched,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use labgrid_ui_core::types::ResourceMatch;
+    use std::time::Instant;
+
+    fn resource(name: &str, cls: &str, available: bool) -> Resource {
+        Resource {
+            available,
+            ..test_fixtures::resource(name, cls)
+        }
+    }
+
+    fn place(patterns: &[&str]) -> Place {
+        Place {
+            matches: patterns
+                .iter()
+                .map(|p| p.parse::<MatchPattern>().unwrap().into_resource_match(None))
+                .collect::<Vec<ResourceMatch>>(),
+            ..test_fixtures::place("board-1")
+        }
+    }
+
+    #[test]
+    fn place_resource_availability() {
+        let resources = vec![
+            (
+                resource("serial", "NetworkSerialPort", true),
+                ResourceUi::default(),
+            ),
+            (
+                resource("power", "NetworkPowerPort", false),
+                ResourceUi::default(),
+            ),
+            (
+                resource("usb", "USBSerialPort", true),
+                ResourceUi {
+                    stale_since: Some(Instant::now()),
+                    ..ResourceUi::default()
+                },
+            ),
+            (
+                resource("other", "NetworkService", true),
+                ResourceUi::default(),
+            ),
+        ];
+
+        assert_eq!(resource_availability(&place(&[]), &resources), None);
+        let availability = resource_availability(
+            &place(&[
+                "exporter-1/board-1/*SerialPort",
+                "exporter-1/board-1/NetworkPowerPort",
+                "exporter-2/*/*",
+            ]),
+            &resources,
+        )
+        .unwrap();
+        assert_eq!(
+            availability,
+            ResourceAvailability {
+                available: 1,
+                total: 4
+            }
+        );
+        assert!(!availability.is_complete());
+        assert!(
+            resource_availability(&place(&["*/*/NetworkService"]), &resources)
+                .unwrap()
+                .is_complete()
+        );
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use iced::widget::image;
+use labgrid_ui_core::types::{MapValue, Resource};
+
+#[cfg(feature = "camera-preview")]
+pub(crate) use http::fetch_snapshot;
+
+/// The classes of resources that get a preview in the resource details.
+pub(crate) const CAMERA_CLASSES: [&str; 3] = ["HTTPVideoStream", "USBVideo", "NetworkUSBVideo"];
+/// The key of the resource extra holding a snapshot URL, for cameras that don't have an HTTP URL param.
+pub(crate) const SNAPSHOT_URL_EXTRA: &str = "snapshot_url";
+
+/// The state of the preview of a camera resource.
+#[derive(Debug, Clone)]
+pub(crate) enum CameraPreview {
+    Loading,
+    Loaded(image::Handle),
+    Failed(String),
+}
+
+/// Whether resources of the class are previewed, never when built without the `camera-preview` feature.
+pub(crate) fn is_camera(cls: &str) -> bool {
+    cfg!(feature = "camera-preview") && CAMERA_CLASSES.contains(&cls)
+}
+
+/// The URL a snapshot of the camera resource is fetched from, `None` if it has none.
+///
+/// The `snapshot_url` extra takes precedence over the `url` param of `HTTPVideoStream`s,
+/// so that USB cameras can be previewed through a separate HTTP service of the exporter.
+pub(crate) fn snapshot_url(resource: &Resource) -> Option<&str> {
+    fn url(value: &MapValue) -> Option<&str> {
+        match value {
+            MapValue::String(url) if !url.is_empty() => Some(url),
+            _ => None,
+        }
+    }
+    resource
+        .extra
+        .get(SNAPSHOT_URL_EXTRA)
+        .and_then(url)
+        .or_else(|| resource.params.get("url").and_then(url))
+}
+
+/// Fetching snapshots over HTTP, only built with the `camera-preview` feature.
+#[cfg(feature = "camera-preview")]
+mod http {
+    use anyhow::Context;
+    use bytes::Bytes;
+    use http_body_util::{BodyExt, Empty};
+    use hyper::header::{CONTENT_TYPE, HOST, USER_AGENT};
+    use hyper::{Request, Uri};
+    use hyper_util::rt::TokioIo;
+    use std::time::Duration;
+    use tokio::net::TcpStream;
+    use tracing::debug;
+
+    const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(10);
+    /// Larger snapshots are not displayed, also limits reading streams that don't contain a frame.
+    const SNAPSHOT_MAX_LEN: usize = 16 * 1024 * 1024;
+
+    /// Fetches a snapshot from the `http` URL.
+    ///
+    /// The response is either a single image, or an MJPEG stream of which the first frame is used.
+    pub(crate) async fn fetch_snapshot(url: String) -> Result<Vec<u8>, String> {
+        tokio::time::timeout(SNAPSHOT_TIMEOUT, fetch(&url))
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("Timed out")))
+            .map_err(|e| format!("{e:#}"))
+    }
+
+    async fn fetch(url: &str) -> anyhow::Result<Vec<u8>> {
+        let uri = url.parse::<Uri>().context("Invalid URL")?;
+        if uri.scheme_str() != Some("http") {
+            anyhow::bail!("Unsupported scheme, only 'http' is supported");
+        }
+        let host = uri.host().context("URL has no host")?;
+        let port = uri.port_u16().unwrap_or(80);
+        let stream = TcpStream::connect((host.trim_matches(['[', ']']), port))
+            .await
+            .context("Connect")?;
+        let (mut sender, connection) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+            .await
+            .context("HTTP handshake")?;
+        tokio::spawn(async move {
+            if let Err(error) = connection.await {
+                // Expected when a stream is dropped after its first frame
+                debug!(?error, "Camera snapshot connection");
+            }
+        });
+        let request = Request::builder()
+            .uri(
+                uri.path_and_query()
+                    .map_or("/", |path_and_query| path_and_query.as_str()),
+            )
+            .header(
+                HOST,
+                uri.authority().map_or(host, |authority| authority.as_str()),
+            )
+            .header(
+                USER_AGENT,
+                concat!("labgrid-ui/", env!("CARGO_PKG_VERSION")),
+            )
+            .body(Empty::<Bytes>::new())?;
+        let response = sender.send_request(request).await.context("Request")?;
+        if !response.status().is_success() {
+            anyhow::bail!("Camera responded with status {}", response.status());
+        }
+        let is_stream = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.starts_with("multipart/"));
+
+        let mut body = response.into_body();
+        let mut data = Vec::new();
+        while let Some(frame) = body.frame().await {
+            if let Ok(chunk) = frame.context("Read response")?.into_data() {
+                data.extend_from_slice(&chunk);
+            }
+            if is_stream {
+                if let Some(jpeg) = first_jpeg(&data) {
+                    return Ok(jpeg.to_vec());
+                }
+            }
+            if data.len() > SNAPSHOT_MAX_LEN {
+                anyhow::bail!("Snapshot is larger than {SNAPSHOT_MAX_LEN} bytes");
+            }
+        }
+        if is_stream {
+            anyhow::bail!("Stream ended without a frame");
+        }
+        Ok(data)
+    }
+
+    /// The first complete JPEG image in the data of an MJPEG stream.
+    ///
+    /// Found by its start and end markers, so that the multipart boundaries and headers don't need to be parsed.
+    fn first_jpeg(data: &[u8]) -> Option<&[u8]> {
+        let start = data.windows(2).position(|w| w == [0xff, 0xd8])?;
+        let len = data[start..].windows(2).position(|w| w == [0xff, 0xd9])?;
+        Some(&data[start..start + len + 2])
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        #[test]
+        fn mjpeg_first_frame() {
+            let stream = b"--frame\r\nContent-Type: image/jpeg\r\n\r\n\
+                \xff\xd8abc\xff\xd9\r\n--frame\r\n\xff\xd8";
+            assert_eq!(first_jpeg(stream), Some(&b"\xff\xd8abc\xff\xd9"[..]));
+            assert_eq!(first_jpeg(b"--frame\r\n\xff\xd8abc"), None);
+        }
+
+        /// Serves the response to the first request, returns the URL.
+        async fn serve(response: &'static [u8]) -> String {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request).await.unwrap();
+                stream.write_all(response).await.unwrap();
+                // Keeps streams open, the snapshot must be taken from the first frame
+                tokio::time::sleep(Duration::from_secs(30)).await;
+            });
+            format!("http://{addr}/snapshot")
+        }
+
+        #[tokio::test]
+        async fn camera_fetch_snapshot() {
+            let url = serve(
+                b"HTTP/1.1 200 OK\r\nContent-Type: image/jpeg\r\nContent-Length: 5\r\n\r\nimage",
+            )
+            .await;
+            assert_eq!(fetch_snapshot(url).await.unwrap(), b"image");
+
+            let url = serve(
+                b"HTTP/1.1 200 OK\r\nContent-Type: multipart/x-mixed-replace; boundary=frame\r\n\r\n\
+                --frame\r\nContent-Type: image/jpeg\r\n\r\n\xff\xd8frame\xff\xd9\r\n",
+            )
+            .await;
+            assert_eq!(fetch_snapshot(url).await.unwrap(), b"\xff\xd8frame\xff\xd9");
+
+            let url = serve(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n").await;
+            assert!(fetch_snapshot(url).await.unwrap_err().contains("404"));
+            assert!(fetch_snapshot("https://cam/snapshot".to_string())
+                .await
+                .unwrap_err()
+                .contains("Unsupported scheme"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    #[test]
+    fn camera_snapshot_url() {
+        let mut resource = Resource {
+            params: test_fixtures::params(&[(
+                "url",
+                MapValue::String("http://cam/stream".to_string()),
+            )]),
+            ..test_fixtures::resource("camera", "HTTPVideoStream")
+        };
+        assert_eq!(is_camera(&resource.cls), cfg!(feature = "camera-preview"));
+        assert!(!is_camera("NetworkSerialPort"));
+        assert_eq!(snapshot_url(&resource), Some("http://cam/stream"));
+        resource.extra.insert(
+            SNAPSHOT_URL_EXTRA.to_string(),
+            MapValue::String("http://cam/snapshot.jpg".to_string()),
+        );
+        assert_eq!(snapshot_url(&resource), Some("http://cam/snapshot.jpg"));
+        resource.extra.clear();
+        resource.params.clear();
+        assert_eq!(snapshot_url(&resource), None);
+    }
+}
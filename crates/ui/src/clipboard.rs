@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Context;
+use tracing::debug;
+
+/// Access to a clipboard.
+///
+/// Abstracted so that the message handling of the app does not depend on the system clipboard.
+pub(crate) trait ClipboardAccess {
+    /// Get the clipboard text.
+    fn text(&mut self) -> anyhow::Result<String>;
+
+    /// Set the clipboard text.
+    fn set_text(&mut self, text: String) -> anyhow::Result<()>;
+}
+
+impl ClipboardAccess for arboard::Clipboard {
+    fn text(&mut self) -> anyhow::Result<String> {
+        debug!("Get system clipboard text");
+        self.get_text().context("Get clipboard text")
+    }
+
+    fn set_text(&mut self, text: String) -> anyhow::Result<()> {
+        debug!("Set system clipboard text");
+        arboard::Clipboard::set_text(self, text).context("Set clipboard text")
+    }
+}
+
+/// Clipboard implementation that only holds its data in the application.
+///
+/// Useful when the host does not implement a clipboard (e.g. when running on a kiosk wayland compositor like cage).
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InternalClipboard(String);
+
+impl ClipboardAccess for InternalClipboard {
+    fn text(&mut self) -> anyhow::Result<String> {
+        Ok(self.0.clone())
+    }
+
+    fn set_text(&mut self, text: String) -> anyhow::Result<()> {
+        self.0 = text;
+        Ok(())
+    }
+}
+
+/// Used when the system clipboard is unavailable, copying does nothing and pasting is always empty.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct NoClipboard;
+
+impl ClipboardAccess for NoClipboard {
+    fn text(&mut self) -> anyhow::Result<String> {
+        Ok(String::default())
+    }
+
+    fn set_text(&mut self, _text: String) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Creates the clipboard used by the app.
+///
+/// With `internal` set, copy/pasting is not delegated to the system clipboard.
+pub(crate) fn new_clipboard(internal: bool) -> Box<dyn ClipboardAccess> {
+    if internal {
+        return Box::new(InternalClipboard::default());
+    }
+    match arboard::Clipboard::new() {
+        Ok(clipboard) => Box::new(clipboard),
+        Err(error) => {
+            debug!(?error, "System clipboard unavailable");
+            Box::new(NoClipboard)
+        }
+    }
+}
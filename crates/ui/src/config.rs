@@ -3,36 +3,161 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use crate::app::AppMsg;
+use crate::connection::{ConnectionAuth, ConnectionTimeouts};
+use crate::exporters::OfflineExporterResources;
+use crate::headless::HeadlessJob;
+use crate::history::AcquireHistory;
 use crate::i18n::AppLanguage;
-use crate::util;
+use crate::mqtt::MqttSettings;
+use crate::notifications::NotificationSettings;
+use crate::palette::CardPalette;
+use crate::profiles::ConnectionProfile;
+use crate::quick_actions::QuickActionSettings;
+use crate::resource_classes::ResourceClassRegistry;
+use crate::script_meta::ScriptMetaSettings;
+use crate::scripts;
+use crate::session_timer::AutoReleaseSettings;
+use crate::util::{self, IdentityOverride};
+use crate::view_state::ViewState;
 use anyhow::Context;
 use core::time::Duration;
-use iced::futures;
+use iced::futures::{self, SinkExt};
+use iced::stream;
+use notify::Watcher;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
 use tokio::time;
 use tokio_stream::wrappers::IntervalStream;
 use tokio_stream::StreamExt;
+use tracing::{error, info, warn};
+
+/// The default grace period for resources of exporters that went offline.
+pub(crate) const DEFAULT_RESOURCE_GRACE_PERIOD_SECS: u32 = 30;
+/// The default rate limit of bulk place operations, to not overload small coordinators.
+pub(crate) const DEFAULT_BULK_OPS_PER_SEC: u32 = 10;
+/// The default interval of the periodic configuration save.
+pub(crate) const DEFAULT_AUTOSAVE_SECS: u32 = 120;
+/// The scale factors of the UI that can be set, in percent.
+pub(crate) const UI_SCALE_PERCENT_RANGE: std::ops::RangeInclusive<u32> = 50..=250;
+/// The version of the configuration layout written by this app.
+pub(crate) const CONFIG_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Time waited after a change of the configuration file before reloading it,
+/// so that a file that is written in several steps is reloaded once.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Migrates the fields of a configuration to the layout of the next version.
+type Migration = fn(&mut Map<String, Value>) -> anyhow::Result<()>;
+
+/// The migrations of the configuration layouts, the one at index `n` migrates from version `n` to `n + 1`.
+///
+/// Changing the layout in a way that isn't covered by the serde defaults requires appending a migration.
+const MIGRATIONS: &[Migration] = &[migrate_unversioned];
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(default)]
 pub(crate) struct Config {
+    /// The version of the layout, configurations without it were written before versioning.
+    pub(crate) version: u32,
     pub(crate) coordinator_address: String,
     pub(crate) language: AppLanguage,
     pub(crate) optimize_touch: bool,
+    /// Scale factor of the UI in percent.
+    pub(crate) ui_scale_percent: u32,
+    pub(crate) high_contrast: bool,
     pub(crate) venv_dir: PathBuf,
     pub(crate) scripts_dir: PathBuf,
+    /// Depth of the subdirectories of the scripts directory that are scanned for scripts.
+    pub(crate) scripts_max_depth: u32,
+    /// Minutes after which scripts that don't declare a timeout are killed, `0` disables it.
+    pub(crate) script_timeout_mins: u32,
+    pub(crate) script_meta: ScriptMetaSettings,
+    /// Seconds deleted resources are kept as stale before they are removed.
+    pub(crate) resource_grace_period_secs: u32,
+    /// How the resources of offline exporters are displayed.
+    pub(crate) offline_exporter_resources: OfflineExporterResources,
+    pub(crate) persist_acquire_history: bool,
+    /// Whether the acquisitions and releases of places are stored on disk for the dashboard.
+    pub(crate) record_utilization: bool,
+    /// Only saved when `persist_acquire_history` is set.
+    pub(crate) acquire_history: AcquireHistory,
+    pub(crate) card_palette: CardPalette,
+    pub(crate) notifications: NotificationSettings,
+    /// Names of the places for which notifications are sent when they are released.
+    pub(crate) watched_places: BTreeSet<String>,
+    /// Paths of the resources for which availability changes are shown, formatted as `exporter/group/name`.
+    pub(crate) watched_resources: BTreeSet<String>,
+    pub(crate) quick_actions: QuickActionSettings,
+    /// Overrides of `LG_HOSTNAME`/`LG_USERNAME`.
+    pub(crate) identity_override: IdentityOverride,
+    pub(crate) auto_release: AutoReleaseSettings,
+    /// Maximum number of operations per second sent to the coordinator by bulk place actions.
+    pub(crate) bulk_ops_per_sec: u32,
+    /// Seconds between periodic saves of the configuration, `0` disables them.
+    pub(crate) autosave_secs: u32,
+    pub(crate) connection_timeouts: ConnectionTimeouts,
+    /// Retry coordinator calls failing because it is temporarily unavailable.
+    pub(crate) retry_transient_errors: bool,
+    /// Authentication towards a proxy in front of the coordinator.
+    pub(crate) connection_auth: ConnectionAuth,
+    /// Whether validating scripts also checks that their labgrid imports resolve.
+    pub(crate) check_script_imports: bool,
+    /// User mappings of resource classes to icons and quick actions.
+    pub(crate) resource_classes: ResourceClassRegistry,
+    pub(crate) profiles: Vec<ConnectionProfile>,
+    /// The name of the profile used for connecting.
+    pub(crate) selected_profile: Option<String>,
+    /// The scripts run by the headless mode.
+    pub(crate) headless_jobs: Vec<HeadlessJob>,
+    pub(crate) mqtt: MqttSettings,
+    /// The terminal emulator quick connect commands are launched in, split at whitespace. Empty disables it.
+    pub(crate) terminal_command: String,
+    pub(crate) view_state: ViewState,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            version: CONFIG_VERSION,
             coordinator_address: String::default(),
             language: AppLanguage::default(),
             optimize_touch: false,
+            ui_scale_percent: 100,
+            high_contrast: false,
             venv_dir: util::default_venv_dir(),
             scripts_dir: util::default_scripts_dir(),
+            scripts_max_depth: scripts::DEFAULT_MAX_DEPTH,
+            script_timeout_mins: 0,
+            script_meta: ScriptMetaSettings::default(),
+            resource_grace_period_secs: DEFAULT_RESOURCE_GRACE_PERIOD_SECS,
+            offline_exporter_resources: OfflineExporterResources::default(),
+            persist_acquire_history: false,
+            record_utilization: false,
+            acquire_history: AcquireHistory::default(),
+            card_palette: CardPalette::default(),
+            notifications: NotificationSettings::default(),
+            watched_places: BTreeSet::default(),
+            watched_resources: BTreeSet::default(),
+            quick_actions: QuickActionSettings::default(),
+            identity_override: IdentityOverride::default(),
+            auto_release: AutoReleaseSettings::default(),
+            bulk_ops_per_sec: DEFAULT_BULK_OPS_PER_SEC,
+            autosave_secs: DEFAULT_AUTOSAVE_SECS,
+            connection_timeouts: ConnectionTimeouts::default(),
+            retry_transient_errors: true,
+            connection_auth: ConnectionAuth::default(),
+            check_script_imports: true,
+            resource_classes: ResourceClassRegistry::default(),
+            profiles: Vec::default(),
+            selected_profile: None,
+            headless_jobs: Vec::default(),
+            mqtt: MqttSettings::default(),
+            terminal_command: String::new(),
+            view_state: ViewState::default(),
         }
     }
 }
@@ -49,24 +174,320 @@ impl Config {
         }
         let file = File::open(path).context("Open file for reading")?;
         let reader = BufReader::new(file);
-        let config = serde_json::from_reader(reader).context("Read configuration from file")?;
-        Ok(config)
+        let mut value = serde_json::from_reader(reader).context("Read configuration from file")?;
+        migrate(&mut value, MIGRATIONS)?;
+        let config = serde_json::from_value(value).context("Deserialize configuration")?;
+        Ok(Some(config))
     }
 
     /// Saves the configuration to a path.
     ///
+    /// A file of another version or that can't be read is backed up first, see [backup].
+    /// The configuration is written to a temporary file that replaces the file once it is complete.
+    ///
     /// Returns `Ok(())` if saving was successful, `Err(error)` if it failed.
     pub(crate) fn save_to_path(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
-        let file = File::create(path).context("Open/Create file for writing")?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, self).context("Write configuration to file")
+        let path = path.as_ref();
+        backup(path).context("Back up configuration file")?;
+        let tmp_path = path.with_extension("json.tmp");
+        let file = File::create(&tmp_path).context("Open/Create file for writing")?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut writer, self).context("Write configuration to file")?;
+        writer
+            .into_inner()
+            .context("Flush configuration file")?
+            .sync_all()
+            .context("Sync configuration file")?;
+        std::fs::rename(&tmp_path, path).context("Replace configuration file")
+    }
+
+    /// The profile used for connecting, if one is selected.
+    pub(crate) fn selected_profile(&self) -> Option<&ConnectionProfile> {
+        let name = self.selected_profile.as_ref()?;
+        self.profiles.iter().find(|p| p.name == *name)
+    }
+}
+
+/// Settings given on the command line or through the environment, taking precedence over the configuration file.
+///
+/// They are not saved to the file, unless they are changed in the settings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ConfigOverrides {
+    pub(crate) scripts_dir: Option<PathBuf>,
+    pub(crate) venv_dir: Option<PathBuf>,
+    pub(crate) language: Option<AppLanguage>,
+    pub(crate) connection_auth: Option<ConnectionAuth>,
+}
+
+impl ConfigOverrides {
+    /// Overrides the settings of the configuration, returns the values that were replaced.
+    pub(crate) fn apply(&self, config: &mut Config) -> Self {
+        fn replace<T: Clone>(value: &mut T, with: &Option<T>) -> Option<T> {
+            with.clone().map(|with| std::mem::replace(value, with))
+        }
+
+        Self {
+            scripts_dir: replace(&mut config.scripts_dir, &self.scripts_dir),
+            venv_dir: replace(&mut config.venv_dir, &self.venv_dir),
+            language: replace(&mut config.language, &self.language),
+            connection_auth: replace(&mut config.connection_auth, &self.connection_auth),
+        }
+    }
+
+    /// Restores the `replaced` values of the overridden settings that were not changed since, before saving it.
+    pub(crate) fn restore(&self, config: &mut Config, replaced: &Self) {
+        fn restore<T: Clone + PartialEq>(value: &mut T, with: &Option<T>, replaced: &Option<T>) {
+            if let (Some(with), Some(replaced)) = (with, replaced) {
+                if value == with {
+                    *value = replaced.clone();
+                }
+            }
+        }
+
+        restore(
+            &mut config.scripts_dir,
+            &self.scripts_dir,
+            &replaced.scripts_dir,
+        );
+        restore(&mut config.venv_dir, &self.venv_dir, &replaced.venv_dir);
+        restore(&mut config.language, &self.language, &replaced.language);
+        restore(
+            &mut config.connection_auth,
+            &self.connection_auth,
+            &replaced.connection_auth,
+        );
+    }
+}
+
+/// Migrates the configuration `value` from its version to the latest version of `migrations`.
+///
+/// Configurations of newer versions are left as they are, fields unknown to this version are ignored when deserializing.
+fn migrate(value: &mut Value, migrations: &[Migration]) -> anyhow::Result<()> {
+    let fields = value
+        .as_object_mut()
+        .context("Configuration is not a JSON object")?;
+    let version = fields.get("version").map_or(Ok(0), |version| {
+        version
+            .as_u64()
+            .context("Configuration version is not a number")
+    })?;
+    let latest = migrations.len() as u64;
+    if version > latest {
+        warn!(
+            version,
+            latest, "Configuration was written by a newer version of the app"
+        );
+        return Ok(());
+    }
+    for (from, migration) in migrations.iter().enumerate().skip(version as usize) {
+        info!(from, "Migrating configuration");
+        migration(fields).with_context(|| format!("Migrate configuration from version {from}"))?;
+    }
+    fields.insert("version".to_string(), latest.into());
+    Ok(())
+}
+
+/// Configurations written before versioning have the layout of version 1, apart from the missing version.
+fn migrate_unversioned(_fields: &mut Map<String, Value>) -> anyhow::Result<()> {
+    Ok(())
+}
+
+/// Backs up the configuration file at `path` if it isn't of the [CONFIG_VERSION] layout, returns the path of the backup.
+///
+/// The backup of a version is named `<file>.v<version>.bak` and is kept once created, so it can be restored for the
+/// app version that wrote it. Files that can't be read as configuration are backed up to `<file>.unreadable.bak`.
+fn backup(path: &Path) -> anyhow::Result<Option<PathBuf>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = std::fs::read(path).context("Read configuration file")?;
+    let version = serde_json::from_slice::<Value>(&contents)
+        .ok()
+        .and_then(|value| match value.get("version") {
+            Some(version) => version.as_u64(),
+            None => value.is_object().then_some(0),
+        });
+    let suffix = match version {
+        Some(version) if version == u64::from(CONFIG_VERSION) => return Ok(None),
+        Some(version) => format!("v{version}"),
+        None => "unreadable".to_string(),
+    };
+    let mut backup_name = path.file_name().unwrap_or_default().to_os_string();
+    backup_name.push(format!(".{suffix}.bak"));
+    let backup_path = path.with_file_name(backup_name);
+    if version.is_none() || !backup_path.exists() {
+        std::fs::write(&backup_path, contents).context("Write backup")?;
+        info!(?backup_path, "Backed up configuration file");
     }
+    Ok(Some(backup_path))
+}
+
+/// An iced subscription that watches the configuration file, e.g. for configurations pushed centrally.
+///
+/// Triggers `AppMsg::ConfigFileChanged` messages when it is changed on disk, also by the saves of the app itself.
+/// The directory is watched instead of the file, because the file is replaced when saving.
+pub(crate) fn watch_subscription() -> impl futures::Stream<Item = AppMsg> {
+    let path = util::config_path();
+    stream::channel(
+        1,
+        move |mut output: futures::channel::mpsc::Sender<AppMsg>| async move {
+            let (tx, mut changes) = mpsc::unbounded_channel();
+            let watched = path.clone();
+            let watcher =
+                notify::recommended_watcher(move |res: Result<notify::Event, notify::Error>| {
+                    match res {
+                        Ok(event) if event.kind.is_access() => {}
+                        Ok(event) if event.paths.contains(&watched) => {
+                            // Nothing to do if sending fails
+                            let _ = tx.send(());
+                        }
+                        Ok(_) => {}
+                        Err(err) => {
+                            error!(?err, "Watch error");
+                        }
+                    }
+                });
+            let dir = path.parent().unwrap_or(&path);
+            let _watcher = match watcher.and_then(|mut watcher| {
+                watcher
+                    .watch(dir, notify::RecursiveMode::NonRecursive)
+                    .map(|_| watcher)
+            }) {
+                Ok(watcher) => watcher,
+                Err(err) => {
+                    error!(?err, path = %path.display(), "Watching configuration file failed");
+                    return;
+                }
+            };
+            while changes.recv().await.is_some() {
+                time::sleep(WATCH_DEBOUNCE).await;
+                while changes.try_recv().is_ok() {}
+                let _ = output.send(AppMsg::ConfigFileChanged).await;
+            }
+        },
+    )
+}
+
+/// An iced subscription that triggers periodic `AppMsg::PruneStaleResources` messages,
+/// causing stale resources to be removed once their grace period has elapsed.
+pub(crate) fn periodic_prune_stale_resources_subscription() -> impl futures::Stream<Item = AppMsg> {
+    const PRUNE_INTERVAL: Duration = Duration::from_secs(1);
+
+    IntervalStream::new(time::interval(PRUNE_INTERVAL)).map(|_| AppMsg::PruneStaleResources)
 }
 
-/// An iced subscription that triggers periodic `AppMsg::SaveConfig` messages,
+/// An iced subscription that triggers `AppMsg::SaveConfig` messages every `secs` seconds,
 /// causing the application configuration to be saved.
-pub(crate) fn periodic_save_subscription() -> impl futures::Stream<Item = AppMsg> {
-    const SAVE_INTERVAL: Duration = Duration::from_secs(120);
+pub(crate) fn periodic_save_subscription(secs: &u32) -> impl futures::Stream<Item = AppMsg> {
+    let interval = Duration::from_secs((*secs).max(1).into());
+
+    IntervalStream::new(time::interval_at(time::Instant::now() + interval, interval))
+        .map(|_| AppMsg::SaveConfig)
+}
 
-    IntervalStream::new(time::interval(SAVE_INTERVAL)).map(|_| AppMsg::SaveConfig)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::AuthTokenKind;
+    use serde_json::json;
+
+    fn rename_address(fields: &mut Map<String, Value>) -> anyhow::Result<()> {
+        if let Some(address) = fields.remove("address") {
+            fields.insert("coordinator_address".to_string(), address);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn config_migrate() {
+        let migrations: &[Migration] = &[migrate_unversioned, rename_address];
+        let mut value = json!({ "address": "coordinator:20408" });
+        migrate(&mut value, migrations).unwrap();
+        assert_eq!(
+            value,
+            json!({ "version": 2, "coordinator_address": "coordinator:20408" })
+        );
+
+        // Only the migrations from the version on are applied
+        let mut value = json!({ "version": 1, "address": "a", "coordinator_address": "b" });
+        migrate(&mut value, &migrations[..1]).unwrap();
+        assert_eq!(value["coordinator_address"], "b");
+
+        // Newer configurations are left as they are
+        let mut value = json!({ "version": 3, "address": "a" });
+        migrate(&mut value, migrations).unwrap();
+        assert_eq!(value, json!({ "version": 3, "address": "a" }));
+
+        assert!(migrate(&mut json!([]), migrations).is_err());
+        assert!(migrate(&mut json!({ "version": "1" }), migrations).is_err());
+    }
+
+    #[test]
+    fn config_overrides() {
+        let overrides = ConfigOverrides {
+            scripts_dir: Some(PathBuf::from("/srv/scripts")),
+            language: Some(AppLanguage::DeDe),
+            connection_auth: Some(ConnectionAuth {
+                token_kind: AuthTokenKind::Static,
+                token: "secret".to_string(),
+                ..ConnectionAuth::default()
+            }),
+            ..ConfigOverrides::default()
+        };
+        let saved = Config {
+            scripts_dir: PathBuf::from("/home/me/scripts"),
+            venv_dir: PathBuf::from("/home/me/venv"),
+            ..Config::default()
+        };
+        let mut config = saved.clone();
+        let replaced = overrides.apply(&mut config);
+        assert_eq!(config.scripts_dir, PathBuf::from("/srv/scripts"));
+        assert_eq!(config.venv_dir, PathBuf::from("/home/me/venv"));
+        assert_eq!(config.language, AppLanguage::DeDe);
+        assert_eq!(config.connection_auth.token, "secret");
+
+        // Unchanged overrides are not saved, settings changed since are
+        config.language = AppLanguage::EsEs;
+        overrides.restore(&mut config, &replaced);
+        assert_eq!(config.scripts_dir, saved.scripts_dir);
+        assert_eq!(config.venv_dir, saved.venv_dir);
+        assert_eq!(config.language, AppLanguage::EsEs);
+        // Tokens supplied on the command line are not written to the file
+        assert_eq!(config.connection_auth, ConnectionAuth::default());
+    }
+
+    #[test]
+    fn config_save_backup() {
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-config-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+        let backup_path = |suffix: &str| dir.join(format!("config.json.{suffix}.bak"));
+
+        // Unversioned configurations are migrated and backed up before they are overwritten
+        let unversioned = r#"{ "optimize_touch": true }"#;
+        std::fs::write(&path, unversioned).unwrap();
+        let config = Config::load_from_path(&path).unwrap().unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert!(config.optimize_touch);
+        config.save_to_path(&path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(backup_path("v0")).unwrap(),
+            unversioned
+        );
+        let saved = Config::load_from_path(&path).unwrap().unwrap();
+        assert!(saved.optimize_touch);
+        assert_eq!(backup(&path).unwrap(), None);
+
+        // Unreadable files are kept instead of being silently replaced
+        std::fs::write(&path, "{ truncated").unwrap();
+        assert!(Config::load_from_path(&path).is_err());
+        Config::default().save_to_path(&path).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(backup_path("unreadable")).unwrap(),
+            "{ truncated"
+        );
+        assert!(!dir.join("config.json.tmp").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -2,41 +2,53 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use crate::app::{self, ErrorCriticality, ErrorReport};
+use crate::app::{self, AppMsg, ErrorCriticality, ErrorReport};
+use crate::config::DEFAULT_BULK_OPS_PER_SEC;
 use crate::i18n::fl;
-use crate::util;
+use crate::place_clone::PlaceClone;
+use crate::place_snapshot::RestoreStep;
+use crate::protocol_inspector;
+use crate::rate_limit::TokenBucket;
+use anyhow::Context;
 use core::fmt::Display;
 use core::time::Duration;
-use futures_util::stream::Fuse;
+use futures_util::stream::{BoxStream, Fuse};
 use iced::futures::channel::mpsc;
 use iced::futures::{self, SinkExt, StreamExt};
 use iced::stream;
+use labgrid_ui_core::auth::{CallCredentials, TokenSource};
 use labgrid_ui_core::error::GrpcClientError;
 use labgrid_ui_core::types::{
     self, ClientInMsg, ClientOutMsg, Place, Reservation, Resource, StartupDone, Subscribe,
     SubscribeKind, UpdateResponse,
 };
-use labgrid_ui_core::LabgridGrpcClient;
-use labgrid_ui_core::{proto, tonic};
+use labgrid_ui_core::{proto, tonic, version};
+use labgrid_ui_core::{ClientTimeouts, LabgridGrpcClient, RetryPolicy};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
 use tokio::time;
 use tokio_stream::wrappers::IntervalStream;
 use tracing::{debug, error, instrument, warn};
 
 /// Channel size for connection messages.
 const CHANNEL_SIZE: usize = 100;
-/// The timeout that determines failure of a connecting attempt.
-const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
-/// The interval for periodically fetching the current reservations.
-///
-/// Needed because reservation information currently unfortunately is not part of the client stream.
-const GET_RESERVATIONS_INTERVAL: Duration = Duration::from_secs(30);
+/// The maximum duration of the shutdown sequence when the application exits.
+pub(crate) const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// A connection message emitted by the UI and received by the connection subscription.
 #[derive(Debug, Clone)]
 pub(crate) enum ConnectionMsg {
     Connect {
         address: String,
+        /// The `host/user` name to identify with towards the coordinator.
+        identity: String,
+        timeouts: ConnectionTimeouts,
+        /// Retry calls failing because the coordinator is temporarily unavailable.
+        retry: bool,
+        auth: ConnectionAuth,
+        scope: SubscriptionScope,
     },
     Disconnect,
     Sync,
@@ -46,22 +58,65 @@ pub(crate) enum ConnectionMsg {
     AcquirePlace {
         name: String,
     },
+    /// Acquires the place and stores the reason in its [ACQUIRE_REASON_TAG](app::ACQUIRE_REASON_TAG) tag.
+    AcquirePlaceWithReason {
+        name: String,
+        reason: String,
+    },
     ReleasePlace {
         name: String,
     },
+    /// Releases the place acquired by another user.
+    ForceReleasePlace {
+        name: String,
+        from_user: String,
+    },
     AddPlace {
         name: String,
     },
+    /// Creates the place and sets up its aliases, tags and matches, stopping at the first failure.
+    ClonePlace {
+        place_clone: PlaceClone,
+    },
     DeletePlace {
         name: String,
     },
+    /// Restores a place snapshot step by step, the failures are reported aggregated.
+    RestorePlaces {
+        steps: Vec<RestoreStep>,
+    },
+    AcquirePlaces {
+        names: Vec<String>,
+    },
+    ReleasePlaces {
+        names: Vec<String>,
+    },
+    DeletePlaces {
+        names: Vec<String>,
+    },
+    SetPlacesTag {
+        names: Vec<String>,
+        tag: (String, String),
+    },
+    /// Adds the match pattern to the place, resources matched by it are renamed to `rename` in the place if set.
     AddPlaceMatch {
         place_name: String,
         pattern: String,
+        rename: Option<String>,
     },
+    /// Deletes the match of the place, the coordinator only deletes it if the rename matches too.
     DeletePlaceMatch {
         place_name: String,
         pattern: String,
+        rename: Option<String>,
+    },
+    AddPlaceAlias {
+        place_name: String,
+        alias: String,
+    },
+    DeletePlaceAlias {
+        place_name: String,
+        alias: String,
     },
     AddPlaceTag {
         place_name: String,
@@ -71,10 +126,63 @@ pub(crate) enum ConnectionMsg {
         place_name: String,
         tag: String,
     },
+    SetPlaceComment {
+        place_name: String,
+        comment: String,
+    },
     GetReservations,
+    CreateReservation {
+        filters: HashMap<String, types::Filter>,
+        prio: f64,
+    },
     CancelReservation {
         token: String,
     },
+    /// Keeps the reservation from expiring, which it does when it is not polled regularly.
+    PollReservation {
+        token: String,
+    },
+    /// Cancels the reservations sequentially, the failures are reported aggregated.
+    CancelReservations {
+        tokens: Vec<String>,
+    },
+    /// Releases the places and closes the connection before the application exits.
+    ///
+    /// Messages sent before are handled first,
+    /// [ConnectionEvent::ShutdownComplete] is emitted once done or after [SHUTDOWN_TIMEOUT].
+    Shutdown {
+        release: Vec<String>,
+    },
+}
+
+impl ConnectionMsg {
+    /// Whether the message modifies the place with the supplied name.
+    pub(crate) fn affects_place(&self, name: &str) -> bool {
+        match self {
+            Self::AcquirePlace { name: n }
+            | Self::AcquirePlaceWithReason { name: n, .. }
+            | Self::ReleasePlace { name: n }
+            | Self::ForceReleasePlace { name: n, .. }
+            | Self::AddPlace { name: n }
+            | Self::ClonePlace {
+                place_clone: PlaceClone { name: n, .. },
+            }
+            | Self::DeletePlace { name: n }
+            | Self::AddPlaceMatch { place_name: n, .. }
+            | Self::DeletePlaceMatch { place_name: n, .. }
+            | Self::AddPlaceAlias { place_name: n, .. }
+            | Self::DeletePlaceAlias { place_name: n, .. }
+            | Self::AddPlaceTag { place_name: n, .. }
+            | Self::DeletePlaceTag { place_name: n, .. }
+            | Self::SetPlaceComment { place_name: n, .. } => n == name,
+            Self::AcquirePlaces { names }
+            | Self::ReleasePlaces { names }
+            | Self::DeletePlaces { names }
+            | Self::SetPlacesTag { names, .. } => names.iter().any(|n| n == name),
+            Self::RestorePlaces { steps } => steps.iter().any(|step| step.place() == name),
+            _ => false,
+        }
+    }
 }
 
 /// A connection event that is produced by the connection and sent to the UI through iced's message passing.
@@ -83,15 +191,269 @@ pub(crate) enum ConnectionMsg {
 #[derive(Debug, Clone)]
 pub(crate) enum ConnectionEvent {
     ReceiveReady(ConnectionSender),
-    Connected { address: String },
-    Disconnected { error: Option<app::ErrorReport> },
-    NonCriticalError { error: app::ErrorReport },
+    Connected {
+        address: String,
+        identity: String,
+    },
+    Disconnected {
+        error: Option<app::ErrorReport>,
+    },
+    NonCriticalError {
+        error: app::ErrorReport,
+    },
     Place(Place),
     DeletePlace(String),
     Places(Vec<Place>),
     Resource(Resource),
     DeleteResource(types::Path),
     Reservations(Vec<Reservation>),
+    /// The reservation was created in response to [ConnectionMsg::CreateReservation],
+    /// followed by the refreshed reservations.
+    ReservationCreated(Reservation),
+    /// The places and resources present when connecting were all received.
+    Synced,
+    /// The progress of the running bulk place operation, `None` once it is finished.
+    BulkPlaceOpProgress(Option<BulkPlaceOpProgress>),
+    /// The connection was shut down in response to [ConnectionMsg::Shutdown].
+    ShutdownComplete,
+    /// The round-trip time of the last keepalive.
+    Latency(Duration),
+    /// The labgrid version reported by the coordinator after connecting, `None` if it did not report one.
+    CoordinatorVersion(Option<String>),
+    /// A message from the coordinator could not be converted, most likely because it uses a different protocol.
+    ///
+    /// Only sent for the first failing message of a connection.
+    ProtocolMismatch(String),
+}
+
+/// The timeouts and polling intervals of the connection to the coordinator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ConnectionTimeouts {
+    /// Seconds for establishing the connection.
+    pub(crate) connect_secs: u32,
+    /// Seconds for initiating the client stream once connected.
+    pub(crate) stream_secs: u32,
+    /// Seconds for each call, like acquiring a place.
+    pub(crate) rpc_secs: u32,
+    /// Seconds between keepalives over the client stream, `0` disables them.
+    ///
+    /// The stream is considered dead when a keepalive is not echoed within the stream timeout.
+    pub(crate) keepalive_secs: u32,
+    /// Seconds between fetching the current reservations.
+    ///
+    /// Needed because reservation information currently unfortunately is not part of the client stream.
+    pub(crate) reservations_poll_secs: u32,
+}
+
+impl Default for ConnectionTimeouts {
+    fn default() -> Self {
+        TimeoutPreset::Vpn.timeouts()
+    }
+}
+
+impl ConnectionTimeouts {
+    pub(crate) fn connect(&self) -> Duration {
+        Duration::from_secs(self.connect_secs.into())
+    }
+
+    pub(crate) fn stream(&self) -> Duration {
+        Duration::from_secs(self.stream_secs.into())
+    }
+
+    pub(crate) fn rpc(&self) -> Duration {
+        Duration::from_secs(self.rpc_secs.into())
+    }
+
+    /// The interval between keepalives, `None` when they are disabled.
+    pub(crate) fn keepalive(&self) -> Option<Duration> {
+        (self.keepalive_secs > 0).then(|| Duration::from_secs(self.keepalive_secs.into()))
+    }
+
+    pub(crate) fn reservations_poll(&self) -> Duration {
+        // A zero period would panic in the interval
+        Duration::from_secs(self.reservations_poll_secs.max(1).into())
+    }
+
+    /// The preset with the same timeouts, if there is one.
+    pub(crate) fn preset(&self) -> Option<TimeoutPreset> {
+        TimeoutPreset::ALL
+            .into_iter()
+            .find(|preset| preset.timeouts() == *self)
+    }
+}
+
+/// Presets of connection timeouts for typical lab networks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeoutPreset {
+    /// Coordinators in the local network respond quickly, failures are reported early.
+    Lan,
+    /// Coordinators reached through a VPN or across sites might need longer.
+    Vpn,
+}
+
+impl TimeoutPreset {
+    pub(crate) const ALL: [Self; 2] = [Self::Lan, Self::Vpn];
+
+    pub(crate) fn timeouts(&self) -> ConnectionTimeouts {
+        match self {
+            Self::Lan => ConnectionTimeouts {
+                connect_secs: 5,
+                stream_secs: 10,
+                rpc_secs: 10,
+                keepalive_secs: 10,
+                reservations_poll_secs: 30,
+            },
+            Self::Vpn => ConnectionTimeouts {
+                connect_secs: 30,
+                stream_secs: 60,
+                rpc_secs: 60,
+                keepalive_secs: 30,
+                reservations_poll_secs: 30,
+            },
+        }
+    }
+}
+
+impl Display for TimeoutPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lan => write!(f, "{}", fl!("settings-timeout-preset-lan")),
+            Self::Vpn => write!(f, "{}", fl!("settings-timeout-preset-vpn")),
+        }
+    }
+}
+
+/// Where the bearer token sent to the coordinator is taken from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum AuthTokenKind {
+    #[default]
+    None,
+    Static,
+    /// A file containing the token, e.g. one rotated by an external agent.
+    File,
+    /// A command printing the token.
+    Command,
+}
+
+impl AuthTokenKind {
+    pub(crate) const ALL: [Self; 4] = [Self::None, Self::Static, Self::File, Self::Command];
+}
+
+impl Display for AuthTokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "{}", fl!("settings-auth-token-none")),
+            Self::Static => write!(f, "{}", fl!("settings-auth-token-static")),
+            Self::File => write!(f, "{}", fl!("settings-auth-token-file")),
+            Self::Command => write!(f, "{}", fl!("settings-auth-token-command")),
+        }
+    }
+}
+
+/// The authentication sent with every call, for coordinators behind an authenticating proxy.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ConnectionAuth {
+    pub(crate) token_kind: AuthTokenKind,
+    /// The token, the path of the token file or the token command, depending on the kind.
+    pub(crate) token: String,
+    /// Seconds until the token command is run again.
+    pub(crate) token_refresh_secs: u32,
+    /// Comma separated `key=value` metadata entries, e.g. an API key expected by the proxy.
+    pub(crate) metadata: String,
+}
+
+impl Default for ConnectionAuth {
+    fn default() -> Self {
+        Self {
+            token_kind: AuthTokenKind::default(),
+            token: String::new(),
+            token_refresh_secs: 300,
+            metadata: String::new(),
+        }
+    }
+}
+
+// Static tokens must not end up in the logs.
+impl std::fmt::Debug for ConnectionAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let token = match self.token_kind {
+            AuthTokenKind::Static if !self.token.is_empty() => "..",
+            _ => &self.token,
+        };
+        f.debug_struct("ConnectionAuth")
+            .field("token_kind", &self.token_kind)
+            .field("token", &token)
+            .field("token_refresh_secs", &self.token_refresh_secs)
+            .field(
+                "metadata_keys",
+                &self
+                    .metadata
+                    .split(',')
+                    .filter_map(|entry| Some(entry.split_once('=')?.0.trim()))
+                    .collect::<Vec<&str>>(),
+            )
+            .finish()
+    }
+}
+
+impl ConnectionAuth {
+    /// The credentials of the client, fails if a metadata entry is invalid.
+    pub(crate) fn credentials(&self) -> anyhow::Result<CallCredentials> {
+        let token = self.token.trim();
+        let mut credentials = match self.token_kind {
+            AuthTokenKind::None => CallCredentials::new(),
+            AuthTokenKind::Static => {
+                CallCredentials::new().token(TokenSource::Static(token.to_string()))
+            }
+            AuthTokenKind::File => CallCredentials::new().token(TokenSource::File(token.into())),
+            AuthTokenKind::Command => CallCredentials::new().token(TokenSource::Command {
+                command: token.to_string(),
+                refresh: Duration::from_secs(self.token_refresh_secs.into()),
+            }),
+        };
+        for entry in self.metadata.split(',').filter(|e| !e.trim().is_empty()) {
+            let (key, value) = entry
+                .split_once('=')
+                .with_context(|| format!("Metadata entry '{}' is not 'key=value'", entry.trim()))?;
+            credentials = credentials.metadata(key.trim(), value.trim())?;
+        }
+        Ok(credentials)
+    }
+}
+
+/// The updates of the coordinator that are subscribed to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum SubscriptionScope {
+    #[default]
+    PlacesAndResources,
+    /// Resources are not received, which reduces the traffic with coordinators that have many resources.
+    PlacesOnly,
+}
+
+impl SubscriptionScope {
+    pub(crate) const ALL: [Self; 2] = [Self::PlacesAndResources, Self::PlacesOnly];
+}
+
+impl Display for SubscriptionScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PlacesAndResources => {
+                write!(f, "{}", fl!("subscription-scope-places-and-resources"))
+            }
+            Self::PlacesOnly => write!(f, "{}", fl!("subscription-scope-places-only")),
+        }
+    }
+}
+
+/// The progress of a bulk place operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BulkPlaceOpProgress {
+    pub(crate) done: usize,
+    pub(crate) total: usize,
 }
 
 /// A synchronization ID which needs to be always incrementing when sending sync messages to the labgrid coordinator.
@@ -115,16 +477,159 @@ impl SyncId {
     }
 }
 
+/// Application-level keepalive over the client stream.
+///
+/// Sync messages are sent periodically and the stream is considered dead when one is not echoed in time,
+/// which detects streams silently dropped by NATs or firewalls faster than TCP timeouts.
+struct Keepalive {
+    ticks: Fuse<BoxStream<'static, time::Instant>>,
+    /// The id and send time of the sync message whose echo is awaited.
+    pending: Option<(u64, Instant)>,
+    timeout: Duration,
+}
+
+/// What needs to happen when the keepalive ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeepaliveAction {
+    /// Send a sync message with the id.
+    Ping(u64),
+    /// The echo of the previous sync message is still awaited.
+    Wait,
+    Dead,
+}
+
+impl std::fmt::Debug for Keepalive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Keepalive")
+            .field("pending", &self.pending)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl Keepalive {
+    fn new(timeouts: &ConnectionTimeouts) -> Self {
+        let ticks = match timeouts.keepalive() {
+            Some(interval) => {
+                IntervalStream::new(time::interval_at(time::Instant::now() + interval, interval))
+                    .boxed()
+            }
+            None => futures::stream::pending().boxed(),
+        };
+        Self {
+            ticks: ticks.fuse(),
+            pending: None,
+            timeout: timeouts.stream(),
+        }
+    }
+
+    fn tick(&mut self, sync_id: &mut SyncId, now: Instant) -> KeepaliveAction {
+        match self.pending {
+            Some((_, sent)) if now.duration_since(sent) > self.timeout => KeepaliveAction::Dead,
+            Some(_) => KeepaliveAction::Wait,
+            None => {
+                let id = sync_id.next();
+                self.pending = Some((id, now));
+                KeepaliveAction::Ping(id)
+            }
+        }
+    }
+
+    /// Handles a sync response, returns the round-trip time if it is the echo of the keepalive.
+    fn echo(&mut self, id: u64, now: Instant) -> Option<Duration> {
+        match self.pending {
+            Some((pending_id, sent)) if pending_id == id => {
+                self.pending = None;
+                Some(now.duration_since(sent))
+            }
+            _ => None,
+        }
+    }
+}
+
 /// The sender that gets used by the UI to send connection messages to the connection subscription.
 #[derive(Debug, Clone)]
-pub(crate) struct ConnectionSender(mpsc::Sender<ConnectionMsg>);
+pub(crate) struct ConnectionSender {
+    sender: mpsc::Sender<ConnectionMsg>,
+    bulk_op_control: Arc<BulkOpControl>,
+}
 
 impl ConnectionSender {
     pub(crate) fn send(&mut self, msg: ConnectionMsg) {
-        if let Err(error) = self.0.try_send(msg) {
+        if let Err(error) = self.sender.try_send(msg) {
             error!(?error, "Send connection message");
         }
     }
+
+    /// Controls the running bulk place operation.
+    ///
+    /// Bypasses the message channel, because the connection does not receive messages while the operation is running.
+    pub(crate) fn bulk_op_control(&self) -> &BulkOpControl {
+        &self.bulk_op_control
+    }
+
+    /// Creates a sender with the receiving end of the channel, used to inspect the sent messages in tests.
+    #[cfg(test)]
+    pub(crate) fn channel(buffer: usize) -> (Self, mpsc::Receiver<ConnectionMsg>) {
+        let (sender, receiver) = mpsc::channel(buffer);
+        let sender = Self {
+            sender,
+            bulk_op_control: Arc::default(),
+        };
+        (sender, receiver)
+    }
+}
+
+/// Shared between the UI and the connection to pause, cancel and rate limit bulk place operations.
+#[derive(Debug)]
+pub(crate) struct BulkOpControl {
+    paused: AtomicBool,
+    cancelled: AtomicBool,
+    ops_per_sec: AtomicU32,
+}
+
+impl Default for BulkOpControl {
+    fn default() -> Self {
+        Self {
+            paused: AtomicBool::new(false),
+            cancelled: AtomicBool::new(false),
+            ops_per_sec: AtomicU32::new(DEFAULT_BULK_OPS_PER_SEC),
+        }
+    }
+}
+
+impl BulkOpControl {
+    pub(crate) fn paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
+    pub(crate) fn cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Cancels the running operation, the remaining places are skipped.
+    pub(crate) fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub(crate) fn ops_per_sec(&self) -> u32 {
+        self.ops_per_sec.load(Ordering::Relaxed)
+    }
+
+    /// Applies to operations started after changing it.
+    pub(crate) fn set_ops_per_sec(&self, ops_per_sec: u32) {
+        self.ops_per_sec.store(ops_per_sec, Ordering::Relaxed);
+    }
+
+    /// Resets pausing and cancelling before starting an operation.
+    fn reset(&self) {
+        self.set_paused(false);
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
 }
 
 /// Represents the current connection state.
@@ -138,6 +643,11 @@ enum State {
         client_in_sender: mpsc::UnboundedSender<ClientInMsg>,
         client_out_stream: Fuse<tonic::Streaming<proto::ClientOutMessage>>,
         sync_id: SyncId,
+        keepalive: Keepalive,
+        /// Whether the initial sync sent when connecting was echoed.
+        synced: bool,
+        /// Whether [ConnectionEvent::ProtocolMismatch] was sent, it is only sent once per connection.
+        mismatch_reported: bool,
     },
 }
 
@@ -157,13 +667,20 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
     stream::channel(CHANNEL_SIZE, |mut output| async move {
         let mut state = State::Disconnected;
         let (sender, ref mut receiver) = mpsc::channel(CHANNEL_SIZE);
+        let bulk_op_control = Arc::new(BulkOpControl::default());
         output_send(
             &mut output,
-            ConnectionEvent::ReceiveReady(ConnectionSender(sender)),
+            ConnectionEvent::ReceiveReady(ConnectionSender {
+                sender,
+                bulk_op_control: Arc::clone(&bulk_op_control),
+            }),
         )
         .await;
-        let mut get_reservations_interval =
-            IntervalStream::new(time::interval(GET_RESERVATIONS_INTERVAL)).fuse();
+        // Replaced with the interval of the settings when connecting
+        let mut get_reservations_interval = IntervalStream::new(time::interval(
+            ConnectionTimeouts::default().reservations_poll(),
+        ))
+        .fuse();
 
         loop {
             debug!(%state);
@@ -172,31 +689,31 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                     futures::select! {
                         msg = receiver.select_next_some() => {
                             debug!(?msg, "Received connection message");
-                            #[allow(clippy::single_match)]
                             match msg {
-                                ConnectionMsg::Connect { address } => {
+                                ConnectionMsg::Connect { address, identity, timeouts, retry, auth, scope } => {
                                     if address.trim().is_empty() {
                                         output_send(&mut output,
                                             ConnectionEvent::Disconnected {
                                                 error: Some(ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 })
                                             }
                                         ).await;
                                         state = State::Disconnected;
                                         continue;
                                     }
-                                    let timeout_sleep = tokio::time::sleep(CONNECT_TIMEOUT);
+                                    let timeout_sleep = tokio::time::sleep(timeouts.connect() + timeouts.stream());
                                     tokio::pin!(timeout_sleep);
 
                                     // For visually debugging UI 'connecting' state
                                     //tokio::time::sleep(Duration::from_secs(5)).await;
 
                                     tokio::select!{
-                                        res = connect(address.clone()) => {
-                                            let (client, client_in_sender, client_out_stream, sync_id) = match res {
+                                        res = connect(address.clone(), identity.clone(), timeouts, retry, auth, scope) => {
+                                            let (client, client_in_sender, client_out_stream, sync_id, coordinator_version) = match res {
                                                 Ok(res) => res,
                                                 Err(e) => {
                                                     output_send(&mut output,
@@ -204,8 +721,9 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                             error: Some(
                                                                 ErrorReport {
                                                                     criticality: ErrorCriticality::Critical,
-                                                                    short: "Connecting failed".to_string(),
-                                                                    detailed: format!("{e:?}")
+                                                                    short: fl!("error-connecting-failed"),
+                                                                    detailed: format!("{e:?}"),
+                                                                    retry: None,
                                                                 }
                                                             )
                                                         }
@@ -214,12 +732,18 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                     continue;
                                                 }
                                             };
-                                            output_send(&mut output, ConnectionEvent::Connected { address }).await;
+                                            output_send(&mut output, ConnectionEvent::Connected { address, identity }).await;
+                                            output_send(&mut output, ConnectionEvent::CoordinatorVersion(coordinator_version)).await;
+                                            get_reservations_interval =
+                                                IntervalStream::new(time::interval(timeouts.reservations_poll())).fuse();
                                             state = State::Connected {
                                                 client,
                                                 client_in_sender,
                                                 client_out_stream: client_out_stream.fuse(),
                                                 sync_id,
+                                                keepalive: Keepalive::new(&timeouts),
+                                                synced: false,
+                                                mismatch_reported: false,
                                             };
                                         },
                                         _ = &mut timeout_sleep => {
@@ -228,8 +752,9 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                     error: Some(
                                                         ErrorReport {
                                                             criticality: ErrorCriticality::Critical,
-                                                            short: "Timeout reached while trying to connect".to_string(),
-                                                            detailed: "".to_string()
+                                                            short: fl!("error-connecting-timeout"),
+                                                            detailed: "".to_string(),
+                                                            retry: None,
                                                         }
                                                     )
                                                 }
@@ -238,6 +763,9 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                         }
                                     };
                                 }
+                                ConnectionMsg::Shutdown { .. } => {
+                                    output_send(&mut output, ConnectionEvent::ShutdownComplete).await;
+                                }
                                 _ => {}
                             }
                         }
@@ -249,38 +777,43 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                     client_in_sender,
                     client_out_stream,
                     sync_id,
+                    keepalive,
+                    synced,
+                    mismatch_reported,
                 } => {
                     futures::select! {
                         msg = receiver.select_next_some() => {
                             debug!(?msg, "Received connection message");
                             match msg {
-                                ConnectionMsg::Connect { address } => {
+                                ConnectionMsg::Connect { address, identity, timeouts, retry, auth, scope } => {
                                     if address.trim().is_empty() {
                                         output_send(&mut output,
                                             ConnectionEvent::NonCriticalError {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: format!("Input: '{address}")
+                                                    detailed: format!("Input: '{address}"),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
-                                    let timeout_sleep = tokio::time::sleep(CONNECT_TIMEOUT);
+                                    let timeout_sleep = tokio::time::sleep(timeouts.connect() + timeouts.stream());
                                     tokio::pin!(timeout_sleep);
 
                                     tokio::select!{
-                                        res = connect(address.clone()) => {
-                                            let (client, client_in_sender, client_out_stream, sync_id) = match res {
+                                        res = connect(address.clone(), identity.clone(), timeouts, retry, auth, scope) => {
+                                            let (client, client_in_sender, client_out_stream, sync_id, coordinator_version) = match res {
                                                 Ok(res) => res,
                                                 Err(e) => {
                                                     output_send(&mut output, ConnectionEvent::Disconnected {
                                                         error: Some(
                                                                 ErrorReport {
                                                                 criticality: ErrorCriticality::Critical,
-                                                                short: "Connecting failed".to_string(),
-                                                                detailed: format!("{e:?}")
+                                                                short: fl!("error-connecting-failed"),
+                                                                detailed: format!("{e:?}"),
+                                                                retry: None,
                                                             }
                                                         )
                                                     }).await;
@@ -288,13 +821,19 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                     continue;
                                                 }
                                             };
-                                            output_send(&mut output, ConnectionEvent::Connected { address }).await;
+                                            output_send(&mut output, ConnectionEvent::Connected { address, identity }).await;
+                                            output_send(&mut output, ConnectionEvent::CoordinatorVersion(coordinator_version)).await;
+                                            get_reservations_interval =
+                                                IntervalStream::new(time::interval(timeouts.reservations_poll())).fuse();
                                             state = State::Connected {
                                                 client,
                                                 client_in_sender,
                                                 client_out_stream: client_out_stream.fuse(),
                                                 sync_id,
-                                             };
+                                                keepalive: Keepalive::new(&timeouts),
+                                                synced: false,
+                                                mismatch_reported: false,
+                                            };
                                         },
                                         _ = &mut timeout_sleep => {
                                             warn!("Timeout reached while trying to connect");
@@ -302,8 +841,9 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: Some(
                                                         ErrorReport {
                                                         criticality: ErrorCriticality::Critical,
-                                                        short: "Timeout reached while trying to connect".to_string(),
-                                                        detailed: "".to_string()
+                                                        short: fl!("error-connecting-timeout"),
+                                                        detailed: "".to_string(),
+                                                        retry: None,
                                                     }
                                                 )
                                             }).await;
@@ -321,7 +861,7 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                 ConnectionMsg::GetPlaces => {
                                     match client.get_places().await {
                                         Ok(places) => output_send(&mut output, ConnectionEvent::Places(places)).await,
-                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error).await
+                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error, None).await
                                     }
                                 }
                                 ConnectionMsg::AcquirePlace {name} => {
@@ -331,14 +871,40 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
+                                    let retry = ConnectionMsg::AcquirePlace { name: name.clone() };
                                     if let Err(error) = client.acquire_place(name).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                        handle_grpc_client_error(&mut state, &mut output, error, Some(retry)).await;
+                                    };
+                                },
+                                ConnectionMsg::AcquirePlaceWithReason {name, reason} => {
+                                    if name.trim().is_empty() || reason.trim().is_empty() {
+                                        output_send(&mut output,
+                                            ConnectionEvent::NonCriticalError {
+                                                error: ErrorReport {
+                                                    criticality: ErrorCriticality::NonCritical,
+                                                    short: fl!("connection-msg-invalid-input"),
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
+                                                }
+                                            }
+                                        ).await;
+                                        continue;
+                                    }
+                                    let retry = ConnectionMsg::AcquirePlaceWithReason { name: name.clone(), reason: reason.clone() };
+                                    if let Err(error) = client.acquire_place(name.clone()).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, Some(retry)).await;
+                                        continue;
+                                    };
+                                    let tags = HashMap::from([(app::ACQUIRE_REASON_TAG.to_string(), reason.trim().to_string())]);
+                                    if let Err(error) = client.set_place_tags(name, tags).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                     };
                                 },
                                 ConnectionMsg::ReleasePlace {name} => {
@@ -348,16 +914,64 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
                                     if let Err(error) = client.release_place(name, None).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
+                                    };
+                                },
+                                ConnectionMsg::ForceReleasePlace {name, from_user} => {
+                                    if name.trim().is_empty() || from_user.trim().is_empty() {
+                                        output_send(&mut output,
+                                            ConnectionEvent::NonCriticalError {
+                                                error: ErrorReport {
+                                                    criticality: ErrorCriticality::NonCritical,
+                                                    short: fl!("connection-msg-invalid-input"),
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
+                                                }
+                                            }
+                                        ).await;
+                                        continue;
+                                    }
+                                    if let Err(error) = client.release_place(name, Some(from_user)).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                     };
                                 },
+                                ConnectionMsg::AcquirePlaces {names} => {
+                                    let res = bulk_place_op(client, BulkPlaceOp::Acquire, names, &bulk_op_control, &mut output).await;
+                                    handle_bulk_op_result(&mut state, &mut output, res, bulk_place_op_failed_msg).await;
+                                },
+                                ConnectionMsg::ReleasePlaces {names} => {
+                                    let res = bulk_place_op(client, BulkPlaceOp::Release, names, &bulk_op_control, &mut output).await;
+                                    handle_bulk_op_result(&mut state, &mut output, res, bulk_place_op_failed_msg).await;
+                                },
+                                ConnectionMsg::DeletePlaces {names} => {
+                                    let res = bulk_place_op(client, BulkPlaceOp::Delete, names, &bulk_op_control, &mut output).await;
+                                    handle_bulk_op_result(&mut state, &mut output, res, bulk_place_op_failed_msg).await;
+                                },
+                                ConnectionMsg::SetPlacesTag {names, tag} => {
+                                    if tag.0.trim().is_empty() || tag.1.trim().is_empty() {
+                                        output_send(&mut output,
+                                            ConnectionEvent::NonCriticalError {
+                                                error: ErrorReport {
+                                                    criticality: ErrorCriticality::NonCritical,
+                                                    short: fl!("connection-msg-invalid-input"),
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
+                                                }
+                                            }
+                                        ).await;
+                                        continue;
+                                    }
+                                    let res = bulk_place_op(client, BulkPlaceOp::SetTag(tag), names, &bulk_op_control, &mut output).await;
+                                    handle_bulk_op_result(&mut state, &mut output, res, bulk_place_op_failed_msg).await;
+                                },
                                 ConnectionMsg::AddPlace {name} => {
                                     if name.trim().is_empty() {
                                         output_send(&mut output,
@@ -365,14 +979,20 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
                                     if let Err(error) = client.add_place(name).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
+                                    };
+                                },
+                                ConnectionMsg::ClonePlace {place_clone} => {
+                                    if let Err(error) = clone_place(client, place_clone).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                     };
                                 },
                                 ConnectionMsg::DeletePlace {name} => {
@@ -382,50 +1002,94 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
                                     if let Err(error) = client.delete_place(name).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                         continue;
                                     };
                                 },
-                                ConnectionMsg::AddPlaceMatch {place_name, pattern} => {
+                                ConnectionMsg::AddPlaceMatch {place_name, pattern, rename} => {
                                     if place_name.trim().is_empty() || pattern.trim().is_empty() {
                                         output_send(&mut output,
                                             ConnectionEvent::NonCriticalError {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
-                                    if let Err(error) = client.add_place_match(place_name, pattern, None).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                    let retry = ConnectionMsg::AddPlaceMatch { place_name: place_name.clone(), pattern: pattern.clone(), rename: rename.clone() };
+                                    let rename = rename.map(|rename| rename.trim().to_string()).filter(|rename| !rename.is_empty());
+                                    if let Err(error) = client.add_place_match(place_name, pattern, rename).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, Some(retry)).await;
                                         continue;
                                     };
                                 },
-                                ConnectionMsg::DeletePlaceMatch {place_name, pattern} => {
+                                ConnectionMsg::DeletePlaceMatch {place_name, pattern, rename} => {
                                     if place_name.trim().is_empty() | pattern.trim().is_empty() {
                                         output_send(&mut output,
                                             ConnectionEvent::NonCriticalError {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
-                                    if let Err(error) = client.delete_place_match(place_name, pattern, None).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                    if let Err(error) = client.delete_place_match(place_name, pattern, rename).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
+                                        continue;
+                                    };
+                                },
+                                ConnectionMsg::AddPlaceAlias {place_name, alias} => {
+                                    if place_name.trim().is_empty() || alias.trim().is_empty() {
+                                        output_send(&mut output,
+                                            ConnectionEvent::NonCriticalError {
+                                                error: ErrorReport {
+                                                    criticality: ErrorCriticality::NonCritical,
+                                                    short: fl!("connection-msg-invalid-input"),
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
+                                                }
+                                            }
+                                        ).await;
+                                        continue;
+                                    }
+                                    let retry = ConnectionMsg::AddPlaceAlias { place_name: place_name.clone(), alias: alias.clone() };
+                                    if let Err(error) = client.add_place_alias(place_name, alias.trim().to_string()).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, Some(retry)).await;
+                                        continue;
+                                    };
+                                },
+                                ConnectionMsg::DeletePlaceAlias {place_name, alias} => {
+                                    if place_name.trim().is_empty() || alias.trim().is_empty() {
+                                        output_send(&mut output,
+                                            ConnectionEvent::NonCriticalError {
+                                                error: ErrorReport {
+                                                    criticality: ErrorCriticality::NonCritical,
+                                                    short: fl!("connection-msg-invalid-input"),
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
+                                                }
+                                            }
+                                        ).await;
+                                        continue;
+                                    }
+                                    if let Err(error) = client.delete_place_alias(place_name, alias).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                         continue;
                                     };
                                 },
@@ -439,14 +1103,15 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
                                     if let Err(error) = client.set_place_tags(place_name, HashMap::from([tag])).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                         continue;
                                     };
                                 }
@@ -460,21 +1125,73 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
                                     if let Err(error) = client.set_place_tags(place_name, HashMap::from([(tag, String::default())])).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
+                                        continue;
+                                    };
+                                },
+                                ConnectionMsg::SetPlaceComment {
+                                    place_name,
+                                    comment
+                                } => {
+                                    if place_name.trim().is_empty() {
+                                        output_send(&mut output,
+                                            ConnectionEvent::NonCriticalError {
+                                                error: ErrorReport {
+                                                    criticality: ErrorCriticality::NonCritical,
+                                                    short: fl!("connection-msg-invalid-input"),
+                                                    detailed: "Place name must not be empty".to_string(),
+                                                    retry: None,
+                                                }
+                                            }
+                                        ).await;
+                                        continue;
+                                    }
+                                    if let Err(error) = client.set_place_comment(place_name, comment).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                         continue;
                                     };
                                 },
                                 ConnectionMsg::GetReservations => {
                                     match client.get_reservations().await {
                                         Ok(reservations) => output_send(&mut output, ConnectionEvent::Reservations(reservations)).await,
-                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error).await
+                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error, None).await
+                                    }
+                                },
+                                ConnectionMsg::CreateReservation {
+                                    filters,
+                                    prio
+                                } => {
+                                    if filters.is_empty() {
+                                        output_send(&mut output,
+                                            ConnectionEvent::NonCriticalError {
+                                                error: ErrorReport {
+                                                    criticality: ErrorCriticality::NonCritical,
+                                                    short: fl!("connection-msg-invalid-input"),
+                                                    detailed: "Filters must not be empty".to_string(),
+                                                    retry: None,
+                                                }
+                                            }
+                                        ).await;
+                                        continue;
+                                    }
+                                    match client.create_reservation(filters, prio).await {
+                                        Ok(reservation) => output_send(&mut output, ConnectionEvent::ReservationCreated(reservation)).await,
+                                        Err(error) => {
+                                            handle_grpc_client_error(&mut state, &mut output, error, None).await;
+                                            continue;
+                                        }
+                                    };
+                                    match client.get_reservations().await {
+                                        Ok(reservations) => output_send(&mut output, ConnectionEvent::Reservations(reservations)).await,
+                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error, None).await
                                     }
                                 },
                                 ConnectionMsg::CancelReservation {
@@ -486,39 +1203,120 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
                                                 error: ErrorReport {
                                                     criticality: ErrorCriticality::NonCritical,
                                                     short: fl!("connection-msg-invalid-input"),
-                                                    detailed: "Input must not be empty".to_string()
+                                                    detailed: "Input must not be empty".to_string(),
+                                                    retry: None,
                                                 }
                                             }
                                         ).await;
                                         continue;
                                     }
                                     if let Err(error) = client.cancel_reservation(token).await {
-                                        handle_grpc_client_error(&mut state, &mut output, error).await;
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
                                         continue;
                                     };
                                     match client.get_reservations().await {
                                         Ok(reservations) => output_send(&mut output, ConnectionEvent::Reservations(reservations)).await,
-                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error).await
+                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error, None).await
                                     }
                                 },
+                                ConnectionMsg::PollReservation { token } => {
+                                    if let Err(error) = client.poll_reservation(token).await {
+                                        handle_grpc_client_error(&mut state, &mut output, error, None).await;
+                                        continue;
+                                    };
+                                    match client.get_reservations().await {
+                                        Ok(reservations) => output_send(&mut output, ConnectionEvent::Reservations(reservations)).await,
+                                        Err(error) => handle_grpc_client_error(&mut state, &mut output, error, None).await
+                                    }
+                                },
+                                ConnectionMsg::RestorePlaces { steps } => {
+                                    let res = restore_places(client, steps).await;
+                                    handle_bulk_op_result(&mut state, &mut output, res, |count| {
+                                        fl!("connection-msg-restore-places-failed", count = count)
+                                    }).await;
+                                },
+                                ConnectionMsg::CancelReservations { tokens } => {
+                                    let res = cancel_reservations(client, tokens).await;
+                                    let refreshed = match &res {
+                                        Ok(_) => Some(client.get_reservations().await),
+                                        Err(_) => None,
+                                    };
+                                    handle_bulk_op_result(&mut state, &mut output, res, |count| {
+                                        fl!("connection-msg-cancel-reservations-failed", count = count)
+                                    }).await;
+                                    match refreshed {
+                                        Some(Ok(reservations)) => output_send(&mut output, ConnectionEvent::Reservations(reservations)).await,
+                                        Some(Err(error)) => handle_grpc_client_error(&mut state, &mut output, error, None).await,
+                                        None => {}
+                                    }
+                                },
+                                ConnectionMsg::Shutdown { release } => {
+                                    if time::timeout(
+                                        SHUTDOWN_TIMEOUT,
+                                        shutdown(client, client_in_sender, client_out_stream, release),
+                                    )
+                                    .await
+                                    .is_err()
+                                    {
+                                        warn!("Timeout reached while shutting down the connection");
+                                    }
+                                    state = State::Disconnected;
+                                    output_send(&mut output, ConnectionEvent::ShutdownComplete).await;
+                                }
                             }
                         },
                         client_out_msg = client_out_stream.select_next_some() => {
                             let Ok(msg) = client_out_msg.inspect_err(|error| error!(?error, "Received error as client out message")) else {
                                 continue;
                             };
-                            let Ok(msg) = ClientOutMsg::try_from(msg).inspect_err(|error| error!(?error, "Converting proto client out message")) else{
-                                continue;
+                            let msg = match ClientOutMsg::try_from(msg) {
+                                Ok(msg) => msg,
+                                Err(error) => {
+                                    error!(?error, "Converting proto client out message");
+                                    if !std::mem::replace(mismatch_reported, true) {
+                                        output_send(&mut output, ConnectionEvent::ProtocolMismatch(error.to_string())).await;
+                                    }
+                                    continue;
+                                }
                             };
+                            if let Some(latency) = msg.sync.as_ref().and_then(|sync| keepalive.echo(sync.id, Instant::now())) {
+                                output_send(&mut output, ConnectionEvent::Latency(latency)).await;
+                            }
+                            // The coordinator echoes the initial sync once it sent all places and resources
+                            let initial_sync = msg.sync.is_some() && !*synced;
                             if let Err(error) = handle_out_msg(&mut output, msg).await {
                                 error!(?error, "Handling received client out message");
                                 continue;
                             }
+                            if initial_sync {
+                                *synced = true;
+                                output_send(&mut output, ConnectionEvent::Synced).await;
+                            }
                         },
+                        _ = keepalive.ticks.select_next_some() => {
+                            match keepalive.tick(sync_id, Instant::now()) {
+                                KeepaliveAction::Ping(id) => {
+                                    client_stream_send(client_in_sender, ClientInMsg::Sync(types::Sync { id })).await;
+                                }
+                                KeepaliveAction::Wait => {}
+                                KeepaliveAction::Dead => {
+                                    warn!("Keepalive not echoed in time, the client stream is considered dead");
+                                    output_send(&mut output, ConnectionEvent::Disconnected {
+                                        error: Some(ErrorReport {
+                                            criticality: ErrorCriticality::Critical,
+                                            short: fl!("error-keepalive-timeout"),
+                                            detailed: format!("No response within {:?}", keepalive.timeout),
+                                            retry: None,
+                                        })
+                                    }).await;
+                                    state = State::Disconnected;
+                                }
+                            }
+                        }
                         _ = get_reservations_interval.select_next_some() => {
                             match client.get_reservations().await {
                                 Ok(reservations) => output_send(&mut output, ConnectionEvent::Reservations(reservations)).await,
-                                Err(error) => handle_grpc_client_error(&mut state, &mut output, error).await
+                                Err(error) => handle_grpc_client_error(&mut state, &mut output, error, None).await
                             }
                         }
                         // TODO: cancellation?
@@ -531,76 +1329,303 @@ pub(crate) fn kickoff() -> impl futures::Stream<Item = ConnectionEvent> {
 
 /// Used when the grpc client reported an error.
 ///
-/// Sends different events based on the error's severity.
+/// Disconnects when the error is fatal, otherwise reports it as non-critical error.
+/// `retry` is offered to the user when repeating the operation might succeed.
 async fn handle_grpc_client_error(
     state: &mut State,
     output: &mut mpsc::Sender<ConnectionEvent>,
     error: GrpcClientError,
+    retry: Option<ConnectionMsg>,
 ) {
-    match &error {
-        GrpcClientError::TonicTransport(error) => {
-            error!(?error, "Transport failure");
-            output_send(
-                output,
-                ConnectionEvent::Disconnected {
-                    error: Some(ErrorReport {
-                        criticality: ErrorCriticality::Critical,
-                        short: "Transport failure".to_string(),
-                        detailed: format!("{error:?}"),
-                    }),
-                },
-            )
-            .await;
-            *state = State::Disconnected;
+    let kind = error.kind();
+    if kind.is_fatal() {
+        error!(?error, %kind, "Encountered fatal client error");
+        let short = match &error {
+            GrpcClientError::TonicStatus(status) => fl!(
+                "error-grpc-status-unavailable",
+                code = format!("{:?}", status.code())
+            ),
+            _ => fl!("error-transport-failure"),
+        };
+        output_send(
+            output,
+            ConnectionEvent::Disconnected {
+                error: Some(ErrorReport {
+                    criticality: ErrorCriticality::Critical,
+                    short,
+                    detailed: format!("{error:?}"),
+                    retry: None,
+                }),
+            },
+        )
+        .await;
+        *state = State::Disconnected;
+        return;
+    }
+
+    let short = match &error {
+        GrpcClientError::MsgConversion(_) => fl!("error-msg-conversion"),
+        GrpcClientError::TonicStatus(status) if status.code() == tonic::Code::Ok => {
+            warn!("Everything's fine?!");
+            return;
+        }
+        GrpcClientError::TonicStatus(status) => {
+            fl!("error-grpc-status", code = format!("{:?}", status.code()))
+        }
+        _ => fl!("error-grpc-status", code = kind.to_string()),
+    };
+    error!(?error, %kind, "Encountered client error");
+    output_send(
+        output,
+        ConnectionEvent::NonCriticalError {
+            error: ErrorReport {
+                criticality: ErrorCriticality::NonCritical,
+                short,
+                detailed: format!("{error:?}"),
+                retry: retry
+                    .filter(|_| kind.is_retryable())
+                    .map(|msg| Box::new(AppMsg::ConnectionMsg(msg))),
+            },
+        },
+    )
+    .await;
+}
+
+/// An operation that is applied to multiple places.
+#[derive(Debug, Clone)]
+enum BulkPlaceOp {
+    Acquire,
+    Release,
+    Delete,
+    SetTag((String, String)),
+}
+
+/// Runs the operation for all supplied places sequentially, rate limited and reporting the progress.
+///
+/// Returns the places for which the operation failed together with the error,
+/// or `Err(error)` if an error was encountered that requires disconnecting, in which case the remaining places are skipped.
+/// The remaining places are also skipped when the operation is cancelled through `control`.
+async fn bulk_place_op(
+    client: &mut LabgridGrpcClient,
+    op: BulkPlaceOp,
+    names: Vec<String>,
+    control: &BulkOpControl,
+    output: &mut mpsc::Sender<ConnectionEvent>,
+) -> Result<Vec<(String, GrpcClientError)>, GrpcClientError> {
+    control.reset();
+    let total = names.len();
+    let mut bucket = TokenBucket::new(control.ops_per_sec(), Instant::now());
+    output_send(
+        output,
+        ConnectionEvent::BulkPlaceOpProgress(Some(BulkPlaceOpProgress { done: 0, total })),
+    )
+    .await;
+    let res = bulk_place_op_rate_limited(client, op, names, control, output, &mut bucket).await;
+    output_send(output, ConnectionEvent::BulkPlaceOpProgress(None)).await;
+    res
+}
+
+async fn bulk_place_op_rate_limited(
+    client: &mut LabgridGrpcClient,
+    op: BulkPlaceOp,
+    names: Vec<String>,
+    control: &BulkOpControl,
+    output: &mut mpsc::Sender<ConnectionEvent>,
+    bucket: &mut TokenBucket,
+) -> Result<Vec<(String, GrpcClientError)>, GrpcClientError> {
+    const PAUSED_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    let total = names.len();
+    let mut failed = Vec::new();
+    for (done, name) in names.into_iter().enumerate() {
+        while control.paused() && !control.cancelled() {
+            time::sleep(PAUSED_POLL_INTERVAL).await;
         }
-        GrpcClientError::MsgConversion(msg) => {
+        if control.cancelled() {
+            debug!(done, total, "Bulk place operation cancelled");
+            break;
+        }
+        time::sleep(bucket.take(Instant::now())).await;
+        output_send(
+            output,
+            ConnectionEvent::BulkPlaceOpProgress(Some(BulkPlaceOpProgress { done, total })),
+        )
+        .await;
+        let res = match &op {
+            BulkPlaceOp::Acquire => client.acquire_place(name.clone()).await,
+            BulkPlaceOp::Release => client.release_place(name.clone(), None).await,
+            BulkPlaceOp::Delete => client.delete_place(name.clone()).await,
+            BulkPlaceOp::SetTag(tag) => {
+                client
+                    .set_place_tags(name.clone(), HashMap::from([tag.clone()]))
+                    .await
+            }
+        };
+        match res {
+            Ok(()) => {}
+            Err(error @ GrpcClientError::TonicTransport(_)) => return Err(error),
+            Err(GrpcClientError::TonicStatus(status))
+                if matches!(
+                    status.code(),
+                    tonic::Code::Unavailable | tonic::Code::DeadlineExceeded
+                ) =>
+            {
+                return Err(GrpcClientError::TonicStatus(status))
+            }
+            Err(error) => failed.push((name, error)),
+        }
+    }
+    Ok(failed)
+}
+
+fn bulk_place_op_failed_msg(count: usize) -> String {
+    fl!("connection-msg-bulk-place-op-failed", count = count)
+}
+
+/// Creates the cloned place, then adds its aliases, tags and matches one after another.
+async fn clone_place(
+    client: &mut LabgridGrpcClient,
+    place_clone: PlaceClone,
+) -> Result<(), GrpcClientError> {
+    let name = place_clone.name.trim().to_string();
+    client.add_place(name.clone()).await?;
+    for alias in place_clone.aliases {
+        client.add_place_alias(name.clone(), alias).await?;
+    }
+    if !place_clone.tags.is_empty() {
+        client
+            .set_place_tags(name.clone(), place_clone.tags.into_iter().collect())
+            .await?;
+    }
+    for m in place_clone.matches {
+        client
+            .add_place_match(name.clone(), m.pattern, m.rename)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Applies the steps restoring a place snapshot sequentially.
+///
+/// Returns the failed steps together with the error, the remaining steps of a place that could not be created
+/// are skipped. Returns `Err(error)` if an error was encountered that requires disconnecting.
+async fn restore_places(
+    client: &mut LabgridGrpcClient,
+    steps: Vec<RestoreStep>,
+) -> Result<Vec<(String, GrpcClientError)>, GrpcClientError> {
+    let mut failed = Vec::new();
+    let mut failed_places = Vec::new();
+    for step in steps {
+        if failed_places.iter().any(|p| p == step.place()) {
+            continue;
+        }
+        let description = step.to_string();
+        let res = match step {
+            RestoreStep::AddPlace { place } => {
+                let res = client.add_place(place.clone()).await;
+                if res.is_err() {
+                    failed_places.push(place);
+                }
+                res
+            }
+            RestoreStep::AddAlias { place, alias } => client.add_place_alias(place, alias).await,
+            RestoreStep::SetComment { place, comment } => {
+                client.set_place_comment(place, comment).await
+            }
+            RestoreStep::SetTag {
+                place, key, value, ..
+            } => {
+                client
+                    .set_place_tags(place, HashMap::from([(key, value)]))
+                    .await
+            }
+            RestoreStep::AddMatch {
+                place,
+                pattern,
+                rename,
+            } => client.add_place_match(place, pattern, rename).await,
+        };
+        match res {
+            Ok(()) => {}
+            Err(error) if error.is_fatal() => return Err(error),
+            Err(error) => failed.push((description, error)),
+        }
+    }
+    Ok(failed)
+}
+
+/// Cancels the reservations with the supplied tokens sequentially.
+///
+/// Returns the tokens for which cancelling failed together with the error,
+/// or `Err(error)` if an error was encountered that requires disconnecting, in which case the remaining ones are skipped.
+async fn cancel_reservations(
+    client: &mut LabgridGrpcClient,
+    tokens: Vec<String>,
+) -> Result<Vec<(String, GrpcClientError)>, GrpcClientError> {
+    let mut failed = Vec::new();
+    for token in tokens {
+        match client.cancel_reservation(token.clone()).await {
+            Ok(()) => {}
+            Err(error) if error.is_fatal() => return Err(error),
+            Err(error) => failed.push((token, error)),
+        }
+    }
+    Ok(failed)
+}
+
+/// Reports the failures of a bulk operation aggregated in a single error, summarized by `short`.
+async fn handle_bulk_op_result(
+    state: &mut State,
+    output: &mut mpsc::Sender<ConnectionEvent>,
+    res: Result<Vec<(String, GrpcClientError)>, GrpcClientError>,
+    short: impl FnOnce(usize) -> String,
+) {
+    match res {
+        Ok(failed) if failed.is_empty() => {}
+        Ok(failed) => {
+            let detailed = failed
+                .iter()
+                .map(|(name, error)| format!("{name}: {error:?}"))
+                .collect::<Vec<String>>()
+                .join("\n");
             output_send(
                 output,
                 ConnectionEvent::NonCriticalError {
                     error: ErrorReport {
                         criticality: ErrorCriticality::NonCritical,
-                        short: "Message conversion".to_string(),
-                        detailed: format!("{msg:?}"),
+                        short: short(failed.len()),
+                        detailed,
+                        retry: None,
                     },
                 },
             )
             .await;
         }
-        GrpcClientError::TonicStatus(status) => match status.code() {
-            tonic::Code::Ok => warn!("Everything's fine?!"),
-            tonic::Code::Unavailable | tonic::Code::DeadlineExceeded => {
-                error!(?error, "Encountered non-recoverable tonic error status");
-                output_send(
-                    output,
-                    ConnectionEvent::Disconnected {
-                        error: Some(ErrorReport {
-                            criticality: ErrorCriticality::Critical,
-                            short: "Non-recoverable tonic error status".to_string(),
-                            detailed: format!("{error:?}"),
-                        }),
-                    },
-                )
-                .await;
-                *state = State::Disconnected;
-            }
-            _ => {
-                error!(?error, "Encountered tonic error status");
-                output_send(
-                    output,
-                    ConnectionEvent::NonCriticalError {
-                        error: ErrorReport {
-                            criticality: ErrorCriticality::NonCritical,
-                            short: "Tonic error status".to_string(),
-                            detailed: format!("{error:?}"),
-                        },
-                    },
-                )
-                .await;
-            }
-        },
+        Err(error) => handle_grpc_client_error(state, output, error, None).await,
     }
 }
 
+/// Releases the places, then closes the client stream and waits for the coordinator to end the subscription.
+///
+/// Errors are only logged, the application exits regardless.
+async fn shutdown(
+    client: &mut LabgridGrpcClient,
+    client_in_sender: &mut mpsc::UnboundedSender<ClientInMsg>,
+    client_out_stream: &mut Fuse<tonic::Streaming<proto::ClientOutMessage>>,
+    release: Vec<String>,
+) {
+    for name in release {
+        debug!(%name, "Releasing place on exit");
+        if let Err(error) = client.release_place(name, None).await {
+            error!(?error, "Releasing place on exit");
+        }
+    }
+    client_in_sender.close_channel();
+    // Remaining updates are not of interest anymore
+    while client_out_stream.next().await.is_some() {}
+    debug!("Client stream terminated");
+}
+
 /// Sends an event through the connection event channel.
 ///
 /// The sent event will be handled by iced's message passing and appear in the `update` routine of the UI.
@@ -636,37 +1661,70 @@ async fn handle_out_msg(
             UpdateResponse::DeletePlace(n) => {
                 output_send(output, ConnectionEvent::DeletePlace(n)).await;
             }
+            update => warn!(?update, "Unhandled update response"),
         }
     }
     Ok(())
 }
 
-/// Attempts to connect to the coordinator with the supplied address (including port, delimited by `:` character).
+/// The established connection returned by [connect].
+type Established = (
+    LabgridGrpcClient,
+    mpsc::UnboundedSender<ClientInMsg>,
+    tonic::Streaming<proto::ClientOutMessage>,
+    SyncId,
+    Option<String>,
+);
+
+/// Attempts to connect to the coordinator with the supplied address (including port, delimited by `:` character),
+/// identifying with the supplied `host/user` name.
 ///
 /// Returns:
 /// - the gRPC client that needs to be held to keep the connection alive.
 /// - the client in message sender, which can be used to send client in messages to the coordinator event stream.
 /// - a stream that emits client out messages incoming from the coordinator.
 /// - the sync id that needs to be used whenever a sync event is sent to the coordinator.
+/// - the labgrid version reported by the coordinator, if it reports one.
 #[instrument]
 async fn connect(
     address: String,
-) -> anyhow::Result<(
-    LabgridGrpcClient,
-    mpsc::UnboundedSender<ClientInMsg>,
-    tonic::Streaming<proto::ClientOutMessage>,
-    SyncId,
-)> {
-    let mut client = LabgridGrpcClient::new(address.as_str()).await?;
+    identity: String,
+    timeouts: ConnectionTimeouts,
+    retry: bool,
+    auth: ConnectionAuth,
+    scope: SubscriptionScope,
+) -> anyhow::Result<Established> {
+    let mut builder = LabgridGrpcClient::builder()
+        .timeouts(ClientTimeouts {
+            connect: Some(timeouts.connect()),
+            rpc: Some(timeouts.rpc()),
+        })
+        .credentials(auth.credentials().context("Authentication")?)
+        .message_trace(protocol_inspector::PROTOCOL_TRACE.clone());
+    if retry {
+        builder = builder.retry_policy(RetryPolicy::default());
+    }
+    let mut client = builder.connect(&address).await?;
     debug!("Successfully connected with gRPC client");
+    // Opens a short exporter stream, waiting at most `version::HELLO_TIMEOUT`
+    let coordinator_version = match client.coordinator_version().await {
+        Ok(version) => {
+            debug!(version, "Coordinator reported its version");
+            Some(version)
+        }
+        Err(error) => {
+            warn!(?error, "Coordinator did not report its version");
+            None
+        }
+    };
     let (mut client_in_sender, client_in_receiver) = mpsc::unbounded::<ClientInMsg>();
     let mut sync_id = SyncId::default();
 
     client_stream_send(
         &mut client_in_sender,
         ClientInMsg::StartupDone(StartupDone {
-            version: "1".to_string(),
-            name: format!("{}/{}", util::get_lg_hostname(), util::get_lg_username()),
+            version: version::PROTOCOL_VERSION.to_string(),
+            name: identity,
         }),
     )
     .await;
@@ -678,14 +1736,16 @@ async fn connect(
         }),
     )
     .await;
-    client_stream_send(
-        &mut client_in_sender,
-        ClientInMsg::Subscribe(Subscribe {
-            is_unsubscribe: None,
-            kind: SubscribeKind::AllResources(true),
-        }),
-    )
-    .await;
+    if scope == SubscriptionScope::PlacesAndResources {
+        client_stream_send(
+            &mut client_in_sender,
+            ClientInMsg::Subscribe(Subscribe {
+                is_unsubscribe: None,
+                kind: SubscribeKind::AllResources(true),
+            }),
+        )
+        .await;
+    }
     client_stream_send(
         &mut client_in_sender,
         ClientInMsg::Sync(types::Sync { id: sync_id.next() }),
@@ -693,7 +1753,624 @@ async fn connect(
     .await;
 
     // We need to send the messages first before initiating a client stream, otherwise it would never resolve.
-    let client_out_stream = client.client_stream(client_in_receiver.fuse()).await?;
+    let client_out_stream = time::timeout(
+        timeouts.stream(),
+        client.client_stream(client_in_receiver.fuse()),
+    )
+    .await
+    .context("Timeout reached while initiating the client stream")??;
     debug!("Successfully initiated client stream");
-    Ok((client, client_in_sender, client_out_stream, sync_id))
+    Ok((
+        client,
+        client_in_sender,
+        client_out_stream,
+        sync_id,
+        coordinator_version,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::place;
+    use labgrid_ui_core::mock::MockCoordinator;
+
+    /// The next event of the connection, skipping the periodically fetched reservations and the coordinator version.
+    async fn next_event(
+        events: &mut (impl futures::Stream<Item = ConnectionEvent> + Unpin),
+    ) -> ConnectionEvent {
+        loop {
+            let event = time::timeout(Duration::from_secs(5), events.next())
+                .await
+                .expect("Timeout waiting for connection event")
+                .expect("Connection ended");
+            if !matches!(
+                event,
+                ConnectionEvent::Reservations(_)
+                    | ConnectionEvent::Synced
+                    | ConnectionEvent::CoordinatorVersion(_)
+            ) {
+                return event;
+            }
+        }
+    }
+
+    /// Starts the connection and connects it to the address.
+    async fn connect_to(
+        address: String,
+        timeouts: ConnectionTimeouts,
+    ) -> (
+        impl futures::Stream<Item = ConnectionEvent> + Unpin,
+        ConnectionSender,
+    ) {
+        let mut events = Box::pin(kickoff());
+        let ConnectionEvent::ReceiveReady(mut sender) = next_event(&mut events).await else {
+            panic!("Expected the connection to be ready");
+        };
+        sender.send(ConnectionMsg::Connect {
+            address,
+            identity: "host/me".to_string(),
+            timeouts,
+            retry: false,
+            auth: ConnectionAuth::default(),
+            scope: SubscriptionScope::default(),
+        });
+        (events, sender)
+    }
+
+    #[tokio::test]
+    async fn connection_acquire_and_disconnect() {
+        let mock = MockCoordinator::new().with_place(place("board-1"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { identity, .. } if identity == "host/me"
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Place(place) if place.name == "board-1" && place.acquired.is_none()
+        ));
+
+        // The change is received through the client stream
+        sender.send(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        });
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Place(place) if place.acquired.as_deref() == Some("host/me")
+        ));
+
+        // Errors that are not recoverable disconnect
+        mock.fail_next("GetPlaces", tonic::Status::unavailable("restarting"));
+        sender.send(ConnectionMsg::GetPlaces);
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected { error: Some(_) }
+        ));
+    }
+
+    #[tokio::test]
+    async fn connection_synced() {
+        let mock = MockCoordinator::new().with_place(place("board-1"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        let mut received = Vec::new();
+        // The synced event follows the initial places and is only sent once
+        loop {
+            let event = time::timeout(Duration::from_secs(5), events.next())
+                .await
+                .expect("Timeout waiting for connection event")
+                .expect("Connection ended");
+            match event {
+                ConnectionEvent::Synced => break,
+                ConnectionEvent::Place(place) => received.push(place.name),
+                _ => {}
+            }
+        }
+        assert_eq!(received, vec!["board-1".to_string()]);
+
+        sender.send(ConnectionMsg::Sync);
+        sender.send(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        });
+        loop {
+            let event = time::timeout(Duration::from_secs(5), events.next())
+                .await
+                .expect("Timeout waiting for connection event")
+                .expect("Connection ended");
+            match event {
+                ConnectionEvent::Synced => panic!("Synced sent again"),
+                ConnectionEvent::Place(place) if place.acquired.is_some() => break,
+                _ => {}
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn connection_cancel_reservations() {
+        let reservation = |token: &str| types::Reservation {
+            owner: "host/me".to_string(),
+            token: token.to_string(),
+            state: 3,
+            prio: 0.,
+            filters: HashMap::new(),
+            allocations: HashMap::new(),
+            created: 0.,
+            timeout: 0.,
+        };
+        let mock = MockCoordinator::new()
+            .with_reservation(reservation("AAAA"))
+            .with_reservation(reservation("BBBB"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+
+        // Failures are reported aggregated, the remaining reservations are still cancelled
+        sender.send(ConnectionMsg::CancelReservations {
+            tokens: vec!["CCCC".to_string(), "AAAA".to_string()],
+        });
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::NonCriticalError { error } if error.detailed.starts_with("CCCC")
+        ));
+        let refreshed = time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(ConnectionEvent::Reservations(reservations)) = events.next().await {
+                    break reservations;
+                }
+            }
+        })
+        .await
+        .expect("Timeout waiting for the refreshed reservations");
+        assert_eq!(
+            refreshed
+                .iter()
+                .map(|r| r.token.as_str())
+                .collect::<Vec<_>>(),
+            vec!["BBBB"]
+        );
+    }
+
+    #[tokio::test]
+    async fn connection_acquire_with_reason() {
+        let mock = MockCoordinator::new().with_place(place("board-1"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Place(_)
+        ));
+
+        sender.send(ConnectionMsg::AcquirePlaceWithReason {
+            name: "board-1".to_string(),
+            reason: " bisecting boot issue ".to_string(),
+        });
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Place(place) if place.acquired.as_deref() == Some("host/me")
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Place(place)
+                if place.tags.get(app::ACQUIRE_REASON_TAG).map(String::as_str) == Some("bisecting boot issue")
+        ));
+    }
+
+    #[tokio::test]
+    async fn connection_clone_place() {
+        let mut source = place("board-1");
+        source.aliases = vec!["board-1-lab".to_string()];
+        source.tags = HashMap::from([("board".to_string(), "imx8".to_string())]);
+        source.matches = vec![types::ResourceMatch {
+            exporter: "*".to_string(),
+            group: "board-1".to_string(),
+            cls: "NetworkSerialPort".to_string(),
+            name: None,
+            rename: Some("console".to_string()),
+        }];
+        let place_clone = PlaceClone::new(&source, [&source]);
+        let mock = MockCoordinator::new().with_place(source);
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+
+        sender.send(ConnectionMsg::ClonePlace { place_clone });
+        let cloned = time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let ConnectionEvent::Place(place) = next_event(&mut events).await {
+                    if place.name == "board-2" && !place.matches.is_empty() {
+                        break place;
+                    }
+                }
+            }
+        })
+        .await
+        .expect("Timeout waiting for the cloned place");
+        assert_eq!(cloned.aliases, vec!["board-2-lab"]);
+        assert_eq!(cloned.tags.get("board").map(String::as_str), Some("imx8"));
+        assert_eq!(cloned.matches[0].group, "board-2");
+        assert_eq!(cloned.matches[0].rename.as_deref(), Some("console"));
+    }
+
+    #[tokio::test]
+    async fn connection_restore_places() {
+        let mock = MockCoordinator::new().with_place(place("board-1"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+
+        // Failures are reported aggregated, the remaining steps are still applied
+        sender.send(ConnectionMsg::RestorePlaces {
+            steps: vec![
+                RestoreStep::AddAlias {
+                    place: "missing".to_string(),
+                    alias: "rack3".to_string(),
+                },
+                RestoreStep::AddPlace {
+                    place: "board-2".to_string(),
+                },
+                RestoreStep::SetTag {
+                    place: "board-2".to_string(),
+                    key: "board".to_string(),
+                    value: "imx8".to_string(),
+                    current: None,
+                },
+            ],
+        });
+        let restored = time::timeout(Duration::from_secs(5), async {
+            let mut failed = false;
+            loop {
+                match next_event(&mut events).await {
+                    ConnectionEvent::NonCriticalError { error } => {
+                        assert!(error.detailed.starts_with("Add alias 'rack3' to 'missing'"));
+                        failed = true;
+                    }
+                    ConnectionEvent::Place(place)
+                        if place.name == "board-2" && !place.tags.is_empty() =>
+                    {
+                        break (failed, place);
+                    }
+                    _ => {}
+                }
+            }
+        })
+        .await
+        .expect("Timeout waiting for the restored place");
+        assert!(restored.0, "Failed step not reported");
+        assert_eq!(
+            restored.1.tags.get("board").map(String::as_str),
+            Some("imx8")
+        );
+    }
+
+    #[tokio::test]
+    async fn connection_place_aliases() {
+        let mock = MockCoordinator::new().with_place(place("board-1"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+
+        let place_name = "board-1".to_string();
+        sender.send(ConnectionMsg::AddPlaceAlias {
+            place_name: place_name.clone(),
+            alias: " rack3 ".to_string(),
+        });
+        sender.send(ConnectionMsg::DeletePlaceAlias {
+            place_name: place_name.clone(),
+            alias: "rack3".to_string(),
+        });
+        sender.send(ConnectionMsg::AddPlaceAlias {
+            place_name,
+            alias: "rack4".to_string(),
+        });
+        time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let ConnectionEvent::Place(place) = next_event(&mut events).await {
+                    if place.aliases.contains(&"rack4".to_string()) {
+                        break;
+                    }
+                }
+            }
+        })
+        .await
+        .expect("Timeout waiting for the alias");
+        assert_eq!(mock.places()[0].aliases, vec!["rack4"]);
+    }
+
+    #[tokio::test]
+    async fn connection_non_critical_error() {
+        let mock = MockCoordinator::new().with_place(place("board-1"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Place(_)
+        ));
+
+        mock.fail_next(
+            "AcquirePlace",
+            tonic::Status::failed_precondition("acquired by someone else"),
+        );
+        sender.send(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        });
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::NonCriticalError { error } if error.retry.is_some()
+        ));
+
+        // Retrying is not offered when it would fail again
+        mock.fail_next(
+            "AcquirePlace",
+            tonic::Status::permission_denied("not allowed"),
+        );
+        sender.send(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        });
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::NonCriticalError { error } if error.retry.is_none()
+        ));
+
+        // Still connected
+        sender.send(ConnectionMsg::GetPlaces);
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Places(places) if places.len() == 1
+        ));
+    }
+
+    #[tokio::test]
+    async fn connection_coordinator_version() {
+        let mock = MockCoordinator::new().with_version("25.0.1");
+        let server = mock.serve().await.unwrap();
+        let (mut events, _sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        let mut received = vec![];
+        while received.len() < 2 {
+            let event = time::timeout(Duration::from_secs(5), events.next())
+                .await
+                .unwrap()
+                .unwrap();
+            if matches!(
+                event,
+                ConnectionEvent::Connected { .. } | ConnectionEvent::CoordinatorVersion(_)
+            ) {
+                received.push(event);
+            }
+        }
+        assert!(matches!(received[0], ConnectionEvent::Connected { .. }));
+        assert!(matches!(
+            &received[1],
+            ConnectionEvent::CoordinatorVersion(Some(version)) if version == "25.0.1"
+        ));
+        assert!(mock.received_messages().iter().any(|msg| matches!(
+            msg,
+            ClientInMsg::StartupDone(startup) if startup.version == version::PROTOCOL_VERSION
+        )));
+    }
+
+    #[tokio::test]
+    async fn connection_shutdown() {
+        let mock = MockCoordinator::new().with_place(place("board-1"));
+        let server = mock.serve().await.unwrap();
+        let (mut events, mut sender) =
+            connect_to(server.address(), ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Place(_)
+        ));
+
+        // Pending messages are handled before shutting down
+        sender.send(ConnectionMsg::AcquirePlace {
+            name: "board-1".to_string(),
+        });
+        sender.send(ConnectionMsg::Shutdown {
+            release: vec!["board-1".to_string()],
+        });
+        let mut events_before_shutdown = 0;
+        while !matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::ShutdownComplete
+        ) {
+            events_before_shutdown += 1;
+        }
+        assert!(events_before_shutdown <= 2);
+        assert!(mock.calls().contains(&"AcquirePlace"));
+        assert_eq!(mock.places()[0].acquired, None);
+
+        // Disconnected after the shutdown
+        sender.send(ConnectionMsg::Shutdown { release: vec![] });
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::ShutdownComplete
+        ));
+    }
+
+    #[test]
+    fn keepalive_tick_and_echo() {
+        let mut keepalive = Keepalive {
+            ticks: futures::stream::pending().boxed().fuse(),
+            pending: None,
+            timeout: Duration::from_secs(10),
+        };
+        let mut sync_id = SyncId::default();
+        let start = Instant::now();
+
+        assert_eq!(
+            keepalive.tick(&mut sync_id, start),
+            KeepaliveAction::Ping(1)
+        );
+        // Only a single keepalive is in flight
+        assert_eq!(
+            keepalive.tick(&mut sync_id, start + Duration::from_secs(5)),
+            KeepaliveAction::Wait
+        );
+        // Other sync responses are not the echo
+        assert_eq!(keepalive.echo(2, start), None);
+        assert_eq!(
+            keepalive.echo(1, start + Duration::from_millis(40)),
+            Some(Duration::from_millis(40))
+        );
+
+        assert_eq!(
+            keepalive.tick(&mut sync_id, start + Duration::from_secs(20)),
+            KeepaliveAction::Ping(2)
+        );
+        assert_eq!(
+            keepalive.tick(&mut sync_id, start + Duration::from_secs(31)),
+            KeepaliveAction::Dead
+        );
+    }
+
+    #[tokio::test]
+    async fn connection_keepalive_latency() {
+        let mock = MockCoordinator::new();
+        let server = mock.serve().await.unwrap();
+        let (mut events, _sender) = connect_to(
+            server.address(),
+            ConnectionTimeouts {
+                keepalive_secs: 1,
+                ..ConnectionTimeouts::default()
+            },
+        )
+        .await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Latency(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn connection_reservations_poll() {
+        let mock = MockCoordinator::new();
+        let server = mock.serve().await.unwrap();
+        let (mut events, _sender) = connect_to(
+            server.address(),
+            ConnectionTimeouts {
+                reservations_poll_secs: 1,
+                ..ConnectionTimeouts::default()
+            },
+        )
+        .await;
+        // Fetched right after connecting and then with the configured interval
+        let mut fetched = 0;
+        while fetched < 2 {
+            let event = time::timeout(Duration::from_secs(5), events.next())
+                .await
+                .expect("Timeout waiting for the reservations")
+                .expect("Connection ended");
+            if matches!(event, ConnectionEvent::Reservations(_)) {
+                fetched += 1;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn connection_refused() {
+        let address = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().to_string()
+        };
+        let (mut events, _sender) = connect_to(address, ConnectionTimeouts::default()).await;
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected { error: Some(error) }
+                if error.criticality == ErrorCriticality::Critical
+        ));
+    }
+
+    #[test]
+    fn connection_auth_credentials() {
+        let auth = ConnectionAuth {
+            token_kind: AuthTokenKind::Static,
+            token: "secret".to_string(),
+            metadata: "x-api-key = key, x-tenant=lab,".to_string(),
+            ..ConnectionAuth::default()
+        };
+        assert!(!auth.credentials().unwrap().is_empty());
+        let debug = format!("{auth:?}");
+        assert!(!debug.contains("secret") && !debug.contains("key,"));
+        assert!(debug.contains("x-tenant"));
+
+        assert!(ConnectionAuth::default().credentials().unwrap().is_empty());
+        let malformed = ConnectionAuth {
+            metadata: "x-api-key".to_string(),
+            ..ConnectionAuth::default()
+        };
+        assert!(malformed.credentials().is_err());
+    }
+
+    #[tokio::test]
+    async fn connection_auth() {
+        let mock = MockCoordinator::new()
+            .with_place(place("board-1"))
+            .with_required_metadata("authorization", "Bearer secret");
+        let server = mock.serve().await.unwrap();
+        let connect = |auth| ConnectionMsg::Connect {
+            address: server.address(),
+            identity: "host/me".to_string(),
+            timeouts: ConnectionTimeouts::default(),
+            retry: false,
+            auth,
+            scope: SubscriptionScope::default(),
+        };
+
+        let mut events = Box::pin(kickoff());
+        let ConnectionEvent::ReceiveReady(mut sender) = next_event(&mut events).await else {
+            panic!("Expected the connection to be ready");
+        };
+        sender.send(connect(ConnectionAuth::default()));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Disconnected { error: Some(_) }
+        ));
+
+        sender.send(connect(ConnectionAuth {
+            token_kind: AuthTokenKind::Static,
+            token: "secret".to_string(),
+            ..ConnectionAuth::default()
+        }));
+        assert!(matches!(
+            next_event(&mut events).await,
+            ConnectionEvent::Connected { .. }
+        ));
+    }
 }
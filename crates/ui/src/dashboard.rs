@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{PlaceUi, ResourceUi};
+use crate::palette::PlaceCardState;
+use crate::resource_classes::{self, ClassSummary};
+use labgrid_ui_core::types::{Place, Resource};
+use std::collections::HashMap;
+
+/// Aggregate statistics of the places and resources, displayed in the dashboard tab.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DashboardStats {
+    pub(crate) places: usize,
+    /// Places that are acquired, including the ones in maintenance.
+    pub(crate) acquired: usize,
+    /// Places that are neither acquired, reserved nor in maintenance.
+    pub(crate) free: usize,
+    /// The number of places per state, in the order of [`PlaceCardState::ALL`].
+    pub(crate) place_states: Vec<(PlaceCardState, usize)>,
+    /// The number of acquired places per `host/user` owner, sorted by the number and then by the owner.
+    pub(crate) acquisitions: Vec<(String, usize)>,
+    pub(crate) resources: usize,
+    /// Resources that are available and not removed by the coordinator.
+    pub(crate) available: usize,
+    pub(crate) classes: Vec<ClassSummary>,
+}
+
+impl DashboardStats {
+    /// Computes the statistics of the places and resources for the [crate::app::AppConnected::identity].
+    pub(crate) fn compute<'a>(
+        places: impl IntoIterator<Item = &'a (Place, PlaceUi)>,
+        resources: &'a [(Resource, ResourceUi)],
+        identity: &str,
+    ) -> Self {
+        let mut states: HashMap<PlaceCardState, usize> = HashMap::new();
+        let mut acquisitions: HashMap<&str, usize> = HashMap::new();
+        let mut place_count = 0;
+        for (place, _) in places {
+            place_count += 1;
+            *states
+                .entry(PlaceCardState::of(place, identity))
+                .or_default() += 1;
+            if let Some(owner) = &place.acquired {
+                *acquisitions.entry(owner.as_str()).or_default() += 1;
+            }
+        }
+        let mut acquisitions = acquisitions
+            .into_iter()
+            .map(|(owner, count)| (owner.to_string(), count))
+            .collect::<Vec<_>>();
+        acquisitions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Self {
+            places: place_count,
+            acquired: acquisitions.iter().map(|(_, count)| count).sum(),
+            free: states
+                .get(&PlaceCardState::Free)
+                .copied()
+                .unwrap_or_default(),
+            place_states: PlaceCardState::ALL
+                .into_iter()
+                .map(|state| (state, states.get(&state).copied().unwrap_or_default()))
+                .collect(),
+            acquisitions,
+            resources: resources.len(),
+            available: resources
+                .iter()
+                .filter(|(resource, ui)| resource.available && ui.stale_since.is_none())
+                .count(),
+            classes: resource_classes::summarize_classes(resources),
+        }
+    }
+
+    /// The share of `count` of all places, from `0.0` to `1.0`.
+    pub(crate) fn place_share(&self, count: usize) -> f32 {
+        share(count, self.places)
+    }
+
+    /// The share of available resources, from `0.0` to `1.0`.
+    pub(crate) fn availability(&self) -> f32 {
+        share(self.available, self.resources)
+    }
+}
+
+fn share(count: usize, total: usize) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f32 / total as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::palette::MAINTENANCE_TAG;
+    use crate::test_fixtures;
+    use std::time::Instant;
+
+    fn place(name: &str, acquired: Option<&str>, reservation: Option<&str>) -> (Place, PlaceUi) {
+        let place = Place {
+            acquired: acquired.map(str::to_string),
+            reservation: reservation.map(str::to_string),
+            ..test_fixtures::place(name)
+        };
+        (place, PlaceUi::default())
+    }
+
+    fn resource(cls: &str, available: bool) -> (Resource, ResourceUi) {
+        let resource = Resource {
+            available,
+            ..test_fixtures::resource(cls, cls)
+        };
+        (resource, ResourceUi::default())
+    }
+
+    #[test]
+    fn dashboard_stats() {
+        let mut places = vec![
+            place("board-1", None, None),
+            place("board-2", Some("host/me"), None),
+            place("board-3", Some("host/other"), None),
+            place("board-4", Some("host/other"), None),
+            place("board-5", None, Some("ABCDEF")),
+            place("board-6", Some("host/me"), None),
+        ];
+        places[5]
+            .0
+            .tags
+            .insert(MAINTENANCE_TAG.to_string(), "true".to_string());
+        let mut resources = vec![
+            resource("NetworkSerialPort", true),
+            resource("NetworkSerialPort", false),
+            resource("USBSerialPort", true),
+        ];
+        resources[2].1.stale_since = Some(Instant::now());
+
+        let stats = DashboardStats::compute(&places, &resources, "host/me");
+        assert_eq!(stats.places, 6);
+        assert_eq!(stats.acquired, 4);
+        assert_eq!(stats.free, 1);
+        assert_eq!(
+            stats.place_states,
+            vec![
+                (PlaceCardState::Free, 1),
+                (PlaceCardState::Mine, 1),
+                (PlaceCardState::Others, 2),
+                (PlaceCardState::Reserved, 1),
+                (PlaceCardState::Maintenance, 1),
+            ]
+        );
+        assert_eq!(
+            stats.acquisitions,
+            vec![("host/me".to_string(), 2), ("host/other".to_string(), 2)]
+        );
+        assert_eq!(stats.place_share(3), 0.5);
+        assert_eq!(stats.resources, 3);
+        assert_eq!(stats.available, 1);
+        assert_eq!(stats.classes.len(), 2);
+
+        let empty = DashboardStats::compute(&[], &[], "host/me");
+        assert_eq!(empty.place_share(0), 0.0);
+        assert_eq!(empty.availability(), 0.0);
+    }
+}
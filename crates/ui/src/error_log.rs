@@ -0,0 +1,210 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{ErrorCriticality, ErrorReport};
+use crate::i18n::fl;
+use chrono::{DateTime, Local};
+
+/// The maximum number of errors kept in the log, the oldest ones are dropped first.
+const MAX_ENTRIES: usize = 500;
+
+/// A reported error with the time it was reported.
+#[derive(Debug, Clone)]
+pub(crate) struct ErrorLogEntry {
+    pub(crate) time: DateTime<Local>,
+    pub(crate) report: ErrorReport,
+    /// Dismissed errors are only listed in the error console.
+    pub(crate) dismissed: bool,
+}
+
+/// Filters the errors listed in the error console by their criticality.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ErrorFilter {
+    #[default]
+    All,
+    Critical,
+    NonCritical,
+}
+
+impl ErrorFilter {
+    pub(crate) const ALL: [Self; 3] = [Self::All, Self::Critical, Self::NonCritical];
+
+    fn matches(&self, criticality: &ErrorCriticality) -> bool {
+        match self {
+            Self::All => true,
+            Self::Critical => *criticality == ErrorCriticality::Critical,
+            Self::NonCritical => *criticality == ErrorCriticality::NonCritical,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::All => write!(f, "{}", fl!("error-console-filter-all")),
+            Self::Critical => write!(f, "{}", fl!("error-critical")),
+            Self::NonCritical => write!(f, "{}", fl!("error-noncritical")),
+        }
+    }
+}
+
+/// All errors reported during the session.
+///
+/// The errors that are not dismissed yet are displayed as stack, the latest on top.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ErrorLog {
+    entries: Vec<ErrorLogEntry>,
+    /// The number of errors reported since the error console was last shown.
+    unseen: usize,
+    pub(crate) filter: ErrorFilter,
+}
+
+impl ErrorLog {
+    pub(crate) fn push(&mut self, report: ErrorReport) {
+        self.push_at(report, Local::now());
+    }
+
+    fn push_at(&mut self, report: ErrorReport, time: DateTime<Local>) {
+        self.entries.push(ErrorLogEntry {
+            time,
+            report,
+            dismissed: false,
+        });
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.unseen = (self.unseen + 1).min(MAX_ENTRIES);
+    }
+
+    /// Dismisses the latest error of the stack, it is kept in the log.
+    pub(crate) fn pop(&mut self) -> Option<ErrorReport> {
+        let entry = self.entries.iter_mut().rev().find(|e| !e.dismissed)?;
+        entry.dismissed = true;
+        Some(entry.report.clone())
+    }
+
+    /// The errors that are not dismissed, the latest last.
+    pub(crate) fn stack(&self) -> Vec<&ErrorReport> {
+        self.entries
+            .iter()
+            .filter(|e| !e.dismissed)
+            .map(|e| &e.report)
+            .collect()
+    }
+
+    /// The number of logged errors, including the dismissed ones.
+    pub(crate) fn logged(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The logged errors matching the filter, the latest first.
+    pub(crate) fn filtered(&self) -> impl Iterator<Item = &ErrorLogEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|e| self.filter.matches(&e.report.criticality))
+    }
+
+    pub(crate) fn unseen(&self) -> usize {
+        self.unseen
+    }
+
+    pub(crate) fn mark_seen(&mut self) {
+        self.unseen = 0;
+    }
+
+    /// Removes all errors, including the ones on the stack.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.unseen = 0;
+    }
+
+    /// The logged errors matching the filter as text, the oldest first.
+    pub(crate) fn to_text(&self) -> String {
+        let entries = self.filtered().collect::<Vec<_>>();
+        entries
+            .into_iter()
+            .rev()
+            .map(|e| {
+                let criticality = match e.report.criticality {
+                    ErrorCriticality::NonCritical => "non-critical",
+                    ErrorCriticality::Critical => "critical",
+                };
+                let mut line = format!(
+                    "{} [{criticality}] {}",
+                    e.time.format("%Y-%m-%d %H:%M:%S"),
+                    e.report.short
+                );
+                if !e.report.detailed.is_empty() {
+                    line.push('\n');
+                    line.push_str(&e.report.detailed);
+                }
+                line + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn report(criticality: ErrorCriticality, short: &str) -> ErrorReport {
+        ErrorReport {
+            criticality,
+            short: short.to_string(),
+            detailed: String::new(),
+            retry: None,
+        }
+    }
+
+    #[test]
+    fn error_log() {
+        let mut log = ErrorLog::default();
+        let time = Local.with_ymd_and_hms(2025, 3, 14, 9, 30, 0).unwrap();
+        log.push_at(
+            report(ErrorCriticality::Critical, "Connecting failed"),
+            time,
+        );
+        log.push_at(
+            ErrorReport {
+                detailed: "Permission denied".to_string(),
+                ..report(ErrorCriticality::NonCritical, "Saving failed")
+            },
+            time,
+        );
+        assert_eq!(log.stack().len(), 2);
+        assert_eq!(log.unseen(), 2);
+
+        // Dismissing keeps the error in the log
+        assert_eq!(log.pop().unwrap().short, "Saving failed");
+        assert_eq!(log.stack().len(), 1);
+        assert_eq!(log.stack()[0].short, "Connecting failed");
+        assert_eq!(log.filtered().count(), 2);
+        assert_eq!(
+            log.to_text(),
+            "2025-03-14 09:30:00 [critical] Connecting failed\n\
+             2025-03-14 09:30:00 [non-critical] Saving failed\nPermission denied\n"
+        );
+
+        log.filter = ErrorFilter::NonCritical;
+        assert_eq!(
+            log.filtered()
+                .map(|e| e.report.short.as_str())
+                .collect::<Vec<_>>(),
+            ["Saving failed"]
+        );
+        log.mark_seen();
+        assert_eq!(log.unseen(), 0);
+        log.clear();
+        assert!(log.stack().is_empty());
+        assert_eq!(log.pop().map(|e| e.short), None);
+
+        for _ in 0..MAX_ENTRIES + 10 {
+            log.push(report(ErrorCriticality::Critical, "Timeout"));
+        }
+        assert_eq!(log.logged(), MAX_ENTRIES);
+    }
+}
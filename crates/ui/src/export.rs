@@ -0,0 +1,315 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::types::{MapValue, Place, Reservation, Resource};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// The tabs whose displayed entries can be exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExportKind {
+    Places,
+    Resources,
+    Reservations,
+}
+
+impl ExportKind {
+    /// The suggested file name, without extension.
+    pub(crate) fn file_stem(&self) -> &'static str {
+        match self {
+            Self::Places => "places",
+            Self::Resources => "resources",
+            Self::Reservations => "reservations",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// Determines the format from the extension of the path picked in the save-file dialog, CSV by default.
+    pub(crate) fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Csv,
+        }
+    }
+}
+
+/// Entries prepared for exporting, with one value per column.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Table {
+    columns: &'static [&'static str],
+    rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    pub(crate) fn places<'a>(places: impl IntoIterator<Item = &'a Place>) -> Self {
+        Self {
+            columns: &[
+                "name",
+                "aliases",
+                "comment",
+                "tags",
+                "matches",
+                "acquired",
+                "acquired_resources",
+                "allowed",
+                "created",
+                "changed",
+                "reservation",
+            ],
+            rows: places
+                .into_iter()
+                .map(|p| {
+                    vec![
+                        json!(p.name),
+                        json!(p.aliases),
+                        json!(p.comment),
+                        json!(sorted(&p.tags)),
+                        json!(p
+                            .matches
+                            .iter()
+                            .map(|m| m.pattern().to_string())
+                            .collect::<Vec<String>>()),
+                        json!(p.acquired),
+                        json!(p.acquired_resources),
+                        json!(p.allowed),
+                        json!(p.created),
+                        json!(p.changed),
+                        json!(p.reservation),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn resources<'a>(resources: impl IntoIterator<Item = &'a Resource>) -> Self {
+        Self {
+            columns: &[
+                "exporter",
+                "group",
+                "name",
+                "cls",
+                "available",
+                "acquired",
+                "params",
+                "extra",
+            ],
+            rows: resources
+                .into_iter()
+                .map(|r| {
+                    vec![
+                        json!(r.path.exporter_name),
+                        json!(r.path.group_name),
+                        json!(r.path.resource_name),
+                        json!(r.cls),
+                        json!(r.available),
+                        json!(r.acquired),
+                        map_values_json(&r.params),
+                        map_values_json(&r.extra),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn reservations<'a>(
+        reservations: impl IntoIterator<Item = &'a Reservation>,
+    ) -> Self {
+        Self {
+            columns: &[
+                "owner",
+                "token",
+                "state",
+                "prio",
+                "filters",
+                "allocations",
+                "created",
+                "timeout",
+            ],
+            rows: reservations
+                .into_iter()
+                .map(|r| {
+                    vec![
+                        json!(r.owner),
+                        json!(r.token),
+                        match r.reservation_state() {
+                            Some(state) => json!(format!("{state:?}").to_lowercase()),
+                            None => json!(r.state),
+                        },
+                        json!(r.prio),
+                        json!(r
+                            .filters
+                            .iter()
+                            .map(|(name, filter)| (name, sorted(&filter.0)))
+                            .collect::<BTreeMap<_, _>>()),
+                        json!(sorted(&r.allocations)),
+                        json!(r.created),
+                        json!(r.timeout),
+                    ]
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn serialize(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Csv => self.to_csv(),
+            ExportFormat::Json => self.to_json(),
+        }
+    }
+
+    /// One line per entry with a header line.
+    ///
+    /// Nested values like lists and maps are written as JSON into their cell.
+    fn to_csv(&self) -> String {
+        std::iter::once(
+            self.columns
+                .iter()
+                .map(|c| csv_field(c))
+                .collect::<Vec<_>>(),
+        )
+        .chain(self.rows.iter().map(|row| {
+            row.iter()
+                .map(|value| match value {
+                    Value::Null => String::new(),
+                    Value::String(s) => csv_field(s),
+                    value => csv_field(&value.to_string()),
+                })
+                .collect()
+        }))
+        .map(|fields| fields.join(",") + "\n")
+        .collect()
+    }
+
+    /// One object per entry, keyed by the column names.
+    pub(crate) fn json_entries(&self) -> Vec<Value> {
+        self.rows
+            .iter()
+            .map(|row| {
+                Value::Object(
+                    self.columns
+                        .iter()
+                        .map(|c| c.to_string())
+                        .zip(row.iter().cloned())
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// An array with one object per entry.
+    fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.json_entries()).unwrap_or_default()
+    }
+}
+
+/// Quotes the field if needed, as described in RFC 4180.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Sorts the map for deterministic output.
+fn sorted(map: &HashMap<String, String>) -> BTreeMap<&String, &String> {
+    map.iter().collect()
+}
+
+fn map_values_json(values: &HashMap<String, MapValue>) -> Value {
+    fn map_value_json(value: &MapValue) -> Value {
+        match value {
+            MapValue::Bool(v) => json!(v),
+            MapValue::Int(v) => json!(v),
+            MapValue::UInt(v) => json!(v),
+            MapValue::Float(v) => json!(v),
+            MapValue::String(v) => json!(v),
+            MapValue::Array(values) => Value::Array(values.iter().map(map_value_json).collect()),
+        }
+    }
+
+    json!(values
+        .iter()
+        .map(|(k, v)| (k, map_value_json(v)))
+        .collect::<BTreeMap<_, _>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use labgrid_ui_core::types::ResourceMatch;
+
+    fn place() -> Place {
+        Place {
+            comment: "Rack 3, \"top\"".to_string(),
+            tags: HashMap::from([("board".to_string(), "imx8".to_string())]),
+            matches: vec![ResourceMatch {
+                exporter: "exporter-1".to_string(),
+                group: "board-1".to_string(),
+                cls: "NetworkPowerPort".to_string(),
+                name: None,
+                rename: None,
+            }],
+            acquired: Some("host/alice".to_string()),
+            created: 1.5,
+            changed: 2.,
+            ..test_fixtures::place("board-1")
+        }
+    }
+
+    #[test]
+    fn export_places() {
+        let table = Table::places([&place()]);
+        insta::assert_snapshot!(table.serialize(ExportFormat::Csv), @r#"
+            name,aliases,comment,tags,matches,acquired,acquired_resources,allowed,created,changed,reservation
+            board-1,[],"Rack 3, ""top""","{""board"":""imx8""}","[""exporter-1/board-1/NetworkPowerPort""]",host/alice,[],[],1.5,2.0,
+            "#);
+        insta::assert_snapshot!(table.serialize(ExportFormat::Json), @r#"
+            [
+              {
+                "acquired": "host/alice",
+                "acquired_resources": [],
+                "aliases": [],
+                "allowed": [],
+                "changed": 2.0,
+                "comment": "Rack 3, \"top\"",
+                "created": 1.5,
+                "matches": [
+                  "exporter-1/board-1/NetworkPowerPort"
+                ],
+                "name": "board-1",
+                "reservation": null,
+                "tags": {
+                  "board": "imx8"
+                }
+              }
+            ]
+            "#);
+    }
+
+    #[test]
+    fn export_format_from_path() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("places.JSON")),
+            ExportFormat::Json
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("places.csv")),
+            ExportFormat::Csv
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("places")),
+            ExportFormat::Csv
+        );
+    }
+}
@@ -0,0 +1,267 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::ResourceUi;
+use crate::i18n::fl;
+use crate::usb_topology::UsbLocation;
+use chrono::{DateTime, Local};
+use labgrid_ui_core::types::{Path, Resource};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+/// The times resources of exporters were last seen in the client stream, keyed by the exporter name.
+///
+/// Resources without an exporter name are recorded with an empty name.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ExporterLastSeen {
+    seen: HashMap<String, DateTime<Local>>,
+    /// When the exporters went offline, see [ExporterLastSeen::record_deleted].
+    offline: HashMap<String, DateTime<Local>>,
+}
+
+impl ExporterLastSeen {
+    /// Records that a resource at `path` was seen at `time`, its exporter is online again.
+    pub(crate) fn record(&mut self, path: &Path, time: DateTime<Local>) {
+        let exporter = exporter_name(path);
+        self.seen.insert(exporter.to_string(), time);
+        self.offline.remove(exporter);
+    }
+
+    /// Records that the coordinator deleted the resource at `path` at `time`.
+    ///
+    /// The coordinator deletes all resources of an exporter when it disconnects,
+    /// so the exporter is considered offline once none of the remaining `resources` of it are left, apart from stale ones.
+    pub(crate) fn record_deleted(
+        &mut self,
+        path: &Path,
+        resources: &[(Resource, ResourceUi)],
+        time: DateTime<Local>,
+    ) {
+        let exporter = exporter_name(path);
+        let is_live = resources.iter().any(|(resource, ui)| {
+            exporter_name(&resource.path) == exporter && ui.stale_since.is_none()
+        });
+        if !is_live {
+            self.offline.entry(exporter.to_string()).or_insert(time);
+        }
+    }
+
+    pub(crate) fn get(&self, exporter: &str) -> Option<DateTime<Local>> {
+        self.seen.get(exporter).copied()
+    }
+
+    /// When the exporter went offline, `None` if it is online or unknown.
+    pub(crate) fn offline_since(&self, exporter: &str) -> Option<DateTime<Local>> {
+        self.offline.get(exporter).copied()
+    }
+}
+
+/// How the resources of offline exporters are displayed in the resources tab.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum OfflineExporterResources {
+    #[default]
+    Show,
+    Gray,
+    /// Only the section of the exporter is shown.
+    Hide,
+}
+
+impl OfflineExporterResources {
+    pub(crate) const ALL: [Self; 3] = [Self::Show, Self::Gray, Self::Hide];
+}
+
+impl Display for OfflineExporterResources {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Show => write!(f, "{}", fl!("settings-offline-exporter-resources-show")),
+            Self::Gray => write!(f, "{}", fl!("settings-offline-exporter-resources-gray")),
+            Self::Hide => write!(f, "{}", fl!("settings-offline-exporter-resources-hide")),
+        }
+    }
+}
+
+/// Per-exporter statistics of the known resources.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ExporterSummary {
+    /// The exporter name, empty for resources without an exporter name.
+    pub(crate) name: String,
+    pub(crate) resources: usize,
+    pub(crate) available: usize,
+    pub(crate) acquired: usize,
+    /// Resources that were deleted by the coordinator, but are still kept during the grace period.
+    pub(crate) stale: usize,
+    /// Resources of USB devices with a known location, shown in the USB topology.
+    pub(crate) usb: usize,
+    pub(crate) last_seen: Option<DateTime<Local>>,
+    /// When the exporter went offline, `None` if it is online.
+    pub(crate) offline_since: Option<DateTime<Local>>,
+}
+
+impl ExporterSummary {
+    /// The share of available resources, from `0.0` to `1.0`.
+    pub(crate) fn availability(&self) -> f32 {
+        if self.resources == 0 {
+            0.0
+        } else {
+            self.available as f32 / self.resources as f32
+        }
+    }
+}
+
+/// The exporter name of the resource path, empty when the exporter is unknown.
+pub(crate) fn exporter_name(path: &Path) -> &str {
+    path.exporter_name.as_deref().unwrap_or_default()
+}
+
+/// Summarizes the resources per exporter, sorted by the exporter name.
+///
+/// Offline exporters whose resources were already removed are included without resources.
+pub(crate) fn summarize<'a>(
+    resources: impl IntoIterator<Item = &'a (Resource, ResourceUi)>,
+    last_seen: &'a ExporterLastSeen,
+) -> Vec<ExporterSummary> {
+    let summary = |name: &str| ExporterSummary {
+        name: name.to_string(),
+        resources: 0,
+        available: 0,
+        acquired: 0,
+        stale: 0,
+        usb: 0,
+        last_seen: last_seen.get(name),
+        offline_since: last_seen.offline_since(name),
+    };
+    let mut summaries: BTreeMap<&str, ExporterSummary> = last_seen
+        .offline
+        .keys()
+        .map(|name| (name.as_str(), summary(name)))
+        .collect();
+    for (resource, ui) in resources {
+        let name = exporter_name(&resource.path);
+        let summary = summaries.entry(name).or_insert_with(|| summary(name));
+        summary.resources += 1;
+        if resource.available {
+            summary.available += 1;
+        }
+        if !resource.acquired.is_empty() {
+            summary.acquired += 1;
+        }
+        if ui.stale_since.is_some() {
+            summary.stale += 1;
+        }
+        if UsbLocation::of(resource).is_some() {
+            summary.usb += 1;
+        }
+    }
+    summaries.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use chrono::TimeZone;
+    use std::time::Instant;
+
+    fn resource(exporter: Option<&str>, name: &str, available: bool, acquired: &str) -> Resource {
+        let mut resource = Resource {
+            acquired: acquired.to_string(),
+            available,
+            ..test_fixtures::resource(name, "NetworkSerialPort")
+        };
+        resource.path.exporter_name = exporter.map(str::to_string);
+        resource
+    }
+
+    #[test]
+    fn exporter_summaries() {
+        let seen = Local.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let resources = vec![
+            (
+                resource(Some("exporter-2"), "serial", true, ""),
+                ResourceUi::default(),
+            ),
+            (
+                resource(Some("exporter-1"), "serial", true, "board-1"),
+                ResourceUi::default(),
+            ),
+            (
+                resource(Some("exporter-1"), "power", false, ""),
+                ResourceUi {
+                    stale_since: Some(Instant::now()),
+                    ..ResourceUi::default()
+                },
+            ),
+            (resource(None, "usb", true, ""), ResourceUi::default()),
+        ];
+        let mut last_seen = ExporterLastSeen::default();
+        last_seen.record(&resources[1].0.path, seen);
+
+        let summaries = summarize(&resources, &last_seen);
+        assert_eq!(
+            summaries
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["", "exporter-1", "exporter-2"]
+        );
+        assert_eq!(
+            summaries[1],
+            ExporterSummary {
+                name: "exporter-1".to_string(),
+                resources: 2,
+                available: 1,
+                acquired: 1,
+                stale: 1,
+                usb: 0,
+                last_seen: Some(seen),
+                offline_since: None,
+            }
+        );
+        assert_eq!(summaries[1].availability(), 0.5);
+        assert_eq!(summaries[2].last_seen, None);
+    }
+
+    #[test]
+    fn exporter_offline() {
+        let seen = Local.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        let deleted = Local.with_ymd_and_hms(2025, 3, 1, 12, 5, 0).unwrap();
+        let stale = || ResourceUi {
+            stale_since: Some(Instant::now()),
+            ..ResourceUi::default()
+        };
+        let mut resources = vec![
+            (
+                resource(Some("exporter-1"), "serial", true, ""),
+                ResourceUi::default(),
+            ),
+            (
+                resource(Some("exporter-1"), "power", true, ""),
+                ResourceUi::default(),
+            ),
+        ];
+        let mut last_seen = ExporterLastSeen::default();
+        last_seen.record(&resources[0].0.path, seen);
+
+        resources[0].1 = stale();
+        last_seen.record_deleted(&resources[0].0.path, &resources, deleted);
+        assert_eq!(last_seen.offline_since("exporter-1"), None);
+        resources[1].1 = stale();
+        last_seen.record_deleted(&resources[1].0.path, &resources, deleted);
+        assert_eq!(last_seen.offline_since("exporter-1"), Some(deleted));
+        assert_eq!(
+            summarize(&resources, &last_seen)[0].offline_since,
+            Some(deleted)
+        );
+        // Still summarized once the stale resources are removed
+        let summaries = summarize(&[], &last_seen);
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].resources, 0);
+        assert_eq!(summaries[0].last_seen, Some(seen));
+
+        last_seen.record(&resources[0].0.path, deleted);
+        assert_eq!(last_seen.offline_since("exporter-1"), None);
+        assert!(summarize(&[], &last_seen).is_empty());
+    }
+}
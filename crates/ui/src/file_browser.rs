@@ -0,0 +1,208 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, ConnectedMsg, VenvWizardMsg};
+use crate::scripts::EnvEntry;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Native dialogs that return without a path faster than this are assumed to have failed to open,
+/// since nobody is able to cancel them that quickly.
+pub(crate) const NATIVE_DIALOG_FAILURE_THRESHOLD: Duration = Duration::from_millis(300);
+
+/// What is picked through the file browser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileBrowserTarget {
+    VenvDir,
+    /// The directory of the virtual environment created by the wizard.
+    CreatedVenvDir,
+    ScriptsDir,
+    LgEnvFile,
+}
+
+impl FileBrowserTarget {
+    /// Whether a directory is picked, otherwise a file.
+    pub(crate) fn picks_dir(&self) -> bool {
+        match self {
+            Self::VenvDir | Self::CreatedVenvDir | Self::ScriptsDir => true,
+            Self::LgEnvFile => false,
+        }
+    }
+
+    /// The extensions of the files that are listed, all are listed when empty.
+    pub(crate) fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::VenvDir | Self::CreatedVenvDir => &[],
+            Self::ScriptsDir => &["py"],
+            Self::LgEnvFile => &["yml", "yaml"],
+        }
+    }
+
+    /// The message applying the picked path.
+    pub(crate) fn picked_msg(&self, path: PathBuf) -> AppMsg {
+        match self {
+            Self::VenvDir => AppMsg::ChangeVenvDir { dir: path },
+            Self::CreatedVenvDir => {
+                AppMsg::VenvWizard(VenvWizardMsg::UpdateDir(path.display().to_string()))
+            }
+            Self::ScriptsDir => AppMsg::ChangeScriptsDir { dir: path },
+            Self::LgEnvFile => AppMsg::Connected(ConnectedMsg::ScriptsEnvUpdate {
+                entry: EnvEntry::LgEnv,
+                value: path.to_string_lossy().to_string(),
+            }),
+        }
+    }
+}
+
+/// An entry of the listed directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FileBrowserEntry {
+    pub(crate) name: String,
+    pub(crate) is_dir: bool,
+}
+
+/// The state of the built-in file browser.
+#[derive(Debug, Clone)]
+pub(crate) struct FileBrowser {
+    pub(crate) target: FileBrowserTarget,
+    /// The displayed directory.
+    pub(crate) dir: PathBuf,
+    /// The text of the path input, navigated to on submit.
+    pub(crate) path_input: String,
+    /// `None` while the directory is listed.
+    pub(crate) entries: Option<Vec<FileBrowserEntry>>,
+    /// Listing the directory failed with the contained error.
+    pub(crate) error: Option<String>,
+    /// The name of the selected file.
+    pub(crate) selected: Option<String>,
+}
+
+impl FileBrowser {
+    pub(crate) fn new(target: FileBrowserTarget, dir: PathBuf) -> Self {
+        Self {
+            target,
+            path_input: dir.display().to_string(),
+            dir,
+            entries: None,
+            error: None,
+            selected: None,
+        }
+    }
+
+    /// Shows the directory, its entries have to be listed with [list_dir].
+    pub(crate) fn navigate(&mut self, dir: PathBuf) {
+        *self = Self::new(self.target, dir);
+    }
+
+    /// The picked path, `None` if a file has to be picked but none is selected.
+    pub(crate) fn picked(&self) -> Option<PathBuf> {
+        if self.target.picks_dir() {
+            Some(self.dir.clone())
+        } else {
+            self.selected.as_ref().map(|name| self.dir.join(name))
+        }
+    }
+}
+
+/// Lists the subdirectories and the files with the extensions of the directory, each sorted by name.
+///
+/// Hidden entries are skipped. Falls back to the closest existing ancestor of the directory,
+/// returns the actually listed directory.
+pub(crate) async fn list_dir(
+    dir: PathBuf,
+    extensions: &[&str],
+) -> (PathBuf, Result<Vec<FileBrowserEntry>, String>) {
+    let mut listed = dir.as_path();
+    while !tokio::fs::try_exists(listed).await.unwrap_or(false) {
+        match listed.parent() {
+            Some(parent) => listed = parent,
+            None => break,
+        }
+    }
+    let listed = listed.to_path_buf();
+    let entries = read_entries(&listed, extensions)
+        .await
+        .map_err(|e| e.to_string());
+    (listed, entries)
+}
+
+async fn read_entries(dir: &Path, extensions: &[&str]) -> std::io::Result<Vec<FileBrowserEntry>> {
+    let mut read_dir = tokio::fs::read_dir(dir).await?;
+    let mut entries = Vec::new();
+    while let Some(entry) = read_dir.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        // Follows symlinks
+        let is_dir = tokio::fs::metadata(entry.path())
+            .await
+            .is_ok_and(|m| m.is_dir());
+        let listed = is_dir
+            || extensions.is_empty()
+            || Path::new(&name)
+                .extension()
+                .is_some_and(|ext| extensions.iter().any(|e| ext.eq_ignore_ascii_case(e)));
+        if listed {
+            entries.push(FileBrowserEntry { name, is_dir });
+        }
+    }
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| numeric_sort::cmp(&a.name.to_lowercase(), &b.name.to_lowercase()))
+    });
+    Ok(entries)
+}
+
+/// Whether native dialogs can be expected to open.
+///
+/// On Linux they need either the desktop portal on the session bus or `zenity`.
+/// Dialogs can still fail if the portal is missing on the bus, see [NATIVE_DIALOG_FAILURE_THRESHOLD].
+pub(crate) fn native_dialog_available() -> bool {
+    if !cfg!(target_os = "linux") {
+        return true;
+    }
+    std::env::var_os("DBUS_SESSION_BUS_ADDRESS").is_some()
+        || std::env::var_os("PATH")
+            .is_some_and(|path| std::env::split_paths(&path).any(|p| p.join("zenity").exists()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn list_dir_entries() {
+        let dir =
+            std::env::temp_dir().join(format!("labgrid-ui-file-browser-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("venv")).unwrap();
+        std::fs::create_dir_all(dir.join(".hidden")).unwrap();
+        for file in ["env-10.yaml", "env-9.yml", "notes.txt"] {
+            std::fs::write(dir.join(file), "").unwrap();
+        }
+
+        let (listed, entries) = list_dir(dir.join("missing/sub"), &["yml", "yaml"]).await;
+        assert_eq!(listed, dir);
+        let entries = entries.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                FileBrowserEntry {
+                    name: "venv".to_string(),
+                    is_dir: true
+                },
+                FileBrowserEntry {
+                    name: "env-9.yml".to_string(),
+                    is_dir: false
+                },
+                FileBrowserEntry {
+                    name: "env-10.yaml".to_string(),
+                    is_dir: false
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
+use std::path::PathBuf;
+use tracing::debug;
+
+/// What is picked with a file dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FileDialogKind {
+    SaveFile,
+    PickFile,
+    PickFolder,
+}
+
+/// The options of a file dialog.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct FileDialogRequest {
+    pub(crate) directory: Option<PathBuf>,
+    /// The file name proposed when saving.
+    pub(crate) file_name: Option<String>,
+    /// Named lists of file extensions the user can choose between.
+    pub(crate) filters: Vec<(String, Vec<String>)>,
+}
+
+impl FileDialogRequest {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn directory(mut self, directory: PathBuf) -> Self {
+        self.directory = Some(directory);
+        self
+    }
+
+    pub(crate) fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    pub(crate) fn filter(mut self, name: impl Into<String>, extensions: &[&str]) -> Self {
+        self.filters.push((
+            name.into(),
+            extensions.iter().map(|e| e.to_string()).collect(),
+        ));
+        self
+    }
+}
+
+/// Opens file dialogs.
+///
+/// Abstracted so that the message handling of the app does not depend on the native dialogs.
+pub(crate) trait FileDialogs: std::fmt::Debug + Send + Sync {
+    /// Opens the dialog, resolves to the chosen path or `None` if the dialog was cancelled or failed to open.
+    fn open(
+        &self,
+        kind: FileDialogKind,
+        request: FileDialogRequest,
+    ) -> BoxFuture<'static, Option<PathBuf>>;
+}
+
+/// The native dialogs of the platform, through the XDG desktop portal on Linux.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct NativeFileDialogs;
+
+impl FileDialogs for NativeFileDialogs {
+    fn open(
+        &self,
+        kind: FileDialogKind,
+        request: FileDialogRequest,
+    ) -> BoxFuture<'static, Option<PathBuf>> {
+        debug!(?kind, ?request, "Open native file dialog");
+        let mut dialog = rfd::AsyncFileDialog::new();
+        if let Some(directory) = request.directory {
+            dialog = dialog.set_directory(directory);
+        }
+        if let Some(file_name) = request.file_name {
+            dialog = dialog.set_file_name(file_name);
+        }
+        for (name, extensions) in &request.filters {
+            dialog = dialog.add_filter(name, extensions.as_slice());
+        }
+        async move {
+            let file = match kind {
+                FileDialogKind::SaveFile => dialog.save_file().await,
+                FileDialogKind::PickFile => dialog.pick_file().await,
+                FileDialogKind::PickFolder => dialog.pick_folder().await,
+            };
+            file.map(|file| file.path().to_path_buf())
+        }
+        .boxed()
+    }
+}
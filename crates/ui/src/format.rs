@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::i18n::fl;
+use chrono::{DateTime, Local};
+use std::fmt::Write;
+use std::time::Duration;
+use tracing::warn;
+
+/// The format of the en-US translation, used when the format of a translation is invalid.
+const FALLBACK_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Formats the time as date and time of day in the format of the current language.
+pub(crate) fn date_time(time: DateTime<Local>) -> String {
+    with_format(time, &fl!("time-format"))
+}
+
+/// Formats the time with the strftime `format`, falling back to [FALLBACK_TIME_FORMAT] if it is invalid.
+///
+/// The format comes from the translations, which users can override, so it must not panic while rendering.
+fn with_format(time: DateTime<Local>, format: &str) -> String {
+    let mut formatted = String::new();
+    if write!(formatted, "{}", time.format(format)).is_ok() {
+        return formatted;
+    }
+    warn!(
+        format,
+        "Invalid time format in translation, using the fallback"
+    );
+    time.format(FALLBACK_TIME_FORMAT).to_string()
+}
+
+/// Formats how long before `now` the time was, e.g. "5 min ago".
+///
+/// Times after `now` are formatted as just now.
+pub(crate) fn time_ago(time: DateTime<Local>, now: DateTime<Local>) -> String {
+    let elapsed = now - time;
+    if elapsed.num_minutes() < 1 {
+        fl!("time-ago-just-now")
+    } else if elapsed.num_hours() < 1 {
+        fl!("time-ago-minutes", minutes = elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        fl!("time-ago-hours", hours = elapsed.num_hours())
+    } else {
+        fl!("time-ago-days", days = elapsed.num_days())
+    }
+}
+
+/// Formats the duration as `h:mm:ss`.
+pub(crate) fn duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/// Formats the number with the decimal separator of the current language.
+pub(crate) fn decimal(value: f64) -> String {
+    with_separator(value, &fl!("decimal-separator"))
+}
+
+fn with_separator(value: f64, separator: &str) -> String {
+    value.to_string().replacen('.', separator, 1)
+}
+
+/// Parses a number entered with the decimal separator of the current language.
+///
+/// A point is accepted as well, as it is what most users are used to type into technical input fields.
+pub(crate) fn parse_decimal(text: &str) -> Option<f64> {
+    parse_with_separator(text, &fl!("decimal-separator"))
+}
+
+fn parse_with_separator(text: &str, separator: &str) -> Option<f64> {
+    text.trim().replacen(separator, ".", 1).parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn time_formatting() {
+        let time = Local.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(date_time(time), "2025-03-01 12:00:00");
+        assert_eq!(with_format(time, "%d.%m.%Y"), "01.03.2025");
+        assert_eq!(with_format(time, "%Q %d"), "2025-03-01 12:00:00");
+
+        let ago = |duration: chrono::Duration| {
+            time_ago(time, time + duration).replace(['\u{2068}', '\u{2069}'], "")
+        };
+        assert_eq!(ago(chrono::Duration::seconds(-5)), "just now");
+        assert_eq!(ago(chrono::Duration::seconds(59)), "just now");
+        assert_eq!(ago(chrono::Duration::minutes(5)), "5 min ago");
+        assert_eq!(ago(chrono::Duration::minutes(90)), "1 h ago");
+        assert_eq!(ago(chrono::Duration::days(1)), "1 day ago");
+        assert_eq!(ago(chrono::Duration::days(3)), "3 days ago");
+
+        assert_eq!(duration(Duration::from_secs(3725)), "1:02:05");
+    }
+
+    #[test]
+    fn decimal_formatting() {
+        assert_eq!(decimal(0.5), "0.5");
+        assert_eq!(decimal(2.), "2");
+        assert_eq!(with_separator(-1.25, ","), "-1,25");
+
+        assert_eq!(parse_decimal(" 0.5 "), Some(0.5));
+        assert_eq!(parse_with_separator("0,5", ","), Some(0.5));
+        assert_eq!(parse_with_separator("0.5", ","), Some(0.5));
+        assert_eq!(parse_with_separator("1,000.5", ","), None);
+        assert_eq!(parse_decimal("prio"), None);
+    }
+}
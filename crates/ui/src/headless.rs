@@ -0,0 +1,460 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{self, newly_allocated_reservations};
+use crate::config::Config;
+use crate::connection::{self, ConnectionEvent, ConnectionMsg, ConnectionSender};
+use crate::mqtt::MqttBridge;
+use crate::scripts::{self, Env, EnvEntry, Script, ScriptExit};
+use crate::webhooks::Webhooks;
+use crate::{metrics, mqtt, util, Args};
+use anyhow::Context;
+use core::time::Duration;
+use iced::futures::{self, StreamExt};
+use labgrid_ui_core::metrics::Metrics;
+use labgrid_ui_core::types::{Path, Place, Reservation};
+use labgrid_ui_core::version::{self, Compatibility};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use tracing::{debug, error, info, warn};
+
+/// Time waited before reconnecting after the connection to the coordinator was lost.
+const RECONNECT_DELAY: Duration = Duration::from_secs(10);
+
+/// A script that is run by the headless mode when its trigger fires.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct HeadlessJob {
+    /// The script file, relative to the scripts directory or absolute.
+    pub(crate) script: PathBuf,
+    pub(crate) trigger: JobTrigger,
+    /// Only trigger for events of this place, passed to the script as `LG_PLACE`.
+    ///
+    /// Without it, scripts triggered by events receive the place of the event.
+    #[serde(default)]
+    pub(crate) place: Option<String>,
+    /// Passed to the script as `LG_ENV`.
+    #[serde(default)]
+    pub(crate) env: Option<String>,
+}
+
+/// When a [HeadlessJob] is run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "on", rename_all = "snake_case")]
+pub(crate) enum JobTrigger {
+    /// Every `secs` seconds while connected.
+    Interval { secs: u64 },
+    /// When a place is released.
+    PlaceReleased,
+    /// When a reservation of this client is allocated.
+    ReservationAllocated,
+}
+
+/// An observed event that may trigger jobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JobEvent<'a> {
+    Interval,
+    PlaceReleased(&'a str),
+    /// Contains the allocated place, if known.
+    ReservationAllocated(Option<&'a str>),
+}
+
+impl HeadlessJob {
+    /// The environment the script is run with when `event` triggers the job, `None` if it doesn't.
+    pub(crate) fn triggered_env(&self, event: JobEvent) -> Option<Env> {
+        let event_place = match (self.trigger, event) {
+            (JobTrigger::Interval { .. }, JobEvent::Interval) => None,
+            (JobTrigger::PlaceReleased, JobEvent::PlaceReleased(place)) => Some(place),
+            (JobTrigger::ReservationAllocated, JobEvent::ReservationAllocated(place)) => place,
+            _ => return None,
+        };
+        if let (Some(place), Some(event_place)) = (&self.place, event_place) {
+            if place != event_place {
+                return None;
+            }
+        }
+        let mut env = Env::default();
+        if let Some(place) = self.place.as_deref().or(event_place) {
+            env.insert(EnvEntry::LgPlace, place.to_string());
+        }
+        if let Some(lg_env) = &self.env {
+            env.insert(EnvEntry::LgEnv, lg_env.clone());
+        }
+        Some(env)
+    }
+}
+
+/// Runs the headless mode until interrupted with Ctrl-C.
+///
+/// Keeps the connection to the coordinator and runs the jobs of the configuration, without bringing up the UI.
+pub(crate) fn run(args: Args) -> anyhow::Result<()> {
+    tokio::runtime::Runtime::new()
+        .context("Create async runtime")?
+        .block_on(run_async(args))
+}
+
+/// Loads the configuration of the app with the overrides of the arguments,
+/// without the UI there is nothing to fall back to when it is invalid.
+pub(crate) fn load_config(args: &Args) -> anyhow::Result<Config> {
+    let mut config = Config::load_from_path(util::config_path())
+        .context("Load configuration")?
+        .unwrap_or_default();
+    args.config_overrides().apply(&mut config);
+    Ok(config)
+}
+
+/// The message connecting to the coordinator of the arguments or the selected profile of the configuration.
+pub(crate) fn connect_msg(args: &Args, config: &Config) -> anyhow::Result<ConnectionMsg> {
+    let profile = config.selected_profile();
+    let address = args
+        .coordinator
+        .clone()
+        .or_else(|| profile.map(|p| p.address.clone()))
+        .unwrap_or_else(|| config.coordinator_address.clone());
+    if address.trim().is_empty() {
+        anyhow::bail!("Coordinator must be supplied through '--coordinator', 'LG_COORDINATOR' or the configuration");
+    }
+    let address = match profile {
+        Some(profile) => profile.uri(&address),
+        None => address,
+    };
+    Ok(app::connect_msg(
+        address,
+        &config.identity_override,
+        profile,
+        config.connection_timeouts,
+        config.retry_transient_errors,
+        &config.connection_auth,
+    ))
+}
+
+async fn run_async(args: Args) -> anyhow::Result<()> {
+    let config = load_config(&args)?;
+    let profile = config.selected_profile();
+    let connect = connect_msg(&args, &config)?;
+    let scripts = config
+        .headless_jobs
+        .iter()
+        .map(|job| {
+            Script::from_path(config.scripts_dir.join(&job.script))
+                .with_context(|| format!("Script '{}' of job", job.script.display()))
+        })
+        .collect::<anyhow::Result<Vec<Script>>>()?;
+    if scripts.is_empty() {
+        warn!("No headless jobs configured");
+    }
+
+    let exports = metrics::exports(args.metrics_listen, args.metrics_file.clone());
+    let headless_metrics = (!exports.is_empty()).then(Metrics::new);
+    if let Some(headless_metrics) = &headless_metrics {
+        for export in exports {
+            let headless_metrics = Arc::clone(headless_metrics);
+            tokio::spawn(async move {
+                if let Err(error) = export.run(headless_metrics).await {
+                    error!(?error, "Exporting metrics");
+                }
+            });
+        }
+    }
+
+    let identity = util::get_lg_identity(&match profile {
+        Some(profile) => profile.identity_override(&config.identity_override),
+        None => config.identity_override.clone(),
+    });
+    let mqtt = config.mqtt.enabled.then(|| {
+        let (bridge, connection) = MqttBridge::new(&config.mqtt, &identity);
+        tokio::spawn(mqtt::log_status(connection.status()));
+        tokio::spawn(connection.run());
+        bridge
+    });
+    let webhooks =
+        Webhooks::new(args.webhook_urls.clone(), identity).map(|(webhooks, delivery)| {
+            tokio::spawn(delivery.run());
+            webhooks
+        });
+
+    let mut headless = Headless {
+        jobs: config.headless_jobs.iter().cloned().zip(scripts).collect(),
+        running: HashMap::new(),
+        venv_dir: config.venv_dir.clone(),
+        default_script_timeout: scripts::default_timeout(config.script_timeout_mins),
+        identity: String::new(),
+        connected: false,
+        places: HashMap::new(),
+        resources: BTreeSet::new(),
+        reservations: Vec::new(),
+        metrics: headless_metrics,
+        webhooks,
+        mqtt,
+    };
+    let mut ticks = futures::stream::select_all(headless.jobs.iter().enumerate().filter_map(
+        |(index, (job, _))| match job.trigger {
+            JobTrigger::Interval { secs } => {
+                let period = Duration::from_secs(secs.max(1));
+                let interval = time::interval_at(time::Instant::now() + period, period);
+                Some(IntervalStream::new(interval).map(move |_| index))
+            }
+            _ => None,
+        },
+    ));
+    let mut events = Box::pin(connection::kickoff());
+    let mut sender: Option<ConnectionSender> = None;
+
+    loop {
+        tokio::select! {
+            event = events.next() => {
+                let Some(event) = event else {
+                    anyhow::bail!("Connection ended");
+                };
+                if let Some(webhooks) = &mut headless.webhooks {
+                    webhooks.record_event(&event);
+                }
+                if let Some(mqtt) = &mut headless.mqtt {
+                    mqtt.record_event(&event);
+                }
+                match event {
+                    ConnectionEvent::ReceiveReady(mut new_sender) => {
+                        new_sender.send(connect.clone());
+                        sender = Some(new_sender);
+                    }
+                    ConnectionEvent::Disconnected { error } => {
+                        headless.disconnected(error.is_some());
+                        let short = error.as_ref().map(|e| e.short.as_str());
+                        warn!(error = short, "Disconnected, reconnecting in {}s", RECONNECT_DELAY.as_secs());
+                        debug!(?error, "Disconnect error");
+                        tokio::select! {
+                            _ = time::sleep(RECONNECT_DELAY) => {}
+                            _ = tokio::signal::ctrl_c() => return Ok(()),
+                        }
+                        if let Some(sender) = &mut sender {
+                            sender.send(connect.clone());
+                        }
+                    }
+                    event => {
+                        if let Some(msg) = headless.handle_event(event) {
+                            if let Some(sender) = &mut sender {
+                                sender.send(msg);
+                            }
+                        }
+                    }
+                }
+            }
+            Some(index) = ticks.next(), if headless.connected => {
+                headless.trigger(Some(index), JobEvent::Interval);
+            }
+            _ = tokio::signal::ctrl_c() => {
+                info!("Interrupted, exiting");
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// The state of the headless mode.
+struct Headless {
+    jobs: Vec<(HeadlessJob, Script)>,
+    /// The tasks of the running jobs by their index.
+    running: HashMap<usize, JoinHandle<()>>,
+    venv_dir: PathBuf,
+    /// The timeout of scripts that don't declare one.
+    default_script_timeout: Option<Duration>,
+    identity: String,
+    connected: bool,
+    places: HashMap<String, Place>,
+    resources: BTreeSet<Path>,
+    reservations: Vec<Reservation>,
+    metrics: Option<Arc<Metrics>>,
+    webhooks: Option<Webhooks>,
+    mqtt: Option<MqttBridge>,
+}
+
+impl Headless {
+    /// Handles an event of the connection, returns a message that should be sent to it.
+    fn handle_event(&mut self, event: ConnectionEvent) -> Option<ConnectionMsg> {
+        match event {
+            ConnectionEvent::Connected { address, identity } => {
+                info!(address, identity, "Connected");
+                self.identity = identity;
+                self.connected = true;
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_connected();
+                }
+                return Some(ConnectionMsg::GetReservations);
+            }
+            ConnectionEvent::CoordinatorVersion(Some(version)) => {
+                match version::compatibility(&version) {
+                    Compatibility::Compatible => info!(version, "Coordinator version"),
+                    Compatibility::Untested => {
+                        warn!(
+                            version,
+                            "Coordinator version was not tested with this client"
+                        )
+                    }
+                    Compatibility::Incompatible => {
+                        warn!(
+                            version,
+                            "Coordinator version is incompatible with this client"
+                        )
+                    }
+                }
+            }
+            ConnectionEvent::ProtocolMismatch(error) => {
+                warn!(
+                    error,
+                    "Coordinator message could not be converted, protocol mismatch"
+                );
+            }
+            ConnectionEvent::NonCriticalError { error } => {
+                warn!(error = error.short, "Connection error");
+                debug!(?error, "Connection error");
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_grpc_error();
+                }
+            }
+            ConnectionEvent::Places(places) => {
+                self.places = places.into_iter().map(|p| (p.name.clone(), p)).collect();
+            }
+            ConnectionEvent::Place(place) => {
+                let prev = self.places.get(&place.name);
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_place_update(prev, &place);
+                }
+                if prev.is_some_and(|prev| prev.acquired.is_some()) && place.acquired.is_none() {
+                    info!(place = place.name, "Place released");
+                    self.trigger(None, JobEvent::PlaceReleased(&place.name));
+                }
+                self.places.insert(place.name.clone(), place);
+            }
+            ConnectionEvent::DeletePlace(name) => {
+                self.places.remove(&name);
+            }
+            ConnectionEvent::Resource(resource) => {
+                self.resources.insert(resource.path);
+            }
+            ConnectionEvent::DeleteResource(path) => {
+                self.resources.remove(&path);
+            }
+            ConnectionEvent::Reservations(reservations) => {
+                let allocated =
+                    newly_allocated_reservations(&self.identity, &self.reservations, &reservations)
+                        .map(|r| (r.token.clone(), r.allocations.values().next().cloned()))
+                        .collect::<Vec<(String, Option<String>)>>();
+                for (token, place) in allocated {
+                    info!(token, ?place, "Reservation allocated");
+                    self.trigger(None, JobEvent::ReservationAllocated(place.as_deref()));
+                }
+                self.reservations = reservations;
+            }
+            event => debug!(?event, "Unhandled connection event"),
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics.set_places(self.places.len());
+            metrics.set_resources(self.resources.len());
+        }
+        None
+    }
+
+    fn disconnected(&mut self, with_error: bool) {
+        self.connected = false;
+        self.places.clear();
+        self.resources.clear();
+        self.reservations.clear();
+        if let Some(metrics) = &self.metrics {
+            metrics.record_disconnected();
+            if with_error {
+                metrics.record_grpc_error();
+            }
+            metrics.set_places(0);
+            metrics.set_resources(0);
+        }
+    }
+
+    /// Runs the jobs triggered by `event`, or only the job with index `only`.
+    ///
+    /// Jobs that are still running from a previous trigger are skipped.
+    fn trigger(&mut self, only: Option<usize>, event: JobEvent) {
+        for (index, (job, script)) in self.jobs.iter().enumerate() {
+            if only.is_some_and(|only| only != index) {
+                continue;
+            }
+            let Some(env) = job.triggered_env(event) else {
+                continue;
+            };
+            let script_path = script.path().display().to_string();
+            if self.running.get(&index).is_some_and(|h| !h.is_finished()) {
+                warn!(script = script_path, "Job is still running, skipping");
+                continue;
+            }
+            info!(script = script_path, ?event, "Running job");
+            let script = script.clone();
+            let venv_dir = self.venv_dir.clone();
+            let timeout = script.timeout(self.default_script_timeout);
+            let handle = tokio::spawn(async move {
+                match script.execute(&venv_dir, &env, timeout).await {
+                    Ok((ScriptExit::Exited(0), _, _)) => {
+                        info!(script = script_path, "Job finished")
+                    }
+                    Ok((ScriptExit::Exited(exit_code), _, _)) => {
+                        warn!(script = script_path, exit_code, "Job failed")
+                    }
+                    Ok((ScriptExit::TimedOut(timeout), _, _)) => {
+                        warn!(script = script_path, ?timeout, "Job timed out")
+                    }
+                    Err(error) => error!(script = script_path, ?error, "Running job"),
+                }
+            });
+            self.running.insert(index, handle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headless_job_triggers() {
+        let jobs: Vec<HeadlessJob> = serde_json::from_str(
+            r#"[
+                { "script": "health.sh", "trigger": { "on": "interval", "secs": 300 } },
+                { "script": "reset.py", "trigger": { "on": "place_released" }, "place": "board-1", "env": "/etc/lg/board-1.yaml" },
+                { "script": "prepare.py", "trigger": { "on": "reservation_allocated" } }
+            ]"#,
+        )
+        .unwrap();
+        let place = |env: Option<Env>| env.and_then(|env| env.get(&EnvEntry::LgPlace).cloned());
+
+        assert_eq!(jobs[0].trigger, JobTrigger::Interval { secs: 300 });
+        assert!(jobs[0]
+            .triggered_env(JobEvent::Interval)
+            .unwrap()
+            .is_empty());
+        assert!(jobs[0]
+            .triggered_env(JobEvent::PlaceReleased("board-1"))
+            .is_none());
+
+        let env = jobs[1]
+            .triggered_env(JobEvent::PlaceReleased("board-1"))
+            .unwrap();
+        assert_eq!(
+            env.get(&EnvEntry::LgEnv).map(String::as_str),
+            Some("/etc/lg/board-1.yaml")
+        );
+        assert!(jobs[1]
+            .triggered_env(JobEvent::PlaceReleased("board-2"))
+            .is_none());
+
+        assert_eq!(
+            place(jobs[2].triggered_env(JobEvent::ReservationAllocated(Some("board-2")))),
+            Some("board-2".to_string())
+        );
+        assert_eq!(
+            place(jobs[2].triggered_env(JobEvent::ReservationAllocated(None))),
+            None
+        );
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::i18n::fl;
+use chrono::{DateTime, Local};
+use labgrid_ui_core::types::{Path, Place, Resource};
+use std::collections::{BTreeMap, VecDeque};
+
+/// The maximum number of acquisitions that are kept per resource.
+pub(crate) const ACQUIRE_HISTORY_LEN: usize = 5;
+/// The maximum number of activities that are kept per place.
+pub(crate) const PLACE_ACTIVITY_LEN: usize = 100;
+
+/// A single observed acquisition of a resource.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct AcquireRecord {
+    pub(crate) acquired_by: String,
+    pub(crate) time: DateTime<Local>,
+}
+
+/// The most recent acquisitions of all resources, as observed by the UI.
+///
+/// Keyed by the resource path, formatted as `exporter/group/name`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub(crate) struct AcquireHistory(BTreeMap<String, VecDeque<AcquireRecord>>);
+
+impl AcquireHistory {
+    fn key(path: &Path) -> String {
+        path.to_string()
+    }
+
+    /// Records an acquisition of the resource at `time`,
+    /// if it is acquired and was not acquired by the same place before.
+    ///
+    /// `prev_acquired` is the previously known state of the resource's `acquired` field, if any.
+    pub(crate) fn record(
+        &mut self,
+        prev_acquired: Option<&str>,
+        resource: &Resource,
+        time: DateTime<Local>,
+    ) {
+        if resource.acquired.is_empty() || prev_acquired == Some(resource.acquired.as_str()) {
+            return;
+        }
+        let records = self.0.entry(Self::key(&resource.path)).or_default();
+        records.push_front(AcquireRecord {
+            acquired_by: resource.acquired.clone(),
+            time,
+        });
+        records.truncate(ACQUIRE_HISTORY_LEN);
+    }
+
+    /// The recorded acquisitions of the resource with the given path, the most recent first.
+    pub(crate) fn get(&self, path: &Path) -> impl Iterator<Item = &AcquireRecord> {
+        self.0.get(&Self::key(path)).into_iter().flatten()
+    }
+}
+
+/// A state transition of a place.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PlaceActivityKind {
+    Acquired { by: String },
+    Released { by: String },
+    MatchAdded { pattern: String },
+    MatchRemoved { pattern: String },
+}
+
+impl std::fmt::Display for PlaceActivityKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::Acquired { by } => fl!("place-activity-acquired", user = by.as_str()),
+            Self::Released { by } => fl!("place-activity-released", user = by.as_str()),
+            Self::MatchAdded { pattern } => {
+                fl!("place-activity-match-added", pattern = pattern.as_str())
+            }
+            Self::MatchRemoved { pattern } => {
+                fl!("place-activity-match-removed", pattern = pattern.as_str())
+            }
+        };
+        f.write_str(&description)
+    }
+}
+
+/// A single observed state transition of a place.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PlaceActivity {
+    pub(crate) kind: PlaceActivityKind,
+    pub(crate) time: DateTime<Local>,
+}
+
+/// The most recent state transitions of all places, as observed through the client stream.
+///
+/// Keyed by the place name.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PlaceActivityLog(BTreeMap<String, VecDeque<PlaceActivity>>);
+
+impl PlaceActivityLog {
+    /// Records the transitions from the previously known state `prev` to the current state `place` at `time`.
+    pub(crate) fn record(&mut self, prev: &Place, place: &Place, time: DateTime<Local>) {
+        let mut kinds = Vec::new();
+        if prev.acquired != place.acquired {
+            if let Some(by) = &prev.acquired {
+                kinds.push(PlaceActivityKind::Released { by: by.clone() });
+            }
+            if let Some(by) = &place.acquired {
+                kinds.push(PlaceActivityKind::Acquired { by: by.clone() });
+            }
+        }
+        kinds.extend(
+            prev.matches
+                .iter()
+                .filter(|m| !place.matches.contains(m))
+                .map(|m| PlaceActivityKind::MatchRemoved {
+                    pattern: m.pattern().to_string(),
+                }),
+        );
+        kinds.extend(
+            place
+                .matches
+                .iter()
+                .filter(|m| !prev.matches.contains(m))
+                .map(|m| PlaceActivityKind::MatchAdded {
+                    pattern: m.pattern().to_string(),
+                }),
+        );
+        if kinds.is_empty() {
+            return;
+        }
+        let activities = self.0.entry(place.name.clone()).or_default();
+        activities.extend(kinds.into_iter().map(|kind| PlaceActivity { kind, time }));
+        while activities.len() > PLACE_ACTIVITY_LEN {
+            activities.pop_front();
+        }
+    }
+
+    /// The recorded activities of the place with the given name, in chronological order.
+    pub(crate) fn get(&self, place_name: &str) -> impl Iterator<Item = &PlaceActivity> {
+        self.0.get(place_name).into_iter().flatten()
+    }
+
+    /// The durations of the recorded acquisitions of the place that were released again.
+    pub(crate) fn acquisition_durations(
+        &self,
+        place_name: &str,
+    ) -> impl Iterator<Item = chrono::Duration> + '_ {
+        let mut acquired_at = None;
+        self.get(place_name)
+            .filter_map(move |activity| match activity.kind {
+                PlaceActivityKind::Acquired { .. } => {
+                    acquired_at = Some(activity.time);
+                    None
+                }
+                PlaceActivityKind::Released { .. } => {
+                    acquired_at.take().map(|acquired| activity.time - acquired)
+                }
+                _ => None,
+            })
+    }
+
+    /// The recorded activities of the place as text, one activity per line.
+    pub(crate) fn export(&self, place_name: &str) -> String {
+        self.get(place_name)
+            .map(|a| format!("{}\t{}\n", a.time.to_rfc3339(), a.kind))
+            .collect::<String>()
+            // Fluent isolates placeables with unicode directional marks, which are not wanted in files.
+            .replace(['\u{2068}', '\u{2069}'], "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use chrono::TimeZone;
+    use labgrid_ui_core::types::ResourceMatch;
+
+    fn place() -> Place {
+        Place {
+            matches: vec![ResourceMatch {
+                exporter: "exporter-1".to_string(),
+                group: "board-1".to_string(),
+                cls: "NetworkSerialPort".to_string(),
+                name: None,
+                rename: None,
+            }],
+            ..test_fixtures::place("board-1")
+        }
+    }
+
+    #[test]
+    fn place_activity_record() {
+        let mut log = PlaceActivityLog::default();
+        let time = Local.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let prev = place();
+        let mut acquired = place();
+        acquired.acquired = Some("host/alice".to_string());
+        acquired.matches[0].cls = "USBSerialPort".to_string();
+        log.record(&prev, &acquired, time);
+        // Unchanged acquisition and matches are not recorded.
+        log.record(&acquired, &acquired, time);
+
+        assert_eq!(
+            log.get("board-1")
+                .map(|a| a.kind.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                PlaceActivityKind::Acquired {
+                    by: "host/alice".to_string()
+                },
+                PlaceActivityKind::MatchRemoved {
+                    pattern: "exporter-1/board-1/NetworkSerialPort".to_string()
+                },
+                PlaceActivityKind::MatchAdded {
+                    pattern: "exporter-1/board-1/USBSerialPort".to_string()
+                },
+            ]
+        );
+        assert!(log
+            .export("board-1")
+            .starts_with(&format!("{}\tAcquired by host/alice\n", time.to_rfc3339())));
+    }
+}
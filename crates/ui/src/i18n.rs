@@ -3,8 +3,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use i18n_embed::fluent::{fluent_language_loader, FluentLanguageLoader};
-use i18n_embed::{DesktopLanguageRequester, LanguageLoader};
+use i18n_embed::{
+    AssetsMultiplexor, DesktopLanguageRequester, FileSystemAssets, I18nAssets, LanguageLoader,
+};
 use once_cell::sync::Lazy;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use tracing::{debug, error};
 
 /// Embeds the localization data.
@@ -12,21 +16,59 @@ use tracing::{debug, error};
 #[folder = "i18n"] // path to the compiled localization resources
 struct Localizations;
 
+/// The user locale directory, see [set_locale_dir].
+static LOCALE_DIR: OnceLock<PathBuf> = OnceLock::new();
+
 /// Lazy initialized language loader which holds state about the currently used and fallback languages
 /// and the translations for them.
 pub(crate) static LOADER: Lazy<FluentLanguageLoader> = Lazy::new(|| {
     let loader = fluent_language_loader!();
+    let assets = assets();
     loader
-        .load_fallback_language(&Localizations)
+        .load_fallback_language(&assets)
         .expect("Loading fallback language");
     let requested_languages = DesktopLanguageRequester::requested_languages();
     debug!(?requested_languages, "Loading initial requested languages");
-    if let Err(error) = loader.load_languages(&Localizations, &requested_languages) {
+    if let Err(error) = loader.load_languages(&assets, &requested_languages) {
         error!(?error, "Load initial requested language");
     }
     loader
 });
 
+/// Sets the user locale directory from which additional translations are loaded.
+///
+/// Must be called before the first translation is looked up, otherwise the default directory is used.
+pub(crate) fn set_locale_dir(dir: PathBuf) {
+    if LOCALE_DIR.set(dir).is_err() {
+        error!("The locale directory was set after it was already in use");
+    }
+}
+
+/// Returns the user locale directory.
+///
+/// It is laid out like the embedded translations, as `<language>/labgrid_ui.ftl`.
+/// Its messages take precedence over the embedded ones, missing messages fall back to them.
+pub(crate) fn locale_dir() -> &'static Path {
+    LOCALE_DIR.get_or_init(util::default_locale_dir)
+}
+
+/// The translations of the user locale directory, if present, followed by the embedded ones.
+///
+/// Files are read when the languages are loaded, so changes are picked up when the language is changed.
+fn assets() -> AssetsMultiplexor {
+    assets_in(locale_dir())
+}
+
+fn assets_in(locale_dir: &Path) -> AssetsMultiplexor {
+    let mut assets: Vec<Box<dyn I18nAssets + Send + Sync>> = Vec::new();
+    match FileSystemAssets::try_new(locale_dir) {
+        Ok(user_assets) => assets.push(Box::new(user_assets)),
+        Err(error) => debug!(?error, "User locale directory not loaded"),
+    }
+    assets.push(Box::new(Localizations));
+    AssetsMultiplexor::new(assets)
+}
+
 /// Convenience macro to access translations without having to specify the language loader.
 ///
 /// Enables compile time checked queries.
@@ -48,6 +90,7 @@ macro_rules! fl {
 }
 pub(crate) use fl;
 
+use crate::util;
 use anyhow::Context;
 use core::fmt::Display;
 
@@ -62,7 +105,7 @@ pub(crate) fn change_language(
 ) -> anyhow::Result<()> {
     debug!(?language, "Load new language");
     LOADER
-        .load_languages(&Localizations, &[language])
+        .load_languages(&assets(), &[language])
         .context("Load new language")
 }
 
@@ -78,6 +121,9 @@ pub(crate) enum AppLanguage {
     #[default]
     EnUs,
     EsEs,
+    FrFr,
+    ItIt,
+    ZhCn,
 }
 
 impl Display for AppLanguage {
@@ -87,6 +133,9 @@ impl Display for AppLanguage {
             AppLanguage::DeDe => write!(f, "{}", fl!("lang-de-de")),
             AppLanguage::EnUs => write!(f, "{}", fl!("lang-en-us")),
             AppLanguage::EsEs => write!(f, "{}", fl!("lang-es-es")),
+            AppLanguage::FrFr => write!(f, "{}", fl!("lang-fr-fr")),
+            AppLanguage::ItIt => write!(f, "{}", fl!("lang-it-it")),
+            AppLanguage::ZhCn => write!(f, "{}", fl!("lang-zh-cn")),
         }
     }
 }
@@ -98,6 +147,9 @@ impl From<AppLanguage> for i18n_embed::unic_langid::LanguageIdentifier {
             AppLanguage::DeDe => "de-DE".parse().unwrap(),
             AppLanguage::EnUs => "en-US".parse().unwrap(),
             AppLanguage::EsEs => "es-ES".parse().unwrap(),
+            AppLanguage::FrFr => "fr-FR".parse().unwrap(),
+            AppLanguage::ItIt => "it-IT".parse().unwrap(),
+            AppLanguage::ZhCn => "zh-CN".parse().unwrap(),
         }
     }
 }
@@ -114,6 +166,9 @@ impl TryFrom<i18n_embed::unic_langid::LanguageIdentifier> for AppLanguage {
             ("de", Some("de")) | ("de", None) => Ok(Self::DeDe),
             ("en", Some("us")) | ("en", None) => Ok(Self::EnUs),
             ("es", Some("es")) | ("es", None) => Ok(Self::EsEs),
+            ("fr", Some("fr")) | ("fr", None) => Ok(Self::FrFr),
+            ("it", Some("it")) | ("it", None) => Ok(Self::ItIt),
+            ("zh", Some("cn")) | ("zh", None) => Ok(Self::ZhCn),
             (lang, region) => Err(anyhow::anyhow!(
                 "Conversion to AppLanguage failed, unsupported language '{lang}-{region:?}'"
             )),
@@ -121,8 +176,131 @@ impl TryFrom<i18n_embed::unic_langid::LanguageIdentifier> for AppLanguage {
     }
 }
 
+impl std::str::FromStr for AppLanguage {
+    type Err = anyhow::Error;
+
+    /// Parses a language tag like `de-DE`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language: i18n_embed::unic_langid::LanguageIdentifier = s.parse()?;
+        Self::try_from(language)
+    }
+}
+
 impl AppLanguage {
     /// All currently available languages as a slice.
-    pub(crate) const LANGS_AVAILABLE: &'static [Self] =
-        &[Self::DeCh, Self::DeDe, Self::EnUs, Self::EsEs];
+    pub(crate) const LANGS_AVAILABLE: &'static [Self] = &[
+        Self::DeCh,
+        Self::DeDe,
+        Self::EnUs,
+        Self::EsEs,
+        Self::FrFr,
+        Self::ItIt,
+        Self::ZhCn,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// Calls that pass UI-facing strings, which must be looked up with `fl!` instead of being string literals.
+    const UI_STRING_CALLS: [&str; 4] = ["short: \"", "text(\"", ".placeholder(\"", ".label(\""];
+
+    fn source_files(dir: &Path) -> Vec<PathBuf> {
+        std::fs::read_dir(dir)
+            .unwrap()
+            .flat_map(|entry| {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    source_files(&path)
+                } else {
+                    vec![path]
+                }
+            })
+            .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+            .collect()
+    }
+
+    fn message_ids(lang: &str) -> BTreeSet<String> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("i18n")
+            .join(lang)
+            .join("labgrid_ui.ftl");
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(id, _)| id.to_string())
+            .filter(|id| !id.starts_with(char::is_whitespace))
+            .collect()
+    }
+
+    #[test]
+    fn ui_strings_translated() {
+        let mut untranslated = Vec::new();
+        for file in source_files(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src")) {
+            let content = std::fs::read_to_string(&file).unwrap();
+            // Tests use literals for their fixtures
+            let content = content.split("#[cfg(test)]\nmod tests").next().unwrap();
+            for (i, line) in content.lines().enumerate() {
+                let literal_text = UI_STRING_CALLS.iter().any(|call| {
+                    line.match_indices(call).any(|(pos, _)| {
+                        // Skips e.g. `context("..")`
+                        !line[..pos].ends_with(|c: char| c.is_alphanumeric() || c == '_')
+                            && line[pos + call.len()..].starts_with(|c: char| c.is_alphabetic())
+                    })
+                });
+                if literal_text && !line.trim_start().starts_with("//") {
+                    untranslated.push(format!("{}:{}: {}", file.display(), i + 1, line.trim()));
+                }
+            }
+        }
+        assert!(
+            untranslated.is_empty(),
+            "UI-facing string literals, use `fl!` instead:\n{}",
+            untranslated.join("\n")
+        );
+    }
+
+    /// The languages which are checked for missing translations, the others partially fall back to en-US.
+    const COMPLETE_LANGS: [&str; 4] = ["de-DE", "fr-FR", "it-IT", "zh-CN"];
+
+    #[test]
+    fn translations_complete() {
+        let fallback = message_ids("en-US");
+        for lang in COMPLETE_LANGS {
+            let missing = fallback
+                .difference(&message_ids(lang))
+                .cloned()
+                .collect::<Vec<String>>();
+            assert!(missing.is_empty(), "Missing in {lang}: {missing:?}");
+        }
+    }
+
+    #[test]
+    fn locale_dir_overrides() {
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-locale-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("de-DE")).unwrap();
+        std::fs::write(
+            dir.join("de-DE").join("labgrid_ui.ftl"),
+            "app-title = Eigene Oberfläche\n",
+        )
+        .unwrap();
+
+        let loader = fluent_language_loader!();
+        loader
+            .load_languages(&assets_in(&dir), &[AppLanguage::DeDe.into()])
+            .unwrap();
+        assert_eq!(loader.get("app-title"), "Eigene Oberfläche");
+        // Messages missing in the user locale directory fall back to the embedded ones
+        assert_eq!(loader.get("connect-button"), "Verbinden");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        let loader = fluent_language_loader!();
+        loader
+            .load_languages(&assets_in(&dir), &[AppLanguage::DeDe.into()])
+            .unwrap();
+        assert_eq!(loader.get("app-title"), "Labgrid Benutzeroberfläche");
+    }
 }
@@ -0,0 +1,261 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, LogViewerMsg};
+use chrono::{DateTime, Local};
+use iced::futures;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use tokio_stream::StreamExt;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// The number of recent events kept in the buffer, older ones are dropped first.
+const CAPACITY: usize = 1000;
+
+/// The buffer of the log viewer, fed by the [layer] installed with the tracing subscriber of the UI.
+pub(crate) static LOG_BUFFER: LazyLock<Arc<LogBuffer>> = LazyLock::new(Arc::default);
+
+/// The most verbose level of the events that are recorded and shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub(crate) const ALL: [Self; 5] = [
+        Self::Error,
+        Self::Warn,
+        Self::Info,
+        Self::Debug,
+        Self::Trace,
+    ];
+
+    fn from_u8(level: u8) -> Self {
+        Self::ALL
+            .get(usize::from(level))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn includes(&self, level: &Level) -> bool {
+        Self::from(*level) <= *self
+    }
+}
+
+impl From<Level> for LogLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::ERROR => Self::Error,
+            Level::WARN => Self::Warn,
+            Level::INFO => Self::Info,
+            Level::DEBUG => Self::Debug,
+            Level::TRACE => Self::Trace,
+        }
+    }
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let level = match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN",
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        };
+        write!(f, "{level}")
+    }
+}
+
+/// A recorded tracing event.
+#[derive(Debug, Clone)]
+pub(crate) struct LogEntry {
+    pub(crate) time: DateTime<Local>,
+    pub(crate) level: Level,
+    pub(crate) target: String,
+    /// The message followed by the other fields as `name=value`.
+    pub(crate) message: String,
+}
+
+impl std::fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {:>5} {}: {}",
+            self.time.format("%H:%M:%S%.3f"),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Ring buffer of the recent tracing events.
+#[derive(Debug)]
+pub(crate) struct LogBuffer {
+    entries: Mutex<VecDeque<LogEntry>>,
+    /// The [LogLevel] of the recorded events.
+    level: AtomicU8,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::default(),
+            level: AtomicU8::new(LogLevel::default() as u8),
+        }
+    }
+}
+
+impl LogBuffer {
+    pub(crate) fn level(&self) -> LogLevel {
+        LogLevel::from_u8(self.level.load(Ordering::Relaxed))
+    }
+
+    /// Events more verbose than the level are not recorded anymore, already recorded ones are kept.
+    pub(crate) fn set_level(&self, level: LogLevel) {
+        self.level.store(level as u8, Ordering::Relaxed);
+    }
+
+    /// The recorded events, the oldest first.
+    pub(crate) fn entries(&self) -> Vec<LogEntry> {
+        self.lock().iter().cloned().collect()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn push(&self, entry: LogEntry) {
+        let mut entries = self.lock();
+        if entries.len() >= CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<LogEntry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Periodically refreshes the events shown in the log viewer.
+pub(crate) fn refresh_subscription() -> impl futures::Stream<Item = AppMsg> {
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+    IntervalStream::new(time::interval(REFRESH_INTERVAL))
+        .map(|_| AppMsg::LogViewer(LogViewerMsg::Refresh))
+}
+
+/// The tracing layer recording the events of the buffer's level into the buffer.
+pub(crate) fn layer<S>(buffer: Arc<LogBuffer>) -> impl Layer<S>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let filter_buffer = buffer.clone();
+    BufferLayer(buffer).with_filter(tracing_subscriber::filter::filter_fn(move |metadata| {
+        filter_buffer.level().includes(metadata.level())
+    }))
+}
+
+struct BufferLayer(Arc<LogBuffer>);
+
+impl<S: Subscriber> Layer<S> for BufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.0.push(LogEntry {
+            time: Local::now(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.message + &visitor.fields,
+        });
+    }
+}
+
+/// Formats the message and the other fields of an event.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn log_buffer() {
+        let buffer = Arc::new(LogBuffer::default());
+        let subscriber = tracing_subscriber::registry().with(layer(buffer.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(place = "board-1", "Acquired place");
+            tracing::debug!("Not recorded at the default level");
+            buffer.set_level(LogLevel::Debug);
+            tracing::debug!(attempt = 2, "Retrying");
+            buffer.set_level(LogLevel::Error);
+            tracing::warn!("Not recorded anymore");
+        });
+        let entries = buffer.entries();
+        assert_eq!(
+            entries
+                .iter()
+                .map(|e| (e.level, e.message.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (Level::INFO, "Acquired place place=board-1"),
+                (Level::DEBUG, "Retrying attempt=2"),
+            ]
+        );
+        assert!(entries[0]
+            .to_string()
+            .ends_with(" INFO labgrid_ui::log_viewer::tests: Acquired place place=board-1"));
+        assert!(LogLevel::Info.includes(&Level::WARN));
+        assert!(!LogLevel::Info.includes(&Level::DEBUG));
+
+        for i in 0..CAPACITY + 1 {
+            buffer.push(LogEntry {
+                message: i.to_string(),
+                ..entries[0].clone()
+            });
+        }
+        let entries = buffer.entries();
+        assert_eq!(entries.len(), CAPACITY);
+        assert_eq!(entries[0].message, "1");
+        buffer.clear();
+        assert!(buffer.entries().is_empty());
+    }
+}
@@ -8,28 +8,126 @@
     windows_subsystem = "windows"
 )]
 
+/// Live view of the resources acquired by places and highlighting of their changes.
+pub(crate) mod acquired_resources;
+/// One-shot actions requested on the command line, run without bringing up the UI.
+pub(crate) mod actions;
 /// Core app logic and state.
 pub(crate) mod app;
+/// Availability of the resources matched by places.
+pub(crate) mod availability;
+/// Previews of camera resources in the resource details.
+pub(crate) mod camera;
+/// Access to the system or an app internal clipboard.
+pub(crate) mod clipboard;
 /// Persistent application configuration.
 pub(crate) mod config;
 /// Connection subscription and state for communicating with the coordinator through grpc.
 pub(crate) mod connection;
+/// Aggregate statistics of the places and resources for the dashboard tab.
+pub(crate) mod dashboard;
+/// Log of all errors reported during the session, listed in the error console.
+pub(crate) mod error_log;
+/// Exporting the displayed places, resources and reservations to CSV or JSON.
+pub(crate) mod export;
+/// Per-exporter overview derived from the resource paths.
+pub(crate) mod exporters;
+/// Built-in file and directory browser, used when native dialogs are unavailable.
+pub(crate) mod file_browser;
+/// File dialogs opened by the app, abstracted for testing.
+pub(crate) mod file_dialogs;
+/// Formatting dates, durations and numbers in the format of the current language.
+pub(crate) mod format;
+/// Running configured scripts on schedule or on coordinator events without bringing up the UI.
+pub(crate) mod headless;
+/// Session-local history of resource acquisitions.
+pub(crate) mod history;
 /// Utilities for changing the application language, retreive translations, and so on.
 pub(crate) mod i18n;
+/// In-app viewer of the recent tracing events, for inspecting diagnostics without a terminal.
+pub(crate) mod log_viewer;
+/// Building place match patterns component by component.
+pub(crate) mod match_builder;
+/// Recording the application's operational metrics and exporting them when requested on the command line.
+pub(crate) mod metrics;
+/// Mirroring the place and resource state to an MQTT broker.
+pub(crate) mod mqtt;
+/// Desktop notifications for place, reservation and script events.
+pub(crate) mod notifications;
+/// Customizable color coding of place cards.
+pub(crate) mod palette;
+/// Cloning places as template for new places.
+pub(crate) mod place_clone;
+/// Snapshots of the place configuration for backing up and restoring coordinators.
+pub(crate) mod place_snapshot;
+/// Connection profiles with per-coordinator identity and connection settings.
+pub(crate) mod profiles;
+/// Inspecting the protobuf messages exchanged with the coordinator, for diagnosing incompatibilities.
+pub(crate) mod protocol_inspector;
+/// Queue position estimation of waiting reservations.
+pub(crate) mod queue;
+/// The configurable quick-action buttons of the place cards.
+pub(crate) mod quick_actions;
+/// Commands connecting to network resources through ssh, microcom or telnet.
+pub(crate) mod quick_connect;
+/// Fuzzy keyboard-driven switching to places and scripts.
+pub(crate) mod quick_switcher;
+/// Rate limiting of operations sent to the coordinator.
+pub(crate) mod rate_limit;
+/// Running scripts on the next available place matching a reservation template.
+pub(crate) mod reservation_runner;
+/// Icons, parameter schemas and quick actions of resource classes.
+pub(crate) mod resource_classes;
+/// The class, exporter, availability and search filters of the resources tab.
+pub(crate) mod resource_filter;
+/// Notices about availability changes of watched resources.
+pub(crate) mod resource_watch;
+/// Serving the places, resources and reservations as JSON when requested on the command line.
+#[cfg(feature = "rest-gateway")]
+pub(crate) mod rest_gateway;
+/// Favorites, display names and the manual order of scripts.
+pub(crate) mod script_meta;
 /// State and logic related to the scripts tab of the application.
 pub(crate) mod scripts;
+/// Timers of places acquired through the UI and automatically releasing them when idle.
+pub(crate) mod session_timer;
+/// Global keyboard shortcuts.
+pub(crate) mod shortcuts;
+/// Places, resources and reservations shared by the unit tests, adjusted with the struct update syntax.
+#[cfg(test)]
+pub(crate) mod test_fixtures;
+/// Arranging the USB resources of exporters by the hub ports their devices are plugged into.
+pub(crate) mod usb_topology;
 /// Miscellaneous utilities.
 pub(crate) mod util;
+/// Recording the acquisitions and releases of places on disk, for the occupancy in the dashboard.
+pub(crate) mod utilization;
+/// Creating python virtual environments with labgrid installed for running the scripts.
+pub(crate) mod venv;
+/// Persisting the active tab, the filters of the views and the window geometry across restarts.
+pub(crate) mod view_state;
 /// Application UI views derived from the application state.
 pub(crate) mod views;
+/// Notifying webhooks about coordinator events when requested on the command line.
+pub(crate) mod webhooks;
 
+use actions::CliAction;
+use app::AppTheme;
 use clap::Parser;
+use config::ConfigOverrides;
+use connection::{AuthTokenKind, ConnectionAuth};
+use i18n::AppLanguage;
+use labgrid_ui_core::webhooks::WebhookUrl;
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use tracing::debug;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::layer::{Layer, SubscriberExt};
 
 /// Command line arguments for additional options.
 ///
 /// Can be used to overwrite the app defaults
-/// or restored values from settings (persistent settings not yet implemented).
+/// or the values of the configuration file, without saving them to it.
 #[derive(Debug, clap::Parser)]
 pub(crate) struct Args {
     /// Labgrid coordinator host and port.
@@ -42,24 +140,161 @@ pub(crate) struct Args {
     // Useful when the app is started on a wayland/X11 server that does not implement a clipboard.
     #[arg(long, default_value_t = false)]
     internal_clipboard: bool,
+    /// Directory of the scripts, instead of the one of the configuration.
+    #[arg(long, env = "LG_UI_SCRIPTS_DIR")]
+    scripts_dir: Option<PathBuf>,
+    /// Directory of the python virtual environment with labgrid, instead of the one of the configuration.
+    #[arg(long, env = "LG_UI_VENV_DIR")]
+    venv_dir: Option<PathBuf>,
+    /// Language of the UI, e.g. `de-DE`, instead of the one of the configuration.
+    #[arg(long, env = "LG_UI_LANGUAGE")]
+    language: Option<AppLanguage>,
+    /// Directory with additional or overriding translations, laid out as `<language>/labgrid_ui.ftl`,{n}
+    /// instead of `i18n` in the app data directory.
+    #[arg(long, env = "LG_UI_LOCALE_DIR")]
+    locale_dir: Option<PathBuf>,
+    /// Color theme of the UI, follows the system color scheme by default.
+    #[arg(long, value_enum, env = "LG_UI_THEME")]
+    theme: Option<AppTheme>,
+    /// Run without the UI: keep the connection to the coordinator
+    /// and run the jobs of the configuration file on schedule or on events.{n}
+    /// Logs at the `info` level unless `RUST_LOG` is set.
+    #[arg(long, default_value_t = false, conflicts_with = "action")]
+    headless: bool,
+    /// Acquire the place and exit, without bringing up the UI.
+    #[arg(long, value_name = "PLACE", group = "action")]
+    acquire: Option<String>,
+    /// Release the place and exit, without bringing up the UI.
+    #[arg(long, value_name = "PLACE", group = "action")]
+    release: Option<String>,
+    /// Run the script, relative to the scripts directory or absolute, and exit with its exit code,{n}
+    /// without bringing up the UI.
+    #[arg(long, value_name = "SCRIPT", group = "action")]
+    run_script: Option<PathBuf>,
+    /// The place passed to the script of `--run-script` as `LG_PLACE`.
+    #[arg(long, requires = "run_script")]
+    place: Option<String>,
+    /// The environment configuration file passed to the script of `--run-script` as `LG_ENV`.
+    #[arg(long, requires = "run_script")]
+    lg_env: Option<String>,
+    /// Serve operational metrics in the Prometheus text format at `http://<ADDR>/metrics`,{n}
+    /// e.g. `127.0.0.1:9464`.
+    #[arg(long, env = "LG_UI_METRICS_LISTEN")]
+    metrics_listen: Option<SocketAddr>,
+    /// Periodically write operational metrics in the Prometheus text format to this file,{n}
+    /// e.g. for the textfile collector of the node exporter.
+    #[arg(long, env = "LG_UI_METRICS_FILE")]
+    metrics_file: Option<PathBuf>,
+    /// POST a JSON notification to this URL when places are acquired or released,{n}
+    /// resources appear or disappear or reservations change, e.g. `http://127.0.0.1:8080/labgrid`.{n}
+    /// Can be supplied multiple times, only `http` URLs are supported.
+    #[arg(
+        long = "webhook-url",
+        env = "LG_UI_WEBHOOK_URLS",
+        value_delimiter = ','
+    )]
+    webhook_urls: Vec<WebhookUrl>,
+    /// Serve the places, resources and reservations of the coordinator as JSON while connected,{n}
+    /// at `http://<ADDR>/api/{status,places,places/<name>,resources,reservations}`, e.g. `127.0.0.1:8080`.
+    #[cfg(feature = "rest-gateway")]
+    #[arg(long, env = "LG_UI_REST_LISTEN")]
+    rest_listen: Option<SocketAddr>,
+    /// Bearer token sent with every call, for coordinators behind an authenticating proxy.{n}
+    /// The authentication arguments replace the authentication of the configuration.
+    #[arg(long, env = "LG_UI_AUTH_TOKEN", group = "auth", hide_env_values = true)]
+    auth_token: Option<String>,
+    /// File containing the bearer token, read again every second.
+    #[arg(long, env = "LG_UI_AUTH_TOKEN_FILE", group = "auth")]
+    auth_token_file: Option<PathBuf>,
+    /// Command printing the bearer token, run again once the token is older than `--auth-token-refresh`.
+    #[arg(long, env = "LG_UI_AUTH_TOKEN_COMMAND", group = "auth")]
+    auth_token_command: Option<String>,
+    /// Seconds until the token command is run again.
+    #[arg(long, value_name = "SECS", requires = "auth_token_command")]
+    auth_token_refresh: Option<u32>,
+    /// Metadata entry sent with every call as `key=value`, e.g. `x-api-key=secret`.{n}
+    /// Can be supplied multiple times.
+    #[arg(
+        long = "grpc-metadata",
+        env = "LG_UI_GRPC_METADATA",
+        value_delimiter = ',',
+        hide_env_values = true
+    )]
+    grpc_metadata: Vec<String>,
+}
+
+impl Args {
+    /// The settings of the arguments that take precedence over the configuration.
+    pub(crate) fn config_overrides(&self) -> ConfigOverrides {
+        ConfigOverrides {
+            scripts_dir: self.scripts_dir.clone(),
+            venv_dir: self.venv_dir.clone(),
+            language: self.language,
+            connection_auth: self.connection_auth(),
+        }
+    }
+
+    /// The authentication of the arguments, `None` if none is supplied.
+    fn connection_auth(&self) -> Option<ConnectionAuth> {
+        let (token_kind, token) = if let Some(token) = &self.auth_token {
+            (AuthTokenKind::Static, token.clone())
+        } else if let Some(path) = &self.auth_token_file {
+            (AuthTokenKind::File, path.display().to_string())
+        } else if let Some(command) = &self.auth_token_command {
+            (AuthTokenKind::Command, command.clone())
+        } else if !self.grpc_metadata.is_empty() {
+            (AuthTokenKind::None, String::new())
+        } else {
+            return None;
+        };
+        let default = ConnectionAuth::default();
+        Some(ConnectionAuth {
+            token_kind,
+            token,
+            token_refresh_secs: self
+                .auth_token_refresh
+                .unwrap_or(default.token_refresh_secs),
+            metadata: self.grpc_metadata.join(","),
+        })
+    }
 }
 
 fn main() -> anyhow::Result<()> {
-    setup_tracing_subscriber()?;
     let args = Args::parse();
-    app::run(args)?;
+    if let Some(locale_dir) = &args.locale_dir {
+        i18n::set_locale_dir(locale_dir.clone());
+    }
+    if let Some(action) = CliAction::from_args(&args) {
+        setup_tracing_subscriber(LevelFilter::WARN, false)?;
+        let exit_code = actions::run(&args, action)?;
+        std::process::exit(exit_code);
+    }
+    if args.headless {
+        setup_tracing_subscriber(LevelFilter::INFO, false)?;
+        headless::run(args)?;
+    } else {
+        setup_tracing_subscriber(LevelFilter::ERROR, true)?;
+        app::run(args)?;
+    }
     Ok(())
 }
 
 /// Sets up a tracing subscriber that logs to the console.
 ///
 /// Picks up values of environment variable `RUST_LOG` to determine event emission levels
-/// (error, warn, info, debug, ..).
-fn setup_tracing_subscriber() -> anyhow::Result<()> {
+/// (error, warn, info, debug, ..), defaulting to `default_level`.
+/// With `in_app_log` the events are additionally recorded for the log viewer, independent of `RUST_LOG`.
+fn setup_tracing_subscriber(default_level: LevelFilter, in_app_log: bool) -> anyhow::Result<()> {
     tracing::subscriber::set_global_default(
-        tracing_subscriber::fmt()
-            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
-            .finish(),
+        tracing_subscriber::registry()
+            .with(
+                tracing_subscriber::fmt::layer().with_filter(
+                    tracing_subscriber::EnvFilter::builder()
+                        .with_default_directive(default_level.into())
+                        .from_env_lossy(),
+                ),
+            )
+            .with(in_app_log.then(|| log_viewer::layer(log_viewer::LOG_BUFFER.clone()))),
     )?;
     debug!(".. tracing subscriber initialized");
     Ok(())
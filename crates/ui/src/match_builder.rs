@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::ResourceUi;
+use labgrid_ui_core::pattern::{MatchPattern, PatternError};
+use labgrid_ui_core::types::Resource;
+
+/// A component of a match pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MatchComponent {
+    Exporter,
+    Group,
+    Cls,
+    Name,
+}
+
+/// A match pattern `exporter/group/cls[/name]` that is edited component by component.
+///
+/// The components may contain wildcards, an empty name matches all resources of the group with the class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct MatchBuilder {
+    pub(crate) exporter: String,
+    pub(crate) group: String,
+    pub(crate) cls: String,
+    pub(crate) name: String,
+}
+
+impl MatchBuilder {
+    pub(crate) fn get(&self, component: MatchComponent) -> &str {
+        match component {
+            MatchComponent::Exporter => &self.exporter,
+            MatchComponent::Group => &self.group,
+            MatchComponent::Cls => &self.cls,
+            MatchComponent::Name => &self.name,
+        }
+    }
+
+    pub(crate) fn set(&mut self, component: MatchComponent, value: String) {
+        match component {
+            MatchComponent::Exporter => self.exporter = value,
+            MatchComponent::Group => self.group = value,
+            MatchComponent::Cls => self.cls = value,
+            MatchComponent::Name => self.name = value,
+        }
+    }
+
+    /// Fills the components from a textual pattern, e.g. pasted from the clipboard.
+    ///
+    /// Excess components are kept in the name, so they show up in the validation.
+    pub(crate) fn set_pattern(&mut self, pattern: &str) {
+        let mut components = pattern.trim().splitn(4, '/').map(str::to_string);
+        *self = Self {
+            exporter: components.next().unwrap_or_default(),
+            group: components.next().unwrap_or_default(),
+            cls: components.next().unwrap_or_default(),
+            name: components.next().unwrap_or_default(),
+        };
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The pattern of the components, an error if a component is empty or contains a `/`.
+    pub(crate) fn pattern(&self) -> Result<MatchPattern, PatternError> {
+        let mut pattern = [&self.exporter, &self.group, &self.cls]
+            .map(|c| c.trim())
+            .join("/");
+        if !self.name.trim().is_empty() {
+            pattern.push('/');
+            pattern.push_str(self.name.trim());
+        }
+        pattern.parse()
+    }
+
+    /// The number of the known resources the pattern currently matches, `None` if it is invalid.
+    pub(crate) fn matching_resources(&self, resources: &[(Resource, ResourceUi)]) -> Option<usize> {
+        let pattern = self.pattern().ok()?;
+        Some(
+            resources
+                .iter()
+                .filter(|(r, _)| pattern.matches(&r.path, &r.cls))
+                .count(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    fn resource(exporter: &str, group: &str, name: &str, cls: &str) -> (Resource, ResourceUi) {
+        let mut resource = test_fixtures::resource(name, cls);
+        resource.path.exporter_name = Some(exporter.to_string());
+        resource.path.group_name = group.to_string();
+        (resource, ResourceUi::default())
+    }
+
+    #[test]
+    fn match_builder() {
+        let resources = [
+            resource("exporter-1", "board-1", "console", "NetworkSerialPort"),
+            resource("exporter-1", "board-1", "power", "NetworkPowerPort"),
+            resource("exporter-2", "board-2", "console", "NetworkSerialPort"),
+        ];
+        let mut builder = MatchBuilder::default();
+        assert!(builder.is_empty());
+        assert_eq!(
+            builder.pattern(),
+            Err(PatternError::EmptyComponent {
+                input: "//".to_string()
+            })
+        );
+        assert_eq!(builder.matching_resources(&resources), None);
+
+        builder.set(MatchComponent::Exporter, "*".to_string());
+        builder.set(MatchComponent::Group, "board-?".to_string());
+        builder.set(MatchComponent::Cls, " NetworkSerialPort ".to_string());
+        assert_eq!(
+            builder.pattern().map(|p| p.to_string()).as_deref(),
+            Ok("*/board-?/NetworkSerialPort")
+        );
+        assert_eq!(builder.matching_resources(&resources), Some(2));
+        builder.set(MatchComponent::Exporter, "exporter-1".to_string());
+        assert_eq!(builder.matching_resources(&resources), Some(1));
+
+        builder.set_pattern("exporter-1/board-1/*/power");
+        assert_eq!(builder.get(MatchComponent::Name), "power");
+        assert_eq!(builder.matching_resources(&resources), Some(1));
+        builder.set_pattern("exporter-1/board-1/NetworkPowerPort/power/extra");
+        assert_eq!(builder.name, "power/extra");
+        assert!(builder.pattern().is_err());
+        builder.set_pattern("");
+        assert!(builder.is_empty());
+    }
+}
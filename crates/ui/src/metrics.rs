@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, AppState};
+use crate::connection::ConnectionEvent;
+use iced::Task;
+use labgrid_ui_core::metrics::{Metrics, MetricsExport};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How often the metrics file is rewritten.
+pub(crate) const FILE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The exports requested through the command line, metrics are only recorded if there are any.
+pub(crate) fn exports(listen: Option<SocketAddr>, file: Option<PathBuf>) -> Vec<MetricsExport> {
+    listen
+        .map(MetricsExport::Http)
+        .into_iter()
+        .chain(file.map(|path| MetricsExport::File {
+            path,
+            interval: FILE_INTERVAL,
+        }))
+        .collect()
+}
+
+/// A task running the exports, reporting with `AppMsg::MetricsExportFailed` when one fails.
+pub(crate) fn export_task(metrics: &Arc<Metrics>, exports: Vec<MetricsExport>) -> Task<AppMsg> {
+    Task::batch(exports.into_iter().map(|export| {
+        Task::perform(export.run(Arc::clone(metrics)), |res| match res {
+            Ok(()) => AppMsg::None,
+            Err(error) => AppMsg::MetricsExportFailed(format!("{error:?}")),
+        })
+    }))
+}
+
+/// Records the connection event, must be called with the state from before the event is handled.
+pub(crate) fn record_event(metrics: &Metrics, state: &AppState, event: &ConnectionEvent) {
+    match event {
+        ConnectionEvent::Connected { .. } => metrics.record_connected(),
+        ConnectionEvent::Disconnected { error } => {
+            metrics.record_disconnected();
+            if error.is_some() {
+                metrics.record_grpc_error();
+            }
+        }
+        ConnectionEvent::NonCriticalError { .. } => metrics.record_grpc_error(),
+        ConnectionEvent::Place(place) => {
+            if let AppState::Connected(connected) = state {
+                let prev = connected.place_by_name(&place.name).map(|(p, _)| p);
+                metrics.record_place_update(prev, place);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Updates the number of places and resources from the current state.
+pub(crate) fn record_state(metrics: &Metrics, state: &AppState) {
+    let (places, resources) = match state {
+        AppState::Connected(connected) => (connected.places.len(), connected.resources.len()),
+        _ => (0, 0),
+    };
+    metrics.set_places(places);
+    metrics.set_resources(resources);
+}
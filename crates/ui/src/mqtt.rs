@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::AppMsg;
+use crate::connection::ConnectionEvent;
+use iced::futures::channel::mpsc;
+use iced::futures::{self, SinkExt};
+use iced::stream;
+use labgrid_ui_core::mqtt::{
+    MqttConnection, MqttMessage, MqttOptions, MqttPublisher, MqttStateMirror, MqttStatus,
+};
+use labgrid_ui_core::types::{Place, Resource};
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+/// Settings of the MQTT bridge.
+#[derive(Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct MqttSettings {
+    pub(crate) enabled: bool,
+    /// The broker host and port.
+    pub(crate) broker: String,
+    pub(crate) topic_prefix: String,
+    /// Not sent when empty.
+    pub(crate) username: String,
+    /// Not sent when empty, requires the username, saved in plain text.
+    pub(crate) password: String,
+}
+
+impl Default for MqttSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            broker: "localhost:1883".to_string(),
+            topic_prefix: "labgrid".to_string(),
+            username: String::new(),
+            password: String::new(),
+        }
+    }
+}
+
+// Passwords must not end up in the logs.
+impl std::fmt::Debug for MqttSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let password = if self.password.is_empty() { "" } else { ".." };
+        f.debug_struct("MqttSettings")
+            .field("enabled", &self.enabled)
+            .field("broker", &self.broker)
+            .field("topic_prefix", &self.topic_prefix)
+            .field("username", &self.username)
+            .field("password", &password)
+            .finish()
+    }
+}
+
+impl MqttSettings {
+    /// The options of the connection of a client with the labgrid `identity`.
+    fn options(&self, identity: &str) -> MqttOptions {
+        let client_id = format!(
+            "labgrid-ui-{}-{}",
+            identity.replace('/', "-"),
+            std::process::id()
+        );
+        let mut options = MqttOptions::new(self.broker.trim(), client_id);
+        options.topic_prefix = self.topic_prefix.trim().trim_end_matches('/').to_string();
+        options.username = Some(self.username.clone()).filter(|u| !u.is_empty());
+        options.password = Some(self.password.clone()).filter(|p| !p.is_empty());
+        options
+    }
+}
+
+/// Mirrors the place and resource state observed through the connection events to the broker.
+#[derive(Debug, Clone)]
+pub(crate) struct MqttBridge {
+    publisher: MqttPublisher,
+    mirror: MqttStateMirror,
+}
+
+impl MqttBridge {
+    /// Creates the bridge and the connection to the broker for a client with the labgrid `identity`.
+    pub(crate) fn new(settings: &MqttSettings, identity: &str) -> (Self, MqttConnection) {
+        let options = settings.options(identity);
+        let mirror = MqttStateMirror::new(options.topic_prefix.clone());
+        let (publisher, connection) = MqttPublisher::new(options);
+        (Self { publisher, mirror }, connection)
+    }
+
+    /// Publishes the state already known when the bridge was created.
+    pub(crate) fn publish_state<'a>(
+        &mut self,
+        places: impl IntoIterator<Item = &'a Place>,
+        resources: impl IntoIterator<Item = &'a Resource>,
+    ) {
+        let mut messages = Vec::new();
+        for place in places {
+            messages.extend(self.mirror.place(place));
+        }
+        for resource in resources {
+            messages.extend(self.mirror.resource(resource));
+        }
+        self.publisher.publish(messages);
+    }
+
+    /// Records the connection event, publishing the state changes it caused.
+    pub(crate) fn record_event(&mut self, event: &ConnectionEvent) {
+        let messages: Vec<MqttMessage> = match event {
+            ConnectionEvent::Disconnected { .. } => {
+                self.mirror.resync();
+                return;
+            }
+            ConnectionEvent::Synced => self.mirror.synced(),
+            ConnectionEvent::Place(place) => self.mirror.place(place),
+            ConnectionEvent::Places(places) => places
+                .iter()
+                .flat_map(|place| self.mirror.place(place))
+                .collect(),
+            ConnectionEvent::DeletePlace(name) => self.mirror.delete_place(name),
+            ConnectionEvent::Resource(resource) => self.mirror.resource(resource),
+            ConnectionEvent::DeleteResource(path) => self.mirror.delete_resource(path),
+            _ => return,
+        };
+        self.publisher.publish(messages);
+    }
+}
+
+/// An iced subscription running the bridge for the settings and labgrid identity.
+///
+/// Emits [AppMsg::MqttBridgeReady] with the bridge to record the connection events with,
+/// followed by [AppMsg::MqttStatus] whenever the status of the connection to the broker changes.
+pub(crate) fn bridge_subscription(
+    target: &(MqttSettings, String),
+) -> impl futures::Stream<Item = AppMsg> {
+    let (settings, identity) = target.clone();
+    stream::channel(8, move |mut output: mpsc::Sender<AppMsg>| async move {
+        let (bridge, connection) = MqttBridge::new(&settings, &identity);
+        let mut status = connection.status();
+        let _ = output.send(AppMsg::MqttBridgeReady(bridge)).await;
+        let forward_status = async {
+            while status.changed().await.is_ok() {
+                let status = status.borrow_and_update().clone();
+                let _ = output.send(AppMsg::MqttStatus(status)).await;
+            }
+        };
+        futures::join!(connection.run(), forward_status);
+    })
+}
+
+/// Logs the changes of the status of the connection to the broker, used by the headless mode.
+pub(crate) async fn log_status(mut status: watch::Receiver<MqttStatus>) {
+    while status.changed().await.is_ok() {
+        match &*status.borrow_and_update() {
+            MqttStatus::Connected => info!("Connected to MQTT broker"),
+            MqttStatus::Disconnected { error } => warn!(error, "Disconnected from MQTT broker"),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::AppMsg;
+use iced::Task;
+use tracing::error;
+
+/// The events for which desktop notifications are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct NotificationSettings {
+    /// A watched place got released.
+    pub(crate) place_released: bool,
+    /// A reservation owned by this client got allocated.
+    pub(crate) reservation_allocated: bool,
+    /// A script finished executing.
+    pub(crate) script_finished: bool,
+    /// A place acquired through the UI is about to be released automatically.
+    pub(crate) auto_release_warning: bool,
+    /// A watched resource became available or unavailable.
+    pub(crate) resource_availability_changed: bool,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            place_released: true,
+            reservation_allocated: true,
+            script_finished: true,
+            auto_release_warning: true,
+            resource_availability_changed: true,
+        }
+    }
+}
+
+/// The kinds of events for which desktop notifications can be enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NotificationEvent {
+    PlaceReleased,
+    ReservationAllocated,
+    ScriptFinished,
+    AutoReleaseWarning,
+    ResourceAvailabilityChanged,
+}
+
+impl NotificationSettings {
+    pub(crate) fn enabled(&self, event: NotificationEvent) -> bool {
+        match event {
+            NotificationEvent::PlaceReleased => self.place_released,
+            NotificationEvent::ReservationAllocated => self.reservation_allocated,
+            NotificationEvent::ScriptFinished => self.script_finished,
+            NotificationEvent::AutoReleaseWarning => self.auto_release_warning,
+            NotificationEvent::ResourceAvailabilityChanged => self.resource_availability_changed,
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, event: NotificationEvent, enabled: bool) {
+        match event {
+            NotificationEvent::PlaceReleased => self.place_released = enabled,
+            NotificationEvent::ReservationAllocated => self.reservation_allocated = enabled,
+            NotificationEvent::ScriptFinished => self.script_finished = enabled,
+            NotificationEvent::AutoReleaseWarning => self.auto_release_warning = enabled,
+            NotificationEvent::ResourceAvailabilityChanged => {
+                self.resource_availability_changed = enabled
+            }
+        }
+    }
+}
+
+/// Shows a desktop notification if notifications for the event are enabled.
+///
+/// Showing the notification might block, so it is done in a separate task.
+pub(crate) fn notify(
+    settings: &NotificationSettings,
+    event: NotificationEvent,
+    summary: String,
+    body: String,
+) -> Task<AppMsg> {
+    if !settings.enabled(event) {
+        return Task::none();
+    }
+    Task::future(async move {
+        let res = tokio::task::spawn_blocking(move || {
+            notify_rust::Notification::new()
+                .appname("labgrid-ui")
+                .summary(&summary)
+                .body(&body)
+                .show()
+                .map(|_| ())
+        })
+        .await;
+        match res {
+            Ok(Ok(())) => {}
+            Ok(Err(error)) => error!(?error, ?event, "Show desktop notification"),
+            Err(error) => error!(?error, ?event, "Join desktop notification task"),
+        }
+        AppMsg::None
+    })
+}
@@ -0,0 +1,307 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use iced::theme::Palette;
+use iced::{Color, Theme};
+use labgrid_ui_core::types::Place;
+
+/// The tag key that marks a place as being under maintenance.
+pub(crate) const MAINTENANCE_TAG: &str = "maintenance";
+
+/// The state of a place, used for color coding its card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum PlaceCardState {
+    Free,
+    Mine,
+    Others,
+    Reserved,
+    Maintenance,
+}
+
+impl PlaceCardState {
+    pub(crate) const ALL: [Self; 5] = [
+        Self::Free,
+        Self::Mine,
+        Self::Others,
+        Self::Reserved,
+        Self::Maintenance,
+    ];
+
+    /// Determines the state of the place for the [crate::app::AppConnected::identity].
+    ///
+    /// Maintenance takes precedence over acquisitions, which take precedence over reservations.
+    pub(crate) fn of(place: &Place, identity: &str) -> Self {
+        if place.tags.contains_key(MAINTENANCE_TAG) {
+            Self::Maintenance
+        } else if let Some(acquired) = &place.acquired {
+            if acquired == identity {
+                Self::Mine
+            } else {
+                Self::Others
+            }
+        } else if place.reservation.as_ref().is_some_and(|r| !r.is_empty()) {
+            Self::Reserved
+        } else {
+            Self::Free
+        }
+    }
+}
+
+/// The user customizable colors of the place cards, stored as hex strings (`#rrggbb`).
+///
+/// Kept as text so that they can be edited in place,
+/// invalid colors fall back to the default for the state.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct CardPalette {
+    pub(crate) free: String,
+    pub(crate) mine: String,
+    pub(crate) others: String,
+    pub(crate) reserved: String,
+    pub(crate) maintenance: String,
+    /// Color places acquired by others and acquired resources with the [identity_color] of their owner,
+    /// instead of with `others`.
+    pub(crate) identity_colors: bool,
+}
+
+impl Default for CardPalette {
+    fn default() -> Self {
+        Self {
+            free: Self::default_hex(PlaceCardState::Free).to_string(),
+            mine: Self::default_hex(PlaceCardState::Mine).to_string(),
+            others: Self::default_hex(PlaceCardState::Others).to_string(),
+            reserved: Self::default_hex(PlaceCardState::Reserved).to_string(),
+            maintenance: Self::default_hex(PlaceCardState::Maintenance).to_string(),
+            identity_colors: true,
+        }
+    }
+}
+
+impl CardPalette {
+    /// The default color of the state as hex string.
+    pub(crate) fn default_hex(state: PlaceCardState) -> &'static str {
+        match state {
+            PlaceCardState::Free => "#43a047",
+            PlaceCardState::Mine => "#1e88e5",
+            PlaceCardState::Others => "#e53935",
+            PlaceCardState::Reserved => "#8e24aa",
+            PlaceCardState::Maintenance => "#fb8c00",
+        }
+    }
+
+    /// The color text of the state, as entered by the user.
+    pub(crate) fn hex(&self, state: PlaceCardState) -> &str {
+        match state {
+            PlaceCardState::Free => &self.free,
+            PlaceCardState::Mine => &self.mine,
+            PlaceCardState::Others => &self.others,
+            PlaceCardState::Reserved => &self.reserved,
+            PlaceCardState::Maintenance => &self.maintenance,
+        }
+    }
+
+    pub(crate) fn set_hex(&mut self, state: PlaceCardState, hex: String) {
+        match state {
+            PlaceCardState::Free => self.free = hex,
+            PlaceCardState::Mine => self.mine = hex,
+            PlaceCardState::Others => self.others = hex,
+            PlaceCardState::Reserved => self.reserved = hex,
+            PlaceCardState::Maintenance => self.maintenance = hex,
+        }
+    }
+
+    /// The color of the state, or its default if the entered text is not a valid color.
+    pub(crate) fn color(&self, state: PlaceCardState) -> Color {
+        parse_hex(self.hex(state))
+            .or_else(|| parse_hex(Self::default_hex(state)))
+            .unwrap_or(Color::BLACK)
+    }
+
+    /// The color of the card of the place for the [crate::app::AppConnected::identity].
+    pub(crate) fn place_color(&self, place: &Place, identity: &str) -> Color {
+        match (PlaceCardState::of(place, identity), &place.acquired) {
+            (PlaceCardState::Others, Some(owner)) if self.identity_colors => identity_color(owner),
+            (state, _) => self.color(state),
+        }
+    }
+
+    /// The color the `host/user` owner is displayed with, `None` if identity colors are disabled.
+    pub(crate) fn owner_color(&self, owner: &str) -> Option<Color> {
+        self.identity_colors.then(|| identity_color(owner))
+    }
+}
+
+/// The color of a `host/user` identity.
+///
+/// Derived from the name only, so that every client displays the same identity with the same color.
+/// The hue varies, saturation and lightness are fixed so that the color is readable on light and dark themes.
+pub(crate) fn identity_color(identity: &str) -> Color {
+    // FNV-1a, unlike the std hashers it is guaranteed to be stable
+    let hash = identity.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    hsl_to_color((hash % 360) as f32, 0.65, 0.5)
+}
+
+/// Converts a color in HSL, with the hue in degrees, to RGB.
+fn hsl_to_color(hue: f32, saturation: f32, lightness: f32) -> Color {
+    let chroma = (1. - (2. * lightness - 1.).abs()) * saturation;
+    let h = hue / 60.;
+    let x = chroma * (1. - (h % 2. - 1.).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.),
+        1 => (x, chroma, 0.),
+        2 => (0., chroma, x),
+        3 => (0., x, chroma),
+        4 => (x, 0., chroma),
+        _ => (chroma, 0., x),
+    };
+    let m = lightness - chroma / 2.;
+    Color::from_rgb(r + m, g + m, b + m)
+}
+
+/// The high contrast variant of the light or dark theme, for hard to read displays.
+pub(crate) fn high_contrast_theme(dark: bool) -> Theme {
+    if dark {
+        Theme::custom(
+            "High Contrast Dark",
+            Palette {
+                background: Color::BLACK,
+                text: Color::WHITE,
+                primary: Color::from_rgb8(0xff, 0xd6, 0x00),
+                success: Color::from_rgb8(0x00, 0xe6, 0x76),
+                warning: Color::from_rgb8(0xff, 0x9e, 0x00),
+                danger: Color::from_rgb8(0xff, 0x52, 0x52),
+            },
+        )
+    } else {
+        Theme::custom(
+            "High Contrast Light",
+            Palette {
+                background: Color::WHITE,
+                text: Color::BLACK,
+                primary: Color::from_rgb8(0x00, 0x24, 0xa8),
+                success: Color::from_rgb8(0x00, 0x5c, 0x1e),
+                warning: Color::from_rgb8(0x7a, 0x3d, 0x00),
+                danger: Color::from_rgb8(0xa8, 0x00, 0x00),
+            },
+        )
+    }
+}
+
+/// Parses a hex color string, returns `None` if it is invalid.
+pub(crate) fn parse_hex(hex: &str) -> Option<Color> {
+    let hex = hex.trim();
+    // Color's parser slices the string by bytes.
+    if !hex.is_ascii() {
+        return None;
+    }
+    hex.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::place;
+
+    #[test]
+    fn place_card_state() {
+        let mut place = place("board-1");
+        assert_eq!(PlaceCardState::of(&place, "host/me"), PlaceCardState::Free);
+        place.reservation = Some("ABCDEF".to_string());
+        assert_eq!(
+            PlaceCardState::of(&place, "host/me"),
+            PlaceCardState::Reserved
+        );
+        place.acquired = Some("host/me".to_string());
+        assert_eq!(PlaceCardState::of(&place, "host/me"), PlaceCardState::Mine);
+        assert_eq!(
+            PlaceCardState::of(&place, "host/other"),
+            PlaceCardState::Others
+        );
+        place
+            .tags
+            .insert(MAINTENANCE_TAG.to_string(), "true".to_string());
+        assert_eq!(
+            PlaceCardState::of(&place, "host/me"),
+            PlaceCardState::Maintenance
+        );
+    }
+
+    #[test]
+    fn identity_colors() {
+        assert_eq!(identity_color("host/alice"), identity_color("host/alice"));
+        assert_ne!(identity_color("host/alice"), identity_color("host/bob"));
+        assert_eq!(hsl_to_color(0., 1., 0.5), Color::from_rgb(1., 0., 0.));
+        assert_eq!(hsl_to_color(240., 1., 0.5), Color::from_rgb(0., 0., 1.));
+
+        let mut palette = CardPalette::default();
+        let mut place = Place {
+            acquired: Some("host/alice".to_string()),
+            ..place("board-1")
+        };
+        assert_eq!(
+            palette.place_color(&place, "host/me"),
+            identity_color("host/alice")
+        );
+        place.acquired = Some("host/me".to_string());
+        assert_eq!(
+            palette.place_color(&place, "host/me"),
+            palette.color(PlaceCardState::Mine)
+        );
+        palette.identity_colors = false;
+        assert_eq!(palette.owner_color("host/alice"), None);
+        assert_eq!(
+            palette.place_color(&place, "host/other"),
+            palette.color(PlaceCardState::Others)
+        );
+    }
+
+    #[test]
+    fn card_palette_invalid_color_falls_back() {
+        let mut palette = CardPalette::default();
+        palette.set_hex(PlaceCardState::Free, "#ff0000".to_string());
+        assert_eq!(
+            palette.color(PlaceCardState::Free),
+            Color::from_rgb8(255, 0, 0)
+        );
+        palette.set_hex(PlaceCardState::Free, "#ff00ä".to_string());
+        assert_eq!(
+            palette.color(PlaceCardState::Free),
+            parse_hex(CardPalette::default_hex(PlaceCardState::Free)).unwrap()
+        );
+    }
+
+    #[test]
+    fn high_contrast_theme_contrast() {
+        // WCAG relative luminance and contrast ratio
+        fn luminance(color: Color) -> f32 {
+            let channel = |c: f32| {
+                if c <= 0.03928 {
+                    c / 12.92
+                } else {
+                    ((c + 0.055) / 1.055).powf(2.4)
+                }
+            };
+            0.2126 * channel(color.r) + 0.7152 * channel(color.g) + 0.0722 * channel(color.b)
+        }
+        fn contrast(a: Color, b: Color) -> f32 {
+            let (a, b) = (luminance(a), luminance(b));
+            (a.max(b) + 0.05) / (a.min(b) + 0.05)
+        }
+
+        for dark in [false, true] {
+            let palette = high_contrast_theme(dark).palette();
+            assert!(contrast(palette.text, palette.background) >= 7.);
+            for color in [
+                palette.primary,
+                palette.success,
+                palette.warning,
+                palette.danger,
+            ] {
+                assert!(contrast(color, palette.background) >= 4.5, "{color:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::ACQUIRE_REASON_TAG;
+use labgrid_ui_core::pattern::MatchPattern;
+use labgrid_ui_core::types::Place;
+use std::collections::BTreeMap;
+
+/// A match pattern of the cloned place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CloneMatch {
+    pub(crate) pattern: String,
+    pub(crate) rename: Option<String>,
+}
+
+/// A new place set up like an existing one, previewed and edited before it is created.
+///
+/// Aliases and match patterns containing the name of the source place have it replaced with the new name,
+/// aliases must be unique so they can't be copied as they are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PlaceClone {
+    /// The name of the cloned place.
+    pub(crate) source: String,
+    pub(crate) name: String,
+    pub(crate) tags: BTreeMap<String, String>,
+    pub(crate) aliases: Vec<String>,
+    pub(crate) matches: Vec<CloneMatch>,
+    /// The aliases and patterns of the source place, the renamed ones are derived from.
+    source_aliases: Vec<String>,
+    source_matches: Vec<CloneMatch>,
+}
+
+/// Why a [PlaceClone] can't be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CloneProblem {
+    EmptyName,
+    NameTaken,
+    /// The alias is already used by a place or is the name of one.
+    AliasTaken(String),
+    InvalidPattern(String),
+}
+
+impl PlaceClone {
+    /// Prepares cloning the place, suggesting the next free name out of `places`.
+    ///
+    /// The acquire reason is not cloned, it only applies to the acquisition of the source place.
+    pub(crate) fn new<'a>(
+        place: &Place,
+        places: impl IntoIterator<Item = &'a Place> + Clone,
+    ) -> Self {
+        let source_matches = place
+            .matches
+            .iter()
+            .map(|m| CloneMatch {
+                pattern: m.pattern().to_string(),
+                rename: m.rename.clone(),
+            })
+            .collect();
+        let mut clone = Self {
+            source: place.name.clone(),
+            name: String::new(),
+            tags: place
+                .tags
+                .iter()
+                .filter(|(key, _)| *key != ACQUIRE_REASON_TAG)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            aliases: Vec::new(),
+            matches: Vec::new(),
+            source_aliases: place.aliases.clone(),
+            source_matches,
+        };
+        clone.set_name(suggest_name(&place.name, places));
+        clone
+    }
+
+    /// Changes the name, deriving the aliases and match patterns again.
+    pub(crate) fn set_name(&mut self, name: String) {
+        let rename = |s: &str| {
+            if self.source.is_empty() || name.trim().is_empty() {
+                s.to_string()
+            } else {
+                s.replace(&self.source, name.trim())
+            }
+        };
+        self.aliases = self.source_aliases.iter().map(|a| rename(a)).collect();
+        self.matches = self
+            .source_matches
+            .iter()
+            .map(|m| CloneMatch {
+                pattern: rename(&m.pattern),
+                rename: m.rename.clone(),
+            })
+            .collect();
+        self.name = name;
+    }
+
+    /// The problems preventing the clone from being created among the existing `places`.
+    pub(crate) fn problems<'a>(
+        &self,
+        places: impl IntoIterator<Item = &'a Place>,
+    ) -> Vec<CloneProblem> {
+        let name = self.name.trim();
+        let mut problems = Vec::new();
+        if name.is_empty() {
+            problems.push(CloneProblem::EmptyName);
+        }
+        let places = places.into_iter().collect::<Vec<_>>();
+        if places.iter().any(|p| p.name == name) {
+            problems.push(CloneProblem::NameTaken);
+        }
+        for alias in &self.aliases {
+            let taken = alias == name
+                || places
+                    .iter()
+                    .any(|p| p.name == *alias || p.aliases.contains(alias));
+            if taken {
+                problems.push(CloneProblem::AliasTaken(alias.clone()));
+            }
+        }
+        for m in &self.matches {
+            if m.pattern.parse::<MatchPattern>().is_err() {
+                problems.push(CloneProblem::InvalidPattern(m.pattern.clone()));
+            }
+        }
+        problems
+    }
+}
+
+/// Suggests a name for a place cloned from the one named `source`.
+///
+/// Increments the trailing number of the name until it is not taken by one of the `places`,
+/// names without a trailing number get one appended.
+pub(crate) fn suggest_name<'a>(
+    source: &str,
+    places: impl IntoIterator<Item = &'a Place> + Clone,
+) -> String {
+    let stem = source.trim_end_matches(|c: char| c.is_ascii_digit());
+    let (stem, start) = match source[stem.len()..].parse::<u64>() {
+        Ok(number) => (stem.to_string(), number + 1),
+        Err(_) => (format!("{source}-"), 2),
+    };
+    (start..)
+        .map(|number| format!("{stem}{number}"))
+        .find(|name| !places.clone().into_iter().any(|p| p.name == *name))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::place;
+    use labgrid_ui_core::types::ResourceMatch;
+    use std::collections::HashMap;
+
+    #[test]
+    fn place_clone() {
+        let mut source = place("imx8-1");
+        source.aliases = vec!["imx8-1-lab".to_string(), "rack3".to_string()];
+        source.tags = HashMap::from([
+            ("board".to_string(), "imx8".to_string()),
+            (ACQUIRE_REASON_TAG.to_string(), "Flashing".to_string()),
+        ]);
+        source.matches = vec![ResourceMatch {
+            exporter: "*".to_string(),
+            group: "imx8-1".to_string(),
+            cls: "NetworkSerialPort".to_string(),
+            name: None,
+            rename: Some("console".to_string()),
+        }];
+        let places = [source.clone(), place("imx8-2"), place("rpi")];
+        assert_eq!(suggest_name("rpi", &places), "rpi-2");
+
+        let mut clone = PlaceClone::new(&source, &places);
+        assert_eq!(clone.name, "imx8-3");
+        assert_eq!(
+            clone.tags,
+            BTreeMap::from([("board".to_string(), "imx8".to_string())])
+        );
+        assert_eq!(clone.aliases, vec!["imx8-3-lab", "rack3"]);
+        assert_eq!(
+            clone.matches,
+            vec![CloneMatch {
+                pattern: "*/imx8-3/NetworkSerialPort".to_string(),
+                rename: Some("console".to_string()),
+            }]
+        );
+        assert_eq!(
+            clone.problems(&places),
+            vec![CloneProblem::AliasTaken("rack3".to_string())]
+        );
+
+        clone.aliases[1] = "rack4".to_string();
+        assert!(clone.problems(&places).is_empty());
+        clone.matches[0].pattern = "imx8-3".to_string();
+        assert_eq!(
+            clone.problems(&places),
+            vec![CloneProblem::InvalidPattern("imx8-3".to_string())]
+        );
+        clone.set_name("imx8-2".to_string());
+        assert_eq!(clone.aliases, vec!["imx8-2-lab", "rack3"]);
+        assert_eq!(
+            clone.problems(&places),
+            vec![
+                CloneProblem::NameTaken,
+                CloneProblem::AliasTaken("rack3".to_string())
+            ]
+        );
+    }
+}
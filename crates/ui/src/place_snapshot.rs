@@ -0,0 +1,664 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::ACQUIRE_REASON_TAG;
+use crate::connection::ConnectionMsg;
+use chrono::{DateTime, Local};
+use labgrid_ui_core::pattern::MatchPattern;
+use labgrid_ui_core::types::Place;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The version of the snapshot format, increased on incompatible changes.
+pub(crate) const SNAPSHOT_VERSION: u32 = 1;
+
+/// The configuration of all places of a coordinator, written to a file for backing it up or migrating it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PlaceSnapshot {
+    pub(crate) version: u32,
+    /// The address of the coordinator the snapshot was taken of.
+    #[serde(default)]
+    pub(crate) coordinator: String,
+    #[serde(default)]
+    pub(crate) created: Option<DateTime<Local>>,
+    pub(crate) places: Vec<SnapshotPlace>,
+}
+
+/// The configuration of a place in a [PlaceSnapshot].
+///
+/// Everything but the name is optional, so that snapshots can also be written by hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SnapshotPlace {
+    pub(crate) name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) aliases: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub(crate) comment: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) tags: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) matches: Vec<SnapshotMatch>,
+}
+
+impl From<&Place> for SnapshotPlace {
+    /// The configuration of the place, without the acquire reason that only applies to the current acquisition.
+    fn from(place: &Place) -> Self {
+        Self {
+            name: place.name.clone(),
+            aliases: place.aliases.clone(),
+            comment: place.comment.clone(),
+            tags: place
+                .tags
+                .iter()
+                .filter(|(key, _)| *key != ACQUIRE_REASON_TAG)
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            matches: place
+                .matches
+                .iter()
+                .map(|m| SnapshotMatch {
+                    pattern: m.pattern().to_string(),
+                    rename: m.rename.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A match pattern of a [SnapshotPlace].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct SnapshotMatch {
+    pub(crate) pattern: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) rename: Option<String>,
+}
+
+/// A snapshot read from the file at `path`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SnapshotFile {
+    pub(crate) path: PathBuf,
+    pub(crate) snapshot: PlaceSnapshot,
+}
+
+/// A change of the coordinator restoring a [PlaceSnapshot].
+///
+/// Restoring only adds to the configuration, places, aliases, tags and matches missing from the snapshot are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RestoreStep {
+    AddPlace {
+        place: String,
+    },
+    AddAlias {
+        place: String,
+        alias: String,
+    },
+    SetComment {
+        place: String,
+        comment: String,
+    },
+    /// Adds the tag or changes its value from `current`.
+    SetTag {
+        place: String,
+        key: String,
+        value: String,
+        current: Option<String>,
+    },
+    AddMatch {
+        place: String,
+        pattern: String,
+        rename: Option<String>,
+    },
+}
+
+impl RestoreStep {
+    /// The name of the changed place.
+    pub(crate) fn place(&self) -> &str {
+        match self {
+            Self::AddPlace { place }
+            | Self::AddAlias { place, .. }
+            | Self::SetComment { place, .. }
+            | Self::SetTag { place, .. }
+            | Self::AddMatch { place, .. } => place,
+        }
+    }
+}
+
+impl std::fmt::Display for RestoreStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AddPlace { place } => write!(f, "Add place '{place}'"),
+            Self::AddAlias { place, alias } => write!(f, "Add alias '{alias}' to '{place}'"),
+            Self::SetComment { place, comment } => {
+                write!(f, "Set comment of '{place}' to '{comment}'")
+            }
+            Self::SetTag {
+                place, key, value, ..
+            } => write!(f, "Set tag '{key}={value}' of '{place}'"),
+            Self::AddMatch {
+                place,
+                pattern,
+                rename: Some(rename),
+            } => write!(f, "Add match '{pattern}' -> '{rename}' to '{place}'"),
+            Self::AddMatch { place, pattern, .. } => {
+                write!(f, "Add match '{pattern}' to '{place}'")
+            }
+        }
+    }
+}
+
+impl PlaceSnapshot {
+    /// Takes a snapshot of the places of the coordinator at `coordinator`, sorted by name.
+    pub(crate) fn new<'a>(
+        coordinator: String,
+        places: impl IntoIterator<Item = &'a Place>,
+        created: DateTime<Local>,
+    ) -> Self {
+        let mut places = places
+            .into_iter()
+            .map(SnapshotPlace::from)
+            .collect::<Vec<_>>();
+        places.sort_by(|a, b| a.name.cmp(&b.name));
+        Self {
+            version: SNAPSHOT_VERSION,
+            coordinator,
+            created: Some(created),
+            places,
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Parses a snapshot, failing for newer versions of the format and invalid match patterns.
+    pub(crate) fn from_json(json: &str) -> Result<Self, String> {
+        let snapshot = serde_json::from_str::<Self>(json).map_err(|e| e.to_string())?;
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(format!(
+                "Unsupported snapshot version {}, the latest supported is {SNAPSHOT_VERSION}",
+                snapshot.version
+            ));
+        }
+        for place in &snapshot.places {
+            if place.name.trim().is_empty() {
+                return Err("Place with an empty name".to_string());
+            }
+            for m in &place.matches {
+                if let Err(e) = m.pattern.parse::<MatchPattern>() {
+                    return Err(format!("Place '{}': {e}", place.name));
+                }
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// The steps restoring the snapshot on a coordinator with the current `places`, in the order of the snapshot.
+    ///
+    /// Empty if the places already contain everything of the snapshot.
+    pub(crate) fn restore_steps<'a>(
+        &self,
+        places: impl IntoIterator<Item = &'a Place> + Clone,
+    ) -> Vec<RestoreStep> {
+        let mut steps = Vec::new();
+        for snapshot_place in &self.places {
+            let name = snapshot_place.name.trim();
+            let current = places.clone().into_iter().find(|p| p.name == name);
+            if current.is_none() {
+                steps.push(RestoreStep::AddPlace {
+                    place: name.to_string(),
+                });
+            }
+            for alias in &snapshot_place.aliases {
+                if !current.is_some_and(|p| p.aliases.contains(alias)) {
+                    steps.push(RestoreStep::AddAlias {
+                        place: name.to_string(),
+                        alias: alias.clone(),
+                    });
+                }
+            }
+            let current_comment = current.map(|p| p.comment.as_str()).unwrap_or_default();
+            if !snapshot_place.comment.is_empty() && snapshot_place.comment != current_comment {
+                steps.push(RestoreStep::SetComment {
+                    place: name.to_string(),
+                    comment: snapshot_place.comment.clone(),
+                });
+            }
+            for (key, value) in &snapshot_place.tags {
+                let current_value = current.and_then(|p| p.tags.get(key));
+                if current_value != Some(value) {
+                    steps.push(RestoreStep::SetTag {
+                        place: name.to_string(),
+                        key: key.clone(),
+                        value: value.clone(),
+                        current: current_value.cloned(),
+                    });
+                }
+            }
+            for m in &snapshot_place.matches {
+                let pattern = normalized_pattern(&m.pattern);
+                let exists = current.is_some_and(|p| {
+                    p.matches
+                        .iter()
+                        .any(|cm| cm.pattern().to_string() == pattern && cm.rename == m.rename)
+                });
+                if !exists {
+                    steps.push(RestoreStep::AddMatch {
+                        place: name.to_string(),
+                        pattern,
+                        rename: m.rename.clone(),
+                    });
+                }
+            }
+        }
+        steps
+    }
+}
+
+/// A difference of a place between the coordinator and a [PlaceSnapshot].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PlaceDifference {
+    /// The place only exists in the snapshot.
+    MissingPlace,
+    /// The place only exists on the coordinator.
+    ExtraPlace,
+    MissingAlias(String),
+    ExtraAlias(String),
+    Comment {
+        snapshot: String,
+        current: String,
+    },
+    /// The tag is missing on the coordinator if `current` is `None`, otherwise it has a different value.
+    Tag {
+        key: String,
+        snapshot: String,
+        current: Option<String>,
+    },
+    ExtraTag {
+        key: String,
+        value: String,
+    },
+    MissingMatch(SnapshotMatch),
+    ExtraMatch(SnapshotMatch),
+}
+
+impl PlaceDifference {
+    /// Whether applying the difference removes something from the coordinator.
+    pub(crate) fn is_removal(&self) -> bool {
+        matches!(
+            self,
+            Self::ExtraPlace | Self::ExtraAlias(_) | Self::ExtraTag { .. } | Self::ExtraMatch(_)
+        )
+    }
+}
+
+/// The differences of the place named `place`, see [PlaceSnapshot::compare].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PlaceDiff {
+    pub(crate) place: String,
+    pub(crate) differences: Vec<PlaceDifference>,
+}
+
+impl PlaceSnapshot {
+    /// Compares the current `places` with the snapshot in both directions.
+    ///
+    /// Returns the places that differ, sorted by name. Places missing on either side are a single difference.
+    pub(crate) fn compare<'a>(
+        &self,
+        places: impl IntoIterator<Item = &'a Place>,
+    ) -> Vec<PlaceDiff> {
+        let mut current = places
+            .into_iter()
+            .map(|place| (place.name.clone(), SnapshotPlace::from(place)))
+            .collect::<BTreeMap<_, _>>();
+        let mut diffs = Vec::new();
+        for snapshot_place in &self.places {
+            let name = snapshot_place.name.trim().to_string();
+            let differences = match current.remove(&name) {
+                Some(current_place) => compare_place(snapshot_place, &current_place),
+                None => vec![PlaceDifference::MissingPlace],
+            };
+            if !differences.is_empty() {
+                diffs.push(PlaceDiff {
+                    place: name,
+                    differences,
+                });
+            }
+        }
+        diffs.extend(current.into_keys().map(|place| PlaceDiff {
+            place,
+            differences: vec![PlaceDifference::ExtraPlace],
+        }));
+        diffs.sort_by(|a, b| a.place.cmp(&b.place));
+        diffs
+    }
+
+    /// The message making the place on the coordinator match the snapshot in the `difference`.
+    ///
+    /// A missing place is created with its whole configuration.
+    pub(crate) fn apply_msg(&self, place: &str, difference: &PlaceDifference) -> ConnectionMsg {
+        let place_name = place.to_string();
+        match difference.clone() {
+            PlaceDifference::MissingPlace => ConnectionMsg::RestorePlaces {
+                steps: self
+                    .restore_steps(std::iter::empty())
+                    .into_iter()
+                    .filter(|step| step.place() == place)
+                    .collect(),
+            },
+            PlaceDifference::ExtraPlace => ConnectionMsg::DeletePlace { name: place_name },
+            PlaceDifference::MissingAlias(alias) => {
+                ConnectionMsg::AddPlaceAlias { place_name, alias }
+            }
+            PlaceDifference::ExtraAlias(alias) => {
+                ConnectionMsg::DeletePlaceAlias { place_name, alias }
+            }
+            PlaceDifference::Comment { snapshot, .. } => ConnectionMsg::SetPlaceComment {
+                place_name,
+                comment: snapshot,
+            },
+            PlaceDifference::Tag { key, snapshot, .. } => ConnectionMsg::AddPlaceTag {
+                place_name,
+                tag: (key, snapshot),
+            },
+            PlaceDifference::ExtraTag { key, .. } => ConnectionMsg::DeletePlaceTag {
+                place_name,
+                tag: key,
+            },
+            PlaceDifference::MissingMatch(m) => ConnectionMsg::AddPlaceMatch {
+                place_name,
+                pattern: m.pattern,
+                rename: m.rename,
+            },
+            PlaceDifference::ExtraMatch(m) => ConnectionMsg::DeletePlaceMatch {
+                place_name,
+                pattern: m.pattern,
+                rename: m.rename,
+            },
+        }
+    }
+}
+
+/// The differences of a place of the snapshot with the `current` one on the coordinator.
+fn compare_place(snapshot: &SnapshotPlace, current: &SnapshotPlace) -> Vec<PlaceDifference> {
+    let mut differences = Vec::new();
+    differences.extend(
+        snapshot
+            .aliases
+            .iter()
+            .filter(|alias| !current.aliases.contains(alias))
+            .map(|alias| PlaceDifference::MissingAlias(alias.clone())),
+    );
+    differences.extend(
+        current
+            .aliases
+            .iter()
+            .filter(|alias| !snapshot.aliases.contains(alias))
+            .map(|alias| PlaceDifference::ExtraAlias(alias.clone())),
+    );
+    if snapshot.comment != current.comment {
+        differences.push(PlaceDifference::Comment {
+            snapshot: snapshot.comment.clone(),
+            current: current.comment.clone(),
+        });
+    }
+    for (key, value) in &snapshot.tags {
+        let current_value = current.tags.get(key);
+        if current_value != Some(value) {
+            differences.push(PlaceDifference::Tag {
+                key: key.clone(),
+                snapshot: value.clone(),
+                current: current_value.cloned(),
+            });
+        }
+    }
+    differences.extend(
+        current
+            .tags
+            .iter()
+            .filter(|(key, _)| !snapshot.tags.contains_key(*key))
+            .map(|(key, value)| PlaceDifference::ExtraTag {
+                key: key.clone(),
+                value: value.clone(),
+            }),
+    );
+    let snapshot_matches = snapshot
+        .matches
+        .iter()
+        .map(|m| SnapshotMatch {
+            pattern: normalized_pattern(&m.pattern),
+            rename: m.rename.clone(),
+        })
+        .collect::<Vec<_>>();
+    differences.extend(
+        snapshot_matches
+            .iter()
+            .filter(|m| !current.matches.contains(m))
+            .map(|m| PlaceDifference::MissingMatch(m.clone())),
+    );
+    differences.extend(
+        current
+            .matches
+            .iter()
+            .filter(|m| !snapshot_matches.contains(m))
+            .map(|m| PlaceDifference::ExtraMatch(m.clone())),
+    );
+    differences
+}
+
+/// The pattern as formatted by the coordinator, e.g. without surrounding whitespace.
+fn normalized_pattern(pattern: &str) -> String {
+    pattern
+        .parse::<MatchPattern>()
+        .map(|p| p.to_string())
+        .unwrap_or_else(|_| pattern.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::place;
+    use labgrid_ui_core::types::ResourceMatch;
+    use std::collections::HashMap;
+
+    #[test]
+    fn place_snapshot_roundtrip() {
+        let mut board = place("board-1");
+        board.aliases = vec!["rack3".to_string()];
+        board.comment = "imx8 eval board".to_string();
+        board.tags = HashMap::from([
+            ("board".to_string(), "imx8".to_string()),
+            (ACQUIRE_REASON_TAG.to_string(), "Flashing".to_string()),
+        ]);
+        board.matches = vec![ResourceMatch {
+            exporter: "*".to_string(),
+            group: "board-1".to_string(),
+            cls: "NetworkSerialPort".to_string(),
+            name: None,
+            rename: Some("console".to_string()),
+        }];
+        let places = [place("rpi"), board];
+        let snapshot = PlaceSnapshot::new("localhost:20408".to_string(), &places, Local::now());
+        assert_eq!(
+            snapshot
+                .places
+                .iter()
+                .map(|p| p.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["board-1", "rpi"]
+        );
+        assert_eq!(
+            snapshot.places[0].tags,
+            BTreeMap::from([("board".to_string(), "imx8".to_string())])
+        );
+        assert_eq!(
+            snapshot.places[0].matches,
+            vec![SnapshotMatch {
+                pattern: "*/board-1/NetworkSerialPort".to_string(),
+                rename: Some("console".to_string()),
+            }]
+        );
+
+        let json = snapshot.to_json();
+        assert_eq!(PlaceSnapshot::from_json(&json), Ok(snapshot.clone()));
+        // Restoring the snapshot of the places changes nothing
+        assert!(snapshot.restore_steps(&places).is_empty());
+
+        assert!(PlaceSnapshot::from_json(r#"{"version": 2, "places": []}"#).is_err());
+        assert!(PlaceSnapshot::from_json(
+            r#"{"version": 1, "places": [{"name": "a", "matches": [{"pattern": "a/b/c/d/e"}]}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn place_snapshot_compare() {
+        let snapshot = PlaceSnapshot::from_json(
+            r#"{
+                "version": 1,
+                "places": [
+                    {
+                        "name": "board-1",
+                        "aliases": ["rack3"],
+                        "tags": {"board": "imx8", "lab": "north"},
+                        "matches": [{"pattern": " */board-1/NetworkSerialPort "}]
+                    },
+                    {"name": "board-2"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut board = place("board-1");
+        board.aliases = vec!["rack4".to_string()];
+        board.tags = HashMap::from([
+            ("lab".to_string(), "south".to_string()),
+            ("owner".to_string(), "ci".to_string()),
+            (ACQUIRE_REASON_TAG.to_string(), "Flashing".to_string()),
+        ]);
+        board.matches = vec![ResourceMatch {
+            exporter: "*".to_string(),
+            group: "board-1".to_string(),
+            cls: "NetworkSerialPort".to_string(),
+            name: None,
+            rename: None,
+        }];
+        let places = [board, place("rpi")];
+
+        let diffs = snapshot.compare(&places);
+        assert_eq!(
+            diffs,
+            vec![
+                PlaceDiff {
+                    place: "board-1".to_string(),
+                    differences: vec![
+                        PlaceDifference::MissingAlias("rack3".to_string()),
+                        PlaceDifference::ExtraAlias("rack4".to_string()),
+                        PlaceDifference::Tag {
+                            key: "board".to_string(),
+                            snapshot: "imx8".to_string(),
+                            current: None,
+                        },
+                        PlaceDifference::Tag {
+                            key: "lab".to_string(),
+                            snapshot: "north".to_string(),
+                            current: Some("south".to_string()),
+                        },
+                        PlaceDifference::ExtraTag {
+                            key: "owner".to_string(),
+                            value: "ci".to_string(),
+                        },
+                    ],
+                },
+                PlaceDiff {
+                    place: "board-2".to_string(),
+                    differences: vec![PlaceDifference::MissingPlace],
+                },
+                PlaceDiff {
+                    place: "rpi".to_string(),
+                    differences: vec![PlaceDifference::ExtraPlace],
+                },
+            ]
+        );
+
+        assert!(matches!(
+            snapshot.apply_msg("board-1", &diffs[0].differences[3]),
+            ConnectionMsg::AddPlaceTag { place_name, tag }
+                if place_name == "board-1" && tag == ("lab".to_string(), "north".to_string())
+        ));
+        assert!(matches!(
+            snapshot.apply_msg("board-2", &PlaceDifference::MissingPlace),
+            ConnectionMsg::RestorePlaces { steps }
+                if steps == [RestoreStep::AddPlace { place: "board-2".to_string() }]
+        ));
+        assert!(matches!(
+            snapshot.apply_msg("rpi", &PlaceDifference::ExtraPlace),
+            ConnectionMsg::DeletePlace { name } if name == "rpi"
+        ));
+    }
+
+    #[test]
+    fn place_snapshot_restore_steps() {
+        let snapshot = PlaceSnapshot::from_json(
+            r#"{
+                "version": 1,
+                "places": [
+                    {
+                        "name": "board-1",
+                        "aliases": ["rack3"],
+                        "comment": "imx8 eval board",
+                        "tags": {"board": "imx8", "lab": "north"},
+                        "matches": [{"pattern": "*/board-1/NetworkSerialPort", "rename": "console"}]
+                    },
+                    {"name": "board-2", "tags": {"board": "rpi"}}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut board = place("board-1");
+        board.aliases = vec!["rack3".to_string()];
+        board.comment = "Broken".to_string();
+        board.tags = HashMap::from([
+            ("board".to_string(), "imx8".to_string()),
+            ("lab".to_string(), "south".to_string()),
+            ("owner".to_string(), "ci".to_string()),
+        ]);
+        board.matches = vec![ResourceMatch {
+            exporter: "*".to_string(),
+            group: "board-1".to_string(),
+            cls: "NetworkSerialPort".to_string(),
+            name: None,
+            rename: None,
+        }];
+
+        assert_eq!(
+            snapshot.restore_steps(&[board]),
+            vec![
+                RestoreStep::SetComment {
+                    place: "board-1".to_string(),
+                    comment: "imx8 eval board".to_string(),
+                },
+                RestoreStep::SetTag {
+                    place: "board-1".to_string(),
+                    key: "lab".to_string(),
+                    value: "north".to_string(),
+                    current: Some("south".to_string()),
+                },
+                RestoreStep::AddMatch {
+                    place: "board-1".to_string(),
+                    pattern: "*/board-1/NetworkSerialPort".to_string(),
+                    rename: Some("console".to_string()),
+                },
+                RestoreStep::AddPlace {
+                    place: "board-2".to_string(),
+                },
+                RestoreStep::SetTag {
+                    place: "board-2".to_string(),
+                    key: "board".to_string(),
+                    value: "rpi".to_string(),
+                    current: None,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::connection::SubscriptionScope;
+use crate::i18n::fl;
+use crate::util::IdentityOverride;
+
+/// The filters of the tabs that are applied when connecting with a profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ProfileFilters {
+    pub(crate) places_only_mine: bool,
+    pub(crate) resources_only_available: bool,
+}
+
+impl Default for ProfileFilters {
+    fn default() -> Self {
+        Self {
+            places_only_mine: false,
+            resources_only_available: true,
+        }
+    }
+}
+
+/// A named set of settings for connecting to a coordinator, selected before connecting.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ConnectionProfile {
+    pub(crate) name: String,
+    pub(crate) address: String,
+    /// Overrides of `LG_HOSTNAME`/`LG_USERNAME` for this profile.
+    ///
+    /// Empty fields fall back to the overrides in the settings.
+    pub(crate) identity: IdentityOverride,
+    /// Connect through TLS (`https://`) when the address has no scheme.
+    pub(crate) tls: bool,
+    pub(crate) scope: SubscriptionScope,
+    pub(crate) filters: ProfileFilters,
+}
+
+impl Default for ConnectionProfile {
+    fn default() -> Self {
+        Self {
+            name: fl!("profile-default-name"),
+            address: String::new(),
+            identity: IdentityOverride::default(),
+            tls: false,
+            scope: SubscriptionScope::default(),
+            filters: ProfileFilters::default(),
+        }
+    }
+}
+
+impl ConnectionProfile {
+    /// The address to connect to, with the `https://` scheme when TLS is enabled and the address has no scheme.
+    pub(crate) fn uri(&self, address: &str) -> String {
+        let address = address.trim();
+        if self.tls && !address.contains("://") {
+            format!("https://{address}")
+        } else {
+            address.to_string()
+        }
+    }
+
+    /// The identity overrides of the profile, empty fields fall back to `fallback`.
+    pub(crate) fn identity_override(&self, fallback: &IdentityOverride) -> IdentityOverride {
+        let or_fallback = |value: &str, fallback: &str| {
+            if value.trim().is_empty() {
+                fallback.to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        IdentityOverride {
+            hostname: or_fallback(&self.identity.hostname, &fallback.hostname),
+            username: or_fallback(&self.identity.username, &fallback.username),
+        }
+    }
+}
+
+/// A profile name that is not used by any of the profiles, derived from `name`.
+pub(crate) fn unique_name(profiles: &[ConnectionProfile], name: &str) -> String {
+    let taken = |candidate: &str| profiles.iter().any(|p| p.name == candidate);
+    if !taken(name) {
+        return name.to_string();
+    }
+    (2..)
+        .map(|n| format!("{name} {n}"))
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_connect_settings() {
+        let profile = ConnectionProfile {
+            tls: true,
+            identity: IdentityOverride {
+                hostname: String::new(),
+                username: "ci".to_string(),
+            },
+            ..ConnectionProfile::default()
+        };
+        assert_eq!(profile.uri(" lab:20408 "), "https://lab:20408");
+        assert_eq!(profile.uri("unix:///run/lg.sock"), "unix:///run/lg.sock");
+        assert_eq!(
+            profile.identity_override(&IdentityOverride {
+                hostname: "kiosk".to_string(),
+                username: "alice".to_string(),
+            }),
+            IdentityOverride {
+                hostname: "kiosk".to_string(),
+                username: "ci".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn profile_unique_name() {
+        let profiles = [
+            ConnectionProfile {
+                name: "Lab".to_string(),
+                ..ConnectionProfile::default()
+            },
+            ConnectionProfile {
+                name: "Lab 2".to_string(),
+                ..ConnectionProfile::default()
+            },
+        ];
+        assert_eq!(unique_name(&profiles, "CI"), "CI");
+        assert_eq!(unique_name(&profiles, "Lab"), "Lab 3");
+    }
+}
@@ -0,0 +1,70 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, ProtocolInspectorMsg};
+use chrono::{DateTime, Local};
+use iced::futures;
+use labgrid_ui_core::trace::{MessageTrace, TracedMessage};
+use std::sync::LazyLock;
+use std::time::Duration;
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use tokio_stream::StreamExt;
+
+/// The number of messages shown in the inspector, the newest matching ones are shown.
+pub(crate) const SHOWN_MESSAGES: usize = 200;
+
+/// The trace of the messages exchanged with the coordinator, shared with the clients of the connection.
+///
+/// It records only while enabled in the protocol inspector.
+pub(crate) static PROTOCOL_TRACE: LazyLock<MessageTrace> = LazyLock::new(MessageTrace::default);
+
+/// Whether the message type or the message contains the filter, ignoring the case.
+pub(crate) fn matches_filter(traced: &TracedMessage, filter: &str) -> bool {
+    let filter = filter.trim().to_lowercase();
+    filter.is_empty()
+        || traced.message_type.to_lowercase().contains(&filter)
+        || traced.message.to_lowercase().contains(&filter)
+}
+
+/// The message formatted as a line of the inspector, with the local time it was traced at.
+pub(crate) fn format_message(traced: &TracedMessage) -> String {
+    format!(
+        "{} {} {}: {}",
+        DateTime::<Local>::from(traced.time).format("%H:%M:%S%.3f"),
+        traced.direction,
+        traced.message_type,
+        traced.message
+    )
+}
+
+/// Periodically refreshes the messages shown in the protocol inspector.
+pub(crate) fn refresh_subscription() -> impl futures::Stream<Item = AppMsg> {
+    const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+    IntervalStream::new(time::interval(REFRESH_INTERVAL))
+        .map(|_| AppMsg::ProtocolInspector(ProtocolInspectorMsg::Refresh))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use labgrid_ui_core::trace::Direction;
+    use std::time::SystemTime;
+
+    #[test]
+    fn protocol_inspector_filter() {
+        let traced = TracedMessage {
+            time: SystemTime::now(),
+            direction: Direction::Received,
+            message_type: "ClientOutMessage",
+            message: "ClientOutMessage { sync: Some(Sync { id: 7 }) }".to_string(),
+        };
+        assert!(matches_filter(&traced, ""));
+        assert!(matches_filter(&traced, " clientout "));
+        assert!(matches_filter(&traced, "id: 7"));
+        assert!(!matches_filter(&traced, "GetPlaces"));
+        assert!(format_message(&traced).contains(" <- ClientOutMessage: ClientOutMessage {"));
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, PlaceUi};
+use crate::history::PlaceActivityLog;
+use crate::util;
+use chrono::{DateTime, Local};
+use iced::futures;
+use labgrid_ui_core::types::{Place, Reservation, ReservationState};
+use std::cmp::Ordering;
+use std::time::Duration;
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use tokio_stream::StreamExt;
+
+/// The estimated queue position of a waiting reservation.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct QueueEstimate {
+    /// The position, starting at 1, among the waiting reservations competing for the same places.
+    pub(crate) position: usize,
+    pub(crate) matching_places: usize,
+    /// The matching places that are neither acquired nor allocated to a reservation.
+    pub(crate) free_places: usize,
+    /// `None` if no acquisitions of the matching places were observed yet to base the estimation on.
+    pub(crate) expected_wait: Option<Duration>,
+}
+
+/// Estimates the queue position of the reservation, if it is waiting.
+///
+/// Waiting reservations are scheduled by the coordinator with the higher priority first, then the older first.
+/// Only reservations ahead that match at least one of the same places are counted.
+/// The expected wait is derived from the observed acquisition durations of the matching places in `activity`.
+pub(crate) fn estimate(
+    reservation: &Reservation,
+    reservations: &[Reservation],
+    places: &[(Place, PlaceUi)],
+    activity: &PlaceActivityLog,
+) -> Option<QueueEstimate> {
+    if reservation.reservation_state() != Some(ReservationState::Waiting) {
+        return None;
+    }
+    let matching = matching_places(reservation, places).collect::<Vec<&Place>>();
+    let free_places = matching
+        .iter()
+        .filter(|p| p.acquired.is_none() && p.reservation.as_ref().map_or(true, |r| r.is_empty()))
+        .count();
+    let ahead = reservations
+        .iter()
+        .filter(|r| {
+            r.token != reservation.token
+                && r.reservation_state() == Some(ReservationState::Waiting)
+                && schedule_order(r, reservation) == Ordering::Less
+                && matching_places(r, places).any(|p| matching.iter().any(|m| m.name == p.name))
+        })
+        .count();
+    let position = ahead + 1;
+
+    let expected_wait = if position == 1 && free_places >= reservation.filters.len() {
+        Some(Duration::ZERO)
+    } else {
+        let durations = matching
+            .iter()
+            .flat_map(|p| activity.acquisition_durations(&p.name))
+            .filter_map(|d| d.to_std().ok())
+            .collect::<Vec<Duration>>();
+        (!durations.is_empty() && !matching.is_empty()).then(|| {
+            let mean = durations.iter().sum::<Duration>() / durations.len() as u32;
+            mean.mul_f64(position as f64 / matching.len() as f64)
+        })
+    };
+
+    Some(QueueEstimate {
+        position,
+        matching_places: matching.len(),
+        free_places,
+        expected_wait,
+    })
+}
+
+/// The places matched by any of the filters of the reservation.
+fn matching_places<'a>(
+    reservation: &'a Reservation,
+    places: &'a [(Place, PlaceUi)],
+) -> impl Iterator<Item = &'a Place> {
+    places
+        .iter()
+        .map(|(p, _)| p)
+        .filter(|p| reservation.filters.values().any(|f| f.matches(&p.tags)))
+}
+
+/// The time left at `now` until the reservation times out, `None` if it already did or has no timeout.
+pub(crate) fn time_remaining(reservation: &Reservation, now: DateTime<Local>) -> Option<Duration> {
+    let timeout = util::local_time(reservation.timeout)?;
+    (timeout - now).to_std().ok().filter(|d| !d.is_zero())
+}
+
+/// An iced subscription that triggers `AppMsg::ReservationsCountdownTick` messages while reservations count down.
+pub(crate) fn countdown_tick_subscription() -> impl futures::Stream<Item = AppMsg> {
+    const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+    IntervalStream::new(time::interval(TICK_INTERVAL)).map(|_| AppMsg::ReservationsCountdownTick)
+}
+
+/// Orders reservations like the coordinator schedules them.
+fn schedule_order(a: &Reservation, b: &Reservation) -> Ordering {
+    b.prio
+        .total_cmp(&a.prio)
+        .then(a.created.total_cmp(&b.created))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use chrono::{Local, TimeZone};
+    use std::collections::HashMap;
+
+    fn place(name: &str, board: &str, acquired: Option<&str>) -> (Place, PlaceUi) {
+        let place = Place {
+            tags: HashMap::from([("board".to_string(), board.to_string())]),
+            acquired: acquired.map(ToOwned::to_owned),
+            ..test_fixtures::place(name)
+        };
+        (place, PlaceUi::default())
+    }
+
+    fn reservation(token: &str, board: &str, prio: f64, created: f64) -> Reservation {
+        Reservation {
+            filters: test_fixtures::board_filter(board),
+            prio,
+            created,
+            ..test_fixtures::reservation(token)
+        }
+    }
+
+    #[test]
+    fn queue_estimate() {
+        let places = vec![
+            place("board-1", "imx8", Some("host/alice")),
+            place("board-2", "imx8", Some("host/bob")),
+            place("board-3", "rpi", None),
+        ];
+        let reservations = vec![
+            reservation("A", "imx8", 0., 1.),
+            // Higher priority goes first, regardless of age
+            reservation("B", "imx8", 1., 2.),
+            // Different places, not competing
+            reservation("C", "rpi", 0., 0.),
+        ];
+        let mut activity = PlaceActivityLog::default();
+        let mut acquired = places[0].0.clone();
+        acquired.acquired = Some("host/carol".to_string());
+        let mut released = acquired.clone();
+        released.acquired = None;
+        let time = Local.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        activity.record(&released, &acquired, time);
+        activity.record(&acquired, &released, time + chrono::Duration::minutes(20));
+
+        assert_eq!(
+            estimate(&reservations[0], &reservations, &places, &activity),
+            Some(QueueEstimate {
+                position: 2,
+                matching_places: 2,
+                free_places: 0,
+                expected_wait: Some(Duration::from_secs(20 * 60)),
+            })
+        );
+        assert_eq!(
+            estimate(&reservations[2], &reservations, &places, &activity),
+            Some(QueueEstimate {
+                position: 1,
+                matching_places: 1,
+                free_places: 1,
+                expected_wait: Some(Duration::ZERO),
+            })
+        );
+    }
+
+    #[test]
+    fn reservation_time_remaining() {
+        let now = Local.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        let mut reservation = reservation("A", "imx8", 0., now.timestamp() as f64);
+        // No timeout set
+        assert_eq!(time_remaining(&reservation, now), None);
+
+        reservation.timeout = now.timestamp() as f64 + 60.5;
+        assert_eq!(
+            time_remaining(&reservation, now),
+            Some(Duration::from_millis(60_500))
+        );
+        assert_eq!(
+            time_remaining(&reservation, now + chrono::Duration::minutes(2)),
+            None
+        );
+    }
+}
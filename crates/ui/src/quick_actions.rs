@@ -0,0 +1,76 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+/// The buttons that can be shown directly on the place cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QuickAction {
+    /// Acquire or release the place.
+    Acquire,
+    /// Power cycle the place through `labgrid-client`.
+    PowerCycle,
+    /// Copy the `labgrid-client` command to connect to the console of the place.
+    Console,
+    /// Execute the favorite script with `LG_PLACE` set to the place.
+    FavoriteScript,
+}
+
+impl QuickAction {
+    pub(crate) const ALL: [Self; 4] = [
+        Self::Acquire,
+        Self::PowerCycle,
+        Self::Console,
+        Self::FavoriteScript,
+    ];
+}
+
+/// Which quick actions are shown on the place cards.
+///
+/// Only acquiring is enabled by default to keep the cards compact.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct QuickActionSettings {
+    pub(crate) acquire: bool,
+    pub(crate) power_cycle: bool,
+    pub(crate) console: bool,
+    pub(crate) favorite_script: bool,
+    /// The file name of the favorite script in the scripts directory.
+    pub(crate) favorite_script_name: Option<String>,
+}
+
+impl Default for QuickActionSettings {
+    fn default() -> Self {
+        Self {
+            acquire: true,
+            power_cycle: false,
+            console: false,
+            favorite_script: false,
+            favorite_script_name: None,
+        }
+    }
+}
+
+impl QuickActionSettings {
+    pub(crate) fn enabled(&self, action: QuickAction) -> bool {
+        match action {
+            QuickAction::Acquire => self.acquire,
+            QuickAction::PowerCycle => self.power_cycle,
+            QuickAction::Console => self.console,
+            QuickAction::FavoriteScript => self.favorite_script,
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, action: QuickAction, enabled: bool) {
+        match action {
+            QuickAction::Acquire => self.acquire = enabled,
+            QuickAction::PowerCycle => self.power_cycle = enabled,
+            QuickAction::Console => self.console = enabled,
+            QuickAction::FavoriteScript => self.favorite_script = enabled,
+        }
+    }
+}
+
+/// The `labgrid-client` command to connect to the console of the place.
+pub(crate) fn console_command(place_name: &str) -> String {
+    format!("labgrid-client -p '{place_name}' console")
+}
@@ -0,0 +1,232 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use anyhow::Context;
+use labgrid_ui_core::types::{MapValue, Resource};
+
+/// The port of `NetworkService`s without a `port` param.
+const DEFAULT_SSH_PORT: u64 = 22;
+/// The speed of `NetworkSerialPort`s without a `speed` param.
+const DEFAULT_SERIAL_SPEED: u64 = 115200;
+
+/// A command line connecting to a resource, offered on the resource cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct QuickConnect {
+    pub(crate) program: &'static str,
+    pub(crate) args: Vec<String>,
+}
+
+impl QuickConnect {
+    /// The command connecting to the resource, built from its params.
+    ///
+    /// - `NetworkService`: `ssh` to the `address` as `username`
+    /// - `NetworkSerialPort`: `microcom` to the `host` and `port`, or `telnet` for the `raw` protocol
+    ///
+    /// `None` for other classes, or if the params are missing. Params starting with `-` are rejected as well,
+    /// they are set by the exporter and must not be parsed as options of the command.
+    pub(crate) fn for_resource(resource: &Resource) -> Option<Self> {
+        match resource.cls.as_str() {
+            "NetworkService" => {
+                let address = param_arg(resource, "address")?;
+                let port = param_u64(resource, "port").unwrap_or(DEFAULT_SSH_PORT);
+                let destination = match resource.params.get("username") {
+                    Some(_) => format!("{}@{address}", param_arg(resource, "username")?),
+                    None => address.to_string(),
+                };
+                let mut args = Vec::new();
+                if port != DEFAULT_SSH_PORT {
+                    args.extend(["-p".to_string(), port.to_string()]);
+                }
+                args.extend(["--".to_string(), destination]);
+                Some(Self {
+                    program: "ssh",
+                    args,
+                })
+            }
+            "NetworkSerialPort" => {
+                let host = param_arg(resource, "host")?;
+                let port = param_u64(resource, "port")?;
+                if param_string(resource, "protocol") == Some("raw") {
+                    return Some(Self {
+                        program: "telnet",
+                        args: vec!["--".to_string(), host.to_string(), port.to_string()],
+                    });
+                }
+                let speed = param_u64(resource, "speed").unwrap_or(DEFAULT_SERIAL_SPEED);
+                Some(Self {
+                    program: "microcom",
+                    args: vec![
+                        "-s".to_string(),
+                        speed.to_string(),
+                        "-t".to_string(),
+                        format!("{host}:{port}"),
+                    ],
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// The command line for a POSIX shell, with arguments quoted where needed.
+    pub(crate) fn command_line(&self) -> String {
+        std::iter::once(self.program.to_string())
+            .chain(self.args.iter().map(|arg| shell_quote(arg)))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    /// Runs the command in the `terminal` emulator, without waiting for it to exit.
+    ///
+    /// `terminal` is the emulator's command line that the command is appended to, split at whitespace
+    /// (e.g. `x-terminal-emulator -e` or `gnome-terminal --`).
+    pub(crate) fn launch(&self, terminal: &str) -> anyhow::Result<()> {
+        let mut terminal_args = terminal.split_whitespace();
+        let terminal_program = terminal_args
+            .next()
+            .context("No terminal emulator configured")?;
+        std::process::Command::new(terminal_program)
+            .args(terminal_args)
+            .arg(self.program)
+            .args(&self.args)
+            .spawn()
+            .with_context(|| format!("Spawn {terminal_program}"))?;
+        Ok(())
+    }
+}
+
+fn param_string<'a>(resource: &'a Resource, param: &str) -> Option<&'a str> {
+    match resource.params.get(param)? {
+        MapValue::String(value) if !value.is_empty() => Some(value),
+        _ => None,
+    }
+}
+
+/// The param as argument of the command, `None` if it could be parsed as option.
+fn param_arg<'a>(resource: &'a Resource, param: &str) -> Option<&'a str> {
+    param_string(resource, param).filter(|value| !value.starts_with('-'))
+}
+
+/// Floats are accepted too, exporters report e.g. the speed of serial ports as such.
+fn param_u64(resource: &Resource, param: &str) -> Option<u64> {
+    match resource.params.get(param)? {
+        MapValue::Int(value) => u64::try_from(*value).ok(),
+        MapValue::UInt(value) => Some(*value),
+        MapValue::Float(value) if value.fract() == 0.0 && *value >= 0.0 => Some(*value as u64),
+        MapValue::String(value) => value.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Quotes the argument for a POSIX shell, unless it only consists of characters that need no quoting.
+fn shell_quote(arg: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "@%+=:,./-_".contains(c);
+    if !arg.is_empty() && arg.chars().all(is_safe) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    fn resource(cls: &str, params: &[(&str, MapValue)]) -> Resource {
+        Resource {
+            params: test_fixtures::params(params),
+            ..test_fixtures::resource(cls, cls)
+        }
+    }
+
+    #[test]
+    fn quick_connect_commands() {
+        let ssh = QuickConnect::for_resource(&resource(
+            "NetworkService",
+            &[
+                ("address", MapValue::String("192.168.1.10".to_string())),
+                ("username", MapValue::String("root".to_string())),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(ssh.command_line(), "ssh -- root@192.168.1.10");
+        let ssh = QuickConnect::for_resource(&resource(
+            "NetworkService",
+            &[
+                ("address", MapValue::String("fe80::1%eth0".to_string())),
+                ("port", MapValue::Int(2222)),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(ssh.command_line(), "ssh -p 2222 -- fe80::1%eth0");
+        // Values of the exporter must not inject options
+        for (address, username) in [("-oProxyCommand=sh", "root"), ("host", "-oProxyCommand=sh")] {
+            assert_eq!(
+                QuickConnect::for_resource(&resource(
+                    "NetworkService",
+                    &[
+                        ("address", MapValue::String(address.to_string())),
+                        ("username", MapValue::String(username.to_string())),
+                    ],
+                )),
+                None
+            );
+        }
+
+        let microcom = QuickConnect::for_resource(&resource(
+            "NetworkSerialPort",
+            &[
+                ("host", MapValue::String("exporter-1".to_string())),
+                ("port", MapValue::UInt(52981)),
+                ("speed", MapValue::Float(921600.)),
+                ("protocol", MapValue::String("rfc2217".to_string())),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(
+            microcom.command_line(),
+            "microcom -s 921600 -t exporter-1:52981"
+        );
+        let telnet = QuickConnect::for_resource(&resource(
+            "NetworkSerialPort",
+            &[
+                ("host", MapValue::String("exporter-1".to_string())),
+                ("port", MapValue::Int(52981)),
+                ("protocol", MapValue::String("raw".to_string())),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(telnet.command_line(), "telnet -- exporter-1 52981");
+        assert_eq!(
+            QuickConnect::for_resource(&resource(
+                "NetworkSerialPort",
+                &[
+                    ("host", MapValue::String("-l".to_string())),
+                    ("port", MapValue::Int(52981)),
+                ],
+            )),
+            None
+        );
+
+        assert_eq!(
+            QuickConnect::for_resource(&resource(
+                "NetworkSerialPort",
+                &[("host", MapValue::String("exporter-1".to_string()))]
+            )),
+            None
+        );
+        assert_eq!(
+            QuickConnect::for_resource(&resource("USBSerialPort", &[])),
+            None
+        );
+    }
+
+    #[test]
+    fn quick_connect_shell_quote() {
+        assert_eq!(shell_quote("user@host:22"), "user@host:22");
+        assert_eq!(shell_quote("my host"), "'my host'");
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+        assert_eq!(shell_quote(""), "''");
+    }
+}
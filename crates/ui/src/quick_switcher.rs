@@ -0,0 +1,251 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, ConnectedMsg, Modal, TabId};
+use crate::connection::ConnectionMsg;
+use crate::i18n::fl;
+use iced::keyboard;
+
+/// The id of the query text input, focused when the quick switcher is opened.
+pub(crate) const QUICK_SWITCHER_INPUT_ID: &str = "quick-switcher-input";
+/// The maximum number of listed matches.
+pub(crate) const MAX_MATCHES: usize = 10;
+
+/// An action of the command palette, the quick switcher opened with `Ctrl+K`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Command {
+    Refresh,
+    ShowTab(TabId),
+    CreateReservation,
+    OpenSettings,
+    Disconnect,
+}
+
+impl Command {
+    /// All commands, in the order they are listed with an empty query.
+    pub(crate) fn all() -> impl Iterator<Item = Self> {
+        [Self::Refresh]
+            .into_iter()
+            .chain(TabId::ALL.into_iter().map(Self::ShowTab))
+            .chain([
+                Self::CreateReservation,
+                Self::OpenSettings,
+                Self::Disconnect,
+            ])
+    }
+
+    pub(crate) fn label(&self) -> String {
+        match self {
+            Self::Refresh => fl!("command-refresh"),
+            Self::ShowTab(tab) => fl!("command-show-tab", tab = tab.label()),
+            Self::CreateReservation => fl!("command-create-reservation"),
+            Self::OpenSettings => fl!("command-open-settings"),
+            Self::Disconnect => fl!("command-disconnect"),
+        }
+    }
+
+    /// The message running the command, the quick switcher is hidden beforehand.
+    pub(crate) fn msg(&self) -> AppMsg {
+        match self {
+            Self::Refresh => AppMsg::Connected(ConnectedMsg::Refresh).hide_modal(),
+            Self::ShowTab(tab) => {
+                AppMsg::Connected(ConnectedMsg::TabSelected(tab.clone())).hide_modal()
+            }
+            Self::CreateReservation => AppMsg::ShowModal(Box::new(Modal::CreateReservation)),
+            Self::OpenSettings => AppMsg::ShowModal(Box::new(Modal::Settings)),
+            Self::Disconnect => AppMsg::ConnectionMsg(ConnectionMsg::Disconnect).hide_modal(),
+        }
+    }
+}
+
+/// An entry of the quick switcher.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum QuickSwitcherItem {
+    Command(Command),
+    Place(String),
+    /// The file name of a script in the scripts directory.
+    Script(String),
+}
+
+impl QuickSwitcherItem {
+    /// The text matched by the query, the name of places and scripts.
+    pub(crate) fn label(&self) -> String {
+        match self {
+            Self::Command(command) => command.label(),
+            Self::Place(name) | Self::Script(name) => name.clone(),
+        }
+    }
+}
+
+/// State of the quick switcher modal.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct QuickSwitcher {
+    pub(crate) query: String,
+    /// Index of the selected entry in the matches.
+    pub(crate) selected: usize,
+    /// Whether commands are listed, as command palette.
+    pub(crate) commands: bool,
+}
+
+impl QuickSwitcher {
+    /// Moves the selection up or down, wrapping around at the ends of the `count` matches.
+    pub(crate) fn move_selection(&mut self, down: bool, count: usize) {
+        if count == 0 {
+            self.selected = 0;
+        } else if down {
+            self.selected = (self.selected + 1) % count;
+        } else {
+            self.selected = (self.selected + count - 1) % count;
+        }
+    }
+}
+
+/// Scores how well `query` fuzzy matches `candidate`, case-insensitive.
+///
+/// Returns `None` when the characters of the query do not all appear in order in the candidate.
+/// Consecutive characters and characters at word starts score higher, shorter candidates are preferred.
+pub(crate) fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    const WORD_SEPARATORS: [char; 6] = [' ', '-', '_', '/', '.', ':'];
+
+    let candidate = candidate.chars().collect::<Vec<char>>();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last_match: Option<usize> = None;
+    for q in query.chars().filter(|c| !c.is_whitespace()) {
+        let offset = candidate[pos..]
+            .iter()
+            .position(|c| c.to_lowercase().eq(q.to_lowercase()))?;
+        let i = pos + offset;
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == i) {
+            score += 5;
+        }
+        if i == 0
+            || WORD_SEPARATORS.contains(&candidate[i - 1])
+            || (candidate[i - 1].is_lowercase() && candidate[i].is_uppercase())
+        {
+            score += 3;
+        }
+        last_match = Some(i);
+        pos = i + 1;
+    }
+    Some(score * 1000 - candidate.len() as i64)
+}
+
+/// The commands, places and scripts matching the query, best first and limited to [MAX_MATCHES].
+///
+/// With an empty query, commands are listed before places and places before scripts.
+pub(crate) fn matching_items<'a>(
+    query: &str,
+    commands: impl IntoIterator<Item = Command>,
+    place_names: impl IntoIterator<Item = &'a str>,
+    script_names: impl IntoIterator<Item = &'a str>,
+) -> Vec<QuickSwitcherItem> {
+    let items = commands
+        .into_iter()
+        .map(QuickSwitcherItem::Command)
+        .chain(
+            place_names
+                .into_iter()
+                .map(|name| QuickSwitcherItem::Place(name.to_string())),
+        )
+        .chain(
+            script_names
+                .into_iter()
+                .map(|name| QuickSwitcherItem::Script(name.to_string())),
+        );
+    let mut scored = items
+        .filter_map(|item| {
+            let label = item.label();
+            Some((fuzzy_score(query, &label)?, label, item))
+        })
+        .collect::<Vec<_>>();
+    if !query.trim().is_empty() {
+        scored.sort_by(|(a_score, a_label, _), (b_score, b_label, _)| {
+            b_score.cmp(a_score).then_with(|| a_label.cmp(b_label))
+        });
+    }
+    scored
+        .into_iter()
+        .take(MAX_MATCHES)
+        .map(|(_, _, item)| item)
+        .collect()
+}
+
+/// Maps the keys for navigating the quick switcher while it is shown.
+///
+/// `Enter` activates the selected entry, `Shift+Enter` acquires the selected place.
+pub(crate) fn navigation_keys(event: keyboard::Event) -> Option<AppMsg> {
+    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+        return None;
+    };
+    let msg = match key {
+        keyboard::Key::Named(keyboard::key::Named::ArrowDown) => {
+            ConnectedMsg::QuickSwitcherMove { down: true }
+        }
+        keyboard::Key::Named(keyboard::key::Named::ArrowUp) => {
+            ConnectedMsg::QuickSwitcherMove { down: false }
+        }
+        keyboard::Key::Named(keyboard::key::Named::Enter) => ConnectedMsg::QuickSwitcherSubmit {
+            acquire: modifiers.shift(),
+        },
+        _ => return None,
+    };
+    Some(AppMsg::Connected(msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_matching() {
+        assert!(fuzzy_score("brd1", "board-1").is_some());
+        assert!(fuzzy_score("BOARD", "board-1").is_some());
+        assert_eq!(fuzzy_score("1b", "board-1"), None);
+        // Word starts and consecutive characters are preferred
+        assert!(fuzzy_score("pc", "power-cycle.py") > fuzzy_score("pc", "upcoming.py"));
+        assert!(fuzzy_score("board", "board-1") > fuzzy_score("board", "b-o-a-r-d"));
+
+        let items = matching_items(
+            "imx",
+            [],
+            ["imx8-1", "rpi-4", "board-imx6"],
+            ["flash-imx.py", "reset.py"],
+        );
+        assert_eq!(
+            items,
+            vec![
+                QuickSwitcherItem::Place("imx8-1".to_string()),
+                QuickSwitcherItem::Place("board-imx6".to_string()),
+                QuickSwitcherItem::Script("flash-imx.py".to_string()),
+            ]
+        );
+        assert_eq!(matching_items("", [], ["b", "a"], ["c.py"]).len(), 3);
+
+        let items = matching_items("settings", Command::all(), ["settings-rig"], []);
+        assert_eq!(
+            items,
+            vec![
+                QuickSwitcherItem::Place("settings-rig".to_string()),
+                QuickSwitcherItem::Command(Command::OpenSettings),
+            ]
+        );
+        assert_eq!(
+            matching_items("", Command::all(), ["board-1"], [])[0],
+            QuickSwitcherItem::Command(Command::Refresh)
+        );
+    }
+
+    #[test]
+    fn move_selection() {
+        let mut switcher = QuickSwitcher::default();
+        switcher.move_selection(false, 3);
+        assert_eq!(switcher.selected, 2);
+        switcher.move_selection(true, 3);
+        assert_eq!(switcher.selected, 0);
+        switcher.move_selection(true, 0);
+        assert_eq!(switcher.selected, 0);
+    }
+}
@@ -0,0 +1,68 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use core::time::Duration;
+use std::time::Instant;
+
+/// Limits the rate of operations with the token bucket algorithm.
+///
+/// The bucket holds up to one second worth of tokens, so short bursts are executed without delay.
+#[derive(Debug, Clone)]
+pub(crate) struct TokenBucket {
+    /// Tokens added per second.
+    rate: f64,
+    capacity: f64,
+    /// Negative when operations were granted ahead of time and still need to be waited for.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// A full bucket allowing `ops_per_sec` operations per second, at least one.
+    pub(crate) fn new(ops_per_sec: u32, now: Instant) -> Self {
+        let rate = f64::from(ops_per_sec.max(1));
+        Self {
+            rate,
+            capacity: rate,
+            tokens: rate,
+            last_refill: now,
+        }
+    }
+
+    /// Takes a token for an operation, returning how long to wait before executing it.
+    pub(crate) fn take(&mut self, now: Instant) -> Duration {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.last_refill = now;
+        self.tokens -= 1.;
+        if self.tokens >= 0. {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2, start);
+        // Burst of the capacity
+        assert_eq!(bucket.take(start), Duration::ZERO);
+        assert_eq!(bucket.take(start), Duration::ZERO);
+        assert_eq!(bucket.take(start), Duration::from_millis(500));
+        assert_eq!(bucket.take(start), Duration::from_millis(1000));
+        // Refilled after waiting
+        let later = start + Duration::from_secs(3);
+        assert_eq!(bucket.take(later), Duration::ZERO);
+        assert_eq!(bucket.take(later), Duration::ZERO);
+        assert_eq!(bucket.take(later), Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, ConnectedMsg};
+use crate::connection::ConnectionMsg;
+use crate::scripts::Script;
+use core::time::Duration;
+use iced::futures;
+use labgrid_ui_core::filter::MAIN_FILTER_NAME;
+use labgrid_ui_core::types::{Filter, Place, Reservation, ReservationState};
+use std::collections::HashMap;
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use tokio_stream::StreamExt;
+
+/// Interval of polling the reservation of a run, the coordinator expires reservations that are not polled.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The stage of a [ReservationRun].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RunStage {
+    /// Waiting for the coordinator to create the reservation.
+    Reserving,
+    /// Waiting for the reservation to be allocated.
+    Waiting { token: String },
+    /// The allocated place is being acquired.
+    Acquiring { token: String, place: String },
+    /// The script runs with `LG_PLACE` set to the acquired place.
+    Running { token: String, place: String },
+}
+
+/// How the run progressed in response to a connection event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RunStep {
+    /// The reservation was allocated, the place has to be acquired.
+    Acquire(String),
+    /// The place was acquired, the script has to be executed on it.
+    Execute(String),
+    /// The reservation expired or was cancelled before it was allocated, the run has ended.
+    Lost,
+}
+
+/// Runs a script on the next available place matching a reservation template.
+///
+/// Reserves a place, waits for the allocation, acquires the place and runs the script on it.
+/// Once the script finished, the place is released and the reservation is cancelled.
+#[derive(Debug, Clone)]
+pub(crate) struct ReservationRun {
+    pub(crate) script: Script,
+    /// The filters of the reservation.
+    pub(crate) filters: HashMap<String, Filter>,
+    pub(crate) stage: RunStage,
+}
+
+impl ReservationRun {
+    pub(crate) fn new(script: Script, filters: HashMap<String, Filter>) -> Self {
+        Self {
+            script,
+            filters,
+            stage: RunStage::Reserving,
+        }
+    }
+
+    /// The message requesting the reservation of the run.
+    pub(crate) fn create_msg(&self) -> ConnectionMsg {
+        ConnectionMsg::CreateReservation {
+            filters: self.filters.clone(),
+            prio: 0.,
+        }
+    }
+
+    /// The token of the reservation, once it is created.
+    pub(crate) fn token(&self) -> Option<&str> {
+        match &self.stage {
+            RunStage::Reserving => None,
+            RunStage::Waiting { token }
+            | RunStage::Acquiring { token, .. }
+            | RunStage::Running { token, .. } => Some(token),
+        }
+    }
+
+    /// The place of the allocated reservation.
+    pub(crate) fn place(&self) -> Option<&str> {
+        match &self.stage {
+            RunStage::Reserving | RunStage::Waiting { .. } => None,
+            RunStage::Acquiring { place, .. } | RunStage::Running { place, .. } => Some(place),
+        }
+    }
+
+    /// Takes over the created reservation, when it was created with the filters of the run.
+    pub(crate) fn reservation_created(&mut self, reservation: &Reservation) {
+        if self.stage == RunStage::Reserving && reservation.filters == self.filters {
+            self.stage = RunStage::Waiting {
+                token: reservation.token.clone(),
+            };
+        }
+    }
+
+    /// Advances the run with the refreshed reservations.
+    pub(crate) fn reservations_changed(&mut self, reservations: &[Reservation]) -> Option<RunStep> {
+        let RunStage::Waiting { token } = &self.stage else {
+            return None;
+        };
+        let Some(reservation) = reservations.iter().find(|r| r.token == *token) else {
+            return Some(RunStep::Lost);
+        };
+        match reservation.reservation_state() {
+            Some(ReservationState::Allocated) => {
+                let place = reservation
+                    .allocations
+                    .get(MAIN_FILTER_NAME)
+                    .or_else(|| reservation.allocations.values().next())?
+                    .clone();
+                self.stage = RunStage::Acquiring {
+                    token: token.clone(),
+                    place: place.clone(),
+                };
+                Some(RunStep::Acquire(place))
+            }
+            Some(ReservationState::Expired | ReservationState::Invalid) => Some(RunStep::Lost),
+            _ => None,
+        }
+    }
+
+    /// Advances the run with the changed place for the [crate::app::AppConnected::identity].
+    pub(crate) fn place_changed(&mut self, place: &Place, identity: &str) -> Option<RunStep> {
+        let RunStage::Acquiring {
+            token,
+            place: acquiring,
+        } = &self.stage
+        else {
+            return None;
+        };
+        if *acquiring != place.name || place.acquired.as_deref() != Some(identity) {
+            return None;
+        }
+        let place = acquiring.clone();
+        self.stage = RunStage::Running {
+            token: token.clone(),
+            place: place.clone(),
+        };
+        Some(RunStep::Execute(place))
+    }
+
+    /// The messages ending the run, releasing the place once it is acquired and cancelling the reservation.
+    pub(crate) fn end_msgs(&self) -> Vec<ConnectionMsg> {
+        let release = match &self.stage {
+            RunStage::Running { place, .. } => Some(ConnectionMsg::ReleasePlace {
+                name: place.clone(),
+            }),
+            _ => None,
+        };
+        let cancel = self.token().map(|token| ConnectionMsg::CancelReservation {
+            token: token.to_string(),
+        });
+        release.into_iter().chain(cancel).collect()
+    }
+}
+
+/// An iced subscription that triggers polling the reservation of the run, while one is running.
+pub(crate) fn poll_subscription() -> impl futures::Stream<Item = AppMsg> {
+    IntervalStream::new(time::interval(POLL_INTERVAL))
+        .map(|_| AppMsg::Connected(ConnectedMsg::PollReservationRun))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scripts::{FrontMatter, ScriptType};
+    use crate::test_fixtures::{self, board_filter};
+    use std::path::PathBuf;
+
+    fn reservation(state: ReservationState, allocation: Option<&str>) -> Reservation {
+        Reservation {
+            state: state.into(),
+            allocations: allocation
+                .map(|place| HashMap::from([("main".to_string(), place.to_string())]))
+                .unwrap_or_default(),
+            ..test_fixtures::reservation("AAAA")
+        }
+    }
+
+    fn place(acquired: Option<&str>) -> Place {
+        Place {
+            acquired: acquired.map(str::to_string),
+            ..test_fixtures::place("imx8-1")
+        }
+    }
+
+    #[test]
+    fn reservation_run() {
+        let script = Script {
+            path: PathBuf::from("/scripts/flash.py"),
+            name: "flash.py".to_string(),
+            _type: ScriptType::Python,
+            front_matter: FrontMatter::default(),
+        };
+        let mut run = ReservationRun::new(script, board_filter("imx8"));
+        assert!(run.end_msgs().is_empty());
+
+        // Reservations created with other filters are not taken over
+        let mut other = reservation(ReservationState::Waiting, None);
+        other.filters = HashMap::new();
+        run.reservation_created(&other);
+        assert_eq!(run.token(), None);
+        run.reservation_created(&reservation(ReservationState::Waiting, None));
+        assert_eq!(run.token(), Some("AAAA"));
+
+        assert_eq!(
+            run.reservations_changed(&[reservation(ReservationState::Waiting, None)]),
+            None
+        );
+        assert_eq!(
+            run.reservations_changed(&[reservation(ReservationState::Allocated, Some("imx8-1"))]),
+            Some(RunStep::Acquire("imx8-1".to_string()))
+        );
+        assert_eq!(
+            run.place_changed(&place(Some("host/other")), "host/me"),
+            None
+        );
+        assert_eq!(
+            run.place_changed(&place(Some("host/me")), "host/me"),
+            Some(RunStep::Execute("imx8-1".to_string()))
+        );
+        assert_eq!(run.place(), Some("imx8-1"));
+        assert!(matches!(
+            run.end_msgs().as_slice(),
+            [
+                ConnectionMsg::ReleasePlace { name },
+                ConnectionMsg::CancelReservation { token },
+            ] if name == "imx8-1" && token == "AAAA"
+        ));
+
+        // Reservations that vanish before they are allocated end the run
+        let mut run = ReservationRun::new(run.script, board_filter("imx8"));
+        run.reservation_created(&reservation(ReservationState::Waiting, None));
+        assert_eq!(run.reservations_changed(&[]), Some(RunStep::Lost));
+    }
+}
@@ -0,0 +1,399 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::ResourceUi;
+use crate::i18n::fl;
+use core::cmp::Reverse;
+use core::fmt::Display;
+use iced::widget::Text;
+use iced_fonts::bootstrap;
+use labgrid_ui_core::types::{MapValue, Resource};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The icons that can be shown for resources of a class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum ClassIcon {
+    Board,
+    Power,
+    Serial,
+    Network,
+    Usb,
+    Video,
+    Storage,
+    Display,
+    Relay,
+    Sensor,
+}
+
+impl ClassIcon {
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Board,
+        Self::Power,
+        Self::Serial,
+        Self::Network,
+        Self::Usb,
+        Self::Video,
+        Self::Storage,
+        Self::Display,
+        Self::Relay,
+        Self::Sensor,
+    ];
+
+    pub(crate) fn icon<'a>(&self) -> Text<'a> {
+        match self {
+            Self::Board => bootstrap::motherboard(),
+            Self::Power => bootstrap::power(),
+            Self::Serial => bootstrap::terminal(),
+            Self::Network => bootstrap::router(),
+            Self::Usb => bootstrap::usb_plug(),
+            Self::Video => bootstrap::camera_video(),
+            Self::Storage => bootstrap::sd_card(),
+            Self::Display => bootstrap::display(),
+            Self::Relay => bootstrap::outlet(),
+            Self::Sensor => bootstrap::thermometer(),
+        }
+    }
+
+    /// The icon of the labgrid resource classes that are known to the UI.
+    pub(crate) fn builtin(cls: &str) -> Option<Self> {
+        let icon = match cls {
+            "RawSerialPort" | "NetworkSerialPort" | "USBSerialPort" => Self::Serial,
+            "NetworkPowerPort" | "PDUDaemonPort" | "YKUSHPowerPort" | "USBPowerPort"
+            | "SiSPMPowerPort" | "TasmotaPowerPort" => Self::Power,
+            "NetworkService"
+            | "NetworkInterface"
+            | "RemoteNetworkInterface"
+            | "USBNetworkInterface"
+            | "EthernetPort" => Self::Network,
+            "USBVideo" | "NetworkUSBVideo" | "HTTPVideoStream" => Self::Video,
+            "USBMassStorage"
+            | "NetworkUSBMassStorage"
+            | "USBSDMuxDevice"
+            | "NetworkUSBSDMuxDevice"
+            | "USBSDWireDevice"
+            | "NetworkUSBSDWireDevice" => Self::Storage,
+            "USBDebugger"
+            | "NetworkUSBDebugger"
+            | "AndroidUSBFastboot"
+            | "AndroidNetFastboot"
+            | "IMXUSBLoader"
+            | "NetworkIMXUSBLoader"
+            | "MXSUSBLoader"
+            | "NetworkMXSUSBLoader"
+            | "RKUSBLoader"
+            | "NetworkRKUSBLoader"
+            | "DFUDevice"
+            | "NetworkDFUDevice" => Self::Usb,
+            "HIDRelay"
+            | "NetworkHIDRelay"
+            | "SysfsGPIO"
+            | "NetworkSysfsGPIO"
+            | "ModbusTCPCoil"
+            | "DeditecRelais8"
+            | "NetworkDeditecRelais8" => Self::Relay,
+            "LXAUSBMux" | "NetworkLXAUSBMux" | "USBTMC" | "NetworkUSBTMC"
+            | "USBFlashableDevice" => Self::Board,
+            _ => return None,
+        };
+        Some(icon)
+    }
+}
+
+impl Display for ClassIcon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Board => fl!("resource-class-icon-board"),
+            Self::Power => fl!("resource-class-icon-power"),
+            Self::Serial => fl!("resource-class-icon-serial"),
+            Self::Network => fl!("resource-class-icon-network"),
+            Self::Usb => fl!("resource-class-icon-usb"),
+            Self::Video => fl!("resource-class-icon-video"),
+            Self::Storage => fl!("resource-class-icon-storage"),
+            Self::Display => fl!("resource-class-icon-display"),
+            Self::Relay => fl!("resource-class-icon-relay"),
+            Self::Sensor => fl!("resource-class-icon-sensor"),
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// How resources of a class are presented, configured by the user.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ClassMapping {
+    /// Overrides the built-in icon of the class.
+    pub(crate) icon: Option<ClassIcon>,
+    /// The parameter whose value is copied to the clipboard by the quick action button of the resources.
+    pub(crate) copy_param: Option<String>,
+}
+
+/// The user configured mappings of resource classes, keyed by the class name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub(crate) struct ResourceClassRegistry(BTreeMap<String, ClassMapping>);
+
+impl ResourceClassRegistry {
+    pub(crate) fn get(&self, cls: &str) -> Option<&ClassMapping> {
+        self.0.get(cls)
+    }
+
+    /// Sets the mapping of the class, a default mapping removes it.
+    pub(crate) fn set(&mut self, cls: String, mapping: ClassMapping) {
+        if mapping == ClassMapping::default() {
+            self.0.remove(&cls);
+        } else {
+            self.0.insert(cls, mapping);
+        }
+    }
+
+    /// The icon of the class, either mapped by the user or built-in.
+    pub(crate) fn icon(&self, cls: &str) -> Option<ClassIcon> {
+        self.get(cls)
+            .and_then(|mapping| mapping.icon)
+            .or_else(|| ClassIcon::builtin(cls))
+    }
+}
+
+/// Per-class statistics of the known resources, for capacity planning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ClassSummary {
+    pub(crate) cls: String,
+    pub(crate) resources: usize,
+    /// Resources that are available and not removed by the coordinator.
+    pub(crate) available: usize,
+    pub(crate) acquired: usize,
+}
+
+impl ClassSummary {
+    /// The share of available resources, from `0.0` to `1.0`.
+    pub(crate) fn availability(&self) -> f32 {
+        if self.resources == 0 {
+            0.0
+        } else {
+            self.available as f32 / self.resources as f32
+        }
+    }
+}
+
+/// Summarizes the resources per class, sorted by the number of resources and then by the class name.
+pub(crate) fn summarize_classes<'a>(
+    resources: impl IntoIterator<Item = &'a (Resource, ResourceUi)>,
+) -> Vec<ClassSummary> {
+    let mut summaries: BTreeMap<&str, ClassSummary> = BTreeMap::new();
+    for (resource, ui) in resources {
+        let summary = summaries
+            .entry(resource.cls.as_str())
+            .or_insert_with(|| ClassSummary {
+                cls: resource.cls.clone(),
+                resources: 0,
+                available: 0,
+                acquired: 0,
+            });
+        summary.resources += 1;
+        if resource.available && ui.stale_since.is_none() {
+            summary.available += 1;
+        }
+        if !resource.acquired.is_empty() {
+            summary.acquired += 1;
+        }
+    }
+    let mut summaries = summaries.into_values().collect::<Vec<_>>();
+    // Stable sort keeps the name order for classes with the same number of resources
+    summaries.sort_by_key(|summary| Reverse(summary.resources));
+    summaries
+}
+
+/// The type of a resource parameter value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum ParamKind {
+    Bool,
+    Int,
+    UInt,
+    Float,
+    String,
+    Array,
+}
+
+impl From<&MapValue> for ParamKind {
+    fn from(value: &MapValue) -> Self {
+        match value {
+            MapValue::Bool(_) => Self::Bool,
+            MapValue::Int(_) => Self::Int,
+            MapValue::UInt(_) => Self::UInt,
+            MapValue::Float(_) => Self::Float,
+            MapValue::String(_) => Self::String,
+            MapValue::Array(_) => Self::Array,
+        }
+    }
+}
+
+impl Display for ParamKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::Int => write!(f, "int"),
+            Self::UInt => write!(f, "uint"),
+            Self::Float => write!(f, "float"),
+            Self::String => write!(f, "string"),
+            Self::Array => write!(f, "array"),
+        }
+    }
+}
+
+/// A parameter observed on the resources of a class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ParamSchema {
+    /// The types of the observed values, usually only one.
+    pub(crate) kinds: BTreeSet<ParamKind>,
+    /// The number of resources that have the parameter.
+    pub(crate) count: usize,
+}
+
+/// The parameters observed on the resources of a class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ClassSchema {
+    /// The number of observed resources.
+    pub(crate) resources: usize,
+    pub(crate) params: BTreeMap<String, ParamSchema>,
+}
+
+impl ClassSchema {
+    /// Collects the schema from the params of all resources of the class.
+    pub(crate) fn observe<'a>(
+        cls: &str,
+        resources: impl IntoIterator<Item = &'a Resource>,
+    ) -> Self {
+        let mut schema = Self::default();
+        for resource in resources.into_iter().filter(|r| r.cls == cls) {
+            schema.resources += 1;
+            for (name, value) in &resource.params {
+                let param = schema.params.entry(name.clone()).or_default();
+                param.kinds.insert(ParamKind::from(value));
+                param.count += 1;
+            }
+        }
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    fn resource(cls: &str, params: &[(&str, MapValue)]) -> Resource {
+        Resource {
+            params: test_fixtures::params(params),
+            ..test_fixtures::resource(cls, cls)
+        }
+    }
+
+    #[test]
+    fn class_schema_observe() {
+        let resources = [
+            resource(
+                "CustomProbe",
+                &[
+                    ("host", MapValue::String("probe-1".to_string())),
+                    ("port", MapValue::UInt(4000)),
+                ],
+            ),
+            resource(
+                "CustomProbe",
+                &[
+                    ("host", MapValue::String("probe-2".to_string())),
+                    ("port", MapValue::Int(4001)),
+                ],
+            ),
+            resource(
+                "CustomProbe",
+                &[("host", MapValue::String("probe-3".to_string()))],
+            ),
+            resource("NetworkSerialPort", &[("speed", MapValue::UInt(115200))]),
+        ];
+        let schema = ClassSchema::observe("CustomProbe", &resources);
+        assert_eq!(schema.resources, 3);
+        assert_eq!(
+            schema.params,
+            BTreeMap::from([
+                (
+                    "host".to_string(),
+                    ParamSchema {
+                        kinds: BTreeSet::from([ParamKind::String]),
+                        count: 3
+                    }
+                ),
+                (
+                    "port".to_string(),
+                    ParamSchema {
+                        kinds: BTreeSet::from([ParamKind::Int, ParamKind::UInt]),
+                        count: 2
+                    }
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn registry_icon() {
+        let mut registry = ResourceClassRegistry::default();
+        assert_eq!(registry.icon("NetworkSerialPort"), Some(ClassIcon::Serial));
+        assert_eq!(registry.icon("CustomProbe"), None);
+        registry.set(
+            "CustomProbe".to_string(),
+            ClassMapping {
+                icon: Some(ClassIcon::Sensor),
+                copy_param: None,
+            },
+        );
+        assert_eq!(registry.icon("CustomProbe"), Some(ClassIcon::Sensor));
+        // A default mapping removes the entry
+        registry.set("CustomProbe".to_string(), ClassMapping::default());
+        assert_eq!(registry, ResourceClassRegistry::default());
+    }
+
+    #[test]
+    fn class_summaries() {
+        let mut acquired = resource("NetworkPowerPort", &[]);
+        acquired.acquired = "board-1".to_string();
+        acquired.available = false;
+        let stale = ResourceUi {
+            stale_since: Some(std::time::Instant::now()),
+            ..Default::default()
+        };
+        let resources = vec![
+            (resource("USBSerialPort", &[]), ResourceUi::default()),
+            (resource("NetworkSerialPort", &[]), ResourceUi::default()),
+            (resource("NetworkSerialPort", &[]), stale),
+            (acquired, ResourceUi::default()),
+            (resource("NetworkPowerPort", &[]), ResourceUi::default()),
+        ];
+        let summaries = summarize_classes(&resources);
+        assert_eq!(
+            summaries,
+            vec![
+                ClassSummary {
+                    cls: "NetworkPowerPort".to_string(),
+                    resources: 2,
+                    available: 1,
+                    acquired: 1,
+                },
+                ClassSummary {
+                    cls: "NetworkSerialPort".to_string(),
+                    resources: 2,
+                    available: 1,
+                    acquired: 0,
+                },
+                ClassSummary {
+                    cls: "USBSerialPort".to_string(),
+                    resources: 1,
+                    available: 1,
+                    acquired: 0,
+                },
+            ]
+        );
+        assert_eq!(summaries[0].availability(), 0.5);
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::exporters;
+use crate::views::connected::format_map_value;
+use labgrid_ui_core::types::Resource;
+use std::collections::BTreeSet;
+
+/// The filters of the resources tab, resources are displayed if they match all of them.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ResourceFilter<'a> {
+    pub(crate) only_available: bool,
+    pub(crate) exporter: Option<&'a str>,
+    pub(crate) cls: Option<&'a str>,
+    /// Whitespace separated terms, see [ResourceFilter::matches_search].
+    pub(crate) search: &'a str,
+}
+
+impl ResourceFilter<'_> {
+    pub(crate) fn matches(&self, resource: &Resource) -> bool {
+        (!self.only_available || resource.available)
+            && self.exporter.map_or(true, |exporter| {
+                exporters::exporter_name(&resource.path) == exporter
+            })
+            && self.cls.map_or(true, |cls| resource.cls == cls)
+            && self.matches_search(resource)
+    }
+
+    /// Whether each search term is contained in the exporter, group or name of the resource,
+    /// or in the name or value of one of its params, ignoring the case.
+    pub(crate) fn matches_search(&self, resource: &Resource) -> bool {
+        let fields = [
+            exporters::exporter_name(&resource.path).to_lowercase(),
+            resource.path.group_name.to_lowercase(),
+            resource.path.resource_name.to_lowercase(),
+        ]
+        .into_iter()
+        .chain(resource.params.iter().flat_map(|(param, value)| {
+            [param.to_lowercase(), format_map_value(value).to_lowercase()]
+        }))
+        .collect::<Vec<String>>();
+        self.search.split_whitespace().all(|term| {
+            let term = term.to_lowercase();
+            fields.iter().any(|field| field.contains(&term))
+        })
+    }
+}
+
+/// The classes of the resources, sorted and without duplicates.
+pub(crate) fn observed_classes<'a>(
+    resources: impl IntoIterator<Item = &'a Resource>,
+) -> Vec<String> {
+    resources
+        .into_iter()
+        .map(|resource| resource.cls.clone())
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use labgrid_ui_core::types::MapValue;
+
+    fn resource(exporter: &str, cls: &str, name: &str, available: bool) -> Resource {
+        let mut resource = Resource {
+            params: test_fixtures::params(&[
+                ("host", MapValue::String("Lab-1".to_string())),
+                ("port", MapValue::UInt(4001)),
+            ]),
+            available,
+            ..test_fixtures::resource(name, cls)
+        };
+        resource.path.exporter_name = Some(exporter.to_string());
+        resource
+    }
+
+    #[test]
+    fn resource_filter_matches() {
+        let serial = resource("exporter-1", "NetworkSerialPort", "console", true);
+        let power = resource("exporter-2", "NetworkPowerPort", "power", false);
+
+        assert!(ResourceFilter::default().matches(&power));
+        let only_available = ResourceFilter {
+            only_available: true,
+            ..Default::default()
+        };
+        assert!(only_available.matches(&serial));
+        assert!(!only_available.matches(&power));
+        let exporter = ResourceFilter {
+            exporter: Some("exporter-2"),
+            ..Default::default()
+        };
+        assert!(!exporter.matches(&serial));
+        assert!(exporter.matches(&power));
+        let cls = ResourceFilter {
+            cls: Some("NetworkSerialPort"),
+            ..Default::default()
+        };
+        assert!(cls.matches(&serial));
+        assert!(!cls.matches(&power));
+    }
+
+    #[test]
+    fn resource_filter_search() {
+        let serial = resource("exporter-1", "NetworkSerialPort", "console", true);
+        let search = |search| ResourceFilter {
+            search,
+            ..Default::default()
+        };
+        assert!(search("").matches(&serial));
+        assert!(search("  CONSOLE ").matches(&serial));
+        assert!(search("board-1 exporter-1").matches(&serial));
+        // Param names and values
+        assert!(search("host lab-1").matches(&serial));
+        assert!(search("4001").matches(&serial));
+        assert!(!search("console exporter-2").matches(&serial));
+    }
+
+    #[test]
+    fn resource_filter_observed_classes() {
+        let resources = [
+            resource("exporter-1", "NetworkSerialPort", "console", true),
+            resource("exporter-1", "NetworkPowerPort", "power", true),
+            resource("exporter-2", "NetworkSerialPort", "console", true),
+        ];
+        assert_eq!(
+            observed_classes(&resources),
+            ["NetworkPowerPort", "NetworkSerialPort"]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use chrono::{DateTime, Local};
+use labgrid_ui_core::types::{Path, Resource};
+use std::collections::BTreeSet;
+
+/// A change of the availability of a watched resource, shown in a banner until it is dismissed.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AvailabilityNotice {
+    pub(crate) path: Path,
+    pub(crate) available: bool,
+    pub(crate) time: DateTime<Local>,
+}
+
+/// Detects changes of the availability of watched resources.
+///
+/// Watched resources are keyed by their path, formatted as `exporter/group/name`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ResourceWatch {
+    /// Watched resources that were deleted by the coordinator, e.g. because their exporter went down.
+    gone: BTreeSet<Path>,
+    /// The changes that weren't dismissed yet, at most one per resource.
+    pub(crate) notices: Vec<AvailabilityNotice>,
+}
+
+impl ResourceWatch {
+    pub(crate) fn key(path: &Path) -> String {
+        path.to_string()
+    }
+
+    /// Observes an added or updated resource, `prev_available` is its previously known availability.
+    ///
+    /// Returns the notice if the availability of a watched resource changed,
+    /// resources that are seen for the first time don't cause notices.
+    pub(crate) fn observe(
+        &mut self,
+        watched: &BTreeSet<String>,
+        prev_available: Option<bool>,
+        resource: &Resource,
+        time: DateTime<Local>,
+    ) -> Option<AvailabilityNotice> {
+        let prev_available = if self.gone.remove(&resource.path) {
+            Some(false)
+        } else {
+            prev_available
+        };
+        if prev_available? == resource.available || !watched.contains(&Self::key(&resource.path)) {
+            return None;
+        }
+        Some(self.push(AvailabilityNotice {
+            path: resource.path.clone(),
+            available: resource.available,
+            time,
+        }))
+    }
+
+    /// Observes a resource deleted by the coordinator, `prev_available` is its previously known availability.
+    ///
+    /// Returns the notice if a watched resource was available.
+    pub(crate) fn observe_deleted(
+        &mut self,
+        watched: &BTreeSet<String>,
+        prev_available: Option<bool>,
+        path: &Path,
+        time: DateTime<Local>,
+    ) -> Option<AvailabilityNotice> {
+        if !watched.contains(&Self::key(path)) {
+            return None;
+        }
+        self.gone.insert(path.clone());
+        if prev_available != Some(true) {
+            return None;
+        }
+        Some(self.push(AvailabilityNotice {
+            path: path.clone(),
+            available: false,
+            time,
+        }))
+    }
+
+    /// Dismisses the notice of the resource with the supplied path.
+    pub(crate) fn dismiss(&mut self, path: &Path) {
+        self.notices.retain(|notice| notice.path != *path);
+    }
+
+    /// Replaces an earlier notice of the same resource.
+    fn push(&mut self, notice: AvailabilityNotice) -> AvailabilityNotice {
+        self.dismiss(&notice.path);
+        self.notices.push(notice.clone());
+        notice
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    fn resource(name: &str, available: bool) -> Resource {
+        Resource {
+            available,
+            ..test_fixtures::resource(name, "NetworkSerialPort")
+        }
+    }
+
+    #[test]
+    fn resource_watch_availability_changes() {
+        let time = Local::now();
+        let watched = BTreeSet::from(["exporter-1/board-1/console".to_string()]);
+        let mut watch = ResourceWatch::default();
+
+        // First seen and unchanged
+        assert_eq!(
+            watch.observe(&watched, None, &resource("console", true), time),
+            None
+        );
+        assert_eq!(
+            watch.observe(&watched, Some(true), &resource("console", true), time),
+            None
+        );
+        // Not watched
+        assert_eq!(
+            watch.observe(&watched, Some(true), &resource("power", false), time),
+            None
+        );
+
+        let notice = watch
+            .observe(&watched, Some(true), &resource("console", false), time)
+            .unwrap();
+        assert!(!notice.available);
+        let notice = watch
+            .observe(&watched, Some(false), &resource("console", true), time)
+            .unwrap();
+        assert!(notice.available);
+        assert_eq!(watch.notices.len(), 1);
+
+        watch.dismiss(&notice.path);
+        assert!(watch.notices.is_empty());
+    }
+
+    #[test]
+    fn resource_watch_deleted() {
+        let time = Local::now();
+        let watched = BTreeSet::from(["exporter-1/board-1/console".to_string()]);
+        let mut watch = ResourceWatch::default();
+        let console = resource("console", true);
+
+        let notice = watch
+            .observe_deleted(&watched, Some(true), &console.path, time)
+            .unwrap();
+        assert!(!notice.available);
+        // The exporter comes back
+        let notice = watch.observe(&watched, None, &console, time).unwrap();
+        assert!(notice.available);
+        assert_eq!(watch.notices.len(), 1);
+
+        // Deleted while unavailable
+        assert_eq!(
+            watch.observe_deleted(&watched, Some(false), &console.path, time),
+            None
+        );
+        assert_eq!(
+            watch.observe(&watched, None, &resource("console", false), time),
+            None
+        );
+    }
+}
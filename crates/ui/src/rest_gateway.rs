@@ -0,0 +1,280 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::AppMsg;
+use crate::connection::ConnectionEvent;
+use crate::export::Table;
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::header::CONTENT_TYPE;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use iced::Task;
+use labgrid_ui_core::types::{Path, Place, Reservation, Resource};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::{Arc, PoisonError, RwLock};
+use tokio::net::TcpListener;
+use tracing::debug;
+
+/// What the coordinator reported through the connection, served by the gateway.
+#[derive(Debug, Default)]
+pub(crate) struct GatewayState {
+    /// The address of the coordinator, `None` while not connected.
+    address: Option<String>,
+    places: BTreeMap<String, Place>,
+    resources: BTreeMap<Path, Resource>,
+    reservations: Vec<Reservation>,
+}
+
+impl GatewayState {
+    fn record_event(&mut self, event: &ConnectionEvent) {
+        match event {
+            ConnectionEvent::Connected { address, .. } => {
+                *self = Self {
+                    address: Some(address.clone()),
+                    ..Self::default()
+                };
+            }
+            ConnectionEvent::Disconnected { .. } => *self = Self::default(),
+            ConnectionEvent::Place(place) => {
+                self.places.insert(place.name.clone(), place.clone());
+            }
+            ConnectionEvent::Places(places) => {
+                for place in places {
+                    self.places.insert(place.name.clone(), place.clone());
+                }
+            }
+            ConnectionEvent::DeletePlace(name) => {
+                self.places.remove(name);
+            }
+            ConnectionEvent::Resource(resource) => {
+                self.resources
+                    .insert(resource.path.clone(), resource.clone());
+            }
+            ConnectionEvent::DeleteResource(path) => {
+                self.resources.remove(path);
+            }
+            ConnectionEvent::Reservations(reservations) => {
+                self.reservations = reservations.clone();
+            }
+            _ => {}
+        }
+    }
+
+    /// The response to a GET request of the path.
+    ///
+    /// Everything but the status is only served while connected.
+    fn route(&self, path: &str) -> (StatusCode, Value) {
+        let path = path.trim_end_matches('/');
+        if path == "/api/status" {
+            return (
+                StatusCode::OK,
+                json!({
+                    "connected": self.address.is_some(),
+                    "address": self.address,
+                    "places": self.places.len(),
+                    "resources": self.resources.len(),
+                    "reservations": self.reservations.len(),
+                }),
+            );
+        }
+        let known = path == "/api/places"
+            || path == "/api/resources"
+            || path == "/api/reservations"
+            || path.starts_with("/api/places/");
+        if !known {
+            return error(StatusCode::NOT_FOUND, "Unknown endpoint");
+        }
+        if self.address.is_none() {
+            return error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Not connected to the coordinator",
+            );
+        }
+        match path {
+            "/api/places" => (
+                StatusCode::OK,
+                Value::Array(Table::places(self.places.values()).json_entries()),
+            ),
+            "/api/resources" => (
+                StatusCode::OK,
+                Value::Array(Table::resources(self.resources.values()).json_entries()),
+            ),
+            "/api/reservations" => (
+                StatusCode::OK,
+                Value::Array(Table::reservations(&self.reservations).json_entries()),
+            ),
+            _ => {
+                let name = percent_decode(path.trim_start_matches("/api/places/"));
+                match self.places.get(&name) {
+                    Some(place) => (
+                        StatusCode::OK,
+                        Table::places([place])
+                            .json_entries()
+                            .pop()
+                            .unwrap_or_default(),
+                    ),
+                    None => error(StatusCode::NOT_FOUND, "Unknown place"),
+                }
+            }
+        }
+    }
+}
+
+fn error(status: StatusCode, error: &str) -> (StatusCode, Value) {
+    (status, json!({ "error": error }))
+}
+
+/// Decodes `%XX` escapes, invalid escapes are kept as they are.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| s.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Serves the places, resources and reservations observed through the connection events as JSON.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RestGateway {
+    state: Arc<RwLock<GatewayState>>,
+}
+
+impl RestGateway {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn record_event(&self, event: &ConnectionEvent) {
+        self.state
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .record_event(event);
+    }
+
+    /// A task serving the gateway at `addr`, reporting with `AppMsg::RestGatewayFailed` when it fails.
+    pub(crate) fn serve_task(&self, addr: SocketAddr) -> Task<AppMsg> {
+        let state = Arc::clone(&self.state);
+        Task::perform(
+            async move { serve(state, TcpListener::bind(addr).await?).await },
+            |res: io::Result<()>| match res {
+                Ok(()) => AppMsg::None,
+                Err(error) => AppMsg::RestGatewayFailed(format!("{error:?}")),
+            },
+        )
+    }
+}
+
+/// Serves the state to HTTP clients connecting to `listener`, until accepting a connection fails.
+async fn serve(state: Arc<RwLock<GatewayState>>, listener: TcpListener) -> io::Result<()> {
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            let service = service_fn(move |request: Request<hyper::body::Incoming>| {
+                let response = respond(&state, &request);
+                async move { Ok::<_, Infallible>(response) }
+            });
+            if let Err(error) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                debug!(?error, %peer, "Serve REST gateway connection");
+            }
+        });
+    }
+}
+
+fn respond<B>(state: &RwLock<GatewayState>, request: &Request<B>) -> Response<Full<Bytes>> {
+    let (status, body) = if request.method() == Method::GET {
+        state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .route(request.uri().path())
+    } else {
+        error(StatusCode::METHOD_NOT_ALLOWED, "Only GET is supported")
+    };
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, "application/json".parse().unwrap());
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    fn place(name: &str) -> Place {
+        Place {
+            acquired: Some("host/user".to_string()),
+            ..test_fixtures::place(name)
+        }
+    }
+
+    #[test]
+    fn gateway_routes() {
+        let mut state = GatewayState::default();
+        assert_eq!(state.route("/api/status").1["connected"], false);
+        assert_eq!(
+            state.route("/api/places").0,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+
+        state.record_event(&ConnectionEvent::Connected {
+            address: "lab:20408".to_string(),
+            identity: "host/user".to_string(),
+        });
+        state.record_event(&ConnectionEvent::Places(vec![
+            place("board 1"),
+            place("board-2"),
+        ]));
+        state.record_event(&ConnectionEvent::DeletePlace("board-2".to_string()));
+        let (status, status_body) = state.route("/api/status/");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(status_body["address"], "lab:20408");
+        assert_eq!(status_body["places"], 1);
+
+        let (status, places) = state.route("/api/places");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(places.as_array().map(Vec::len), Some(1));
+        let (status, place) = state.route("/api/places/board%201");
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(place["name"], "board 1");
+        assert_eq!(place["acquired"], "host/user");
+        assert_eq!(state.route("/api/places/board-2").0, StatusCode::NOT_FOUND);
+        assert_eq!(state.route("/api/other").0, StatusCode::NOT_FOUND);
+        assert_eq!(state.route("/api/reservations").1, json!([]));
+
+        state.record_event(&ConnectionEvent::Disconnected { error: None });
+        assert_eq!(state.route("/api/place/board%201").0, StatusCode::NOT_FOUND);
+        assert_eq!(
+            state.route("/api/places/board%201").0,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+}
@@ -0,0 +1,178 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::scripts::Script;
+use std::collections::BTreeMap;
+
+/// User metadata of a single script.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ScriptMeta {
+    /// Favorites are pinned at the top of the scripts tab.
+    pub(crate) favorite: bool,
+    /// Displayed instead of the file name.
+    pub(crate) display_name: Option<String>,
+}
+
+/// User metadata and the manual order of the scripts, keyed by the script names.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ScriptMetaSettings {
+    pub(crate) scripts: BTreeMap<String, ScriptMeta>,
+    /// Manually ordered script names, scripts that aren't listed follow alphabetically.
+    pub(crate) order: Vec<String>,
+}
+
+impl ScriptMetaSettings {
+    pub(crate) fn is_favorite(&self, script: &Script) -> bool {
+        self.scripts
+            .get(&script.name)
+            .is_some_and(|meta| meta.favorite)
+    }
+
+    /// The custom display name, or the file name of the script.
+    pub(crate) fn display_name<'a>(&'a self, script: &'a Script) -> &'a str {
+        self.scripts
+            .get(&script.name)
+            .and_then(|meta| meta.display_name.as_deref())
+            .unwrap_or_else(|| {
+                script
+                    .name
+                    .rsplit_once('/')
+                    .map_or(script.name.as_str(), |(_, file_name)| file_name)
+            })
+    }
+
+    pub(crate) fn toggle_favorite(&mut self, name: &str) {
+        let meta = self.scripts.entry(name.to_string()).or_default();
+        meta.favorite = !meta.favorite;
+        self.prune(name);
+    }
+
+    /// Sets the display name, an empty name resets it to the file name.
+    pub(crate) fn set_display_name(&mut self, name: &str, display_name: &str) {
+        let display_name = display_name.trim();
+        self.scripts
+            .entry(name.to_string())
+            .or_default()
+            .display_name = (!display_name.is_empty()).then(|| display_name.to_string());
+        self.prune(name);
+    }
+
+    /// Removes the metadata of the script if it has none set.
+    fn prune(&mut self, name: &str) {
+        if self.scripts.get(name) == Some(&ScriptMeta::default()) {
+            self.scripts.remove(name);
+        }
+    }
+
+    /// Sorts the scripts by their manual order, followed by the not manually ordered scripts alphabetically.
+    pub(crate) fn sort<'a>(
+        &self,
+        scripts: impl IntoIterator<Item = &'a Script>,
+    ) -> Vec<&'a Script> {
+        let mut scripts = scripts.into_iter().collect::<Vec<_>>();
+        scripts.sort_by_key(|script| {
+            (
+                self.order
+                    .iter()
+                    .position(|name| *name == script.name)
+                    .unwrap_or(usize::MAX),
+                script.name.clone(),
+            )
+        });
+        scripts
+    }
+
+    /// The scripts that are displayed together with the script: the favorites, or the other scripts of its directory.
+    fn siblings<'a>(&self, scripts: &'a [Script], script: &Script) -> Vec<&'a Script> {
+        let favorite = self.is_favorite(script);
+        self.sort(
+            scripts.iter().filter(|s| {
+                self.is_favorite(s) == favorite && (favorite || s.dir() == script.dir())
+            }),
+        )
+    }
+
+    /// Moves the script one position up or down among the scripts it is displayed with.
+    pub(crate) fn move_script(&mut self, scripts: &[Script], name: &str, up: bool) {
+        let Some(script) = scripts.iter().find(|s| s.name == name) else {
+            return;
+        };
+        let mut siblings = self
+            .siblings(scripts, script)
+            .into_iter()
+            .map(|s| s.name.clone())
+            .collect::<Vec<_>>();
+        let Some(index) = siblings.iter().position(|n| n == name) else {
+            return;
+        };
+        let other = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|other| *other < siblings.len())
+        };
+        let Some(other) = other else {
+            return;
+        };
+        siblings.swap(index, other);
+        self.order.retain(|n| !siblings.contains(n));
+        self.order.extend(siblings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scripts::{FrontMatter, ScriptType};
+    use std::path::PathBuf;
+
+    fn script(name: &str) -> Script {
+        Script {
+            path: PathBuf::from("/scripts").join(name),
+            name: name.to_string(),
+            _type: ScriptType::Shell,
+            front_matter: FrontMatter::default(),
+        }
+    }
+
+    fn names<'a>(scripts: impl IntoIterator<Item = &'a Script>) -> Vec<&'a str> {
+        scripts.into_iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn script_meta_settings() {
+        let scripts = ["a.sh", "b.sh", "c.sh", "imx8/flash.sh", "imx8/boot.sh"].map(script);
+        let mut settings = ScriptMetaSettings::default();
+        assert_eq!(
+            names(settings.sort(&scripts)),
+            vec!["a.sh", "b.sh", "c.sh", "imx8/boot.sh", "imx8/flash.sh"]
+        );
+
+        settings.move_script(&scripts, "c.sh", true);
+        settings.move_script(&scripts, "imx8/boot.sh", false);
+        // Moving beyond the first script of the directory keeps the order
+        settings.move_script(&scripts, "imx8/flash.sh", true);
+        assert_eq!(
+            names(settings.sort(&scripts)),
+            vec!["a.sh", "c.sh", "b.sh", "imx8/flash.sh", "imx8/boot.sh"]
+        );
+
+        settings.toggle_favorite("imx8/boot.sh");
+        settings.toggle_favorite("b.sh");
+        assert!(settings.is_favorite(&scripts[1]));
+        settings.move_script(&scripts, "b.sh", false);
+        assert_eq!(
+            names(settings.siblings(&scripts, &scripts[1])),
+            vec!["imx8/boot.sh", "b.sh"]
+        );
+
+        assert_eq!(settings.display_name(&scripts[3]), "flash.sh");
+        settings.set_display_name("imx8/flash.sh", " Flash U-Boot ");
+        assert_eq!(settings.display_name(&scripts[3]), "Flash U-Boot");
+        settings.set_display_name("imx8/flash.sh", "");
+        settings.toggle_favorite("b.sh");
+        assert_eq!(settings.scripts.len(), 1);
+    }
+}
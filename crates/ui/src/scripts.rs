@@ -2,18 +2,65 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
+use crate::app::{AppMsg, ConnectedMsg};
 use crate::util;
 use anyhow::Context;
 use core::fmt::Display;
 use core::ops::{Deref, DerefMut};
+use core::time::Duration;
+use iced::futures::{self, SinkExt};
+use iced::stream;
 use notify::Watcher;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use tokio::io::AsyncReadExt;
 use tokio::sync::mpsc;
+use tokio::time;
 use tracing::error;
 
+/// The default depth of the subdirectories that are scanned for scripts.
+pub(crate) const DEFAULT_MAX_DEPTH: u32 = 3;
+/// Time waited after a change in the scripts directory before rescanning it, so that bursts of changes,
+/// e.g. an editor saving a file, cause a single rescan.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// The timeout of scripts that don't declare one, from the settings in minutes. `0` disables it.
+pub(crate) fn default_timeout(mins: u32) -> Option<Duration> {
+    (mins > 0).then(|| Duration::from_secs(60 * u64::from(mins)))
+}
+
+/// Python program that imports the labgrid modules and names imported by the script passed as first argument,
+/// printing the failed imports to stderr.
+const LABGRID_IMPORTS_CHECK: &str = r#"
+import ast, importlib, sys
+path = sys.argv[1]
+with open(path, "rb") as f:
+    tree = ast.parse(f.read(), path)
+failed = False
+def check(lineno, module, name=None):
+    global failed
+    try:
+        imported = importlib.import_module(module)
+        if name is not None and name != "*" and not hasattr(imported, name):
+            importlib.import_module(module + "." + name)
+    except Exception as e:
+        failed = True
+        target = module if name is None else module + "." + name
+        print(f"{path}:{lineno}: Importing '{target}' failed: {e!r}", file=sys.stderr)
+for node in ast.walk(tree):
+    if isinstance(node, ast.Import):
+        for alias in node.names:
+            if alias.name.split(".")[0] == "labgrid":
+                check(node.lineno, alias.name)
+    elif isinstance(node, ast.ImportFrom) and node.level == 0 and node.module:
+        if node.module.split(".")[0] == "labgrid":
+            for alias in node.names:
+                check(node.lineno, node.module, alias.name)
+sys.exit(1 if failed else 0)
+"#;
+
 /// A specific environment entry.
 ///
 /// Used to let users change specific environment values which will be passed to the executed script.
@@ -82,21 +129,19 @@ impl Env {
 
 /// Holds information for found scripts in the specified directory.
 ///
-/// Is also responsible for holding a file watcher that looks for changes in this directory.
+/// Changes in the directory are picked up through [watch_subscription].
 #[derive(Debug)]
 pub(crate) struct Scripts {
     /// The path to the script directory.
     pub(crate) dir: PathBuf,
-    /// The found scripts found in the specified directory.
+    /// The found scripts found in the specified directory, sorted by their name.
     pub(crate) scripts: Vec<Script>,
+    /// Depth of the subdirectories that are scanned for scripts, `0` only scans the directory itself.
+    pub(crate) max_depth: u32,
+    /// The timeout of scripts that don't declare one in their front-matter.
+    pub(crate) default_timeout: Option<Duration>,
     /// The environment that will be passed when executing a script.
     pub(crate) env: Env,
-    /// Watches the script directory while it is held.
-    ///
-    /// It its drop-guarded, so will stop watching and calling the specified closure defined in `watch()`
-    /// as soon as it dropped.
-    #[allow(unused)]
-    watcher: Option<notify::RecommendedWatcher>,
 }
 
 impl Default for Scripts {
@@ -104,64 +149,61 @@ impl Default for Scripts {
         Self {
             dir: util::default_scripts_dir(),
             scripts: Vec::default(),
-            watcher: None,
+            max_depth: DEFAULT_MAX_DEPTH,
+            default_timeout: None,
             env: Env::default(),
         }
     }
 }
 
 impl Scripts {
-    /// Finds scripts in the supplied directory.
-    pub(crate) fn from_dir(dir: PathBuf) -> anyhow::Result<Self> {
+    /// Finds scripts in the supplied directory and its subdirectories up to `max_depth`.
+    pub(crate) fn from_dir(dir: PathBuf, max_depth: u32) -> anyhow::Result<Self> {
         if !dir.exists() || !dir.is_dir() {
             return Err(anyhow::anyhow!("Path must point to a directory"));
         }
-        let scripts = scripts_in_dir(&dir)?;
+        let scripts = scripts_in_dir(&dir, max_depth)?;
         Ok(Self {
             dir,
             scripts,
-            watcher: None,
+            max_depth,
+            default_timeout: None,
             env: Env::with_env(),
         })
     }
 
     /// Performs a rescan of the scripts directory.
     pub(crate) fn rescan(&mut self) -> anyhow::Result<()> {
-        let scripts = scripts_in_dir(&self.dir)?;
+        let scripts = scripts_in_dir(&self.dir, self.max_depth)?;
         self.scripts = scripts;
         Ok(())
     }
 
-    /// Starts watching the scripts directory by registering a file watcher.
-    ///
-    /// the file watcher will send events through the channel which can be received
-    /// by the returned channel receiver.
-    #[allow(unused)]
-    pub(crate) fn watch(&mut self) -> anyhow::Result<mpsc::UnboundedReceiver<()>> {
-        let (tx, rx) = mpsc::unbounded_channel();
-        let mut watcher = notify::recommended_watcher(
-            move |res: Result<notify::Event, notify::Error>| match res {
-                Ok(_) => {
-                    // Nothing to do if sending fails
-                    let _ = tx.send(());
-                }
-                Err(err) => {
-                    error!(?err, "Watch error");
-                }
-            },
-        )
-        .context("Creating watcher")?;
-        watcher
-            .watch(&self.dir, notify::RecursiveMode::NonRecursive)
-            .context("Start watching dir")?;
-        self.watcher = Some(watcher);
-        Ok(rx)
+    /// Returns the script with the supplied name, the path relative to the scripts directory.
+    pub(crate) fn get(&self, name: &str) -> Option<&Script> {
+        self.scripts.iter().find(|s| s.name == name)
     }
 
-    /// Stops watching the scripts directory by taking the register file watcher and dropping it.
-    #[allow(unused)]
-    pub(crate) fn unwatch(&mut self) {
-        self.watcher.take();
+    /// Groups the scripts by the subdirectory they are located in.
+    ///
+    /// Keys are the relative directory paths, `""` for the scripts directory itself.
+    /// Directories only containing subdirectories with scripts are included with no scripts,
+    /// so the keys form a tree.
+    pub(crate) fn by_dir(&self) -> BTreeMap<&str, Vec<&Script>> {
+        let mut dirs = BTreeMap::<&str, Vec<&Script>>::new();
+        for script in &self.scripts {
+            let dir = script.dir();
+            let mut ancestor = dir;
+            while let Some((parent, _)) = ancestor.rsplit_once('/') {
+                dirs.entry(parent).or_default();
+                ancestor = parent;
+            }
+            if !dir.is_empty() {
+                dirs.entry("").or_default();
+            }
+            dirs.entry(dir).or_default().push(script);
+        }
+        dirs
     }
 
     /// Returns the current scripts directory.
@@ -175,23 +217,167 @@ impl Scripts {
     }
 }
 
-/// Returns all found python scripts in the supplied directory.
-fn scripts_in_dir(scripts_dir: impl AsRef<Path>) -> anyhow::Result<Vec<Script>> {
-    let dir = std::fs::read_dir(scripts_dir).context("Enumerating files in scripts dir")?;
-    Ok(dir
-        .into_iter()
-        .filter_map(|f| {
-            let Ok(f) = f else { return None };
-            Script::from_path(f.path()).ok()
+/// Returns all found scripts in the supplied directory and its subdirectories up to `max_depth`, sorted by name.
+///
+/// Hidden directories and python caches are skipped, unreadable subdirectories are ignored.
+fn scripts_in_dir(scripts_dir: impl AsRef<Path>, max_depth: u32) -> anyhow::Result<Vec<Script>> {
+    let scripts_dir = scripts_dir.as_ref();
+    let mut scripts = Vec::new();
+    let mut dirs = vec![(scripts_dir.to_path_buf(), 0)];
+    while let Some((dir, depth)) = dirs.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if depth == 0 => {
+                return Err(err).context("Enumerating files in scripts dir");
+            }
+            Err(err) => {
+                error!(?err, dir = %dir.display(), "Enumerating files in scripts subdir");
+                continue;
+            }
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let name = entry.file_name();
+                let skipped = name.to_string_lossy().starts_with('.') || name == "__pycache__";
+                if depth < max_depth && !skipped {
+                    dirs.push((path, depth + 1));
+                }
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(scripts_dir) else {
+                continue;
+            };
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if let Ok(script) = Script::from_path(path.clone()) {
+                scripts.push(Script { name, ..script });
+            }
+        }
+    }
+    scripts.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(scripts)
+}
+
+/// Starts watching the directory by registering a file watcher, recursively if `recursive` is set.
+///
+/// The file watcher will send events through the channel which can be received by the returned channel receiver,
+/// until the returned watcher is dropped. File accesses are ignored, so that rescanning doesn't trigger itself.
+fn watch(
+    dir: &Path,
+    recursive: bool,
+) -> anyhow::Result<(notify::RecommendedWatcher, mpsc::UnboundedReceiver<()>)> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let mut watcher =
+        notify::recommended_watcher(move |res: Result<notify::Event, notify::Error>| match res {
+            Ok(event) if event.kind.is_access() => {}
+            Ok(_) => {
+                // Nothing to do if sending fails
+                let _ = tx.send(());
+            }
+            Err(err) => {
+                error!(?err, "Watch error");
+            }
         })
-        .collect())
+        .context("Creating watcher")?;
+    let mode = if recursive {
+        notify::RecursiveMode::Recursive
+    } else {
+        notify::RecursiveMode::NonRecursive
+    };
+    watcher.watch(dir, mode).context("Start watching dir")?;
+    Ok((watcher, rx))
+}
+
+/// An iced subscription that watches the scripts directory and its subdirectories if `max_depth` is not `0`.
+///
+/// Triggers `ConnectedMsg::RescanScriptsDir` messages when files are added, removed or modified.
+pub(crate) fn watch_subscription(target: &(PathBuf, u32)) -> impl futures::Stream<Item = AppMsg> {
+    let (dir, max_depth) = target.clone();
+    stream::channel(
+        1,
+        move |mut output: futures::channel::mpsc::Sender<AppMsg>| async move {
+            let (_watcher, mut changes) = match watch(&dir, max_depth > 0) {
+                Ok(watch) => watch,
+                Err(err) => {
+                    error!(?err, dir = %dir.display(), "Watching scripts dir failed");
+                    return;
+                }
+            };
+            while changes.recv().await.is_some() {
+                time::sleep(WATCH_DEBOUNCE).await;
+                while changes.try_recv().is_ok() {}
+                let _ = output
+                    .send(AppMsg::Connected(ConnectedMsg::RescanScriptsDir))
+                    .await;
+            }
+        },
+    )
+}
+
+/// Metadata declared by a script in its leading comment block.
+///
+/// Entries have the form `# labgrid-ui-<key>: <value>`, e.g.:
+///
+/// ```text
+/// # labgrid-ui-cleanup: labgrid-client -p "$LG_PLACE" power off
+/// # labgrid-ui-timeout: 600
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct FrontMatter {
+    /// Shell command that is run after the script was aborted,
+    /// to ensure the hardware isn't left in a bad state.
+    pub(crate) cleanup: Option<String>,
+    /// Seconds after which the script is killed, overriding the default timeout. `0` disables it.
+    pub(crate) timeout_secs: Option<u64>,
+}
+
+impl FrontMatter {
+    const PREFIX: &str = "labgrid-ui-";
+
+    /// Parses the front-matter from the script contents.
+    ///
+    /// Only the leading block of comment and empty lines is considered, unknown keys are ignored.
+    pub(crate) fn parse(contents: &str) -> Self {
+        let mut front_matter = Self::default();
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() {
+                continue;
+            }
+            let Some(comment) = line.strip_prefix('#') else {
+                break;
+            };
+            let Some((key, value)) = comment
+                .trim()
+                .strip_prefix(Self::PREFIX)
+                .and_then(|entry| entry.split_once(':'))
+            else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "cleanup" if !value.is_empty() => front_matter.cleanup = Some(value.to_string()),
+                "timeout" => front_matter.timeout_secs = value.parse().ok(),
+                _ => {}
+            }
+        }
+        front_matter
+    }
 }
 
 /// Represents a single found script.
 #[derive(Debug, Clone)]
 pub(crate) struct Script {
     pub(crate) path: PathBuf,
+    /// The path relative to the scripts directory with `/` separators, identifying the script.
+    ///
+    /// Only the file name for scripts that are not located in the scripts directory.
+    pub(crate) name: String,
     pub(crate) _type: ScriptType,
+    pub(crate) front_matter: FrontMatter,
 }
 
 impl PartialEq for Script {
@@ -225,6 +411,10 @@ impl ScriptType {
 impl Script {
     /// Creates a new script from the supplied path to the script file.
     pub(crate) fn from_path(path: PathBuf) -> anyhow::Result<Self> {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
         // Follows symlinks, which we'll allow
         let Ok(path) = std::fs::canonicalize(path) else {
             return Err(anyhow::anyhow!("Unable to canonicalize path"));
@@ -236,7 +426,32 @@ impl Script {
             return Err(anyhow::anyhow!("File does not have an extension"));
         };
         let _type = ScriptType::from_ext(ext)?;
-        Ok(Self { path, _type })
+        let front_matter = std::fs::read_to_string(&path)
+            .map(|contents| FrontMatter::parse(&contents))
+            .unwrap_or_default();
+        Ok(Self {
+            path,
+            name,
+            _type,
+            front_matter,
+        })
+    }
+
+    /// The relative path of the subdirectory the script is located in, `""` for the scripts directory.
+    pub(crate) fn dir(&self) -> &str {
+        self.name
+            .rsplit_once('/')
+            .map(|(dir, _)| dir)
+            .unwrap_or_default()
+    }
+
+    /// The timeout of the script, the one declared in its front-matter or `default`.
+    pub(crate) fn timeout(&self, default: Option<Duration>) -> Option<Duration> {
+        match self.front_matter.timeout_secs {
+            Some(0) => None,
+            Some(secs) => Some(Duration::from_secs(secs)),
+            None => default,
+        }
     }
 
     //// Returns the path to the script file.
@@ -249,42 +464,251 @@ impl Script {
     /// It will pass the supplied environment to the execution environment
     /// And, if the script is python, run through it through the python interpreter
     /// found by the supplied virtual environment directory.
+    /// When the script exceeds the `timeout`, it is killed and the output until then is returned.
     ///
-    /// Returns: `Result<(exit-code, stdout, stderr)>`
+    /// Returns: `Result<(exit, stdout, stderr)>`
     pub(crate) async fn execute(
         &self,
         venv_dir: impl AsRef<Path>,
         env: &Env,
-    ) -> anyhow::Result<(i32, String, String)> {
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<(ScriptExit, String, String)> {
+        self.execute_with(venv_dir, env, timeout, true).await
+    }
+
+    /// Executes the script like [`Script::execute`], without printing the environment and the output,
+    /// for callers that print the output themselves.
+    pub(crate) async fn execute_quiet(
+        &self,
+        venv_dir: impl AsRef<Path>,
+        env: &Env,
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<(ScriptExit, String, String)> {
+        self.execute_with(venv_dir, env, timeout, false).await
+    }
+
+    async fn execute_with(
+        &self,
+        venv_dir: impl AsRef<Path>,
+        env: &Env,
+        timeout: Option<Duration>,
+        print: bool,
+    ) -> anyhow::Result<(ScriptExit, String, String)> {
         let program = match self._type {
             ScriptType::Shell => PathBuf::from("/usr/bin/bash"),
             ScriptType::Python => venv_dir.as_ref().join("bin").join("python3"),
         };
 
+        run_command_with_timeout(
+            tokio::process::Command::new(program.as_os_str()).args([&self.path]),
+            env,
+            timeout,
+            print,
+        )
+        .await
+    }
+
+    /// Validates a python script without executing it, with the python interpreter of the virtual environment.
+    ///
+    /// The script is compiled through `py_compile`, the bytecode cache is written to the temporary directory
+    /// instead of the scripts directory. When `check_imports` is set, the labgrid modules imported by the script
+    /// are imported as well, so errors of a mismatching labgrid version are found.
+    ///
+    /// Returns: `Result<Option<errors>>`, `None` if the script is valid.
+    pub(crate) async fn validate(
+        &self,
+        venv_dir: impl AsRef<Path>,
+        check_imports: bool,
+    ) -> anyhow::Result<Option<String>> {
+        let python = venv_dir.as_ref().join("bin").join("python3");
+        let pycache_prefix = std::env::temp_dir().join("labgrid-ui-pycache");
+        let (exit_code, _, stderr) = run_command(
+            tokio::process::Command::new(&python)
+                .args(["-m", "py_compile"])
+                .arg(&self.path)
+                .env("PYTHONPYCACHEPREFIX", &pycache_prefix),
+            &Env::default(),
+        )
+        .await?;
+        if exit_code != 0 {
+            return Ok(Some(stderr));
+        }
+        if !check_imports {
+            return Ok(None);
+        }
+        let (exit_code, _, stderr) = run_command(
+            tokio::process::Command::new(&python)
+                .args(["-c", LABGRID_IMPORTS_CHECK])
+                .arg(&self.path)
+                .env("PYTHONPYCACHEPREFIX", &pycache_prefix),
+            &Env::default(),
+        )
+        .await?;
+        Ok((exit_code != 0).then_some(stderr))
+    }
+
+    /// Runs the cleanup command declared in the script front-matter, if there is one.
+    ///
+    /// The command is run by bash with the supplied environment,
+    /// and with the `bin` directory of the virtual environment prepended to `PATH`
+    /// so that for example `labgrid-client` is found.
+    ///
+    /// Returns: `Result<Option<(exit-code, stdout, stderr)>>`
+    pub(crate) async fn cleanup(
+        &self,
+        venv_dir: impl AsRef<Path>,
+        env: &Env,
+    ) -> anyhow::Result<Option<(i32, String, String)>> {
+        let Some(cleanup) = &self.front_matter.cleanup else {
+            return Ok(None);
+        };
+
+        run_command(
+            tokio::process::Command::new("/usr/bin/bash")
+                .args(["-c", cleanup])
+                .env("PATH", venv_path(venv_dir)?),
+            env,
+        )
+        .await
+        .map(Some)
+    }
+}
+
+/// Runs `labgrid-client` of the virtual environment with the supplied arguments.
+///
+/// `LG_COORDINATOR` is set to the supplied coordinator address.
+///
+/// Returns: `Result<(exit-code, stdout, stderr)>`
+pub(crate) async fn labgrid_client(
+    venv_dir: impl AsRef<Path>,
+    coordinator_address: &str,
+    args: &[&str],
+    env: &Env,
+) -> anyhow::Result<(i32, String, String)> {
+    run_command(
+        tokio::process::Command::new("labgrid-client")
+            .args(args)
+            .env("PATH", venv_path(venv_dir)?)
+            .env("LG_COORDINATOR", coordinator_address),
+        env,
+    )
+    .await
+}
+
+/// `PATH` with the `bin` directory of the virtual environment prepended.
+fn venv_path(venv_dir: impl AsRef<Path>) -> anyhow::Result<std::ffi::OsString> {
+    let mut path = std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default())
+        .collect::<Vec<PathBuf>>();
+    path.insert(0, venv_dir.as_ref().join("bin"));
+    std::env::join_paths(path).context("Join PATH")
+}
+
+/// Runs the command to completion, passing the supplied environment.
+///
+/// Returns: `Result<(exit-code, stdout, stderr)>`
+async fn run_command(
+    command: &mut tokio::process::Command,
+    env: &Env,
+) -> anyhow::Result<(i32, String, String)> {
+    println!("### Executing Command ###\nEnv:\n{env}");
+    let child = command
+        .envs(env.env_vars())
+        .kill_on_drop(true)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Script execution failed")?;
+    let output = child
+        .wait_with_output()
+        .await
+        .context("Failed to wait on spawned command child")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    println!("### Command finished ###");
+    println!("### Command stdout ###\n{stdout}\n");
+    eprintln!("### Command stderr ###\n{stderr}\n");
+    Ok((
+        exit_code(output.status),
+        stdout.to_string(),
+        stderr.to_string(),
+    ))
+}
+
+/// Runs the command until it exits or exceeds the `timeout`, then it is killed.
+///
+/// Only the command itself is killed, processes it started in the background may outlive it.
+/// With `print`, the environment and the output are printed to stdout and stderr.
+///
+/// Returns: `Result<(exit, stdout, stderr)>`
+async fn run_command_with_timeout(
+    command: &mut tokio::process::Command,
+    env: &Env,
+    timeout: Option<Duration>,
+    print: bool,
+) -> anyhow::Result<(ScriptExit, String, String)> {
+    if print {
         println!("### Executing Command ###\nEnv:\n{env}");
-        let child = tokio::process::Command::new(program.as_os_str())
-            .args([&self.path])
-            .envs(env.env_vars())
-            .kill_on_drop(true)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .context("Script execution failed")?;
-        let output = child
-            .wait_with_output()
-            .await
-            .context("Failed to wait on spawned command child")?;
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        println!("### Command finished ###");
+    }
+    let mut child = command
+        .envs(env.env_vars())
+        .kill_on_drop(true)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Script execution failed")?;
+    let mut stdout_pipe = child.stdout.take().context("Take stdout of child")?;
+    let mut stderr_pipe = child.stderr.take().context("Take stderr of child")?;
+    let (mut stdout, mut stderr) = (Vec::new(), Vec::new());
+    // Reads into the buffers as the output arrives, so they hold the partial output when timing out
+    let run = async {
+        tokio::try_join!(
+            stdout_pipe.read_to_end(&mut stdout),
+            stderr_pipe.read_to_end(&mut stderr),
+            child.wait()
+        )
+    };
+    let result = match timeout {
+        Some(timeout) => time::timeout(timeout, run).await.map_err(|_| timeout),
+        None => Ok(run.await),
+    };
+    let exit = match result {
+        Ok(result) => {
+            let (_, _, status) = result.context("Failed to wait on spawned command child")?;
+            ScriptExit::Exited(exit_code(status))
+        }
+        Err(timeout) => {
+            child.kill().await.context("Kill timed out command child")?;
+            ScriptExit::TimedOut(timeout)
+        }
+    };
+    let stdout = String::from_utf8_lossy(&stdout);
+    let stderr = String::from_utf8_lossy(&stderr);
+    if print {
+        println!("### Command finished: {exit:?} ###");
         println!("### Command stdout ###\n{stdout}\n");
         eprintln!("### Command stderr ###\n{stderr}\n");
-        Ok((
-            output.status.code().unwrap_or(0),
-            stdout.to_string(),
-            stderr.to_string(),
-        ))
     }
+    Ok((exit, stdout.to_string(), stderr.to_string()))
+}
+
+/// The exit code of the process, `128 + signal` like in shells when it was killed by a signal.
+fn exit_code(status: std::process::ExitStatus) -> i32 {
+    if let Some(code) = status.code() {
+        return code;
+    }
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(&status) {
+        return 128 + signal;
+    }
+    1
+}
+
+/// How the execution of a script ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ScriptExit {
+    Exited(i32),
+    /// The script exceeded the timeout and was killed.
+    TimedOut(Duration),
 }
 
 /// Represents the current status of the script.
@@ -302,6 +726,28 @@ pub(crate) enum ScriptStatus {
         script: Script,
         exit_code: i32,
     },
+    /// The script was killed after exceeding the timeout.
+    TimedOut {
+        script: Script,
+        timeout: Duration,
+    },
+    /// The script was aborted and the cleanup command of its front-matter is running.
+    CleaningUp {
+        script: Script,
+        #[allow(unused)]
+        handle: iced::task::Handle,
+        /// The timeout when the script was killed after exceeding it.
+        timed_out: Option<Duration>,
+    },
+}
+
+/// The result of validating a python script with [Script::validate].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ScriptValidation {
+    Running,
+    Valid,
+    /// The script has syntax or import errors, which are reported in the script output.
+    Invalid,
 }
 
 /// Validate if the supplied path points to a valid python virtual environment directory.
@@ -322,3 +768,134 @@ pub(crate) fn validate_venv_dir(dir: impl AsRef<Path>) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn front_matter_parse() {
+        let front_matter = FrontMatter::parse(
+            "#!/usr/bin/env python3\n\n# labgrid-ui-cleanup:  labgrid-client -p \"$LG_PLACE\" power off \n# labgrid-ui-timeout: 600\n# labgrid-ui-unknown: foo\nimport os\n",
+        );
+        assert_eq!(
+            front_matter.cleanup.as_deref(),
+            Some("labgrid-client -p \"$LG_PLACE\" power off")
+        );
+        assert_eq!(front_matter.timeout_secs, Some(600));
+
+        let front_matter = FrontMatter::parse("import os\n# labgrid-ui-cleanup: ignored\n");
+        assert_eq!(front_matter, FrontMatter::default());
+    }
+
+    #[tokio::test]
+    async fn execute_timeout() {
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-timeout-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("hangs.sh");
+        std::fs::write(
+            &path,
+            "# labgrid-ui-timeout: 0\necho \"partial\"\nsleep 10\necho \"never\"\n",
+        )
+        .unwrap();
+        let script = Script::from_path(path).unwrap();
+        assert_eq!(script.timeout(Some(Duration::from_secs(60))), None);
+
+        let timeout = Duration::from_millis(500);
+        let executed = script
+            .execute("/nonexistent", &Env::default(), Some(timeout))
+            .await;
+        std::fs::remove_dir_all(&dir).unwrap();
+        let (exit, stdout, _) = executed.unwrap();
+        assert_eq!(exit, ScriptExit::TimedOut(timeout));
+        assert_eq!(stdout, "partial\n");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn execute_killed() {
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-killed-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("killed.sh");
+        std::fs::write(&path, "kill -TERM $$\n").unwrap();
+        let script = Script::from_path(path).unwrap();
+
+        let executed = script.execute("/nonexistent", &Env::default(), None).await;
+        std::fs::remove_dir_all(&dir).unwrap();
+        let (exit, _, _) = executed.unwrap();
+        assert_eq!(exit, ScriptExit::Exited(128 + 15));
+    }
+
+    #[test]
+    fn scripts_in_subdirs() {
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-scripts-{}", std::process::id()));
+        for subdir in ["imx8/flash", "rpi", ".git", "__pycache__"] {
+            std::fs::create_dir_all(dir.join(subdir)).unwrap();
+        }
+        for file in [
+            "power.sh",
+            "notes.txt",
+            "imx8/flash/uboot.py",
+            "imx8/flash/deep/ignored.py",
+            "rpi/boot.py",
+            ".git/hook.sh",
+            "__pycache__/cached.py",
+        ] {
+            let path = dir.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+
+        let scripts = Scripts::from_dir(dir.clone(), 2);
+        let flat = Scripts::from_dir(dir.clone(), 0);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let scripts = scripts.unwrap();
+        assert_eq!(
+            scripts.iter().map(|s| s.name.as_str()).collect::<Vec<_>>(),
+            vec!["imx8/flash/uboot.py", "power.sh", "rpi/boot.py"]
+        );
+        assert_eq!(
+            scripts
+                .by_dir()
+                .into_iter()
+                .map(|(dir, scripts)| (dir, scripts.len()))
+                .collect::<Vec<_>>(),
+            vec![("", 1), ("imx8", 0), ("imx8/flash", 1), ("rpi", 1)]
+        );
+        assert!(scripts.get("rpi/boot.py").is_some());
+        assert_eq!(scripts.get("rpi/boot.py").map(Script::dir), Some("rpi"));
+        assert_eq!(
+            flat.unwrap()
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["power.sh"]
+        );
+    }
+
+    #[tokio::test]
+    async fn watch_scripts_dir() {
+        use iced::futures::StreamExt;
+
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("imx8")).unwrap();
+        let mut rescans = Box::pin(watch_subscription(&(dir.clone(), 1)));
+        // The watcher is registered once the stream is polled, so keep changing files until it is noticed
+        let rescan = time::timeout(Duration::from_secs(5), async {
+            let mut index = 0;
+            loop {
+                index += 1;
+                std::fs::write(dir.join(format!("imx8/flash-{index}.py")), "").unwrap();
+                if let Ok(msg) = time::timeout(Duration::from_millis(200), rescans.next()).await {
+                    break msg;
+                }
+            }
+        })
+        .await;
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(
+            rescan,
+            Ok(Some(AppMsg::Connected(ConnectedMsg::RescanScriptsDir)))
+        ));
+    }
+}
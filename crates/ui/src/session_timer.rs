@@ -0,0 +1,228 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::AppMsg;
+use core::time::Duration;
+use iced::futures;
+use std::collections::HashMap;
+use std::time::Instant;
+use tokio::time;
+use tokio_stream::wrappers::IntervalStream;
+use tokio_stream::StreamExt;
+
+/// Settings for automatically releasing places acquired through the UI after being idle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct AutoReleaseSettings {
+    pub(crate) enabled: bool,
+    /// Minutes without activity on the place after which it is released.
+    pub(crate) idle_mins: u32,
+    /// Minutes before the release at which a warning is shown.
+    pub(crate) warn_mins: u32,
+    /// Release the places acquired through the UI when the application exits.
+    ///
+    /// Independent of `enabled`.
+    pub(crate) release_on_exit: bool,
+}
+
+impl Default for AutoReleaseSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            idle_mins: 60,
+            warn_mins: 5,
+            release_on_exit: false,
+        }
+    }
+}
+
+impl AutoReleaseSettings {
+    pub(crate) fn idle_duration(&self) -> Duration {
+        Duration::from_secs(u64::from(self.idle_mins) * 60)
+    }
+
+    pub(crate) fn warn_duration(&self) -> Duration {
+        Duration::from_secs(u64::from(self.warn_mins) * 60)
+    }
+}
+
+/// Tracks the session of a place that was acquired through the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SessionTimer {
+    acquired: Instant,
+    /// Reset when the place is used through the UI or the session is extended.
+    last_activity: Instant,
+    /// Cleared when the user chose to keep the place regardless of the idle duration.
+    auto_release: bool,
+    /// Set once the warning before the release was issued, to issue it only once.
+    warned: bool,
+}
+
+/// The state of a session timer at a point in time, used for displaying it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SessionTimerStatus {
+    pub(crate) elapsed: Duration,
+    /// `None` when the place is not automatically released.
+    pub(crate) remaining: Option<Duration>,
+    /// Set when the place is about to be released.
+    pub(crate) warning: bool,
+}
+
+/// What needs to happen for a place when its session timer is ticked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SessionTimerAction {
+    Warn { remaining: Duration },
+    Release,
+}
+
+impl SessionTimer {
+    pub(crate) fn new(now: Instant) -> Self {
+        Self {
+            acquired: now,
+            last_activity: now,
+            auto_release: true,
+            warned: false,
+        }
+    }
+
+    /// Registers activity on the place, restarting the idle duration.
+    pub(crate) fn touch(&mut self, now: Instant) {
+        self.last_activity = now;
+        self.warned = false;
+    }
+
+    /// Keep the place acquired, it will not be released automatically anymore.
+    pub(crate) fn keep(&mut self) {
+        self.auto_release = false;
+    }
+
+    /// The remaining time until the place is released, if it is released automatically.
+    pub(crate) fn remaining(
+        &self,
+        settings: &AutoReleaseSettings,
+        now: Instant,
+    ) -> Option<Duration> {
+        (settings.enabled && self.auto_release).then(|| {
+            settings
+                .idle_duration()
+                .saturating_sub(now.saturating_duration_since(self.last_activity))
+        })
+    }
+
+    pub(crate) fn status(
+        &self,
+        settings: &AutoReleaseSettings,
+        now: Instant,
+    ) -> SessionTimerStatus {
+        let remaining = self.remaining(settings, now);
+        SessionTimerStatus {
+            elapsed: now.saturating_duration_since(self.acquired),
+            remaining,
+            warning: remaining.is_some_and(|r| r <= settings.warn_duration()),
+        }
+    }
+}
+
+/// Advances the session timers, returning the places which need to be warned about or released.
+///
+/// Timers of released places are removed.
+pub(crate) fn tick(
+    timers: &mut HashMap<String, SessionTimer>,
+    settings: &AutoReleaseSettings,
+    now: Instant,
+) -> Vec<(String, SessionTimerAction)> {
+    let mut actions = Vec::new();
+    for (place_name, timer) in timers.iter_mut() {
+        match timer.remaining(settings, now) {
+            Some(Duration::ZERO) => actions.push((place_name.clone(), SessionTimerAction::Release)),
+            Some(remaining) if remaining <= settings.warn_duration() && !timer.warned => {
+                timer.warned = true;
+                actions.push((place_name.clone(), SessionTimerAction::Warn { remaining }));
+            }
+            _ => {}
+        }
+    }
+    for (place_name, action) in &actions {
+        if *action == SessionTimerAction::Release {
+            timers.remove(place_name);
+        }
+    }
+    actions.sort_by(|a, b| a.0.cmp(&b.0));
+    actions
+}
+
+/// An iced subscription that triggers periodic `AppMsg::SessionTimerTick` messages while session timers are running.
+pub(crate) fn periodic_tick_subscription() -> impl futures::Stream<Item = AppMsg> {
+    const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+    IntervalStream::new(time::interval(TICK_INTERVAL)).map(|_| AppMsg::SessionTimerTick)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_timer_tick() {
+        let settings = AutoReleaseSettings {
+            enabled: true,
+            idle_mins: 10,
+            warn_mins: 2,
+            release_on_exit: false,
+        };
+        let start = Instant::now();
+        let mins = |m: u64| start + Duration::from_secs(m * 60);
+        let mut timers = HashMap::from([
+            ("board-1".to_string(), SessionTimer::new(start)),
+            ("board-2".to_string(), SessionTimer::new(start)),
+        ]);
+        timers.get_mut("board-2").unwrap().keep();
+
+        assert!(tick(&mut timers, &settings, mins(5)).is_empty());
+        assert_eq!(
+            tick(&mut timers, &settings, mins(8)),
+            vec![(
+                "board-1".to_string(),
+                SessionTimerAction::Warn {
+                    remaining: Duration::from_secs(2 * 60)
+                }
+            )]
+        );
+        // Warned only once
+        assert!(tick(&mut timers, &settings, mins(9)).is_empty());
+        assert_eq!(
+            timers["board-1"].status(&settings, mins(9)),
+            SessionTimerStatus {
+                elapsed: Duration::from_secs(9 * 60),
+                remaining: Some(Duration::from_secs(60)),
+                warning: true,
+            }
+        );
+
+        // Activity restarts the idle duration
+        timers.get_mut("board-1").unwrap().touch(mins(9));
+        assert!(tick(&mut timers, &settings, mins(10)).is_empty());
+        assert_eq!(
+            tick(&mut timers, &settings, mins(19)),
+            vec![("board-1".to_string(), SessionTimerAction::Release)]
+        );
+        assert!(!timers.contains_key("board-1"));
+        // Kept places are never released
+        assert!(tick(&mut timers, &settings, mins(120)).is_empty());
+        assert_eq!(timers["board-2"].remaining(&settings, mins(120)), None);
+    }
+
+    #[test]
+    fn session_timer_disabled() {
+        let settings = AutoReleaseSettings::default();
+        let start = Instant::now();
+        let mut timers = HashMap::from([("board-1".to_string(), SessionTimer::new(start))]);
+        assert!(tick(
+            &mut timers,
+            &settings,
+            start + Duration::from_secs(24 * 3600)
+        )
+        .is_empty());
+    }
+}
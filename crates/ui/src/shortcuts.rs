@@ -0,0 +1,113 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppMsg, ConnectedMsg, Modal, TabId};
+use iced::keyboard;
+
+/// Maps the shortcuts opening the quick switcher, available while connected.
+///
+/// `Ctrl+F` and `Ctrl+P` open it for searching places and scripts, `Ctrl+K` as command palette.
+pub(crate) fn open_shortcuts(event: keyboard::Event) -> Option<AppMsg> {
+    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+        return None;
+    };
+    if !modifiers.command() {
+        return None;
+    }
+    let commands = match key.as_ref() {
+        keyboard::Key::Character("f" | "p") => false,
+        keyboard::Key::Character("k") => true,
+        _ => return None,
+    };
+    Some(AppMsg::ShowModal(Box::new(Modal::QuickSwitcher {
+        commands,
+    })))
+}
+
+/// Maps `Ctrl+Shift+I` to opening the protocol inspector, which is not offered anywhere else in the UI.
+pub(crate) fn protocol_inspector_shortcut(event: keyboard::Event) -> Option<AppMsg> {
+    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+        return None;
+    };
+    match key.as_ref() {
+        keyboard::Key::Character("i" | "I") if modifiers.command() && modifiers.shift() => {
+            Some(AppMsg::ShowModal(Box::new(Modal::ProtocolInspector)))
+        }
+        _ => None,
+    }
+}
+
+/// Maps the shortcuts available while connected and no modal is shown.
+///
+/// `Ctrl+R` refreshes, the number keys switch to the tab at their position.
+/// Keys typed into text inputs don't reach the shortcuts.
+pub(crate) fn connected_shortcuts(event: keyboard::Event) -> Option<AppMsg> {
+    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+        return None;
+    };
+    let msg = match key.as_ref() {
+        keyboard::Key::Character("r") if modifiers.command() => ConnectedMsg::Refresh,
+        // Shift is needed for the number keys of some layouts
+        keyboard::Key::Character(c) if !modifiers.command() && !modifiers.alt() => {
+            let position = c.parse::<usize>().ok()?;
+            let tab = TabId::ALL.get(position.checked_sub(1)?)?;
+            ConnectedMsg::TabSelected(tab.clone())
+        }
+        _ => return None,
+    };
+    Some(AppMsg::Connected(msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::keyboard::key::{Code, Physical};
+    use iced::keyboard::{Key, Location, Modifiers};
+
+    fn key_pressed(c: &str, modifiers: Modifiers) -> keyboard::Event {
+        keyboard::Event::KeyPressed {
+            key: Key::Character(c.into()),
+            modified_key: Key::Character(c.into()),
+            physical_key: Physical::Code(Code::KeyA),
+            location: Location::Standard,
+            modifiers,
+            text: None,
+            repeat: false,
+        }
+    }
+
+    #[test]
+    fn shortcuts() {
+        assert!(matches!(
+            open_shortcuts(key_pressed("k", Modifiers::CTRL)),
+            Some(AppMsg::ShowModal(modal)) if matches!(*modal, Modal::QuickSwitcher { commands: true })
+        ));
+        assert!(matches!(
+            open_shortcuts(key_pressed("f", Modifiers::CTRL)),
+            Some(AppMsg::ShowModal(modal)) if matches!(*modal, Modal::QuickSwitcher { commands: false })
+        ));
+        assert!(open_shortcuts(key_pressed("k", Modifiers::empty())).is_none());
+
+        assert!(matches!(
+            protocol_inspector_shortcut(key_pressed("I", Modifiers::CTRL | Modifiers::SHIFT)),
+            Some(AppMsg::ShowModal(modal)) if matches!(*modal, Modal::ProtocolInspector)
+        ));
+        assert!(protocol_inspector_shortcut(key_pressed("i", Modifiers::CTRL)).is_none());
+
+        assert!(matches!(
+            connected_shortcuts(key_pressed("r", Modifiers::CTRL)),
+            Some(AppMsg::Connected(ConnectedMsg::Refresh))
+        ));
+        assert!(matches!(
+            connected_shortcuts(key_pressed("3", Modifiers::empty())),
+            Some(AppMsg::Connected(ConnectedMsg::TabSelected(
+                TabId::Resources
+            )))
+        ));
+        assert!(connected_shortcuts(key_pressed("3", Modifiers::CTRL)).is_none());
+        assert!(connected_shortcuts(key_pressed("0", Modifiers::empty())).is_none());
+        assert!(connected_shortcuts(key_pressed("9", Modifiers::empty())).is_none());
+        assert!(connected_shortcuts(key_pressed("r", Modifiers::empty())).is_none());
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use labgrid_ui_core::types::{
+    Filter, MapValue, Path, Place, Reservation, ReservationState, Resource,
+};
+use std::collections::HashMap;
+
+/// A free place without aliases, tags or matches.
+pub(crate) fn place(name: &str) -> Place {
+    Place {
+        name: name.to_string(),
+        aliases: vec![],
+        comment: String::new(),
+        tags: HashMap::new(),
+        matches: vec![],
+        acquired: None,
+        acquired_resources: vec![],
+        allowed: vec![],
+        created: 0.,
+        changed: 0.,
+        reservation: None,
+    }
+}
+
+/// An available, unacquired resource of `exporter-1` in the group `board-1`, without params.
+pub(crate) fn resource(name: &str, cls: &str) -> Resource {
+    Resource {
+        path: Path {
+            exporter_name: Some("exporter-1".to_string()),
+            group_name: "board-1".to_string(),
+            resource_name: name.to_string(),
+        },
+        cls: cls.to_string(),
+        params: HashMap::new(),
+        extra: HashMap::new(),
+        acquired: String::new(),
+        available: true,
+    }
+}
+
+/// The params of a resource.
+pub(crate) fn params(params: &[(&str, MapValue)]) -> HashMap<String, MapValue> {
+    params
+        .iter()
+        .map(|(param, value)| (param.to_string(), value.clone()))
+        .collect()
+}
+
+/// A waiting reservation of `host/me` for a place with the tag `board=imx8`.
+pub(crate) fn reservation(token: &str) -> Reservation {
+    Reservation {
+        owner: "host/me".to_string(),
+        token: token.to_string(),
+        state: ReservationState::Waiting.into(),
+        prio: 0.,
+        filters: board_filter("imx8"),
+        allocations: HashMap::new(),
+        created: 0.,
+        timeout: 0.,
+    }
+}
+
+/// The filters of a reservation for a place with the tag `board`.
+pub(crate) fn board_filter(board: &str) -> HashMap<String, Filter> {
+    HashMap::from([(
+        "main".to_string(),
+        Filter(HashMap::from([("board".to_string(), board.to_string())])),
+    )])
+}
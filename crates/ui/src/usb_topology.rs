@@ -0,0 +1,223 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::exporters;
+use labgrid_ui_core::types::{MapValue, Path, Resource};
+use std::collections::BTreeMap;
+
+/// Where a USB device is plugged in, derived from the `path`, `busnum` and `devnum` params of its resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UsbLocation {
+    pub(crate) busnum: u32,
+    /// The port numbers from the root hub down to the device, empty if the port is unknown.
+    pub(crate) ports: Vec<u32>,
+    pub(crate) devnum: Option<u32>,
+}
+
+impl UsbLocation {
+    /// The location of the resource's device, `None` if it isn't a USB device or its bus is unknown.
+    ///
+    /// The `path` is the sysfs name of the device (e.g. `1-1.2.3`, optionally followed by the `:config.interface`),
+    /// which is also looked up in the extras for the resources that report it there.
+    pub(crate) fn of(resource: &Resource) -> Option<Self> {
+        let sysfs_path = resource
+            .params
+            .get("path")
+            .or_else(|| resource.extra.get("path"))
+            .and_then(|value| match value {
+                MapValue::String(path) => parse_sysfs_path(path),
+                _ => None,
+            });
+        let devnum = param_u32(resource, "devnum");
+        match sysfs_path {
+            Some((busnum, ports)) => Some(Self {
+                busnum,
+                ports,
+                devnum,
+            }),
+            None => Some(Self {
+                busnum: param_u32(resource, "busnum")?,
+                ports: Vec::new(),
+                devnum,
+            }),
+        }
+    }
+}
+
+/// A device of the USB topology.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct UsbDevice {
+    pub(crate) path: Path,
+    pub(crate) cls: String,
+    pub(crate) devnum: Option<u32>,
+    pub(crate) available: bool,
+    /// The name of the place that acquired the resource, empty if it isn't acquired.
+    pub(crate) acquired: String,
+}
+
+/// A port of a USB hub, with the devices plugged into it and the ports of a hub plugged into it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct UsbPort {
+    /// Multiple resources can share a device, e.g. the serial ports of a multi-port adapter.
+    pub(crate) devices: Vec<UsbDevice>,
+    /// Keyed by the port number.
+    pub(crate) ports: BTreeMap<u32, UsbPort>,
+}
+
+impl UsbPort {
+    fn insert(&mut self, ports: &[u32], device: UsbDevice) {
+        match ports.split_first() {
+            Some((port, downstream)) => self
+                .ports
+                .entry(*port)
+                .or_default()
+                .insert(downstream, device),
+            None => self.devices.push(device),
+        }
+    }
+
+    fn sort(&mut self) {
+        self.devices
+            .sort_by(|first, second| first.path.numeric_cmp(&second.path));
+        self.ports.values_mut().for_each(Self::sort);
+    }
+}
+
+/// The USB devices of an exporter, arranged by their bus and hub ports.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct UsbTopology {
+    /// The root hubs keyed by the bus number, devices whose port is unknown are attached to the root hub.
+    pub(crate) buses: BTreeMap<u32, UsbPort>,
+}
+
+impl UsbTopology {
+    /// Arranges the USB devices of the resources of the `exporter`, its name is empty for resources without one.
+    pub(crate) fn of_exporter<'a>(
+        resources: impl IntoIterator<Item = &'a Resource>,
+        exporter: &str,
+    ) -> Self {
+        let mut topology = Self::default();
+        for resource in resources
+            .into_iter()
+            .filter(|resource| exporters::exporter_name(&resource.path) == exporter)
+        {
+            let Some(location) = UsbLocation::of(resource) else {
+                continue;
+            };
+            topology.buses.entry(location.busnum).or_default().insert(
+                &location.ports,
+                UsbDevice {
+                    path: resource.path.clone(),
+                    cls: resource.cls.clone(),
+                    devnum: location.devnum,
+                    available: resource.available,
+                    acquired: resource.acquired.clone(),
+                },
+            );
+        }
+        topology.buses.values_mut().for_each(UsbPort::sort);
+        topology
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.buses.is_empty()
+    }
+}
+
+/// Parses the bus number and the port numbers of a sysfs USB device name like `1-1.2.3:1.0`.
+fn parse_sysfs_path(path: &str) -> Option<(u32, Vec<u32>)> {
+    let device = path.split(':').next()?;
+    let (bus, ports) = device.split_once('-')?;
+    Some((
+        bus.parse().ok()?,
+        ports
+            .split('.')
+            .map(|port| port.parse().ok())
+            .collect::<Option<Vec<u32>>>()?,
+    ))
+}
+
+fn param_u32(resource: &Resource, param: &str) -> Option<u32> {
+    match resource.params.get(param)? {
+        MapValue::Int(value) => u32::try_from(*value).ok(),
+        MapValue::UInt(value) => u32::try_from(*value).ok(),
+        MapValue::String(value) => value.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+
+    fn resource(exporter: &str, name: &str, params: &[(&str, MapValue)]) -> Resource {
+        let mut resource = Resource {
+            params: test_fixtures::params(params),
+            ..test_fixtures::resource(name, "NetworkUSBSerialPort")
+        };
+        resource.path.exporter_name = Some(exporter.to_string());
+        resource
+    }
+
+    fn path(value: &str) -> (&'static str, MapValue) {
+        ("path", MapValue::String(value.to_string()))
+    }
+
+    #[test]
+    fn usb_location() {
+        let location =
+            |params: &[(&str, MapValue)]| UsbLocation::of(&resource("exporter-1", "usb", params));
+        assert_eq!(
+            location(&[path("1-1.4.2:1.0"), ("devnum", MapValue::UInt(7))]),
+            Some(UsbLocation {
+                busnum: 1,
+                ports: vec![1, 4, 2],
+                devnum: Some(7),
+            })
+        );
+        assert_eq!(
+            location(&[path("/dev/ttyUSB0"), ("busnum", MapValue::Int(3))]),
+            Some(UsbLocation {
+                busnum: 3,
+                ports: vec![],
+                devnum: None,
+            })
+        );
+        assert_eq!(location(&[path("/dev/ttyUSB0")]), None);
+        assert_eq!(location(&[]), None);
+    }
+
+    #[test]
+    fn usb_topology_of_exporter() {
+        let resources = vec![
+            resource("exporter-1", "serial-2", &[path("1-1.2")]),
+            resource("exporter-1", "serial-1", &[path("1-1.2")]),
+            resource("exporter-1", "loader", &[path("1-1.3.1")]),
+            resource("exporter-1", "fastboot", &[("busnum", MapValue::UInt(2))]),
+            resource("exporter-1", "power", &[]),
+            resource("exporter-2", "serial", &[path("1-1.2")]),
+        ];
+        let topology = UsbTopology::of_exporter(&resources, "exporter-1");
+        assert_eq!(topology.buses.keys().copied().collect::<Vec<_>>(), [1, 2]);
+
+        let hub = &topology.buses[&1].ports[&1];
+        assert!(hub.devices.is_empty());
+        assert_eq!(
+            hub.ports[&2]
+                .devices
+                .iter()
+                .map(|device| device.path.resource_name.as_str())
+                .collect::<Vec<_>>(),
+            ["serial-1", "serial-2"]
+        );
+        assert_eq!(
+            hub.ports[&3].ports[&1].devices[0].path.resource_name,
+            "loader"
+        );
+        assert_eq!(topology.buses[&2].devices[0].path.resource_name, "fastboot");
+
+        assert!(UsbTopology::of_exporter(&resources, "exporter-3").is_empty());
+    }
+}
@@ -3,7 +3,8 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use anyhow::Context;
-use std::path::PathBuf;
+use chrono::{DateTime, Local};
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 use tracing::debug;
 
@@ -39,11 +40,37 @@ pub(crate) fn default_venv_dir() -> PathBuf {
     PathBuf::from("/opt/labgrid/venv")
 }
 
+/// Returns the default user locale directory for additional translations in the default app data dir.
+pub(crate) fn default_locale_dir() -> PathBuf {
+    PROJECT_DIRS.data_dir().join("i18n")
+}
+
+/// Returns the path to the file the utilization of the places is recorded in, in the default app data dir.
+pub(crate) fn utilization_path() -> PathBuf {
+    PROJECT_DIRS.data_dir().join("utilization.jsonl")
+}
+
 /// Returns the path to the app configuration file.
 pub(crate) fn config_path() -> PathBuf {
     PROJECT_DIRS.config_dir().join("config.json")
 }
 
+/// Opens the file or directory with the default application of the desktop, without waiting for it to exit.
+pub(crate) fn open_path(path: &Path) -> anyhow::Result<()> {
+    let program = if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    std::process::Command::new(program)
+        .arg(path)
+        .spawn()
+        .with_context(|| format!("Spawn {program}"))?;
+    Ok(())
+}
+
 /// Ensure that all default app directories are present.
 ///
 /// If not, new directories will be created.
@@ -81,3 +108,66 @@ pub(crate) fn get_lg_hostname() -> String {
 pub(crate) fn get_lg_username() -> String {
     std::env::var("LG_USERNAME").unwrap_or_else(|_| whoami::username().unwrap_or_default())
 }
+
+/// User overrides of the hostname and username of the labgrid identity.
+///
+/// Empty fields fall back to [get_lg_hostname] and [get_lg_username].
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct IdentityOverride {
+    pub(crate) hostname: String,
+    pub(crate) username: String,
+}
+
+/// Get the `host/user` name with which the labgrid grpc client identifies itself.
+///
+/// The coordinator reports places acquired by this client with this name.
+pub(crate) fn get_lg_identity(identity_override: &IdentityOverride) -> String {
+    let hostname = match identity_override.hostname.trim() {
+        "" => get_lg_hostname(),
+        hostname => hostname.to_string(),
+    };
+    let username = match identity_override.username.trim() {
+        "" => get_lg_username(),
+        username => username.to_string(),
+    };
+    format!("{hostname}/{username}")
+}
+
+/// The local time of a coordinator timestamp in seconds since the unix epoch, `None` if it is unset.
+pub(crate) fn local_time(timestamp: f64) -> Option<DateTime<Local>> {
+    if timestamp <= 0. {
+        return None;
+    }
+    DateTime::from_timestamp_millis((timestamp * 1000.) as i64).map(|t| t.with_timezone(&Local))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn lg_identity_override() {
+        let identity_override = IdentityOverride {
+            hostname: " kiosk ".to_string(),
+            username: "alice".to_string(),
+        };
+        assert_eq!(get_lg_identity(&identity_override), "kiosk/alice");
+        let identity_override = IdentityOverride {
+            hostname: String::new(),
+            username: "alice".to_string(),
+        };
+        assert_eq!(
+            get_lg_identity(&identity_override),
+            format!("{}/alice", get_lg_hostname())
+        );
+    }
+
+    #[test]
+    fn time_formatting() {
+        let time = Local.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(local_time(time.timestamp() as f64), Some(time));
+        assert_eq!(local_time(0.), None);
+    }
+}
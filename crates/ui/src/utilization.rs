@@ -0,0 +1,360 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::i18n::fl;
+use anyhow::Context;
+use chrono::{DateTime, Local};
+use labgrid_ui_core::types::Place;
+use std::collections::{BTreeSet, HashMap};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// Days the recorded events are kept, the longest range the occupancy is displayed for.
+pub(crate) const UTILIZATION_RETENTION_DAYS: i64 = 7;
+
+/// An observed change of the owner of a place, stored as one JSON line.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct UtilizationEvent {
+    pub(crate) time: DateTime<Local>,
+    pub(crate) place: String,
+    /// The `host/user` owner, `None` when the place was released.
+    pub(crate) acquired_by: Option<String>,
+}
+
+/// The time range the occupancy of the places is displayed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) enum UtilizationRange {
+    #[default]
+    Day,
+    Week,
+}
+
+impl UtilizationRange {
+    pub(crate) const ALL: [Self; 2] = [Self::Day, Self::Week];
+
+    pub(crate) fn duration(&self) -> chrono::Duration {
+        match self {
+            Self::Day => chrono::Duration::days(1),
+            Self::Week => chrono::Duration::days(UTILIZATION_RETENTION_DAYS),
+        }
+    }
+}
+
+impl std::fmt::Display for UtilizationRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Day => fl!("utilization-range-day"),
+            Self::Week => fl!("utilization-range-week"),
+        };
+        f.write_str(&label)
+    }
+}
+
+/// The acquisitions and releases of places observed while the app runs.
+///
+/// Events are appended to a JSON lines file, events older than [UTILIZATION_RETENTION_DAYS]
+/// are dropped when it is opened.
+/// The app doesn't observe anything while it is not running, the owners recorded last are assumed to be kept.
+#[derive(Debug, Default)]
+pub(crate) struct UtilizationLog {
+    /// The file the events are appended to, `None` when they are only kept in memory.
+    path: Option<PathBuf>,
+    /// In chronological order.
+    events: Vec<UtilizationEvent>,
+    /// The owner recorded last for each place.
+    owners: HashMap<String, Option<String>>,
+}
+
+impl UtilizationLog {
+    /// Opens the log at `path`, dropping events that are older than the retention at `now`.
+    ///
+    /// Lines that can't be read are skipped, the file is rewritten without them.
+    pub(crate) fn open(path: PathBuf, now: DateTime<Local>) -> anyhow::Result<Self> {
+        let mut events = match std::fs::File::open(&path) {
+            Ok(file) => read_events(file).context("Read utilization file")?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(error).context("Open utilization file"),
+        };
+        events.sort_by_key(|event: &UtilizationEvent| event.time);
+        let mut log = Self {
+            path: None,
+            events: compact(
+                events,
+                now - chrono::Duration::days(UTILIZATION_RETENTION_DAYS),
+            ),
+            owners: HashMap::new(),
+        };
+        for event in &log.events {
+            log.owners
+                .insert(event.place.clone(), event.acquired_by.clone());
+        }
+        write_events(&path, &log.events).context("Write compacted utilization file")?;
+        log.path = Some(path);
+        Ok(log)
+    }
+
+    /// Whether the events are appended to a file.
+    pub(crate) fn is_open(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// Stops appending the events to the file, keeping the recorded events in memory.
+    pub(crate) fn close(&mut self) {
+        self.path = None;
+    }
+
+    /// Records the owner of the place at `time`, if it changed since it was recorded last.
+    pub(crate) fn observe(&mut self, place: &Place, time: DateTime<Local>) -> anyhow::Result<()> {
+        self.record(&place.name, place.acquired.clone(), time)
+    }
+
+    /// Records the deleted place as released at `time`, if it was acquired.
+    pub(crate) fn observe_deleted(
+        &mut self,
+        place_name: &str,
+        time: DateTime<Local>,
+    ) -> anyhow::Result<()> {
+        self.record(place_name, None, time)
+    }
+
+    fn record(
+        &mut self,
+        place_name: &str,
+        acquired_by: Option<String>,
+        time: DateTime<Local>,
+    ) -> anyhow::Result<()> {
+        let recorded = self.owners.get(place_name);
+        // Places that were never recorded are free as far as the log is concerned
+        if recorded.map_or(acquired_by.is_none(), |owner| *owner == acquired_by) {
+            return Ok(());
+        }
+        self.owners
+            .insert(place_name.to_string(), acquired_by.clone());
+        let event = UtilizationEvent {
+            time,
+            place: place_name.to_string(),
+            acquired_by,
+        };
+        let appended = match &self.path {
+            Some(path) => append_event(path, &event),
+            None => Ok(()),
+        };
+        self.events.push(event);
+        appended
+    }
+
+    /// The share of the `range` before `now` the place was acquired, from `0.0` to `1.0`.
+    pub(crate) fn occupancy(
+        &self,
+        place_name: &str,
+        range: UtilizationRange,
+        now: DateTime<Local>,
+    ) -> f32 {
+        let start = now - range.duration();
+        let mut acquired_since = None;
+        let mut acquired = chrono::Duration::zero();
+        for event in self.events.iter().filter(|event| event.place == place_name) {
+            if let Some(since) = acquired_since.take() {
+                acquired += overlap(since, event.time, start, now);
+            }
+            if event.acquired_by.is_some() {
+                acquired_since = Some(event.time);
+            }
+        }
+        if let Some(since) = acquired_since {
+            acquired += overlap(since, now, start, now);
+        }
+        (acquired.num_milliseconds() as f64 / range.duration().num_milliseconds() as f64) as f32
+    }
+}
+
+/// The duration of the interval from `from` to `to` that lies within `start` and `end`.
+fn overlap(
+    from: DateTime<Local>,
+    to: DateTime<Local>,
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+) -> chrono::Duration {
+    (to.min(end) - from.max(start)).max(chrono::Duration::zero())
+}
+
+/// Drops the events before `cutoff`, except the last one of each place that tells its owner at the cutoff.
+fn compact(events: Vec<UtilizationEvent>, cutoff: DateTime<Local>) -> Vec<UtilizationEvent> {
+    let mut last_before: HashMap<&str, usize> = HashMap::new();
+    for (i, event) in events.iter().enumerate() {
+        if event.time < cutoff {
+            last_before.insert(&event.place, i);
+        }
+    }
+    let keep = last_before
+        .into_iter()
+        // Releases before the cutoff are the same as no events
+        .filter(|(_, i)| events[*i].acquired_by.is_some())
+        .map(|(_, i)| i)
+        .collect::<BTreeSet<_>>();
+    events
+        .into_iter()
+        .enumerate()
+        .filter(|(i, event)| event.time >= cutoff || keep.contains(i))
+        .map(|(_, event)| event)
+        .collect()
+}
+
+/// Reads the events line by line, skipping lines that are not valid UTF-8 or no valid event.
+fn read_events(file: std::fs::File) -> std::io::Result<Vec<UtilizationEvent>> {
+    let mut events = Vec::new();
+    for line in BufReader::new(file).split(b'\n') {
+        let line = line?;
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+        match serde_json::from_slice(&line) {
+            Ok(event) => events.push(event),
+            Err(error) => {
+                let line = String::from_utf8_lossy(&line);
+                warn!(?error, %line, "Skipping unreadable utilization event");
+            }
+        }
+    }
+    Ok(events)
+}
+
+/// Replaces the file with the events, through a temporary file so that it is never left half written.
+fn write_events(path: &Path, events: &[UtilizationEvent]) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).context("Create utilization directory")?;
+    }
+    let tmp_path = path.with_extension("jsonl.tmp");
+    let mut writer = BufWriter::new(std::fs::File::create(&tmp_path)?);
+    for event in events {
+        serde_json::to_writer(&mut writer, event)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.into_inner()?.sync_all()?;
+    std::fs::rename(&tmp_path, path).context("Replace utilization file")
+}
+
+fn append_event(path: &Path, event: &UtilizationEvent) -> anyhow::Result<()> {
+    let mut line = serde_json::to_string(event)?;
+    line.push('\n');
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .context("Open utilization file for appending")?
+        .write_all(line.as_bytes())
+        .context("Append utilization event")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use chrono::TimeZone;
+
+    fn place(acquired: Option<&str>) -> Place {
+        Place {
+            acquired: acquired.map(str::to_string),
+            ..test_fixtures::place("board-1")
+        }
+    }
+
+    #[test]
+    fn utilization_occupancy() {
+        let now = Local.with_ymd_and_hms(2025, 3, 8, 12, 0, 0).unwrap();
+        let hours_ago = |hours| now - chrono::Duration::hours(hours);
+        let mut log = UtilizationLog::default();
+        // Acquired for the whole day before yesterday
+        log.observe(&place(Some("host/me")), hours_ago(48)).unwrap();
+        log.observe(&place(None), hours_ago(24)).unwrap();
+        // Acquired for 6 hours today, unchanged owners are not recorded again
+        log.observe(&place(Some("host/other")), hours_ago(12))
+            .unwrap();
+        log.observe(&place(Some("host/other")), hours_ago(9))
+            .unwrap();
+        log.observe(&place(None), hours_ago(6)).unwrap();
+        log.observe(&place(None), hours_ago(5)).unwrap();
+        assert_eq!(log.events.len(), 4);
+
+        assert_eq!(log.occupancy("board-1", UtilizationRange::Day, now), 0.25);
+        assert_eq!(
+            log.occupancy("board-1", UtilizationRange::Week, now),
+            30. / (7. * 24.)
+        );
+        // Still acquired places count until now
+        log.observe(&place(Some("host/me")), hours_ago(3)).unwrap();
+        assert_eq!(log.occupancy("board-1", UtilizationRange::Day, now), 0.375);
+        assert_eq!(log.occupancy("board-2", UtilizationRange::Day, now), 0.);
+    }
+
+    #[test]
+    fn utilization_file() {
+        let dir =
+            std::env::temp_dir().join(format!("labgrid-ui-utilization-{}", std::process::id()));
+        let path = dir.join("utilization.jsonl");
+        let now = Local.with_ymd_and_hms(2025, 3, 8, 12, 0, 0).unwrap();
+        let days_ago = |days| now - chrono::Duration::days(days);
+
+        let mut log = UtilizationLog::open(path.clone(), days_ago(10)).unwrap();
+        assert!(log.is_open());
+        log.observe(&place(Some("host/me")), days_ago(10)).unwrap();
+        log.observe(&place(None), days_ago(9)).unwrap();
+        log.observe(&place(Some("host/other")), days_ago(8))
+            .unwrap();
+        log.observe(&place(None), days_ago(1)).unwrap();
+        std::fs::write(
+            &path,
+            std::fs::read_to_string(&path).unwrap() + "{ unreadable\n",
+        )
+        .unwrap();
+
+        // Only the acquisition that lasts into the retention is kept of the older events
+        let mut log = UtilizationLog::open(path.clone(), now).unwrap();
+        assert_eq!(
+            log.events
+                .iter()
+                .map(|event| (event.time, event.acquired_by.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![(days_ago(8), Some("host/other")), (days_ago(1), None)]
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+        log.observe_deleted("board-1", now).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn utilization_file_invalid_utf8() {
+        let dir = std::env::temp_dir().join(format!(
+            "labgrid-ui-utilization-utf8-{}",
+            std::process::id()
+        ));
+        let path = dir.join("utilization.jsonl");
+        let now = Local.with_ymd_and_hms(2025, 3, 8, 12, 0, 0).unwrap();
+        let hours_ago = |hours| now - chrono::Duration::hours(hours);
+
+        let mut log = UtilizationLog::open(path.clone(), now).unwrap();
+        log.observe(&place(Some("host/me")), hours_ago(3)).unwrap();
+        let mut content = std::fs::read(&path).unwrap();
+        content.extend_from_slice(b"{\"place\":\"\xff\xfe\"}\n");
+        std::fs::write(&path, content).unwrap();
+        log.observe(&place(None), hours_ago(2)).unwrap();
+
+        // The events after the line that is not valid UTF-8 are kept
+        let log = UtilizationLog::open(path.clone(), now).unwrap();
+        assert_eq!(
+            log.events
+                .iter()
+                .map(|event| (event.time, event.acquired_by.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![(hours_ago(3), Some("host/me")), (hours_ago(2), None)]
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 2);
+        assert!(!path.with_extension("jsonl.tmp").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,362 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::scripts;
+use crate::util;
+use anyhow::Context;
+use labgrid_ui_core::version::{self, Compatibility};
+use std::path::{Path, PathBuf};
+
+/// Prints the installed labgrid version, used to validate the created virtual environment.
+const LABGRID_VERSION_CHECK: &str =
+    "from importlib.metadata import version; print(version('labgrid'))";
+
+/// Prints the python version and the installed labgrid version, an empty line when labgrid is not installed.
+const VERSIONS_CHECK: &str = r#"
+import sys
+try:
+    import labgrid
+    from importlib.metadata import version
+    labgrid_version = version('labgrid')
+except Exception:
+    labgrid_version = ''
+print(sys.version.split()[0])
+print(labgrid_version)
+"#;
+
+/// The suggested location of a virtual environment created by the app, in the app data dir.
+pub(crate) fn default_created_venv_dir() -> PathBuf {
+    util::PROJECT_DIRS.data_dir().join("venv")
+}
+
+/// A step of creating a virtual environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VenvStep {
+    /// Creates the virtual environment with `python3 -m venv`.
+    Create,
+    /// Installs labgrid through pip of the virtual environment.
+    InstallLabgrid,
+    /// Validates the virtual environment and reads the installed labgrid version.
+    Validate,
+}
+
+impl VenvStep {
+    fn next(self) -> Option<Self> {
+        match self {
+            Self::Create => Some(Self::InstallLabgrid),
+            Self::InstallLabgrid => Some(Self::Validate),
+            Self::Validate => None,
+        }
+    }
+
+    /// Runs the step for the virtual environment at `dir`, installing the pip `requirement`.
+    ///
+    /// Returns: `Result<output>`, the installed labgrid version for [VenvStep::Validate].
+    pub(crate) async fn run(self, dir: PathBuf, requirement: String) -> anyhow::Result<String> {
+        let python = dir.join("bin").join("python3");
+        match self {
+            Self::Create => {
+                run_command(
+                    tokio::process::Command::new("python3")
+                        .args(["-m", "venv"])
+                        .arg(&dir),
+                )
+                .await
+            }
+            Self::InstallLabgrid => {
+                run_command(
+                    tokio::process::Command::new(&python)
+                        .args(["-m", "pip", "install", "--disable-pip-version-check"])
+                        .arg(&requirement),
+                )
+                .await
+            }
+            Self::Validate => {
+                scripts::validate_venv_dir(&dir)?;
+                let labgrid_client = dir.join("bin").join("labgrid-client");
+                if !labgrid_client.is_file() {
+                    anyhow::bail!("'{}' does not exist", labgrid_client.display());
+                }
+                run_command(
+                    tokio::process::Command::new(&python).args(["-c", LABGRID_VERSION_CHECK]),
+                )
+                .await
+                .map(|version| version.trim().to_string())
+            }
+        }
+    }
+}
+
+/// Runs the command to completion.
+///
+/// Returns: `Result<stdout>`, an error containing stderr if it failed.
+async fn run_command(command: &mut tokio::process::Command) -> anyhow::Result<String> {
+    let output = command
+        .kill_on_drop(true)
+        .output()
+        .await
+        .context("Command execution failed")?;
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    if !output.status.success() {
+        anyhow::bail!(
+            "Exit code {}\n{stdout}{}",
+            output.status.code().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(stdout)
+}
+
+/// The versions of python and labgrid detected in a virtual environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VenvVersions {
+    pub(crate) python: String,
+    /// `None` when labgrid is not installed.
+    pub(crate) labgrid: Option<String>,
+}
+
+impl VenvVersions {
+    /// Detects the versions through the python interpreter of the virtual environment at `dir`.
+    pub(crate) async fn detect(dir: PathBuf) -> anyhow::Result<Self> {
+        let output = run_command(
+            tokio::process::Command::new(dir.join("bin").join("python3"))
+                .args(["-c", VERSIONS_CHECK]),
+        )
+        .await?;
+        Self::parse(&output).context("Unexpected output of the versions check")
+    }
+
+    fn parse(output: &str) -> Option<Self> {
+        let mut lines = output.lines().map(str::trim);
+        let python = lines.next().filter(|python| !python.is_empty())?;
+        Some(Self {
+            python: python.to_string(),
+            labgrid: lines
+                .next()
+                .filter(|labgrid| !labgrid.is_empty())
+                .map(str::to_string),
+        })
+    }
+
+    /// Whether the installed labgrid communicates through gRPC like the coordinators the app connects to.
+    ///
+    /// Versions that can't be parsed, e.g. development builds of forks, are assumed to be compatible.
+    pub(crate) fn grpc_compatible(&self) -> bool {
+        self.labgrid
+            .as_deref()
+            .is_some_and(|labgrid| version::compatibility(labgrid) != Compatibility::Incompatible)
+    }
+}
+
+/// The progress of the [VenvWizard].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum VenvProgress {
+    Editing,
+    Running(VenvStep),
+    /// The virtual environment was created with the contained labgrid version.
+    Done(String),
+    Failed {
+        step: VenvStep,
+        error: String,
+    },
+}
+
+/// Why the [VenvWizard] can't create the virtual environment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum VenvProblem {
+    /// The path is empty or relative.
+    InvalidDir,
+    /// The directory exists and is not empty.
+    DirNotEmpty,
+    InvalidVersion,
+}
+
+/// Creates a python virtual environment with labgrid installed, for running the scripts.
+#[derive(Debug, Clone)]
+pub(crate) struct VenvWizard {
+    /// The text of the directory input.
+    pub(crate) dir: String,
+    /// The labgrid version that is installed, the latest one when empty.
+    pub(crate) labgrid_version: String,
+    pub(crate) progress: VenvProgress,
+}
+
+impl Default for VenvWizard {
+    fn default() -> Self {
+        Self {
+            dir: default_created_venv_dir().display().to_string(),
+            labgrid_version: String::new(),
+            progress: VenvProgress::Editing,
+        }
+    }
+}
+
+impl VenvWizard {
+    pub(crate) fn dir(&self) -> PathBuf {
+        PathBuf::from(self.dir.trim())
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        matches!(self.progress, VenvProgress::Running(_))
+    }
+
+    /// The pip requirement installing the selected labgrid version.
+    pub(crate) fn requirement(&self) -> String {
+        match self.labgrid_version.trim() {
+            "" => "labgrid".to_string(),
+            version => format!("labgrid=={version}"),
+        }
+    }
+
+    /// The problem preventing the virtual environment from being created.
+    pub(crate) fn problem(&self) -> Option<VenvProblem> {
+        let dir = self.dir();
+        if dir.as_os_str().is_empty() || dir.is_relative() {
+            return Some(VenvProblem::InvalidDir);
+        }
+        if !dir_is_empty(&dir) {
+            return Some(VenvProblem::DirNotEmpty);
+        }
+        let version = self.labgrid_version.trim();
+        let valid_version = version.is_empty()
+            || (version.starts_with(|c: char| c.is_ascii_digit())
+                && version
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || ".+!-".contains(c)));
+        (!valid_version).then_some(VenvProblem::InvalidVersion)
+    }
+
+    /// Starts creating the virtual environment, returning the first step to run.
+    pub(crate) fn start(&mut self) -> Option<VenvStep> {
+        if self.is_running() || self.problem().is_some() {
+            return None;
+        }
+        self.progress = VenvProgress::Running(VenvStep::Create);
+        Some(VenvStep::Create)
+    }
+
+    /// Records the result of the step, returning the next step to run.
+    pub(crate) fn step_finished(
+        &mut self,
+        step: VenvStep,
+        result: Result<String, String>,
+    ) -> Option<VenvStep> {
+        if self.progress != VenvProgress::Running(step) {
+            return None;
+        }
+        match result {
+            Ok(output) => match step.next() {
+                Some(next) => {
+                    self.progress = VenvProgress::Running(next);
+                    Some(next)
+                }
+                None => {
+                    self.progress = VenvProgress::Done(output);
+                    None
+                }
+            },
+            Err(error) => {
+                self.progress = VenvProgress::Failed { step, error };
+                None
+            }
+        }
+    }
+}
+
+/// Whether the directory doesn't exist or contains no entries.
+fn dir_is_empty(dir: &Path) -> bool {
+    match std::fs::read_dir(dir) {
+        Ok(mut entries) => entries.next().is_none(),
+        Err(_) => !dir.exists(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn venv_versions() {
+        let versions = VenvVersions::parse("3.11.2\n24.0.1\n").unwrap();
+        assert_eq!(versions.python, "3.11.2");
+        assert_eq!(versions.labgrid.as_deref(), Some("24.0.1"));
+        assert!(versions.grpc_compatible());
+
+        let crossbar = VenvVersions::parse("3.9.2\n23.0.6\n").unwrap();
+        assert!(!crossbar.grpc_compatible());
+        let missing = VenvVersions::parse("3.12.3\n\n").unwrap();
+        assert_eq!(missing.labgrid, None);
+        assert!(!missing.grpc_compatible());
+        let fork = VenvVersions::parse("3.12.3\n0.1.dev1+g1442\n").unwrap();
+        assert!(!fork.grpc_compatible());
+        assert!(VenvVersions {
+            python: "3.12.3".to_string(),
+            labgrid: Some("unknown".to_string())
+        }
+        .grpc_compatible());
+        assert_eq!(VenvVersions::parse(""), None);
+    }
+
+    #[test]
+    fn venv_wizard() {
+        let dir = std::env::temp_dir().join(format!("labgrid-ui-venv-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut wizard = VenvWizard {
+            dir: dir.display().to_string(),
+            ..VenvWizard::default()
+        };
+        assert_eq!(wizard.problem(), None);
+        assert_eq!(wizard.requirement(), "labgrid");
+        std::fs::write(dir.join("file"), "").unwrap();
+        let not_empty = wizard.problem();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(not_empty, Some(VenvProblem::DirNotEmpty));
+
+        wizard.labgrid_version = "24.0; rm -rf /".to_string();
+        assert_eq!(wizard.problem(), Some(VenvProblem::InvalidVersion));
+        wizard.labgrid_version = " 25.0.1 ".to_string();
+        assert_eq!(wizard.requirement(), "labgrid==25.0.1");
+        wizard.dir = "venv".to_string();
+        assert_eq!(wizard.problem(), Some(VenvProblem::InvalidDir));
+        assert_eq!(wizard.start(), None);
+
+        wizard.dir = dir.display().to_string();
+        assert_eq!(wizard.start(), Some(VenvStep::Create));
+        assert_eq!(wizard.start(), None);
+        assert_eq!(
+            wizard.step_finished(VenvStep::Create, Ok(String::new())),
+            Some(VenvStep::InstallLabgrid)
+        );
+        // Results of steps that aren't running are ignored
+        assert_eq!(
+            wizard.step_finished(VenvStep::Validate, Ok(String::new())),
+            None
+        );
+        assert_eq!(
+            wizard.step_finished(
+                VenvStep::InstallLabgrid,
+                Ok("Successfully installed labgrid-25.0.1\n".to_string())
+            ),
+            Some(VenvStep::Validate)
+        );
+        assert_eq!(
+            wizard.step_finished(VenvStep::Validate, Ok("25.0.1".to_string())),
+            None
+        );
+        assert_eq!(wizard.progress, VenvProgress::Done("25.0.1".to_string()));
+
+        assert_eq!(wizard.start(), Some(VenvStep::Create));
+        assert_eq!(
+            wizard.step_finished(VenvStep::Create, Err("No module named venv".to_string())),
+            None
+        );
+        assert!(matches!(
+            wizard.progress,
+            VenvProgress::Failed {
+                step: VenvStep::Create,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::{AppConnected, PlaceGrouping, TabId};
+use crate::utilization::UtilizationRange;
+use iced::{window, Point, Size};
+
+/// The state of the views that is restored when connecting, also after a restart.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct ViewState {
+    pub(crate) active_tab: TabId,
+    pub(crate) places_only_mine: bool,
+    pub(crate) place_grouping: PlaceGrouping,
+    pub(crate) resources_only_show_available: bool,
+    pub(crate) resources_exporter_filter: Option<String>,
+    pub(crate) resources_class_filter: Option<String>,
+    pub(crate) reservations_show_foreign: bool,
+    pub(crate) utilization_range: UtilizationRange,
+    /// The last size and position of the window, restored at startup.
+    pub(crate) window: Option<WindowGeometry>,
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self {
+            active_tab: TabId::default(),
+            places_only_mine: false,
+            place_grouping: PlaceGrouping::default(),
+            resources_only_show_available: true,
+            resources_exporter_filter: None,
+            resources_class_filter: None,
+            reservations_show_foreign: true,
+            utilization_range: UtilizationRange::default(),
+            window: None,
+        }
+    }
+}
+
+impl ViewState {
+    /// Takes over the state of the views of the connection.
+    pub(crate) fn capture(&mut self, connected: &AppConnected) {
+        self.active_tab = connected.active_tab.clone();
+        self.places_only_mine = connected.places_only_mine;
+        self.place_grouping = connected.place_grouping.clone();
+        self.resources_only_show_available = connected.resources_only_show_available;
+        self.resources_exporter_filter = connected.resources_exporter_filter.clone();
+        self.resources_class_filter = connected.resources_class_filter.clone();
+        self.reservations_show_foreign = connected.reservations_show_foreign;
+        self.utilization_range = connected.utilization_range;
+    }
+
+    /// Restores the state of the views of a new connection.
+    pub(crate) fn restore(&self, connected: &mut AppConnected) {
+        connected.active_tab = self.active_tab.clone();
+        connected.places_only_mine = self.places_only_mine;
+        connected.place_grouping = self.place_grouping.clone();
+        connected.resources_only_show_available = self.resources_only_show_available;
+        connected.resources_exporter_filter = self.resources_exporter_filter.clone();
+        connected.resources_class_filter = self.resources_class_filter.clone();
+        connected.reservations_show_foreign = self.reservations_show_foreign;
+        connected.utilization_range = self.utilization_range;
+    }
+}
+
+/// The logical size and position of the window.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub(crate) struct WindowGeometry {
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    /// Unknown until the window was moved, and on platforms that don't report it (e.g. Wayland).
+    pub(crate) position: Option<(f32, f32)>,
+}
+
+impl WindowGeometry {
+    /// Applies the geometry to the settings the window is created with.
+    pub(crate) fn apply(&self, settings: &mut window::Settings) {
+        let min_size = settings.min_size.unwrap_or(Size::ZERO);
+        settings.size = Size::new(
+            self.width.max(min_size.width),
+            self.height.max(min_size.height),
+        );
+        if let Some((x, y)) = self.position {
+            settings.position = window::Position::Specific(Point::new(x, y));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn view_state_capture_restore() {
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        connected.active_tab = TabId::Resources;
+        connected.place_grouping = PlaceGrouping::Tag("rack".to_string());
+        connected.resources_only_show_available = false;
+        connected.resources_class_filter = Some("NetworkSerialPort".to_string());
+
+        let mut view_state = ViewState::default();
+        view_state.capture(&connected);
+        let view_state: ViewState =
+            serde_json::from_str(&serde_json::to_string(&view_state).unwrap()).unwrap();
+
+        let mut restored = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        view_state.restore(&mut restored);
+        assert_eq!(restored.active_tab, TabId::Resources);
+        assert_eq!(
+            restored.place_grouping,
+            PlaceGrouping::Tag("rack".to_string())
+        );
+        assert!(!restored.resources_only_show_available);
+        assert_eq!(
+            restored.resources_class_filter.as_deref(),
+            Some("NetworkSerialPort")
+        );
+        assert!(restored.reservations_show_foreign);
+    }
+
+    #[test]
+    fn window_geometry_apply() {
+        let mut settings = window::Settings {
+            min_size: Some(Size::new(600., 400.)),
+            ..Default::default()
+        };
+        WindowGeometry {
+            width: 1280.,
+            height: 200.,
+            position: Some((10., 20.)),
+        }
+        .apply(&mut settings);
+        assert_eq!(settings.size, Size::new(1280., 400.));
+        assert!(matches!(
+            settings.position,
+            window::Position::Specific(Point { x: 10., y: 20. })
+        ));
+    }
+}
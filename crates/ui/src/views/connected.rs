@@ -3,43 +3,149 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::generic::{
-    card_container_style, modal_container_style, optimized_scrollbar_properties, view_empty,
-    view_heading, view_list_row, view_section, view_text_tooltip,
+    button_padding, card_container_style, colored_card_container_style, modal_container_style,
+    optimized_scrollbar_properties, view_empty, view_error_console_button, view_heading,
+    view_list_row, view_section, view_text_tooltip,
 };
 use super::{NONE_ELEMENT, UI_MAX_WIDTH};
+use crate::acquired_resources::{self, AcquiredResourceChange};
 use crate::app::{
-    AppConnected, AppMsg, ConnectedMsg, Modal, PlaceUi, ResourceUi, TabId, FONT_INCONSOLATA,
+    self, App, AppConnected, AppMsg, ConnectedMsg, Modal, PlaceGrouping, PlaceUi, ProtocolWarning,
+    ReservationCleanup, ResourceUi, TabId, FONT_INCONSOLATA,
 };
-use crate::connection::ConnectionMsg;
+use crate::availability::{self, ResourceAvailability};
+use crate::camera::{self, CameraPreview};
+use crate::connection::{BulkPlaceOpProgress, ConnectionMsg};
+use crate::dashboard::DashboardStats;
+use crate::export::ExportKind;
+use crate::exporters::{self, ExporterLastSeen, ExporterSummary, OfflineExporterResources};
+use crate::file_browser::FileBrowserTarget;
+use crate::history::{AcquireHistory, PlaceActivityLog};
 use crate::i18n::fl;
-use crate::scripts::{Env, EnvEntry, Script, Scripts};
-use crate::{scripts, util};
+use crate::match_builder::{MatchBuilder, MatchComponent};
+use crate::palette::{CardPalette, PlaceCardState};
+use crate::place_clone::{CloneProblem, PlaceClone};
+use crate::place_snapshot::{PlaceDifference, RestoreStep, SnapshotFile};
+use crate::queue::{self, QueueEstimate};
+use crate::quick_actions::{self, QuickActionSettings};
+use crate::quick_connect::QuickConnect;
+use crate::quick_switcher::{QuickSwitcher, QuickSwitcherItem, QUICK_SWITCHER_INPUT_ID};
+use crate::reservation_runner::{ReservationRun, RunStage};
+use crate::resource_classes::{
+    self, ClassIcon, ClassMapping, ClassSchema, ClassSummary, ResourceClassRegistry,
+};
+use crate::resource_filter;
+use crate::resource_watch::{AvailabilityNotice, ResourceWatch};
+use crate::script_meta::ScriptMetaSettings;
+use crate::scripts::{Env, EnvEntry, Script, ScriptType, ScriptValidation, Scripts};
+use crate::session_timer::{AutoReleaseSettings, SessionTimerStatus};
+use crate::usb_topology::{UsbDevice, UsbPort, UsbTopology};
+use crate::utilization::{UtilizationLog, UtilizationRange};
+use crate::{format, scripts, util};
+use chrono::{DateTime, Local};
 use iced::border::Radius;
 use iced::widget::text::Shaping;
 use iced::widget::{
-    button, checkbox, column, container, pick_list, row, rule, scrollable, space, text, text_input,
-    Space,
+    button, checkbox, column, container, image, mouse_area, pick_list, progress_bar, row, rule,
+    scrollable, space, text, text_input, Space,
 };
-use iced::{padding, Alignment, Color, Element, Length};
+use iced::{mouse, padding, Alignment, Color, Element, Length, Padding, Theme};
 use iced_aw::{TabBarPosition, TabLabel, Tabs};
 use iced_fonts::bootstrap;
-use labgrid_ui_core::types::{Place, Reservation, Resource, ResourceMatch};
-use std::collections::BTreeMap;
+use labgrid_ui_core::filter::FilterExpr;
+use labgrid_ui_core::pattern::MatchPattern;
+use labgrid_ui_core::types::{
+    MapValue, Path, Place, Reservation, ReservationState, Resource, ResourceMatch,
+};
+use labgrid_ui_core::version;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// View for a card element that contains general info and basic control for the supplied place
+///
+/// With `edit_comment` the comment can be edited and the place can be force released if it is acquired.
+/// The owner of an acquired place is displayed in `owner_color`.
 pub(crate) fn view_place_general_info<'a>(
     place: &'a Place,
     ui: &'a PlaceUi,
+    edit_comment: bool,
+    owner_color: Option<Color>,
 ) -> Element<'a, AppMsg> {
+    let comment_row: Element<'a, AppMsg> = match (&ui.edit_comment_text, edit_comment) {
+        (Some(comment_text), true) => view_list_row(
+            text(fl!("labgrid-place-comment-label") + " : "),
+            row![
+                text_input(&fl!("labgrid-place-comment-placeholder"), comment_text)
+                    .on_input(
+                        |text| AppMsg::Connected(ConnectedMsg::UpdateEditPlaceCommentText {
+                            place_name: place.name.clone(),
+                            text
+                        })
+                    )
+                    .on_submit(AppMsg::Connected(ConnectedMsg::SavePlaceComment {
+                        place_name: place.name.clone()
+                    })),
+                button(text(fl!("labgrid-place-comment-save-button"))).on_press(AppMsg::Connected(
+                    ConnectedMsg::SavePlaceComment {
+                        place_name: place.name.clone()
+                    }
+                )),
+                button(text(fl!("labgrid-place-comment-cancel-button")))
+                    .style(button::secondary)
+                    .on_press(AppMsg::Connected(ConnectedMsg::CancelEditPlaceComment {
+                        place_name: place.name.clone()
+                    })),
+            ]
+            .spacing(3)
+            .align_y(Alignment::Center),
+        ),
+        (None, true) => view_list_row(
+            text(fl!("labgrid-place-comment-label") + " : "),
+            row![
+                text(&place.comment),
+                view_text_tooltip(
+                    button(bootstrap::pencil()).on_press(AppMsg::Connected(
+                        ConnectedMsg::EditPlaceComment {
+                            place_name: place.name.clone()
+                        }
+                    )),
+                    fl!("labgrid-place-comment-edit-tooltip")
+                )
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        ),
+        (_, false) => view_list_row(
+            text(fl!("labgrid-place-comment-label") + " : "),
+            text(&place.comment),
+        ),
+    };
     let acquired_by_row: Element<'_, AppMsg> = if let Some(acquired) = &place.acquired {
         view_list_row(
             text(fl!("labgrid-place-acquired-by-label") + " : "),
-            text(acquired),
+            row![
+                text(acquired).color_maybe(owner_color),
+                edit_comment.then(|| view_force_release_button(
+                    place,
+                    acquired,
+                    button::DEFAULT_PADDING
+                ))
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
         )
     } else {
         view_list_row(view_empty(), text(fl!("labgrid-place-not-acquired-label")))
     };
+    let acquire_reason_row = app::acquire_reason(place).map(|reason| {
+        view_list_row(
+            text(fl!("labgrid-place-acquire-reason-label") + " : "),
+            row![bootstrap::chat_quote(), text(reason)]
+                .spacing(6)
+                .align_y(Alignment::Center),
+        )
+    });
     let tags_row: Element<'a, AppMsg> = if let Some(tag) = &ui.add_tag_text {
         row![
             row![
@@ -102,9 +208,13 @@ pub(crate) fn view_place_general_info<'a>(
         view_list_row(
             text(fl!("labgrid-place-tags-label") + " : "),
             row![
-                row(place.tags.iter().map(|t| view_tag(&place.name, (t.0, t.1))))
-                    .spacing(3)
-                    .wrap(),
+                row(place
+                    .tags
+                    .iter()
+                    .filter(|(key, _)| key.as_str() != app::ACQUIRE_REASON_TAG)
+                    .map(|t| view_tag(&place.name, (t.0, t.1))))
+                .spacing(3)
+                .wrap(),
                 view_text_tooltip(
                     button(bootstrap::plus()).on_press(AppMsg::Connected(
                         ConnectedMsg::ShowAddPlaceTag {
@@ -124,29 +234,146 @@ pub(crate) fn view_place_general_info<'a>(
             text(&place.name)
         ),
         rule::horizontal(1),
-        view_list_row(
-            text(fl!("labgrid-place-comment-label") + " : "),
-            text(&place.comment)
-        ),
+        comment_row,
         rule::horizontal(1),
         acquired_by_row,
+        acquire_reason_row,
         rule::horizontal(1),
         tags_row,
     ]
     .into()
 }
 
-/// View for the tab that views the supplied places
+/// View for the tab that views the places of the connected state.
+///
+/// Optionally a panel with resources is shown next to the places, from which resources can be dragged onto places
+/// to add matches for them.
 pub(crate) fn view_places_tab<'a>(
-    places: &'a [(Place, PlaceUi)],
-    add_place_text: &'a str,
+    connected: &'a AppConnected,
+    card_palette: &CardPalette,
+    watched_places: &BTreeSet<String>,
+    quick_actions: &'a QuickActionSettings,
+    auto_release: &AutoReleaseSettings,
     optimize_touch: bool,
 ) -> Element<'a, AppMsg> {
-    let places_list = row(places.iter().map(|(p, ui)| view_place(p, ui)))
-        .spacing(12.)
-        .padding(padding::bottom(12))
-        .wrap();
-    container(view_section(
+    let dragged_pattern = connected.dragged_resource_pattern.as_ref();
+    let now = Instant::now();
+    let place_card = |p: &'a Place, ui: &'a PlaceUi| -> Element<'a, AppMsg> {
+        let selected = connected
+            .places_select_mode
+            .then(|| connected.selected_places.contains(&p.name));
+        let place = view_place(
+            p,
+            ui,
+            selected,
+            card_palette.place_color(p, &connected.identity),
+            p.acquired
+                .as_ref()
+                .and_then(|owner| card_palette.owner_color(owner)),
+            watched_places.contains(&p.name),
+            connected.acquired_by_me(p),
+            quick_actions,
+            connected
+                .session_timers
+                .get(&p.name)
+                .map(|t| t.status(auto_release, now)),
+            // Without any resources, e.g. when only subscribed to places, the availability is unknown
+            (!connected.resources.is_empty())
+                .then(|| availability::resource_availability(p, &connected.resources))
+                .flatten(),
+            optimize_touch,
+        );
+        if let Some(pattern) = dragged_pattern {
+            mouse_area(place)
+                .on_release(AppMsg::ShowModal(Box::new(Modal::Confirmation {
+                    msg: fl!(
+                        "labgrid-place-drop-resource-confirmation-msg",
+                        pattern = pattern.as_str(),
+                        place = p.name.as_str()
+                    ),
+                    confirm: AppMsg::ConnectionMsg(ConnectionMsg::AddPlaceMatch {
+                        place_name: p.name.clone(),
+                        pattern: pattern.clone(),
+                        rename: None,
+                    }),
+                })))
+                .into()
+        } else {
+            place
+        }
+    };
+    let place_cards = |places: Vec<&'a (Place, PlaceUi)>| {
+        row(places.into_iter().map(|(p, ui)| place_card(p, ui)))
+            .spacing(12.)
+            .padding(padding::bottom(12))
+            .wrap()
+    };
+    let visible_places = connected
+        .places
+        .iter()
+        .filter(|(p, _)| !connected.places_only_mine || connected.acquired_by_me(p));
+    let places_list: Element<'a, AppMsg> = match &connected.place_grouping {
+        PlaceGrouping::None => place_cards(visible_places.collect()).into(),
+        PlaceGrouping::Tag(key) => {
+            // Places without the tag are grouped under `None`, which is listed last.
+            let mut groups: BTreeMap<Option<&str>, Vec<&(Place, PlaceUi)>> = BTreeMap::new();
+            for place in visible_places {
+                let value = place.0.tags.get(key).map(String::as_str);
+                groups.entry(value).or_default().push(place);
+            }
+            let untagged = groups.remove(&None);
+            column(
+                groups
+                    .into_iter()
+                    .chain(untagged.map(|places| (None, places)))
+                    .map(|(value, places)| {
+                        let value = value.map(str::to_string);
+                        let collapsed = connected.collapsed_place_groups.contains(&value);
+                        let label = match &value {
+                            Some(value) => format!("{key} = {value}"),
+                            None => fl!("labgrid-places-group-untagged", key = key.as_str()),
+                        };
+                        column![
+                            button(
+                                row![
+                                    if collapsed {
+                                        bootstrap::chevron_right()
+                                    } else {
+                                        bootstrap::chevron_down()
+                                    },
+                                    text(label).size(18),
+                                    text(format!("({})", places.len())),
+                                ]
+                                .spacing(6)
+                                .align_y(Alignment::Center)
+                            )
+                            .style(button::text)
+                            .on_press(AppMsg::Connected(
+                                ConnectedMsg::TogglePlaceGroupCollapsed(value)
+                            )),
+                            (!collapsed).then(|| place_cards(places)),
+                        ]
+                        .spacing(6)
+                        .into()
+                    }),
+            )
+            .spacing(6)
+            .into()
+        }
+    };
+    let grouping_options: Vec<PlaceGrouping> = std::iter::once(PlaceGrouping::None)
+        .chain(
+            connected
+                .places
+                .iter()
+                .flat_map(|(p, _)| p.tags.keys())
+                .collect::<BTreeSet<&String>>()
+                .into_iter()
+                .map(|key| PlaceGrouping::Tag(key.clone())),
+        )
+        .collect();
+    let add_place_text = connected.add_place_text.as_str();
+    let places_section = view_section(
         fl!("labgrid-places-label"),
         Some(
             row![
@@ -171,31 +398,313 @@ pub(crate) fn view_places_tab<'a>(
                     ConnectionMsg::AddPlace {
                         name: add_place_text.to_string()
                     }
-                ))
+                )),
+                Space::new().width(6),
+                checkbox(connected.places_only_mine)
+                    .label(fl!("labgrid-places-only-mine-checkbox"))
+                    .on_toggle(|only_mine| AppMsg::Connected(ConnectedMsg::PlacesOnlyMine(
+                        only_mine
+                    ))),
+                Space::new().width(6),
+                pick_list(
+                    grouping_options,
+                    Some(&connected.place_grouping),
+                    |grouping| AppMsg::Connected(ConnectedMsg::ChangePlaceGrouping(grouping))
+                ),
+                Space::new().width(6),
+                button(text(if connected.places_select_mode {
+                    fl!("labgrid-places-exit-select-mode-button")
+                } else {
+                    fl!("labgrid-places-select-mode-button")
+                }))
+                .style(button::secondary)
+                .on_press(AppMsg::Connected(ConnectedMsg::TogglePlacesSelectMode)),
+                Space::new().width(6),
+                button(text(if connected.places_show_resources_panel {
+                    fl!("labgrid-places-hide-resources-panel-button")
+                } else {
+                    fl!("labgrid-places-show-resources-panel-button")
+                }))
+                .style(button::secondary)
+                .on_press(AppMsg::Connected(ConnectedMsg::ToggleResourcesPanel)),
+                Space::new().width(6),
+                view_export_button(ExportKind::Places),
+                Space::new().width(6),
+                view_text_tooltip(
+                    button(text(fl!("place-snapshot-export-button")))
+                        .style(button::secondary)
+                        .on_press(AppMsg::ExportPlaceSnapshot),
+                    fl!("place-snapshot-export-tooltip"),
+                ),
+                Space::new().width(6),
+                view_text_tooltip(
+                    button(text(fl!("place-snapshot-restore-button")))
+                        .style(button::secondary)
+                        .on_press(AppMsg::ImportPlaceSnapshot { compare: false }),
+                    fl!("place-snapshot-restore-tooltip"),
+                ),
+                Space::new().width(6),
+                view_text_tooltip(
+                    button(text(fl!("place-snapshot-compare-button")))
+                        .style(button::secondary)
+                        .on_press(AppMsg::ImportPlaceSnapshot { compare: true }),
+                    fl!("place-snapshot-compare-tooltip"),
+                ),
             ]
             .spacing(1),
         ),
-        scrollable(places_list)
-            .direction(optimized_scrollbar_properties(false, true, optimize_touch))
-            .width(Length::Fill),
-    ))
+        column![
+            connected
+                .places_select_mode
+                .then(|| view_bulk_place_actions(
+                    &connected.selected_places,
+                    &connected.bulk_place_tag_text
+                )),
+            connected
+                .bulk_op_progress
+                .map(|progress| view_bulk_place_op_progress(progress, connected.bulk_op_paused)),
+            scrollable(places_list)
+                .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+                .width(Length::Fill)
+        ]
+        .spacing(6),
+    );
+
+    let content: Element<'_, AppMsg> = if connected.places_show_resources_panel {
+        row![
+            places_section,
+            view_resources_panel(&connected.resources, dragged_pattern, optimize_touch)
+        ]
+        .spacing(12)
+        .into()
+    } else {
+        places_section
+    };
+
+    // Releasing anywhere ends the drag, after a possible drop onto a place was handled.
+    let mut area = mouse_area(container(content).padding(6));
+    if dragged_pattern.is_some() {
+        area = area
+            .on_release(AppMsg::Connected(ConnectedMsg::EndResourceDrag))
+            .interaction(mouse::Interaction::Grabbing);
+    }
+    area.into()
+}
+
+/// View for the actions that are applied to all selected places.
+pub(crate) fn view_bulk_place_actions<'a>(
+    selected_places: &'a BTreeSet<String>,
+    tag_text: &'a (String, String),
+) -> Element<'a, AppMsg> {
+    let names = || selected_places.iter().cloned().collect::<Vec<String>>();
+    let any_selected = !selected_places.is_empty();
+    let tag_valid = !tag_text.0.trim().is_empty() && !tag_text.1.trim().is_empty();
+
+    container(
+        row![
+            text(fl!(
+                "labgrid-places-selected-label",
+                count = selected_places.len()
+            )),
+            Space::new().width(6),
+            button(text(fl!("labgrid-places-select-all-button")))
+                .style(button::secondary)
+                .on_press(AppMsg::Connected(ConnectedMsg::SelectAllPlaces)),
+            button(text(fl!("labgrid-places-clear-selection-button")))
+                .style(button::secondary)
+                .on_press_maybe(
+                    any_selected.then_some(AppMsg::Connected(ConnectedMsg::ClearPlaceSelection))
+                ),
+            space::horizontal(),
+            button(text(fl!("labgrid-place-acquire-button"))).on_press_maybe(
+                any_selected.then(|| AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlaces {
+                    names: names()
+                }))
+            ),
+            button(text(fl!("labgrid-place-release-label")))
+                .style(button::danger)
+                .on_press_maybe(any_selected.then(|| AppMsg::ConnectionMsg(
+                    ConnectionMsg::ReleasePlaces { names: names() }
+                ))),
+            button(text(fl!("labgrid-place-delete-button")))
+                .style(button::danger)
+                .on_press_maybe(any_selected.then(|| AppMsg::ShowModal(Box::new(
+                    Modal::Confirmation {
+                        msg: fl!(
+                            "labgrid-places-bulk-delete-confirmation-msg",
+                            count = selected_places.len()
+                        ),
+                        confirm: AppMsg::ConnectionMsg(ConnectionMsg::DeletePlaces {
+                            names: names()
+                        }),
+                    }
+                )))),
+            Space::new().width(6),
+            text_input(&fl!("labgrid-place-add-tag-placeholder"), &tag_text.0)
+                .on_input(|text| AppMsg::Connected(ConnectedMsg::UpdateBulkPlaceTagText(text)))
+                .width(120),
+            text(" = "),
+            text_input(&fl!("labgrid-place-add-tag-value-placeholder"), &tag_text.1)
+                .on_input(|text| AppMsg::Connected(ConnectedMsg::UpdateBulkPlaceTagValueText(text)))
+                .width(120),
+            button(text(fl!("labgrid-places-bulk-set-tag-button"))).on_press_maybe(
+                (any_selected && tag_valid).then(|| AppMsg::ConnectionMsg(
+                    ConnectionMsg::SetPlacesTag {
+                        names: names(),
+                        tag: tag_text.clone(),
+                    }
+                ))
+            ),
+        ]
+        .spacing(3)
+        .align_y(Alignment::Center),
+    )
+    .style(card_container_style)
+    .padding(6)
+    .into()
+}
+
+/// View for the progress of the running bulk place operation, which can be paused or cancelled.
+pub(crate) fn view_bulk_place_op_progress<'a>(
+    progress: BulkPlaceOpProgress,
+    paused: bool,
+) -> Element<'a, AppMsg> {
+    container(
+        row![
+            text(fl!(
+                "labgrid-places-bulk-progress-label",
+                done = progress.done,
+                total = progress.total
+            )),
+            progress_bar(0.0..=progress.total.max(1) as f32, progress.done as f32)
+                .length(Length::Fill)
+                .girth(12),
+            button(text(if paused {
+                fl!("labgrid-places-bulk-resume-button")
+            } else {
+                fl!("labgrid-places-bulk-pause-button")
+            }))
+            .style(button::secondary)
+            .on_press(AppMsg::Connected(ConnectedMsg::PauseBulkPlaceOp(!paused))),
+            button(text(fl!("labgrid-places-bulk-cancel-button")))
+                .style(button::danger)
+                .on_press(AppMsg::Connected(ConnectedMsg::CancelBulkPlaceOp)),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center),
+    )
+    .style(card_container_style)
     .padding(6)
     .into()
 }
 
+/// View for the panel listing resources which can be dragged onto places.
+pub(crate) fn view_resources_panel<'a>(
+    resources: &'a [(Resource, ResourceUi)],
+    dragged_pattern: Option<&'a String>,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let resources_list = column(resources.iter().map(|(resource, _)| {
+        let pattern = MatchPattern::for_resource(&resource.path, &resource.cls).to_string();
+        let dragged = dragged_pattern == Some(&pattern);
+        mouse_area(
+            container(row![text(pattern.clone()).size(12), space::horizontal()])
+                .style(move |theme| {
+                    if dragged {
+                        container::primary(theme)
+                    } else {
+                        card_container_style(theme)
+                    }
+                })
+                .padding(6),
+        )
+        .on_press(AppMsg::Connected(ConnectedMsg::StartResourceDrag {
+            pattern,
+        }))
+        .interaction(mouse::Interaction::Grab)
+        .into()
+    }))
+    .spacing(6)
+    .padding(padding::right(12));
+
+    view_section(
+        fl!("labgrid-resources-label"),
+        NONE_ELEMENT,
+        column![
+            text(if let Some(pattern) = dragged_pattern {
+                fl!(
+                    "labgrid-places-resources-panel-dragging-msg",
+                    pattern = pattern.as_str()
+                )
+            } else {
+                fl!("labgrid-places-resources-panel-hint")
+            })
+            .size(12),
+            scrollable(resources_list).direction(optimized_scrollbar_properties(
+                false,
+                true,
+                optimize_touch
+            ))
+        ]
+        .spacing(6)
+        .width(320),
+    )
+}
+
 /// View for the tab viewing all supplied reservations
+///
+/// The queue positions of waiting reservations are estimated from the places and their observed activity.
+/// The own reservations are listed first, the ones of other users can be hidden.
 pub(crate) fn view_reservations_tab<'a>(
-    reservations: impl IntoIterator<Item = &'a Reservation>,
+    connected: &'a AppConnected,
+    place_activity: &'a PlaceActivityLog,
     optimize_touch: bool,
 ) -> Element<'a, AppMsg> {
-    let reservations_list = row(reservations.into_iter().map(view_reservation))
+    let now = Local::now();
+    let reservations_list =
+        row(connected
+            .displayed_reservations()
+            .into_iter()
+            .map(|reservation| {
+                view_reservation(
+                    reservation,
+                    connected.reservation_is_mine(reservation),
+                    queue::estimate(
+                        reservation,
+                        &connected.reservations,
+                        &connected.places,
+                        place_activity,
+                    ),
+                    now,
+                )
+            }))
         .spacing(12.)
         .padding(padding::bottom(12))
         .wrap();
 
     container(view_section(
         fl!("labgrid-reservations-label"),
-        NONE_ELEMENT,
+        Some(
+            row![
+                button(text(fl!("labgrid-reservation-create-button")))
+                    .on_press(AppMsg::ShowModal(Box::new(Modal::CreateReservation))),
+                view_text_tooltip(
+                    button(text(fl!("labgrid-reservations-cleanup-button")))
+                        .style(button::secondary)
+                        .on_press(AppMsg::ShowModal(Box::new(Modal::CleanupReservations))),
+                    fl!("labgrid-reservations-cleanup-tooltip")
+                ),
+                view_export_button(ExportKind::Reservations),
+                Space::new().width(6),
+                checkbox(connected.reservations_show_foreign)
+                    .label(fl!("labgrid-reservations-show-foreign-checkbox"))
+                    .on_toggle(
+                        |show| AppMsg::Connected(ConnectedMsg::ReservationsShowForeign(show))
+                    ),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        ),
         scrollable(reservations_list)
             .direction(optimized_scrollbar_properties(false, true, optimize_touch))
             .width(Length::Fill),
@@ -204,83 +713,762 @@ pub(crate) fn view_reservations_tab<'a>(
     .into()
 }
 
-/// View for the tab viewing all supplied resources
-pub(crate) fn view_resources_tab<'a>(
-    resources: impl IntoIterator<Item = &'a (Resource, ResourceUi)>,
-    only_show_available: bool,
-    optimize_touch: bool,
-) -> Element<'a, AppMsg> {
-    let unnamed_group: String = fl!("labgrid-resources-no-exporter-name");
-    // BTreeMap is automatically sorted by keys
-    let mut grouped_resources: BTreeMap<String, Vec<&(Resource, ResourceUi)>> = BTreeMap::new();
-
-    for r in resources.into_iter() {
-        let exporter_name =
-            r.0.path
-                .exporter_name
-                .clone()
-                .unwrap_or(unnamed_group.clone());
-        if let Some(v) = grouped_resources.get_mut(&exporter_name) {
-            v.push(r);
-        } else {
-            grouped_resources.insert(exporter_name, vec![r]);
-        }
-    }
-
-    let resources_list = column(grouped_resources.into_iter().map(|(n, mut resources)| {
-        resources.sort_by(|first, second| first.0.path.numeric_cmp(&second.0.path));
-
-        view_section(
-            n,
-            NONE_ELEMENT,
-            column(resources.into_iter().filter_map(|(resource, ui)| {
-                if only_show_available {
-                    if resource.available {
-                        Some(view_resource(resource, ui))
-                    } else {
-                        None
-                    }
-                } else {
-                    Some(view_resource(resource, ui))
-                }
-            }))
-            .spacing(6),
-        )
-    }))
-    .width(Length::Fill)
-    .spacing(12);
+/// View for a card summarizing the resources of an exporter.
+pub(crate) fn view_exporter(summary: ExporterSummary) -> Element<'static, AppMsg> {
+    let name = if summary.name.is_empty() {
+        fl!("labgrid-resources-no-exporter-name")
+    } else {
+        summary.name.clone()
+    };
+    let stale_row = (summary.stale > 0).then(|| {
+        column![
+            rule::horizontal(1),
+            view_list_row(
+                text(fl!("labgrid-exporter-stale-label") + " : "),
+                text(summary.stale.to_string())
+            ),
+        ]
+        .spacing(6)
+    });
 
-    container(view_section(
-        fl!("labgrid-resources-label"),
-        Some(
-            checkbox(only_show_available)
-                .label(fl!("labgrid-resources-only-show-available-checkbox"))
-                .on_toggle(|show| {
-                    AppMsg::Connected(ConnectedMsg::ResourcesOnlyShowAvailable(show))
+    container(column![
+        row![bootstrap::hdd_network(), view_heading(name)]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        view_list_row(
+            text(fl!("labgrid-exporter-resources-label") + " : "),
+            text(summary.resources.to_string())
+        ),
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-exporter-available-label") + " : "),
+            text(fl!(
+                "labgrid-exporter-available-value",
+                available = summary.available,
+                total = summary.resources
+            ))
+        ),
+        progress_bar(0.0..=1.0, summary.availability())
+            .length(Length::Fill)
+            .girth(6),
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-exporter-acquired-label") + " : "),
+            text(summary.acquired.to_string())
+        ),
+        stale_row,
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-exporter-last-seen-label") + " : "),
+            text(summary.last_seen.map_or_else(
+                || fl!("labgrid-exporter-last-seen-unknown"),
+                format::date_time
+            ))
+        ),
+        summary.offline_since.map(|since| {
+            view_list_row(
+                text(fl!("labgrid-exporter-offline-label") + " : ").style(text::warning),
+                text(format::date_time(since)).style(text::warning),
+            )
+        }),
+        view_list_row(
+            view_empty(),
+            row![
+                (summary.usb > 0).then(|| {
+                    button(text(fl!("labgrid-exporter-usb-topology-button")))
+                        .style(button::secondary)
+                        .on_press(AppMsg::ShowModal(Box::new(Modal::UsbTopology {
+                            exporter: summary.name.clone(),
+                        })))
                 }),
+                button(text(fl!("labgrid-exporter-show-resources-button"))).on_press(
+                    AppMsg::Connected(ConnectedMsg::ShowExporterResources(summary.name))
+                ),
+            ]
+            .spacing(6)
         ),
-        scrollable(resources_list)
-            .direction(optimized_scrollbar_properties(false, true, optimize_touch))
-            .width(Length::Fill),
-    ))
+    ])
+    .style(card_container_style)
+    // Must be a fixed width for predictable layout and to avoid panic when using space::horizontal
+    .width(320)
     .padding(6)
     .into()
 }
 
-/// View for the tab viewing all scripts contained in the supplied `connected` app state
-pub(crate) fn view_scripts_tab(
+/// View for the tab giving an overview of the exporters the supplied resources are derived from
+pub(crate) fn view_exporters_tab<'a>(
+    resources: &'a [(Resource, ResourceUi)],
+    last_seen: &'a ExporterLastSeen,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let summaries = exporters::summarize(resources, last_seen);
+    let exporters_list: Element<'a, AppMsg> = if summaries.is_empty() {
+        text(fl!("labgrid-exporters-empty")).into()
+    } else {
+        row(summaries.into_iter().map(view_exporter))
+            .spacing(12.)
+            .padding(padding::bottom(12))
+            .wrap()
+            .into()
+    };
+
+    container(view_section(
+        fl!("labgrid-exporters-label"),
+        NONE_ELEMENT,
+        scrollable(exporters_list)
+            .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+            .width(Length::Fill),
+    ))
+    .padding(6)
+    .into()
+}
+
+/// View for the modal with the USB topology of the exporter.
+pub(crate) fn view_usb_topology<'a>(
+    exporter: &'a str,
+    topology: UsbTopology,
+    classes: &'a ResourceClassRegistry,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let name = if exporter.is_empty() {
+        fl!("labgrid-resources-no-exporter-name")
+    } else {
+        exporter.to_string()
+    };
+    let buses: Element<'a, AppMsg> = if topology.is_empty() {
+        text(fl!("usb-topology-empty")).into()
+    } else {
+        column(topology.buses.into_iter().map(|(busnum, root_hub)| {
+            let mut rows = Vec::new();
+            if !root_hub.devices.is_empty() {
+                rows.push(text(fl!("usb-topology-unknown-port-label")).into());
+                rows.extend(
+                    root_hub
+                        .devices
+                        .iter()
+                        .map(|device| view_usb_device(device, 1, classes)),
+                );
+            }
+            for (port, hub_port) in &root_hub.ports {
+                push_usb_port_rows(&mut rows, format!("{busnum}-{port}"), 0, hub_port, classes);
+            }
+            view_section(
+                fl!("usb-topology-bus-label", bus = busnum),
+                NONE_ELEMENT,
+                column(rows).spacing(3),
+            )
+        }))
+        .spacing(12)
+        .padding(padding::right(12))
+        .into()
+    };
+
+    container(
+        column![
+            row![
+                text(fl!("usb-topology-header", exporter = name)).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal)
+            ],
+            scrollable(buses).direction(optimized_scrollbar_properties(
+                true,
+                false,
+                optimize_touch
+            )),
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .max_height(600)
+    .padding(12)
+    .into()
+}
+
+/// Appends the rows of the hub port with the sysfs name `port_path` and of the ports below it,
+/// indented by their `depth` below the root hub.
+fn push_usb_port_rows<'a>(
+    rows: &mut Vec<Element<'a, AppMsg>>,
+    port_path: String,
+    depth: u16,
+    port: &UsbPort,
+    classes: &'a ResourceClassRegistry,
+) {
+    rows.push(
+        container(text(fl!(
+            "usb-topology-port-label",
+            port = port_path.as_str()
+        )))
+        .padding(padding::left(f32::from(depth) * 18.))
+        .into(),
+    );
+    rows.extend(
+        port.devices
+            .iter()
+            .map(|device| view_usb_device(device, depth + 1, classes)),
+    );
+    for (number, downstream) in &port.ports {
+        push_usb_port_rows(
+            rows,
+            format!("{port_path}.{number}"),
+            depth + 1,
+            downstream,
+            classes,
+        );
+    }
+}
+
+/// View for a row of a device in the USB topology, indented by its `depth` below the root hub.
+fn view_usb_device<'a>(
+    device: &UsbDevice,
+    depth: u16,
+    classes: &ResourceClassRegistry,
+) -> Element<'a, AppMsg> {
+    let icon = classes
+        .icon(&device.cls)
+        .map(|icon| icon.icon())
+        .unwrap_or_else(bootstrap::question_circle);
+    let card = container(
+        row![
+            icon,
+            text(MatchPattern::for_resource(&device.path, &device.cls).to_string())
+                .width(Length::Fill),
+            device
+                .devnum
+                .map(|devnum| text(fl!("usb-topology-device-value", devnum = devnum)).size(12)),
+            (!device.acquired.is_empty()).then(|| {
+                text(fl!(
+                    "usb-topology-acquired-value",
+                    place = device.acquired.as_str()
+                ))
+                .size(12)
+            }),
+            view_text_tooltip(
+                checkbox(device.available),
+                fl!("labgrid-resource-availability-tooltip"),
+            ),
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center),
+    )
+    .style(card_container_style)
+    .padding(6);
+    container(card)
+        .padding(padding::left(f32::from(depth) * 18.))
+        .into()
+}
+
+/// View for a row summarizing the resources of a class.
+pub(crate) fn view_class(
+    summary: ClassSummary,
+    classes: &ResourceClassRegistry,
+) -> Element<'static, AppMsg> {
+    let icon = classes
+        .icon(&summary.cls)
+        .map(|icon| icon.icon())
+        .unwrap_or_else(bootstrap::question_circle);
+    let percent = (summary.availability() * 100.0).round() as u32;
+
+    container(
+        row![
+            icon,
+            text(summary.cls.clone()).width(Length::Fill),
+            text(fl!(
+                "labgrid-class-resources-value",
+                resources = summary.resources
+            ))
+            .width(120),
+            column![
+                text(fl!(
+                    "labgrid-class-available-value",
+                    available = summary.available,
+                    total = summary.resources,
+                    percent = percent
+                ))
+                .size(12),
+                progress_bar(0.0..=1.0, summary.availability())
+                    .length(Length::Fill)
+                    .girth(6),
+            ]
+            .spacing(3)
+            .width(200),
+            text(fl!(
+                "labgrid-class-acquired-value",
+                acquired = summary.acquired
+            ))
+            .width(120),
+            button(text(fl!("labgrid-class-show-resources-button"))).on_press(AppMsg::Connected(
+                ConnectedMsg::ShowClassResources(summary.cls)
+            )),
+        ]
+        .spacing(12)
+        .align_y(Alignment::Center),
+    )
+    .style(card_container_style)
+    .padding(6)
+    .into()
+}
+
+/// View for the tab breaking down the supplied resources per class
+pub(crate) fn view_classes_tab<'a>(
+    resources: &'a [(Resource, ResourceUi)],
+    classes: &'a ResourceClassRegistry,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let summaries = resource_classes::summarize_classes(resources);
+    let classes_list: Element<'a, AppMsg> = if summaries.is_empty() {
+        text(fl!("labgrid-classes-empty")).into()
+    } else {
+        column(
+            summaries
+                .into_iter()
+                .map(|summary| view_class(summary, classes)),
+        )
+        .spacing(6)
+        .padding(padding::bottom(12))
+        .into()
+    };
+
+    container(view_section(
+        fl!("labgrid-classes-label"),
+        NONE_ELEMENT,
+        scrollable(classes_list)
+            .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+            .width(Length::Fill),
+    ))
+    .padding(6)
+    .into()
+}
+
+/// View for a bar of a chart in the dashboard tab, `share` ranges from `0.0` to `1.0`.
+fn view_dashboard_bar(
+    label: String,
+    value: String,
+    share: f32,
+    color: Option<Color>,
+) -> Element<'static, AppMsg> {
+    row![
+        text(label).width(240),
+        progress_bar(0.0..=1.0, share)
+            .length(Length::Fill)
+            .girth(12)
+            .style(move |theme: &Theme| {
+                let style = progress_bar::primary(theme);
+                match color {
+                    Some(color) => progress_bar::Style {
+                        bar: color.into(),
+                        ..style
+                    },
+                    None => style,
+                }
+            }),
+        text(value).width(160),
+    ]
+    .spacing(12)
+    .align_y(Alignment::Center)
+    .into()
+}
+
+/// View for a card with a single figure in the dashboard tab.
+fn view_dashboard_figure(label: String, value: String) -> Element<'static, AppMsg> {
+    container(column![text(label).size(14), view_heading(value)].spacing(3))
+        .style(card_container_style)
+        .width(180)
+        .padding(6)
+        .into()
+}
+
+/// View for a card with a bar chart in the dashboard tab, `empty` is displayed when there are no bars.
+///
+/// The optional `title_element` is right-aligned next to the label.
+fn view_dashboard_chart(
+    label: String,
+    title_element: Option<Element<'static, AppMsg>>,
+    bars: Vec<Element<'static, AppMsg>>,
+    empty: String,
+) -> Element<'static, AppMsg> {
+    let chart: Element<'static, AppMsg> = if bars.is_empty() {
+        text(empty).into()
+    } else {
+        column(bars).spacing(6).into()
+    };
+    let title = row![text(label).size(18), space::horizontal(), title_element]
+        .spacing(6)
+        .align_y(Alignment::Center);
+    container(column![title, rule::horizontal(1), chart].spacing(6))
+        .style(card_container_style)
+        .width(Length::Fill)
+        .padding(6)
+        .into()
+}
+
+/// View for the tab with aggregate statistics of the places and resources, updated live from the client stream.
+///
+/// The occupancy of the places is only displayed when `record_utilization` is set.
+pub(crate) fn view_dashboard_tab(
     connected: &AppConnected,
+    card_palette: &CardPalette,
+    utilization: &UtilizationLog,
+    record_utilization: bool,
     optimize_touch: bool,
-) -> Element<'_, AppMsg> {
+) -> Element<'static, AppMsg> {
+    let stats =
+        DashboardStats::compute(&connected.places, &connected.resources, &connected.identity);
+    let percent = |share: f32| (share * 100.0).round() as u32;
+    let count_value = |count: usize, share: f32| {
+        fl!(
+            "dashboard-count-value",
+            count = count,
+            percent = percent(share)
+        )
+    };
+
+    let figures = row![
+        view_dashboard_figure(fl!("dashboard-places-label"), stats.places.to_string()),
+        view_dashboard_figure(fl!("dashboard-acquired-label"), stats.acquired.to_string()),
+        view_dashboard_figure(fl!("dashboard-free-label"), stats.free.to_string()),
+        view_dashboard_figure(
+            fl!("dashboard-resources-available-label"),
+            count_value(stats.available, stats.availability())
+        ),
+    ]
+    .spacing(12)
+    .wrap();
+
+    let place_states = stats
+        .place_states
+        .iter()
+        .map(|&(state, count)| {
+            let label = match state {
+                PlaceCardState::Free => fl!("dashboard-state-free"),
+                PlaceCardState::Mine => fl!("dashboard-state-mine"),
+                PlaceCardState::Others => fl!("dashboard-state-others"),
+                PlaceCardState::Reserved => fl!("dashboard-state-reserved"),
+                PlaceCardState::Maintenance => fl!("dashboard-state-maintenance"),
+            };
+            view_dashboard_bar(
+                label,
+                count_value(count, stats.place_share(count)),
+                stats.place_share(count),
+                Some(card_palette.color(state)),
+            )
+        })
+        .collect();
+    let acquisitions = stats
+        .acquisitions
+        .iter()
+        .map(|(owner, count)| {
+            view_dashboard_bar(
+                owner.clone(),
+                count_value(*count, stats.place_share(*count)),
+                stats.place_share(*count),
+                card_palette.owner_color(owner),
+            )
+        })
+        .collect();
+    let classes = stats
+        .classes
+        .iter()
+        .map(|summary| {
+            view_dashboard_bar(
+                summary.cls.clone(),
+                fl!(
+                    "labgrid-class-available-value",
+                    available = summary.available,
+                    total = summary.resources,
+                    percent = percent(summary.availability())
+                ),
+                summary.availability(),
+                None,
+            )
+        })
+        .collect();
+    let now = Local::now();
+    let occupancy = if record_utilization {
+        connected
+            .places
+            .iter()
+            .map(|(place, _)| {
+                let share = utilization.occupancy(&place.name, connected.utilization_range, now);
+                view_dashboard_bar(
+                    place.name.clone(),
+                    fl!("dashboard-percent-value", percent = percent(share)),
+                    share,
+                    None,
+                )
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let occupancy_empty = if record_utilization {
+        fl!("dashboard-places-empty")
+    } else {
+        fl!("dashboard-occupancy-disabled")
+    };
+    let range_pick_list = pick_list(
+        UtilizationRange::ALL,
+        Some(connected.utilization_range),
+        |range| AppMsg::Connected(ConnectedMsg::UtilizationRangeSelected(range)),
+    );
+
+    container(view_section(
+        fl!("dashboard-label"),
+        NONE_ELEMENT,
+        scrollable(
+            column![
+                figures,
+                view_dashboard_chart(
+                    fl!("dashboard-place-states-label"),
+                    None,
+                    place_states,
+                    fl!("dashboard-places-empty")
+                ),
+                view_dashboard_chart(
+                    fl!("dashboard-acquisitions-label"),
+                    None,
+                    acquisitions,
+                    fl!("dashboard-acquisitions-empty")
+                ),
+                view_dashboard_chart(
+                    fl!("dashboard-occupancy-label"),
+                    record_utilization.then(|| range_pick_list.into()),
+                    occupancy,
+                    occupancy_empty
+                ),
+                view_dashboard_chart(
+                    fl!("dashboard-class-availability-label"),
+                    None,
+                    classes,
+                    fl!("labgrid-classes-empty")
+                ),
+            ]
+            .spacing(12)
+            .padding(padding::bottom(12).right(12)),
+        )
+        .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+        .width(Length::Fill),
+    ))
+    .padding(6)
+    .into()
+}
+
+/// Chip showing an active filter of the resources tab, with a button clearing it.
+fn view_resources_filter_chip<'a>(
+    label: String,
+    clear_msg: ConnectedMsg,
+    clear_tooltip: String,
+) -> Element<'a, AppMsg> {
+    container(
+        row![
+            text(label),
+            view_text_tooltip(
+                button(bootstrap::x())
+                    .style(button::text)
+                    .on_press(AppMsg::Connected(clear_msg)),
+                clear_tooltip
+            ),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center),
+    )
+    .style(container::secondary)
+    .padding(padding::left(6))
+    .into()
+}
+
+/// Button exporting the entries displayed in the tab to CSV or JSON.
+fn view_export_button<'a>(kind: ExportKind) -> Element<'a, AppMsg> {
+    view_text_tooltip(
+        button(text(fl!("export-button")))
+            .style(button::secondary)
+            .on_press(AppMsg::ExportTab { kind }),
+        fl!("export-tooltip"),
+    )
+    .into()
+}
+
+/// View for the tab viewing the resources of the `connected` app state that match its filters
+///
+/// Acquired resources are colored by the owner of the acquiring place.
+/// The resources in `watched_resources` are marked as watched.
+/// The sections of offline exporters are marked, their resources are displayed according to `offline_display`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn view_resources_tab<'a>(
+    connected: &'a AppConnected,
+    card_palette: &CardPalette,
+    acquire_history: &'a AcquireHistory,
+    classes: &'a ResourceClassRegistry,
+    watched_resources: &BTreeSet<String>,
+    offline_display: OfflineExporterResources,
+    launch_terminal: bool,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let unnamed_group: String = fl!("labgrid-resources-no-exporter-name");
+    let filter = connected.resource_filter();
+    // BTreeMap is automatically sorted by keys
+    let mut grouped_resources: BTreeMap<String, Vec<&(Resource, ResourceUi)>> = BTreeMap::new();
+
+    for r in connected
+        .resources
+        .iter()
+        .filter(|(resource, _)| filter.matches(resource))
+    {
+        let exporter_name =
+            r.0.path
+                .exporter_name
+                .clone()
+                .unwrap_or(unnamed_group.clone());
+        if let Some(v) = grouped_resources.get_mut(&exporter_name) {
+            v.push(r);
+        } else {
+            grouped_resources.insert(exporter_name, vec![r]);
+        }
+    }
+
+    let resources_list = column(grouped_resources.into_iter().map(|(n, mut resources)| {
+        resources.sort_by(|first, second| first.0.path.numeric_cmp(&second.0.path));
+        let offline_since = resources.first().and_then(|(resource, _)| {
+            connected
+                .exporter_last_seen
+                .offline_since(exporters::exporter_name(&resource.path))
+        });
+        let offline_display = if offline_since.is_some() {
+            offline_display
+        } else {
+            OfflineExporterResources::Show
+        };
+        if offline_display == OfflineExporterResources::Hide {
+            resources.clear();
+        }
+
+        view_section(
+            n,
+            offline_since.map(|since| {
+                view_text_tooltip(
+                    row![
+                        bootstrap::exclamation_triangle(),
+                        text(fl!(
+                            "labgrid-resources-exporter-offline-label",
+                            time = format::date_time(since)
+                        ))
+                    ]
+                    .spacing(6)
+                    .align_y(Alignment::Center),
+                    fl!("labgrid-resources-exporter-offline-tooltip"),
+                )
+            }),
+            column(resources.into_iter().map(|(resource, ui)| {
+                // Resources are acquired by places, the owner is the one of the place
+                let owner_color = connected
+                    .places
+                    .iter()
+                    .find(|(p, _)| !resource.acquired.is_empty() && p.name == resource.acquired)
+                    .and_then(|(p, _)| p.acquired.as_ref())
+                    .and_then(|owner| card_palette.owner_color(owner));
+                let card = view_resource(
+                    resource,
+                    ui,
+                    acquire_history,
+                    classes,
+                    owner_color,
+                    watched_resources.contains(&ResourceWatch::key(&resource.path)),
+                    launch_terminal,
+                );
+                if offline_display == OfflineExporterResources::Gray {
+                    container(card).style(offline_resource_style).into()
+                } else {
+                    card
+                }
+            }))
+            .spacing(6),
+        )
+    }))
+    .width(Length::Fill)
+    .spacing(12);
+
+    container(view_section(
+        fl!("labgrid-resources-label"),
+        Some(
+            row![
+                filter.exporter.map(|exporter| {
+                    view_resources_filter_chip(
+                        fl!(
+                            "labgrid-resources-exporter-filter-label",
+                            exporter = if exporter.is_empty() {
+                                unnamed_group.as_str()
+                            } else {
+                                exporter
+                            }
+                        ),
+                        ConnectedMsg::ClearResourcesExporterFilter,
+                        fl!("labgrid-resources-exporter-filter-clear-tooltip"),
+                    )
+                }),
+                text_input(&fl!("labgrid-resources-search-placeholder"), filter.search)
+                    .on_input(|text| AppMsg::Connected(ConnectedMsg::ResourcesSearchInput(text)))
+                    .width(240),
+                pick_list(
+                    resource_filter::observed_classes(connected.resources.iter().map(|(r, _)| r)),
+                    connected.resources_class_filter.clone(),
+                    |cls| AppMsg::Connected(ConnectedMsg::ResourcesClassSelected(cls))
+                )
+                .placeholder(fl!("labgrid-resources-class-filter-placeholder")),
+                filter.cls.map(|_| {
+                    view_text_tooltip(
+                        button(bootstrap::x())
+                            .style(button::text)
+                            .on_press(AppMsg::Connected(ConnectedMsg::ClearResourcesClassFilter)),
+                        fl!("labgrid-resources-class-filter-clear-tooltip"),
+                    )
+                }),
+                checkbox(filter.only_available)
+                    .label(fl!("labgrid-resources-only-show-available-checkbox"))
+                    .on_toggle(|show| {
+                        AppMsg::Connected(ConnectedMsg::ResourcesOnlyShowAvailable(show))
+                    }),
+                view_export_button(ExportKind::Resources),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        ),
+        scrollable(resources_list)
+            .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+            .width(Length::Fill),
+    ))
+    .padding(6)
+    .into()
+}
+
+/// View for the tab viewing all scripts contained in the supplied `connected` app state
+pub(crate) fn view_scripts_tab<'a>(
+    connected: &'a AppConnected,
+    script_meta: &'a ScriptMetaSettings,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
     column![
         row![
             column![
                 view_heading(fl!("scripts-env-label")),
-                view_env(&connected.scripts.env, &connected.places)
+                view_env(&connected.scripts.env, &connected.places),
+                view_heading(fl!("reservation-run-label")),
+                view_reservation_run(
+                    &connected.scripts,
+                    &connected.reservation_run_filter_text,
+                    connected.reservation_run_script.as_ref(),
+                    connected.reservation_run.as_ref()
+                )
             ]
             .spacing(12)
             .padding(6),
-            view_scripts(&connected.scripts, &connected.script_status, optimize_touch)
+            view_scripts(
+                &connected.scripts,
+                &connected.script_status,
+                &connected.script_validations,
+                &connected.collapsed_script_dirs,
+                script_meta,
+                optimize_touch
+            )
         ]
         .height(Length::FillPortion(1)),
         view_section(
@@ -365,11 +1553,13 @@ pub(crate) fn view_env<'a>(env: &'a Env, places: &'a [(Place, PlaceUi)]) -> Elem
                 text(EnvEntry::LgEnv.as_env_var() + " = "),
                 space::horizontal(),
                 text(lg_env_val.clone()),
-                button(bootstrap::foldertwo_open()).on_press(AppMsg::Connected(
-                    ConnectedMsg::ScriptsEnvOpenLgEnvFileDialog {
-                        initial_file: PathBuf::from(lg_env_val)
-                    }
-                )),
+                button(bootstrap::foldertwo_open()).on_press(AppMsg::PickPath {
+                    target: FileBrowserTarget::LgEnvFile,
+                    dir: PathBuf::from(&lg_env_val)
+                        .parent()
+                        .map(|dir| dir.to_path_buf())
+                        .unwrap_or_default()
+                }),
                 button(bootstrap::backspace()).on_press(AppMsg::Connected(
                     ConnectedMsg::ScriptsEnvClear {
                         entry: EnvEntry::LgEnv
@@ -387,17 +1577,87 @@ pub(crate) fn view_env<'a>(env: &'a Env, places: &'a [(Place, PlaceUi)]) -> Elem
     .into()
 }
 
-/// View for the supplied scripts.
+/// View for running a script on the next available place matching a reservation template.
 ///
-/// `script_status` is the state for the single current script.
-/// E.g. if it's path matches with one of the scripts, the script element will display running, finished
-/// with the exit-code, .. depending on the status
-pub(crate) fn view_scripts<'a>(
+/// `run` is the currently active run, while it is active a new one can't be started.
+pub(crate) fn view_reservation_run<'a>(
     scripts: &'a Scripts,
-    script_status: &'a scripts::ScriptStatus,
-    optimize_touch: bool,
+    filter_text: &'a str,
+    selected_script: Option<&'a String>,
+    run: Option<&'a ReservationRun>,
 ) -> Element<'a, AppMsg> {
-    let scripts_dir = scripts.dir();
+    let filters_valid = filter_text
+        .parse::<FilterExpr>()
+        .is_ok_and(|expr| expr.to_filters().is_ok());
+    let script_names: Vec<String> = scripts.iter().map(|s| s.name.clone()).collect();
+    let run_button = button(text(fl!("reservation-run-button"))).on_press_maybe(
+        (filters_valid && selected_script.is_some() && run.is_none())
+            .then_some(AppMsg::Connected(ConnectedMsg::StartReservationRun)),
+    );
+    let status: Element<'a, AppMsg> = match run {
+        Some(run) => row![
+            text(match &run.stage {
+                RunStage::Reserving => fl!("reservation-run-status-reserving"),
+                RunStage::Waiting { .. } => fl!("reservation-run-status-waiting"),
+                RunStage::Acquiring { place, .. } => {
+                    fl!("reservation-run-status-acquiring", place = place.as_str())
+                }
+                RunStage::Running { place, .. } => {
+                    fl!("reservation-run-status-running", place = place.as_str())
+                }
+            }),
+            space::horizontal(),
+            button(text(fl!("reservation-run-cancel-button")))
+                .style(button::danger)
+                .on_press(AppMsg::Connected(ConnectedMsg::CancelReservationRun)),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center)
+        .into(),
+        None => view_empty(),
+    };
+
+    container(
+        column![
+            text_input(&fl!("reservation-run-filter-placeholder"), filter_text)
+                .on_input(|text| AppMsg::Connected(ConnectedMsg::UpdateReservationRunFilter(text))),
+            row![
+                pick_list(script_names, selected_script, |name| {
+                    AppMsg::Connected(ConnectedMsg::SelectReservationRunScript(name))
+                })
+                .placeholder(fl!("reservation-run-script-placeholder")),
+                space::horizontal(),
+                run_button,
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+            status,
+        ]
+        .spacing(6)
+        .padding(3)
+        .width(350.),
+    )
+    .style(container::rounded_box)
+    .into()
+}
+
+/// View for the supplied scripts.
+///
+/// `script_status` is the state for the single current script.
+/// E.g. if it's path matches with one of the scripts, the script element will display running, finished
+/// with the exit-code, .. depending on the status
+/// `validations` are the results of validating the python scripts, by script path.
+/// Scripts in subdirectories are listed in a tree, `collapsed_dirs` are the relative paths of the collapsed ones.
+/// The favorites of `script_meta` are pinned at the top, the scripts are ordered by its manual order.
+pub(crate) fn view_scripts<'a>(
+    scripts: &'a Scripts,
+    script_status: &'a scripts::ScriptStatus,
+    validations: &'a HashMap<PathBuf, ScriptValidation>,
+    collapsed_dirs: &'a BTreeSet<String>,
+    script_meta: &'a ScriptMetaSettings,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let scripts_dir = scripts.dir();
     let scripts_iter = scripts.iter();
     let scripts_dir_str = scripts_dir.display().to_string();
     let scripts_list: Element<'a, AppMsg> = if scripts_iter.len() == 0 {
@@ -405,11 +1665,81 @@ pub(crate) fn view_scripts<'a>(
             .padding(12)
             .into()
     } else {
-        row(scripts_iter.map(|s| view_script(s, script_status)))
+        let script_cards = |dir_scripts: Vec<&'a Script>| {
+            row(dir_scripts
+                .into_iter()
+                .map(|s| view_script(s, script_status, validations.get(&s.path), script_meta)))
             .spacing(12.)
             .padding(padding::bottom(12))
             .wrap()
-            .into()
+        };
+        let ancestor_collapsed = |dir: &str| {
+            collapsed_dirs
+                .iter()
+                .any(|collapsed| dir.starts_with(&format!("{collapsed}/")))
+        };
+        let favorites = script_meta.sort(scripts.iter().filter(|s| script_meta.is_favorite(s)));
+        let favorites_section = (!favorites.is_empty()).then(|| {
+            column![
+                row![
+                    bootstrap::star_fill(),
+                    text(fl!("scripts-favorites-label")).size(18)
+                ]
+                .spacing(6)
+                .align_y(Alignment::Center),
+                script_cards(favorites),
+            ]
+            .spacing(6)
+        });
+        column(
+            std::iter::once(favorites_section.into()).chain(
+                scripts
+                    .by_dir()
+                    .into_iter()
+                    .filter(|(dir, _)| !ancestor_collapsed(dir))
+                    .map(|(dir, dir_scripts)| {
+                        let dir_scripts = script_meta.sort(
+                            dir_scripts
+                                .into_iter()
+                                .filter(|s| !script_meta.is_favorite(s)),
+                        );
+                        if dir.is_empty() {
+                            return script_cards(dir_scripts).into();
+                        }
+                        let collapsed = collapsed_dirs.contains(dir);
+                        let (depth, name) = dir
+                            .rsplit_once('/')
+                            .map(|(parent, name)| (parent.split('/').count() + 1, name))
+                            .unwrap_or((1, dir));
+                        column![
+                            button(
+                                row![
+                                    if collapsed {
+                                        bootstrap::chevron_right()
+                                    } else {
+                                        bootstrap::chevron_down()
+                                    },
+                                    bootstrap::folder(),
+                                    text(name).size(18),
+                                ]
+                                .spacing(6)
+                                .align_y(Alignment::Center)
+                            )
+                            .style(button::text)
+                            .on_press(AppMsg::Connected(
+                                ConnectedMsg::ToggleScriptDirCollapsed(dir.to_string())
+                            )),
+                            (!collapsed && !dir_scripts.is_empty())
+                                .then(|| script_cards(dir_scripts)),
+                        ]
+                        .spacing(6)
+                        .padding(padding::left(18. * (depth - 1) as f32))
+                        .into()
+                    }),
+            ),
+        )
+        .spacing(6)
+        .into()
     };
 
     container(column![view_section(
@@ -418,11 +1748,10 @@ pub(crate) fn view_scripts<'a>(
             row![
                 container(text(scripts_dir_str)).padding(padding::right(5)),
                 view_text_tooltip(
-                    button(bootstrap::foldertwo_open()).on_press(AppMsg::Connected(
-                        ConnectedMsg::OpenChangeScriptsDirDialog {
-                            initial_dir: scripts_dir.to_owned()
-                        }
-                    )),
+                    button(bootstrap::foldertwo_open()).on_press(AppMsg::PickPath {
+                        target: FileBrowserTarget::ScriptsDir,
+                        dir: scripts_dir.to_owned()
+                    }),
                     fl!("scripts-dir-pick-tooltip")
                 ),
                 view_text_tooltip(
@@ -433,6 +1762,7 @@ pub(crate) fn view_scripts<'a>(
                 ),
                 view_text_tooltip(
                     button(bootstrap::arrow_clockwise())
+                        .style(button::secondary)
                         .on_press(AppMsg::Connected(ConnectedMsg::RescanScriptsDir)),
                     fl!("scripts-dir-rescan-tooltip")
                 ),
@@ -456,19 +1786,68 @@ pub(crate) fn view_scripts<'a>(
 pub(crate) fn view_script<'a>(
     script: &'a Script,
     script_status: &'a scripts::ScriptStatus,
+    validation: Option<&'a ScriptValidation>,
+    script_meta: &'a ScriptMetaSettings,
 ) -> Element<'a, AppMsg> {
-    let filename = script
-        .path()
-        .file_name()
-        .expect("Path to script without name")
-        .to_string_lossy()
-        .to_string();
+    let favorite = script_meta.is_favorite(script);
+    let move_button = |icon: Element<'a, AppMsg>, up: bool, tooltip: String| {
+        view_text_tooltip(
+            button(icon)
+                .style(button::text)
+                .on_press(AppMsg::MoveScript {
+                    name: script.name.clone(),
+                    up,
+                }),
+            tooltip,
+        )
+    };
+    let header = row![
+        view_text_tooltip(
+            button(if favorite {
+                bootstrap::star_fill()
+            } else {
+                bootstrap::star()
+            })
+            .style(button::text)
+            .on_press(AppMsg::ToggleScriptFavorite(script.name.clone())),
+            fl!("script-favorite-tooltip")
+        ),
+        view_text_tooltip(
+            text(script_meta.display_name(script)).size(18),
+            script.name.as_str()
+        ),
+        space::horizontal(),
+        move_button(
+            bootstrap::arrow_up().into(),
+            true,
+            fl!("script-move-up-tooltip")
+        ),
+        move_button(
+            bootstrap::arrow_down().into(),
+            false,
+            fl!("script-move-down-tooltip")
+        ),
+        view_text_tooltip(
+            button(bootstrap::pencil())
+                .style(button::text)
+                .on_press(AppMsg::ShowModal(Box::new(Modal::ScriptDisplayName {
+                    script_name: script.name.clone(),
+                }))),
+            fl!("script-display-name-tooltip")
+        ),
+    ]
+    .spacing(3)
+    .align_y(Alignment::Center);
     let script_execute_abort_button = match script_status {
         scripts::ScriptStatus::Running {
             script: running, ..
         } if script == running => button(text(fl!("script-abort-button")))
             .style(button::danger)
             .on_press(AppMsg::Connected(ConnectedMsg::AbortScript)),
+        scripts::ScriptStatus::CleaningUp {
+            script: cleaning_up,
+            ..
+        } if script == cleaning_up => button(text(fl!("script-execute-button"))),
 
         _ => button(text(fl!("script-execute-button"))).on_press(AppMsg::Connected(
             ConnectedMsg::ExecuteScript {
@@ -480,6 +1859,10 @@ pub(crate) fn view_script<'a>(
         scripts::ScriptStatus::Running {
             script: running, ..
         } if script == running => text(fl!("script-status-running")).into(),
+        scripts::ScriptStatus::CleaningUp {
+            script: cleaning_up,
+            ..
+        } if script == cleaning_up => text(fl!("script-status-cleaning-up")).into(),
         scripts::ScriptStatus::Finished {
             script: finished,
             exit_code,
@@ -498,15 +1881,62 @@ pub(crate) fn view_script<'a>(
         })
         .padding(6)
         .into(),
+        scripts::ScriptStatus::TimedOut {
+            script: timed_out,
+            timeout,
+        } if script == timed_out => container(text(fl!(
+            "script-status-timed-out",
+            secs = timeout.as_secs()
+        )))
+        .style(|theme: &iced::Theme| {
+            container::rounded_box(theme).background(theme.extended_palette().danger.weak.color)
+        })
+        .padding(6)
+        .into(),
         _ => text(fl!("script-status-none")).into(),
     };
 
+    let validate_button = (script._type == ScriptType::Python).then(|| {
+        let button = button(text(fl!("script-validate-button")));
+        view_text_tooltip(
+            if validation == Some(&ScriptValidation::Running) {
+                button
+            } else {
+                button.on_press(AppMsg::Connected(ConnectedMsg::ValidateScript {
+                    script: script.clone(),
+                }))
+            },
+            fl!("script-validate-tooltip"),
+        )
+    });
+    let validation_row = validation.map(|validation| {
+        let validation_element: Element<'a, AppMsg> = match validation {
+            ScriptValidation::Running => text(fl!("script-validation-running")).into(),
+            ScriptValidation::Valid => text(fl!("script-validation-valid")).into(),
+            ScriptValidation::Invalid => container(text(fl!("script-validation-invalid")))
+                .style(|theme: &iced::Theme| {
+                    container::rounded_box(theme)
+                        .background(theme.extended_palette().danger.weak.color)
+                })
+                .padding(6)
+                .into(),
+        };
+        column![
+            rule::horizontal(1),
+            view_list_row(text(fl!("script-validation-label")), validation_element)
+        ]
+    });
+
     container(column![
-        view_list_row(text(fl!("script-label") + " : "), text(filename)),
+        header,
         rule::horizontal(1),
         view_list_row(text(fl!("script-status-label")), status_element),
+        validation_row,
         rule::horizontal(1),
-        view_list_row(view_empty(), script_execute_abort_button)
+        view_list_row(
+            view_empty(),
+            row![validate_button, script_execute_abort_button].spacing(6)
+        )
     ])
     .style(card_container_style)
     // Must be a fixed width for predictable layout and to avoid panic when using space::horizontal
@@ -549,7 +1979,30 @@ pub(crate) fn view_process_output<'a>(
 
 /// View a single supplied place.
 /// `ui` holds state about the place ui, e.g. whether the place details should be shown or not.
-pub(crate) fn view_place<'a>(place: &'a Place, ui: &'a PlaceUi) -> Element<'a, AppMsg> {
+///
+/// `selected` is [Option::Some] when the places are in select mode and then contains whether the place is selected.
+/// The card is color coded with `color`, which reflects the state of the place.
+/// The owner of an acquired place is displayed in `owner_color`.
+/// `watched` determines whether notifications are sent when the place gets released,
+/// `mine` whether the place is acquired by this client,
+/// `quick_actions` which action buttons are shown,
+/// `session` the status of the session timer if the place was acquired through the UI,
+/// `availability` the availability of the matched resources if it is known.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn view_place<'a>(
+    place: &'a Place,
+    ui: &'a PlaceUi,
+    selected: Option<bool>,
+    color: Color,
+    owner_color: Option<Color>,
+    watched: bool,
+    mine: bool,
+    quick_actions: &'a QuickActionSettings,
+    session: Option<SessionTimerStatus>,
+    availability: Option<ResourceAvailability>,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let padding = button_padding(optimize_touch);
     let delete_button: Element<'_, AppMsg> = button(text(fl!("labgrid-place-delete-button")))
         .on_press(AppMsg::ShowModal(Box::new(Modal::Confirmation {
             msg: fl!(
@@ -561,159 +2014,1212 @@ pub(crate) fn view_place<'a>(place: &'a Place, ui: &'a PlaceUi) -> Element<'a, A
             }),
         })))
         .style(button::danger)
+        .padding(padding)
         .into();
-    let acquired_release_button: Element<'_, AppMsg> = if place.acquired.is_some() {
-        button(text(fl!("labgrid-place-release-label")))
-            .on_press(AppMsg::ConnectionMsg(ConnectionMsg::ReleasePlace {
-                name: place.name.clone(),
-            }))
-            .style(button::danger)
-            .into()
-    } else {
-        button(text(fl!("labgrid-place-acquire-button")))
-            .on_press(AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlace {
-                name: place.name.clone(),
-            }))
-            .into()
-    };
+    let acquired_release_button: Option<Element<'_, AppMsg>> = quick_actions
+        .acquire
+        .then(|| view_place_acquire_release_button(place, mine, padding));
+    let power_cycle_button = quick_actions.power_cycle.then(|| {
+        view_text_tooltip(
+            button(bootstrap::power())
+                .style(button::secondary)
+                .padding(padding)
+                .on_press_maybe(mine.then(|| {
+                    AppMsg::Connected(ConnectedMsg::PowerCyclePlace {
+                        place_name: place.name.clone(),
+                    })
+                })),
+            fl!("labgrid-place-power-cycle-tooltip"),
+        )
+    });
+    let console_button = quick_actions.console.then(|| {
+        view_text_tooltip(
+            button(bootstrap::terminal())
+                .style(button::secondary)
+                .padding(padding)
+                .on_press(AppMsg::ClipboardCopy(quick_actions::console_command(
+                    &place.name,
+                ))),
+            fl!("labgrid-place-console-tooltip"),
+        )
+    });
+    let favorite_script_button = quick_actions
+        .favorite_script_name
+        .as_ref()
+        .filter(|_| quick_actions.favorite_script)
+        .map(|script_name| {
+            view_text_tooltip(
+                button(text(script_name.as_str()))
+                    .style(button::secondary)
+                    .padding(padding)
+                    .on_press(AppMsg::Connected(ConnectedMsg::ExecuteScriptOnPlace {
+                        script_name: script_name.clone(),
+                        place_name: place.name.clone(),
+                    })),
+                fl!("labgrid-place-favorite-script-tooltip"),
+            )
+        });
+
+    let select_row = selected.map(|selected| {
+        column![
+            checkbox(selected)
+                .label(fl!("labgrid-place-select-checkbox"))
+                .on_toggle(|_| AppMsg::Connected(ConnectedMsg::TogglePlaceSelected {
+                    place_name: place.name.clone()
+                })),
+            rule::horizontal(1),
+        ]
+        .spacing(6)
+    });
+
+    let mine_badge = mine.then(|| {
+        row![
+            space::horizontal(),
+            view_text_tooltip(
+                text(fl!("labgrid-place-mine-badge")).style(text::primary),
+                fl!("labgrid-place-mine-tooltip")
+            )
+        ]
+    });
 
     container(column![
-        view_place_general_info(place, ui),
+        select_row,
+        mine_badge,
+        view_place_general_info(place, ui, false, owner_color),
+        availability.map(view_place_resource_availability),
+        session.map(|status| view_place_session(place, status)),
         rule::horizontal(1),
         view_list_row(
-            button(text(fl!("show-details-button")))
-                .style(button::secondary)
-                .on_press(AppMsg::ShowModal(Box::new(Modal::PlaceDetails {
-                    place_name: place.name.clone()
-                }))),
-            row![delete_button, acquired_release_button]
-                .align_y(Alignment::Center)
-                .spacing(6)
+            row![
+                button(text(fl!("show-details-button")))
+                    .style(button::secondary)
+                    .padding(padding)
+                    .on_press(AppMsg::ShowModal(Box::new(Modal::PlaceDetails {
+                        place_name: place.name.clone()
+                    }))),
+                view_text_tooltip(
+                    button(if watched {
+                        bootstrap::star_fill()
+                    } else {
+                        bootstrap::star()
+                    })
+                    .style(button::secondary)
+                    .padding(padding)
+                    .on_press(AppMsg::ToggleWatchPlace {
+                        place_name: place.name.clone()
+                    }),
+                    if watched {
+                        fl!("labgrid-place-unwatch-tooltip")
+                    } else {
+                        fl!("labgrid-place-watch-tooltip")
+                    }
+                ),
+            ]
+            .spacing(6),
+            row![
+                favorite_script_button,
+                console_button,
+                power_cycle_button,
+                delete_button,
+                acquired_release_button
+            ]
+            .align_y(Alignment::Center)
+            .spacing(6)
         )
     ])
-    .style(card_container_style)
+    .style(colored_card_container_style(color))
     // Must be a fixed width for predictable layout and to avoid panic when using space::horizontal
     .width(320)
     .padding(6)
     .into()
 }
 
-/// View for a single reservation
-pub(crate) fn view_reservation(reservation: &Reservation) -> Element<'_, AppMsg> {
-    container(column![
+/// View for the summary of how many resources matched by the place are available.
+fn view_place_resource_availability(
+    availability: ResourceAvailability,
+) -> Element<'static, AppMsg> {
+    column![
+        rule::horizontal(1),
         view_list_row(
-            text(fl!("labgrid-reservation-owner-label") + " : "),
-            text(&reservation.owner)
+            text(fl!("labgrid-place-resources-label") + " : "),
+            view_text_tooltip(
+                text(fl!(
+                    "labgrid-place-resources-available",
+                    available = availability.available,
+                    total = availability.total
+                ))
+                .style(if availability.is_complete() {
+                    text::success
+                } else {
+                    text::warning
+                }),
+                fl!("labgrid-place-resources-tooltip")
+            )
         ),
+    ]
+    .spacing(6)
+    .into()
+}
+
+/// View for the session timer of a place acquired through the UI.
+///
+/// The session can be extended or kept when the place is about to be released automatically.
+fn view_place_session(place: &Place, status: SessionTimerStatus) -> Element<'_, AppMsg> {
+    let remaining = status.remaining.map(|remaining| {
+        text(fl!(
+            "labgrid-place-session-remaining",
+            remaining = format::duration(remaining)
+        ))
+        .style(if status.warning {
+            text::warning
+        } else {
+            text::default
+        })
+    });
+    let buttons = status.remaining.is_some().then(|| {
+        row![
+            view_text_tooltip(
+                button(bootstrap::hourglass_split())
+                    .style(button::secondary)
+                    .on_press(AppMsg::Connected(ConnectedMsg::ExtendSession {
+                        place_name: place.name.clone()
+                    })),
+                fl!("labgrid-place-session-extend-tooltip")
+            ),
+            view_text_tooltip(
+                button(bootstrap::pin_fill())
+                    .style(button::secondary)
+                    .on_press(AppMsg::Connected(ConnectedMsg::KeepPlace {
+                        place_name: place.name.clone()
+                    })),
+                fl!("labgrid-place-session-keep-tooltip")
+            ),
+        ]
+        .spacing(6)
+    });
+
+    column![
         rule::horizontal(1),
         view_list_row(
-            text(fl!("labgrid-reservation-token-label") + " : "),
+            text(fl!("labgrid-place-session-label") + " : "),
             row![
-                text(&reservation.token),
-                view_text_tooltip(
-                    button(bootstrap::copy())
-                        .style(button::secondary)
-                        .on_press(AppMsg::ClipboardCopy(reservation.token.clone())),
-                    fl!("clipboard-copy-tooltip")
-                )
+                column![text(format::duration(status.elapsed)), remaining].align_x(Alignment::End),
+                buttons,
             ]
-            .align_y(Alignment::Center)
             .spacing(6)
+            .align_y(Alignment::Center)
         ),
-        rule::horizontal(1),
-        view_list_row(
-            text(fl!("labgrid-reservation-prio-label") + " : "),
-            text(reservation.prio.to_string())
-        ),
-        rule::horizontal(1),
-        view_list_row(
-            text(fl!("labgrid-reservation-filters-label") + " : "),
-            text(format!("{:?}", reservation.filters))
-        ),
-        view_list_row(
-            view_empty(),
-            button(text(fl!("labgrid-reservation-cancel-label")))
-                .style(button::danger)
-                .on_press(AppMsg::ConnectionMsg(ConnectionMsg::CancelReservation {
-                    token: reservation.token.clone()
-                }))
-        ),
-    ])
-    .style(card_container_style)
-    // Must be a fixed width for predictable layout and to avoid panic when using space::horizontal
-    .width(320)
-    .padding(6)
+    ]
+    .spacing(6)
     .into()
 }
 
-/// View for a single resource.
+/// Button to acquire the place, or release it if it is acquired.
 ///
-/// `ui` holds state about the resource UI, e.g. whether details about the resource should be shown
-pub(crate) fn view_resource<'a>(resource: &'a Resource, ui: &'a ResourceUi) -> Element<'a, AppMsg> {
-    let resource_path_str = format!(
-        "{}/{}/{}[/{}]",
-        resource.path.exporter_name.clone().unwrap_or_default(),
-        resource.path.group_name,
-        resource.cls,
-        resource.path.resource_name
-    );
-    let copy_clipboard_msg = format!(
-        "{}/{}/{}/{}",
-        resource.path.exporter_name.clone().unwrap_or_default(),
-        resource.path.group_name,
-        resource.cls,
-        resource.path.resource_name
-    );
-    let copy_name_to_clipboard_button = view_text_tooltip(
-        button(bootstrap::copy())
-            .style(button::secondary)
-            .on_press(AppMsg::ClipboardCopy(copy_clipboard_msg)),
-        fl!("clipboard-copy-tooltip"),
-    );
-    let availability_widget = view_text_tooltip(
-        checkbox(resource.available),
-        fl!("labgrid-resource-availability-tooltip"),
-    );
-
-    if ui.show_details {
-        container(column![
-            view_list_row(
-                text(resource_path_str),
-                row![
-                    copy_name_to_clipboard_button,
-                    availability_widget,
-                    button(text(fl!("hide-details-button"))).on_press(AppMsg::Connected(
-                        ConnectedMsg::HideResourceDetails(resource.path.clone())
-                    ))
-                ]
-                .align_y(Alignment::Center)
-                .spacing(6)
-            ),
-            rule::horizontal(1),
-            view_list_row(
-                text(fl!("labgrid-resource-acquired-label") + " : "),
-                text(&resource.acquired)
-            ),
-            rule::horizontal(1),
-            // TODO: improve params view
-            view_list_row(
-                text(fl!("labgrid-resource-params-label") + " : "),
-                text(format!("{:?}", resource.params))
-            ),
-            rule::horizontal(1),
-            // TODO: improve extra view
-            view_list_row(
-                text(fl!("labgrid-resource-extra-label") + " : "),
-                text(format!("{:?}", resource.extra))
-            ),
-        ])
-        .style(card_container_style)
-        .into()
+/// Places acquired by others can only be force released.
+fn view_place_acquire_release_button(
+    place: &Place,
+    mine: bool,
+    padding: Padding,
+) -> Element<'_, AppMsg> {
+    if let Some(owner) = place.acquired.as_ref().filter(|_| !mine) {
+        view_force_release_button(place, owner, padding)
+    } else if place.acquired.is_some() {
+        button(text(fl!("labgrid-place-release-label")))
+            .on_press(AppMsg::ConnectionMsg(ConnectionMsg::ReleasePlace {
+                name: place.name.clone(),
+            }))
+            .style(button::danger)
+            .padding(padding)
+            .into()
+    } else {
+        row![
+            button(text(fl!("labgrid-place-acquire-button")))
+                .padding(padding)
+                .on_press(AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlace {
+                    name: place.name.clone(),
+                })),
+            view_text_tooltip(
+                button(bootstrap::chat_quote())
+                    .style(button::secondary)
+                    .padding(padding)
+                    .on_press(AppMsg::ShowModal(Box::new(Modal::AcquireWithReason {
+                        place_name: place.name.clone(),
+                    }))),
+                fl!("labgrid-place-acquire-with-reason-tooltip"),
+            ),
+        ]
+        .spacing(3)
+        .into()
+    }
+}
+
+/// Modal for acquiring the place with a reason that is displayed to other users.
+pub(crate) fn view_acquire_with_reason_modal<'a>(
+    place_name: &'a str,
+    reason_text: &'a str,
+) -> Element<'a, AppMsg> {
+    let acquire_msg = (!reason_text.trim().is_empty()).then(|| {
+        AppMsg::ConnectionMsg(ConnectionMsg::AcquirePlaceWithReason {
+            name: place_name.to_string(),
+            reason: reason_text.to_string(),
+        })
+        .hide_modal()
+    });
+    container(
+        column![
+            text(fl!(
+                "labgrid-place-acquire-with-reason-msg",
+                place = place_name
+            )),
+            text_input(
+                &fl!("labgrid-place-acquire-reason-placeholder"),
+                reason_text
+            )
+            .on_input(|text| AppMsg::Connected(ConnectedMsg::UpdateAcquireReasonText(text)))
+            .on_submit_maybe(acquire_msg.clone()),
+            row![
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!("labgrid-place-acquire-button"))).on_press_maybe(acquire_msg),
+            ]
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// Modal changing the displayed name of the script, an empty `name_text` resets it to the file name.
+pub(crate) fn view_script_display_name_modal<'a>(
+    script_name: &'a str,
+    name_text: &'a str,
+) -> Element<'a, AppMsg> {
+    let set_msg = AppMsg::SetScriptDisplayName {
+        name: script_name.to_string(),
+        display_name: name_text.to_string(),
+    }
+    .hide_modal();
+    container(
+        column![
+            text(fl!("script-display-name-msg", script = script_name)),
+            text_input(&fl!("script-display-name-placeholder"), name_text)
+                .on_input(|text| AppMsg::Connected(ConnectedMsg::UpdateScriptDisplayNameText(text)))
+                .on_submit(set_msg.clone()),
+            row![
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!("script-display-name-button"))).on_press(set_msg),
+            ]
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// Modal previewing the place cloned from another one out of `places`, with its name, aliases and match patterns
+/// editable.
+pub(crate) fn view_place_clone_modal<'a>(
+    place_clone: &'a PlaceClone,
+    places: &'a [(Place, PlaceUi)],
+) -> Element<'a, AppMsg> {
+    let problems = place_clone.problems(places.iter().map(|(p, _)| p));
+    let clone_msg = problems.is_empty().then(|| {
+        AppMsg::ConnectionMsg(ConnectionMsg::ClonePlace {
+            place_clone: place_clone.clone(),
+        })
+        .hide_modal()
+    });
+    let problem_texts = column(problems.iter().map(|problem| {
+        text(match problem {
+            CloneProblem::EmptyName => fl!("labgrid-place-clone-empty-name-msg"),
+            CloneProblem::NameTaken => fl!("labgrid-place-clone-name-taken-msg"),
+            CloneProblem::AliasTaken(alias) => {
+                fl!(
+                    "labgrid-place-clone-alias-taken-msg",
+                    alias = alias.as_str()
+                )
+            }
+            CloneProblem::InvalidPattern(pattern) => fl!(
+                "labgrid-place-clone-invalid-pattern-msg",
+                pattern = pattern.as_str()
+            ),
+        })
+        .style(text::danger)
+        .into()
+    }));
+    let tags_list = column(
+        place_clone
+            .tags
+            .iter()
+            .map(|(key, value)| view_list_row(text(key), text(value))),
+    );
+    let aliases_list = column(
+        place_clone
+            .aliases
+            .iter()
+            .enumerate()
+            .map(|(index, alias)| {
+                text_input("", alias)
+                    .on_input(move |alias| {
+                        AppMsg::Connected(ConnectedMsg::UpdatePlaceCloneAlias { index, alias })
+                    })
+                    .into()
+            }),
+    )
+    .spacing(3);
+    let matches_list = column(place_clone.matches.iter().enumerate().map(|(index, m)| {
+        row![
+            text_input("", &m.pattern).on_input(move |pattern| {
+                AppMsg::Connected(ConnectedMsg::UpdatePlaceCloneMatch { index, pattern })
+            }),
+            m.rename.as_ref().map(|rename| text(format!("-> {rename}")))
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center)
+        .into()
+    }))
+    .spacing(3);
+    let none_label = || text(fl!("labgrid-place-clone-none-label"));
+
+    container(
+        column![
+            text(fl!(
+                "labgrid-place-clone-msg",
+                place = place_clone.source.as_str()
+            )),
+            text_input(&fl!("labgrid-place-add-placeholder"), &place_clone.name)
+                .on_input(|name| AppMsg::Connected(ConnectedMsg::UpdatePlaceCloneName(name))),
+            scrollable(
+                column![
+                    view_heading(fl!("labgrid-place-tags-label")),
+                    if place_clone.tags.is_empty() {
+                        none_label().into()
+                    } else {
+                        Element::from(tags_list)
+                    },
+                    view_heading(fl!("labgrid-place-clone-aliases-label")),
+                    if place_clone.aliases.is_empty() {
+                        none_label().into()
+                    } else {
+                        Element::from(aliases_list)
+                    },
+                    view_heading(fl!("labgrid-place-resource-matches-header")),
+                    if place_clone.matches.is_empty() {
+                        none_label().into()
+                    } else {
+                        Element::from(matches_list)
+                    },
+                ]
+                .spacing(6)
+            )
+            .height(Length::Shrink),
+            problem_texts,
+            row![
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!("labgrid-place-clone-button"))).on_press_maybe(clone_msg),
+            ]
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// Dry run of restoring the snapshot of the `file` on the coordinator with the current `places`.
+///
+/// The steps are listed grouped by place, like a diff of the coordinator configuration.
+pub(crate) fn view_restore_places_modal<'a>(
+    file: &'a SnapshotFile,
+    places: &'a [(Place, PlaceUi)],
+) -> Element<'a, AppMsg> {
+    let steps = file.snapshot.restore_steps(places.iter().map(|(p, _)| p));
+    let restore_msg = (!steps.is_empty()).then(|| {
+        AppMsg::ConnectionMsg(ConnectionMsg::RestorePlaces {
+            steps: steps.clone(),
+        })
+        .hide_modal()
+    });
+    let mut steps_list = column![].spacing(3);
+    for (index, step) in steps.iter().enumerate() {
+        if index == 0 || steps[index - 1].place() != step.place() {
+            steps_list = steps_list.push(view_heading(step.place().to_string()));
+        }
+        steps_list = steps_list.push(view_restore_step(step));
+    }
+    let created = file.snapshot.created.map(|created| {
+        text(fl!(
+            "place-snapshot-taken-msg",
+            coordinator = file.snapshot.coordinator.as_str(),
+            created = format::date_time(created)
+        ))
+        .style(text::secondary)
+    });
+
+    container(
+        column![
+            text(fl!(
+                "place-snapshot-restore-msg",
+                file = file.path.display().to_string()
+            )),
+            created,
+            if steps.is_empty() {
+                Element::from(text(fl!("place-snapshot-up-to-date-msg")))
+            } else {
+                scrollable(steps_list).height(Length::Shrink).into()
+            },
+            row![
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!(
+                    "place-snapshot-apply-button",
+                    count = steps.len()
+                )))
+                .on_press_maybe(restore_msg),
+            ]
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// The differences of the current `places` with the snapshot of the `file`, grouped by place.
+///
+/// Every difference can be applied on its own, removing places and tags needs a confirmation.
+pub(crate) fn view_compare_places_modal<'a>(
+    file: &'a SnapshotFile,
+    places: &'a [(Place, PlaceUi)],
+) -> Element<'a, AppMsg> {
+    let diffs = file.snapshot.compare(places.iter().map(|(p, _)| p));
+    let mut diffs_list = column![].spacing(3);
+    for diff in &diffs {
+        diffs_list = diffs_list.push(view_heading(diff.place.clone()));
+        for difference in &diff.differences {
+            let apply_msg = AppMsg::ConnectionMsg(file.snapshot.apply_msg(&diff.place, difference));
+            let apply_msg = match difference {
+                PlaceDifference::ExtraPlace => AppMsg::ShowModal(Box::new(Modal::Confirmation {
+                    msg: fl!(
+                        "labgrid-place-delete-confirmation-msg",
+                        place = diff.place.as_str()
+                    ),
+                    confirm: apply_msg,
+                })),
+                PlaceDifference::ExtraTag { key, .. } => {
+                    AppMsg::ShowModal(Box::new(Modal::Confirmation {
+                        msg: fl!(
+                            "labgrid-place-delete-tag-confirmation-msg",
+                            tag = key.as_str()
+                        ),
+                        confirm: apply_msg,
+                    }))
+                }
+                _ => apply_msg,
+            };
+            diffs_list = diffs_list.push(
+                row![
+                    view_place_difference(difference),
+                    space::horizontal(),
+                    if difference.is_removal() {
+                        button(text(fl!("place-snapshot-diff-remove-button"))).style(button::danger)
+                    } else {
+                        button(text(fl!("place-snapshot-diff-apply-button")))
+                            .style(button::secondary)
+                    }
+                    .on_press(apply_msg),
+                ]
+                .spacing(6)
+                .align_y(Alignment::Center),
+            );
+        }
+    }
+
+    container(
+        column![
+            text(fl!(
+                "place-snapshot-compare-msg",
+                file = file.path.display().to_string()
+            )),
+            if diffs.is_empty() {
+                Element::from(text(fl!("place-snapshot-no-differences-msg")))
+            } else {
+                scrollable(diffs_list).height(Length::Shrink).into()
+            },
+            row![
+                button(text(fl!("place-snapshot-close-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!("place-snapshot-restore-all-button")))
+                    .on_press(AppMsg::ShowModal(Box::new(Modal::RestorePlaces))),
+            ]
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// A difference of a place with a snapshot as diff line, `+` for what only the snapshot contains,
+/// `-` for what only the coordinator contains and `~` for changed values.
+fn view_place_difference<'a>(difference: &PlaceDifference) -> Element<'a, AppMsg> {
+    let (sign, description) = match difference {
+        PlaceDifference::MissingPlace => ('+', fl!("place-snapshot-diff-missing-place")),
+        PlaceDifference::ExtraPlace => ('-', fl!("place-snapshot-diff-extra-place")),
+        PlaceDifference::MissingAlias(alias) => (
+            '+',
+            fl!("place-snapshot-diff-missing-alias", alias = alias.as_str()),
+        ),
+        PlaceDifference::ExtraAlias(alias) => (
+            '-',
+            fl!("place-snapshot-diff-extra-alias", alias = alias.as_str()),
+        ),
+        PlaceDifference::Comment { snapshot, current } => (
+            '~',
+            fl!(
+                "place-snapshot-diff-comment",
+                snapshot = snapshot.as_str(),
+                current = current.as_str()
+            ),
+        ),
+        PlaceDifference::Tag {
+            key,
+            snapshot,
+            current: None,
+        } => (
+            '+',
+            fl!(
+                "place-snapshot-diff-missing-tag",
+                key = key.as_str(),
+                value = snapshot.as_str()
+            ),
+        ),
+        PlaceDifference::Tag {
+            key,
+            snapshot,
+            current: Some(current),
+        } => (
+            '~',
+            fl!(
+                "place-snapshot-diff-changed-tag",
+                key = key.as_str(),
+                snapshot = snapshot.as_str(),
+                current = current.as_str()
+            ),
+        ),
+        PlaceDifference::ExtraTag { key, value } => (
+            '-',
+            fl!(
+                "place-snapshot-diff-extra-tag",
+                key = key.as_str(),
+                value = value.as_str()
+            ),
+        ),
+        PlaceDifference::MissingMatch(m) => (
+            '+',
+            fl!(
+                "place-snapshot-diff-missing-match",
+                pattern = match_label(&m.pattern, m.rename.as_deref())
+            ),
+        ),
+        PlaceDifference::ExtraMatch(m) => (
+            '-',
+            fl!(
+                "place-snapshot-diff-extra-match",
+                pattern = match_label(&m.pattern, m.rename.as_deref())
+            ),
+        ),
+    };
+    row![
+        text(sign.to_string()).style(match sign {
+            '+' => text::success,
+            '-' => text::danger,
+            _ => text::warning,
+        }),
+        text(description),
+    ]
+    .spacing(6)
+    .into()
+}
+
+/// The match pattern with the name resources are renamed to.
+fn match_label(pattern: &str, rename: Option<&str>) -> String {
+    match rename {
+        Some(rename) => format!("{pattern} -> {rename}"),
+        None => pattern.to_string(),
+    }
+}
+
+/// A step restoring a place snapshot as diff line, `+` for additions and `~` for changes.
+fn view_restore_step<'a>(step: &RestoreStep) -> Element<'a, AppMsg> {
+    let (added, description) = match step {
+        RestoreStep::AddPlace { place } => (
+            true,
+            fl!("place-snapshot-step-add-place", place = place.as_str()),
+        ),
+        RestoreStep::AddAlias { alias, .. } => (
+            true,
+            fl!("place-snapshot-step-add-alias", alias = alias.as_str()),
+        ),
+        RestoreStep::SetComment { comment, .. } => (
+            false,
+            fl!(
+                "place-snapshot-step-set-comment",
+                comment = comment.as_str()
+            ),
+        ),
+        RestoreStep::SetTag {
+            key,
+            value,
+            current: None,
+            ..
+        } => (
+            true,
+            fl!(
+                "place-snapshot-step-add-tag",
+                key = key.as_str(),
+                value = value.as_str()
+            ),
+        ),
+        RestoreStep::SetTag {
+            key,
+            value,
+            current: Some(current),
+            ..
+        } => (
+            false,
+            fl!(
+                "place-snapshot-step-change-tag",
+                key = key.as_str(),
+                value = value.as_str(),
+                current = current.as_str()
+            ),
+        ),
+        RestoreStep::AddMatch {
+            pattern, rename, ..
+        } => (
+            true,
+            fl!(
+                "place-snapshot-step-add-match",
+                pattern = match_label(pattern, rename.as_deref())
+            ),
+        ),
+    };
+    row![
+        text(if added { "+" } else { "~" }).style(if added {
+            text::success
+        } else {
+            text::warning
+        }),
+        text(description),
+    ]
+    .spacing(6)
+    .into()
+}
+
+/// Button that opens the confirmation for force releasing the place acquired by `owner`.
+fn view_force_release_button<'a>(
+    place: &'a Place,
+    owner: &'a str,
+    padding: Padding,
+) -> Element<'a, AppMsg> {
+    button(text(fl!("labgrid-place-force-release-button")))
+        .on_press(AppMsg::ShowModal(Box::new(Modal::ForceRelease {
+            place_name: place.name.clone(),
+            owner: owner.to_string(),
+        })))
+        .style(button::danger)
+        .padding(padding)
+        .into()
+}
+
+/// Modal confirming to force release the place acquired by `owner`.
+///
+/// The place name must be typed into `confirm_text` before the release can be confirmed.
+pub(crate) fn view_force_release_modal<'a>(
+    place_name: &'a str,
+    owner: &'a str,
+    confirm_text: &'a str,
+) -> Element<'a, AppMsg> {
+    let confirmed = confirm_text == place_name;
+    container(
+        column![
+            text(fl!(
+                "labgrid-place-force-release-confirmation-msg",
+                place = place_name,
+                owner = owner
+            )),
+            text_input(place_name, confirm_text).on_input(|text| AppMsg::Connected(
+                ConnectedMsg::UpdateForceReleaseConfirmText(text)
+            )),
+            row![
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!("labgrid-place-force-release-button")))
+                    .style(button::danger)
+                    .on_press_maybe(confirmed.then(|| {
+                        AppMsg::ConnectionMsg(ConnectionMsg::ForceReleasePlace {
+                            name: place_name.to_string(),
+                            from_user: owner.to_string(),
+                        })
+                        .hide_modal()
+                    })),
+            ]
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// View for the confirmation of cancelling the reservation of another user, requires typing the owner.
+pub(crate) fn view_cancel_foreign_reservation_modal<'a>(
+    token: &'a str,
+    owner: &'a str,
+    confirm_text: &'a str,
+) -> Element<'a, AppMsg> {
+    let confirmed = confirm_text == owner;
+    container(
+        column![
+            text(fl!(
+                "labgrid-reservation-cancel-foreign-confirmation-msg",
+                token = token,
+                owner = owner
+            )),
+            text_input(owner, confirm_text).on_input(|text| AppMsg::Connected(
+                ConnectedMsg::UpdateCancelReservationConfirmText(text)
+            )),
+            row![
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!("labgrid-reservation-cancel-foreign-button")))
+                    .style(button::danger)
+                    .on_press_maybe(confirmed.then(|| {
+                        AppMsg::ConnectionMsg(ConnectionMsg::CancelReservation {
+                            token: token.to_string(),
+                        })
+                        .hide_modal()
+                    })),
+            ]
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// View for when the place was created and last changed, relative to `now`.
+///
+/// The absolute times are shown on hover.
+pub(crate) fn view_place_timestamps(place: &Place, now: DateTime<Local>) -> Element<'_, AppMsg> {
+    let timestamp_row = |label: String, timestamp: f64| {
+        util::local_time(timestamp).map(|time| {
+            view_list_row(
+                text(label + " : "),
+                view_text_tooltip(text(format::time_ago(time, now)), format::date_time(time)),
+            )
+        })
+    };
+
+    column![
+        timestamp_row(fl!("labgrid-place-created-label"), place.created),
+        timestamp_row(fl!("labgrid-place-changed-label"), place.changed),
+    ]
+    .into()
+}
+
+/// View for a single reservation
+///
+/// `queue_estimate` is only present for waiting reservations.
+/// Reservations of other users, not `mine`, are only cancelled after a confirmation.
+/// The countdown until the reservation times out is relative to `now`.
+pub(crate) fn view_reservation(
+    reservation: &Reservation,
+    mine: bool,
+    queue_estimate: Option<QueueEstimate>,
+    now: DateTime<Local>,
+) -> Element<'_, AppMsg> {
+    let format_time = |timestamp: f64| {
+        util::local_time(timestamp)
+            .map_or_else(|| fl!("labgrid-reservation-time-unset"), format::date_time)
+    };
+    let countdown = util::local_time(reservation.timeout).map(|_| {
+        match queue::time_remaining(reservation, now) {
+            Some(remaining) => text(fl!(
+                "labgrid-reservation-expires-in",
+                remaining = format::duration(remaining)
+            )),
+            None => text(fl!("labgrid-reservation-timed-out")).style(text::danger),
+        }
+    });
+    let mut allocations = reservation.allocations.iter().collect::<Vec<_>>();
+    allocations.sort();
+    let allocations_row = (!allocations.is_empty()).then(|| {
+        column![
+            rule::horizontal(1),
+            view_list_row(
+                text(fl!("labgrid-reservation-allocations-label") + " : "),
+                column(allocations.into_iter().map(|(filter, place_name)| {
+                    row![
+                        text(format!("{filter} : {place_name}")),
+                        space::horizontal(),
+                        button(text(fl!("labgrid-reservation-goto-place-button")))
+                            .style(button::secondary)
+                            .on_press(AppMsg::ShowModal(Box::new(Modal::PlaceDetails {
+                                place_name: place_name.clone(),
+                            }))),
+                    ]
+                    .align_y(Alignment::Center)
+                    .spacing(6)
+                    .into()
+                }))
+                .spacing(3)
+            ),
+        ]
+        .spacing(6)
+    });
+    let queue_row = queue_estimate.map(|estimate| {
+        let expected_wait = match estimate.expected_wait {
+            Some(wait) => fl!(
+                "labgrid-reservation-queue-expected-wait",
+                minutes = wait.as_secs().div_ceil(60)
+            ),
+            None => fl!("labgrid-reservation-queue-expected-wait-unknown"),
+        };
+        column![
+            rule::horizontal(1),
+            view_list_row(
+                text(fl!("labgrid-reservation-queue-label") + " : "),
+                column![
+                    text(fl!(
+                        "labgrid-reservation-queue-position",
+                        position = estimate.position
+                    )),
+                    text(fl!(
+                        "labgrid-reservation-queue-free-places",
+                        free = estimate.free_places,
+                        matching = estimate.matching_places
+                    )),
+                    text(expected_wait),
+                ]
+                .spacing(3)
+            ),
+        ]
+        .spacing(6)
+    });
+
+    let mine_badge = mine.then(|| {
+        row![
+            space::horizontal(),
+            text(fl!("labgrid-reservation-mine-badge")).style(text::primary)
+        ]
+    });
+    let cancel_msg = if mine {
+        AppMsg::ConnectionMsg(ConnectionMsg::CancelReservation {
+            token: reservation.token.clone(),
+        })
+    } else {
+        AppMsg::ShowModal(Box::new(Modal::CancelForeignReservation {
+            token: reservation.token.clone(),
+            owner: reservation.owner.clone(),
+        }))
+    };
+
+    container(column![
+        mine_badge,
+        view_list_row(
+            text(fl!("labgrid-reservation-owner-label") + " : "),
+            text(&reservation.owner)
+        ),
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-reservation-token-label") + " : "),
+            row![
+                text(&reservation.token),
+                view_text_tooltip(
+                    button(bootstrap::copy())
+                        .style(button::secondary)
+                        .on_press(AppMsg::ClipboardCopy(reservation.token.clone())),
+                    fl!("clipboard-copy-tooltip")
+                )
+            ]
+            .align_y(Alignment::Center)
+            .spacing(6)
+        ),
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-reservation-prio-label") + " : "),
+            text(format::decimal(reservation.prio))
+        ),
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-reservation-filters-label") + " : "),
+            text(format!("{:?}", reservation.filters))
+        ),
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-reservation-created-label") + " : "),
+            text(format_time(reservation.created))
+        ),
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-reservation-expires-label") + " : "),
+            column![text(format_time(reservation.timeout)), countdown].spacing(3)
+        ),
+        allocations_row,
+        queue_row,
+        view_list_row(
+            view_empty(),
+            button(text(fl!("labgrid-reservation-cancel-label")))
+                .style(button::danger)
+                .on_press(cancel_msg)
+        ),
+    ])
+    .style(card_container_style)
+    // Must be a fixed width for predictable layout and to avoid panic when using space::horizontal
+    .width(320)
+    .padding(6)
+    .into()
+}
+
+/// View for a single resource.
+///
+/// `ui` holds state about the resource UI, e.g. whether details about the resource should be shown.
+/// The recent acquisitions of the resource are looked up in `acquire_history`,
+/// its icon and quick action in the user mappings of the resource `classes`.
+/// An acquired resource is accented with `owner_color`, the color of the owner of the acquiring place.
+/// `watched` is whether its availability changes are notified.
+/// Its quick connect command can be launched in a terminal emulator if `launch_terminal` is set.
+pub(crate) fn view_resource<'a>(
+    resource: &'a Resource,
+    ui: &'a ResourceUi,
+    acquire_history: &'a AcquireHistory,
+    classes: &'a ResourceClassRegistry,
+    owner_color: Option<Color>,
+    watched: bool,
+    launch_terminal: bool,
+) -> Element<'a, AppMsg> {
+    let resource_pattern = MatchPattern::for_resource(&resource.path, &resource.cls).to_string();
+    let class_icon = classes.icon(&resource.cls);
+    let class_button = view_text_tooltip(
+        button(
+            class_icon
+                .map(|icon| icon.icon())
+                .unwrap_or_else(bootstrap::question_circle),
+        )
+        .style(button::text)
+        .on_press(AppMsg::ShowModal(Box::new(Modal::ResourceClass {
+            cls: resource.cls.clone(),
+        }))),
+        if class_icon.is_some() {
+            fl!(
+                "labgrid-resource-class-tooltip",
+                cls = resource.cls.as_str()
+            )
+        } else {
+            fl!(
+                "labgrid-resource-class-unknown-tooltip",
+                cls = resource.cls.as_str()
+            )
+        },
+    );
+    let header = row![class_button, text(resource_pattern.clone())]
+        .align_y(Alignment::Center)
+        .spacing(6);
+    let copy_param_button = classes
+        .get(&resource.cls)
+        .and_then(|mapping| mapping.copy_param.as_ref())
+        .and_then(|param| Some((param, resource.params.get(param)?)))
+        .map(|(param, value)| {
+            view_text_tooltip(
+                button(text(param))
+                    .style(button::secondary)
+                    .on_press(AppMsg::ClipboardCopy(format_map_value(value))),
+                fl!(
+                    "labgrid-resource-copy-param-tooltip",
+                    param = param.as_str()
+                ),
+            )
+        });
+    let quick_connect = QuickConnect::for_resource(resource);
+    let copy_command_button = quick_connect.as_ref().map(|command| {
+        view_text_tooltip(
+            button(bootstrap::terminal())
+                .style(button::secondary)
+                .on_press(AppMsg::ClipboardCopy(command.command_line())),
+            fl!(
+                "labgrid-resource-copy-command-tooltip",
+                program = command.program
+            ),
+        )
+    });
+    let launch_command_button = quick_connect.filter(|_| launch_terminal).map(|command| {
+        view_text_tooltip(
+            button(bootstrap::box_arrow_up_right())
+                .style(button::secondary)
+                .on_press(AppMsg::LaunchQuickConnect(command.clone())),
+            fl!(
+                "labgrid-resource-launch-command-tooltip",
+                program = command.program
+            ),
+        )
+    });
+    let copy_clipboard_msg = resource_pattern.clone();
+    let copy_name_to_clipboard_button = view_text_tooltip(
+        button(bootstrap::copy())
+            .style(button::secondary)
+            .on_press(AppMsg::ClipboardCopy(copy_clipboard_msg)),
+        fl!("clipboard-copy-tooltip"),
+    );
+    let assign_button = view_text_tooltip(
+        button(bootstrap::plus())
+            .style(button::secondary)
+            .on_press(AppMsg::ShowModal(Box::new(Modal::AssignResource {
+                path: resource.path.clone(),
+                cls: resource.cls.clone(),
+            }))),
+        fl!("labgrid-resource-assign-tooltip"),
+    );
+    let watch_button = view_text_tooltip(
+        button(if watched {
+            bootstrap::bell_fill()
+        } else {
+            bootstrap::bell()
+        })
+        .style(button::secondary)
+        .on_press(AppMsg::ToggleWatchResource(resource.path.clone())),
+        if watched {
+            fl!("labgrid-resource-unwatch-tooltip")
+        } else {
+            fl!("labgrid-resource-watch-tooltip")
+        },
+    );
+    let availability_widget = view_text_tooltip(
+        checkbox(resource.available),
+        fl!("labgrid-resource-availability-tooltip"),
+    );
+    let acquire_records = acquire_history
+        .get(&resource.path)
+        .map(|r| {
+            fl!(
+                "labgrid-resource-acquire-record",
+                acquired_by = r.acquired_by.as_str(),
+                time = format::date_time(r.time)
+            )
+        })
+        .collect::<Vec<String>>();
+    let stale_widget: Element<'_, AppMsg> = if ui.stale_since.is_some() {
+        view_text_tooltip(
+            text(fl!("labgrid-resource-stale-label")).style(text::warning),
+            fl!("labgrid-resource-stale-tooltip"),
+        )
+        .into()
+    } else {
+        view_empty()
+    };
+    let acquire_history_widget: Element<'_, AppMsg> = if acquire_records.is_empty() {
+        view_empty()
+    } else {
+        view_text_tooltip(
+            bootstrap::clock_history(),
+            fl!("labgrid-resource-acquire-history-label") + ":\n" + &acquire_records.join("\n"),
+        )
+        .into()
+    };
+
+    if ui.show_details {
+        let camera_preview =
+            camera::is_camera(&resource.cls).then(|| view_camera_preview(resource, ui));
+        container(column![
+            view_list_row(
+                header,
+                row![
+                    stale_widget,
+                    acquire_history_widget,
+                    copy_command_button,
+                    launch_command_button,
+                    copy_param_button,
+                    copy_name_to_clipboard_button,
+                    assign_button,
+                    watch_button,
+                    availability_widget,
+                    button(text(fl!("hide-details-button"))).on_press(AppMsg::Connected(
+                        ConnectedMsg::HideResourceDetails(resource.path.clone())
+                    ))
+                ]
+                .align_y(Alignment::Center)
+                .spacing(6)
+            ),
+            rule::horizontal(1),
+            view_list_row(
+                text(fl!("labgrid-resource-acquired-label") + " : "),
+                text(&resource.acquired).color_maybe(owner_color)
+            ),
+            rule::horizontal(1),
+            view_list_row(
+                text(fl!("labgrid-resource-acquire-history-label") + " : "),
+                if acquire_records.is_empty() {
+                    text("-")
+                } else {
+                    text(acquire_records.join("\n"))
+                }
+            ),
+            camera_preview,
+            rule::horizontal(1),
+            view_list_row(
+                text(fl!("labgrid-resource-params-label") + " : "),
+                NONE_ELEMENT
+            ),
+            view_map_values(&resource.params),
+            rule::horizontal(1),
+            view_list_row(
+                text(fl!("labgrid-resource-extra-label") + " : "),
+                NONE_ELEMENT
+            ),
+            view_map_values(&resource.extra),
+        ])
+        .style(resource_card_style(owner_color))
+        .into()
     } else {
         container(view_list_row(
-            text(resource_path_str),
+            header,
             row![
+                stale_widget,
+                acquire_history_widget,
+                copy_command_button,
+                launch_command_button,
+                copy_param_button,
                 copy_name_to_clipboard_button,
+                assign_button,
+                watch_button,
                 availability_widget,
                 button(text(fl!("show-details-button")))
                     .style(button::secondary)
@@ -724,11 +3230,143 @@ pub(crate) fn view_resource<'a>(resource: &'a Resource, ui: &'a ResourceUi) -> E
             .align_y(Alignment::Center)
             .spacing(6),
         ))
-        .style(card_container_style)
+        .style(resource_card_style(owner_color))
         .into()
     }
 }
 
+/// View for the snapshot of a camera resource in the resource details.
+fn view_camera_preview<'a>(resource: &'a Resource, ui: &'a ResourceUi) -> Element<'a, AppMsg> {
+    let url = camera::snapshot_url(resource);
+    let refresh_button = url.map(|_| {
+        button(text(fl!("labgrid-camera-preview-refresh-button")))
+            .style(button::secondary)
+            .on_press(AppMsg::Connected(ConnectedMsg::RefreshCameraPreview(
+                resource.path.clone(),
+            )))
+    });
+    let preview: Element<'a, AppMsg> = match (url, &ui.camera_preview) {
+        (None, _) => text(fl!("labgrid-camera-preview-no-url")).into(),
+        (Some(_), None) => view_empty(),
+        (Some(_), Some(CameraPreview::Loading)) => {
+            text(fl!("labgrid-camera-preview-loading")).into()
+        }
+        (Some(_), Some(CameraPreview::Loaded(handle))) => image(handle.clone()).height(240).into(),
+        (Some(_), Some(CameraPreview::Failed(error))) => {
+            text(fl!("labgrid-camera-preview-failed", error = error.as_str()))
+                .style(text::danger)
+                .into()
+        }
+    };
+
+    column![
+        rule::horizontal(1),
+        view_list_row(
+            text(fl!("labgrid-camera-preview-label") + " : "),
+            refresh_button
+        ),
+        container(preview).padding(padding::horizontal(6).bottom(6)),
+    ]
+    .into()
+}
+
+/// The style of a resource card, accented with the color of the owner if it is acquired.
+fn resource_card_style(owner_color: Option<Color>) -> impl Fn(&iced::Theme) -> container::Style {
+    move |theme| match owner_color {
+        Some(color) => colored_card_container_style(color)(theme),
+        None => card_container_style(theme),
+    }
+}
+
+/// Grays out the text of the resource cards of offline exporters.
+fn offline_resource_style(theme: &iced::Theme) -> container::Style {
+    container::Style {
+        text_color: Some(theme.extended_palette().background.strong.color),
+        ..container::Style::default()
+    }
+}
+
+/// Formats a map value for display.
+///
+/// Floats always contain a decimal point and array elements are separated by commas.
+pub(crate) fn format_map_value(value: &MapValue) -> String {
+    match value {
+        MapValue::Bool(val) => val.to_string(),
+        MapValue::Int(val) => val.to_string(),
+        MapValue::UInt(val) => val.to_string(),
+        MapValue::Float(val) => format!("{val:?}"),
+        MapValue::String(val) => val.clone(),
+        MapValue::Array(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(format_map_value)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// View for the key/value table of resource params or extras, sorted by key.
+pub(crate) fn view_map_values<'a>(values: &'a HashMap<String, MapValue>) -> Element<'a, AppMsg> {
+    if values.is_empty() {
+        return container(text("-")).padding(padding::left(18)).into();
+    }
+    let mut entries = values.iter().collect::<Vec<(&String, &MapValue)>>();
+    entries.sort_by(|first, second| numeric_sort::cmp(first.0, second.0));
+
+    container(
+        column(
+            entries
+                .into_iter()
+                .map(|(key, value)| view_map_value(key.clone(), value)),
+        )
+        .spacing(2),
+    )
+    .padding(padding::left(18))
+    .into()
+}
+
+/// View for a single key/value row with a button to copy the value.
+///
+/// The elements of array values are additionally listed below, indented and labeled by their index.
+fn view_map_value<'a>(key: String, value: &'a MapValue) -> Element<'a, AppMsg> {
+    let value_str = format_map_value(value);
+    let value_row = view_list_row(
+        text(key),
+        row![
+            text(value_str.clone()),
+            view_text_tooltip(
+                button(bootstrap::copy())
+                    .padding(2)
+                    .style(button::secondary)
+                    .on_press(AppMsg::ClipboardCopy(value_str)),
+                fl!("clipboard-copy-tooltip")
+            )
+        ]
+        .align_y(Alignment::Center)
+        .spacing(6),
+    );
+
+    match value {
+        MapValue::Array(values) if !values.is_empty() => column![
+            value_row,
+            container(
+                column(
+                    values
+                        .iter()
+                        .enumerate()
+                        .map(|(i, value)| view_map_value(format!("[{i}]"), value))
+                )
+                .spacing(2)
+            )
+            .padding(padding::left(18))
+        ]
+        .into(),
+        _ => value_row,
+    }
+}
+
 /// View for a single place tag.
 pub(crate) fn view_tag<'a>(place_name: &'a str, tag: (&'a str, &'a str)) -> Element<'a, AppMsg> {
     container(
@@ -764,82 +3402,282 @@ pub(crate) fn view_resource_match<'a>(
     place: &'a Place,
     resource_match: &'a ResourceMatch,
 ) -> Element<'a, AppMsg> {
-    let (match_pattern, match_display) = if let Some(name) = &resource_match.name {
-        (
-            format!(
-                "{}/{}/{}/{}",
-                resource_match.exporter, resource_match.group, resource_match.cls, name
+    let match_pattern = resource_match.pattern().to_string();
+    let renamed = resource_match.rename.as_ref().map(|rename| {
+        view_text_tooltip(
+            container(text(rename))
+                .style(container::primary)
+                .padding([0, 6]),
+            fl!("labgrid-place-resource-match-renamed-tooltip"),
+        )
+    });
+    container(view_list_row(
+        row![text(match_pattern.clone()), renamed]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        row![
+            view_text_tooltip(
+                button(bootstrap::copy())
+                    .style(button::secondary)
+                    .on_press(AppMsg::ClipboardCopy(match_pattern.clone())),
+                fl!("clipboard-copy-tooltip")
             ),
-            format!(
-                "{}/{}/{}/[{}]",
-                resource_match.exporter, resource_match.group, resource_match.cls, name
+            button(text(fl!("labgrid-place-resource-match-delete-button")))
+                .style(button::danger)
+                .on_press(AppMsg::ConnectionMsg(ConnectionMsg::DeletePlaceMatch {
+                    place_name: place.name.clone(),
+                    pattern: match_pattern,
+                    rename: resource_match.rename.clone(),
+                },))
+        ]
+        .spacing(6),
+    ))
+    .style(card_container_style)
+    .into()
+}
+
+/// View for an alias of a place.
+pub(crate) fn view_place_alias<'a>(place: &'a Place, alias: &'a str) -> Element<'a, AppMsg> {
+    container(view_list_row(
+        text(alias),
+        row![
+            view_text_tooltip(
+                button(bootstrap::copy())
+                    .style(button::secondary)
+                    .on_press(AppMsg::ClipboardCopy(alias.to_string())),
+                fl!("clipboard-copy-tooltip")
             ),
-        )
+            button(text(fl!("labgrid-place-alias-delete-button")))
+                .style(button::danger)
+                .on_press(AppMsg::ConnectionMsg(ConnectionMsg::DeletePlaceAlias {
+                    place_name: place.name.clone(),
+                    alias: alias.to_string(),
+                }))
+        ]
+        .spacing(6),
+    ))
+    .style(card_container_style)
+    .into()
+}
+
+/// View for a acquired resource in a place as reported by labgrid's client out stream.
+///
+/// Shows the exporter the resource was grabbed from and highlights a recent acquire or release,
+/// fading out over the highlight duration.
+pub(crate) fn view_acquired_resource(
+    acquired_resource: String,
+    change: Option<&AcquiredResourceChange>,
+    now: Instant,
+) -> Element<'static, AppMsg> {
+    let description: Element<'static, AppMsg> =
+        match acquired_resources::parse_acquired_resource(&acquired_resource) {
+            Some(pattern) => column![
+                text(pattern.name.unwrap_or_default()),
+                text(fl!(
+                    "labgrid-place-acquired-resource-origin",
+                    exporter = pattern.exporter,
+                    group = pattern.group,
+                    cls = pattern.cls
+                ))
+                .size(12),
+            ]
+            .into(),
+            None => text(acquired_resource.clone()).into(),
+        };
+    let change_icon = change.map(|change| {
+        if change.acquired {
+            view_text_tooltip(
+                bootstrap::plus_circle(),
+                fl!("labgrid-place-acquired-resource-acquired-tooltip"),
+            )
+        } else {
+            view_text_tooltip(
+                bootstrap::dash_circle(),
+                fl!("labgrid-place-acquired-resource-released-tooltip"),
+            )
+        }
+    });
+    let highlight = change.map(|change| (change.acquired, change.progress(now)));
+
+    container(view_list_row(
+        row![change_icon, description]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        view_text_tooltip(
+            button(bootstrap::copy())
+                .style(button::secondary)
+                .on_press(AppMsg::ClipboardCopy(acquired_resource)),
+            fl!("clipboard-copy-tooltip"),
+        ),
+    ))
+    .style(move |theme| {
+        let mut s = card_container_style(theme);
+        if let Some((acquired, progress)) = highlight {
+            let palette = theme.extended_palette();
+            let pair = if acquired {
+                palette.success.weak
+            } else {
+                palette.danger.weak
+            };
+            s.background = Some(pair.color.scale_alpha(1. - progress).into());
+            s.text_color = Some(pair.text);
+        }
+        s
+    })
+    .into()
+}
+
+/// View for the resources acquired by a place, including recently released ones that are still highlighted.
+pub(crate) fn view_acquired_resources<'a>(
+    place: &'a Place,
+    ui: &'a PlaceUi,
+) -> Element<'a, AppMsg> {
+    let now = Instant::now();
+    let changes = &ui.acquired_resource_changes;
+    let acquired = place
+        .acquired_resources
+        .iter()
+        .map(|r| view_acquired_resource(r.to_owned(), changes.get(r), now));
+    let released = changes
+        .released()
+        .filter(|c| !place.acquired_resources.contains(&c.resource))
+        .map(|c| view_acquired_resource(c.resource.clone(), Some(c), now));
+
+    column(acquired.chain(released))
+        .spacing(6)
+        .padding(6)
+        .into()
+}
+
+/// View for building a match pattern component by component and adding it to the place.
+///
+/// Shows how many of the known `resources` the pattern matches, or why it is invalid.
+fn view_match_builder<'a>(
+    place: &'a Place,
+    builder: &'a MatchBuilder,
+    rename_text: &'a str,
+    resources: &'a [(Resource, ResourceUi)],
+) -> Element<'a, AppMsg> {
+    let component_input = |component: MatchComponent, placeholder: String| {
+        text_input(&placeholder, builder.get(component)).on_input(move |value| {
+            AppMsg::Connected(ConnectedMsg::UpdateAddPlaceMatchComponent { component, value })
+        })
+    };
+    let pattern = builder.pattern();
+    let validation: Option<Element<'a, AppMsg>> = if builder.is_empty() {
+        None
     } else {
-        (
-            format!(
-                "{}/{}/{}",
-                resource_match.exporter, resource_match.group, resource_match.cls
+        match &pattern {
+            Ok(_) => Some(
+                text(fl!(
+                    "labgrid-place-resource-match-builder-matching",
+                    count = builder.matching_resources(resources).unwrap_or_default()
+                ))
+                .into(),
+            ),
+            Err(e) => Some(text(e.to_string()).style(text::danger).into()),
+        }
+    };
+    let add_msg = pattern.ok().map(|pattern| {
+        AppMsg::ConnectionMsg(ConnectionMsg::AddPlaceMatch {
+            place_name: place.name.clone(),
+            pattern: pattern.to_string(),
+            rename: (!rename_text.trim().is_empty()).then(|| rename_text.to_string()),
+        })
+    });
+
+    column![
+        row![
+            component_input(
+                MatchComponent::Exporter,
+                fl!("labgrid-place-resource-match-builder-exporter")
             ),
-            format!(
-                "{}/{}/{}",
-                resource_match.exporter, resource_match.group, resource_match.cls
+            text("/"),
+            component_input(
+                MatchComponent::Group,
+                fl!("labgrid-place-resource-match-builder-group")
             ),
-        )
-    };
-    container(view_list_row(
-        text(match_display),
+            text("/"),
+            component_input(
+                MatchComponent::Cls,
+                fl!("labgrid-place-resource-match-builder-cls")
+            ),
+            text("/"),
+            component_input(
+                MatchComponent::Name,
+                fl!("labgrid-place-resource-match-builder-name")
+            ),
+        ]
+        .spacing(3)
+        .align_y(Alignment::Center),
         row![
+            validation,
+            space::horizontal(),
             view_text_tooltip(
-                button(bootstrap::copy())
-                    .style(button::secondary)
-                    .on_press(AppMsg::ClipboardCopy(match_pattern.clone())),
-                fl!("clipboard-copy-tooltip")
+                text_input(
+                    fl!("labgrid-place-resource-match-rename-placeholder-text").as_str(),
+                    rename_text
+                )
+                .on_input(|text| AppMsg::Connected(ConnectedMsg::UpdateAddPlaceMatchRename(text)))
+                .width(160),
+                fl!("labgrid-place-resource-match-rename-tooltip")
             ),
-            button(text(fl!("labgrid-place-resource-match-delete-button")))
-                .style(button::danger)
-                .on_press(AppMsg::ConnectionMsg(ConnectionMsg::DeletePlaceMatch {
-                    place_name: place.name.clone(),
-                    pattern: match_pattern,
-                },))
+            button(text(fl!("labgrid-place-resource-match-add-button"))).on_press_maybe(add_msg)
         ]
-        .spacing(6),
-    ))
-    .style(card_container_style)
-    .into()
-}
-
-/// View for a acquired resource in a place as reported by labgrid's client out stream
-pub(crate) fn view_acquired_resource(acquired_resource: String) -> Element<'static, AppMsg> {
-    container(view_list_row(
-        text(acquired_resource.clone()),
-        view_text_tooltip(
-            button(bootstrap::copy())
-                .style(button::secondary)
-                .on_press(AppMsg::ClipboardCopy(acquired_resource)),
-            fl!("clipboard-copy-tooltip"),
-        ),
-    ))
-    .style(card_container_style)
+        .spacing(6)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(6)
+    .padding(6)
     .into()
 }
 
 /// View for the place details modal that gets displayed when the place UI state `show_details` is set.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn view_place_details<'a>(
     place: &'a Place,
     ui: &'a PlaceUi,
     optimize_touch: bool,
-    add_place_match_text: &'a str,
+    add_place_match: &'a MatchBuilder,
+    add_place_match_rename_text: &'a str,
+    resources: &'a [(Resource, ResourceUi)],
+    add_place_alias_text: &'a str,
+    notice: Option<&'a str>,
+    place_activity: &'a PlaceActivityLog,
+    owner_color: Option<Color>,
 ) -> Element<'a, AppMsg> {
     let place_name = &place.name;
+    let activity_list = column(place_activity.get(place_name).map(|activity| {
+        view_list_row(
+            text(format::date_time(activity.time)),
+            text(activity.kind.to_string()),
+        )
+    }))
+    .spacing(6)
+    .padding(6);
+    let notice_banner = notice.map(|notice| {
+        container(
+            row![
+                text(notice),
+                space::horizontal(),
+                button(bootstrap::x())
+                    .style(button::text)
+                    .on_press(AppMsg::Connected(ConnectedMsg::DismissPlaceDetailsNotice)),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        )
+        .style(container::warning)
+        .padding(6)
+    });
     let resource_matches_list = column(place.matches.iter().map(|m| view_resource_match(place, m)))
         .spacing(6)
         .padding(6);
-    let resources_acquired_list = column(
+    let aliases_list = column(
         place
-            .acquired_resources
+            .aliases
             .iter()
-            .map(|n| view_acquired_resource(n.to_owned())),
+            .map(|alias| view_place_alias(place, alias)),
     )
     .spacing(6)
     .padding(6);
@@ -849,13 +3687,56 @@ pub(crate) fn view_place_details<'a>(
             row![
                 text(fl!("labgrid-place-details-header", place = place_name)).size(24),
                 space::horizontal(),
+                view_text_tooltip(
+                    button(text(fl!("labgrid-place-clone-button")))
+                        .style(button::secondary)
+                        .on_press(AppMsg::ShowModal(Box::new(Modal::ClonePlace {
+                            place_name: place_name.clone()
+                        }))),
+                    fl!("labgrid-place-clone-tooltip")
+                ),
                 button(bootstrap::x()).on_press(AppMsg::HideModal)
             ],
+            notice_banner,
             scrollable(
                 column![
-                    container(view_place_general_info(place, ui))
-                        .style(card_container_style)
-                        .padding(6),
+                    container(column![
+                        view_place_general_info(place, ui, true, owner_color),
+                        view_place_timestamps(place, Local::now()),
+                    ])
+                    .style(card_container_style)
+                    .padding(6),
+                    view_section(
+                        fl!("labgrid-place-aliases-header"),
+                        Some(
+                            row![
+                                text_input(
+                                    fl!("labgrid-place-alias-add-placeholder-text").as_str(),
+                                    add_place_alias_text
+                                )
+                                .on_input(
+                                    |text| AppMsg::Connected(ConnectedMsg::UpdateAddPlaceAlias(
+                                        text
+                                    ))
+                                ),
+                                view_text_tooltip(
+                                    button(bootstrap::backspace()).on_press(AppMsg::Connected(
+                                        ConnectedMsg::UpdateAddPlaceAlias(String::new())
+                                    )),
+                                    fl!("text-input-clear-tooltip")
+                                ),
+                                Space::new().width(6),
+                                button(text(fl!("labgrid-place-alias-add-button"))).on_press(
+                                    AppMsg::ConnectionMsg(ConnectionMsg::AddPlaceAlias {
+                                        place_name: place.name.clone(),
+                                        alias: add_place_alias_text.to_string()
+                                    })
+                                )
+                            ]
+                            .spacing(1)
+                        ),
+                        aliases_list,
+                    ),
                     view_section(
                         fl!("labgrid-place-resource-matches-header"),
                         Some(
@@ -866,39 +3747,39 @@ pub(crate) fn view_place_details<'a>(
                                     )),
                                     fl!("clipboard-paste-tooltip")
                                 ),
-                                text_input(
-                                    fl!("labgrid-place-resource-match-add-placeholder-text")
-                                        .as_str(),
-                                    add_place_match_text
-                                )
-                                .on_input(
-                                    |text| AppMsg::Connected(
-                                        ConnectedMsg::UpdateAddPlaceMatchPattern(text)
-                                    )
-                                ),
                                 view_text_tooltip(
                                     button(bootstrap::backspace()).on_press(AppMsg::Connected(
                                         ConnectedMsg::UpdateAddPlaceMatchPattern(String::new())
                                     )),
                                     fl!("text-input-clear-tooltip")
                                 ),
-                                Space::new().width(6),
-                                button(text(fl!("labgrid-place-resource-match-add-button")))
-                                    .on_press(AppMsg::ConnectionMsg(
-                                        ConnectionMsg::AddPlaceMatch {
-                                            place_name: place.name.clone(),
-                                            pattern: add_place_match_text.to_string()
-                                        }
-                                    ))
                             ]
                             .spacing(1)
                         ),
-                        resource_matches_list,
+                        column![
+                            view_match_builder(
+                                place,
+                                add_place_match,
+                                add_place_match_rename_text,
+                                resources
+                            ),
+                            resource_matches_list
+                        ]
+                        .spacing(6),
                     ),
                     view_section(
                         fl!("labgrid-place-resource-acquired-header"),
                         NONE_ELEMENT,
-                        resources_acquired_list,
+                        view_acquired_resources(place, ui),
+                    ),
+                    view_section(
+                        fl!("place-activity-header"),
+                        Some(button(text(fl!("place-activity-export-button"))).on_press(
+                            AppMsg::ExportPlaceActivity {
+                                place_name: place_name.clone()
+                            }
+                        )),
+                        activity_list,
                     )
                 ]
                 .spacing(12)
@@ -908,16 +3789,555 @@ pub(crate) fn view_place_details<'a>(
         .spacing(12),
     )
     .style(modal_container_style)
-    .max_width(UI_MAX_WIDTH)
+    .max_width(UI_MAX_WIDTH)
+    .padding(12)
+    .into()
+}
+
+/// View for the modal that assigns a resource to a place by adding a match for it.
+///
+/// The match pattern is derived from the resource path, places which already have it are not selectable.
+pub(crate) fn view_assign_resource<'a>(
+    places: &'a [(Place, PlaceUi)],
+    path: &'a Path,
+    cls: &'a str,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let pattern = MatchPattern::for_resource(path, cls);
+    let places_list = column(places.iter().map(|(place, _)| {
+        let already_assigned = place.matches.iter().any(|m| m.pattern() == pattern);
+        container(view_list_row(
+            text(&place.name),
+            button(text(fl!("labgrid-resource-assign-button"))).on_press_maybe(
+                (!already_assigned).then(|| {
+                    AppMsg::ConnectionMsg(ConnectionMsg::AddPlaceMatch {
+                        place_name: place.name.clone(),
+                        pattern: pattern.to_string(),
+                        rename: None,
+                    })
+                    .hide_modal()
+                }),
+            ),
+        ))
+        .style(card_container_style)
+        .into()
+    }))
+    .spacing(6);
+
+    container(
+        column![
+            row![
+                text(fl!("labgrid-resource-assign-header")).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal)
+            ],
+            view_list_row(
+                text(fl!("labgrid-resource-assign-pattern-label") + " : "),
+                text(pattern.to_string())
+            ),
+            scrollable(places_list)
+                .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+                .height(Length::Shrink)
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// View for the modal of a resource class, showing the parameters observed on its resources
+/// and letting the user map it to an icon and a parameter copied by the quick action button.
+///
+/// Intended for classes which are unknown to the UI, but the built-in icons of known classes can be overridden as well.
+pub(crate) fn view_resource_class<'a>(
+    cls: &'a str,
+    schema: ClassSchema,
+    mapping: Option<&'a ClassMapping>,
+) -> Element<'a, AppMsg> {
+    let mapping = mapping.cloned().unwrap_or_default();
+    let builtin_icon = ClassIcon::builtin(cls);
+    let change_mapping = move |mapping: ClassMapping| AppMsg::ChangeResourceClassMapping {
+        cls: cls.to_string(),
+        mapping,
+    };
+    let param_names = schema.params.keys().cloned().collect::<Vec<String>>();
+    let params_list: Element<'_, AppMsg> = if schema.params.is_empty() {
+        text(fl!("resource-class-no-params-msg")).into()
+    } else {
+        column(schema.params.into_iter().map(|(name, param)| {
+            view_list_row(
+                text(name),
+                text(fl!(
+                    "resource-class-param-observed",
+                    kinds = param
+                        .kinds
+                        .iter()
+                        .map(|k| k.to_string())
+                        .collect::<Vec<String>>()
+                        .join(" | "),
+                    count = param.count,
+                    total = schema.resources
+                )),
+            )
+        }))
+        .into()
+    };
+
+    container(
+        column![
+            row![
+                text(fl!("resource-class-header", cls = cls)).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal)
+            ],
+            text(if builtin_icon.is_some() {
+                fl!("resource-class-known-msg")
+            } else {
+                fl!("resource-class-unknown-msg")
+            }),
+            view_section(
+                fl!("resource-class-params-label"),
+                NONE_ELEMENT,
+                params_list
+            ),
+            view_list_row(
+                text(fl!("resource-class-icon-label")),
+                pick_list(ClassIcon::ALL, mapping.icon.or(builtin_icon), {
+                    let mapping = mapping.clone();
+                    move |icon| {
+                        change_mapping(ClassMapping {
+                            icon: Some(icon),
+                            ..mapping.clone()
+                        })
+                    }
+                })
+                .placeholder(fl!("resource-class-icon-placeholder")),
+            ),
+            view_list_row(
+                text(fl!("resource-class-copy-param-label")),
+                pick_list(param_names, mapping.copy_param.clone(), {
+                    let mapping = mapping.clone();
+                    move |param| {
+                        change_mapping(ClassMapping {
+                            copy_param: Some(param),
+                            ..mapping.clone()
+                        })
+                    }
+                })
+                .placeholder(fl!("resource-class-copy-param-placeholder")),
+            ),
+            row![
+                space::horizontal(),
+                button(text(fl!("resource-class-reset-button")))
+                    .style(button::secondary)
+                    .on_press_maybe(
+                        (mapping != ClassMapping::default())
+                            .then(|| change_mapping(ClassMapping::default()))
+                    )
+            ],
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// View for the quick switcher modal, listing the commands, places and scripts matching the query.
+pub(crate) fn view_quick_switcher<'a>(
+    quick_switcher: &'a QuickSwitcher,
+    items: Vec<QuickSwitcherItem>,
+) -> Element<'a, AppMsg> {
+    let list: Element<'_, AppMsg> = if items.is_empty() {
+        text(fl!("quick-switcher-no-matches-msg")).into()
+    } else {
+        column(items.into_iter().enumerate().map(|(i, item)| {
+            let (icon, kind) = match &item {
+                QuickSwitcherItem::Command(_) => {
+                    (bootstrap::command(), fl!("quick-switcher-command-label"))
+                }
+                QuickSwitcherItem::Place(_) => {
+                    (bootstrap::motherboard(), fl!("quick-switcher-place-label"))
+                }
+                QuickSwitcherItem::Script(_) => {
+                    (bootstrap::terminal(), fl!("quick-switcher-script-label"))
+                }
+            };
+            button(
+                row![
+                    icon,
+                    text(item.label()).shaping(Shaping::Advanced),
+                    space::horizontal(),
+                    text(kind).size(12)
+                ]
+                .spacing(6)
+                .align_y(Alignment::Center),
+            )
+            .width(Length::Fill)
+            .style(if i == quick_switcher.selected {
+                button::primary
+            } else {
+                button::text
+            })
+            .on_press(AppMsg::Connected(ConnectedMsg::QuickSwitcherActivate {
+                item,
+                acquire: false,
+            }))
+            .into()
+        }))
+        .spacing(2)
+        .into()
+    };
+
+    container(
+        column![
+            text_input(
+                &if quick_switcher.commands {
+                    fl!("command-palette-placeholder")
+                } else {
+                    fl!("quick-switcher-placeholder")
+                },
+                &quick_switcher.query
+            )
+            .id(QUICK_SWITCHER_INPUT_ID)
+            .on_input(|query| AppMsg::Connected(ConnectedMsg::QuickSwitcherInput(query))),
+            list,
+            text(if quick_switcher.commands {
+                fl!("command-palette-hint")
+            } else {
+                fl!("quick-switcher-hint")
+            })
+            .size(12),
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// View for the modal that creates a new reservation.
+///
+/// The places matched by the filter expression are previewed by evaluating it client-side.
+pub(crate) fn view_create_reservation<'a>(
+    places: &'a [(Place, PlaceUi)],
+    filter_text: &'a str,
+    prio_text: &'a str,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let prio = format::parse_decimal(prio_text);
+    let (preview, filters): (Element<'_, AppMsg>, _) = if filter_text.trim().is_empty() {
+        (
+            text(fl!("labgrid-reservation-filter-empty-msg")).into(),
+            None,
+        )
+    } else {
+        match filter_text.parse::<FilterExpr>() {
+            Ok(expr) => {
+                let matching = places
+                    .iter()
+                    .filter(|(p, _)| expr.matches(&p.tags))
+                    .collect::<Vec<_>>();
+                let filters = expr.to_filters();
+                let client_side_note: Element<'_, AppMsg> = match &filters {
+                    Ok(_) => view_empty(),
+                    Err(error) => text(fl!(
+                        "labgrid-reservation-filter-client-side-msg",
+                        reason = error.to_string()
+                    ))
+                    .style(text::warning)
+                    .into(),
+                };
+                (
+                    column![
+                        client_side_note,
+                        text(fl!(
+                            "labgrid-reservation-filter-matches-label",
+                            count = matching.len()
+                        )),
+                        scrollable(
+                            column(matching.into_iter().map(|(p, _)| {
+                                container(view_list_row(
+                                    text(&p.name),
+                                    text(p.acquired.as_deref().unwrap_or_default()),
+                                ))
+                                .style(card_container_style)
+                                .into()
+                            }))
+                            .spacing(6)
+                        )
+                        .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+                        .height(Length::Shrink)
+                    ]
+                    .spacing(6)
+                    .into(),
+                    filters.ok(),
+                )
+            }
+            Err(error) => (
+                text(fl!(
+                    "labgrid-reservation-filter-invalid-msg",
+                    error = error.to_string()
+                ))
+                .style(text::danger)
+                .into(),
+                None,
+            ),
+        }
+    };
+    let create_msg = filters.zip(prio).map(|(filters, prio)| {
+        AppMsg::ConnectionMsg(ConnectionMsg::CreateReservation { filters, prio }).hide_modal()
+    });
+
+    container(
+        column![
+            row![
+                text(fl!("labgrid-reservation-create-header")).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal)
+            ],
+            row![
+                text_input(
+                    fl!("labgrid-reservation-filter-placeholder").as_str(),
+                    filter_text
+                )
+                .on_input(|text| AppMsg::Connected(
+                    ConnectedMsg::UpdateCreateReservationFilter(text)
+                )),
+                view_text_tooltip(
+                    button(bootstrap::backspace()).on_press(AppMsg::Connected(
+                        ConnectedMsg::UpdateCreateReservationFilter(String::new())
+                    )),
+                    fl!("text-input-clear-tooltip")
+                ),
+            ]
+            .spacing(1),
+            view_list_row(
+                text(fl!("labgrid-reservation-prio-label") + " : "),
+                text_input("0.0", prio_text)
+                    .on_input(
+                        |text| AppMsg::Connected(ConnectedMsg::UpdateCreateReservationPrio(text))
+                    )
+                    .width(120)
+            ),
+            preview,
+            row![
+                space::horizontal(),
+                button(text(fl!("labgrid-reservation-create-button"))).on_press_maybe(create_msg)
+            ]
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// The translated label of the reservation state.
+fn reservation_state_label(state: Option<ReservationState>) -> String {
+    match state {
+        Some(ReservationState::Waiting) => fl!("labgrid-reservation-state-waiting"),
+        Some(ReservationState::Allocated) => fl!("labgrid-reservation-state-allocated"),
+        Some(ReservationState::Acquired) => fl!("labgrid-reservation-state-acquired"),
+        Some(ReservationState::Expired) => fl!("labgrid-reservation-state-expired"),
+        Some(ReservationState::Invalid) => fl!("labgrid-reservation-state-invalid"),
+        _ => fl!("labgrid-reservation-state-unknown"),
+    }
+}
+
+/// Modal summarizing the reservations selected by `cleanup` before cancelling them.
+pub(crate) fn view_cleanup_reservations<'a>(
+    selected: Vec<&'a Reservation>,
+    cleanup: ReservationCleanup,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let cancel_msg = (!selected.is_empty()).then(|| {
+        AppMsg::ConnectionMsg(ConnectionMsg::CancelReservations {
+            tokens: selected.iter().map(|r| r.token.clone()).collect(),
+        })
+        .hide_modal()
+    });
+    let summary = text(fl!(
+        "labgrid-reservations-cleanup-summary",
+        count = selected.len()
+    ));
+    let reservations_list = column(selected.into_iter().map(|r| {
+        container(view_list_row(
+            column![text(&r.owner), text(&r.token).size(12)].spacing(3),
+            text(reservation_state_label(r.reservation_state())),
+        ))
+        .style(card_container_style)
+        .into()
+    }))
+    .spacing(6);
+
+    container(
+        column![
+            row![
+                text(fl!("labgrid-reservations-cleanup-header")).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal)
+            ],
+            view_list_row(
+                text(fl!("labgrid-reservations-cleanup-select-label") + " : "),
+                pick_list(ReservationCleanup::ALL, Some(cleanup), |cleanup| {
+                    AppMsg::Connected(ConnectedMsg::ChangeReservationCleanup(cleanup))
+                })
+            ),
+            summary,
+            scrollable(reservations_list)
+                .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+                .height(Length::Shrink),
+            row![
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                space::horizontal(),
+                button(text(fl!("labgrid-reservations-cleanup-confirm-button")))
+                    .style(button::danger)
+                    .on_press_maybe(cancel_msg)
+            ]
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
     .padding(12)
     .into()
 }
 
+/// View for the banner displaying a lab-wide announcement.
+pub(crate) fn view_announcement(announcement: &str) -> Element<'_, AppMsg> {
+    container(
+        row![
+            bootstrap::megaphone(),
+            text(announcement),
+            space::horizontal(),
+            view_text_tooltip(
+                button(bootstrap::x())
+                    .style(button::text)
+                    .on_press(AppMsg::Connected(ConnectedMsg::DismissAnnouncement)),
+                fl!("announcement-dismiss-tooltip")
+            ),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center),
+    )
+    .style(container::warning)
+    .width(Length::Fill)
+    .padding(6)
+    .into()
+}
+
+/// View for the banner showing an availability change of a watched resource.
+pub(crate) fn view_availability_notice(notice: &AvailabilityNotice) -> Element<'_, AppMsg> {
+    let path = notice.path.to_string();
+    let time = format::date_time(notice.time);
+    let (msg, style): (_, fn(&Theme) -> container::Style) = if notice.available {
+        (
+            fl!(
+                "resource-available-notice",
+                path = path.as_str(),
+                time = time.as_str()
+            ),
+            container::success,
+        )
+    } else {
+        (
+            fl!(
+                "resource-unavailable-notice",
+                path = path.as_str(),
+                time = time.as_str()
+            ),
+            container::warning,
+        )
+    };
+    container(
+        row![
+            bootstrap::bell(),
+            text(msg),
+            space::horizontal(),
+            view_text_tooltip(
+                button(bootstrap::x())
+                    .style(button::text)
+                    .on_press(AppMsg::Connected(ConnectedMsg::DismissAvailabilityNotice(
+                        notice.path.clone()
+                    ))),
+                fl!("availability-notice-dismiss-tooltip")
+            ),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center),
+    )
+    .style(style)
+    .width(Length::Fill)
+    .padding(6)
+    .into()
+}
+
+/// View for the banner warning about the protocol of the coordinator.
+pub(crate) fn view_protocol_warning(warning: ProtocolWarning) -> Element<'static, AppMsg> {
+    let unknown = || fl!("protocol-version-unknown");
+    let (msg, style): (_, fn(&Theme) -> container::Style) = match warning {
+        ProtocolWarning::Untested { version } => (
+            fl!(
+                "protocol-untested-msg",
+                version = version,
+                client_version = version::PROTOCOL_VERSION
+            ),
+            container::warning,
+        ),
+        ProtocolWarning::Incompatible { version } => (
+            fl!(
+                "protocol-incompatible-msg",
+                version = version,
+                min = version::MIN_GRPC_MAJOR
+            ),
+            container::danger,
+        ),
+        ProtocolWarning::Mismatch { version, error } => (
+            fl!(
+                "protocol-mismatch-msg",
+                version = version.unwrap_or_else(unknown),
+                client_version = version::PROTOCOL_VERSION,
+                error = error
+            ),
+            container::danger,
+        ),
+    };
+    container(
+        row![
+            bootstrap::exclamation_triangle(),
+            text(msg),
+            space::horizontal(),
+            view_text_tooltip(
+                button(bootstrap::x())
+                    .style(button::text)
+                    .on_press(AppMsg::Connected(ConnectedMsg::DismissProtocolWarning)),
+                fl!("protocol-warning-dismiss-tooltip")
+            ),
+        ]
+        .spacing(6)
+        .align_y(Alignment::Center),
+    )
+    .style(style)
+    .width(Length::Fill)
+    .padding(6)
+    .into()
+}
+
 /// View for the "connected" app state
-pub(crate) fn view_app_connected(
-    connected: &AppConnected,
-    optimize_touch: bool,
-) -> Element<'_, AppMsg> {
+pub(crate) fn view_app_connected<'a>(
+    app: &'a App,
+    connected: &'a AppConnected,
+) -> Element<'a, AppMsg> {
+    let optimize_touch = app.optimize_touch;
+    let button_padding = button_padding(optimize_touch);
     column![
         row![
             container(
@@ -927,13 +4347,23 @@ pub(crate) fn view_app_connected(
                         "connected-to-coordinator-label",
                         address = connected.address.as_str()
                     )),
+                    connected.latency.map(|latency| view_text_tooltip(
+                        text(fl!(
+                            "connection-latency-label",
+                            ms = latency.as_millis().to_string()
+                        ))
+                        .size(12),
+                        fl!("connection-latency-tooltip")
+                    )),
                     space::horizontal(),
                     view_text_tooltip(
                         button(bootstrap::arrow_clockwise())
+                            .padding(button_padding)
                             .on_press(AppMsg::Connected(ConnectedMsg::Refresh)),
                         fl!("refresh-ui-tooltip")
                     ),
                     button(text(fl!("disconnect-button")))
+                        .padding(button_padding)
                         .on_press(AppMsg::Connected(ConnectedMsg::Disconnect)),
                 ]
                 .spacing(6)
@@ -943,19 +4373,37 @@ pub(crate) fn view_app_connected(
             .padding(6)
             .style(card_container_style),
             container(
-                button(text(fl!("settings-button")))
-                    .on_press(AppMsg::ShowModal(Box::new(Modal::Settings)))
+                row![
+                    button(text(fl!("settings-button")))
+                        .padding(button_padding)
+                        .on_press(AppMsg::ShowModal(Box::new(Modal::Settings))),
+                    view_error_console_button(&app.errors),
+                ]
+                .spacing(6)
             )
             .padding(6)
         ]
         .spacing(6),
+        connected.protocol_warning().map(view_protocol_warning),
+        connected.announcement().map(view_announcement),
+        column(
+            connected
+                .resource_watch
+                .notices
+                .iter()
+                .map(view_availability_notice)
+        )
+        .spacing(6),
         Tabs::new(|id| AppMsg::Connected(ConnectedMsg::TabSelected(id)))
             .push(
                 TabId::Places,
                 TabLabel::Text(fl!("labgrid-places-label")),
                 container(view_places_tab(
-                    &connected.places,
-                    &connected.add_place_text,
+                    connected,
+                    &app.card_palette,
+                    &app.watched_places,
+                    &app.quick_actions,
+                    &app.auto_release,
                     optimize_touch
                 ))
                 .padding(padding::top(6))
@@ -964,7 +4412,8 @@ pub(crate) fn view_app_connected(
                 TabId::Reservations,
                 TabLabel::Text(fl!("labgrid-reservations-label")),
                 container(view_reservations_tab(
-                    &connected.reservations,
+                    connected,
+                    &app.place_activity,
                     optimize_touch
                 ))
                 .padding(padding::top(6))
@@ -973,8 +4422,33 @@ pub(crate) fn view_app_connected(
                 TabId::Resources,
                 TabLabel::Text(fl!("labgrid-resources-label")),
                 container(view_resources_tab(
+                    connected,
+                    &app.card_palette,
+                    &app.acquire_history,
+                    &app.resource_classes,
+                    &app.watched_resources,
+                    app.offline_exporter_resources,
+                    !app.terminal_command.trim().is_empty(),
+                    optimize_touch
+                ))
+                .padding(padding::top(6))
+            )
+            .push(
+                TabId::Exporters,
+                TabLabel::Text(fl!("labgrid-exporters-label")),
+                container(view_exporters_tab(
+                    &connected.resources,
+                    &connected.exporter_last_seen,
+                    optimize_touch
+                ))
+                .padding(padding::top(6))
+            )
+            .push(
+                TabId::Classes,
+                TabLabel::Text(fl!("labgrid-classes-label")),
+                container(view_classes_tab(
                     &connected.resources,
-                    connected.resources_only_show_available,
+                    &app.resource_classes,
                     optimize_touch
                 ))
                 .padding(padding::top(6))
@@ -982,13 +4456,1682 @@ pub(crate) fn view_app_connected(
             .push(
                 TabId::Scripts,
                 TabLabel::Text(fl!("scripts-label")),
-                container(view_scripts_tab(connected, optimize_touch)).padding(padding::top(6))
+                container(view_scripts_tab(
+                    connected,
+                    &app.script_meta,
+                    optimize_touch
+                ))
+                .padding(padding::top(6))
+            )
+            .push(
+                TabId::Dashboard,
+                TabLabel::Text(fl!("dashboard-label")),
+                container(view_dashboard_tab(
+                    connected,
+                    &app.card_palette,
+                    &app.utilization,
+                    app.record_utilization,
+                    optimize_touch
+                ))
+                .padding(padding::top(6))
             )
             .set_active_tab(&connected.active_tab)
             .tab_bar_position(TabBarPosition::Top)
             .tab_label_spacing(6.)
-            .tab_label_padding(6.)
+            .tab_label_padding(if optimize_touch { 14. } else { 6. })
     ]
     .spacing(6)
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures;
+    use crate::views::testing::ViewHarness;
+    use chrono::{Local, TimeZone};
+    use core::time::Duration;
+    use std::collections::HashMap;
+
+    fn place() -> Place {
+        Place {
+            comment: "Rack 3".to_string(),
+            tags: HashMap::from([("board".to_string(), "imx8".to_string())]),
+            ..test_fixtures::place("board-1")
+        }
+    }
+
+    fn resource() -> Resource {
+        Resource {
+            params: test_fixtures::params(&[
+                ("host", MapValue::String("lab-1".to_string())),
+                ("port", MapValue::UInt(4001)),
+                ("speed", MapValue::Float(115200.)),
+                (
+                    "protocols",
+                    MapValue::Array(vec![
+                        MapValue::String("rfc2217".to_string()),
+                        MapValue::Array(vec![MapValue::Bool(true), MapValue::Int(-1)]),
+                    ]),
+                ),
+            ]),
+            acquired: "board-1".to_string(),
+            ..test_fixtures::resource("NetworkSerialPort", "NetworkSerialPort")
+        }
+    }
+
+    fn reservation() -> Reservation {
+        Reservation {
+            owner: "lab/user".to_string(),
+            state: ReservationState::Allocated.into(),
+            prio: 0.5,
+            created: reservation_now().timestamp() as f64 - 30.,
+            timeout: reservation_now().timestamp() as f64 + 90.,
+            ..test_fixtures::reservation("ABCDEF")
+        }
+    }
+
+    fn reservation_now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn place_card() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let ui = PlaceUi::default();
+        insta::assert_snapshot!(harness.snapshot(view_place(&place, &ui, None, Color::BLACK, None, false, false, &QuickActionSettings::default(), None, None, false)), @r#"
+            container
+              container
+                container
+                  container
+                    text "Name : "
+                    text "board-1"
+                  container
+                    text "Comment : "
+                    text "Rack 3"
+                  container
+                    text "Available"
+                  container
+                    text "Tags : "
+                    container
+                      container
+                        container
+                          container
+                            text "board"
+                            text "="
+                            text "imx8"
+                            container
+                              text "\u{f62a}"
+                      container
+                        container
+                          text "\u{f4fe}"
+                container
+                  container
+                    container
+                      text "Show Details"
+                    container
+                      container
+                        text "\u{f588}"
+                  container
+                    container
+                      text "Delete"
+                    container
+                      container
+                        text "Acquire"
+                      container
+                        container
+                          text "\u{f255}"
+            "#);
+    }
+
+    #[test]
+    fn place_card_acquired() {
+        let mut harness = ViewHarness::new();
+        let place = Place {
+            acquired: Some("lab/user".to_string()),
+            ..place()
+        };
+        let ui = PlaceUi::default();
+        insta::assert_snapshot!(harness.snapshot(view_place(&place, &ui, None, Color::BLACK, None, false, false, &QuickActionSettings::default(), None, None, false)), @r#"
+            container
+              container
+                container
+                  container
+                    text "Name : "
+                    text "board-1"
+                  container
+                    text "Comment : "
+                    text "Rack 3"
+                  container
+                    text "Acquired by : "
+                    container
+                      text "lab/user"
+                  container
+                    text "Tags : "
+                    container
+                      container
+                        container
+                          container
+                            text "board"
+                            text "="
+                            text "imx8"
+                            container
+                              text "\u{f62a}"
+                      container
+                        container
+                          text "\u{f4fe}"
+                container
+                  container
+                    container
+                      text "Show Details"
+                    container
+                      container
+                        text "\u{f588}"
+                  container
+                    container
+                      text "Delete"
+                    container
+                      text "Force release"
+            "#);
+    }
+
+    #[test]
+    fn place_timestamps() {
+        let mut harness = ViewHarness::new();
+        let now = reservation_now();
+        let changed_place = Place {
+            created: (now - chrono::Duration::days(3)).timestamp() as f64,
+            changed: (now - chrono::Duration::minutes(5)).timestamp() as f64,
+            ..place()
+        };
+        insta::assert_snapshot!(harness.snapshot(view_place_timestamps(&changed_place, now)), @r#"
+            container
+              container
+                text "Created : "
+                container
+                  text "3 days ago"
+              container
+                text "Changed : "
+                container
+                  text "5 min ago"
+            "#);
+        // Unset timestamps are omitted
+        insta::assert_snapshot!(harness.snapshot(view_place_timestamps(&place(), now)), @r"
+            container
+            ");
+    }
+
+    #[test]
+    fn place_edit_comment() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let ui = PlaceUi {
+            edit_comment_text: Some("Rack 4".to_string()),
+            ..Default::default()
+        };
+        insta::assert_snapshot!(harness.snapshot(view_place_general_info(&place, &ui, true, None)), @r#"
+            container
+              container
+                text "Name : "
+                text "board-1"
+              container
+                text "Comment : "
+                container
+                  text_input "Rack 4"
+                  container
+                    text "Save"
+                  container
+                    text "Cancel"
+              container
+                text "Available"
+              container
+                text "Tags : "
+                container
+                  container
+                    container
+                      container
+                        text "board"
+                        text "="
+                        text "imx8"
+                        container
+                          text "\u{f62a}"
+                  container
+                    container
+                      text "\u{f4fe}"
+            "#);
+        let messages = harness.click(view_place_general_info(&place, &ui, true, None), "Save");
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    SavePlaceComment {
+                        place_name: "board-1",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn place_card_add_tag() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let ui = PlaceUi {
+            add_tag_text: Some(("os".to_string(), "linux".to_string())),
+            ..Default::default()
+        };
+        insta::assert_snapshot!(harness.snapshot(view_place(&place, &ui, None, Color::BLACK, None, false, false, &QuickActionSettings::default(), None, None, false)), @r#"
+            container
+              container
+                container
+                  container
+                    text "Name : "
+                    text "board-1"
+                  container
+                    text "Comment : "
+                    text "Rack 3"
+                  container
+                    text "Available"
+                  container
+                    container
+                      text_input "os"
+                      text " = "
+                      text_input "linux"
+                    container
+                      container
+                        container
+                          text "\u{f159}"
+                      container
+                        container
+                          text "\u{f4fe}"
+                      container
+                        container
+                          text "\u{f62a}"
+                container
+                  container
+                    container
+                      text "Show Details"
+                    container
+                      container
+                        text "\u{f588}"
+                  container
+                    container
+                      text "Delete"
+                    container
+                      container
+                        text "Acquire"
+                      container
+                        container
+                          text "\u{f255}"
+            "#);
+    }
+
+    #[test]
+    fn place_session() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let status = SessionTimerStatus {
+            elapsed: Duration::from_secs(3600 + 5),
+            remaining: Some(Duration::from_secs(4 * 60)),
+            warning: true,
+        };
+        insta::assert_snapshot!(harness.snapshot(view_place_session(&place, status)), @r#"
+            container
+              container
+                text "Session : "
+                container
+                  container
+                    text "1:00:05"
+                    text "Released in 0:04:00"
+                  container
+                    container
+                      container
+                        text "\u{f41f}"
+                    container
+                      container
+                        text "\u{f4ec}"
+            "#);
+        // Kept places show only the elapsed time
+        let status = SessionTimerStatus {
+            remaining: None,
+            ..status
+        };
+        insta::assert_snapshot!(harness.snapshot(view_place_session(&place, status)), @r#"
+            container
+              container
+                text "Session : "
+                container
+                  container
+                    text "1:00:05"
+            "#);
+    }
+
+    #[test]
+    fn place_card_resource_availability() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let ui = PlaceUi::default();
+        let availability = ResourceAvailability {
+            available: 4,
+            total: 5,
+        };
+        insta::assert_snapshot!(harness.snapshot(view_place(&place, &ui, None, Color::BLACK, None, false, false, &QuickActionSettings::default(), None, Some(availability), false)), @r#"
+            container
+              container
+                container
+                  container
+                    text "Name : "
+                    text "board-1"
+                  container
+                    text "Comment : "
+                    text "Rack 3"
+                  container
+                    text "Available"
+                  container
+                    text "Tags : "
+                    container
+                      container
+                        container
+                          container
+                            text "board"
+                            text "="
+                            text "imx8"
+                            container
+                              text "\u{f62a}"
+                      container
+                        container
+                          text "\u{f4fe}"
+                container
+                  container
+                    text "Resources : "
+                    container
+                      text "4/5 available"
+                container
+                  container
+                    container
+                      text "Show Details"
+                    container
+                      container
+                        text "\u{f588}"
+                  container
+                    container
+                      text "Delete"
+                    container
+                      container
+                        text "Acquire"
+                      container
+                        container
+                          text "\u{f255}"
+            "#);
+    }
+
+    #[test]
+    fn place_card_messages() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let ui = PlaceUi::default();
+        let acquire = harness.click(
+            view_place(
+                &place,
+                &ui,
+                None,
+                Color::BLACK,
+                None,
+                false,
+                false,
+                &QuickActionSettings::default(),
+                None,
+                None,
+                false,
+            ),
+            "Acquire",
+        );
+        insta::assert_debug_snapshot!(acquire, @r#"
+            [
+                ConnectionMsg(
+                    AcquirePlace {
+                        name: "board-1",
+                    },
+                ),
+            ]
+            "#);
+        let details = harness.click(
+            view_place(
+                &place,
+                &ui,
+                None,
+                Color::BLACK,
+                None,
+                false,
+                false,
+                &QuickActionSettings::default(),
+                None,
+                None,
+                false,
+            ),
+            "Show Details",
+        );
+        insta::assert_debug_snapshot!(details, @r#"
+            [
+                ShowModal(
+                    PlaceDetails {
+                        place_name: "board-1",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn place_card_quick_actions() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let ui = PlaceUi::default();
+        let quick_actions = QuickActionSettings {
+            acquire: false,
+            power_cycle: true,
+            console: true,
+            favorite_script: true,
+            favorite_script_name: Some("hello_world.py".to_string()),
+        };
+        let messages = harness.click(
+            view_place(
+                &place,
+                &ui,
+                None,
+                Color::BLACK,
+                None,
+                false,
+                true,
+                &quick_actions,
+                None,
+                None,
+                false,
+            ),
+            "hello_world.py",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    ExecuteScriptOnPlace {
+                        script_name: "hello_world.py",
+                        place_name: "board-1",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn force_release_modal_messages() {
+        let mut harness = ViewHarness::new();
+        let messages = harness.click(
+            view_force_release_modal("board-1", "lab/user", "board"),
+            "Force release",
+        );
+        insta::assert_debug_snapshot!(messages, @r"
+            []
+            ");
+        let messages = harness.click(
+            view_force_release_modal("board-1", "lab/user", "board-1"),
+            "Force release",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                WithHideModal(
+                    ConnectionMsg(
+                        ForceReleasePlace {
+                            name: "board-1",
+                            from_user: "lab/user",
+                        },
+                    ),
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn resource_card() {
+        let mut harness = ViewHarness::new();
+        let resource = resource();
+        let ui = ResourceUi::default();
+        insta::assert_snapshot!(harness.snapshot(view_resource(&resource, &ui, &AcquireHistory::default(), &ResourceClassRegistry::default(), None, false, false)), @r#"
+            container
+              container
+                container
+                  container
+                    container
+                      text "\u{f5c3}"
+                  text "exporter-1/board-1/NetworkSerialPort/NetworkSerialPort"
+                container
+                  container
+                    container
+                      text "\u{f5c3}"
+                  container
+                    container
+                      text "\u{f759}"
+                  container
+                    container
+                      text "\u{f4fe}"
+                  container
+                    container
+                      text "\u{f18a}"
+                  container
+                  container
+                    text "Show Details"
+            "#);
+    }
+
+    #[test]
+    fn resource_card_stale() {
+        let mut harness = ViewHarness::new();
+        let resource = resource();
+        let ui = ResourceUi {
+            stale_since: Some(std::time::Instant::now()),
+            ..Default::default()
+        };
+        insta::assert_snapshot!(harness.snapshot(view_resource(&resource, &ui, &AcquireHistory::default(), &ResourceClassRegistry::default(), None, false, false)), @r#"
+            container
+              container
+                container
+                  container
+                    container
+                      text "\u{f5c3}"
+                  text "exporter-1/board-1/NetworkSerialPort/NetworkSerialPort"
+                container
+                  container
+                    text "Offline"
+                  container
+                    container
+                      text "\u{f5c3}"
+                  container
+                    container
+                      text "\u{f759}"
+                  container
+                    container
+                      text "\u{f4fe}"
+                  container
+                    container
+                      text "\u{f18a}"
+                  container
+                  container
+                    text "Show Details"
+            "#);
+    }
+
+    #[test]
+    fn resource_card_details() {
+        let mut harness = ViewHarness::new();
+        let resource = resource();
+        let ui = ResourceUi {
+            show_details: true,
+            ..Default::default()
+        };
+        let mut acquire_history = AcquireHistory::default();
+        acquire_history.record(
+            None,
+            &resource,
+            Local.with_ymd_and_hms(2025, 4, 1, 12, 30, 0).unwrap(),
+        );
+        insta::assert_snapshot!(harness.snapshot(view_resource(&resource, &ui, &acquire_history, &ResourceClassRegistry::default(), None, false, false)), @r#"
+            container
+              container
+                container
+                  container
+                    container
+                      container
+                        text "\u{f5c3}"
+                    text "exporter-1/board-1/NetworkSerialPort/NetworkSerialPort"
+                  container
+                    container
+                      text "\u{f292}"
+                    container
+                      container
+                        text "\u{f5c3}"
+                    container
+                      container
+                        text "\u{f759}"
+                    container
+                      container
+                        text "\u{f4fe}"
+                    container
+                      container
+                        text "\u{f18a}"
+                    container
+                    container
+                      text "Hide Details"
+                container
+                  text "Acquired : "
+                  text "board-1"
+                container
+                  text "Recently acquired by : "
+                  text "board-1 at 2025-04-01 12:30:00"
+                container
+                  text "Parameter : "
+                container
+                  container
+                    container
+                      text "host"
+                      container
+                        text "lab-1"
+                        container
+                          container
+                            text "\u{f759}"
+                    container
+                      text "port"
+                      container
+                        text "4001"
+                        container
+                          container
+                            text "\u{f759}"
+                    container
+                      container
+                        text "protocols"
+                        container
+                          text "[rfc2217, [true, -1]]"
+                          container
+                            container
+                              text "\u{f759}"
+                      container
+                        container
+                          container
+                            text "[0]"
+                            container
+                              text "rfc2217"
+                              container
+                                container
+                                  text "\u{f759}"
+                          container
+                            container
+                              text "[1]"
+                              container
+                                text "[true, -1]"
+                                container
+                                  container
+                                    text "\u{f759}"
+                            container
+                              container
+                                container
+                                  text "[0]"
+                                  container
+                                    text "true"
+                                    container
+                                      container
+                                        text "\u{f759}"
+                                container
+                                  text "[1]"
+                                  container
+                                    text "-1"
+                                    container
+                                      container
+                                        text "\u{f759}"
+                    container
+                      text "speed"
+                      container
+                        text "115200.0"
+                        container
+                          container
+                            text "\u{f759}"
+                container
+                  text "Extra : "
+                container
+                  text "-"
+            "#);
+    }
+
+    #[cfg(feature = "camera-preview")]
+    #[test]
+    fn resource_card_camera_preview() {
+        let mut harness = ViewHarness::new();
+        let resource = Resource {
+            params: test_fixtures::params(&[(
+                "url",
+                MapValue::String("http://cam-1/stream".to_string()),
+            )]),
+            acquired: "board-1".to_string(),
+            ..test_fixtures::resource("camera", "HTTPVideoStream")
+        };
+        let ui = ResourceUi {
+            show_details: true,
+            camera_preview: Some(CameraPreview::Failed("Connect: refused".to_string())),
+            ..Default::default()
+        };
+        let history = AcquireHistory::default();
+        let classes = ResourceClassRegistry::default();
+        insta::assert_snapshot!(harness.snapshot(view_resource(&resource, &ui, &history, &classes, None, false, false)), @r#"
+            container
+              container
+                container
+                  container
+                    container
+                      container
+                        text "\u{f21f}"
+                    text "exporter-1/board-1/HTTPVideoStream/camera"
+                  container
+                    container
+                      container
+                        text "\u{f759}"
+                    container
+                      container
+                        text "\u{f4fe}"
+                    container
+                      container
+                        text "\u{f18a}"
+                    container
+                    container
+                      text "Hide Details"
+                container
+                  text "Acquired : "
+                  text "board-1"
+                container
+                  text "Recently acquired by : "
+                  text "-"
+                container
+                  container
+                    text "Preview : "
+                    container
+                      text "Refresh"
+                  container
+                    text "Fetching the snapshot failed: Connect: refused"
+                container
+                  text "Parameter : "
+                container
+                  container
+                    container
+                      text "url"
+                      container
+                        text "http://cam-1/stream"
+                        container
+                          container
+                            text "\u{f759}"
+                container
+                  text "Extra : "
+                container
+                  text "-"
+            "#);
+        let messages = harness.click(
+            view_resource(&resource, &ui, &history, &classes, None, false, false),
+            "Refresh",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    RefreshCameraPreview(
+                        Path {
+                            exporter_name: Some(
+                                "exporter-1",
+                            ),
+                            group_name: "board-1",
+                            resource_name: "camera",
+                        },
+                    ),
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn resources_panel_drag_messages() {
+        let mut harness = ViewHarness::new();
+        let resources = vec![(resource(), ResourceUi::default())];
+        let messages = harness.click(
+            view_resources_panel(&resources, None, false),
+            "exporter-1/board-1/NetworkSerialPort/NetworkSerialPort",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    StartResourceDrag {
+                        pattern: "exporter-1/board-1/NetworkSerialPort/NetworkSerialPort",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn resource_card_unknown_class() {
+        let mut harness = ViewHarness::new();
+        let mut resource = resource();
+        resource.cls = "CustomProbe".to_string();
+        let ui = ResourceUi::default();
+        insta::assert_snapshot!(harness.snapshot(view_resource(&resource, &ui, &AcquireHistory::default(), &ResourceClassRegistry::default(), None, false, false)), @r#"
+            container
+              container
+                container
+                  container
+                    container
+                      text "\u{f505}"
+                  text "exporter-1/board-1/CustomProbe/NetworkSerialPort"
+                container
+                  container
+                    container
+                      text "\u{f759}"
+                  container
+                    container
+                      text "\u{f4fe}"
+                  container
+                    container
+                      text "\u{f18a}"
+                  container
+                  container
+                    text "Show Details"
+            "#);
+
+        let mut classes = ResourceClassRegistry::default();
+        classes.set(
+            "CustomProbe".to_string(),
+            ClassMapping {
+                icon: Some(ClassIcon::Sensor),
+                copy_param: Some("host".to_string()),
+            },
+        );
+        let messages = harness.click(
+            view_resource(
+                &resource,
+                &ui,
+                &AcquireHistory::default(),
+                &classes,
+                None,
+                false,
+                false,
+            ),
+            "host",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                ClipboardCopy(
+                    "lab-1",
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn resource_card_quick_connect() {
+        let mut harness = ViewHarness::new();
+        let resource = Resource {
+            cls: "NetworkService".to_string(),
+            params: HashMap::from([
+                (
+                    "address".to_string(),
+                    MapValue::String("192.168.1.10".to_string()),
+                ),
+                ("username".to_string(), MapValue::String("root".to_string())),
+            ]),
+            ..resource()
+        };
+        let ui = ResourceUi::default();
+        let history = AcquireHistory::default();
+        let classes = ResourceClassRegistry::default();
+        let mut messages = harness.click(
+            view_resource(&resource, &ui, &history, &classes, None, false, true),
+            "\u{f5c3}",
+        );
+        messages.extend(harness.click(
+            view_resource(&resource, &ui, &history, &classes, None, false, true),
+            "\u{f1c5}",
+        ));
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                ClipboardCopy(
+                    "ssh -- root@192.168.1.10",
+                ),
+                LaunchQuickConnect(
+                    QuickConnect {
+                        program: "ssh",
+                        args: [
+                            "--",
+                            "root@192.168.1.10",
+                        ],
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn availability_notice() {
+        let mut harness = ViewHarness::new();
+        let notice = AvailabilityNotice {
+            path: resource().path,
+            available: false,
+            time: reservation_now(),
+        };
+        insta::assert_snapshot!(harness.snapshot(view_availability_notice(&notice)), @r#"
+            container
+              container
+                text "\u{f18a}"
+                text "The watched resource 'exporter-1/board-1/NetworkSerialPort' became unavailable at 2025-03-01 12:00:00"
+                container
+                  container
+                    text "\u{f62a}"
+            "#);
+        let messages = harness.click(view_availability_notice(&notice), "\u{f62a}");
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    DismissAvailabilityNotice(
+                        Path {
+                            exporter_name: Some(
+                                "exporter-1",
+                            ),
+                            group_name: "board-1",
+                            resource_name: "NetworkSerialPort",
+                        },
+                    ),
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn resource_card_messages() {
+        let mut harness = ViewHarness::new();
+        let resource = resource();
+        let ui = ResourceUi::default();
+        let history = AcquireHistory::default();
+        let classes = ResourceClassRegistry::default();
+        let mut messages = harness.click(
+            view_resource(&resource, &ui, &history, &classes, None, false, false),
+            "Show Details",
+        );
+        messages.extend(harness.click(
+            view_resource(&resource, &ui, &history, &classes, None, false, false),
+            "\u{f18a}",
+        ));
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    ShowResourceDetails(
+                        Path {
+                            exporter_name: Some(
+                                "exporter-1",
+                            ),
+                            group_name: "board-1",
+                            resource_name: "NetworkSerialPort",
+                        },
+                    ),
+                ),
+                ToggleWatchResource(
+                    Path {
+                        exporter_name: Some(
+                            "exporter-1",
+                        ),
+                        group_name: "board-1",
+                        resource_name: "NetworkSerialPort",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn assign_resource_messages() {
+        let mut harness = ViewHarness::new();
+        let places = vec![(place(), PlaceUi::default())];
+        let resource = resource();
+        let messages = harness.click(
+            view_assign_resource(&places, &resource.path, &resource.cls, false),
+            "Assign",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                WithHideModal(
+                    ConnectionMsg(
+                        AddPlaceMatch {
+                            place_name: "board-1",
+                            pattern: "exporter-1/board-1/NetworkSerialPort/NetworkSerialPort",
+                            rename: None,
+                        },
+                    ),
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn resource_match_messages() {
+        let mut harness = ViewHarness::new();
+        let place = place();
+        let resource_match = ResourceMatch {
+            exporter: "*".to_string(),
+            group: "board-1".to_string(),
+            cls: "NetworkSerialPort".to_string(),
+            name: None,
+            rename: Some("console".to_string()),
+        };
+        let messages = harness.click(view_resource_match(&place, &resource_match), "Delete");
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                ConnectionMsg(
+                    DeletePlaceMatch {
+                        place_name: "board-1",
+                        pattern: "*/board-1/NetworkSerialPort",
+                        rename: Some(
+                            "console",
+                        ),
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn bulk_place_actions_messages() {
+        let mut harness = ViewHarness::new();
+        let selected = BTreeSet::from(["board-1".to_string(), "board-2".to_string()]);
+        let tag_text = ("rack".to_string(), "3".to_string());
+        let acquire = harness.click(view_bulk_place_actions(&selected, &tag_text), "Acquire");
+        insta::assert_debug_snapshot!(acquire, @r#"
+            [
+                ConnectionMsg(
+                    AcquirePlaces {
+                        names: [
+                            "board-1",
+                            "board-2",
+                        ],
+                    },
+                ),
+            ]
+            "#);
+        let set_tag = harness.click(view_bulk_place_actions(&selected, &tag_text), "Set Tag");
+        insta::assert_debug_snapshot!(set_tag, @r#"
+            [
+                ConnectionMsg(
+                    SetPlacesTag {
+                        names: [
+                            "board-1",
+                            "board-2",
+                        ],
+                        tag: (
+                            "rack",
+                            "3",
+                        ),
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn bulk_place_op_progress_messages() {
+        let mut harness = ViewHarness::new();
+        let progress = BulkPlaceOpProgress { done: 3, total: 10 };
+        let pause = harness.click(view_bulk_place_op_progress(progress, false), "Pause");
+        insta::assert_debug_snapshot!(pause, @r"
+            [
+                Connected(
+                    PauseBulkPlaceOp(
+                        true,
+                    ),
+                ),
+            ]
+            ");
+        let resume = harness.click(view_bulk_place_op_progress(progress, true), "Resume");
+        insta::assert_debug_snapshot!(resume, @r"
+            [
+                Connected(
+                    PauseBulkPlaceOp(
+                        false,
+                    ),
+                ),
+            ]
+            ");
+        let cancel = harness.click(view_bulk_place_op_progress(progress, true), "Cancel");
+        insta::assert_debug_snapshot!(cancel, @r"
+            [
+                Connected(
+                    CancelBulkPlaceOp,
+                ),
+            ]
+            ");
+    }
+
+    #[test]
+    fn places_grouped_by_tag() {
+        let mut harness = ViewHarness::new();
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        let mut untagged = place();
+        untagged.name = "board-2".to_string();
+        untagged.tags.clear();
+        connected.places = vec![
+            (place(), PlaceUi::default()),
+            (untagged, PlaceUi::default()),
+        ];
+        connected.place_grouping = PlaceGrouping::Tag("board".to_string());
+        let palette = CardPalette::default();
+        let messages = harness.click(
+            view_places_tab(
+                &connected,
+                &palette,
+                &BTreeSet::new(),
+                &QuickActionSettings::default(),
+                &AutoReleaseSettings::default(),
+                false,
+            ),
+            "board = imx8",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    TogglePlaceGroupCollapsed(
+                        Some(
+                            "imx8",
+                        ),
+                    ),
+                ),
+            ]
+            "#);
+        let messages = harness.click(
+            view_places_tab(
+                &connected,
+                &palette,
+                &BTreeSet::new(),
+                &QuickActionSettings::default(),
+                &AutoReleaseSettings::default(),
+                false,
+            ),
+            "Without tag 'board'",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    TogglePlaceGroupCollapsed(
+                        None,
+                    ),
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn places_only_mine() {
+        let mut harness = ViewHarness::new();
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        let mut mine = place();
+        mine.acquired = Some(connected.identity.clone());
+        connected.places = vec![(mine, PlaceUi::default())];
+        let palette = CardPalette::default();
+        let messages = harness.click(
+            view_places_tab(
+                &connected,
+                &palette,
+                &BTreeSet::new(),
+                &QuickActionSettings::default(),
+                &AutoReleaseSettings::default(),
+                false,
+            ),
+            "Only my places",
+        );
+        insta::assert_debug_snapshot!(messages, @r"
+            [
+                Connected(
+                    PlacesOnlyMine(
+                        true,
+                    ),
+                ),
+            ]
+            ");
+        connected.places_only_mine = true;
+        let messages = harness.click(
+            view_places_tab(
+                &connected,
+                &palette,
+                &BTreeSet::new(),
+                &QuickActionSettings::default(),
+                &AutoReleaseSettings::default(),
+                false,
+            ),
+            "Acquired by me",
+        );
+        insta::assert_debug_snapshot!(messages, @r"
+            []
+            ");
+    }
+
+    #[test]
+    fn resources_tab_offline_exporter() {
+        let mut harness = ViewHarness::new();
+        let mut connected = AppConnected::new(
+            "localhost:20408".to_string(),
+            "host/me".to_string(),
+            PathBuf::new(),
+            0,
+        );
+        let resource = resource();
+        connected.resources = vec![(resource.clone(), ResourceUi::default())];
+        connected.resource_mark_stale(resource.path.clone());
+        connected.exporter_last_seen.record_deleted(
+            &resource.path,
+            &connected.resources,
+            Local.with_ymd_and_hms(2025, 3, 1, 12, 5, 0).unwrap(),
+        );
+        let history = AcquireHistory::default();
+        let classes = ResourceClassRegistry::default();
+        insta::assert_snapshot!(harness.snapshot(view_resources_tab(
+            &connected,
+            &CardPalette::default(),
+            &history,
+            &classes,
+            &BTreeSet::new(),
+            OfflineExporterResources::Hide,
+            false,
+            false,
+        )), @r#"
+            container
+              container
+                container
+                  text "Resources"
+                  container
+                    text_input "Search exporter, group, name or params"
+                    text "Only Show Available"
+                    container
+                      container
+                        text "Export…"
+                scrollable
+                  container
+                    container
+                      container
+                        text "exporter-1"
+                        container
+                          container
+                            text "\u{f33b}"
+                            text "Offline since 2025-03-01 12:05:00"
+                      container
+            "#);
+    }
+
+    #[test]
+    fn class_row() {
+        let mut harness = ViewHarness::new();
+        let summary = ClassSummary {
+            cls: "NetworkSerialPort".to_string(),
+            resources: 4,
+            available: 3,
+            acquired: 1,
+        };
+        let classes = ResourceClassRegistry::default();
+        insta::assert_snapshot!(harness.snapshot(view_class(summary.clone(), &classes)), @r#"
+            container
+              container
+                text "\u{f5c3}"
+                text "NetworkSerialPort"
+                text "4 Resources"
+                container
+                  text "3 of 4 available (75 %)"
+                text "1 acquired"
+                container
+                  text "Show Resources"
+            "#);
+        let messages = harness.click(view_class(summary, &classes), "Show Resources");
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    ShowClassResources(
+                        "NetworkSerialPort",
+                    ),
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn dashboard_chart() {
+        let mut harness = ViewHarness::new();
+        let bars = vec![view_dashboard_bar(
+            "host/me".to_string(),
+            "2 (50 %)".to_string(),
+            0.5,
+            None,
+        )];
+        insta::assert_snapshot!(harness.snapshot(view_dashboard_chart(
+            "Acquired Places per User".to_string(),
+            None,
+            bars,
+            "No places are acquired.".to_string()
+        )), @r#"
+            container
+              container
+                container
+                  text "Acquired Places per User"
+                container
+                  container
+                    text "host/me"
+                    text "2 (50 %)"
+            "#);
+        insta::assert_snapshot!(harness.snapshot(view_dashboard_chart(
+            "Acquired Places per User".to_string(),
+            None,
+            vec![],
+            "No places are acquired.".to_string()
+        )), @r#"
+            container
+              container
+                container
+                  text "Acquired Places per User"
+                text "No places are acquired."
+            "#);
+    }
+
+    #[test]
+    fn exporter_card() {
+        let mut harness = ViewHarness::new();
+        let summary = ExporterSummary {
+            name: "exporter-1".to_string(),
+            resources: 4,
+            available: 3,
+            acquired: 1,
+            stale: 1,
+            usb: 2,
+            last_seen: Some(Local.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap()),
+            offline_since: Some(Local.with_ymd_and_hms(2025, 3, 1, 12, 5, 0).unwrap()),
+        };
+        insta::assert_snapshot!(harness.snapshot(view_exporter(summary.clone())), @r#"
+            container
+              container
+                container
+                  text "\u{f40d}"
+                  text "exporter-1"
+                container
+                  text "Resources : "
+                  text "4"
+                container
+                  text "Available : "
+                  text "3 of 4"
+                container
+                  text "Acquired : "
+                  text "1"
+                container
+                  container
+                    text "Removed : "
+                    text "1"
+                container
+                  text "Last seen : "
+                  text "2025-03-01 12:00:00"
+                container
+                  text "Offline since : "
+                  text "2025-03-01 12:05:00"
+                container
+                  container
+                    container
+                      text "USB Topology"
+                    container
+                      text "Show Resources"
+            "#);
+        let mut messages = harness.click(view_exporter(summary.clone()), "Show Resources");
+        messages.extend(harness.click(view_exporter(summary), "USB Topology"));
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                Connected(
+                    ShowExporterResources(
+                        "exporter-1",
+                    ),
+                ),
+                ShowModal(
+                    UsbTopology {
+                        exporter: "exporter-1",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn usb_topology_modal() {
+        let mut harness = ViewHarness::new();
+        let usb_resource = |name: &str, cls: &str, params: &[(&str, MapValue)]| Resource {
+            path: Path {
+                exporter_name: Some("exporter-1".to_string()),
+                group_name: "board-1".to_string(),
+                resource_name: name.to_string(),
+            },
+            cls: cls.to_string(),
+            params: params
+                .iter()
+                .map(|(param, value)| (param.to_string(), value.clone()))
+                .collect(),
+            extra: HashMap::new(),
+            acquired: String::new(),
+            available: true,
+        };
+        let mut resources = vec![
+            usb_resource(
+                "loader",
+                "NetworkIMXUSBLoader",
+                &[
+                    ("path", MapValue::String("1-1.3.1".to_string())),
+                    ("devnum", MapValue::UInt(9)),
+                ],
+            ),
+            usb_resource(
+                "fastboot",
+                "NetworkAndroidFastboot",
+                &[("busnum", MapValue::UInt(2))],
+            ),
+        ];
+        resources[0].acquired = "board-1".to_string();
+        let topology = UsbTopology::of_exporter(&resources, "exporter-1");
+        let classes = ResourceClassRegistry::default();
+        insta::assert_snapshot!(harness.snapshot(view_usb_topology("exporter-1", topology, &classes, false)), @r#"
+            container
+              container
+                container
+                  text "USB Topology of exporter-1"
+                  container
+                    text "\u{f62a}"
+                scrollable
+                  container
+                    container
+                      container
+                        text "Bus 1"
+                      container
+                        container
+                          text "Port 1-1"
+                        container
+                          text "Port 1-1.3"
+                        container
+                          text "Port 1-1.3.1"
+                        container
+                          container
+                            container
+                              text "\u{f6dc}"
+                              text "exporter-1/board-1/NetworkIMXUSBLoader/loader"
+                              text "Device 9"
+                              text "Acquired by board-1"
+                              container
+                    container
+                      container
+                        text "Bus 2"
+                      container
+                        text "Port unknown"
+                        container
+                          container
+                            container
+                              text "\u{f505}"
+                              text "exporter-1/board-1/NetworkAndroidFastboot/fastboot"
+                              container
+            "#);
+    }
+
+    #[test]
+    fn reservation_card() {
+        let mut harness = ViewHarness::new();
+        let reservation = reservation();
+        insta::assert_snapshot!(harness.snapshot(view_reservation(&reservation, false, None, reservation_now())), @r#"
+            container
+              container
+                container
+                  text "Owner : "
+                  text "lab/user"
+                container
+                  text "Token : "
+                  container
+                    text "ABCDEF"
+                    container
+                      container
+                        text "\u{f759}"
+                container
+                  text "Priority : "
+                  text "0.5"
+                container
+                  text "Filters : "
+                  text "{\"main\": Filter({\"board\": \"imx8\"})}"
+                container
+                  text "Created : "
+                  text "2025-03-01 11:59:30"
+                container
+                  text "Expires : "
+                  container
+                    text "2025-03-01 12:01:30"
+                    text "in 0:01:30"
+                container
+                  container
+                    text "Cancel"
+            "#);
+    }
+
+    #[test]
+    fn reservation_card_messages() {
+        let mut harness = ViewHarness::new();
+        let reservation = reservation();
+        let messages = harness.click(
+            view_reservation(&reservation, true, None, reservation_now()),
+            "Cancel",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                ConnectionMsg(
+                    CancelReservation {
+                        token: "ABCDEF",
+                    },
+                ),
+            ]
+            "#);
+        // Reservations of others are cancelled after a confirmation
+        let messages = harness.click(
+            view_reservation(&reservation, false, None, reservation_now()),
+            "Cancel",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                ShowModal(
+                    CancelForeignReservation {
+                        token: "ABCDEF",
+                        owner: "lab/user",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn reservation_card_allocated() {
+        let mut harness = ViewHarness::new();
+        let reservation = Reservation {
+            state: ReservationState::Allocated.into(),
+            allocations: HashMap::from([("main".to_string(), "board-1".to_string())]),
+            ..reservation()
+        };
+        insta::assert_snapshot!(harness.snapshot(view_reservation(&reservation, true, None, reservation_now() + chrono::Duration::minutes(2))), @r#"
+            container
+              container
+                container
+                  text "My reservation"
+                container
+                  text "Owner : "
+                  text "lab/user"
+                container
+                  text "Token : "
+                  container
+                    text "ABCDEF"
+                    container
+                      container
+                        text "\u{f759}"
+                container
+                  text "Priority : "
+                  text "0.5"
+                container
+                  text "Filters : "
+                  text "{\"main\": Filter({\"board\": \"imx8\"})}"
+                container
+                  text "Created : "
+                  text "2025-03-01 11:59:30"
+                container
+                  text "Expires : "
+                  container
+                    text "2025-03-01 12:01:30"
+                    text "Timed out"
+                container
+                  container
+                    text "Allocated : "
+                    container
+                      container
+                        text "main : board-1"
+                        container
+                          text "Go to place"
+                container
+                  container
+                    text "Cancel"
+            "#);
+        let messages = harness.click(
+            view_reservation(&reservation, true, None, reservation_now()),
+            "Go to place",
+        );
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                ShowModal(
+                    PlaceDetails {
+                        place_name: "board-1",
+                    },
+                ),
+            ]
+            "#);
+    }
+
+    #[test]
+    fn compare_places_modal_messages() {
+        let mut harness = ViewHarness::new();
+        let places = vec![(place(), PlaceUi::default())];
+        let file = SnapshotFile {
+            path: std::path::PathBuf::from("places-snapshot.json"),
+            snapshot: crate::place_snapshot::PlaceSnapshot::from_json(
+                r#"{"version": 1, "places": [{"name": "board-1", "comment": "Rack 4"}]}"#,
+            )
+            .unwrap(),
+        };
+        let mut messages = harness.click(view_compare_places_modal(&file, &places), "Apply");
+        messages.extend(harness.click(view_compare_places_modal(&file, &places), "Remove"));
+        insta::assert_debug_snapshot!(messages, @r#"
+            [
+                ConnectionMsg(
+                    SetPlaceComment {
+                        place_name: "board-1",
+                        comment: "Rack 4",
+                    },
+                ),
+                ShowModal(
+                    Confirmation {
+                        msg: "Are you sure you want to delete place tag '\u{2068}board\u{2069}'?",
+                        confirm: ConnectionMsg(
+                            DeletePlaceTag {
+                                place_name: "board-1",
+                                tag: "board",
+                            },
+                        ),
+                    },
+                ),
+            ]
+            "#);
+    }
+}
@@ -3,16 +3,18 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 use super::UI_MAX_WIDTH;
-use crate::app::{self, AppMsg, ErrorCriticality, FONT_NOTO_EMOJI};
+use crate::app::{self, AppMsg, ErrorCriticality, FileBrowserMsg, Modal, FONT_NOTO_EMOJI};
+use crate::error_log::{ErrorFilter, ErrorLog};
+use crate::file_browser::FileBrowser;
 use crate::i18n::fl;
 use iced::border::Radius;
 use iced::widget::scrollable::{Direction, Scrollbar};
 use iced::widget::text::Shaping;
 use iced::widget::{
-    button, center, column, container, mouse_area, opaque, row, rule, scrollable, space, stack,
-    text, tooltip, Space, Text, Tooltip,
+    button, center, column, container, mouse_area, opaque, pick_list, row, rule, scrollable, space,
+    stack, text, text_input, tooltip, Space, Text, Tooltip,
 };
-use iced::{Alignment, Color, Element, Length, Shadow, Theme, Vector};
+use iced::{Alignment, Color, Element, Length, Padding, Shadow, Theme, Vector};
 use iced_fonts::bootstrap;
 
 /// "Card" style for a container.
@@ -28,6 +30,28 @@ pub(crate) fn card_container_style(theme: &Theme) -> container::Style {
     s
 }
 
+/// "Card" style for a container with a border and a background tint in the supplied color.
+///
+/// intended to be used in `container.style` method.
+pub(crate) fn colored_card_container_style(color: Color) -> impl Fn(&Theme) -> container::Style {
+    const TINT: f32 = 0.15;
+
+    move |theme| {
+        let mut s = card_container_style(theme);
+        let base = theme.extended_palette().background.weak.color;
+        s.background = Some(
+            Color::from_rgb(
+                base.r + (color.r - base.r) * TINT,
+                base.g + (color.g - base.g) * TINT,
+                base.b + (color.b - base.b) * TINT,
+            )
+            .into(),
+        );
+        s.border = s.border.color(color).width(2);
+        s
+    }
+}
+
 /// "Modal" style for a container.
 ///
 /// intended to be used in `container.style` method.
@@ -69,6 +93,15 @@ pub(super) fn optimized_scrollbar_properties(
     }
 }
 
+/// The padding of buttons, larger when optimizing for touch input so that they are easier to hit.
+pub(crate) fn button_padding(optimize_touch: bool) -> Padding {
+    if optimize_touch {
+        Padding::from([12, 18])
+    } else {
+        button::DEFAULT_PADDING
+    }
+}
+
 /// View for a modal supplied by `content`, overlaying base elements supplied by `base`.
 /// `on_blur` determines the action when clicking/pressing on the blurred background
 pub(crate) fn modal<'a>(
@@ -235,6 +268,11 @@ pub(crate) fn view_error(error: &app::ErrorReport, optimize_touch: bool) -> Elem
             row![
                 text(criticality + " : " + error.short.as_str()),
                 space::horizontal(),
+                error.retry.as_ref().map(|_| {
+                    button(text(fl!("error-retry-button")))
+                        .style(button::secondary)
+                        .on_press(AppMsg::RetryError)
+                }),
                 button(bootstrap::x())
                     .style(button::secondary)
                     .on_press(AppMsg::DismissError)
@@ -268,11 +306,105 @@ pub(crate) fn view_error(error: &app::ErrorReport, optimize_touch: bool) -> Elem
     .into()
 }
 
+/// View for the header button opening the error console, with a badge counting the errors not seen yet.
+pub(crate) fn view_error_console_button(errors: &ErrorLog) -> Element<'_, AppMsg> {
+    let unseen = errors.unseen();
+    let badge = (unseen > 0).then(|| {
+        container(text(unseen.to_string()).size(12))
+            .style(|theme: &Theme| {
+                let mut s = container::danger(theme);
+                s.border.radius = Radius::new(8.);
+                s
+            })
+            .padding([0, 5])
+    });
+    view_text_tooltip(
+        button(
+            row![bootstrap::exclamation_triangle()]
+                .push(badge)
+                .spacing(4)
+                .align_y(Alignment::Center),
+        )
+        .style(button::secondary)
+        .on_press(AppMsg::ShowModal(Box::new(Modal::ErrorConsole))),
+        fl!("error-console-tooltip"),
+    )
+    .into()
+}
+
+/// View for the error console, listing all errors of the session with their time, the latest first.
+pub(crate) fn view_error_console(errors: &ErrorLog, optimize_touch: bool) -> Element<'_, AppMsg> {
+    let entries = column(errors.filtered().map(|entry| {
+        let criticality = match entry.report.criticality {
+            ErrorCriticality::NonCritical => fl!("error-noncritical"),
+            ErrorCriticality::Critical => fl!("error-critical"),
+        };
+        let style = match entry.report.criticality {
+            ErrorCriticality::NonCritical => text::warning,
+            ErrorCriticality::Critical => text::danger,
+        };
+        column![
+            row![
+                text(entry.time.format("%H:%M:%S").to_string()).size(14),
+                text(criticality).size(14).style(style),
+                text(entry.report.short.as_str()),
+            ]
+            .spacing(12)
+            .align_y(Alignment::Center),
+            (!entry.report.detailed.is_empty())
+                .then(|| text(entry.report.detailed.as_str()).size(12)),
+        ]
+        .spacing(2)
+        .into()
+    }))
+    .spacing(8);
+    let n_errors = errors.filtered().count();
+    container(
+        column![
+            row![
+                text(fl!("error-console-header")).size(24),
+                space::horizontal(),
+                pick_list(
+                    ErrorFilter::ALL,
+                    Some(errors.filter),
+                    AppMsg::ChangeErrorFilter
+                ),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+            if n_errors == 0 {
+                Element::from(text(fl!("error-console-empty")))
+            } else {
+                scrollable(entries)
+                    .direction(optimized_scrollbar_properties(false, true, optimize_touch))
+                    .height(Length::Fixed(400.))
+                    .into()
+            },
+            row![
+                button(text(fl!("error-console-clear-button")))
+                    .style(button::danger)
+                    .on_press_maybe((errors.logged() > 0).then_some(AppMsg::ClearErrors)),
+                space::horizontal(),
+                button(text(fl!("error-console-copy-button")))
+                    .style(button::secondary)
+                    .on_press_maybe((n_errors > 0).then_some(AppMsg::CopyErrors)),
+            ]
+            .spacing(6),
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 200.)
+    .padding(12)
+    .into()
+}
+
 /// View for a confirmation modal that only sends the suppliced `confirm` message
 /// when the user has clicked on the confirm button.
 pub(crate) fn view_confirmation_modal<'a>(
     msg: impl text::IntoFragment<'a>,
     confirm: AppMsg,
+    optimize_touch: bool,
 ) -> Element<'a, AppMsg> {
     container(
         column![
@@ -280,10 +412,12 @@ pub(crate) fn view_confirmation_modal<'a>(
             row![
                 button(text(fl!("confirmation-modal-cancel-button")))
                     .on_press(AppMsg::HideModal)
-                    .style(button::secondary),
+                    .style(button::secondary)
+                    .padding(button_padding(optimize_touch)),
                 space::horizontal(),
                 button(text(fl!("confirmation-modal-confirm-button")))
-                    .on_press(confirm.hide_modal()),
+                    .on_press(confirm.hide_modal())
+                    .padding(button_padding(optimize_touch)),
             ]
         ]
         .align_x(Alignment::Center)
@@ -294,3 +428,250 @@ pub(crate) fn view_confirmation_modal<'a>(
     .padding(12)
     .into()
 }
+
+/// View for the modal of the built-in file browser.
+///
+/// Directories are navigated into on press, files are selected.
+pub(crate) fn view_file_browser(browser: &FileBrowser) -> Element<'_, AppMsg> {
+    let header = if browser.target.picks_dir() {
+        fl!("file-browser-pick-dir-header")
+    } else {
+        fl!("file-browser-pick-file-header")
+    };
+    let entries: Element<'_, AppMsg> = match &browser.entries {
+        None => text(fl!("file-browser-listing-msg")).into(),
+        Some(_) if browser.error.is_some() => text(fl!(
+            "file-browser-list-failed-msg",
+            error = browser.error.clone().unwrap_or_default()
+        ))
+        .style(text::danger)
+        .into(),
+        Some(entries) if entries.is_empty() => text(fl!("file-browser-empty-msg")).into(),
+        Some(entries) => scrollable(
+            column(entries.iter().map(|entry| {
+                let (icon, msg) = if entry.is_dir {
+                    (
+                        bootstrap::folder(),
+                        FileBrowserMsg::Navigate(browser.dir.join(&entry.name)),
+                    )
+                } else {
+                    (
+                        bootstrap::file_earmark(),
+                        FileBrowserMsg::Select(entry.name.clone()),
+                    )
+                };
+                let selected = browser.selected.as_ref() == Some(&entry.name);
+                button(
+                    row![icon, text(&entry.name).shaping(Shaping::Advanced)]
+                        .spacing(6)
+                        .align_y(Alignment::Center),
+                )
+                .width(Length::Fill)
+                .style(if selected {
+                    button::primary
+                } else {
+                    button::text
+                })
+                .on_press(AppMsg::FileBrowser(msg))
+                .into()
+            }))
+            .spacing(2),
+        )
+        .height(300)
+        .into(),
+    };
+    let picked = browser.picked();
+
+    container(
+        column![
+            row![
+                text(header).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal),
+            ]
+            .align_y(Alignment::Center),
+            row![
+                view_text_tooltip(
+                    button(bootstrap::arrow_up()).on_press_maybe(browser.dir.parent().map(
+                        |parent| AppMsg::FileBrowser(FileBrowserMsg::Navigate(
+                            parent.to_path_buf()
+                        ))
+                    )),
+                    fl!("file-browser-up-tooltip")
+                ),
+                text_input("", &browser.path_input)
+                    .on_input(|input| AppMsg::FileBrowser(FileBrowserMsg::PathInput(input)))
+                    .on_submit(AppMsg::FileBrowser(FileBrowserMsg::SubmitPathInput)),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+            entries,
+            row![
+                text(
+                    picked
+                        .as_ref()
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_default()
+                ),
+                space::horizontal(),
+                button(text(fl!("confirmation-modal-cancel-button")))
+                    .on_press(AppMsg::HideModal)
+                    .style(button::secondary),
+                button(text(fl!("file-browser-pick-button")))
+                    .on_press_maybe(picked.map(|_| AppMsg::FileBrowser(FileBrowserMsg::Pick))),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_browser::{FileBrowserEntry, FileBrowserTarget};
+    use crate::views::testing::ViewHarness;
+
+    fn error(criticality: ErrorCriticality) -> app::ErrorReport {
+        app::ErrorReport {
+            criticality,
+            short: "Connecting failed".to_string(),
+            detailed: "transport error".to_string(),
+            retry: None,
+        }
+    }
+
+    #[test]
+    fn file_browser() {
+        let mut harness = ViewHarness::new();
+        let mut browser = FileBrowser::new(FileBrowserTarget::LgEnvFile, "/lab/envs".into());
+        browser.entries = Some(vec![
+            FileBrowserEntry {
+                name: "archive".to_string(),
+                is_dir: true,
+            },
+            FileBrowserEntry {
+                name: "board-1.yaml".to_string(),
+                is_dir: false,
+            },
+        ]);
+        browser.selected = Some("board-1.yaml".to_string());
+        insta::assert_snapshot!(harness.snapshot(view_file_browser(&browser)), @r#"
+            container
+              container
+                container
+                  text "Choose File"
+                  container
+                    text "\u{f62a}"
+                container
+                  container
+                    container
+                      text "\u{f148}"
+                  text_input "/lab/envs"
+                scrollable
+                  container
+                    container
+                      container
+                        text "\u{f3d7}"
+                        text "archive"
+                    container
+                      container
+                        text "\u{f392}"
+                        text "board-1.yaml"
+                container
+                  text "/lab/envs/board-1.yaml"
+                  container
+                    text "Cancel"
+                  container
+                    text "Choose"
+            "#);
+    }
+
+    #[test]
+    fn error_banner() {
+        let mut harness = ViewHarness::new();
+        let error = error(ErrorCriticality::Critical);
+        insta::assert_snapshot!(harness.snapshot(view_error(&error, false)), @r#"
+            container
+              container
+                container
+                  text "Critical Error : Connecting failed"
+                  container
+                    text "\u{f62a}"
+                scrollable
+                  text "transport error"
+            "#);
+    }
+
+    #[test]
+    fn error_banner_noncritical() {
+        let mut harness = ViewHarness::new();
+        let error = error(ErrorCriticality::NonCritical);
+        insta::assert_snapshot!(harness.snapshot(view_error(&error, false)), @r#"
+            container
+              container
+                container
+                  text "Non-Critical Error : Connecting failed"
+                  container
+                    text "\u{f62a}"
+                scrollable
+                  text "transport error"
+            "#);
+    }
+
+    #[test]
+    fn error_banner_stacked() {
+        let mut harness = ViewHarness::new();
+        let errors = [
+            error(ErrorCriticality::NonCritical),
+            error(ErrorCriticality::Critical),
+        ];
+        insta::assert_snapshot!(harness.snapshot(view_errors(errors.iter(), false)), @r#"
+            container
+              container
+              container
+                container
+                  container
+                    text "Critical Error : Connecting failed"
+                    container
+                      text "\u{f62a}"
+                  scrollable
+                    text "transport error"
+            "#);
+    }
+
+    #[test]
+    fn error_banner_retry_messages() {
+        let mut harness = ViewHarness::new();
+        let mut error = error(ErrorCriticality::NonCritical);
+        error.retry = Some(Box::new(AppMsg::SaveConfig));
+        insta::assert_debug_snapshot!(harness.click(view_error(&error, false), "Retry"), @r"
+            [
+                RetryError,
+            ]
+            ");
+    }
+
+    #[test]
+    fn confirmation_modal_messages() {
+        let mut harness = ViewHarness::new();
+        let confirm = || view_confirmation_modal("Delete place?", AppMsg::SaveConfig, false);
+        insta::assert_debug_snapshot!(harness.click(confirm(), "Confirm"), @r"
+            [
+                WithHideModal(
+                    SaveConfig,
+                ),
+            ]
+            ");
+        insta::assert_debug_snapshot!(harness.click(confirm(), "Cancel"), @r"
+            [
+                HideModal,
+            ]
+            ");
+    }
+}
@@ -8,6 +8,8 @@ pub(crate) mod connecting;
 pub(crate) mod generic;
 pub(crate) mod notconnected;
 pub(crate) mod settings;
+#[cfg(test)]
+pub(crate) mod testing;
 
 // Re-Exports
 //pub(crate) use connected::*;
@@ -17,14 +19,25 @@ pub(crate) mod settings;
 //pub(crate) use settings::*;
 
 // Imports
-use crate::app::{App, AppMsg, AppState, Modal};
-use connected::{view_app_connected, view_place_details};
+use crate::app::{App, AppConnected, AppMsg, AppState, Modal};
+use crate::protocol_inspector;
+use crate::resource_classes::ClassSchema;
+use crate::usb_topology::UsbTopology;
+use connected::{
+    view_acquire_with_reason_modal, view_app_connected, view_assign_resource,
+    view_cancel_foreign_reservation_modal, view_cleanup_reservations, view_compare_places_modal,
+    view_create_reservation, view_force_release_modal, view_place_clone_modal, view_place_details,
+    view_quick_switcher, view_resource_class, view_restore_places_modal,
+    view_script_display_name_modal, view_usb_topology,
+};
 use connecting::view_app_connecting;
-use generic::{modal, view_confirmation_modal, view_errors};
+use generic::{modal, view_confirmation_modal, view_error_console, view_errors, view_file_browser};
 use iced::widget::{column, container};
 use iced::{Element, Length};
 use notconnected::view_app_not_connected;
-use settings::view_settings;
+use settings::{
+    view_create_venv, view_log_viewer, view_profiles, view_protocol_inspector, view_settings,
+};
 use tracing::error;
 
 /// The maximum width for the all base UI element and all modals
@@ -38,21 +51,54 @@ pub(crate) const NONE_STR: Option<&'static str> = None::<&'static str>;
 /// View for the entire application
 pub(crate) fn view_app(app: &App) -> Element<'_, AppMsg> {
     let state_content = match &app.state {
-        AppState::NotConnected(not_connected) => view_app_not_connected(not_connected),
+        AppState::NotConnected(not_connected) => view_app_not_connected(app, not_connected),
         AppState::Connecting { address } => view_app_connecting(address),
-        AppState::Connected(connected) => view_app_connected(connected, app.optimize_touch),
+        AppState::Connected(connected) => view_app_connected(app, connected),
     };
     let content = container(column![
         state_content,
-        view_errors(app.errors.iter(), app.optimize_touch)
+        view_errors(app.errors.stack().into_iter(), app.optimize_touch)
     ])
     .width(Length::Fill)
     .height(Length::Fill)
     .padding(6);
 
-    match &app.modal {
-        Modal::None => content.into(),
+    let content = view_modal(app, content.into(), app.modal.base());
+    match app.modal.overlay() {
+        Some(overlay) => view_modal(app, content, overlay),
+        None => content,
+    }
+}
+
+/// View for the supplied modal on top of the content.
+fn view_modal<'a>(
+    app: &'a App,
+    content: Element<'a, AppMsg>,
+    shown: &'a Modal,
+) -> Element<'a, AppMsg> {
+    match shown {
+        Modal::None => content,
         Modal::Settings => modal(content, view_settings(app), AppMsg::HideModal),
+        Modal::ErrorConsole => modal(
+            content,
+            view_error_console(&app.errors, app.optimize_touch),
+            AppMsg::HideModal,
+        ),
+        Modal::LogViewer => modal(
+            content,
+            view_log_viewer(&app.log_entries, app.log_level, app.optimize_touch),
+            AppMsg::HideModal,
+        ),
+        Modal::ProtocolInspector => modal(
+            content,
+            view_protocol_inspector(
+                &app.protocol_messages,
+                &app.protocol_filter,
+                protocol_inspector::PROTOCOL_TRACE.is_enabled(),
+                app.optimize_touch,
+            ),
+            AppMsg::HideModal,
+        ),
         Modal::PlaceDetails { place_name } => {
             if let AppState::Connected(connected) = &app.state {
                 if let Some((place, ui)) = connected.place_by_name(place_name) {
@@ -62,7 +108,16 @@ pub(crate) fn view_app(app: &App) -> Element<'_, AppMsg> {
                             place,
                             ui,
                             app.optimize_touch,
-                            &connected.add_place_match_text,
+                            &connected.add_place_match,
+                            &connected.add_place_match_rename_text,
+                            &connected.resources,
+                            &connected.add_place_alias_text,
+                            connected.place_details_notice.as_deref(),
+                            &app.place_activity,
+                            place
+                                .acquired
+                                .as_ref()
+                                .and_then(|owner| app.card_palette.owner_color(owner)),
                         ),
                         AppMsg::HideModal,
                     )
@@ -70,17 +125,232 @@ pub(crate) fn view_app(app: &App) -> Element<'_, AppMsg> {
                     error!(
                         "Can't show place details modal, place with name '{place_name}' not found"
                     );
-                    content.into()
+                    content
                 }
             } else {
                 error!("Can't show place details modal, not connected");
-                content.into()
+                content
+            }
+        }
+        Modal::CreateReservation => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_create_reservation(
+                        &connected.places,
+                        &connected.create_reservation_filter_text,
+                        &connected.create_reservation_prio_text,
+                        app.optimize_touch,
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show create reservation modal, not connected");
+                content
             }
         }
+        Modal::CleanupReservations => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_cleanup_reservations(
+                        connected.reservations_to_clean_up(),
+                        connected.reservation_cleanup,
+                        app.optimize_touch,
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show clean up reservations modal, not connected");
+                content
+            }
+        }
+        Modal::AssignResource { path, cls } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_assign_resource(&connected.places, path, cls, app.optimize_touch),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show assign resource modal, not connected");
+                content
+            }
+        }
+        Modal::ResourceClass { cls } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_resource_class(
+                        cls,
+                        ClassSchema::observe(cls, connected.resources.iter().map(|(r, _)| r)),
+                        app.resource_classes.get(cls),
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show resource class modal, not connected");
+                content
+            }
+        }
+        Modal::UsbTopology { exporter } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_usb_topology(
+                        exporter,
+                        UsbTopology::of_exporter(
+                            connected.resources.iter().map(|(r, _)| r),
+                            exporter,
+                        ),
+                        &app.resource_classes,
+                        app.optimize_touch,
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show USB topology modal, not connected");
+                content
+            }
+        }
+        Modal::QuickSwitcher { .. } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_quick_switcher(
+                        &connected.quick_switcher,
+                        connected.quick_switcher_items(),
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show quick switcher modal, not connected");
+                content
+            }
+        }
+        Modal::CreateVenv => modal(
+            content,
+            view_create_venv(&app.venv_wizard),
+            AppMsg::HideModal,
+        ),
+        Modal::Profiles { editing } => {
+            modal(content, view_profiles(app, *editing), AppMsg::HideModal)
+        }
+        Modal::FileBrowser => match &app.file_browser {
+            Some(browser) => modal(content, view_file_browser(browser), AppMsg::HideModal),
+            None => {
+                error!("Can't show file browser modal, no file browser state");
+                content
+            }
+        },
         Modal::Confirmation { msg, confirm } => modal(
             content,
-            view_confirmation_modal(msg, confirm.clone()),
+            view_confirmation_modal(msg, confirm.clone(), app.optimize_touch),
             AppMsg::HideModal,
         ),
+        Modal::ForceRelease { place_name, owner } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_force_release_modal(
+                        place_name,
+                        owner,
+                        &connected.force_release_confirm_text,
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show force release modal, not connected");
+                content
+            }
+        }
+        Modal::CancelForeignReservation { token, owner } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_cancel_foreign_reservation_modal(
+                        token,
+                        owner,
+                        &connected.cancel_reservation_confirm_text,
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show cancel reservation modal, not connected");
+                content
+            }
+        }
+        Modal::ClonePlace { .. } => match &app.state {
+            AppState::Connected(AppConnected {
+                place_clone: Some(place_clone),
+                places,
+                ..
+            }) => modal(
+                content,
+                view_place_clone_modal(place_clone, places),
+                AppMsg::HideModal,
+            ),
+            _ => {
+                error!("Can't show clone place modal, place not found or not connected");
+                content
+            }
+        },
+        Modal::RestorePlaces => match &app.state {
+            AppState::Connected(AppConnected {
+                place_snapshot: Some(file),
+                places,
+                ..
+            }) => modal(
+                content,
+                view_restore_places_modal(file, places),
+                AppMsg::HideModal,
+            ),
+            _ => {
+                error!("Can't show restore places modal, no snapshot loaded or not connected");
+                content
+            }
+        },
+        Modal::ComparePlaces => match &app.state {
+            AppState::Connected(AppConnected {
+                place_snapshot: Some(file),
+                places,
+                ..
+            }) => modal(
+                content,
+                view_compare_places_modal(file, places),
+                AppMsg::HideModal,
+            ),
+            _ => {
+                error!("Can't show compare places modal, no snapshot loaded or not connected");
+                content
+            }
+        },
+        Modal::ScriptDisplayName { script_name } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_script_display_name_modal(
+                        script_name,
+                        &connected.script_display_name_text,
+                    ),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show script display name modal, not connected");
+                content
+            }
+        }
+        Modal::AcquireWithReason { place_name } => {
+            if let AppState::Connected(connected) = &app.state {
+                modal(
+                    content,
+                    view_acquire_with_reason_modal(place_name, &connected.acquire_reason_text),
+                    AppMsg::HideModal,
+                )
+            } else {
+                error!("Can't show acquire with reason modal, not connected");
+                content
+            }
+        }
     }
 }
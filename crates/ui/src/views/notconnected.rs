@@ -2,20 +2,40 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::generic::card_container_style;
-use crate::app::{AppMsg, AppNotConnected, Modal, NotConnectedMsg};
+use super::generic::{card_container_style, view_error_console_button, view_text_tooltip};
+use crate::app::{App, AppMsg, AppNotConnected, Modal, NotConnectedMsg};
 use crate::i18n::fl;
-use iced::widget::{button, container, row, text, text_input};
+use iced::widget::{button, container, pick_list, row, text, text_input};
 use iced::{Alignment, Element, Length};
 use iced_fonts::bootstrap;
 
 /// View for the UI when in state [crate::app::AppState::NotConnected]
-pub(crate) fn view_app_not_connected(not_connected: &AppNotConnected) -> Element<'_, AppMsg> {
+pub(crate) fn view_app_not_connected<'a>(
+    app: &'a App,
+    not_connected: &'a AppNotConnected,
+) -> Element<'a, AppMsg> {
+    let profile_names = app
+        .profiles
+        .iter()
+        .map(|p| p.name.clone())
+        .collect::<Vec<_>>();
+    let clear_profile_button = app.selected_profile.is_some().then(|| {
+        view_text_tooltip(
+            button(bootstrap::x()).on_press(AppMsg::SelectProfile(None)),
+            fl!("profile-clear-tooltip"),
+        )
+    });
+
     container(
         row![
             container(
                 row![
                     bootstrap::ban(),
+                    pick_list(profile_names, app.selected_profile.clone(), |name| {
+                        AppMsg::SelectProfile(Some(name))
+                    })
+                    .placeholder(fl!("profile-select-placeholder")),
+                    clear_profile_button,
                     text_input(
                         fl!("coordinator-address-placeholder").as_str(),
                         not_connected.input_address.as_str()
@@ -34,8 +54,15 @@ pub(crate) fn view_app_not_connected(not_connected: &AppNotConnected) -> Element
             .padding(6)
             .style(card_container_style),
             container(
-                button(text(fl!("settings-button")))
-                    .on_press(AppMsg::ShowModal(Box::new(Modal::Settings)))
+                row![
+                    button(text(fl!("profiles-button"))).on_press(AppMsg::ShowModal(Box::new(
+                        Modal::Profiles { editing: None }
+                    ))),
+                    button(text(fl!("settings-button")))
+                        .on_press(AppMsg::ShowModal(Box::new(Modal::Settings))),
+                    view_error_console_button(&app.errors),
+                ]
+                .spacing(6)
             )
             .padding(6)
         ]
@@ -2,14 +2,71 @@
 //
 // SPDX-License-Identifier: GPL-3.0-or-later
 
-use super::generic::{modal_container_style, view_text_tooltip};
+use super::generic::{modal_container_style, optimized_scrollbar_properties, view_text_tooltip};
 use super::UI_MAX_WIDTH;
-use crate::app::{App, AppMsg, ConnectedMsg};
+use crate::app::{
+    App, AppMsg, AppState, LogViewerMsg, Modal, ProtocolInspectorMsg, VenvWizardMsg,
+    FONT_INCONSOLATA,
+};
+use crate::config;
+use crate::connection::{
+    AuthTokenKind, ConnectionAuth, ConnectionTimeouts, SubscriptionScope, TimeoutPreset,
+};
+use crate::exporters::OfflineExporterResources;
+use crate::file_browser::FileBrowserTarget;
 use crate::i18n::{fl, AppLanguage};
+use crate::log_viewer::{LogEntry, LogLevel};
+use crate::mqtt::MqttSettings;
+use crate::notifications::{NotificationEvent, NotificationSettings};
+use crate::palette::{self, CardPalette, PlaceCardState};
+use crate::profiles::ConnectionProfile;
+use crate::protocol_inspector;
+use crate::quick_actions::{QuickAction, QuickActionSettings};
+use crate::session_timer::AutoReleaseSettings;
 use crate::util;
-use iced::widget::{button, column, container, pick_list, row, rule, space, text, toggler};
+use crate::venv::{VenvProblem, VenvProgress, VenvStep, VenvWizard};
+use iced::widget::{
+    button, column, container, pick_list, row, rule, scrollable, slider, space, text, text_input,
+    toggler,
+};
 use iced::{padding, Alignment, Element, Length};
 use iced_fonts::bootstrap;
+use labgrid_ui_core::mqtt::MqttStatus;
+use labgrid_ui_core::trace::{Direction, TracedMessage};
+use labgrid_ui_core::version;
+use std::path::Path;
+use tracing::Level;
+
+/// View for the settings of the periodic configuration save, with the time of the last save.
+fn view_autosave_settings(app: &App) -> Element<'_, AppMsg> {
+    column![
+        view_settings_row(
+            fl!("settings-autosave-label"),
+            row![
+                text(fl!("settings-autosave-value", secs = app.autosave_secs)),
+                slider(0..=600, app.autosave_secs, AppMsg::ChangeAutosave)
+                    .step(30u32)
+                    .width(200)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(12)
+        ),
+        view_settings_row(
+            app.last_config_save.map_or_else(
+                || fl!("settings-last-save-never"),
+                |time| fl!(
+                    "settings-last-save-label",
+                    time = time.format("%H:%M:%S").to_string()
+                )
+            ),
+            button(text(fl!("settings-save-button")))
+                .style(button::secondary)
+                .on_press(AppMsg::SaveSettings)
+        ),
+    ]
+    .spacing(6)
+    .into()
+}
 
 /// View for a single settings row.
 ///
@@ -25,18 +82,971 @@ pub(crate) fn view_settings_row<'a>(
         .into()
 }
 
+/// View for the versions detected in the venv, warning if labgrid can't reach the coordinator.
+fn view_venv_versions(app: &App) -> Element<'_, AppMsg> {
+    let (versions, warning) = match &app.venv_versions {
+        None => (fl!("settings-venv-versions-detecting"), None),
+        Some(Err(error)) => (
+            fl!("settings-venv-versions-failed"),
+            Some(fl!("settings-venv-versions-error", error = error.as_str())),
+        ),
+        Some(Ok(versions)) => match &versions.labgrid {
+            None => (
+                fl!(
+                    "settings-venv-versions-no-labgrid",
+                    python = versions.python.as_str()
+                ),
+                Some(fl!("settings-venv-labgrid-missing-msg")),
+            ),
+            Some(labgrid) => (
+                fl!(
+                    "settings-venv-versions-value",
+                    python = versions.python.as_str(),
+                    labgrid = labgrid.as_str()
+                ),
+                (matches!(app.state, AppState::Connected(_)) && !versions.grpc_compatible()).then(
+                    || {
+                        fl!(
+                            "settings-venv-incompatible-msg",
+                            labgrid = labgrid.as_str(),
+                            min = version::MIN_GRPC_MAJOR
+                        )
+                    },
+                ),
+            ),
+        },
+    };
+    column![view_settings_row(
+        fl!("settings-venv-versions-label"),
+        text(versions)
+    )]
+    .push(warning.map(|warning| container(text(warning).style(text::danger)).padding(6)))
+    .into()
+}
+
+/// View for the settings rows of the place card colors.
+pub(crate) fn view_card_palette_settings(card_palette: &CardPalette) -> Element<'_, AppMsg> {
+    let identity_colors_row = view_settings_row(
+        fl!("settings-identity-colors-label"),
+        view_text_tooltip(
+            toggler(card_palette.identity_colors).on_toggle(AppMsg::ChangeIdentityColors),
+            fl!("settings-identity-colors-tooltip"),
+        ),
+    );
+    column(PlaceCardState::ALL.into_iter().map(|state| {
+        let label = match state {
+            PlaceCardState::Free => fl!("settings-card-color-free-label"),
+            PlaceCardState::Mine => fl!("settings-card-color-mine-label"),
+            PlaceCardState::Others => fl!("settings-card-color-others-label"),
+            PlaceCardState::Reserved => fl!("settings-card-color-reserved-label"),
+            PlaceCardState::Maintenance => fl!("settings-card-color-maintenance-label"),
+        };
+        let hex = card_palette.hex(state);
+        let valid = palette::parse_hex(hex).is_some();
+        let color = card_palette.color(state);
+        view_settings_row(
+            label,
+            row![
+                container(space::horizontal())
+                    .style(move |_| container::Style::default().background(color))
+                    .width(24)
+                    .height(24),
+                text_input(CardPalette::default_hex(state), hex)
+                    .on_input(move |hex| AppMsg::ChangeCardColor { state, hex })
+                    .style(move |theme, status| {
+                        let mut s = text_input::default(theme, status);
+                        if !valid {
+                            s.border.color = theme.palette().danger;
+                        }
+                        s
+                    })
+                    .width(100),
+                view_text_tooltip(
+                    button(bootstrap::backspace()).on_press(AppMsg::ChangeCardColor {
+                        state,
+                        hex: CardPalette::default_hex(state).to_string()
+                    }),
+                    fl!("settings-card-color-reset-tooltip")
+                ),
+            ]
+            .align_y(Alignment::Center)
+            .spacing(6),
+        )
+    }))
+    .push(identity_colors_row)
+    .into()
+}
+
+/// View for the settings rows toggling desktop notifications.
+pub(crate) fn view_notification_settings(settings: &NotificationSettings) -> Element<'_, AppMsg> {
+    column(
+        [
+            (
+                NotificationEvent::PlaceReleased,
+                fl!("settings-notification-place-released-label"),
+            ),
+            (
+                NotificationEvent::ReservationAllocated,
+                fl!("settings-notification-reservation-allocated-label"),
+            ),
+            (
+                NotificationEvent::ScriptFinished,
+                fl!("settings-notification-script-finished-label"),
+            ),
+            (
+                NotificationEvent::AutoReleaseWarning,
+                fl!("settings-notification-auto-release-warning-label"),
+            ),
+            (
+                NotificationEvent::ResourceAvailabilityChanged,
+                fl!("settings-notification-resource-availability-label"),
+            ),
+        ]
+        .into_iter()
+        .map(|(event, label)| {
+            view_settings_row(
+                label,
+                toggler(settings.enabled(event))
+                    .on_toggle(move |enabled| AppMsg::ChangeNotification { event, enabled }),
+            )
+        }),
+    )
+    .into()
+}
+
+/// View for the settings rows choosing the quick actions shown on the place cards.
+///
+/// `script_names` are the file names of the scripts that can be picked as favorite script.
+pub(crate) fn view_quick_action_settings(
+    settings: &QuickActionSettings,
+    script_names: Vec<String>,
+) -> Element<'_, AppMsg> {
+    column(
+        QuickAction::ALL
+            .into_iter()
+            .map(|action| {
+                let label = match action {
+                    QuickAction::Acquire => fl!("settings-quick-action-acquire-label"),
+                    QuickAction::PowerCycle => fl!("settings-quick-action-power-cycle-label"),
+                    QuickAction::Console => fl!("settings-quick-action-console-label"),
+                    QuickAction::FavoriteScript => {
+                        fl!("settings-quick-action-favorite-script-label")
+                    }
+                };
+                view_settings_row(
+                    label,
+                    toggler(settings.enabled(action))
+                        .on_toggle(move |enabled| AppMsg::ChangeQuickAction { action, enabled }),
+                )
+            })
+            .chain(settings.favorite_script.then(|| {
+                view_settings_row(
+                    fl!("settings-quick-action-favorite-script-pick-label"),
+                    pick_list(
+                        script_names,
+                        settings.favorite_script_name.clone(),
+                        AppMsg::ChangeFavoriteScript,
+                    )
+                    .placeholder(fl!("settings-quick-action-favorite-script-placeholder")),
+                )
+            })),
+    )
+    .into()
+}
+
+/// View for the settings rows of automatically releasing idle places.
+pub(crate) fn view_auto_release_settings(
+    settings: AutoReleaseSettings,
+) -> Element<'static, AppMsg> {
+    let minutes_slider = |label: String,
+                          range: std::ops::RangeInclusive<u32>,
+                          value: u32,
+                          set: fn(&mut AutoReleaseSettings, u32)| {
+        view_settings_row(
+            label,
+            row![
+                text(fl!("settings-auto-release-minutes-value", minutes = value)),
+                slider(range, value, move |value| {
+                    let mut settings = settings;
+                    set(&mut settings, value);
+                    AppMsg::ChangeAutoRelease(settings)
+                })
+                .width(200)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(12),
+        )
+    };
+
+    column![
+        view_settings_row(
+            fl!("settings-auto-release-label"),
+            toggler(settings.enabled).on_toggle(move |enabled| {
+                AppMsg::ChangeAutoRelease(AutoReleaseSettings {
+                    enabled,
+                    ..settings
+                })
+            })
+        ),
+        settings.enabled.then(|| minutes_slider(
+            fl!("settings-auto-release-idle-label"),
+            5..=480,
+            settings.idle_mins,
+            |s, v| s.idle_mins = v
+        )),
+        settings.enabled.then(|| minutes_slider(
+            fl!("settings-auto-release-warn-label"),
+            1..=30,
+            settings.warn_mins,
+            |s, v| s.warn_mins = v
+        )),
+        view_settings_row(
+            fl!("settings-release-on-exit-label"),
+            toggler(settings.release_on_exit).on_toggle(move |release_on_exit| {
+                AppMsg::ChangeAutoRelease(AutoReleaseSettings {
+                    release_on_exit,
+                    ..settings
+                })
+            })
+        ),
+    ]
+    .into()
+}
+
+/// View for the settings rows of the coordinator connection timeouts.
+///
+/// The presets set all timeouts at once, adjusting a single one makes them custom.
+pub(crate) fn view_connection_timeout_settings(
+    timeouts: ConnectionTimeouts,
+) -> Element<'static, AppMsg> {
+    let secs_slider = |label: String,
+                       range: std::ops::RangeInclusive<u32>,
+                       value: u32,
+                       set: fn(&mut ConnectionTimeouts, u32)| {
+        view_settings_row(
+            label,
+            row![
+                text(fl!("settings-timeout-secs-value", secs = value)),
+                slider(range, value, move |value| {
+                    let mut timeouts = timeouts;
+                    set(&mut timeouts, value);
+                    AppMsg::ChangeConnectionTimeouts(timeouts)
+                })
+                .width(200)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(12),
+        )
+    };
+
+    column![
+        view_settings_row(
+            fl!("settings-timeout-preset-label"),
+            pick_list(TimeoutPreset::ALL, timeouts.preset(), |preset| {
+                AppMsg::ChangeConnectionTimeouts(preset.timeouts())
+            })
+            .placeholder(fl!("settings-timeout-preset-custom")),
+        ),
+        secs_slider(
+            fl!("settings-timeout-connect-label"),
+            1..=120,
+            timeouts.connect_secs,
+            |t, v| t.connect_secs = v
+        ),
+        secs_slider(
+            fl!("settings-timeout-stream-label"),
+            1..=300,
+            timeouts.stream_secs,
+            |t, v| t.stream_secs = v
+        ),
+        secs_slider(
+            fl!("settings-timeout-rpc-label"),
+            1..=300,
+            timeouts.rpc_secs,
+            |t, v| t.rpc_secs = v
+        ),
+        secs_slider(
+            fl!("settings-keepalive-label"),
+            0..=120,
+            timeouts.keepalive_secs,
+            |t, v| t.keepalive_secs = v
+        ),
+        secs_slider(
+            fl!("settings-reservations-poll-label"),
+            5..=300,
+            timeouts.reservations_poll_secs,
+            |t, v| t.reservations_poll_secs = v
+        ),
+    ]
+    .into()
+}
+
+/// View for the settings rows of the authentication towards a proxy in front of the coordinator.
+///
+/// Changes are applied when connecting the next time.
+pub(crate) fn view_connection_auth_settings(auth: &ConnectionAuth) -> Element<'_, AppMsg> {
+    let change = |set: fn(&mut ConnectionAuth, String)| {
+        move |value: String| {
+            let mut auth = auth.clone();
+            set(&mut auth, value);
+            AppMsg::ChangeConnectionAuth(auth)
+        }
+    };
+    let token_row = match auth.token_kind {
+        AuthTokenKind::None => None,
+        AuthTokenKind::Static => Some(view_settings_row(
+            fl!("settings-auth-token-static-label"),
+            text_input("", &auth.token)
+                .secure(true)
+                .on_input(change(|a, v| a.token = v))
+                .width(200),
+        )),
+        AuthTokenKind::File => Some(view_settings_row(
+            fl!("settings-auth-token-file-label"),
+            text_input("/run/secrets/labgrid-token", &auth.token)
+                .on_input(change(|a, v| a.token = v))
+                .width(200),
+        )),
+        AuthTokenKind::Command => Some(view_settings_row(
+            fl!("settings-auth-token-command-label"),
+            text_input("", &auth.token)
+                .on_input(change(|a, v| a.token = v))
+                .width(200),
+        )),
+    };
+
+    column![
+        view_settings_row(
+            fl!("settings-auth-token-label"),
+            pick_list(AuthTokenKind::ALL, Some(auth.token_kind), |token_kind| {
+                AppMsg::ChangeConnectionAuth(ConnectionAuth {
+                    token_kind,
+                    ..auth.clone()
+                })
+            }),
+        ),
+        token_row,
+        (auth.token_kind == AuthTokenKind::Command).then(|| view_settings_row(
+            fl!("settings-auth-token-refresh-label"),
+            row![
+                text(fl!(
+                    "settings-timeout-secs-value",
+                    secs = auth.token_refresh_secs
+                )),
+                slider(10..=3600, auth.token_refresh_secs, |token_refresh_secs| {
+                    AppMsg::ChangeConnectionAuth(ConnectionAuth {
+                        token_refresh_secs,
+                        ..auth.clone()
+                    })
+                })
+                .step(10u32)
+                .width(200)
+            ]
+            .align_y(Alignment::Center)
+            .spacing(12),
+        )),
+        view_settings_row(
+            fl!("settings-auth-metadata-label"),
+            view_text_tooltip(
+                text_input("x-api-key=...", &auth.metadata)
+                    .secure(true)
+                    .on_input(change(|a, v| a.metadata = v))
+                    .width(200),
+                fl!("settings-auth-metadata-tooltip"),
+            ),
+        ),
+    ]
+    .into()
+}
+
+/// View for the settings rows of the MQTT bridge.
+///
+/// Enabling and disabling it applies right away, other changes are applied through the apply button.
+pub(crate) fn view_mqtt_settings(app: &App) -> Element<'_, AppMsg> {
+    let settings = &app.mqtt_settings;
+    let change = |set: fn(&mut MqttSettings, String)| {
+        move |value: String| {
+            let mut settings = settings.clone();
+            set(&mut settings, value);
+            AppMsg::ChangeMqttSettings(settings)
+        }
+    };
+    let status = app.mqtt_status.as_ref().map(|status| match status {
+        MqttStatus::Connected => text(fl!("settings-mqtt-status-connected")),
+        MqttStatus::Disconnected { error } => text(fl!(
+            "settings-mqtt-status-disconnected",
+            error = error.clone()
+        ))
+        .style(text::danger),
+        _ => text(fl!("settings-mqtt-status-connecting")),
+    });
+    let apply_button = app
+        .mqtt_applied
+        .as_ref()
+        .is_some_and(|(applied, _)| applied != settings)
+        .then(|| {
+            view_text_tooltip(
+                button(text(fl!("settings-mqtt-apply-button"))).on_press(AppMsg::ApplyMqttSettings),
+                fl!("settings-mqtt-apply-tooltip"),
+            )
+        });
+    let text_row = |label: String, placeholder: &str, value: &str, set| {
+        view_settings_row(
+            label,
+            text_input(placeholder, value)
+                .on_input(change(set))
+                .width(200),
+        )
+    };
+
+    column![
+        view_settings_row(
+            fl!("settings-mqtt-label"),
+            row![
+                status,
+                apply_button,
+                toggler(settings.enabled).on_toggle(|enabled| {
+                    AppMsg::ChangeMqttSettings(MqttSettings {
+                        enabled,
+                        ..settings.clone()
+                    })
+                })
+            ]
+            .spacing(12)
+            .align_y(Alignment::Center)
+        ),
+        settings.enabled.then(|| text_row(
+            fl!("settings-mqtt-broker-label"),
+            "localhost:1883",
+            &settings.broker,
+            |s, v| s.broker = v
+        )),
+        settings.enabled.then(|| text_row(
+            fl!("settings-mqtt-topic-prefix-label"),
+            "labgrid",
+            &settings.topic_prefix,
+            |s, v| s.topic_prefix = v
+        )),
+        settings.enabled.then(|| text_row(
+            fl!("settings-mqtt-username-label"),
+            "",
+            &settings.username,
+            |s, v| s.username = v
+        )),
+        settings.enabled.then(|| view_settings_row(
+            fl!("settings-mqtt-password-label"),
+            text_input("", &settings.password)
+                .secure(true)
+                .on_input(change(|s, v| s.password = v))
+                .width(200)
+        )),
+    ]
+    .into()
+}
+
+/// View for the settings rows of the labgrid identity and its overrides.
+///
+/// Offers to reconnect when the identity of the current connection differs from the configured one.
+pub(crate) fn view_identity_settings(app: &App) -> Element<'_, AppMsg> {
+    let identity = app.lg_identity();
+    let reconnect_button = match &app.state {
+        AppState::Connected(connected) if connected.identity != identity => {
+            Some(view_text_tooltip(
+                button(text(fl!("settings-identity-reconnect-button"))).on_press(AppMsg::Reconnect),
+                fl!(
+                    "settings-identity-reconnect-tooltip",
+                    identity = connected.identity.clone()
+                ),
+            ))
+        }
+        _ => None,
+    };
+
+    column![
+        view_settings_row(
+            fl!("settings-identity-label"),
+            row![
+                text(identity.clone()),
+                view_text_tooltip(
+                    button(bootstrap::copy()).on_press(AppMsg::ClipboardCopy(identity)),
+                    fl!("clipboard-copy-tooltip")
+                ),
+                reconnect_button,
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center)
+        ),
+        view_settings_row(
+            fl!("settings-identity-hostname-label"),
+            text_input(&util::get_lg_hostname(), &app.identity_override.hostname)
+                .on_input(AppMsg::ChangeIdentityHostname)
+                .width(200)
+        ),
+        view_settings_row(
+            fl!("settings-identity-username-label"),
+            text_input(&util::get_lg_username(), &app.identity_override.username)
+                .on_input(AppMsg::ChangeIdentityUsername)
+                .width(200)
+        ),
+    ]
+    .into()
+}
+
+/// View for the form editing a single connection profile.
+fn view_profile_form(index: usize, profile: &ConnectionProfile) -> Element<'_, AppMsg> {
+    let change = move |profile: ConnectionProfile| AppMsg::ChangeProfile { index, profile };
+
+    column![
+        view_settings_row(
+            fl!("profile-name-label"),
+            text_input("", &profile.name)
+                .on_input(move |name| change(ConnectionProfile {
+                    name,
+                    ..profile.clone()
+                }))
+                .width(300)
+        ),
+        view_settings_row(
+            fl!("profile-address-label"),
+            text_input(
+                fl!("coordinator-address-placeholder").as_str(),
+                &profile.address
+            )
+            .on_input(move |address| change(ConnectionProfile {
+                address,
+                ..profile.clone()
+            }))
+            .width(300)
+        ),
+        view_settings_row(
+            fl!("settings-identity-hostname-label"),
+            text_input(&util::get_lg_hostname(), &profile.identity.hostname)
+                .on_input(move |hostname| {
+                    let mut profile = profile.clone();
+                    profile.identity.hostname = hostname;
+                    change(profile)
+                })
+                .width(300)
+        ),
+        view_settings_row(
+            fl!("settings-identity-username-label"),
+            text_input(&util::get_lg_username(), &profile.identity.username)
+                .on_input(move |username| {
+                    let mut profile = profile.clone();
+                    profile.identity.username = username;
+                    change(profile)
+                })
+                .width(300)
+        ),
+        view_settings_row(
+            fl!("profile-tls-label"),
+            toggler(profile.tls).on_toggle(move |tls| change(ConnectionProfile {
+                tls,
+                ..profile.clone()
+            }))
+        ),
+        view_settings_row(
+            fl!("profile-scope-label"),
+            pick_list(SubscriptionScope::ALL, Some(profile.scope), move |scope| {
+                change(ConnectionProfile {
+                    scope,
+                    ..profile.clone()
+                })
+            })
+        ),
+        view_settings_row(
+            fl!("profile-places-only-mine-label"),
+            toggler(profile.filters.places_only_mine).on_toggle(move |places_only_mine| {
+                let mut profile = profile.clone();
+                profile.filters.places_only_mine = places_only_mine;
+                change(profile)
+            })
+        ),
+        view_settings_row(
+            fl!("profile-resources-only-available-label"),
+            toggler(profile.filters.resources_only_available).on_toggle(
+                move |resources_only_available| {
+                    let mut profile = profile.clone();
+                    profile.filters.resources_only_available = resources_only_available;
+                    change(profile)
+                }
+            )
+        ),
+        view_settings_row(
+            "",
+            button(text(fl!("profile-delete-button")))
+                .style(button::danger)
+                .on_press(AppMsg::DeleteProfile(index))
+        ),
+    ]
+    .into()
+}
+
+/// The wizard creating a virtual environment with labgrid installed.
+pub(crate) fn view_create_venv(wizard: &VenvWizard) -> Element<'_, AppMsg> {
+    let editable = !wizard.is_running();
+    let problem = wizard.problem().map(|problem| match problem {
+        VenvProblem::InvalidDir => fl!("venv-wizard-invalid-dir-msg"),
+        VenvProblem::DirNotEmpty => fl!("venv-wizard-dir-not-empty-msg"),
+        VenvProblem::InvalidVersion => fl!("venv-wizard-invalid-version-msg"),
+    });
+    let steps = column(
+        [
+            (VenvStep::Create, fl!("venv-wizard-step-create")),
+            (VenvStep::InstallLabgrid, fl!("venv-wizard-step-install")),
+            (VenvStep::Validate, fl!("venv-wizard-step-validate")),
+        ]
+        .into_iter()
+        .map(|(step, label)| {
+            let order = |step: VenvStep| step as u8;
+            let icon = match &wizard.progress {
+                VenvProgress::Editing => bootstrap::circle(),
+                VenvProgress::Done(_) => bootstrap::check_circle(),
+                VenvProgress::Running(running) if *running == step => bootstrap::hourglass_split(),
+                VenvProgress::Failed { step: failed, .. } if *failed == step => {
+                    bootstrap::x_circle()
+                }
+                VenvProgress::Running(current) | VenvProgress::Failed { step: current, .. }
+                    if order(step) < order(*current) =>
+                {
+                    bootstrap::check_circle()
+                }
+                _ => bootstrap::circle(),
+            };
+            row![icon, text(label)]
+                .spacing(6)
+                .align_y(Alignment::Center)
+                .into()
+        }),
+    )
+    .spacing(3);
+    let result: Option<Element<'_, AppMsg>> = match &wizard.progress {
+        VenvProgress::Done(version) => Some(
+            text(fl!("venv-wizard-done-msg", version = version.as_str()))
+                .style(text::success)
+                .into(),
+        ),
+        VenvProgress::Failed { error, .. } => Some(
+            scrollable(text(error).style(text::danger))
+                .height(Length::Shrink)
+                .into(),
+        ),
+        _ => None,
+    };
+    let done = matches!(wizard.progress, VenvProgress::Done(_));
+
+    container(
+        column![
+            row![
+                text(fl!("venv-wizard-header")).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal),
+            ]
+            .align_y(Alignment::Center),
+            text(fl!("venv-wizard-msg")),
+            view_settings_row(
+                fl!("venv-wizard-dir-label"),
+                row![
+                    text_input("", &wizard.dir)
+                        .on_input_maybe(
+                            editable.then_some(|dir| {
+                                AppMsg::VenvWizard(VenvWizardMsg::UpdateDir(dir))
+                            })
+                        )
+                        .width(350),
+                    view_text_tooltip(
+                        button(bootstrap::foldertwo_open()).on_press_maybe(editable.then(|| {
+                            AppMsg::PickPath {
+                                target: FileBrowserTarget::CreatedVenvDir,
+                                dir: wizard
+                                    .dir()
+                                    .parent()
+                                    .map(Path::to_path_buf)
+                                    .unwrap_or_default(),
+                            }
+                        })),
+                        fl!("settings-venv-dir-pick-tooltip")
+                    ),
+                ]
+                .spacing(1)
+                .align_y(Alignment::Center)
+            ),
+            view_settings_row(
+                fl!("venv-wizard-labgrid-version-label"),
+                text_input(
+                    &fl!("venv-wizard-labgrid-version-placeholder"),
+                    &wizard.labgrid_version
+                )
+                .on_input_maybe(editable.then_some(|version| {
+                    AppMsg::VenvWizard(VenvWizardMsg::UpdateLabgridVersion(version))
+                }))
+                .width(200)
+            ),
+            problem
+                .clone()
+                .map(|problem| text(problem).style(text::danger)),
+            steps,
+            result,
+            row![
+                space::horizontal(),
+                button(text(fl!("venv-wizard-create-button"))).on_press_maybe(
+                    (editable && problem.is_none() && !done)
+                        .then_some(AppMsg::VenvWizard(VenvWizardMsg::Start))
+                ),
+                done.then(|| {
+                    button(text(fl!("venv-wizard-use-button")))
+                        .style(button::success)
+                        .on_press(AppMsg::VenvWizard(VenvWizardMsg::Use))
+                }),
+            ]
+            .spacing(6),
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 300.)
+    .padding(12)
+    .into()
+}
+
+/// View for the recent tracing events of the selected level, the latest first.
+pub(crate) fn view_log_viewer(
+    entries: &[LogEntry],
+    level: LogLevel,
+    optimize_touch: bool,
+) -> Element<'_, AppMsg> {
+    let shown = entries
+        .iter()
+        .rev()
+        .filter(|entry| level.includes(&entry.level))
+        .collect::<Vec<_>>();
+    let n_shown = shown.len();
+    let lines = column(shown.into_iter().map(|entry| {
+        let style = match entry.level {
+            Level::ERROR => text::danger,
+            Level::WARN => text::warning,
+            _ => text::default,
+        };
+        text(entry.to_string())
+            .font(FONT_INCONSOLATA)
+            .size(13)
+            .style(style)
+            .into()
+    }))
+    .spacing(2);
+    container(
+        column![
+            row![
+                text(fl!("log-viewer-header")).size(24),
+                space::horizontal(),
+                text(fl!("log-viewer-level-label")),
+                pick_list(LogLevel::ALL, Some(level), |level| AppMsg::LogViewer(
+                    LogViewerMsg::ChangeLevel(level)
+                )),
+                button(bootstrap::x()).on_press(AppMsg::HideModal),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+            text(fl!("log-viewer-msg")).size(14),
+            if n_shown == 0 {
+                Element::from(text(fl!("log-viewer-empty")))
+            } else {
+                scrollable(lines)
+                    .direction(optimized_scrollbar_properties(true, true, optimize_touch))
+                    .height(Length::Fixed(450.))
+                    .into()
+            },
+            row![
+                button(text(fl!("log-viewer-clear-button")))
+                    .style(button::danger)
+                    .on_press_maybe(
+                        (!entries.is_empty()).then_some(AppMsg::LogViewer(LogViewerMsg::Clear))
+                    ),
+                space::horizontal(),
+                button(text(fl!("log-viewer-copy-button")))
+                    .style(button::secondary)
+                    .on_press_maybe((n_shown > 0).then_some(AppMsg::LogViewer(LogViewerMsg::Copy))),
+            ]
+            .spacing(6),
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH)
+    .padding(12)
+    .into()
+}
+
+/// View for the messages exchanged with the coordinator that match the filter, the latest first.
+pub(crate) fn view_protocol_inspector<'a>(
+    messages: &'a [TracedMessage],
+    filter: &'a str,
+    recording: bool,
+    optimize_touch: bool,
+) -> Element<'a, AppMsg> {
+    let shown = messages
+        .iter()
+        .rev()
+        .filter(|traced| protocol_inspector::matches_filter(traced, filter))
+        .take(protocol_inspector::SHOWN_MESSAGES)
+        .collect::<Vec<_>>();
+    let lines = column(shown.iter().map(|traced| {
+        let style = match (traced.direction, traced.message_type) {
+            (_, "Status") => text::danger,
+            (Direction::Sent, _) => text::primary,
+            (Direction::Received, _) => text::default,
+        };
+        text(protocol_inspector::format_message(traced))
+            .font(FONT_INCONSOLATA)
+            .size(13)
+            .style(style)
+            .into()
+    }))
+    .spacing(2);
+    container(
+        column![
+            row![
+                text(fl!("protocol-inspector-header")).size(24),
+                space::horizontal(),
+                text(fl!("protocol-inspector-record-label")),
+                toggler(recording).on_toggle(|record| {
+                    AppMsg::ProtocolInspector(ProtocolInspectorMsg::Record(record))
+                }),
+                button(bootstrap::x()).on_press(AppMsg::HideModal),
+            ]
+            .spacing(6)
+            .align_y(Alignment::Center),
+            text(fl!(
+                "protocol-inspector-msg",
+                recorded = messages.len(),
+                shown = protocol_inspector::SHOWN_MESSAGES
+            ))
+            .size(14),
+            text_input(&fl!("protocol-inspector-filter-placeholder"), filter).on_input(|filter| {
+                AppMsg::ProtocolInspector(ProtocolInspectorMsg::UpdateFilter(filter))
+            }),
+            if shown.is_empty() {
+                Element::from(text(fl!("protocol-inspector-empty")))
+            } else {
+                scrollable(lines)
+                    .direction(optimized_scrollbar_properties(true, true, optimize_touch))
+                    .height(Length::Fixed(450.))
+                    .into()
+            },
+            row![
+                button(text(fl!("protocol-inspector-clear-button")))
+                    .style(button::danger)
+                    .on_press_maybe(
+                        (!messages.is_empty())
+                            .then_some(AppMsg::ProtocolInspector(ProtocolInspectorMsg::Clear))
+                    ),
+                space::horizontal(),
+                view_text_tooltip(
+                    button(text(fl!("protocol-inspector-copy-button")))
+                        .style(button::secondary)
+                        .on_press_maybe(
+                            (!messages.is_empty())
+                                .then_some(AppMsg::ProtocolInspector(ProtocolInspectorMsg::Copy))
+                        ),
+                    fl!("protocol-inspector-copy-tooltip"),
+                ),
+            ]
+            .spacing(6),
+        ]
+        .spacing(12),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH)
+    .padding(12)
+    .into()
+}
+
+/// View for the connection profiles manager.
+pub(crate) fn view_profiles(app: &App, editing: Option<usize>) -> Element<'_, AppMsg> {
+    let profile_list: Element<'_, AppMsg> = if app.profiles.is_empty() {
+        text(fl!("profiles-empty")).into()
+    } else {
+        row(app.profiles.iter().enumerate().map(|(i, profile)| {
+            button(text(&profile.name))
+                .style(if editing == Some(i) {
+                    button::primary
+                } else {
+                    button::secondary
+                })
+                .on_press(AppMsg::ShowModal(Box::new(Modal::Profiles {
+                    editing: Some(i),
+                })))
+                .into()
+        }))
+        .spacing(6)
+        .wrap()
+        .into()
+    };
+    let form = editing
+        .and_then(|i| app.profiles.get(i).map(|profile| (i, profile)))
+        .map(|(i, profile)| column![rule::horizontal(1), view_profile_form(i, profile)].spacing(6));
+
+    container(
+        column![
+            row![
+                text(fl!("profiles-header")).size(24),
+                space::horizontal(),
+                button(bootstrap::x()).on_press(AppMsg::HideModal),
+            ]
+            .spacing(6),
+            container(
+                column![
+                    row![
+                        profile_list,
+                        space::horizontal(),
+                        button(text(fl!("profile-add-button"))).on_press(AppMsg::AddProfile),
+                    ]
+                    .spacing(6)
+                    .align_y(Alignment::Center),
+                    form,
+                ]
+                .spacing(6)
+                .padding(6)
+            )
+            .width(Length::Fill)
+            .style(container::rounded_box)
+        ]
+        .spacing(6),
+    )
+    .style(modal_container_style)
+    .max_width(UI_MAX_WIDTH - 200.)
+    .padding(12)
+    .into()
+}
+
 /// View for application settings
 pub(crate) fn view_settings(app: &App) -> Element<'_, AppMsg> {
     let project_version = util::project_version();
+    let config_path = util::config_path();
+    let script_names = match &app.state {
+        AppState::Connected(connected) => {
+            connected.scripts.iter().map(|s| s.name.clone()).collect()
+        }
+        _ => Vec::new(),
+    };
 
     container(
         column![
             row![
                 text(fl!("settings-header")).size(24),
                 space::horizontal(),
+                app.settings_saved_confirmation.is_some().then(|| {
+                    row![
+                        bootstrap::check_lg(),
+                        text(fl!("settings-saved-confirmation"))
+                    ]
+                    .spacing(6)
+                    .align_y(Alignment::Center)
+                }),
                 button(bootstrap::x()).on_press(AppMsg::HideModal),
             ]
-            .spacing(6),
+            .spacing(12)
+            .align_y(Alignment::Center),
             container(
                 column![
                     view_settings_row(
@@ -53,6 +1063,138 @@ pub(crate) fn view_settings(app: &App) -> Element<'_, AppMsg> {
                         toggler(app.optimize_touch).on_toggle(AppMsg::OptimizeTouch)
                     ),
                     rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-ui-scale-label"),
+                        row![
+                            text(fl!(
+                                "settings-ui-scale-value",
+                                percent = app.ui_scale_percent
+                            )),
+                            slider(
+                                config::UI_SCALE_PERCENT_RANGE,
+                                app.ui_scale_percent,
+                                AppMsg::ChangeUiScale
+                            )
+                            .step(10u32)
+                            .width(200)
+                        ]
+                        .align_y(Alignment::Center)
+                        .spacing(12)
+                    ),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-high-contrast-label"),
+                        toggler(app.high_contrast).on_toggle(AppMsg::HighContrast)
+                    ),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-resource-grace-period-label"),
+                        row![
+                            text(fl!(
+                                "settings-resource-grace-period-value",
+                                secs = app.resource_grace_period_secs
+                            )),
+                            slider(
+                                0..=300,
+                                app.resource_grace_period_secs,
+                                AppMsg::ChangeResourceGracePeriod
+                            )
+                            .step(10u32)
+                            .width(200)
+                        ]
+                        .align_y(Alignment::Center)
+                        .spacing(12)
+                    ),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-offline-exporter-resources-label"),
+                        pick_list(
+                            OfflineExporterResources::ALL,
+                            Some(app.offline_exporter_resources),
+                            AppMsg::ChangeOfflineExporterResources
+                        )
+                    ),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-persist-acquire-history-label"),
+                        toggler(app.persist_acquire_history)
+                            .on_toggle(AppMsg::PersistAcquireHistory)
+                    ),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-record-utilization-label"),
+                        toggler(app.record_utilization).on_toggle(AppMsg::RecordUtilization)
+                    ),
+                    rule::horizontal(1),
+                    view_card_palette_settings(&app.card_palette),
+                    rule::horizontal(1),
+                    view_notification_settings(&app.notification_settings),
+                    rule::horizontal(1),
+                    view_quick_action_settings(&app.quick_actions, script_names),
+                    rule::horizontal(1),
+                    view_auto_release_settings(app.auto_release),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-bulk-ops-per-sec-label"),
+                        row![
+                            text(fl!(
+                                "settings-bulk-ops-per-sec-value",
+                                ops = app.bulk_ops_per_sec
+                            )),
+                            slider(1..=100, app.bulk_ops_per_sec, AppMsg::ChangeBulkOpsPerSec)
+                                .width(200)
+                        ]
+                        .align_y(Alignment::Center)
+                        .spacing(12)
+                    ),
+                    rule::horizontal(1),
+                    view_autosave_settings(app),
+                    rule::horizontal(1),
+                    view_connection_timeout_settings(app.connection_timeouts),
+                    view_settings_row(
+                        fl!("settings-retry-transient-errors-label"),
+                        toggler(app.retry_transient_errors).on_toggle(AppMsg::RetryTransientErrors)
+                    ),
+                    view_connection_auth_settings(&app.connection_auth),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-check-script-imports-label"),
+                        toggler(app.check_script_imports).on_toggle(AppMsg::CheckScriptImports)
+                    ),
+                    view_settings_row(
+                        fl!("settings-scripts-max-depth-label"),
+                        row![
+                            text(fl!(
+                                "settings-scripts-max-depth-value",
+                                depth = app.scripts_max_depth
+                            )),
+                            slider(0..=10, app.scripts_max_depth, AppMsg::ChangeScriptsMaxDepth)
+                                .width(200)
+                        ]
+                        .align_y(Alignment::Center)
+                        .spacing(12)
+                    ),
+                    view_settings_row(
+                        fl!("settings-script-timeout-label"),
+                        row![
+                            text(fl!(
+                                "settings-script-timeout-value",
+                                mins = app.script_timeout_mins
+                            )),
+                            slider(
+                                0..=240,
+                                app.script_timeout_mins,
+                                AppMsg::ChangeScriptTimeout
+                            )
+                            .step(5u32)
+                            .width(200)
+                        ]
+                        .align_y(Alignment::Center)
+                        .spacing(12)
+                    ),
+                    rule::horizontal(1),
+                    view_mqtt_settings(app),
+                    rule::horizontal(1),
                     view_settings_row(
                         fl!("settings-venv-dir-label"),
                         row![
@@ -65,18 +1207,59 @@ pub(crate) fn view_settings(app: &App) -> Element<'_, AppMsg> {
                                 fl!("venv-dir-reset-tooltip")
                             ),
                             view_text_tooltip(
-                                button(bootstrap::foldertwo_open()).on_press(AppMsg::Connected(
-                                    ConnectedMsg::OpenChangeVenvDirFileDialog {
-                                        initial_dir: app.venv_dir.clone()
-                                    }
-                                )),
+                                button(bootstrap::foldertwo_open()).on_press(AppMsg::PickPath {
+                                    target: FileBrowserTarget::VenvDir,
+                                    dir: app.venv_dir.clone()
+                                }),
                                 fl!("settings-venv-dir-pick-tooltip")
                             ),
+                            view_text_tooltip(
+                                button(bootstrap::plus_lg())
+                                    .on_press(AppMsg::ShowModal(Box::new(Modal::CreateVenv))),
+                                fl!("settings-venv-create-tooltip")
+                            ),
                         ]
                         .align_y(Alignment::Center)
                         .spacing(1)
                     ),
+                    view_venv_versions(app),
+                    rule::horizontal(1),
+                    view_identity_settings(app),
                     rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-terminal-command-label"),
+                        text_input("", &app.terminal_command)
+                            .on_input(AppMsg::ChangeTerminalCommand)
+                            .width(200)
+                    ),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-log-viewer-label"),
+                        button(text(fl!("settings-log-viewer-button")))
+                            .style(button::secondary)
+                            .on_press(AppMsg::ShowModal(Box::new(Modal::LogViewer)))
+                    ),
+                    rule::horizontal(1),
+                    view_settings_row(
+                        fl!("settings-config-file-label"),
+                        row![
+                            container(text(config_path.display().to_string()))
+                                .padding(padding::right(5)),
+                            view_text_tooltip(
+                                button(bootstrap::copy()).on_press(AppMsg::ClipboardCopy(
+                                    config_path.display().to_string()
+                                )),
+                                fl!("clipboard-copy-tooltip")
+                            ),
+                            view_text_tooltip(
+                                button(bootstrap::box_arrow_up_right())
+                                    .on_press(AppMsg::OpenConfigFile),
+                                fl!("settings-config-file-open-tooltip")
+                            ),
+                        ]
+                        .align_y(Alignment::Center)
+                        .spacing(1)
+                    ),
                     rule::horizontal(1),
                     view_settings_row(fl!("app-authors-label"), text(util::project_authors())),
                     rule::horizontal(1),
@@ -0,0 +1,156 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Harness for rendering views headless, used for snapshot based regression tests.
+//!
+//! Views are laid out with the software renderer and are then traversed to produce a textual snapshot
+//! of the widget tree. Additionally clicks on elements can be simulated to verify the emitted messages.
+
+use crate::app::AppMsg;
+use crate::i18n::{self, AppLanguage};
+use iced::advanced::clipboard;
+use iced::advanced::renderer::Headless;
+use iced::advanced::widget::operation::{Scrollable, TextInput};
+use iced::advanced::widget::{Id, Operation};
+use iced::{mouse, Element, Event, Font, Pixels, Rectangle, Size, Vector};
+use iced_runtime::user_interface::{Cache, UserInterface};
+
+/// The viewport size the views are laid out in.
+const VIEWPORT: Size = Size::new(1000., 800.);
+
+/// Renders views headless and allows to inspect them.
+pub(crate) struct ViewHarness {
+    renderer: iced::Renderer,
+}
+
+impl ViewHarness {
+    pub(crate) fn new() -> Self {
+        // Snapshots must not depend on the system language.
+        i18n::change_language(AppLanguage::EnUs.into()).expect("Change language");
+        let renderer = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Build runtime")
+            .block_on(iced::Renderer::new(
+                Font::DEFAULT,
+                Pixels(16.),
+                Some("tiny-skia"),
+            ))
+            .expect("Create headless renderer");
+        Self { renderer }
+    }
+
+    /// Lays out the element and returns a textual snapshot of its widget tree.
+    pub(crate) fn snapshot<'a>(&mut self, element: impl Into<Element<'a, AppMsg>>) -> String {
+        let mut ui = UserInterface::build(element, VIEWPORT, Cache::default(), &mut self.renderer);
+        let mut snapshot = TreeSnapshot::default();
+        ui.operate(&self.renderer, &mut snapshot);
+        snapshot.output
+    }
+
+    /// Simulates a click on the center of the text with the supplied content and returns all emitted messages.
+    ///
+    /// Panics if the text is not present in the element.
+    pub(crate) fn click<'a>(
+        &mut self,
+        element: impl Into<Element<'a, AppMsg>>,
+        content: &str,
+    ) -> Vec<AppMsg> {
+        let mut ui = UserInterface::build(element, VIEWPORT, Cache::default(), &mut self.renderer);
+        let mut find = FindText {
+            content,
+            bounds: None,
+        };
+        ui.operate(&self.renderer, &mut find);
+        let position = find
+            .bounds
+            .unwrap_or_else(|| panic!("Text '{content}' not found in view"))
+            .center();
+        let events = [
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ];
+        let mut messages = Vec::new();
+        let _ = ui.update(
+            &events,
+            mouse::Cursor::Available(position),
+            &mut self.renderer,
+            &mut clipboard::Null,
+            &mut messages,
+        );
+        messages
+    }
+}
+
+/// Removes the unicode isolation marks that fluent inserts around placeables.
+fn strip_isolation_marks(text: &str) -> String {
+    text.replace(['\u{2068}', '\u{2069}'], "")
+}
+
+/// Operation that records the widget tree, one widget per line and indented by nesting depth.
+#[derive(Debug, Default)]
+struct TreeSnapshot {
+    depth: usize,
+    output: String,
+}
+
+impl TreeSnapshot {
+    fn push(&mut self, line: &str) {
+        self.output += &"  ".repeat(self.depth);
+        self.output += line;
+        self.output += "\n";
+    }
+}
+
+impl Operation for TreeSnapshot {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        self.depth += 1;
+        operate(self);
+        self.depth -= 1;
+    }
+
+    fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle) {
+        self.push("container");
+    }
+
+    fn scrollable(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _translation: Vector,
+        _state: &mut dyn Scrollable,
+    ) {
+        self.push("scrollable");
+    }
+
+    fn text_input(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn TextInput) {
+        self.push(&format!(
+            "text_input {:?}",
+            strip_isolation_marks(state.text())
+        ));
+    }
+
+    fn text(&mut self, _id: Option<&Id>, _bounds: Rectangle, text: &str) {
+        self.push(&format!("text {:?}", strip_isolation_marks(text)));
+    }
+}
+
+/// Operation that finds the bounds of the first text with the given content.
+struct FindText<'c> {
+    content: &'c str,
+    bounds: Option<Rectangle>,
+}
+
+impl Operation for FindText<'_> {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        operate(self);
+    }
+
+    fn text(&mut self, _id: Option<&Id>, bounds: Rectangle, text: &str) {
+        if self.bounds.is_none() && strip_isolation_marks(text) == self.content {
+            self.bounds = Some(bounds);
+        }
+    }
+}
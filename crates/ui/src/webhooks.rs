@@ -0,0 +1,71 @@
+// SPDX-FileCopyrightText: 2025 Duagon Germany GmbH
+//
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+use crate::app::AppMsg;
+use crate::connection::ConnectionEvent;
+use iced::Task;
+use labgrid_ui_core::webhooks::{
+    WebhookDelivery, WebhookEvent, WebhookNotifier, WebhookTracker, WebhookUrl,
+};
+
+/// Notifies the webhooks requested on the command line about the changes observed through the connection events.
+#[derive(Debug)]
+pub(crate) struct Webhooks {
+    notifier: WebhookNotifier,
+    tracker: WebhookTracker,
+}
+
+impl Webhooks {
+    /// Creates the webhooks and their delivery identifying the client as `source`, `None` without any URLs.
+    pub(crate) fn new(urls: Vec<WebhookUrl>, source: String) -> Option<(Self, WebhookDelivery)> {
+        if urls.is_empty() {
+            return None;
+        }
+        let (notifier, delivery) = WebhookNotifier::new(urls, source);
+        Some((
+            Self {
+                notifier,
+                tracker: WebhookTracker::new(),
+            },
+            delivery,
+        ))
+    }
+
+    /// Records the connection event, notifying the webhooks about the changes it caused.
+    pub(crate) fn record_event(&mut self, event: &ConnectionEvent) {
+        let events: Vec<WebhookEvent> = match event {
+            ConnectionEvent::Disconnected { .. } => {
+                self.tracker.reset();
+                return;
+            }
+            ConnectionEvent::Synced => {
+                self.tracker.synced();
+                return;
+            }
+            ConnectionEvent::Place(place) => self.tracker.place(place),
+            ConnectionEvent::Places(places) => places
+                .iter()
+                .flat_map(|place| self.tracker.place(place))
+                .collect(),
+            ConnectionEvent::DeletePlace(name) => {
+                self.tracker.delete_place(name);
+                return;
+            }
+            ConnectionEvent::Resource(resource) => {
+                self.tracker.resource(resource).into_iter().collect()
+            }
+            ConnectionEvent::DeleteResource(path) => {
+                self.tracker.delete_resource(path).into_iter().collect()
+            }
+            ConnectionEvent::Reservations(reservations) => self.tracker.reservations(reservations),
+            _ => return,
+        };
+        self.notifier.notify(events);
+    }
+}
+
+/// A task delivering the notifications until the webhooks are dropped.
+pub(crate) fn delivery_task(delivery: WebhookDelivery) -> Task<AppMsg> {
+    Task::future(delivery.run()).discard()
+}